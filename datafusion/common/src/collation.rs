@@ -0,0 +1,235 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the [`Collation`] of string comparisons
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, GenericStringArray, OffsetSizeTrait};
+use arrow::datatypes::DataType;
+
+use crate::cast::as_generic_string_array;
+use crate::error::_plan_err;
+use crate::{DataFusionError, Result, ScalarValue};
+
+/// The field metadata key of the [`Collation`] of a string column
+pub const COLLATION_METADATA_KEY: &str = "datafusion.collation";
+
+/// A string collation, as specified with `expr COLLATE <name>`
+///
+/// Each collation maps a string to a collation key such that two strings
+/// are equal under the collation if and only if their keys are byte-wise
+/// equal, and are ordered by the byte-wise ordering of their keys. Comparing,
+/// sorting, grouping and joining on the keys thus honors the collation.
+///
+/// The collation of a column is carried in its field metadata under
+/// [`COLLATION_METADATA_KEY`], the values themselves are never rewritten.
+/// Operators derive the keys only where they compare, sort, group or join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Collation {
+    /// Byte-wise comparison, the default
+    Binary,
+    /// Case-insensitive comparison
+    NoCase,
+    /// Case and accent insensitive comparison of the root locale
+    UndCi,
+}
+
+impl Collation {
+    /// Returns the collation key of `s`
+    pub fn key<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            Collation::Binary => Cow::Borrowed(s),
+            Collation::NoCase => {
+                if s.chars().any(char::is_uppercase) {
+                    Cow::Owned(s.to_lowercase())
+                } else {
+                    Cow::Borrowed(s)
+                }
+            }
+            Collation::UndCi => {
+                if s.is_ascii() && !s.bytes().any(|b| b.is_ascii_uppercase()) {
+                    return Cow::Borrowed(s);
+                }
+                let mut key = String::with_capacity(s.len());
+                for c in s.chars().flat_map(char::to_lowercase) {
+                    match fold_accent(c) {
+                        Some(folded) => key.push_str(folded),
+                        None => key.push(c),
+                    }
+                }
+                Cow::Owned(key)
+            }
+        }
+    }
+
+    /// Returns the collation stored in the field `metadata`, if any
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Option<Self> {
+        metadata
+            .get(COLLATION_METADATA_KEY)
+            .and_then(|name| name.parse().ok())
+    }
+
+    /// Returns field metadata holding this collation
+    pub fn to_metadata(&self) -> HashMap<String, String> {
+        HashMap::from([(COLLATION_METADATA_KEY.to_string(), self.to_string())])
+    }
+
+    /// Returns the collation keys of the strings in `array`, or `array`
+    /// itself if it does not hold strings or the collation is binary
+    pub fn keys(&self, array: &ArrayRef) -> Result<ArrayRef> {
+        if *self == Collation::Binary {
+            return Ok(Arc::clone(array));
+        }
+        match array.data_type() {
+            DataType::Utf8 => self.keys_of::<i32>(array),
+            DataType::LargeUtf8 => self.keys_of::<i64>(array),
+            _ => Ok(Arc::clone(array)),
+        }
+    }
+
+    fn keys_of<T: OffsetSizeTrait>(&self, array: &ArrayRef) -> Result<ArrayRef> {
+        let keys = as_generic_string_array::<T>(array)?
+            .iter()
+            .map(|s| s.map(|s| self.key(s)))
+            .collect::<GenericStringArray<T>>();
+        Ok(Arc::new(keys))
+    }
+
+    /// Returns the collation key of the string `scalar`, or `scalar` itself
+    /// if it is not a string
+    pub fn scalar_key(&self, scalar: &ScalarValue) -> ScalarValue {
+        match scalar {
+            ScalarValue::Utf8(Some(s)) => {
+                ScalarValue::Utf8(Some(self.key(s).into_owned()))
+            }
+            ScalarValue::LargeUtf8(Some(s)) => {
+                ScalarValue::LargeUtf8(Some(self.key(s).into_owned()))
+            }
+            _ => scalar.clone(),
+        }
+    }
+}
+
+/// Returns the unaccented form of the lowercase letter `c`, or an empty
+/// string for combining diacritical marks
+fn fold_accent(c: char) -> Option<&'static str> {
+    let folded = match c {
+        '\u{0300}'..='\u{036f}' => "",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+impl Display for Collation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let name = match self {
+            Collation::Binary => "binary",
+            Collation::NoCase => "nocase",
+            Collation::UndCi => "und-ci",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Collation {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "binary" | "c" | "posix" => Ok(Collation::Binary),
+            "nocase" => Ok(Collation::NoCase),
+            "und-ci" | "und-u-ks-level1" => Ok(Collation::UndCi),
+            _ => _plan_err!(
+                "Unsupported collation '{s}', expected one of: binary, nocase, und-ci"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collation_keys() {
+        assert_eq!(Collation::Binary.key("Straße"), "Straße");
+        assert_eq!(Collation::NoCase.key("Straße"), "straße");
+        assert_eq!(Collation::NoCase.key("ÉCOLE"), "école");
+        assert_eq!(Collation::UndCi.key("ÉCOLE"), "ecole");
+        assert_eq!(Collation::UndCi.key("Straße"), "strasse");
+        // decomposed accents are dropped as well
+        assert_eq!(Collation::UndCi.key("e\u{0301}cole"), "ecole");
+        assert!(matches!(Collation::UndCi.key("plain"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn collation_names() -> Result<()> {
+        for collation in [Collation::Binary, Collation::NoCase, Collation::UndCi] {
+            assert_eq!(collation.to_string().parse::<Collation>()?, collation);
+        }
+        assert_eq!("NOCASE".parse::<Collation>()?, Collation::NoCase);
+        let err = "fr-ci".parse::<Collation>().unwrap_err();
+        assert!(err.to_string().contains("Unsupported collation 'fr-ci'"));
+        Ok(())
+    }
+
+    #[test]
+    fn collation_array_keys() -> Result<()> {
+        use arrow::array::{Int32Array, StringArray};
+
+        let array: ArrayRef = Arc::new(StringArray::from(vec![Some("ÉCOLE"), None]));
+        let keys = Collation::UndCi.keys(&array)?;
+        let expected: ArrayRef = Arc::new(StringArray::from(vec![Some("ecole"), None]));
+        assert_eq!(&keys, &expected);
+
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        assert!(Arc::ptr_eq(&Collation::NoCase.keys(&ints)?, &ints));
+
+        let metadata = Collation::NoCase.to_metadata();
+        assert_eq!(Collation::from_metadata(&metadata), Some(Collation::NoCase));
+        assert_eq!(Collation::from_metadata(&HashMap::new()), None);
+        Ok(())
+    }
+}
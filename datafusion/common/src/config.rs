@@ -21,6 +21,7 @@ use crate::{DataFusionError, Result};
 use std::any::Any;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// A macro that wraps a configuration struct and automatically derives
 /// [`Default`] and [`ConfigField`] for it, allowing it to be used
@@ -252,6 +253,12 @@ config_namespace! {
         /// batches and merged.
         pub sort_in_place_threshold_bytes: usize, default = 1024 * 1024
 
+        /// Sets the compression codec used when writing spill files of sorts
+        /// and aggregations to disk. Valid values are: uncompressed, lz4_frame
+        /// and zstd. Compressed spill files use less disk space at the cost of
+        /// additional CPU time to write and read them
+        pub spill_compression: String, default = "uncompressed".to_string()
+
         /// Number of files to read in parallel when inferring schema and statistics
         pub meta_fetch_concurrency: usize, default = 32
 
@@ -273,6 +280,23 @@ config_namespace! {
         /// memory consumption
         pub max_buffered_batches_per_output_file: usize, default = 2
 
+        /// Maximum number of files kept open at once when writing to a table
+        /// partitioned by column values. Once reached, the least recently written
+        /// file is closed and further rows of its partition go to a new file
+        pub max_open_partition_files: usize, default = 100
+
+        /// If true, rows written to a table partitioned by column values are first
+        /// sorted by the partition columns, spilling to disk if needed, so that each
+        /// partition is written to a single file with one file open at a time
+        pub sort_partitioned_writes: bool, default = false
+
+        /// Sets how integer and decimal arithmetic handles overflow in binary
+        /// operators, negation, numeric casts and the SUM and AVG aggregates.
+        /// Valid values are: wrap (silently wrap around), error (fail the query
+        /// with an error naming the operator and its operands) and null (produce
+        /// NULL for the overflowing value)
+        pub arithmetic_overflow: ArithmeticOverflowMode, default = ArithmeticOverflowMode::Wrap
+
     }
 }
 
@@ -309,6 +333,18 @@ config_namespace! {
         /// the filters are applied in the same order as written in the query
         pub reorder_filters: bool, default = false
 
+        /// If true, the parquet reader reconciles file schemas that evolved over time:
+        /// columns are matched by Parquet field id when present and by name otherwise,
+        /// and only safe widening casts (e.g. int32 to int64, float to double, larger
+        /// decimal precision) are applied. Files are merged in the order they were last
+        /// modified, so renamed columns take their latest name
+        pub schema_evolution: bool, default = false
+
+        /// If true and `schema_evolution` is enabled, files whose schema cannot be
+        /// reconciled with the table schema are skipped and counted in the
+        /// `file_schema_incompatible` metric. If false, such files fail the query
+        pub skip_incompatible_files: bool, default = false
+
         // The following map to parquet::file::properties::WriterProperties
 
         /// Sets best effort maximum size of data page in bytes
@@ -505,6 +541,16 @@ config_namespace! {
         /// `RepartitionExec` even if this requires subsequently resorting data using a `SortExec`.
         pub prefer_existing_sort: bool, default = false
 
+        /// When set to true, identical subplans that are used several times in
+        /// a query, such as a CTE referenced more than once, are executed once
+        /// and their results are shared by all of their consumers
+        pub share_common_subplans: bool, default = false
+
+        /// When set to true, subplans of a query that match the query of a
+        /// materialized view are rewritten to read the results of the view.
+        /// The results are as of the last refresh of the view
+        pub enable_materialized_view_rewrite: bool, default = false
+
         /// When set to true, the logical plan optimizer will produce warning
         /// messages if any optimization rules produce errors and then proceed to the next
         /// rule. When set to false, any rules that produce errors will cause the query to fail
@@ -878,6 +924,45 @@ config_field!(bool);
 config_field!(usize);
 config_field!(f64);
 config_field!(u64);
+config_field!(ArithmeticOverflowMode);
+
+/// How arithmetic overflow is handled, as configured by
+/// `datafusion.execution.arithmetic_overflow`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ArithmeticOverflowMode {
+    /// Overflowing values wrap around (two's complement)
+    #[default]
+    Wrap,
+    /// Overflow raises an error
+    Error,
+    /// Overflowing values are replaced by NULL
+    Null,
+}
+
+impl FromStr for ArithmeticOverflowMode {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "wrap" => Ok(Self::Wrap),
+            "error" => Ok(Self::Error),
+            "null" => Ok(Self::Null),
+            _ => Err(DataFusionError::Configuration(format!(
+                "Invalid arithmetic overflow mode '{s}', expected one of: wrap, error, null"
+            ))),
+        }
+    }
+}
+
+impl Display for ArithmeticOverflowMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wrap => write!(f, "wrap"),
+            Self::Error => write!(f, "error"),
+            Self::Null => write!(f, "null"),
+        }
+    }
+}
 
 /// An implementation trait used to recursively walk configuration
 trait Visit {
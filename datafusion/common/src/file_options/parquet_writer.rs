@@ -215,9 +215,7 @@ impl TryFrom<(&ConfigOptions, &StatementOptions)> for ParquetWriterOptions {
 }
 
 /// Parses datafusion.execution.parquet.encoding String to a parquet::basic::Encoding
-pub(crate) fn parse_encoding_string(
-    str_setting: &str,
-) -> Result<parquet::basic::Encoding> {
+pub fn parse_encoding_string(str_setting: &str) -> Result<parquet::basic::Encoding> {
     let str_setting_lower: &str = &str_setting.to_lowercase();
     match str_setting_lower {
         "plain" => Ok(parquet::basic::Encoding::PLAIN),
@@ -282,7 +280,7 @@ fn require_level(codec: &str, level: Option<u32>) -> Result<u32> {
 }
 
 /// Parses datafusion.execution.parquet.compression String to a parquet::basic::Compression
-pub(crate) fn parse_compression_string(
+pub fn parse_compression_string(
     str_setting: &str,
 ) -> Result<parquet::basic::Compression> {
     let str_setting_lower: &str = &str_setting.to_lowercase();
@@ -335,7 +333,8 @@ pub(crate) fn parse_compression_string(
     }
 }
 
-pub(crate) fn parse_version_string(str_setting: &str) -> Result<WriterVersion> {
+/// Parses datafusion.execution.parquet.writer_version String to a parquet::file::properties::WriterVersion
+pub fn parse_version_string(str_setting: &str) -> Result<WriterVersion> {
     let str_setting_lower: &str = &str_setting.to_lowercase();
     match str_setting_lower {
         "1.0" => Ok(WriterVersion::PARQUET_1_0),
@@ -347,7 +346,8 @@ pub(crate) fn parse_version_string(str_setting: &str) -> Result<WriterVersion> {
     }
 }
 
-pub(crate) fn parse_statistics_string(str_setting: &str) -> Result<EnabledStatistics> {
+/// Parses datafusion.execution.parquet.statistics_enabled String to a parquet::file::properties::EnabledStatistics
+pub fn parse_statistics_string(str_setting: &str) -> Result<EnabledStatistics> {
     let str_setting_lower: &str = &str_setting.to_lowercase();
     match str_setting_lower {
         "none" => Ok(EnabledStatistics::None),
@@ -27,9 +27,10 @@ use arrow::{downcast_dictionary_array, downcast_primitive_array};
 use arrow_buffer::i256;
 
 use crate::cast::{
-    as_boolean_array, as_generic_binary_array, as_primitive_array, as_string_array,
+    as_boolean_array, as_generic_binary_array, as_map_array, as_primitive_array,
+    as_string_array,
 };
-use crate::error::{DataFusionError, Result, _internal_err};
+use crate::error::{_internal_err, DataFusionError, Result};
 
 // Combines two hashes into one hash
 #[inline]
@@ -240,6 +241,26 @@ where
     Ok(())
 }
 
+fn hash_map_array(
+    array: &MapArray,
+    random_state: &RandomState,
+    hashes_buffer: &mut [u64],
+) -> Result<()> {
+    let entries = [array.keys().clone(), array.values().clone()];
+    let offsets = array.value_offsets();
+    let mut entry_hashes = vec![0u64; array.entries().len()];
+    create_hashes(&entries, random_state, &mut entry_hashes)?;
+    for (i, (start, stop)) in offsets.iter().zip(offsets.iter().skip(1)).enumerate() {
+        if array.is_valid(i) {
+            let hash = &mut hashes_buffer[i];
+            for entry_hash in &entry_hashes[start.as_usize()..stop.as_usize()] {
+                *hash = combine_hashes(*hash, *entry_hash);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Test version of `create_hashes` that produces the same value for
 /// all hashes (to test collisions)
 ///
@@ -335,6 +356,10 @@ pub fn create_hashes<'a>(
                 let array = as_large_list_array(array);
                 hash_list_array(array, random_state, hashes_buffer)?;
             }
+            DataType::Map(_, _) => {
+                let array = as_map_array(array)?;
+                hash_map_array(array, random_state, hashes_buffer)?;
+            }
             _ => {
                 // This is internal because we should have caught this before.
                 return _internal_err!(
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod collation;
 mod column;
 mod dfschema;
 mod error;
@@ -42,6 +43,7 @@ pub mod utils;
 
 /// Reexport arrow crate
 pub use arrow;
+pub use collation::{Collation, COLLATION_METADATA_KEY};
 pub use column::Column;
 pub use dfschema::{DFField, DFSchema, DFSchemaRef, ExprSchema, SchemaExt, ToDFSchema};
 pub use error::{
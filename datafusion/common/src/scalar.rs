@@ -28,12 +28,13 @@ use crate::cast::{
     as_decimal128_array, as_decimal256_array, as_dictionary_array,
     as_fixed_size_binary_array, as_fixed_size_list_array, as_struct_array,
 };
-use crate::error::{DataFusionError, Result, _internal_err, _not_impl_err};
+use crate::error::{_exec_err, _internal_err, _not_impl_err, DataFusionError, Result};
 use crate::hash_utils::create_hashes;
 use crate::utils::array_into_list_array;
 use arrow::buffer::{NullBuffer, OffsetBuffer};
 use arrow::compute::kernels::numeric::*;
 use arrow::datatypes::{i256, FieldRef, Fields, SchemaBuilder};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
 use arrow::{
     array::*,
     compute::kernels::cast::{cast_with_options, CastOptions},
@@ -99,6 +100,8 @@ pub enum ScalarValue {
     Fixedsizelist(Option<Vec<ScalarValue>>, FieldRef, i32),
     /// Represents a single element of a [`ListArray`] as an [`ArrayRef`]
     List(ArrayRef),
+    /// Represents a single element of a [`MapArray`] as an [`ArrayRef`]
+    Map(ArrayRef),
     /// Date stored as a signed 32bit int days since UNIX epoch 1970-01-01
     Date32(Option<i32>),
     /// Date stored as a signed 64bit int milliseconds since UNIX epoch 1970-01-01
@@ -202,6 +205,8 @@ impl PartialEq for ScalarValue {
             (Fixedsizelist(_, _, _), _) => false,
             (List(v1), List(v2)) => v1.eq(v2),
             (List(_), _) => false,
+            (Map(v1), Map(v2)) => v1.eq(v2),
+            (Map(_), _) => false,
             (Date32(v1), Date32(v2)) => v1.eq(v2),
             (Date32(_), _) => false,
             (Date64(v1), Date64(v2)) => v1.eq(v2),
@@ -349,6 +354,9 @@ impl PartialOrd for ScalarValue {
                 }
             }
             (List(_), _) => None,
+            // maps have no ordering, only equality
+            (Map(v1), Map(v2)) => v1.eq(v2).then_some(Ordering::Equal),
+            (Map(_), _) => None,
             (Date32(v1), Date32(v2)) => v1.partial_cmp(v2),
             (Date32(_), _) => None,
             (Date64(v1), Date64(v2)) => v1.partial_cmp(v2),
@@ -470,7 +478,7 @@ impl std::hash::Hash for ScalarValue {
                 t.hash(state);
                 l.hash(state);
             }
-            List(arr) => {
+            List(arr) | Map(arr) => {
                 let arrays = vec![arr.to_owned()];
                 let hashes_buffer = &mut vec![0; arr.len()];
                 let random_state = ahash::RandomState::with_seeds(0, 0, 0, 0);
@@ -744,6 +752,12 @@ impl ScalarValue {
             DataType::UInt64 => ScalarValue::UInt64(Some(0)),
             DataType::Float32 => ScalarValue::Float32(Some(0.0)),
             DataType::Float64 => ScalarValue::Float64(Some(0.0)),
+            DataType::Decimal128(precision, scale) => {
+                ScalarValue::Decimal128(Some(0), *precision, *scale)
+            }
+            DataType::Decimal256(precision, scale) => {
+                ScalarValue::Decimal256(Some(i256::ZERO), *precision, *scale)
+            }
             DataType::Timestamp(TimeUnit::Second, tz) => {
                 ScalarValue::TimestampSecond(Some(0), tz.clone())
             }
@@ -797,6 +811,9 @@ impl ScalarValue {
             DataType::UInt64 => ScalarValue::UInt64(Some(1)),
             DataType::Float32 => ScalarValue::Float32(Some(1.0)),
             DataType::Float64 => ScalarValue::Float64(Some(1.0)),
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+                return Self::new_decimal_power_of_ten(datatype, 0);
+            }
             _ => {
                 return _not_impl_err!(
                     "Can't create an one scalar from data_type \"{datatype:?}\""
@@ -836,6 +853,9 @@ impl ScalarValue {
             DataType::UInt64 => ScalarValue::UInt64(Some(10)),
             DataType::Float32 => ScalarValue::Float32(Some(10.0)),
             DataType::Float64 => ScalarValue::Float64(Some(10.0)),
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+                return Self::new_decimal_power_of_ten(datatype, 1);
+            }
             _ => {
                 return _not_impl_err!(
                     "Can't create a negative one scalar from data_type \"{datatype:?}\""
@@ -844,6 +864,35 @@ impl ScalarValue {
         })
     }
 
+    /// Create the decimal value `10^exponent` in the given decimal type,
+    /// returning an error if it does not fit the precision of the type
+    fn new_decimal_power_of_ten(datatype: &DataType, exponent: i8) -> Result<Self> {
+        let (precision, scale) = match datatype {
+            DataType::Decimal128(precision, scale)
+            | DataType::Decimal256(precision, scale) => (*precision, *scale),
+            _ => return _internal_err!("Expected a decimal type, got {datatype:?}"),
+        };
+        // the unscaled value is 10^(exponent + scale)
+        let digits = exponent as i16 + scale as i16;
+        if digits < 0 || digits >= precision as i16 {
+            return _exec_err!(
+                "Can't represent 10^{exponent} in data_type \"{datatype:?}\""
+            );
+        }
+        Ok(match datatype {
+            DataType::Decimal128(_, _) => ScalarValue::Decimal128(
+                Some(10_i128.pow(digits as u32)),
+                precision,
+                scale,
+            ),
+            _ => ScalarValue::Decimal256(
+                Some(i256::from_i128(10).pow_wrapping(digits as u32)),
+                precision,
+                scale,
+            ),
+        })
+    }
+
     /// return the [`DataType`] of this `ScalarValue`
     pub fn data_type(&self) -> DataType {
         match self {
@@ -885,7 +934,7 @@ impl ScalarValue {
                 Arc::new(Field::new("item", field.data_type().clone(), true)),
                 *length,
             ),
-            ScalarValue::List(arr) => arr.data_type().to_owned(),
+            ScalarValue::List(arr) | ScalarValue::Map(arr) => arr.data_type().to_owned(),
             ScalarValue::Date32(_) => DataType::Date32,
             ScalarValue::Date64(_) => DataType::Date64,
             ScalarValue::Time32Second(_) => DataType::Time32(TimeUnit::Second),
@@ -954,11 +1003,21 @@ impl ScalarValue {
                 Ok(ScalarValue::IntervalMonthDayNano(Some(val)))
             }
             ScalarValue::Decimal128(Some(v), precision, scale) => {
-                Ok(ScalarValue::Decimal128(Some(-v), *precision, *scale))
+                let v = v.checked_neg().ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Decimal128 overflow on negating {v}"
+                    ))
+                })?;
+                Ok(ScalarValue::Decimal128(Some(v), *precision, *scale))
+            }
+            ScalarValue::Decimal256(Some(v), precision, scale) => {
+                let v = v.checked_neg().ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Decimal256 overflow on negating {v}"
+                    ))
+                })?;
+                Ok(ScalarValue::Decimal256(Some(v), *precision, *scale))
             }
-            ScalarValue::Decimal256(Some(v), precision, scale) => Ok(
-                ScalarValue::Decimal256(Some(v.neg_wrapping()), *precision, *scale),
-            ),
             value => _internal_err!(
                 "Can not run arithmetic negative on scalar value {value:?}"
             ),
@@ -1033,7 +1092,9 @@ impl ScalarValue {
             ScalarValue::FixedSizeBinary(_, v) => v.is_none(),
             ScalarValue::LargeBinary(v) => v.is_none(),
             ScalarValue::Fixedsizelist(v, ..) => v.is_none(),
-            ScalarValue::List(arr) => arr.len() == arr.null_count(),
+            ScalarValue::List(arr) | ScalarValue::Map(arr) => {
+                arr.len() == arr.null_count()
+            }
             ScalarValue::Date32(v) => v.is_none(),
             ScalarValue::Date64(v) => v.is_none(),
             ScalarValue::Time32Second(v) => v.is_none(),
@@ -1425,6 +1486,22 @@ impl ScalarValue {
                 let list_array = ScalarValue::iter_to_array_list(scalars)?;
                 Arc::new(list_array)
             }
+            DataType::Map(_, _) => {
+                // `ScalarValue::Map` contains a single element `MapArray`.
+                let arrays = scalars
+                    .map(|scalar| match scalar {
+                        ScalarValue::Map(arr) => Ok(arr),
+                        sv => _internal_err!(
+                            "Inconsistent types in ScalarValue::iter_to_array. \
+                                Expected {:?}, got {:?}",
+                            data_type,
+                            sv
+                        ),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let arrays = arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+                arrow::compute::concat(&arrays)?
+            }
             DataType::Struct(fields) => {
                 // Initialize a Vector to store the ScalarValues for each column
                 let mut columns: Vec<Vec<ScalarValue>> =
@@ -1539,7 +1616,6 @@ impl ScalarValue {
             | DataType::FixedSizeList(_, _)
             | DataType::LargeList(_)
             | DataType::Union(_, _)
-            | DataType::Map(_, _)
             | DataType::RunEndEncoded(_, _) => {
                 return _internal_err!(
                     "Unsupported creation of {:?} array from ScalarValue {:?}",
@@ -1858,7 +1934,7 @@ impl ScalarValue {
             ScalarValue::Fixedsizelist(..) => {
                 return _not_impl_err!("FixedSizeList is not supported yet")
             }
-            ScalarValue::List(arr) => {
+            ScalarValue::List(arr) | ScalarValue::Map(arr) => {
                 let arrays = std::iter::repeat(arr.as_ref())
                     .take(size)
                     .collect::<Vec<_>>();
@@ -2131,6 +2207,8 @@ impl ScalarValue {
 
                 ScalarValue::List(arr)
             }
+            // Produces a single element `MapArray` with the value at `index`.
+            DataType::Map(_, _) => ScalarValue::Map(array.slice(index, 1)),
             // TODO: There is no test for FixedSizeList now, add it later
             DataType::FixedSizeList(_, _) => {
                 let list_array = as_fixed_size_list_array(array)?;
@@ -2411,6 +2489,7 @@ impl ScalarValue {
                 return _not_impl_err!("FixedSizeList is not supported yet")
             }
             ScalarValue::List(_) => return _not_impl_err!("List is not supported yet"),
+            ScalarValue::Map(_) => return _not_impl_err!("Map is not supported yet"),
             ScalarValue::Date32(val) => {
                 eq_array_primitive!(array, index, Date32Array, val)?
             }
@@ -2540,7 +2619,9 @@ impl ScalarValue {
                         // `field` is boxed, so it is NOT already included in `self`
                         + field.size()
                 }
-                ScalarValue::List(arr) => arr.get_array_memory_size(),
+                ScalarValue::List(arr) | ScalarValue::Map(arr) => {
+                    arr.get_array_memory_size()
+                }
                 ScalarValue::Struct(vals, fields) => {
                     vals.as_ref()
                         .map(|vals| {
@@ -2844,6 +2925,8 @@ impl TryFrom<&DataType> for ScalarValue {
                 ))),
                 1,
             )),
+            // `ScalarValue::Map` contains single element `MapArray`.
+            DataType::Map(_, _) => ScalarValue::Map(new_null_array(data_type, 1)),
             DataType::Struct(fields) => ScalarValue::Struct(None, fields.clone()),
             DataType::Null => ScalarValue::Null,
             _ => {
@@ -2925,6 +3008,16 @@ impl fmt::Display for ScalarValue {
                 arrow::util::pretty::pretty_format_columns("col", &[arr.to_owned()])
                     .unwrap()
             )?,
+            ScalarValue::Map(arr) => {
+                if arr.is_null(0) {
+                    write!(f, "NULL")?
+                } else {
+                    let formatter =
+                        ArrayFormatter::try_new(arr, &FormatOptions::default())
+                            .map_err(|_| fmt::Error)?;
+                    write!(f, "{}", formatter.value(0))?
+                }
+            }
             ScalarValue::Date32(e) => format_option!(f, e)?,
             ScalarValue::Date64(e) => format_option!(f, e)?,
             ScalarValue::Time32Second(e) => format_option!(f, e)?,
@@ -3001,6 +3094,7 @@ impl fmt::Debug for ScalarValue {
             ScalarValue::LargeBinary(Some(_)) => write!(f, "LargeBinary(\"{self}\")"),
             ScalarValue::Fixedsizelist(..) => write!(f, "FixedSizeList([{self}])"),
             ScalarValue::List(arr) => write!(f, "List([{arr:?}])"),
+            ScalarValue::Map(_) => write!(f, "Map({self})"),
             ScalarValue::Date32(_) => write!(f, "Date32(\"{self}\")"),
             ScalarValue::Date64(_) => write!(f, "Date64(\"{self}\")"),
             ScalarValue::Time32Second(_) => write!(f, "Time32Second(\"{self}\")"),
@@ -4847,6 +4941,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decimal_zero_one_ten() -> Result<()> {
+        let dt = DataType::Decimal128(10, 2);
+        assert_eq!(
+            ScalarValue::new_zero(&dt)?,
+            ScalarValue::Decimal128(Some(0), 10, 2)
+        );
+        assert_eq!(
+            ScalarValue::new_one(&dt)?,
+            ScalarValue::Decimal128(Some(100), 10, 2)
+        );
+        assert_eq!(
+            ScalarValue::new_ten(&dt)?,
+            ScalarValue::Decimal128(Some(1000), 10, 2)
+        );
+
+        let dt = DataType::Decimal256(50, 4);
+        assert_eq!(
+            ScalarValue::new_one(&dt)?,
+            ScalarValue::Decimal256(Some(i256::from_i128(10_000)), 50, 4)
+        );
+        assert_eq!(
+            ScalarValue::new_ten(&dt)?,
+            ScalarValue::Decimal256(Some(i256::from_i128(100_000)), 50, 4)
+        );
+
+        // 10 does not fit into DECIMAL(2, 1)
+        assert!(ScalarValue::new_ten(&DataType::Decimal128(2, 1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn decimal_negate_overflow() {
+        let v = ScalarValue::Decimal256(Some(i256::from_i128(-5)), 50, 0);
+        assert_eq!(
+            v.arithmetic_negate().unwrap(),
+            ScalarValue::Decimal256(Some(i256::from_i128(5)), 50, 0)
+        );
+        let v = ScalarValue::Decimal256(Some(i256::MIN), 76, 0);
+        assert!(v.arithmetic_negate().is_err());
+    }
+
     #[test]
     fn test_scalar_distance() {
         let cases = [
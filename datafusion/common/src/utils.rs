@@ -23,7 +23,7 @@ use arrow::array::{ArrayRef, PrimitiveArray};
 use arrow::buffer::OffsetBuffer;
 use arrow::compute;
 use arrow::compute::{partition, SortColumn, SortOptions};
-use arrow::datatypes::{Field, SchemaRef, UInt32Type};
+use arrow::datatypes::{DataType, Field, Fields, SchemaRef, UInt32Type};
 use arrow::record_batch::RecordBatch;
 use arrow_array::{Array, ListArray};
 use sqlparser::ast::Ident;
@@ -349,6 +349,19 @@ pub fn array_into_list_array(arr: ArrayRef) -> ListArray {
     )
 }
 
+/// Returns the entries field of a `MapArray` with keys of `key_type` and
+/// values of `value_type`. Keys are never null.
+pub fn map_entries_field(key_type: DataType, value_type: DataType) -> Field {
+    Field::new(
+        "entries",
+        DataType::Struct(Fields::from(vec![
+            Field::new("key", key_type, false),
+            Field::new("value", value_type, true),
+        ])),
+        false,
+    )
+}
+
 /// Wrap arrays into a single element `ListArray`.
 ///
 /// Example:
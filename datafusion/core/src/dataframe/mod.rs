@@ -21,6 +21,7 @@
 mod parquet;
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow::array::{Array, ArrayRef, Int64Array, StringArray};
@@ -32,14 +33,17 @@ use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{
-    DataFusionError, FileType, FileTypeWriterOptions, SchemaError, UnnestOptions,
+    plan_datafusion_err, plan_err, DataFusionError, FileType, FileTypeWriterOptions,
+    SchemaError, UnnestOptions,
 };
 use datafusion_expr::dml::CopyOptions;
+use datafusion_expr::expr::Cast;
 
-use datafusion_common::{Column, DFSchema, ScalarValue};
+use datafusion_common::{Column, DFField, DFSchema, ScalarValue};
+use datafusion_expr::type_coercion::binary::comparison_coercion;
 use datafusion_expr::{
-    avg, count, is_null, max, median, min, stddev, utils::COUNT_STAR_EXPANSION,
-    TableProviderFilterPushDown, UNNAMED_TABLE,
+    avg, coalesce, count, is_null, lit, max, median, min, stddev,
+    utils::COUNT_STAR_EXPANSION, TableProviderFilterPushDown, UNNAMED_TABLE,
 };
 
 use crate::arrow::datatypes::Schema;
@@ -1105,36 +1109,7 @@ impl DataFrame {
     /// # }
     /// ```
     pub fn with_column(self, name: &str, expr: Expr) -> Result<DataFrame> {
-        let window_func_exprs = find_window_exprs(&[expr.clone()]);
-        let plan = if window_func_exprs.is_empty() {
-            self.plan
-        } else {
-            LogicalPlanBuilder::window_plan(self.plan, window_func_exprs)?
-        };
-
-        let new_column = expr.alias(name);
-        let mut col_exists = false;
-        let mut fields: Vec<Expr> = plan
-            .schema()
-            .fields()
-            .iter()
-            .map(|f| {
-                if f.name() == name {
-                    col_exists = true;
-                    new_column.clone()
-                } else {
-                    col(f.qualified_column())
-                }
-            })
-            .collect();
-
-        if !col_exists {
-            fields.push(new_column);
-        }
-
-        let project_plan = LogicalPlanBuilder::from(plan).project(fields)?.build()?;
-
-        Ok(DataFrame::new(self.session_state, project_plan))
+        self.with_columns(vec![(name, expr)])
     }
 
     /// Rename one column by applying a new projection. This is a no-op if the column to be
@@ -1156,17 +1131,7 @@ impl DataFrame {
         old_name: impl Into<String>,
         new_name: &str,
     ) -> Result<DataFrame> {
-        let ident_opts = self
-            .session_state
-            .config_options()
-            .sql_parser
-            .enable_ident_normalization;
-        let old_column: Column = if ident_opts {
-            Column::from_qualified_name(old_name)
-        } else {
-            Column::from_qualified_name_ignore_case(old_name)
-        };
-
+        let old_column = self.parse_column(old_name);
         let field_to_rename = match self.plan.schema().field_from_column(&old_column) {
             Ok(field) => field,
             // no-op if field not found
@@ -1194,6 +1159,337 @@ impl DataFrame {
         Ok(DataFrame::new(self.session_state, project_plan))
     }
 
+    /// Add or replace several columns at once, using a single projection.
+    ///
+    /// Columns whose name matches an existing column replace it in place,
+    /// the others are appended in the order given.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.with_columns(vec![
+    ///     ("ab_sum", col("a") + col("b")),
+    ///     ("c", col("c") * lit(2)),
+    /// ])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_columns(self, columns: Vec<(&str, Expr)>) -> Result<DataFrame> {
+        let mut names = HashSet::with_capacity(columns.len());
+        if let Some((name, _)) = columns.iter().find(|(name, _)| !names.insert(*name)) {
+            return plan_err!("Column '{name}' is assigned more than once");
+        }
+
+        let exprs: Vec<Expr> = columns.iter().map(|(_, expr)| expr.clone()).collect();
+        let window_func_exprs = find_window_exprs(&exprs);
+        let plan = if window_func_exprs.is_empty() {
+            self.plan
+        } else {
+            LogicalPlanBuilder::window_plan(self.plan, window_func_exprs)?
+        };
+
+        let new_columns: Vec<(&str, Expr)> = columns
+            .into_iter()
+            .map(|(name, expr)| (name, expr.alias(name)))
+            .collect();
+        let mut replaced = vec![false; new_columns.len()];
+        let mut fields: Vec<Expr> = plan
+            .schema()
+            .fields()
+            .iter()
+            .map(
+                |f| match new_columns.iter().position(|(name, _)| f.name() == name) {
+                    Some(i) => {
+                        replaced[i] = true;
+                        new_columns[i].1.clone()
+                    }
+                    None => col(f.qualified_column()),
+                },
+            )
+            .collect();
+        fields.extend(
+            new_columns
+                .into_iter()
+                .zip(replaced)
+                .filter(|(_, replaced)| !replaced)
+                .map(|((_, expr), _)| expr),
+        );
+
+        let project_plan = LogicalPlanBuilder::from(plan).project(fields)?.build()?;
+
+        Ok(DataFrame::new(self.session_state, project_plan))
+    }
+
+    /// Remove the named columns from the DataFrame.
+    ///
+    /// Column names may be qualified, and every column must exist.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.drop_columns(&["a"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn drop_columns(self, columns: &[&str]) -> Result<DataFrame> {
+        let to_drop = self.fields_from_names(columns)?;
+        let projection: Vec<Expr> = self
+            .plan
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !to_drop.contains(f))
+            .map(|f| col(f.qualified_column()))
+            .collect();
+        if projection.is_empty() {
+            return plan_err!("Can not drop every column of a DataFrame");
+        }
+        self.select(projection)
+    }
+
+    /// Rename several columns at once, given `(old_name, new_name)` pairs.
+    ///
+    /// Unlike [`Self::with_column_renamed`], it is an error if a column to
+    /// be renamed does not exist.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.rename(&[("a", "x"), ("b", "y")])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename(self, mapping: &[(&str, &str)]) -> Result<DataFrame> {
+        let old_names: Vec<&str> = mapping.iter().map(|(old, _)| *old).collect();
+        let to_rename = self.fields_from_names(&old_names)?;
+        for (i, field) in to_rename.iter().enumerate() {
+            if to_rename[..i].contains(field) {
+                return plan_err!(
+                    "Column {} is renamed more than once",
+                    field.qualified_name()
+                );
+            }
+        }
+        let projection: Vec<Expr> = self
+            .plan
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| match to_rename.iter().position(|field| *field == f) {
+                Some(i) => col(f.qualified_column()).alias(mapping[i].1),
+                None => col(f.qualified_column()),
+            })
+            .collect();
+        self.select(projection)
+    }
+
+    /// Replace the nulls of the named columns with `value`, or of every
+    /// column `value` can be cast to without loss if `columns` is empty.
+    ///
+    /// It is an error if `value` can not be cast to the type of a named column
+    /// without loss, for example a string to a number or `1.5` to an integer.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion_common::ScalarValue;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// // replace nulls in column `a` with 0
+    /// let df = df.fill_null(ScalarValue::from(0i64), &["a"])?;
+    /// // replace the nulls of every numeric column with 1
+    /// let df = df.fill_null(ScalarValue::from(1i64), &[])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill_null(self, value: ScalarValue, columns: &[&str]) -> Result<DataFrame> {
+        if value.is_null() {
+            return plan_err!("Can not fill nulls with a null value");
+        }
+        let schema = self.plan.schema();
+        let fills: Vec<(&DFField, ScalarValue)> = if columns.is_empty() {
+            schema
+                .fields()
+                .iter()
+                .filter_map(|f| Some((f, cast_scalar(&value, f.data_type())?)))
+                .collect()
+        } else {
+            self.fields_from_names(columns)?
+                .into_iter()
+                .map(|f| match cast_scalar(&value, f.data_type()) {
+                    Some(fill) => Ok((f, fill)),
+                    None => plan_err!(
+                        "Can not fill nulls of column {} of type {} with {value} of type {}",
+                        f.qualified_name(),
+                        f.data_type(),
+                        value.data_type()
+                    ),
+                })
+                .collect::<Result<_>>()?
+        };
+        let projection: Vec<Expr> = schema
+            .fields()
+            .iter()
+            .map(|f| match fills.iter().find(|(field, _)| *field == f) {
+                Some((_, fill)) if f.is_nullable() => {
+                    coalesce(vec![col(f.qualified_column()), lit(fill.clone())])
+                        .alias(f.name())
+                }
+                _ => col(f.qualified_column()),
+            })
+            .collect();
+        self.select(projection)
+    }
+
+    /// Remove the rows that have a null in any of the named columns, or
+    /// in any column if `subset` is empty.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.drop_nulls(&["a", "b"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn drop_nulls(self, subset: &[&str]) -> Result<DataFrame> {
+        let fields = if subset.is_empty() {
+            self.plan.schema().fields().iter().collect()
+        } else {
+            self.fields_from_names(subset)?
+        };
+        let predicate = fields
+            .into_iter()
+            .filter(|f| f.is_nullable())
+            .map(|f| col(f.qualified_column()).is_not_null())
+            .reduce(Expr::and);
+        match predicate {
+            Some(predicate) => self.filter(predicate),
+            None => Ok(self),
+        }
+    }
+
+    /// Join this `DataFrame` with every row of another `DataFrame`,
+    /// producing the cartesian product of both.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let left = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let right = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?
+    ///   .select(vec![col("a").alias("a2")])?;
+    /// let df = left.cross_join(right)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cross_join(self, right: DataFrame) -> Result<DataFrame> {
+        let plan = LogicalPlanBuilder::from(self.plan)
+            .cross_join(right.plan)?
+            .build()?;
+        Ok(DataFrame::new(self.session_state, plan))
+    }
+
+    /// Unpivot the DataFrame from wide to long format.
+    ///
+    /// Each row produces one row per value column, made of the
+    /// `id_columns`, a `variable_name` column holding the name of the value
+    /// column and a `value_name` column holding its value. If
+    /// `value_columns` is empty, every column that is not an id column is
+    /// used. The value columns must have compatible types.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// // columns `a`, `variable` and `value`, with two rows for each input row
+    /// let df = df.melt(&["a"], &["b", "c"], "variable", "value")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn melt(
+        self,
+        id_columns: &[&str],
+        value_columns: &[&str],
+        variable_name: &str,
+        value_name: &str,
+    ) -> Result<DataFrame> {
+        let id_fields = self.fields_from_names(id_columns)?;
+        let value_fields = if value_columns.is_empty() {
+            self.plan
+                .schema()
+                .fields()
+                .iter()
+                .filter(|f| !id_fields.contains(f))
+                .collect()
+        } else {
+            self.fields_from_names(value_columns)?
+        };
+        let Some((first, rest)) = value_fields.split_first() else {
+            return plan_err!("melt requires at least one value column");
+        };
+        let value_type =
+            rest.iter()
+                .try_fold(first.data_type().clone(), |value_type, field| {
+                    comparison_coercion(&value_type, field.data_type()).ok_or_else(|| {
+                        plan_datafusion_err!(
+                            "melt value column {} of type {} is not compatible with type {value_type}",
+                            field.qualified_name(),
+                            field.data_type()
+                        )
+                    })
+                })?;
+
+        let ids: Vec<Expr> = id_fields
+            .iter()
+            .map(|f| col(f.qualified_column()))
+            .collect();
+        let mut builder: Option<LogicalPlanBuilder> = None;
+        for field in &value_fields {
+            let mut exprs = ids.clone();
+            exprs.push(lit(field.name().as_str()).alias(variable_name));
+            exprs.push(
+                Expr::Cast(Cast::new(
+                    Box::new(col(field.qualified_column())),
+                    value_type.clone(),
+                ))
+                .alias(value_name),
+            );
+            let plan = LogicalPlanBuilder::from(self.plan.clone())
+                .project(exprs)?
+                .build()?;
+            builder = Some(match builder {
+                Some(builder) => builder.union(plan)?,
+                None => LogicalPlanBuilder::from(plan),
+            });
+        }
+        // at least one value column was checked above
+        let plan = builder.unwrap().build()?;
+        Ok(DataFrame::new(self.session_state, plan))
+    }
+
     /// Replace all parameters in logical plan with the specified
     /// values, in preparation for execution.
     ///
@@ -1257,6 +1553,55 @@ impl DataFrame {
 
         context.read_table(Arc::new(mem_table))
     }
+
+    /// Parse a possibly qualified column name, normalizing it the same way
+    /// as SQL identifiers
+    fn parse_column(&self, name: impl Into<String>) -> Column {
+        let ident_opts = self
+            .session_state
+            .config_options()
+            .sql_parser
+            .enable_ident_normalization;
+        if ident_opts {
+            Column::from_qualified_name(name)
+        } else {
+            Column::from_qualified_name_ignore_case(name)
+        }
+    }
+
+    /// Returns the fields of the named columns, in order
+    fn fields_from_names(&self, names: &[&str]) -> Result<Vec<&DFField>> {
+        names
+            .iter()
+            .map(|name| {
+                self.plan
+                    .schema()
+                    .field_from_column(&self.parse_column(*name))
+            })
+            .collect()
+    }
+}
+
+/// Casts `value` to `data_type` without loss, returning `None` if the value
+/// can not be represented exactly by that type or the types are of different
+/// kinds, such as a number and a string
+fn cast_scalar(value: &ScalarValue, data_type: &DataType) -> Option<ScalarValue> {
+    let value_type = value.data_type();
+    let is_string = |t: &DataType| matches!(t, DataType::Utf8 | DataType::LargeUtf8);
+    let same_kind = value_type == *data_type
+        || (value_type.is_numeric() && data_type.is_numeric())
+        || (is_string(&value_type) && is_string(data_type));
+    if !same_kind {
+        return None;
+    }
+    let array = cast(&value.to_array().ok()?, data_type).ok()?;
+    // casting back must give the original value, so `1.5` is not cast to 1
+    let roundtrip =
+        ScalarValue::try_from_array(&cast(&array, &value_type).ok()?, 0).ok()?;
+    if roundtrip != *value {
+        return None;
+    }
+    ScalarValue::try_from_array(&array, 0).ok()
 }
 
 struct DataFrameTableProvider {
@@ -2530,4 +2875,273 @@ mod tests {
 
         Ok(())
     }
+
+    async fn table_with_nulls() -> Result<DataFrame> {
+        let ctx = SessionContext::new();
+        ctx.sql(
+            "CREATE TABLE t(a INT, b VARCHAR, c DOUBLE) AS VALUES \
+            (1, 'x', NULL), (NULL, NULL, 2.5), (3, NULL, NULL)",
+        )
+        .await?
+        .collect()
+        .await?;
+        ctx.table("t").await
+    }
+
+    fn field_names(df: &DataFrame) -> Vec<&str> {
+        df.schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn with_columns() -> Result<()> {
+        let df = test_table()
+            .await?
+            .select_columns(&["c1", "c2", "c3"])?
+            .filter(col("c2").eq(lit(3)).and(col("c1").eq(lit("a"))))?;
+
+        // expressions refer to the columns before any is replaced
+        let df_results = df
+            .clone()
+            .with_columns(vec![
+                ("c2", col("c2") + lit(1)),
+                ("sum", col("c2") + col("c3")),
+            ])?
+            .collect()
+            .await?;
+
+        assert_batches_sorted_eq!(
+            [
+                "+----+----+-----+-----+",
+                "| c1 | c2 | c3  | sum |",
+                "+----+----+-----+-----+",
+                "| a  | 4  | -12 | -9  |",
+                "| a  | 4  | -72 | -69 |",
+                "| a  | 4  | 13  | 16  |",
+                "| a  | 4  | 13  | 16  |",
+                "| a  | 4  | 14  | 17  |",
+                "| a  | 4  | 17  | 20  |",
+                "+----+----+-----+-----+"
+            ],
+            &df_results
+        );
+
+        let err = df
+            .with_columns(vec![("sum", col("c2")), ("sum", col("c3"))])
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Column 'sum' is assigned more than once"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drop_columns() -> Result<()> {
+        let df = test_table().await?.select_columns(&["c1", "c2", "c3"])?;
+
+        let dropped = df.clone().drop_columns(&["c2", "aggregate_test_100.c3"])?;
+        assert_eq!(field_names(&dropped), ["c1"]);
+
+        let err = df.clone().drop_columns(&["c4"]).unwrap_err();
+        assert!(matches!(
+            err,
+            DataFusionError::SchemaError(SchemaError::FieldNotFound { .. })
+        ));
+
+        let err = df.drop_columns(&["c1", "c2", "c3"]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Can not drop every column of a DataFrame"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rename() -> Result<()> {
+        let df = test_table().await?.select_columns(&["c1", "c2", "c3"])?;
+
+        let renamed = df
+            .clone()
+            .rename(&[("c1", "one"), ("aggregate_test_100.c2", "two")])?;
+        assert_eq!(field_names(&renamed), ["one", "two", "c3"]);
+
+        let err = df.clone().rename(&[("c4", "four")]).unwrap_err();
+        assert!(matches!(
+            err,
+            DataFusionError::SchemaError(SchemaError::FieldNotFound { .. })
+        ));
+
+        let err = df.rename(&[("c1", "x"), ("c1", "y")]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Column aggregate_test_100.c1 is renamed more than once"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fill_null() -> Result<()> {
+        let df = table_with_nulls().await?;
+
+        let results = df
+            .clone()
+            .fill_null(ScalarValue::from(0i32), &["a", "c"])?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(
+            [
+                "+---+---+-----+",
+                "| a | b | c   |",
+                "+---+---+-----+",
+                "| 0 |   | 2.5 |",
+                "| 1 | x | 0.0 |",
+                "| 3 |   | 0.0 |",
+                "+---+---+-----+",
+            ],
+            &results
+        );
+
+        // only fills the columns the value can be cast to
+        let results = df
+            .clone()
+            .fill_null(ScalarValue::from("none"), &[])?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(
+            [
+                "+---+------+-----+",
+                "| a | b    | c   |",
+                "+---+------+-----+",
+                "|   | none | 2.5 |",
+                "| 1 | x    |     |",
+                "| 3 | none |     |",
+                "+---+------+-----+",
+            ],
+            &results
+        );
+
+        // numbers are not cast to strings
+        let results = df
+            .clone()
+            .fill_null(ScalarValue::from(0i64), &[])?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(
+            [
+                "+---+---+-----+",
+                "| a | b | c   |",
+                "+---+---+-----+",
+                "| 0 |   | 2.5 |",
+                "| 1 | x | 0.0 |",
+                "| 3 |   | 0.0 |",
+                "+---+---+-----+",
+            ],
+            &results
+        );
+
+        // only fills the columns the value can be cast to without loss
+        let results = df
+            .clone()
+            .fill_null(ScalarValue::from(1.5f64), &[])?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(
+            [
+                "+---+---+-----+",
+                "| a | b | c   |",
+                "+---+---+-----+",
+                "|   |   | 2.5 |",
+                "| 1 | x | 1.5 |",
+                "| 3 |   | 1.5 |",
+                "+---+---+-----+",
+            ],
+            &results
+        );
+
+        let err = df
+            .clone()
+            .fill_null(ScalarValue::from("none"), &["a"])
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Can not fill nulls of column t.a of type Int32 with none of type Utf8"
+        );
+
+        let err = df.fill_null(ScalarValue::from(1.5f64), &["a"]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Can not fill nulls of column t.a of type Int32 with 1.5 of type Float64"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drop_nulls() -> Result<()> {
+        let df = table_with_nulls().await?;
+
+        assert_eq!(df.clone().drop_nulls(&["a"])?.count().await?, 2);
+        assert_eq!(df.clone().drop_nulls(&["a", "b"])?.count().await?, 1);
+        assert_eq!(df.drop_nulls(&[])?.count().await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cross_join() -> Result<()> {
+        let left = table_with_nulls().await?;
+        let right = table_with_nulls()
+            .await?
+            .select(vec![col("a").alias("a2")])?;
+
+        let df = left.cross_join(right)?;
+        assert_eq!(field_names(&df), ["a", "b", "c", "a2"]);
+        assert_eq!(df.count().await?, 9);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn melt() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE w(id INT, x INT, y BIGINT) AS VALUES (1, 10, 100), (2, 20, 200)")
+            .await?
+            .collect()
+            .await?;
+        let df = ctx.table("w").await?;
+
+        let melted = df.clone().melt(&["id"], &[], "variable", "value")?;
+        assert_eq!(melted.schema().field(2).data_type(), &DataType::Int64);
+        let results = melted.collect().await?;
+        assert_batches_sorted_eq!(
+            [
+                "+----+----------+-------+",
+                "| id | variable | value |",
+                "+----+----------+-------+",
+                "| 1  | x        | 10    |",
+                "| 1  | y        | 100   |",
+                "| 2  | x        | 20    |",
+                "| 2  | y        | 200   |",
+                "+----+----------+-------+",
+            ],
+            &results
+        );
+
+        let err = df
+            .melt(&["id", "x", "y"], &[], "variable", "value")
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: melt requires at least one value column"
+        );
+
+        Ok(())
+    }
 }
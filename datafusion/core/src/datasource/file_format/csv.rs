@@ -29,7 +29,7 @@ use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{PhysicalExpr, PhysicalSortRequirement};
 
 use bytes::{Buf, Bytes};
-use datafusion_physical_plan::metrics::MetricsSet;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use futures::stream::BoxStream;
 use futures::{pin_mut, Stream, StreamExt, TryStreamExt};
 use object_store::{delimited::newline_delimited_stream, ObjectMeta, ObjectStore};
@@ -450,9 +450,11 @@ impl BatchSerializer for CsvSerializer {
 }
 
 /// Implements [`DataSink`] for writing to a CSV file.
-struct CsvSink {
+pub struct CsvSink {
     /// Config options for writing data
     config: FileSinkConfig,
+    /// Metrics of the writes, such as the rows written to each partition
+    metrics: ExecutionPlanMetricsSet,
 }
 
 impl Debug for CsvSink {
@@ -478,8 +480,17 @@ impl DisplayAs for CsvSink {
 }
 
 impl CsvSink {
-    fn new(config: FileSinkConfig) -> Self {
-        Self { config }
+    /// Create from config.
+    pub fn new(config: FileSinkConfig) -> Self {
+        Self {
+            config,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Retrieve the inner [`FileSinkConfig`].
+    pub fn config(&self) -> &FileSinkConfig {
+        &self.config
     }
 
     async fn append_all(
@@ -557,6 +568,7 @@ impl CsvSink {
             Box::new(get_serializer),
             &self.config,
             writer_options.compression.into(),
+            &self.metrics,
         )
         .await
     }
@@ -569,7 +581,7 @@ impl DataSink for CsvSink {
     }
 
     fn metrics(&self) -> Option<MetricsSet> {
-        None
+        Some(self.metrics.clone_inner())
     }
 
     async fn write_all(
@@ -108,6 +108,11 @@ impl FileCompressionType {
         self.variant.is_compressed()
     }
 
+    /// The compression variant
+    pub const fn get_variant(&self) -> &CompressionTypeVariant {
+        &self.variant
+    }
+
     /// Given a `Stream`, create a `Stream` which data are compressed with `FileCompressionType`.
     pub fn convert_to_compress_stream(
         &self,
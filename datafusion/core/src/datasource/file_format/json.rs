@@ -56,7 +56,7 @@ use crate::execution::context::SessionState;
 
 use datafusion_common::{not_impl_err, DataFusionError, FileType};
 use datafusion_execution::TaskContext;
-use datafusion_physical_plan::metrics::MetricsSet;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 
 /// New line delimited JSON `FileFormat` implementation.
 #[derive(Debug)]
@@ -233,6 +233,8 @@ impl BatchSerializer for JsonSerializer {
 pub struct JsonSink {
     /// Config options for writing data
     config: FileSinkConfig,
+    /// Metrics of the writes, such as the rows written to each partition
+    metrics: ExecutionPlanMetricsSet,
 }
 
 impl Debug for JsonSink {
@@ -260,7 +262,10 @@ impl DisplayAs for JsonSink {
 impl JsonSink {
     /// Create from config.
     pub fn new(config: FileSinkConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
     }
 
     /// Retrieve the inner [`FileSinkConfig`].
@@ -322,6 +327,7 @@ impl JsonSink {
             Box::new(get_serializer),
             &self.config,
             (*compression).into(),
+            &self.metrics,
         )
         .await
     }
@@ -334,7 +340,7 @@ impl DataSink for JsonSink {
     }
 
     fn metrics(&self) -> Option<MetricsSet> {
-        None
+        Some(self.metrics.clone_inner())
     }
 
     async fn write_all(
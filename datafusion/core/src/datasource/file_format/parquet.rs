@@ -20,7 +20,7 @@
 use arrow_array::RecordBatch;
 use async_trait::async_trait;
 use datafusion_common::stats::Precision;
-use datafusion_physical_plan::metrics::MetricsSet;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use parquet::arrow::arrow_writer::{
     compute_leaves, get_column_writers, ArrowColumnChunk, ArrowColumnWriter,
     ArrowLeafColumn,
@@ -63,6 +63,9 @@ use crate::arrow::array::{
 use crate::arrow::datatypes::DataType;
 use crate::config::ConfigOptions;
 
+use crate::datasource::physical_plan::schema_evolution::{
+    merge_evolved_schemas, FIELD_ID_META_KEY,
+};
 use crate::datasource::physical_plan::{
     FileGroupDisplay, FileMeta, FileSinkConfig, ParquetExec, SchemaAdapter,
 };
@@ -90,6 +93,10 @@ pub struct ParquetFormat {
     metadata_size_hint: Option<usize>,
     /// Override the global setting for `skip_metadata`
     skip_metadata: Option<bool>,
+    /// Override the global setting for `schema_evolution`
+    schema_evolution: Option<bool>,
+    /// Override the global setting for `skip_incompatible_files`
+    skip_incompatible_files: Option<bool>,
 }
 
 impl ParquetFormat {
@@ -144,19 +151,60 @@ impl ParquetFormat {
         self.skip_metadata
             .unwrap_or(config_options.execution.parquet.skip_metadata)
     }
+
+    /// Reconcile the schemas of files written as the table evolved: match
+    /// columns by Parquet field id when present and only widen their types.
+    /// Files that cannot be reconciled fail the query, see
+    /// [`Self::with_skip_incompatible_files`].
+    ///
+    /// - If `None`, defaults to value on `config_options`
+    pub fn with_schema_evolution(mut self, schema_evolution: Option<bool>) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
+    /// Returns `true` if file schemas are reconciled as described in
+    /// [`Self::with_schema_evolution`]
+    pub fn schema_evolution(&self, config_options: &ConfigOptions) -> bool {
+        self.schema_evolution
+            .unwrap_or(config_options.execution.parquet.schema_evolution)
+    }
+
+    /// With schema evolution enabled, skip the files whose schema cannot be
+    /// reconciled with the table schema instead of failing the query
+    ///
+    /// - If `None`, defaults to value on `config_options`
+    pub fn with_skip_incompatible_files(mut self, skip: Option<bool>) -> Self {
+        self.skip_incompatible_files = skip;
+        self
+    }
+
+    /// Returns `true` if incompatible files are skipped as described in
+    /// [`Self::with_skip_incompatible_files`]
+    pub fn skip_incompatible_files(&self, config_options: &ConfigOptions) -> bool {
+        self.skip_incompatible_files
+            .unwrap_or(config_options.execution.parquet.skip_incompatible_files)
+    }
 }
 
 /// Clears all metadata (Schema level and field level) on an iterator
-/// of Schemas
+/// of Schemas, except for Parquet field ids if `keep_field_ids` is set
 fn clear_metadata(
     schemas: impl IntoIterator<Item = Schema>,
+    keep_field_ids: bool,
 ) -> impl Iterator<Item = Schema> {
-    schemas.into_iter().map(|schema| {
+    schemas.into_iter().map(move |schema| {
         let fields = schema
             .fields()
             .iter()
             .map(|field| {
-                field.as_ref().clone().with_metadata(Default::default()) // clear meta
+                let metadata = field
+                    .metadata()
+                    .iter()
+                    .filter(|(key, _)| keep_field_ids && *key == FIELD_ID_META_KEY)
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                field.as_ref().clone().with_metadata(metadata)
             })
             .collect::<Fields>();
         Schema::new(fields)
@@ -175,6 +223,19 @@ impl FileFormat for ParquetFormat {
         store: &Arc<dyn ObjectStore>,
         objects: &[ObjectMeta],
     ) -> Result<SchemaRef> {
+        let schema_evolution = self.schema_evolution(state.config_options());
+
+        // Evolved schemas must be merged in the order the files were written
+        // for renamed columns to take their latest name
+        let mut objects: Vec<_> = objects.iter().collect();
+        if schema_evolution {
+            objects.sort_by(|a, b| {
+                a.last_modified
+                    .cmp(&b.last_modified)
+                    .then_with(|| a.location.cmp(&b.location))
+            });
+        }
+
         let schemas: Vec<_> = futures::stream::iter(objects)
             .map(|object| fetch_schema(store.as_ref(), object, self.metadata_size_hint))
             .boxed() // Workaround https://github.com/rust-lang/rust/issues/64552
@@ -182,18 +243,26 @@ impl FileFormat for ParquetFormat {
             .try_collect()
             .await?;
 
-        let schema = if self.skip_metadata(state.config_options()) {
-            Schema::try_merge(clear_metadata(schemas))
+        let schemas: Vec<_> = if self.skip_metadata(state.config_options()) {
+            clear_metadata(schemas, schema_evolution).collect()
         } else {
-            Schema::try_merge(schemas)
-        }?;
+            schemas
+        };
+        let schema = if schema_evolution {
+            merge_evolved_schemas(
+                schemas,
+                self.skip_incompatible_files(state.config_options()),
+            )?
+        } else {
+            Schema::try_merge(schemas)?
+        };
 
         Ok(Arc::new(schema))
     }
 
     async fn infer_stats(
         &self,
-        _state: &SessionState,
+        state: &SessionState,
         store: &Arc<dyn ObjectStore>,
         table_schema: SchemaRef,
         object: &ObjectMeta,
@@ -203,6 +272,8 @@ impl FileFormat for ParquetFormat {
             table_schema,
             object,
             self.metadata_size_hint,
+            self.schema_evolution(state.config_options()),
+            self.skip_incompatible_files(state.config_options()),
         )
         .await?;
         Ok(stats)
@@ -222,11 +293,17 @@ impl FileFormat for ParquetFormat {
             .then(|| filters.cloned())
             .flatten();
 
-        Ok(Arc::new(ParquetExec::new(
-            conf,
-            predicate,
-            self.metadata_size_hint(state.config_options()),
-        )))
+        Ok(Arc::new(
+            ParquetExec::new(
+                conf,
+                predicate,
+                self.metadata_size_hint(state.config_options()),
+            )
+            .with_schema_evolution(self.schema_evolution(state.config_options()))
+            .with_skip_incompatible_files(
+                self.skip_incompatible_files(state.config_options()),
+            ),
+        ))
     }
 
     async fn create_writer_physical_plan(
@@ -505,6 +582,8 @@ async fn fetch_statistics(
     table_schema: SchemaRef,
     file: &ObjectMeta,
     metadata_size_hint: Option<usize>,
+    schema_evolution: bool,
+    skip_incompatible_files: bool,
 ) -> Result<Statistics> {
     let metadata = fetch_parquet_metadata(store, file, metadata_size_hint).await?;
     let file_metadata = metadata.file_metadata();
@@ -522,7 +601,22 @@ async fn fetch_statistics(
     let mut null_counts = vec![Precision::Exact(0); num_fields];
     let mut has_statistics = false;
 
-    let schema_adapter = SchemaAdapter::new(table_schema.clone());
+    let schema_adapter =
+        SchemaAdapter::new(table_schema.clone()).with_schema_evolution(schema_evolution);
+
+    if schema_evolution {
+        if let Err(e) = schema_adapter.map_schema(&file_schema) {
+            if !skip_incompatible_files {
+                return Err(e);
+            }
+            // The file is skipped when scanned, so it contributes no rows
+            return Ok(Statistics {
+                num_rows: Precision::Exact(0),
+                total_byte_size: Precision::Exact(0),
+                column_statistics: Statistics::unknown_column(&table_schema),
+            });
+        }
+    }
 
     let (mut max_values, mut min_values) = create_max_min_accs(&table_schema);
 
@@ -581,9 +675,11 @@ async fn fetch_statistics(
 }
 
 /// Implements [`DataSink`] for writing to a parquet file.
-struct ParquetSink {
+pub struct ParquetSink {
     /// Config options for writing data
     config: FileSinkConfig,
+    /// Metrics of the writes, such as the rows written to each partition
+    metrics: ExecutionPlanMetricsSet,
 }
 
 impl Debug for ParquetSink {
@@ -609,8 +705,17 @@ impl DisplayAs for ParquetSink {
 }
 
 impl ParquetSink {
-    fn new(config: FileSinkConfig) -> Self {
-        Self { config }
+    /// Create from config.
+    pub fn new(config: FileSinkConfig) -> Self {
+        Self {
+            config,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Retrieve the inner [`FileSinkConfig`].
+    pub fn config(&self) -> &FileSinkConfig {
+        &self.config
     }
 
     /// Converts table schema to writer schema, which may differ in the case
@@ -682,7 +787,7 @@ impl DataSink for ParquetSink {
     }
 
     fn metrics(&self) -> Option<MetricsSet> {
-        None
+        Some(self.metrics.clone_inner())
     }
 
     async fn write_all(
@@ -722,6 +827,7 @@ impl DataSink for ParquetSink {
             self.config.table_paths[0].clone(),
             "parquet".into(),
             self.config.single_file_output,
+            &self.metrics,
         );
 
         let mut file_write_tasks: JoinSet<std::result::Result<usize, DataFusionError>> =
@@ -1215,9 +1321,11 @@ mod tests {
     use crate::physical_plan::metrics::MetricValue;
     use crate::prelude::{SessionConfig, SessionContext};
     use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow::datatypes::Field;
     use arrow::record_batch::RecordBatch;
     use async_trait::async_trait;
     use bytes::Bytes;
+    use chrono::Duration;
     use datafusion_common::cast::{
         as_binary_array, as_boolean_array, as_float32_array, as_float64_array,
         as_int32_array, as_timestamp_nanosecond_array,
@@ -1233,6 +1341,7 @@ mod tests {
     use parquet::arrow::ParquetRecordBatchStreamBuilder;
     use parquet::file::metadata::{ParquetColumnIndex, ParquetOffsetIndex};
     use parquet::file::page_index::index::Index;
+    use tempfile::NamedTempFile;
     use tokio::fs::File;
     use tokio::io::AsyncWrite;
 
@@ -1254,8 +1363,15 @@ mod tests {
         let format = ParquetFormat::default();
         let schema = format.infer_schema(&ctx, &store, &meta).await.unwrap();
 
-        let stats =
-            fetch_statistics(store.as_ref(), schema.clone(), &meta[0], None).await?;
+        let stats = fetch_statistics(
+            store.as_ref(),
+            schema.clone(),
+            &meta[0],
+            None,
+            false,
+            false,
+        )
+        .await?;
 
         assert_eq!(stats.num_rows, Precision::Exact(3));
         let c1_stats = &stats.column_statistics[0];
@@ -1263,7 +1379,9 @@ mod tests {
         assert_eq!(c1_stats.null_count, Precision::Exact(1));
         assert_eq!(c2_stats.null_count, Precision::Exact(3));
 
-        let stats = fetch_statistics(store.as_ref(), schema, &meta[1], None).await?;
+        let stats =
+            fetch_statistics(store.as_ref(), schema, &meta[1], None, false, false)
+                .await?;
         assert_eq!(stats.num_rows, Precision::Exact(3));
         let c1_stats = &stats.column_statistics[0];
         let c2_stats = &stats.column_statistics[1];
@@ -1377,6 +1495,97 @@ mod tests {
         }
     }
 
+    /// Writes one single column batch per file, with the column keeping
+    /// Parquet field id 1 across files, and returns the files in the order
+    /// they were written
+    async fn store_evolved_parquet(
+        columns: Vec<(&str, ArrayRef)>,
+    ) -> Result<(Vec<ObjectMeta>, Vec<NamedTempFile>)> {
+        let batches = columns
+            .into_iter()
+            .map(|(name, values)| {
+                let metadata = [(FIELD_ID_META_KEY.to_string(), "1".to_string())];
+                let field = Field::new(name, values.data_type().clone(), true)
+                    .with_metadata(std::collections::HashMap::from(metadata));
+                RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![values])
+                    .unwrap()
+            })
+            .collect();
+        let (mut meta, files) = store_parquet(batches, false).await?;
+        // Files written in quick succession may share a modification time
+        for i in 1..meta.len() {
+            meta[i].last_modified = meta[i - 1].last_modified + Duration::seconds(1);
+        }
+        Ok((meta, files))
+    }
+
+    #[tokio::test]
+    async fn infer_evolved_schema_in_write_order() -> Result<()> {
+        // the column was widened and renamed from old to new
+        let (mut meta, _files) = store_evolved_parquet(vec![
+            ("old", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+            ("new", Arc::new(Int64Array::from(vec![2])) as ArrayRef),
+        ])
+        .await?;
+        // the store lists the files in another order than they were written
+        meta.reverse();
+
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let session = SessionContext::new();
+        let state = session.state();
+        let format = ParquetFormat::default().with_schema_evolution(Some(true));
+        let schema = format.infer_schema(&state, &store, &meta).await?;
+
+        assert_eq!(schema.fields().len(), 1);
+        assert_eq!(schema.field(0).name(), "new");
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn infer_incompatible_evolved_schema() -> Result<()> {
+        let (meta, _files) = store_evolved_parquet(vec![
+            ("c1", Arc::new(StringArray::from(vec!["Foo"])) as ArrayRef),
+            ("c1", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+        ])
+        .await?;
+
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let session = SessionContext::new();
+        let state = session.state();
+        let format = ParquetFormat::default().with_schema_evolution(Some(true));
+        let err = format
+            .infer_schema(&state, &store, &meta)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cannot reconcile type Utf8 of column c1 with type Int64"),
+            "{err}"
+        );
+
+        let format = format.with_skip_incompatible_files(Some(true));
+        let schema = format.infer_schema(&state, &store, &meta).await?;
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+
+        // the incompatible file is skipped when scanned, so it has no rows
+        let stats = format
+            .infer_stats(&state, &store, schema.clone(), &meta[1])
+            .await?;
+        assert_eq!(stats.num_rows, Precision::Exact(0));
+
+        let format = format.with_skip_incompatible_files(Some(false));
+        let err = format
+            .infer_stats(&state, &store, schema, &meta[1])
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Cannot evolve file schema"),
+            "{err}"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn fetch_metadata_with_size_hint() -> Result<()> {
         let c1: ArrayRef =
@@ -1408,9 +1617,15 @@ mod tests {
             .await
             .unwrap();
 
-        let stats =
-            fetch_statistics(store.upcast().as_ref(), schema.clone(), &meta[0], Some(9))
-                .await?;
+        let stats = fetch_statistics(
+            store.upcast().as_ref(),
+            schema.clone(),
+            &meta[0],
+            Some(9),
+            false,
+            false,
+        )
+        .await?;
 
         assert_eq!(stats.num_rows, Precision::Exact(3));
         let c1_stats = &stats.column_statistics[0];
@@ -1442,6 +1657,8 @@ mod tests {
             schema.clone(),
             &meta[0],
             Some(size_hint),
+            false,
+            false,
         )
         .await?;
 
@@ -22,17 +22,19 @@ use std::collections::HashMap;
 
 use std::sync::Arc;
 
-use crate::datasource::listing::ListingTableUrl;
+use crate::datasource::listing::{ListingTableUrl, HIVE_DEFAULT_PARTITION};
 
 use crate::error::Result;
+use crate::physical_plan::metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder};
 use crate::physical_plan::SendableRecordBatchStream;
 
+use arrow::compute::{can_cast_types, cast};
 use arrow_array::builder::UInt64Builder;
 use arrow_array::cast::AsArray;
-use arrow_array::{downcast_dictionary_array, RecordBatch, StringArray, StructArray};
+use arrow_array::{Array, RecordBatch, StringArray, StructArray};
 use arrow_schema::{DataType, Schema};
 use datafusion_common::cast::as_string_array;
-use datafusion_common::DataFusionError;
+use datafusion_common::{not_impl_err, DataFusionError};
 
 use datafusion_execution::TaskContext;
 
@@ -77,9 +79,11 @@ pub(crate) fn start_demuxer_task(
     base_output_path: ListingTableUrl,
     file_extension: String,
     single_file_output: bool,
+    metrics: &ExecutionPlanMetricsSet,
 ) -> (JoinHandle<Result<()>>, DemuxedStreamReceiver) {
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
     let context = context.clone();
+    let metrics = metrics.clone();
     let task: JoinHandle<std::result::Result<(), DataFusionError>> = match partition_by {
         Some(parts) => {
             // There could be an arbitrarily large number of parallel hive style partitions being written to, so we cannot
@@ -92,6 +96,7 @@ pub(crate) fn start_demuxer_task(
                     parts,
                     base_output_path,
                     file_extension,
+                    metrics,
                 )
                 .await
             })
@@ -234,6 +239,12 @@ fn create_new_file_stream(
 /// Splits an input stream based on the distinct values of a set of columns
 /// Assumes standard hive style partition paths such as
 /// /col1=val1/col2=val2/outputfile.parquet
+///
+/// Values of any type are written in their string representation, with null
+/// values written as [`HIVE_DEFAULT_PARTITION`]. At most
+/// `max_open_partition_files` files are open at once: the least recently
+/// written one is closed to make room for a new partition. The number of rows
+/// written to each partition is recorded in the `rows_written` metric.
 async fn hive_style_partitions_demuxer(
     tx: UnboundedSender<(Path, Receiver<RecordBatch>)>,
     mut input: SendableRecordBatchStream,
@@ -241,15 +252,22 @@ async fn hive_style_partitions_demuxer(
     partition_by: Vec<(String, DataType)>,
     base_output_path: ListingTableUrl,
     file_extension: String,
+    metrics: ExecutionPlanMetricsSet,
 ) -> Result<()> {
     let write_id =
         rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
 
     let exec_options = &context.session_config().options().execution;
     let max_buffered_recordbatches = exec_options.max_buffered_batches_per_output_file;
+    let max_open_files = exec_options.max_open_partition_files.max(1);
 
-    // To support non string partition col types, cast the type to &str first
-    let mut value_map: HashMap<Vec<String>, Sender<RecordBatch>> = HashMap::new();
+    // Open file of each partition key, with the sequence number of its last write
+    let mut value_map: HashMap<Vec<String>, (Sender<RecordBatch>, usize)> =
+        HashMap::new();
+    // Number of files created so far for each partition key
+    let mut file_counts: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut rows_written: HashMap<Vec<String>, Count> = HashMap::new();
+    let mut write_seq = 0;
 
     while let Some(rb) = input.next().await.transpose()? {
         // First compute partition key for each row of batch, e.g. (col1=val1, col2=val2, ...)
@@ -271,36 +289,60 @@ async fn hive_style_partitions_demuxer(
                 DataFusionError::Internal("Unexpected error partitioning batch!".into())
             })?;
 
-            // Get or create channel for this batch
-            let part_tx = match value_map.get_mut(&part_key) {
-                Some(part_tx) => part_tx,
-                None => {
-                    // Create channel for previously unseen distinct partition key and notify consumer of new file
-                    let (part_tx, part_rx) = tokio::sync::mpsc::channel::<RecordBatch>(
-                        max_buffered_recordbatches,
-                    );
-                    let file_path = compute_hive_style_file_path(
-                        &part_key,
-                        &partition_by,
-                        &write_id,
-                        &file_extension,
-                        &base_output_path,
-                    );
-
-                    tx.send((file_path, part_rx)).map_err(|_| {
-                        DataFusionError::Execution(
-                            "Error sending new file stream!".into(),
-                        )
-                    })?;
-
-                    value_map.insert(part_key.clone(), part_tx);
-                    value_map
-                        .get_mut(&part_key)
-                        .ok_or(DataFusionError::Internal(
-                            "Key must exist since it was just inserted!".into(),
-                        ))?
+            if !value_map.contains_key(&part_key) {
+                // Dropping the sender of the least recently written file closes it
+                if value_map.len() >= max_open_files {
+                    let oldest = value_map
+                        .iter()
+                        .min_by_key(|(_, (_, last_write))| *last_write)
+                        .map(|(key, _)| key.clone());
+                    if let Some(oldest) = oldest {
+                        value_map.remove(&oldest);
+                    }
                 }
-            };
+
+                // Create channel for previously unseen distinct partition key and notify consumer of new file
+                let (part_tx, part_rx) =
+                    tokio::sync::mpsc::channel::<RecordBatch>(max_buffered_recordbatches);
+                let file_idx = file_counts.entry(part_key.clone()).or_default();
+                let file_path = compute_hive_style_file_path(
+                    &part_key,
+                    &partition_by,
+                    &write_id,
+                    *file_idx,
+                    &file_extension,
+                    &base_output_path,
+                );
+                *file_idx += 1;
+
+                tx.send((file_path, part_rx)).map_err(|_| {
+                    DataFusionError::Execution("Error sending new file stream!".into())
+                })?;
+
+                value_map.insert(part_key.clone(), (part_tx, write_seq));
+            }
+
+            // Get channel for this batch
+            let (part_tx, last_write) =
+                value_map
+                    .get_mut(&part_key)
+                    .ok_or(DataFusionError::Internal(
+                        "Key must exist since it was just inserted!".into(),
+                    ))?;
+            *last_write = write_seq;
+            write_seq += 1;
+
+            rows_written
+                .entry(part_key.clone())
+                .or_insert_with(|| {
+                    MetricBuilder::new(&metrics)
+                        .with_new_label(
+                            "partition",
+                            compute_hive_style_partition_path(&part_key, &partition_by),
+                        )
+                        .counter("rows_written", 0)
+                })
+                .add(parted_batch.num_rows());
 
             // remove partitions columns
             let final_batch_to_send =
@@ -316,67 +358,47 @@ async fn hive_style_partitions_demuxer(
     Ok(())
 }
 
-fn compute_partition_keys_by_row<'a>(
-    rb: &'a RecordBatch,
-    partition_by: &'a [(String, DataType)],
-) -> Result<Vec<Vec<&'a str>>> {
-    let mut all_partition_values = vec![];
-
-    for (col, dtype) in partition_by.iter() {
-        let mut partition_values = vec![];
-        let col_array =
-            rb.column_by_name(col)
-                .ok_or(DataFusionError::Execution(format!(
-                    "PartitionBy Column {} does not exist in source data!",
-                    col
-                )))?;
-
-        match dtype {
-            DataType::Utf8 => {
-                let array = as_string_array(col_array)?;
-                for i in 0..rb.num_rows() {
-                    partition_values.push(array.value(i));
-                }
-            }
-            DataType::Dictionary(_, _) => {
-                downcast_dictionary_array!(
-                    col_array =>  {
-                        let array = col_array.downcast_dict::<StringArray>()
-                            .ok_or(DataFusionError::Execution(format!("it is not yet supported to write to hive partitions with datatype {}",
-                            dtype)))?;
-
-                        for val in array.values() {
-                            partition_values.push(
-                                val.ok_or(DataFusionError::Execution(format!("Cannot partition by null value for column {}", col)))?
-                            );
-                        }
-                    },
-                    _ => unreachable!(),
-                )
-            }
-            _ => {
-                return Err(DataFusionError::NotImplemented(format!(
-                "it is not yet supported to write to hive partitions with datatype {}",
-                dtype
-            )))
+/// Computes the string representation of the partition values of each row,
+/// with one array per partition column
+fn compute_partition_keys_by_row(
+    rb: &RecordBatch,
+    partition_by: &[(String, DataType)],
+) -> Result<Vec<StringArray>> {
+    partition_by
+        .iter()
+        .map(|(col, dtype)| {
+            let col_array =
+                rb.column_by_name(col)
+                    .ok_or(DataFusionError::Execution(format!(
+                        "PartitionBy Column {} does not exist in source data!",
+                        col
+                    )))?;
+
+            if !can_cast_types(col_array.data_type(), &DataType::Utf8) {
+                return not_impl_err!(
+                    "it is not yet supported to write to hive partitions with datatype {}",
+                    dtype
+                );
             }
-        }
-
-        all_partition_values.push(partition_values);
-    }
-
-    Ok(all_partition_values)
+            let array = cast(col_array, &DataType::Utf8)?;
+            Ok(as_string_array(&array)?.clone())
+        })
+        .collect()
 }
 
 fn compute_take_arrays(
     rb: &RecordBatch,
-    all_partition_values: Vec<Vec<&str>>,
+    all_partition_values: Vec<StringArray>,
 ) -> HashMap<Vec<String>, UInt64Builder> {
     let mut take_map = HashMap::new();
     for i in 0..rb.num_rows() {
         let mut part_key = vec![];
         for vals in all_partition_values.iter() {
-            part_key.push(vals[i].to_owned());
+            let val = match vals.is_null(i) {
+                true => HIVE_DEFAULT_PARTITION,
+                false => vals.value(i),
+            };
+            part_key.push(val.to_owned());
         }
         let builder = take_map.entry(part_key).or_insert(UInt64Builder::new());
         builder.append_value(i as u64);
@@ -407,10 +429,24 @@ fn remove_partition_by_columns(
     Ok(final_batch_to_send)
 }
 
+/// Computes the relative path of a partition, e.g. `col1=val1/col2=val2`
+fn compute_hive_style_partition_path(
+    part_key: &[String],
+    partition_by: &[(String, DataType)],
+) -> String {
+    part_key
+        .iter()
+        .zip(partition_by)
+        .map(|(value, (col, _))| format!("{col}={value}"))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 fn compute_hive_style_file_path(
-    part_key: &Vec<String>,
+    part_key: &[String],
     partition_by: &[(String, DataType)],
     write_id: &str,
+    file_idx: usize,
     file_extension: &str,
     base_output_path: &ListingTableUrl,
 ) -> Path {
@@ -419,5 +455,5 @@ fn compute_hive_style_file_path(
         file_path = file_path.child(format!("{}={}", partition_by[j].0, part_key[j]));
     }
 
-    file_path.child(format!("{}.{}", write_id, file_extension))
+    file_path.child(format!("{}_{}.{}", write_id, file_idx, file_extension))
 }
@@ -25,6 +25,7 @@ use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::listing::PartitionedFile;
 use crate::datasource::physical_plan::FileSinkConfig;
 use crate::error::Result;
+use crate::physical_plan::metrics::ExecutionPlanMetricsSet;
 use crate::physical_plan::SendableRecordBatchStream;
 
 use arrow_array::RecordBatch;
@@ -239,6 +240,7 @@ pub(crate) async fn stateless_multipart_put(
     get_serializer: Box<dyn Fn() -> Box<dyn BatchSerializer> + Send>,
     config: &FileSinkConfig,
     compression: FileCompressionType,
+    metrics: &ExecutionPlanMetricsSet,
 ) -> Result<u64> {
     let object_store = context
         .runtime_env()
@@ -260,6 +262,7 @@ pub(crate) async fn stateless_multipart_put(
         base_output_path.clone(),
         file_extension,
         single_file_output,
+        metrics,
     );
 
     let rb_buffer_size = &context
@@ -34,7 +34,7 @@ use log::{debug, trace};
 
 use crate::{error::Result, scalar::ScalarValue};
 
-use super::PartitionedFile;
+use super::{PartitionedFile, HIVE_DEFAULT_PARTITION};
 use crate::datasource::listing::ListingTableUrl;
 use crate::execution::context::SessionState;
 use datafusion_common::tree_node::{TreeNode, VisitRecursion};
@@ -66,6 +66,7 @@ pub fn expr_applicable_for_cols(col_names: &[String], expr: &Expr) -> bool {
             Expr::Literal(_)
             | Expr::Alias(_)
             | Expr::OuterReferenceColumn(_, _)
+            | Expr::Lambda(_)
             | Expr::ScalarVariable(_, _)
             | Expr::Not(_)
             | Expr::IsNotNull(_)
@@ -240,7 +241,10 @@ async fn prune_partitions(
 
         let mut builders = builders.iter_mut();
         for (p, b) in parsed.iter().zip(&mut builders) {
-            b.append_value(p);
+            match *p {
+                HIVE_DEFAULT_PARTITION => b.append_null(),
+                p => b.append_value(p),
+            }
         }
         builders.for_each(|b| b.append_null());
     }
@@ -353,8 +357,9 @@ pub async fn pruned_partition_list<'a>(
                 .into_iter()
                 .flatten()
                 .zip(partition_cols)
-                .map(|(parsed, (_, datatype))| {
-                    ScalarValue::try_from_string(parsed.to_string(), datatype)
+                .map(|(parsed, (_, datatype))| match parsed {
+                    HIVE_DEFAULT_PARTITION => ScalarValue::try_from(datatype),
+                    parsed => ScalarValue::try_from_string(parsed.to_string(), datatype),
                 })
                 .collect::<Result<Vec<_>>>()?;
 
@@ -35,6 +35,10 @@ pub use table::{
     ListingOptions, ListingTable, ListingTableConfig, ListingTableInsertMode,
 };
 
+/// Value of a partition column in the path of a partition holding the rows
+/// for which the column is null, e.g. `/year=__HIVE_DEFAULT_PARTITION__/`
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
 /// Stream of files get listed from object store
 pub type PartitionedFileStream =
     Pin<Box<dyn Stream<Item = Result<PartitionedFile>> + Send + Sync + 'static>>;
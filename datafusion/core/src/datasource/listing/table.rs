@@ -59,6 +59,7 @@ use datafusion_execution::cache::cache_manager::FileStatisticsCache;
 use datafusion_execution::cache::cache_unit::DefaultFileStatisticsCache;
 use datafusion_expr::expr::Sort;
 use datafusion_optimizer::utils::conjunction;
+use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_expr::{
     create_physical_expr, LexOrdering, PhysicalSortExpr, PhysicalSortRequirement,
 };
@@ -620,10 +621,11 @@ impl ListingTable {
             DataFusionError::Internal("No ListingOptions provided".into())
         })?;
 
-        // Add the partition columns to the file schema
+        // Add the partition columns to the file schema, which are null in
+        // partitions named after `HIVE_DEFAULT_PARTITION`
         let mut builder = SchemaBuilder::from(file_schema.fields());
         for (part_col_name, part_col_type) in &options.table_partition_cols {
-            builder.push(Field::new(part_col_name, part_col_type.clone(), false));
+            builder.push(Field::new(part_col_name, part_col_type.clone(), true));
         }
         let infinite_source = options.infinite_source;
 
@@ -933,6 +935,27 @@ impl TableProvider for ListingTable {
             None
         };
 
+        // Write each partition at once, so that one file is open at a time
+        let order_requirements =
+            if state.config_options().execution.sort_partitioned_writes
+                && !self.options.table_partition_cols.is_empty()
+            {
+                let schema = self.schema();
+                let mut requirements = self
+                    .options
+                    .table_partition_cols
+                    .iter()
+                    .map(|(name, _)| {
+                        let expr = Column::new_with_schema(name, &schema)?;
+                        Ok(PhysicalSortRequirement::new(Arc::new(expr), None))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                requirements.extend(order_requirements.unwrap_or_default());
+                Some(requirements)
+            } else {
+                order_requirements
+            };
+
         self.options()
             .format
             .create_writer_physical_plan(input, state, config, order_requirements)
@@ -2315,6 +2338,89 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_insert_into_typed_partitions_reports_rows_per_partition() -> Result<()>
+    {
+        let mut config_map: HashMap<String, String> = HashMap::new();
+        config_map.insert(
+            "datafusion.execution.max_open_partition_files".into(),
+            "1".into(),
+        );
+        let config = SessionConfig::from_string_hash_map(config_map)?;
+        let session_ctx = SessionContext::new_with_config(config);
+
+        let tmp_dir = TempDir::new()?;
+        let str_path = tmp_dir.path().to_str().unwrap();
+        session_ctx
+            .sql(&format!(
+                "create external table foo(a int, b int, c date) \
+                        stored as csv \
+                        location '{str_path}' \
+                        partitioned by (b, c) \
+                        options (insert_mode 'append_new_files')"
+            ))
+            .await?
+            .collect()
+            .await?;
+
+        let plan = session_ctx
+            .sql(
+                "insert into foo values (1, 1, '2023-01-01'), (2, NULL, NULL), \
+                 (3, 1, '2023-01-01'), (4, 2, '2023-01-02')",
+            )
+            .await?
+            .create_physical_plan()
+            .await?;
+        collect(plan.clone(), session_ctx.task_ctx()).await?;
+
+        let metrics = plan.metrics().expect("FileSinkExec should report metrics");
+        let mut rows_per_partition = metrics
+            .iter()
+            .filter(|m| m.value().name() == "rows_written")
+            .map(|m| {
+                let partition = m
+                    .labels()
+                    .iter()
+                    .find(|l| l.name() == "partition")
+                    .map(|l| l.value().to_string())
+                    .unwrap();
+                (partition, m.value().as_usize())
+            })
+            .collect::<Vec<_>>();
+        rows_per_partition.sort();
+        assert_eq!(
+            rows_per_partition,
+            vec![
+                ("b=1/c=2023-01-01".to_string(), 2),
+                ("b=2/c=2023-01-02".to_string(), 1),
+                (
+                    "b=__HIVE_DEFAULT_PARTITION__/c=__HIVE_DEFAULT_PARTITION__"
+                        .to_string(),
+                    1
+                ),
+            ]
+        );
+
+        let batches = session_ctx
+            .sql("select * from foo order by a")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+---+---+------------+",
+            "| a | b | c          |",
+            "+---+---+------------+",
+            "| 1 | 1 | 2023-01-01 |",
+            "| 2 |   |            |",
+            "| 3 | 1 | 2023-01-01 |",
+            "| 4 | 2 | 2023-01-02 |",
+            "+---+---+------------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        Ok(())
+    }
+
     /// tests insert into with end to end sql
     /// create external table + insert into statements
     async fn helper_test_insert_into_sql(
@@ -0,0 +1,992 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Materialized view data source which stores the results of a LogicalPlan.
+
+use std::collections::{HashMap, HashSet};
+use std::{any::Any, sync::Arc};
+
+use arrow::datatypes::SchemaRef;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
+use datafusion_common::tree_node::{Transformed, TreeNode, VisitRecursion};
+use datafusion_common::{OwnedTableReference, DEFAULT_ARROW_EXTENSION};
+use datafusion_expr::aggregate_function::AggregateFunction;
+use datafusion_expr::expr::{self, Alias};
+use datafusion_expr::{
+    cast, max, min, sum, Projection, TableProviderFilterPushDown, TableScan,
+};
+use datafusion_optimizer::optimizer::ApplyOrder;
+use datafusion_optimizer::{OptimizerConfig, OptimizerRule};
+use futures::TryStreamExt;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use parking_lot::RwLock;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{
+    dataframe::DataFrame,
+    error::Result,
+    logical_expr::{Expr, LogicalPlan, LogicalPlanBuilder},
+    physical_plan::ExecutionPlan,
+};
+
+use crate::datasource::file_format::arrow::ArrowFormat;
+use crate::datasource::listing::{
+    ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
+};
+use crate::datasource::object_store::ObjectStoreUrl;
+use crate::datasource::{
+    provider_as_source, source_as_provider, MemTable, TableProvider, TableType,
+};
+use crate::execution::context::SessionState;
+
+/// The prefix of the names of the files written by a [`MaterializedView`]
+/// stored in a listing location
+pub const STORED_FILE_PREFIX: &str = "materialized-view-";
+
+/// An implementation of `TableProvider` that stores the results of a
+/// logical plan, which are recomputed by [`MaterializedView::refresh`].
+///
+/// The results are stored either in memory or as Arrow IPC files in a
+/// listing location.
+///
+/// If the view is a filter, projection or aggregate of a single
+/// [`ListingTable`], and files have only been added to the table since
+/// the last refresh, only the new files are read when refreshing. The
+/// results of aggregates are merged with the new rows, which is
+/// supported for `SUM`, `COUNT`, `MIN` and `MAX`.
+pub struct MaterializedView {
+    /// LogicalPlan of the view
+    logical_plan: LogicalPlan,
+    /// SQL used to create the view, if available
+    definition: Option<String>,
+    /// Schema of the results
+    table_schema: SchemaRef,
+    /// Where the results are stored
+    storage: Storage,
+    /// The files of the source table included in the results, if the
+    /// view can be refreshed incrementally. Also ensures refreshes run
+    /// one at a time
+    refreshed_files: Mutex<Option<HashSet<Path>>>,
+}
+
+impl MaterializedView {
+    /// Create a new materialized view that stores its results in memory.
+    ///
+    /// The view is empty until it is refreshed.
+    pub fn try_new(
+        logical_plan: LogicalPlan,
+        definition: Option<String>,
+    ) -> Result<Self> {
+        let table_schema: SchemaRef = Arc::new(logical_plan.schema().as_ref().into());
+        let table = MemTable::try_new(table_schema.clone(), vec![vec![]])?;
+        Ok(Self {
+            logical_plan,
+            definition,
+            table_schema,
+            storage: Storage::Memory(RwLock::new(Arc::new(table))),
+            refreshed_files: Mutex::new(None),
+        })
+    }
+
+    /// Create a new materialized view that stores its results as Arrow
+    /// IPC files in `location`.
+    ///
+    /// The view is empty until it is refreshed. The files written by the
+    /// view are named with the prefix [`STORED_FILE_PREFIX`]. Files with
+    /// this prefix already stored directly in `location` are not read, and
+    /// are deleted by the first refresh. Other files are left untouched.
+    pub fn try_new_with_location(
+        logical_plan: LogicalPlan,
+        definition: Option<String>,
+        location: ListingTableUrl,
+    ) -> Result<Self> {
+        let table_schema: SchemaRef = Arc::new(logical_plan.schema().as_ref().into());
+        let files = StoredFiles::try_new(&location, &table_schema, vec![], vec![])?;
+        Ok(Self {
+            logical_plan,
+            definition,
+            table_schema,
+            storage: Storage::Listing {
+                location,
+                files: RwLock::new(files),
+            },
+            refreshed_files: Mutex::new(None),
+        })
+    }
+
+    /// Get definition ref
+    pub fn definition(&self) -> Option<&String> {
+        self.definition.as_ref()
+    }
+
+    /// Get logical_plan ref
+    pub fn logical_plan(&self) -> &LogicalPlan {
+        &self.logical_plan
+    }
+
+    /// Recompute the results of the view, incrementally if possible
+    pub async fn refresh(&self, state: &SessionState) -> Result<()> {
+        let mut state = state.clone();
+        // the results of the view must not be computed from the view itself
+        state
+            .config_mut()
+            .options_mut()
+            .optimizer
+            .enable_materialized_view_rewrite = false;
+
+        let mut refreshed_files = self.refreshed_files.lock().await;
+        let Some(source) = IncrementalSource::try_new(&self.logical_plan) else {
+            let plan = self.logical_plan.clone();
+            return self
+                .storage
+                .overwrite(&state, &self.table_schema, plan)
+                .await;
+        };
+
+        // the view is computed from the files listed here, so files added
+        // while refreshing are included by the next refresh
+        let files = source.list_files(&state).await?;
+        match refreshed_files.as_ref() {
+            Some(previous) if previous.is_subset(&files) => {
+                let new_files: Vec<_> = files.difference(previous).cloned().collect();
+                if !new_files.is_empty() {
+                    let delta = source.with_files(&self.logical_plan, &new_files)?;
+                    match &source.merge {
+                        Some(merge) => {
+                            let plan = self.merge(delta, merge)?;
+                            self.storage
+                                .overwrite(&state, &self.table_schema, plan)
+                                .await?
+                        }
+                        None => {
+                            self.storage
+                                .append(&state, &self.table_schema, delta)
+                                .await?
+                        }
+                    }
+                }
+            }
+            // the view was never refreshed, or files were removed
+            _ => {
+                let plan = match files.is_empty() {
+                    true => self.logical_plan.clone(),
+                    false => {
+                        let files: Vec<_> = files.iter().cloned().collect();
+                        source.with_files(&self.logical_plan, &files)?
+                    }
+                };
+                self.storage
+                    .overwrite(&state, &self.table_schema, plan)
+                    .await?
+            }
+        }
+        *refreshed_files = Some(files);
+        Ok(())
+    }
+
+    /// Returns a plan that merges the aggregated rows of `delta` into the
+    /// current results of the view
+    fn merge(&self, delta: LogicalPlan, merge: &[Merge]) -> Result<LogicalPlan> {
+        let current = LogicalPlanBuilder::scan(
+            "current",
+            provider_as_source(self.storage.provider()),
+            None,
+        )?;
+        let union = current.union(delta)?.build()?;
+
+        let mut group_expr = vec![];
+        let mut aggr_expr = vec![];
+        for (field, merge) in union.schema().fields().iter().zip(merge) {
+            let column = Expr::Column(field.qualified_column());
+            match merge {
+                Merge::GroupBy(_) => group_expr.push(column),
+                Merge::Sum => aggr_expr.push(sum(column)),
+                Merge::Min => aggr_expr.push(min(column)),
+                Merge::Max => aggr_expr.push(max(column)),
+            }
+        }
+        let num_groups = group_expr.len();
+        let aggregate = LogicalPlanBuilder::from(union)
+            .aggregate(group_expr, aggr_expr)?
+            .build()?;
+
+        // restore the order, names and types of the columns of the view
+        let aggregate_fields = aggregate.schema().fields().clone();
+        let (mut next_group, mut next_aggr) = (0, num_groups);
+        let expr = merge
+            .iter()
+            .zip(self.table_schema.fields())
+            .map(|(merge, field)| {
+                let index = match merge {
+                    Merge::GroupBy(_) => &mut next_group,
+                    _ => &mut next_aggr,
+                };
+                let column = Expr::Column(aggregate_fields[*index].qualified_column());
+                *index += 1;
+                cast(column, field.data_type().clone()).alias(field.name())
+            })
+            .collect::<Vec<_>>();
+        LogicalPlanBuilder::from(aggregate).project(expr)?.build()
+    }
+}
+
+#[async_trait]
+impl TableProvider for MaterializedView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.table_schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::View
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.storage.provider().supports_filters_pushdown(filters)
+    }
+
+    async fn scan(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.storage
+            .provider()
+            .scan(state, projection, filters, limit)
+            .await
+    }
+}
+
+/// Where the results of a [`MaterializedView`] are stored
+enum Storage {
+    /// In memory
+    Memory(RwLock<Arc<MemTable>>),
+    /// As Arrow IPC files in a listing location
+    Listing {
+        location: ListingTableUrl,
+        files: RwLock<StoredFiles>,
+    },
+}
+
+/// The Arrow IPC files of [`Storage::Listing`].
+///
+/// The results are read from an explicit set of files, rather than all
+/// files in the location, so that new results become visible at once when
+/// the set is swapped, and never partially written or missing.
+struct StoredFiles {
+    /// Reads the files of `current`
+    table: Arc<ListingTable>,
+    /// The files of the results
+    current: Vec<Path>,
+    /// The files of the results replaced by the last overwrite. They are
+    /// kept for scans planned before it, and deleted by the next overwrite
+    replaced: Vec<Path>,
+}
+
+impl StoredFiles {
+    fn try_new(
+        location: &ListingTableUrl,
+        schema: &SchemaRef,
+        current: Vec<Path>,
+        replaced: Vec<Path>,
+    ) -> Result<Self> {
+        let options = ListingOptions::new(Arc::new(ArrowFormat))
+            .with_file_extension(DEFAULT_ARROW_EXTENSION);
+        let table =
+            listing_table(&location.object_store(), &current, options, schema.clone())?;
+        Ok(Self {
+            table: Arc::new(table),
+            current,
+            replaced,
+        })
+    }
+}
+
+impl Storage {
+    /// Returns the provider reading the stored results
+    fn provider(&self) -> Arc<dyn TableProvider> {
+        match self {
+            Storage::Memory(table) => table.read().clone(),
+            Storage::Listing { files, .. } => files.read().table.clone(),
+        }
+    }
+
+    /// Replaces the stored results with the results of `plan`
+    async fn overwrite(
+        &self,
+        state: &SessionState,
+        schema: &SchemaRef,
+        plan: LogicalPlan,
+    ) -> Result<()> {
+        let partitions = collect_partitioned(state, schema, plan).await?;
+        match self {
+            Storage::Memory(table) => {
+                *table.write() = Arc::new(MemTable::try_new(schema.clone(), partitions)?);
+            }
+            Storage::Listing { location, files } => {
+                let store = state.runtime_env().object_store(location)?;
+                let path =
+                    write_file(store.as_ref(), location, schema, partitions).await?;
+                let replaced = files.read().current.clone();
+                let new_files = StoredFiles::try_new(
+                    location,
+                    schema,
+                    vec![path.clone()],
+                    replaced.clone(),
+                )?;
+                *files.write() = new_files;
+
+                // delete the files replaced by earlier overwrites, and any
+                // written to the location before the view was created
+                for meta in list_stored_files(store.as_ref(), location).await? {
+                    if meta.location != path && !replaced.contains(&meta.location) {
+                        store.delete(&meta.location).await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the results of `plan` to the stored results
+    async fn append(
+        &self,
+        state: &SessionState,
+        schema: &SchemaRef,
+        plan: LogicalPlan,
+    ) -> Result<()> {
+        let mut partitions = collect_partitioned(state, schema, plan).await?;
+        match self {
+            Storage::Memory(table) => {
+                let current = table.read().clone();
+                let mut all_partitions = Vec::with_capacity(current.batches.len());
+                for partition in &current.batches {
+                    all_partitions.push(partition.read().await.clone());
+                }
+                all_partitions.append(&mut partitions);
+                *table.write() =
+                    Arc::new(MemTable::try_new(schema.clone(), all_partitions)?);
+            }
+            Storage::Listing { location, files } => {
+                let store = state.runtime_env().object_store(location)?;
+                let path =
+                    write_file(store.as_ref(), location, schema, partitions).await?;
+                let (mut current, replaced) = {
+                    let files = files.read();
+                    (files.current.clone(), files.replaced.clone())
+                };
+                current.push(path);
+                let new_files =
+                    StoredFiles::try_new(location, schema, current, replaced)?;
+                *files.write() = new_files;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Executes `plan`, returning batches with the given `schema`
+async fn collect_partitioned(
+    state: &SessionState,
+    schema: &SchemaRef,
+    plan: LogicalPlan,
+) -> Result<Vec<Vec<RecordBatch>>> {
+    let partitions = DataFrame::new(state.clone(), plan)
+        .collect_partitioned()
+        .await?;
+    partitions
+        .into_iter()
+        .map(|batches| {
+            batches
+                .into_iter()
+                .map(|batch| {
+                    Ok(RecordBatch::try_new(
+                        schema.clone(),
+                        batch.columns().to_vec(),
+                    )?)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Lists the files written by a view directly in `location`, leaving out
+/// subdirectories and any other files
+async fn list_stored_files(
+    store: &dyn ObjectStore,
+    location: &ListingTableUrl,
+) -> Result<Vec<ObjectMeta>> {
+    let files = store.list_with_delimiter(Some(location.prefix())).await?;
+    Ok(files
+        .objects
+        .into_iter()
+        .filter(|meta| {
+            meta.location.filename().map_or(false, |name| {
+                name.starts_with(STORED_FILE_PREFIX)
+                    && name.ends_with(DEFAULT_ARROW_EXTENSION)
+            })
+        })
+        .collect())
+}
+
+/// Writes `partitions` to a new Arrow IPC file in `location`, returning
+/// its path
+async fn write_file(
+    store: &dyn ObjectStore,
+    location: &ListingTableUrl,
+    schema: &SchemaRef,
+    partitions: Vec<Vec<RecordBatch>>,
+) -> Result<Path> {
+    let mut writer = FileWriter::try_new(Vec::new(), schema)?;
+    for batch in partitions.iter().flatten() {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    let buffer = writer.into_inner()?;
+
+    let path = location.prefix().child(format!(
+        "{STORED_FILE_PREFIX}{}{DEFAULT_ARROW_EXTENSION}",
+        Uuid::new_v4()
+    ));
+    store.put(&path, buffer.into()).await?;
+    Ok(path)
+}
+
+/// Returns a [`ListingTable`] reading only `files` of `object_store_url`
+fn listing_table(
+    object_store_url: &ObjectStoreUrl,
+    files: &[Path],
+    options: ListingOptions,
+    schema: SchemaRef,
+) -> Result<ListingTable> {
+    let table_paths = files
+        .iter()
+        .map(|file| {
+            ListingTableUrl::parse(format!("{}{file}", object_store_url.as_str()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let config = ListingTableConfig::new_with_multi_paths(table_paths)
+        .with_listing_options(options)
+        .with_schema(schema);
+    ListingTable::try_new(config)
+}
+
+/// How to merge a column of the results of an aggregate with new rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Merge {
+    /// The `n`th group by expression
+    GroupBy(usize),
+    Sum,
+    Min,
+    Max,
+}
+
+/// The [`ListingTable`] read by a view that can be refreshed incrementally
+struct IncrementalSource {
+    /// The name the table is scanned as
+    table_name: OwnedTableReference,
+    table_paths: Vec<ListingTableUrl>,
+    options: ListingOptions,
+    schema: SchemaRef,
+    /// How to merge each column of the results, if the view is an aggregate
+    merge: Option<Vec<Merge>>,
+}
+
+impl IncrementalSource {
+    /// Returns the source of `plan` if it can be refreshed incrementally
+    fn try_new(plan: &LogicalPlan) -> Option<Self> {
+        let Incremental {
+            scan,
+            merge,
+            num_groups,
+        } = incremental(plan)?;
+
+        if let Some(merge) = &merge {
+            // all group by expressions are needed to merge new rows
+            let groups: HashSet<_> = merge
+                .iter()
+                .filter_map(|merge| match merge {
+                    Merge::GroupBy(i) => Some(*i),
+                    _ => None,
+                })
+                .collect();
+            if groups.len() != num_groups {
+                return None;
+            }
+        }
+
+        let provider = source_as_provider(&scan.source).ok()?;
+        let table = provider.as_any().downcast_ref::<ListingTable>()?;
+        // partition values are parsed relative to the table paths, which
+        // differ for a table of only the new files
+        if table.table_paths().is_empty()
+            || !table.options().table_partition_cols.is_empty()
+        {
+            return None;
+        }
+
+        Some(Self {
+            table_name: scan.table_name.clone(),
+            table_paths: table.table_paths().clone(),
+            options: table.options().clone(),
+            schema: table.schema(),
+            merge,
+        })
+    }
+
+    /// Lists the files of the table
+    async fn list_files(&self, state: &SessionState) -> Result<HashSet<Path>> {
+        let store = state.runtime_env().object_store(&self.table_paths[0])?;
+        let mut files = HashSet::new();
+        for table_path in &self.table_paths {
+            let mut stream = table_path
+                .list_all_files(state, store.as_ref(), &self.options.file_extension)
+                .await?;
+            while let Some(meta) = stream.try_next().await? {
+                files.insert(meta.location);
+            }
+        }
+        Ok(files)
+    }
+
+    /// Returns `plan` reading only `files` of the table
+    fn with_files(&self, plan: &LogicalPlan, files: &[Path]) -> Result<LogicalPlan> {
+        let table = listing_table(
+            &self.table_paths[0].object_store(),
+            files,
+            self.options.clone(),
+            self.schema.clone(),
+        )?;
+        let source = provider_as_source(Arc::new(table));
+
+        plan.clone().transform_up(&|plan| match plan {
+            LogicalPlan::TableScan(scan) if scan.table_name == self.table_name => {
+                Ok(Transformed::Yes(LogicalPlan::TableScan(TableScan {
+                    source: source.clone(),
+                    ..scan
+                })))
+            }
+            plan => Ok(Transformed::No(plan)),
+        })
+    }
+}
+
+/// A plan that can be refreshed incrementally
+struct Incremental<'a> {
+    scan: &'a TableScan,
+    /// How to merge each output column, if the plan is an aggregate
+    merge: Option<Vec<Merge>>,
+    /// The number of group by expressions of the aggregate
+    num_groups: usize,
+}
+
+/// Returns how `plan` can be refreshed incrementally, if it is a chain
+/// of filters, projections and at most one aggregate over a table scan
+fn incremental(plan: &LogicalPlan) -> Option<Incremental<'_>> {
+    if contains_subquery(plan) {
+        return None;
+    }
+    match plan {
+        LogicalPlan::TableScan(scan) if scan.fetch.is_none() => Some(Incremental {
+            scan,
+            merge: None,
+            num_groups: 0,
+        }),
+        // filters of aggregates (HAVING) may remove groups that new rows
+        // would be merged into
+        LogicalPlan::Filter(filter) => {
+            incremental(&filter.input).filter(|incremental| incremental.merge.is_none())
+        }
+        LogicalPlan::SubqueryAlias(alias) => incremental(&alias.input),
+        LogicalPlan::Projection(projection) => {
+            let mut incremental = incremental(&projection.input)?;
+            if let Some(merge) = &incremental.merge {
+                let input_schema = projection.input.schema();
+                let merge = projection
+                    .expr
+                    .iter()
+                    .map(|expr| {
+                        let column = match expr {
+                            Expr::Column(column) => column,
+                            Expr::Alias(Alias { expr, .. }) => match expr.as_ref() {
+                                Expr::Column(column) => column,
+                                _ => return None,
+                            },
+                            _ => return None,
+                        };
+                        let index = input_schema.index_of_column(column).ok()?;
+                        Some(merge[index])
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                incremental.merge = Some(merge);
+            }
+            Some(incremental)
+        }
+        LogicalPlan::Aggregate(aggregate) => {
+            let mut incremental = incremental(&aggregate.input)?;
+            if incremental.merge.is_some()
+                || aggregate
+                    .group_expr
+                    .iter()
+                    .any(|expr| matches!(expr, Expr::GroupingSet(_)))
+            {
+                return None;
+            }
+            let mut merge: Vec<_> = (0..aggregate.group_expr.len())
+                .map(Merge::GroupBy)
+                .collect();
+            for expr in &aggregate.aggr_expr {
+                let expr = match expr {
+                    Expr::Alias(Alias { expr, .. }) => expr.as_ref(),
+                    expr => expr,
+                };
+                let Expr::AggregateFunction(expr::AggregateFunction {
+                    fun,
+                    distinct: false,
+                    filter: None,
+                    order_by: None,
+                    ..
+                }) = expr
+                else {
+                    return None;
+                };
+                merge.push(match fun {
+                    AggregateFunction::Sum | AggregateFunction::Count => Merge::Sum,
+                    AggregateFunction::Min => Merge::Min,
+                    AggregateFunction::Max => Merge::Max,
+                    _ => return None,
+                });
+            }
+            incremental.merge = Some(merge);
+            incremental.num_groups = aggregate.group_expr.len();
+            Some(incremental)
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if the expressions of `plan` contain subqueries, which
+/// may read other tables
+fn contains_subquery(plan: &LogicalPlan) -> bool {
+    let mut contains_subquery = false;
+    plan.inspect_expressions(|expr| {
+        expr.apply(&mut |expr| {
+            if matches!(
+                expr,
+                Expr::ScalarSubquery(_) | Expr::Exists(_) | Expr::InSubquery(_)
+            ) {
+                contains_subquery = true;
+                return Ok(VisitRecursion::Stop);
+            }
+            Ok(VisitRecursion::Continue)
+        })?;
+        Ok(()) as Result<()>
+    })
+    // closure always returns OK
+    .unwrap();
+    contains_subquery
+}
+
+/// Optimizer rule that rewrites subplans of a query that are identical
+/// to the query of a registered [`MaterializedView`] to read the stored
+/// results of the view instead.
+///
+/// Only applied when `datafusion.optimizer.enable_materialized_view_rewrite`
+/// is set, as the results of a view are as of its last refresh.
+#[derive(Default)]
+pub struct MaterializedViewRewrite {
+    views: RwLock<HashMap<OwnedTableReference, Arc<MaterializedView>>>,
+}
+
+impl MaterializedViewRewrite {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the materialized view `name`, replacing any view
+    /// previously registered with the same name
+    pub fn register_view(&self, name: OwnedTableReference, view: Arc<MaterializedView>) {
+        self.views.write().insert(name, view);
+    }
+
+    /// Deregisters the materialized view `name`
+    pub fn deregister_view(&self, name: &OwnedTableReference) {
+        self.views.write().remove(name);
+    }
+}
+
+impl OptimizerRule for MaterializedViewRewrite {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        if !config.options().optimizer.enable_materialized_view_rewrite {
+            return Ok(None);
+        }
+
+        let views = self.views.read();
+        let Some((name, view)) =
+            views.iter().find(|(_, view)| view.logical_plan() == plan)
+        else {
+            return Ok(None);
+        };
+
+        let scan = LogicalPlanBuilder::scan(
+            name.clone(),
+            provider_as_source(view.clone()),
+            None,
+        )?
+        .build()?;
+        // keep the schema of the subplan, so expressions referring to its
+        // columns remain valid
+        let expr = scan
+            .schema()
+            .fields()
+            .iter()
+            .zip(plan.schema().fields())
+            .map(|(view_field, field)| {
+                Expr::Column(view_field.qualified_column()).alias(field.name())
+            })
+            .collect();
+        Ok(Some(LogicalPlan::Projection(
+            Projection::try_new_with_schema(expr, Arc::new(scan), plan.schema().clone())?,
+        )))
+    }
+
+    fn name(&self) -> &str {
+        "materialized_view_rewrite"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::TopDown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::Column;
+    use datafusion_expr::{avg, col, count, lit, table_scan};
+
+    use crate::assert_batches_sorted_eq;
+    use crate::physical_plan::collect;
+    use crate::prelude::SessionContext;
+
+    use super::*;
+
+    fn scan() -> Result<LogicalPlanBuilder> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        table_scan(Some("t"), &schema, None)
+    }
+
+    fn merge(plan: &LogicalPlan) -> Option<Option<Vec<Merge>>> {
+        incremental(plan).map(|incremental| incremental.merge)
+    }
+
+    #[test]
+    fn incremental_filter_and_projection() -> Result<()> {
+        let plan = scan()?
+            .filter(col("b").gt(lit(1)))?
+            .project(vec![col("a")])?
+            .build()?;
+        assert_eq!(merge(&plan), Some(None));
+        Ok(())
+    }
+
+    #[test]
+    fn incremental_aggregate() -> Result<()> {
+        let plan = scan()?
+            .aggregate(
+                vec![col("a")],
+                vec![sum(col("b")), count(col("b")), max(col("b"))],
+            )?
+            .project(vec![
+                Expr::Column(Column::from_name("COUNT(t.b)")),
+                Expr::Column(Column::from_name("MAX(t.b)")).alias("m"),
+                col("a"),
+            ])?
+            .build()?;
+        assert_eq!(
+            merge(&plan),
+            Some(Some(vec![Merge::Sum, Merge::Max, Merge::GroupBy(0)]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn not_incremental() -> Result<()> {
+        // AVG can not be merged
+        let plan = scan()?
+            .aggregate(vec![col("a")], vec![avg(col("b"))])?
+            .build()?;
+        assert_eq!(merge(&plan), None);
+
+        // HAVING may remove groups
+        let plan = scan()?
+            .aggregate(vec![col("a")], vec![sum(col("b"))])?
+            .filter(col("a").gt(lit(1)))?
+            .build()?;
+        assert_eq!(merge(&plan), None);
+
+        // aggregates of aggregates
+        let plan = scan()?
+            .aggregate(vec![col("a")], vec![sum(col("b"))])?
+            .aggregate(Vec::<Expr>::new(), vec![count(col("a"))])?
+            .build()?;
+        assert_eq!(merge(&plan), None);
+
+        // limits depend on all rows
+        let plan = scan()?.limit(0, Some(10))?.build()?;
+        assert_eq!(merge(&plan), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_memory_view() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t(a INT, b INT) AS VALUES (1, 10), (2, 20)")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("CREATE MATERIALIZED VIEW v AS SELECT a, b * 2 AS c FROM t")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("INSERT INTO t VALUES (3, 30)")
+            .await?
+            .collect()
+            .await?;
+
+        let view = ctx.table_provider("v").await?;
+        let view = view.as_any().downcast_ref::<MaterializedView>().unwrap();
+        assert!(view.definition().is_some());
+        assert_eq!(view.table_type(), TableType::View);
+
+        let expected = [
+            "+---+----+",
+            "| a | c  |",
+            "+---+----+",
+            "| 1 | 20 |",
+            "| 2 | 40 |",
+            "+---+----+",
+        ];
+        let results = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        assert_batches_sorted_eq!(expected, &results);
+
+        view.refresh(&ctx.state()).await?;
+        let expected = [
+            "+---+----+",
+            "| a | c  |",
+            "+---+----+",
+            "| 1 | 20 |",
+            "| 2 | 40 |",
+            "| 3 | 60 |",
+            "+---+----+",
+        ];
+        let results = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        assert_batches_sorted_eq!(expected, &results);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_listing_view() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = tempfile::TempDir::new()?;
+        // files written by an earlier view in the location are not read
+        let stale = format!("{STORED_FILE_PREFIX}stale{DEFAULT_ARROW_EXTENSION}");
+        std::fs::write(dir.path().join(&stale), b"stale")?;
+        // and other files are neither read nor deleted
+        std::fs::write(dir.path().join("other.arrow"), b"other")?;
+        std::fs::create_dir(dir.path().join("nested"))?;
+        let nested = dir.path().join("nested").join(&stale);
+        std::fs::write(&nested, b"nested")?;
+        let num_files = || {
+            std::fs::read_dir(dir.path())
+                .unwrap()
+                .filter(|entry| {
+                    let name = entry.as_ref().unwrap().file_name();
+                    name.to_str().unwrap().starts_with(STORED_FILE_PREFIX)
+                })
+                .count()
+        };
+        assert_eq!(num_files(), 1);
+
+        ctx.sql("CREATE TABLE t(a INT) AS VALUES (1), (2)")
+            .await?
+            .collect()
+            .await?;
+        let sql = format!(
+            "CREATE MATERIALIZED VIEW v WITH (location = '{}/') AS SELECT a FROM t",
+            dir.path().display()
+        );
+        ctx.sql(&sql).await?.collect().await?;
+        assert_eq!(num_files(), 1);
+
+        let view = ctx.table_provider("v").await?;
+        let view = view.as_any().downcast_ref::<MaterializedView>().unwrap();
+        // a scan planned before a refresh reads the results it replaced
+        let plan = ctx
+            .sql("SELECT a FROM v")
+            .await?
+            .create_physical_plan()
+            .await?;
+        ctx.sql("INSERT INTO t VALUES (3)").await?.collect().await?;
+        view.refresh(&ctx.state()).await?;
+        assert_eq!(num_files(), 2);
+
+        let expected = ["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+        let results = collect(plan, ctx.task_ctx()).await?;
+        assert_batches_sorted_eq!(expected, &results);
+
+        // results replaced by earlier refreshes are deleted
+        ctx.sql("INSERT INTO t VALUES (4)").await?.collect().await?;
+        view.refresh(&ctx.state()).await?;
+        assert_eq!(num_files(), 2);
+
+        let expected = [
+            "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "| 4 |", "+---+",
+        ];
+        let results = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        assert_batches_sorted_eq!(expected, &results);
+
+        assert!(!dir.path().join(&stale).exists());
+        assert!(dir.path().join("other.arrow").exists());
+        assert!(nested.exists());
+        Ok(())
+    }
+}
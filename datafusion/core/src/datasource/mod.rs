@@ -25,9 +25,11 @@ pub mod empty;
 pub mod file_format;
 pub mod listing;
 pub mod listing_table_factory;
+pub mod materialized_view;
 pub mod memory;
 pub mod physical_plan;
 pub mod provider;
+pub mod snapshot;
 mod statistics;
 pub mod streaming;
 pub mod view;
@@ -38,8 +40,10 @@ pub use datafusion_execution::object_store;
 pub use self::default_table_source::{
     provider_as_source, source_as_provider, DefaultTableSource,
 };
+pub use self::materialized_view::MaterializedView;
 pub use self::memory::MemTable;
 pub use self::provider::TableProvider;
+pub use self::snapshot::SnapshotTable;
 pub use self::view::ViewTable;
 pub use crate::logical_expr::TableType;
 pub use statistics::get_statistics_with_limit;
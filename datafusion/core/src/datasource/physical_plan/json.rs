@@ -84,6 +84,11 @@ impl NdJsonExec {
     pub fn base_config(&self) -> &FileScanConfig {
         &self.base_config
     }
+
+    /// The compression of the files
+    pub fn file_compression_type(&self) -> FileCompressionType {
+        self.file_compression_type
+    }
 }
 
 impl DisplayAs for NdJsonExec {
@@ -25,6 +25,7 @@ mod file_stream;
 mod json;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+pub(crate) mod schema_evolution;
 
 pub(crate) use self::csv::plan_to_csv;
 pub use self::csv::{CsvConfig, CsvExec, CsvOpener};
@@ -64,13 +65,14 @@ use crate::{
 use arrow::{
     array::new_null_array,
     compute::{can_cast_types, cast},
-    datatypes::{DataType, Schema, SchemaRef},
+    datatypes::{DataType, Field, FieldRef, Schema, SchemaRef},
     record_batch::{RecordBatch, RecordBatchOptions},
 };
 use datafusion_common::{file_options::FileTypeWriterOptions, plan_err};
 use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_expr::PhysicalSortExpr;
 use datafusion_physical_plan::ExecutionPlan;
+use schema_evolution::is_safe_upcast;
 
 use log::debug;
 use object_store::path::Path;
@@ -277,11 +279,36 @@ where
 pub(crate) struct SchemaAdapter {
     /// Schema for the table
     table_schema: SchemaRef,
+    /// Whether file schemas are reconciled as described in [`schema_evolution`]
+    schema_evolution: bool,
 }
 
 impl SchemaAdapter {
     pub(crate) fn new(table_schema: SchemaRef) -> SchemaAdapter {
-        Self { table_schema }
+        Self {
+            table_schema,
+            schema_evolution: false,
+        }
+    }
+
+    /// Match columns by field id and only allow safe widening casts, see
+    /// [`schema_evolution`]
+    pub(crate) fn with_schema_evolution(mut self, schema_evolution: bool) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
+    /// Find the field of `schema` corresponding to `field`
+    fn find_field<'a>(
+        &self,
+        schema: &'a Schema,
+        field: &Field,
+    ) -> Option<(usize, &'a FieldRef)> {
+        if self.schema_evolution {
+            schema_evolution::find_field(schema, field)
+        } else {
+            schema.fields().find(field.name())
+        }
     }
 
     /// Map a column index in the table schema to a column index in a particular
@@ -294,7 +321,7 @@ impl SchemaAdapter {
         file_schema: &Schema,
     ) -> Option<usize> {
         let field = self.table_schema.field(index);
-        Some(file_schema.fields.find(field.name())?.0)
+        Some(self.find_field(file_schema, field)?.0)
     }
 
     /// Creates a `SchemaMapping` that can be used to cast or map the columns from the file schema to the table schema.
@@ -312,10 +339,24 @@ impl SchemaAdapter {
         let mut projection = Vec::with_capacity(file_schema.fields().len());
         let mut field_mappings = vec![None; self.table_schema.fields().len()];
 
+        let mut renamed = false;
+
         for (file_idx, file_field) in file_schema.fields.iter().enumerate() {
             if let Some((table_idx, table_field)) =
-                self.table_schema.fields().find(file_field.name())
+                self.find_field(&self.table_schema, file_field)
             {
+                if self.schema_evolution {
+                    if !is_safe_upcast(file_field.data_type(), table_field.data_type()) {
+                        return plan_err!(
+                            "Cannot evolve file schema field {} of type {:?} to table schema field {} of type {:?}: only widening casts are allowed",
+                            file_field.name(),
+                            file_field.data_type(),
+                            table_field.name(),
+                            table_field.data_type()
+                        );
+                    }
+                    renamed |= file_field.name() != table_field.name();
+                }
                 match can_cast_types(file_field.data_type(), table_field.data_type()) {
                     true => {
                         field_mappings[table_idx] = Some(projection.len());
@@ -337,6 +378,7 @@ impl SchemaAdapter {
             SchemaMapping {
                 table_schema: self.table_schema.clone(),
                 field_mappings,
+                renamed,
             },
             projection,
         ))
@@ -351,9 +393,17 @@ pub struct SchemaMapping {
     table_schema: SchemaRef,
     /// Mapping from field index in `table_schema` to index in projected file_schema
     field_mappings: Vec<Option<usize>>,
+    /// Whether any file column was matched by field id to a table column with another name
+    renamed: bool,
 }
 
 impl SchemaMapping {
+    /// Returns true if any file column has a different name in the table schema,
+    /// in which case predicates on table columns cannot be evaluated against the file
+    pub(crate) fn has_renamed_columns(&self) -> bool {
+        self.renamed
+    }
+
     /// Adapts a `RecordBatch` to match the `table_schema` using the stored mapping and conversions.
     fn map_batch(&self, batch: RecordBatch) -> Result<RecordBatch> {
         let batch_rows = batch.num_rows();
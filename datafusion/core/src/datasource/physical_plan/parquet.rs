@@ -51,7 +51,7 @@ use datafusion_physical_expr::{
 use bytes::Bytes;
 use futures::future::BoxFuture;
 use futures::{StreamExt, TryStreamExt};
-use log::debug;
+use log::{debug, warn};
 use object_store::path::Path;
 use object_store::ObjectStore;
 use parquet::arrow::arrow_reader::ArrowReaderOptions;
@@ -84,6 +84,12 @@ pub struct ParquetExec {
     /// Override for `Self::with_enable_bloom_filter`. If None, uses
     /// values from base_config
     enable_bloom_filter: Option<bool>,
+    /// Override for `Self::with_schema_evolution`. If None, uses
+    /// values from base_config
+    schema_evolution: Option<bool>,
+    /// Override for `Self::with_skip_incompatible_files`. If None, uses
+    /// values from base_config
+    skip_incompatible_files: Option<bool>,
     /// Base configuration for this scan
     base_config: FileScanConfig,
     projected_statistics: Statistics,
@@ -154,6 +160,8 @@ impl ParquetExec {
             reorder_filters: None,
             enable_page_index: None,
             enable_bloom_filter: None,
+            schema_evolution: None,
+            skip_incompatible_files: None,
             base_config,
             projected_schema,
             projected_statistics,
@@ -258,6 +266,35 @@ impl ParquetExec {
         self.enable_bloom_filter
             .unwrap_or(config_options.execution.parquet.bloom_filter_enabled)
     }
+
+    /// If enabled, file columns are matched to table columns by Parquet
+    /// field id when present and only widening casts are applied. Files
+    /// whose schema cannot be reconciled with the table schema fail the
+    /// scan, see [`Self::with_skip_incompatible_files`]
+    pub fn with_schema_evolution(mut self, schema_evolution: bool) -> Self {
+        self.schema_evolution = Some(schema_evolution);
+        self
+    }
+
+    /// Return the value described in [`Self::with_schema_evolution`]
+    fn schema_evolution(&self, config_options: &ConfigOptions) -> bool {
+        self.schema_evolution
+            .unwrap_or(config_options.execution.parquet.schema_evolution)
+    }
+
+    /// If enabled with schema evolution, files whose schema cannot be
+    /// reconciled with the table schema are skipped and counted in the
+    /// `file_schema_incompatible` metric instead of failing the scan
+    pub fn with_skip_incompatible_files(mut self, skip: bool) -> Self {
+        self.skip_incompatible_files = Some(skip);
+        self
+    }
+
+    /// Return the value described in [`Self::with_skip_incompatible_files`]
+    fn skip_incompatible_files(&self, config_options: &ConfigOptions) -> bool {
+        self.skip_incompatible_files
+            .unwrap_or(config_options.execution.parquet.skip_incompatible_files)
+    }
 }
 
 impl DisplayAs for ParquetExec {
@@ -390,6 +427,8 @@ impl ExecutionPlan for ParquetExec {
             reorder_filters: self.reorder_filters(config_options),
             enable_page_index: self.enable_page_index(config_options),
             enable_bloom_filter: self.enable_bloom_filter(config_options),
+            schema_evolution: self.schema_evolution(config_options),
+            skip_incompatible_files: self.skip_incompatible_files(config_options),
         };
 
         let stream =
@@ -424,11 +463,14 @@ struct ParquetOpener {
     reorder_filters: bool,
     enable_page_index: bool,
     enable_bloom_filter: bool,
+    schema_evolution: bool,
+    skip_incompatible_files: bool,
 }
 
 impl FileOpener for ParquetOpener {
     fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
         let file_range = file_meta.range.clone();
+        let file_location = file_meta.location().to_string();
 
         let file_metrics = ParquetFileMetrics::new(
             self.partition_index,
@@ -447,7 +489,10 @@ impl FileOpener for ParquetOpener {
         let batch_size = self.batch_size;
         let projection = self.projection.clone();
         let projected_schema = SchemaRef::from(self.table_schema.project(&projection)?);
-        let schema_adapter = SchemaAdapter::new(projected_schema);
+        let schema_evolution = self.schema_evolution;
+        let skip_incompatible_files = schema_evolution && self.skip_incompatible_files;
+        let schema_adapter =
+            SchemaAdapter::new(projected_schema).with_schema_evolution(schema_evolution);
         let predicate = self.predicate.clone();
         let pruning_predicate = self.pruning_predicate.clone();
         let page_pruning_predicate = self.page_pruning_predicate.clone();
@@ -468,9 +513,26 @@ impl FileOpener for ParquetOpener {
                     .await?;
 
             let (schema_mapping, adapted_projections) =
-                schema_adapter.map_schema(builder.schema())?;
+                match schema_adapter.map_schema(builder.schema()) {
+                    Ok(mapping) => mapping,
+                    Err(e) if skip_incompatible_files => {
+                        warn!("Skipping parquet file {file_location}: {e}");
+                        file_metrics.file_schema_incompatible.add(1);
+                        return Ok(futures::stream::empty().boxed());
+                    }
+                    Err(e) => return Err(e),
+                };
             // let predicate = predicate.map(|p| reassign_predicate_columns(p, builder.schema(), true)).transpose()?;
 
+            // Predicates refer to columns by their table names, which renamed
+            // columns do not have in this file
+            let (predicate, pruning_predicate, page_pruning_predicate) =
+                if schema_mapping.has_renamed_columns() {
+                    (None, None, None)
+                } else {
+                    (predicate, pruning_predicate, page_pruning_predicate)
+                };
+
             let mask = ProjectionMask::roots(
                 builder.parquet_schema(),
                 adapted_projections.iter().cloned(),
@@ -771,6 +833,7 @@ mod tests {
     use crate::datasource::file_format::test_util::scan_format;
     use crate::datasource::listing::{FileRange, PartitionedFile};
     use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::datasource::physical_plan::schema_evolution::FIELD_ID_META_KEY;
     use crate::execution::context::SessionState;
     use crate::physical_plan::displayable;
     use crate::prelude::{ParquetReadOptions, SessionConfig, SessionContext};
@@ -791,13 +854,14 @@ mod tests {
     use chrono::{TimeZone, Utc};
     use datafusion_common::ScalarValue;
     use datafusion_common::{assert_contains, ToDFSchema};
-    use datafusion_expr::{col, lit, when, Expr};
+    use datafusion_expr::{col, json_get_str, lit, when, Expr};
     use datafusion_physical_expr::create_physical_expr;
     use datafusion_physical_expr::execution_props::ExecutionProps;
     use futures::StreamExt;
     use object_store::local::LocalFileSystem;
     use object_store::path::Path;
     use object_store::ObjectMeta;
+    use std::collections::HashMap;
     use std::fs::{self, File};
     use std::io::Write;
     use tempfile::TempDir;
@@ -820,6 +884,8 @@ mod tests {
         predicate: Option<Expr>,
         pushdown_predicate: bool,
         page_index_predicate: bool,
+        schema_evolution: bool,
+        skip_incompatible_files: bool,
     }
 
     impl RoundTrip {
@@ -852,6 +918,16 @@ mod tests {
             self
         }
 
+        fn with_schema_evolution(mut self) -> Self {
+            self.schema_evolution = true;
+            self
+        }
+
+        fn with_skip_incompatible_files(mut self) -> Self {
+            self.skip_incompatible_files = true;
+            self
+        }
+
         /// run the test, returning only the resulting RecordBatches
         async fn round_trip_to_batches(
             self,
@@ -868,6 +944,8 @@ mod tests {
                 predicate,
                 pushdown_predicate,
                 page_index_predicate,
+                schema_evolution,
+                skip_incompatible_files,
             } = self;
 
             let file_schema = match schema {
@@ -915,6 +993,14 @@ mod tests {
                 parquet_exec = parquet_exec.with_enable_page_index(true);
             }
 
+            if schema_evolution {
+                parquet_exec = parquet_exec.with_schema_evolution(true);
+            }
+
+            if skip_incompatible_files {
+                parquet_exec = parquet_exec.with_skip_incompatible_files(true);
+            }
+
             let session_ctx = SessionContext::new();
             let task_ctx = session_ctx.task_ctx();
             let parquet_exec = Arc::new(parquet_exec);
@@ -1310,6 +1396,106 @@ mod tests {
         assert_eq!(get_value(&metrics, "pushdown_rows_filtered"), 5);
     }
 
+    #[tokio::test]
+    async fn evolved_schema_widened_types() {
+        // batch1: c1(int32)
+        let c1: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let batch1 = create_batch(vec![("c1", c1)]);
+
+        // batch2: c1(int64)
+        let c1: ArrayRef = Arc::new(Int64Array::from(vec![i64::MAX]));
+        let batch2 = create_batch(vec![("c1", c1)]);
+
+        let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Int64, true)]));
+        let read = RoundTrip::new()
+            .with_schema(schema)
+            .with_schema_evolution()
+            .round_trip_to_batches(vec![batch1, batch2])
+            .await
+            .unwrap();
+        let expected = [
+            "+---------------------+",
+            "| c1                  |",
+            "+---------------------+",
+            "|                     |",
+            "| 1                   |",
+            "| 9223372036854775807 |",
+            "+---------------------+",
+        ];
+        assert_batches_sorted_eq!(expected, &read);
+    }
+
+    #[tokio::test]
+    async fn evolved_schema_renamed_by_field_id() {
+        let field = |name: &str| {
+            let metadata = [(FIELD_ID_META_KEY.to_string(), "1".to_string())];
+            Field::new(name, DataType::Int64, true).with_metadata(HashMap::from(metadata))
+        };
+        let batch = |field: Field, values: Vec<i64>| {
+            RecordBatch::try_new(
+                Arc::new(Schema::new(vec![field])),
+                vec![Arc::new(Int64Array::from(values))],
+            )
+            .unwrap()
+        };
+
+        // the column was renamed from old to new between the files
+        let batch1 = batch(field("old"), vec![1, 2]);
+        let batch2 = batch(field("new"), vec![1, 3]);
+
+        let filter = col("new").eq(lit(1_i64));
+        let read = RoundTrip::new()
+            .with_schema(Arc::new(Schema::new(vec![field("new")])))
+            .with_predicate(filter)
+            .with_pushdown_predicate()
+            .with_schema_evolution()
+            .round_trip_to_batches(vec![batch1, batch2])
+            .await
+            .unwrap();
+
+        // The predicate refers to `new`, so it is not pushed down into the
+        // file with `old`. The filter stage above the scan would remove 2.
+        let expected = [
+            "+-----+", "| new |", "+-----+", "| 1   |", "| 1   |", "| 2   |", "+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &read);
+    }
+
+    #[tokio::test]
+    async fn evolved_schema_incompatible_file() {
+        // batch1: c1(string)
+        let c1: ArrayRef = Arc::new(StringArray::from(vec![Some("Foo")]));
+        let batch1 = create_batch(vec![("c1", c1)]);
+
+        // batch2: c1(int64)
+        let c1: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), Some(2)]));
+        let batch2 = create_batch(vec![("c1", c1)]);
+
+        let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Int64, true)]));
+        let err = RoundTrip::new()
+            .with_schema(schema.clone())
+            .with_schema_evolution()
+            .round_trip_to_batches(vec![batch1.clone(), batch2.clone()])
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Cannot evolve file schema"),
+            "{err}"
+        );
+
+        let rt = RoundTrip::new()
+            .with_schema(schema)
+            .with_schema_evolution()
+            .with_skip_incompatible_files()
+            .round_trip(vec![batch1, batch2])
+            .await;
+
+        let expected = ["+----+", "| c1 |", "+----+", "| 1  |", "| 2  |", "+----+"];
+        assert_batches_sorted_eq!(expected, &rt.batches.unwrap());
+        let metrics = rt.parquet_exec.metrics().unwrap();
+        assert_eq!(get_value(&metrics, "file_schema_incompatible"), 1);
+    }
+
     #[tokio::test]
     async fn evolved_schema_disjoint_schema_with_page_index_pushdown() {
         let c1: ArrayRef = Arc::new(StringArray::from(vec![
@@ -1829,6 +2015,44 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parquet_exec_prune_json_function() {
+        // one file of click events (and a null) and one of view events
+        let clicks: ArrayRef = Arc::new(StringArray::from(vec![
+            Some(r#"{"type": "click"}"#),
+            None,
+            Some(r#"{"type": "click"}"#),
+        ]));
+        let views: ArrayRef =
+            Arc::new(StringArray::from(vec![Some(r#"{"type": "view"}"#); 3]));
+        let batch1 = create_batch(vec![("payload", clicks)]);
+        let batch2 = create_batch(vec![("payload", views)]);
+
+        // payload->>'type' = 'view'
+        let filter = json_get_str(vec![col("payload"), lit("type")]).eq(lit("view"));
+
+        let rt = RoundTrip::new()
+            .with_predicate(filter)
+            .round_trip(vec![batch1, batch2])
+            .await;
+
+        let metrics = rt.parquet_exec.metrics().unwrap();
+
+        // the row group of click events is pruned, so only view events are
+        // read even though rows are not filtered by the scan
+        let expected = [
+            "+------------------+",
+            "| payload          |",
+            "+------------------+",
+            r#"| {"type": "view"} |"#,
+            r#"| {"type": "view"} |"#,
+            r#"| {"type": "view"} |"#,
+            "+------------------+",
+        ];
+        assert_batches_sorted_eq!(expected, &rt.batches.unwrap());
+        assert_eq!(get_value(&metrics, "row_groups_pruned"), 1);
+    }
+
     #[tokio::test]
     async fn parquet_exec_display() {
         let c1: ArrayRef = Arc::new(StringArray::from(vec![
@@ -41,6 +41,9 @@ pub struct ParquetFileMetrics {
     pub page_index_rows_filtered: Count,
     /// Total time spent evaluating parquet page index filters
     pub page_index_eval_time: Time,
+    /// Set to 1 if the file was skipped because its schema could not be
+    /// reconciled with the table schema
+    pub file_schema_incompatible: Count,
 }
 
 impl ParquetFileMetrics {
@@ -77,6 +80,10 @@ impl ParquetFileMetrics {
             .with_new_label("filename", filename.to_string())
             .subset_time("page_index_eval_time", partition);
 
+        let file_schema_incompatible = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("file_schema_incompatible", partition);
+
         Self {
             predicate_evaluation_errors,
             row_groups_pruned,
@@ -85,6 +92,7 @@ impl ParquetFileMetrics {
             pushdown_eval_time,
             page_index_rows_filtered,
             page_index_eval_time,
+            file_schema_incompatible,
         }
     }
 }
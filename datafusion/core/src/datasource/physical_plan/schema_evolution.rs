@@ -0,0 +1,267 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reconciliation of file schemas that evolved over the lifetime of a table,
+//! enabled by `datafusion.execution.parquet.schema_evolution`
+//!
+//! Columns are matched by Parquet field id when both schemas carry field ids,
+//! which keeps renamed columns attached to their data, and by name otherwise.
+//! Only widening casts that cannot lose information are applied.
+
+use std::collections::HashMap;
+
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, Schema};
+use datafusion_common::{plan_err, Result};
+use log::warn;
+
+/// Field metadata key under which the `parquet` crate stores Parquet field ids
+pub(crate) const FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
+/// Returns the Parquet field id of `field`, if any
+pub(crate) fn field_id(field: &Field) -> Option<&str> {
+    field.metadata().get(FIELD_ID_META_KEY).map(String::as_str)
+}
+
+/// Finds the field of `schema` corresponding to `field`
+///
+/// If `field` has a field id and `schema` carries field ids, only a field with
+/// the same id matches, so that a column dropped and re-added under the same
+/// name is not mistaken for the original. Otherwise fields are matched by name.
+pub(crate) fn find_field<'a>(
+    schema: &'a Schema,
+    field: &Field,
+) -> Option<(usize, &'a FieldRef)> {
+    match field_id(field) {
+        Some(id) if schema.fields().iter().any(|f| field_id(f).is_some()) => schema
+            .fields()
+            .iter()
+            .enumerate()
+            .find(|(_, f)| field_id(f) == Some(id)),
+        _ => schema.fields().find(field.name()),
+    }
+}
+
+/// Returns true if every value of type `from` can be cast to `to` without
+/// loss of information
+pub(crate) fn is_safe_upcast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+
+    if from == to {
+        return true;
+    }
+    match (from, to) {
+        (Int8, Int16 | Int32 | Int64)
+        | (Int16, Int32 | Int64)
+        | (Int32, Int64)
+        | (UInt8, UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64)
+        | (UInt16, UInt32 | UInt64 | Int32 | Int64)
+        | (UInt32, UInt64 | Int64)
+        | (Int8 | Int16 | UInt8 | UInt16, Float32 | Float64)
+        | (Int32 | UInt32, Float64)
+        | (Float16, Float32 | Float64)
+        | (Float32, Float64)
+        | (Date32, Date64)
+        | (Utf8, LargeUtf8)
+        | (Binary, LargeBinary) => true,
+        (Decimal128(p1, s1), Decimal128(p2, s2) | Decimal256(p2, s2))
+        | (Decimal256(p1, s1), Decimal256(p2, s2)) => {
+            // Neither the integer nor the fractional digits may shrink
+            s2 >= s1 && (*p2 as i16 - *s2 as i16) >= (*p1 as i16 - *s1 as i16)
+        }
+        (List(f1) | LargeList(f1), LargeList(f2)) | (List(f1), List(f2)) => {
+            is_safe_upcast(f1.data_type(), f2.data_type())
+        }
+        (Dictionary(k1, v1), Dictionary(k2, v2)) => {
+            is_safe_upcast(k1, k2) && is_safe_upcast(v1, v2)
+        }
+        (Dictionary(_, v), _) => is_safe_upcast(v, to),
+        _ => false,
+    }
+}
+
+/// Merges the schemas of the files of a table into its table schema
+///
+/// `schemas` are expected in the order the files were written. Columns are
+/// matched with [`find_field`]; a renamed column takes its latest name and a
+/// column whose type was widened takes the widest type. A column whose types
+/// cannot be reconciled is an error, unless `skip_incompatible_files` is set,
+/// in which case it keeps its first type and the files with other types are
+/// skipped when scanned.
+pub(crate) fn merge_evolved_schemas(
+    schemas: impl IntoIterator<Item = Schema>,
+    skip_incompatible_files: bool,
+) -> Result<Schema> {
+    let mut fields: Vec<Field> = vec![];
+    let mut metadata = HashMap::new();
+
+    for schema in schemas {
+        for file_field in schema.fields() {
+            let merged = Schema::new(fields.clone());
+            match find_field(&merged, file_field).map(|(idx, _)| idx) {
+                Some(idx) => {
+                    let field = &fields[idx];
+                    let data_type = if is_safe_upcast(
+                        field.data_type(),
+                        file_field.data_type(),
+                    ) {
+                        file_field.data_type().clone()
+                    } else {
+                        if !is_safe_upcast(file_field.data_type(), field.data_type()) {
+                            if !skip_incompatible_files {
+                                return plan_err!(
+                                    "Cannot reconcile type {} of column {} with type {} of a later file",
+                                    field.data_type(),
+                                    field.name(),
+                                    file_field.data_type()
+                                );
+                            }
+                            warn!(
+                                "Cannot reconcile type {} of column {} with type {}, files with the latter are skipped",
+                                field.data_type(),
+                                field.name(),
+                                file_field.data_type()
+                            );
+                        }
+                        field.data_type().clone()
+                    };
+                    let mut field_metadata = field.metadata().clone();
+                    field_metadata.extend(file_field.metadata().clone());
+                    fields[idx] = Field::new(
+                        file_field.name(),
+                        data_type,
+                        field.is_nullable() || file_field.is_nullable(),
+                    )
+                    .with_metadata(field_metadata);
+                }
+                None => fields.push(file_field.as_ref().clone()),
+            }
+        }
+        metadata.extend(schema.metadata().clone());
+
+        // A column dropped and re-added, or renamed to the name of a dropped
+        // column, supersedes the column that previously had its name
+        let mut superseded = vec![false; fields.len()];
+        for file_field in schema.fields() {
+            for (idx, field) in fields.iter().enumerate() {
+                if field.name() == file_field.name()
+                    && field_id(field) != field_id(file_field)
+                {
+                    superseded[idx] = true;
+                }
+            }
+        }
+        let mut superseded = superseded.into_iter();
+        fields.retain(|_| !superseded.next().unwrap_or(false));
+    }
+
+    Ok(Schema::new(Fields::from(fields)).with_metadata(metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_with_id(name: &str, data_type: DataType, id: &str) -> Field {
+        let metadata = [(FIELD_ID_META_KEY.to_string(), id.to_string())];
+        Field::new(name, data_type, true).with_metadata(HashMap::from(metadata))
+    }
+
+    #[test]
+    fn safe_upcasts() {
+        assert!(is_safe_upcast(&DataType::Int32, &DataType::Int64));
+        assert!(is_safe_upcast(&DataType::Float32, &DataType::Float64));
+        assert!(is_safe_upcast(
+            &DataType::Decimal128(10, 2),
+            &DataType::Decimal128(12, 2)
+        ));
+        assert!(is_safe_upcast(
+            &DataType::Decimal128(10, 2),
+            &DataType::Decimal128(12, 4)
+        ));
+
+        assert!(!is_safe_upcast(&DataType::Int64, &DataType::Int32));
+        assert!(!is_safe_upcast(&DataType::Int64, &DataType::Float64));
+        assert!(!is_safe_upcast(&DataType::Utf8, &DataType::Int64));
+        assert!(!is_safe_upcast(
+            &DataType::Decimal128(10, 2),
+            &DataType::Decimal128(10, 4)
+        ));
+    }
+
+    #[test]
+    fn find_field_by_id() {
+        let schema = Schema::new(vec![
+            field_with_id("a", DataType::Int64, "1"),
+            field_with_id("b", DataType::Int64, "2"),
+        ]);
+
+        let renamed = field_with_id("c", DataType::Int64, "2");
+        assert_eq!(find_field(&schema, &renamed).unwrap().0, 1);
+
+        // Same name, but a different column
+        let readded = field_with_id("a", DataType::Int64, "3");
+        assert!(find_field(&schema, &readded).is_none());
+
+        let without_id = Field::new("b", DataType::Int64, true);
+        assert_eq!(find_field(&schema, &without_id).unwrap().0, 1);
+    }
+
+    #[test]
+    fn merge_schemas() {
+        let schemas = vec![
+            Schema::new(vec![
+                field_with_id("a", DataType::Int32, "1"),
+                field_with_id("b", DataType::Float32, "2"),
+                field_with_id("c", DataType::Utf8, "3"),
+            ]),
+            Schema::new(vec![
+                field_with_id("a", DataType::Int64, "1"),
+                field_with_id("b_renamed", DataType::Float64, "2"),
+                field_with_id("c", DataType::Int64, "4"),
+                field_with_id("d", DataType::Utf8, "5"),
+            ]),
+        ];
+
+        let expected = Schema::new(vec![
+            field_with_id("a", DataType::Int64, "1"),
+            field_with_id("b_renamed", DataType::Float64, "2"),
+            field_with_id("c", DataType::Int64, "4"),
+            field_with_id("d", DataType::Utf8, "5"),
+        ]);
+        assert_eq!(merge_evolved_schemas(schemas, false).unwrap(), expected);
+    }
+
+    #[test]
+    fn merge_incompatible_schemas() {
+        let schemas = || {
+            vec![
+                Schema::new(vec![field_with_id("a", DataType::Utf8, "1")]),
+                Schema::new(vec![field_with_id("a", DataType::Int64, "1")]),
+            ]
+        };
+
+        let err = merge_evolved_schemas(schemas(), false).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cannot reconcile type Utf8 of column a with type Int64"),
+            "{err}"
+        );
+
+        let expected = Schema::new(vec![field_with_id("a", DataType::Utf8, "1")]);
+        assert_eq!(merge_evolved_schemas(schemas(), true).unwrap(), expected);
+    }
+}
@@ -21,8 +21,10 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use datafusion_common::{not_impl_err, Constraints, DataFusionError, Statistics};
-use datafusion_expr::{CreateExternalTable, LogicalPlan};
+use datafusion_common::{
+    not_impl_err, plan_err, Constraints, DataFusionError, Statistics,
+};
+use datafusion_expr::{AsOf, CreateExternalTable, LogicalPlan};
 pub use datafusion_expr::{TableProviderFilterPushDown, TableType};
 
 use crate::arrow::datatypes::SchemaRef;
@@ -64,6 +66,15 @@ pub trait TableProvider: Sync + Send {
         None
     }
 
+    /// Returns a provider reading the version of this table selected by
+    /// `as_of`, for `FROM t VERSION AS OF ...` and
+    /// `FROM t FOR SYSTEM_TIME AS OF ...`.
+    ///
+    /// Returns an error by default, for tables without versions.
+    fn as_of(&self, as_of: &AsOf) -> Result<Arc<dyn TableProvider>> {
+        plan_err!("Time travel ({as_of}) is not supported by this table")
+    }
+
     /// Create an [`ExecutionPlan`] for scanning the table with optionally
     /// specified `projection`, `filter` and `limit`, described below.
     ///
@@ -0,0 +1,651 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The log of commits of a [`SnapshotTable`](super::SnapshotTable).
+//!
+//! Every commit is an Arrow IPC file named after its version in the
+//! `_snapshot_log` directory of the table. Each row of the file is an
+//! [`Action`], and the version and commit time are stored in the schema
+//! metadata. A commit is made visible atomically by renaming a temporary
+//! file to the name of the next version, which fails if another writer
+//! committed that version first.
+//!
+//! Every [`CHECKPOINT_INTERVAL`] versions, the snapshot of the version is
+//! also written to a checkpoint file in the log, so that reading a
+//! snapshot only replays the commits made since the latest checkpoint.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, ListBuilder, StringArray, StringBuilder, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use bytes::Bytes;
+use datafusion_common::cast::{as_list_array, as_string_array, as_uint64_array};
+use datafusion_common::{exec_err, DataFusionError, Result};
+use futures::TryStreamExt;
+use log::debug;
+use object_store::path::Path;
+use object_store::ObjectStore;
+use uuid::Uuid;
+
+/// Directory of the table containing the commit log
+pub(crate) const LOG_DIR: &str = "_snapshot_log";
+
+/// Name of the file in [`LOG_DIR`] containing the schema of the table
+const SCHEMA_FILE: &str = "schema.arrow";
+
+/// Suffix of the names of the checkpoint files in [`LOG_DIR`]
+const CHECKPOINT_SUFFIX: &str = ".checkpoint.arrow";
+
+/// Number of versions between two checkpoints of the log
+pub const CHECKPOINT_INTERVAL: u64 = 10;
+
+const VERSION_META_KEY: &str = "snapshot.version";
+const TIMESTAMP_META_KEY: &str = "snapshot.timestamp";
+
+/// A change to the files of a table, recorded by a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Adds a data file with `num_rows` rows
+    Add { path: String, num_rows: u64 },
+    /// Removes a data file and its delete files
+    Remove { path: String },
+    /// Adds a file of `num_rows` positions of rows deleted from the
+    /// data file `target`
+    Delete {
+        path: String,
+        target: String,
+        num_rows: u64,
+    },
+}
+
+impl Action {
+    /// The data file this action requires to exist when it is committed
+    fn required_file(&self) -> Option<&str> {
+        match self {
+            Action::Add { .. } => None,
+            Action::Remove { path } => Some(path),
+            Action::Delete { target, .. } => Some(target),
+        }
+    }
+}
+
+/// A data file of a [`Snapshot`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFile {
+    /// Path of the file, relative to the table location
+    pub path: String,
+    /// Number of rows in the file, including deleted rows
+    pub num_rows: u64,
+    /// Paths of the files of positions of deleted rows, relative to the
+    /// table location
+    pub deletes: Vec<String>,
+    /// Number of deleted rows
+    pub num_deleted_rows: u64,
+}
+
+impl DataFile {
+    /// Number of rows in the file, excluding deleted rows
+    pub fn num_live_rows(&self) -> u64 {
+        self.num_rows.saturating_sub(self.num_deleted_rows)
+    }
+}
+
+/// The state of a table after a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The version of the commit
+    pub version: u64,
+    /// Time of the commit, in milliseconds since the epoch
+    pub timestamp_millis: i64,
+    /// The data files of the table, in the order they were added
+    pub files: Vec<DataFile>,
+}
+
+impl Snapshot {
+    /// Number of rows in the table, excluding deleted rows
+    pub fn num_rows(&self) -> u64 {
+        self.files.iter().map(DataFile::num_live_rows).sum()
+    }
+
+    fn apply(&mut self, actions: Vec<Action>) -> Result<()> {
+        for action in actions {
+            match action {
+                Action::Add { path, num_rows } => self.files.push(DataFile {
+                    path,
+                    num_rows,
+                    deletes: vec![],
+                    num_deleted_rows: 0,
+                }),
+                Action::Remove { path } => {
+                    let index = self.file_index(&path)?;
+                    self.files.remove(index);
+                }
+                Action::Delete {
+                    path,
+                    target,
+                    num_rows,
+                } => {
+                    let index = self.file_index(&target)?;
+                    let file = &mut self.files[index];
+                    // commits never delete a row twice, see `commit`
+                    let num_deleted_rows = file.num_deleted_rows + num_rows;
+                    if num_deleted_rows > file.num_rows {
+                        return exec_err!(
+                            "Commit {} of snapshot table deletes {num_deleted_rows} rows \
+                             from data file {target} of {} rows",
+                            self.version,
+                            file.num_rows
+                        );
+                    }
+                    file.deletes.push(path);
+                    file.num_deleted_rows = num_deleted_rows;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn file_index(&self, path: &str) -> Result<usize> {
+        match self.files.iter().position(|file| file.path == path) {
+            Some(index) => Ok(index),
+            None => exec_err!(
+                "Commit {} of snapshot table refers to unknown data file {path}",
+                self.version
+            ),
+        }
+    }
+}
+
+/// A commit read from the log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    /// The version of the commit
+    pub version: u64,
+    /// Time of the commit, in milliseconds since the epoch
+    pub timestamp_millis: i64,
+    /// The changes made by the commit
+    pub actions: Vec<Action>,
+}
+
+fn log_dir(table: &Path) -> Path {
+    table.child(LOG_DIR)
+}
+
+fn commit_path(table: &Path, version: u64) -> Path {
+    log_dir(table).child(format!("{version:020}.arrow"))
+}
+
+fn checkpoint_path(table: &Path, version: u64) -> Path {
+    log_dir(table).child(format!("{version:020}{CHECKPOINT_SUFFIX}"))
+}
+
+fn actions_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("action", DataType::Utf8, false),
+        Field::new("path", DataType::Utf8, false),
+        Field::new("target", DataType::Utf8, true),
+        Field::new("num_rows", DataType::UInt64, true),
+    ])
+}
+
+fn checkpoint_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("path", DataType::Utf8, false),
+        Field::new("num_rows", DataType::UInt64, false),
+        Field::new(
+            "deletes",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        Field::new("num_deleted_rows", DataType::UInt64, false),
+    ])
+}
+
+fn to_ipc(schema: &SchemaRef, batches: &[RecordBatch]) -> Result<Bytes> {
+    let mut writer = FileWriter::try_new(Vec::new(), schema)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner()?.into())
+}
+
+/// Writes `bytes` to `to` in the log of `table`, failing with
+/// [`object_store::Error::AlreadyExists`] if `to` already exists
+async fn put_if_not_exists(
+    store: &dyn ObjectStore,
+    table: &Path,
+    to: &Path,
+    bytes: Bytes,
+) -> Result<()> {
+    let tmp = log_dir(table).child(format!("_tmp_{}", Uuid::new_v4()));
+    store.put(&tmp, bytes).await?;
+    let result = store.rename_if_not_exists(&tmp, to).await;
+    if result.is_err() {
+        // the temporary file is left behind if the rename did not move it
+        let _ = store.delete(&tmp).await;
+    }
+    result.map_err(DataFusionError::from)
+}
+
+fn is_already_exists(e: &DataFusionError) -> bool {
+    matches!(
+        e,
+        DataFusionError::ObjectStore(object_store::Error::AlreadyExists { .. })
+    )
+}
+
+/// Creates the log of a new table with the given schema, committing an
+/// empty version 0
+pub async fn create(
+    store: &dyn ObjectStore,
+    table: &Path,
+    schema: &SchemaRef,
+) -> Result<()> {
+    let schema_bytes = to_ipc(schema, &[])?;
+    match put_if_not_exists(
+        store,
+        table,
+        &log_dir(table).child(SCHEMA_FILE),
+        schema_bytes,
+    )
+    .await
+    {
+        Err(e) if is_already_exists(&e) => {
+            return exec_err!("Snapshot table already exists at {table}")
+        }
+        result => result?,
+    }
+    write_commit(store, table, 0, &[]).await
+}
+
+/// Reads the schema of the table
+pub async fn read_schema(store: &dyn ObjectStore, table: &Path) -> Result<SchemaRef> {
+    let path = log_dir(table).child(SCHEMA_FILE);
+    let bytes = match store.get(&path).await {
+        Ok(result) => result.bytes().await?,
+        Err(object_store::Error::NotFound { .. }) => {
+            return exec_err!("No snapshot table found at {table}")
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let reader = FileReader::try_new(Cursor::new(bytes), None)?;
+    Ok(reader.schema())
+}
+
+/// Returns the versions of all commits, in ascending order
+pub async fn list_versions(store: &dyn ObjectStore, table: &Path) -> Result<Vec<u64>> {
+    list_log(store, table, ".arrow").await
+}
+
+/// Returns the versions of all checkpoints, in ascending order
+pub async fn list_checkpoints(store: &dyn ObjectStore, table: &Path) -> Result<Vec<u64>> {
+    list_log(store, table, CHECKPOINT_SUFFIX).await
+}
+
+/// Returns the versions of the files of the log named `<version><suffix>`,
+/// in ascending order
+async fn list_log(
+    store: &dyn ObjectStore,
+    table: &Path,
+    suffix: &str,
+) -> Result<Vec<u64>> {
+    let log_dir = log_dir(table);
+    let mut versions: Vec<u64> = store
+        .list(Some(&log_dir))
+        .await?
+        .try_filter_map(|meta| async move {
+            Ok(meta
+                .location
+                .filename()
+                .and_then(|name| name.strip_suffix(suffix))
+                .and_then(|version| version.parse().ok()))
+        })
+        .try_collect()
+        .await?;
+    versions.sort_unstable();
+    Ok(versions)
+}
+
+/// Returns the version of the latest commit
+pub async fn latest_version(store: &dyn ObjectStore, table: &Path) -> Result<u64> {
+    match list_versions(store, table).await?.last() {
+        Some(version) => Ok(*version),
+        None => exec_err!("No snapshot table found at {table}"),
+    }
+}
+
+/// Reads the commit of `version`
+pub async fn read_commit(
+    store: &dyn ObjectStore,
+    table: &Path,
+    version: u64,
+) -> Result<Commit> {
+    let bytes = match store.get(&commit_path(table, version)).await {
+        Ok(result) => result.bytes().await?,
+        Err(object_store::Error::NotFound { .. }) => {
+            return exec_err!(
+                "Version {version} of snapshot table {table} does not exist"
+            )
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let reader = FileReader::try_new(Cursor::new(bytes), None)?;
+    let metadata = reader.schema().metadata().clone();
+    let timestamp_millis = metadata
+        .get(TIMESTAMP_META_KEY)
+        .and_then(|timestamp| timestamp.parse().ok())
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "Commit {version} of snapshot table {table} has no timestamp"
+            ))
+        })?;
+
+    let mut actions = vec![];
+    for batch in reader {
+        let batch = batch?;
+        let action = as_string_array(batch.column(0))?;
+        let path = as_string_array(batch.column(1))?;
+        let target = as_string_array(batch.column(2))?;
+        let num_rows = as_uint64_array(batch.column(3))?;
+        for row in 0..batch.num_rows() {
+            let path = path.value(row).to_string();
+            actions.push(match action.value(row) {
+                "add" => Action::Add {
+                    path,
+                    num_rows: num_rows.value(row),
+                },
+                "remove" => Action::Remove { path },
+                "delete" => Action::Delete {
+                    path,
+                    target: target.value(row).to_string(),
+                    num_rows: num_rows.value(row),
+                },
+                other => {
+                    return exec_err!(
+                        "Unknown action {other} in commit {version} of snapshot table {table}"
+                    )
+                }
+            });
+        }
+    }
+    Ok(Commit {
+        version,
+        timestamp_millis,
+        actions,
+    })
+}
+
+/// Reads the snapshot of `version`, or of the latest version if `None`
+pub async fn read_snapshot(
+    store: &dyn ObjectStore,
+    table: &Path,
+    version: Option<u64>,
+) -> Result<Snapshot> {
+    let version = match version {
+        Some(version) => version,
+        None => latest_version(store, table).await?,
+    };
+    let checkpoint = list_checkpoints(store, table)
+        .await?
+        .into_iter()
+        .filter(|checkpoint| *checkpoint <= version)
+        .last();
+    let (mut snapshot, first) = match checkpoint {
+        Some(checkpoint) => (
+            read_checkpoint(store, table, checkpoint).await?,
+            checkpoint + 1,
+        ),
+        None => (
+            Snapshot {
+                version: 0,
+                timestamp_millis: 0,
+                files: vec![],
+            },
+            0,
+        ),
+    };
+    for v in first..=version {
+        let commit = read_commit(store, table, v).await?;
+        snapshot.version = commit.version;
+        snapshot.timestamp_millis = commit.timestamp_millis;
+        snapshot.apply(commit.actions)?;
+    }
+    Ok(snapshot)
+}
+
+/// Reads the snapshot stored by the checkpoint of `version`
+async fn read_checkpoint(
+    store: &dyn ObjectStore,
+    table: &Path,
+    version: u64,
+) -> Result<Snapshot> {
+    let bytes = store
+        .get(&checkpoint_path(table, version))
+        .await?
+        .bytes()
+        .await?;
+    let reader = FileReader::try_new(Cursor::new(bytes), None)?;
+    let timestamp_millis = reader
+        .schema()
+        .metadata()
+        .get(TIMESTAMP_META_KEY)
+        .and_then(|timestamp| timestamp.parse().ok())
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "Checkpoint {version} of snapshot table {table} has no timestamp"
+            ))
+        })?;
+
+    let mut files = vec![];
+    for batch in reader {
+        let batch = batch?;
+        let path = as_string_array(batch.column(0))?;
+        let num_rows = as_uint64_array(batch.column(1))?;
+        let deletes = as_list_array(batch.column(2))?;
+        let num_deleted_rows = as_uint64_array(batch.column(3))?;
+        for row in 0..batch.num_rows() {
+            let file_deletes = deletes.value(row);
+            let file_deletes = as_string_array(&file_deletes)?;
+            files.push(DataFile {
+                path: path.value(row).to_string(),
+                num_rows: num_rows.value(row),
+                deletes: file_deletes.iter().flatten().map(str::to_string).collect(),
+                num_deleted_rows: num_deleted_rows.value(row),
+            });
+        }
+    }
+    Ok(Snapshot {
+        version,
+        timestamp_millis,
+        files,
+    })
+}
+
+/// Writes a checkpoint of the snapshot of `version`
+async fn write_checkpoint(
+    store: &dyn ObjectStore,
+    table: &Path,
+    version: u64,
+) -> Result<()> {
+    let snapshot = read_snapshot(store, table, Some(version)).await?;
+    let mut deletes = ListBuilder::new(StringBuilder::new());
+    for file in &snapshot.files {
+        for path in &file.deletes {
+            deletes.values().append_value(path);
+        }
+        deletes.append(true);
+    }
+    let metadata = HashMap::from([
+        (VERSION_META_KEY.to_string(), version.to_string()),
+        (
+            TIMESTAMP_META_KEY.to_string(),
+            snapshot.timestamp_millis.to_string(),
+        ),
+    ]);
+    let schema = Arc::new(checkpoint_schema().with_metadata(metadata));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            snapshot.files.iter().map(|file| &file.path),
+        )),
+        Arc::new(UInt64Array::from_iter_values(
+            snapshot.files.iter().map(|file| file.num_rows),
+        )),
+        Arc::new(deletes.finish()),
+        Arc::new(UInt64Array::from_iter_values(
+            snapshot.files.iter().map(|file| file.num_deleted_rows),
+        )),
+    ];
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    let bytes = to_ipc(&schema, &[batch])?;
+    put_if_not_exists(store, table, &checkpoint_path(table, version), bytes).await
+}
+
+/// Returns the latest version committed at or before `timestamp_millis`
+pub async fn version_as_of(
+    store: &dyn ObjectStore,
+    table: &Path,
+    timestamp_millis: i64,
+) -> Result<u64> {
+    let mut found = None;
+    for version in list_versions(store, table).await? {
+        let commit = read_commit(store, table, version).await?;
+        if commit.timestamp_millis > timestamp_millis {
+            break;
+        }
+        found = Some(version);
+    }
+    match found {
+        Some(version) => Ok(version),
+        None => exec_err!(
+            "Snapshot table {table} has no version committed at or before {timestamp_millis}"
+        ),
+    }
+}
+
+async fn write_commit(
+    store: &dyn ObjectStore,
+    table: &Path,
+    version: u64,
+    actions: &[Action],
+) -> Result<()> {
+    let mut action_names = Vec::with_capacity(actions.len());
+    let mut paths = Vec::with_capacity(actions.len());
+    let mut targets = Vec::with_capacity(actions.len());
+    let mut num_rows = Vec::with_capacity(actions.len());
+    for action in actions {
+        let (name, path, target, rows) = match action {
+            Action::Add { path, num_rows } => ("add", path, None, Some(*num_rows)),
+            Action::Remove { path } => ("remove", path, None, None),
+            Action::Delete {
+                path,
+                target,
+                num_rows,
+            } => ("delete", path, Some(target.as_str()), Some(*num_rows)),
+        };
+        action_names.push(name);
+        paths.push(path.as_str());
+        targets.push(target);
+        num_rows.push(rows);
+    }
+
+    let metadata = HashMap::from([
+        (VERSION_META_KEY.to_string(), version.to_string()),
+        (
+            TIMESTAMP_META_KEY.to_string(),
+            chrono::Utc::now().timestamp_millis().to_string(),
+        ),
+    ]);
+    let schema = Arc::new(actions_schema().with_metadata(metadata));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(action_names)),
+        Arc::new(StringArray::from(paths)),
+        Arc::new(StringArray::from(targets)),
+        Arc::new(UInt64Array::from(num_rows)),
+    ];
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    let bytes = to_ipc(&schema, &[batch])?;
+    put_if_not_exists(store, table, &commit_path(table, version), bytes).await
+}
+
+/// Commits `actions` made by a writer that read the snapshot of
+/// `read_version`, returning the committed version.
+///
+/// If other writers committed since `read_version`, the commit is retried
+/// with the next version, unless one of them removed or deleted rows from
+/// a data file that `actions` refer to. Two concurrent deletes from the
+/// same data file conflict as they may delete the same rows, which would
+/// then be counted twice.
+pub async fn commit(
+    store: &dyn ObjectStore,
+    table: &Path,
+    read_version: u64,
+    actions: Vec<Action>,
+) -> Result<u64> {
+    let required: HashSet<&str> =
+        actions.iter().filter_map(Action::required_file).collect();
+    let mut checked_version = read_version;
+    let mut version = latest_version(store, table).await?.max(read_version) + 1;
+    loop {
+        // check the commits made since the last check for conflicts
+        for v in checked_version + 1..version {
+            let commit = read_commit(store, table, v).await?;
+            for action in commit.actions {
+                match action {
+                    Action::Remove { path } if required.contains(path.as_str()) => {
+                        return exec_err!(
+                            "Conflicting commit: data file {path} of snapshot table {table} \
+                             was removed by version {v}"
+                        );
+                    }
+                    Action::Delete { target, .. }
+                        if required.contains(target.as_str()) =>
+                    {
+                        return exec_err!(
+                            "Conflicting commit: rows of data file {target} of snapshot table \
+                             {table} were deleted by version {v}"
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+        checked_version = version - 1;
+
+        match write_commit(store, table, version, &actions).await {
+            Ok(()) => {
+                if version % CHECKPOINT_INTERVAL == 0 {
+                    // checkpoints only speed up reading snapshots, so the
+                    // commit succeeds even if the checkpoint is not written
+                    if let Err(e) = write_checkpoint(store, table, version).await {
+                        debug!(
+                            "Failed to write checkpoint {version} of snapshot table {table}: {e}"
+                        );
+                    }
+                }
+                return Ok(version);
+            }
+            Err(e) if is_already_exists(&e) => version += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
@@ -0,0 +1,853 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Transactional table format with snapshot isolation, time travel and
+//! positional deletes.
+//!
+//! A [`SnapshotTable`] stores its rows in Arrow IPC data files and
+//! records which files make up each version of the table in a log of
+//! commits. Every scan reads a consistent snapshot of the table, and
+//! concurrent writers are serialized by the atomic creation of commit
+//! files, see [`manifest`] for the layout of the log.
+
+pub mod manifest;
+mod scan;
+
+use std::any::Any;
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::{exec_err, plan_err, DFSchema, DEFAULT_ARROW_EXTENSION};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::create_physical_expr;
+use futures::StreamExt;
+use object_store::ObjectStore;
+use uuid::Uuid;
+
+use self::manifest::{Action, Commit, Snapshot};
+use self::scan::{file_location, read_deleted_positions, read_file, POSITION_COLUMN};
+use crate::datasource::listing::ListingTableUrl;
+use crate::datasource::{TableProvider, TableType};
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::logical_expr::{AsOf, Expr};
+use crate::physical_plan::insert::{DataSink, FileSinkExec};
+use crate::physical_plan::metrics::MetricsSet;
+use crate::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, SendableRecordBatchStream,
+};
+
+pub use self::scan::SnapshotScanExec;
+
+/// Directory of the table containing the data files
+const DATA_DIR: &str = "data";
+/// Directory of the table containing the files of positions of deleted rows
+const DELETES_DIR: &str = "deletes";
+
+/// A table whose changes are committed atomically to a log of versions.
+///
+/// Scans read the latest version of the table when they are planned, or
+/// the version selected with [`SnapshotTable::with_version`],
+/// [`SnapshotTable::with_timestamp`] or `VERSION AS OF` and
+/// `FOR SYSTEM_TIME AS OF` in SQL, and are not
+/// affected by commits made while they run. Rows are added with
+/// `INSERT INTO` and removed with [`SnapshotTable::delete`], which writes
+/// the positions of the deleted rows instead of rewriting data files.
+///
+/// Commits rely on [`ObjectStore::rename_if_not_exists`], which is
+/// supported by the local file system and in-memory object stores.
+#[derive(Debug, Clone)]
+pub struct SnapshotTable {
+    /// Location of the table
+    location: ListingTableUrl,
+    /// Schema of the table
+    schema: SchemaRef,
+    /// The version read by scans, or `None` for the latest version
+    as_of: Option<AsOf>,
+}
+
+impl SnapshotTable {
+    /// Create a new empty table with the given schema at `location`,
+    /// failing if a table already exists there
+    pub async fn try_create(
+        state: &SessionState,
+        location: ListingTableUrl,
+        schema: SchemaRef,
+    ) -> Result<Self> {
+        let store = state.runtime_env().object_store(&location)?;
+        manifest::create(store.as_ref(), location.prefix(), &schema).await?;
+        Ok(Self {
+            location,
+            schema,
+            as_of: None,
+        })
+    }
+
+    /// Open the existing table at `location`
+    pub async fn try_open(
+        state: &SessionState,
+        location: ListingTableUrl,
+    ) -> Result<Self> {
+        let store = state.runtime_env().object_store(&location)?;
+        let schema = manifest::read_schema(store.as_ref(), location.prefix()).await?;
+        Ok(Self {
+            location,
+            schema,
+            as_of: None,
+        })
+    }
+
+    /// Returns a table reading `version` of this table. The returned
+    /// table cannot be written to.
+    pub fn with_version(mut self, version: u64) -> Self {
+        self.as_of = Some(AsOf::Version(version));
+        self
+    }
+
+    /// Returns a table reading the latest version of this table committed
+    /// at or before `timestamp_millis`, in milliseconds since the epoch,
+    /// which is looked up when the table is scanned. The returned table
+    /// cannot be written to.
+    ///
+    /// Errors if the timestamp is out of the range of nanosecond timestamps.
+    pub fn with_timestamp(mut self, timestamp_millis: i64) -> Result<Self> {
+        let Some(nanos) = timestamp_millis.checked_mul(1_000_000) else {
+            return plan_err!(
+                "Timestamp {timestamp_millis} of snapshot table is out of range"
+            );
+        };
+        self.as_of = Some(AsOf::Timestamp(nanos));
+        Ok(self)
+    }
+
+    /// Location of the table
+    pub fn location(&self) -> &ListingTableUrl {
+        &self.location
+    }
+
+    /// Reads the snapshot of the table read by scans
+    pub async fn snapshot(&self, state: &SessionState) -> Result<Snapshot> {
+        let store = self.store(state)?;
+        let table_path = self.location.prefix();
+        let version = match self.as_of {
+            None => None,
+            Some(AsOf::Version(version)) => Some(version),
+            Some(AsOf::Timestamp(nanos)) => Some(
+                manifest::version_as_of(
+                    store.as_ref(),
+                    table_path,
+                    nanos.div_euclid(1_000_000),
+                )
+                .await?,
+            ),
+        };
+        manifest::read_snapshot(store.as_ref(), table_path, version).await
+    }
+
+    /// Reads all commits of the table, in ascending order of version
+    pub async fn history(&self, state: &SessionState) -> Result<Vec<Commit>> {
+        let store = self.store(state)?;
+        let table_path = self.location.prefix();
+        let mut commits = vec![];
+        for version in manifest::list_versions(store.as_ref(), table_path).await? {
+            commits
+                .push(manifest::read_commit(store.as_ref(), table_path, version).await?);
+        }
+        Ok(commits)
+    }
+
+    /// Deletes the rows for which `predicate` is true, returning the
+    /// number of deleted rows.
+    ///
+    /// The positions of the deleted rows of each data file are written to
+    /// a new file and committed as a new version, which fails if a
+    /// concurrent commit removed one of the data files.
+    pub async fn delete(&self, state: &SessionState, predicate: Expr) -> Result<u64> {
+        self.check_writable()?;
+        let store = self.store(state)?;
+        let table_path = self.location.prefix();
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        let predicate = create_physical_expr(
+            &predicate,
+            &df_schema,
+            &self.schema,
+            state.execution_props(),
+        )?;
+
+        let mut actions = vec![];
+        let mut num_deleted = 0;
+        for file in &snapshot.files {
+            let deleted =
+                read_deleted_positions(store.as_ref(), table_path, file).await?;
+            let mut positions = vec![];
+            let mut offset = 0;
+            for batch in read_file(store.as_ref(), table_path, &file.path, None).await? {
+                let batch = batch?;
+                let matches = predicate.evaluate(&batch)?.into_array(batch.num_rows())?;
+                let matches = as_boolean_array(&matches)?;
+                for row in 0..batch.num_rows() {
+                    let position = offset + row as u64;
+                    if matches.is_valid(row)
+                        && matches.value(row)
+                        && !deleted.contains(&position)
+                    {
+                        positions.push(position);
+                    }
+                }
+                offset += batch.num_rows() as u64;
+            }
+            if positions.is_empty() {
+                continue;
+            }
+
+            let num_rows = positions.len() as u64;
+            let schema = Arc::new(Schema::new(vec![Field::new(
+                POSITION_COLUMN,
+                DataType::UInt64,
+                false,
+            )]));
+            let columns: Vec<ArrayRef> = vec![Arc::new(UInt64Array::from(positions))];
+            let batch = RecordBatch::try_new(schema.clone(), columns)?;
+            let path =
+                write_file(store.as_ref(), table_path, DELETES_DIR, &schema, &[batch])
+                    .await?;
+            actions.push(Action::Delete {
+                path,
+                target: file.path.clone(),
+                num_rows,
+            });
+            num_deleted += num_rows;
+        }
+
+        if !actions.is_empty() {
+            commit_or_cleanup(store.as_ref(), table_path, snapshot.version, actions)
+                .await?;
+        }
+        Ok(num_deleted)
+    }
+
+    fn store(&self, state: &SessionState) -> Result<Arc<dyn ObjectStore>> {
+        state.runtime_env().object_store(&self.location)
+    }
+
+    fn check_writable(&self) -> Result<()> {
+        match &self.as_of {
+            Some(as_of) => {
+                exec_err!("Cannot write to snapshot table {} {as_of}", self.location)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl TableProvider for SnapshotTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    fn as_of(&self, as_of: &AsOf) -> Result<Arc<dyn TableProvider>> {
+        let mut table = self.clone();
+        table.as_of = Some(*as_of);
+        Ok(Arc::new(table))
+    }
+
+    async fn scan(
+        &self,
+        state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let snapshot = self.snapshot(state).await?;
+        Ok(Arc::new(SnapshotScanExec::new(
+            self.location.object_store(),
+            self.location.prefix().clone(),
+            &snapshot,
+            &self.schema,
+            projection,
+            limit,
+            state.config().target_partitions(),
+        )?))
+    }
+
+    async fn insert_into(
+        &self,
+        _state: &SessionState,
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !self
+            .schema()
+            .logically_equivalent_names_and_types(&input.schema())
+        {
+            return plan_err!(
+                "Inserting query must have the same schema with the table."
+            );
+        }
+        self.check_writable()?;
+        let sink = Arc::new(SnapshotSink {
+            location: self.location.clone(),
+            schema: self.schema.clone(),
+            overwrite,
+        });
+        Ok(Arc::new(FileSinkExec::new(
+            input,
+            sink,
+            self.schema.clone(),
+            None,
+        )))
+    }
+}
+
+/// Writes the rows inserted into a [`SnapshotTable`] to a new data file
+/// and commits it
+struct SnapshotSink {
+    location: ListingTableUrl,
+    schema: SchemaRef,
+    /// Whether the new data file replaces all files of the table
+    overwrite: bool,
+}
+
+impl Debug for SnapshotSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotSink")
+            .field("location", &self.location)
+            .field("overwrite", &self.overwrite)
+            .finish()
+    }
+}
+
+impl DisplayAs for SnapshotSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "SnapshotTable (location={})", self.location)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for SnapshotSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        None
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        let store = context.runtime_env().object_store(&self.location)?;
+        let table_path = self.location.prefix();
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+
+        let mut batches = vec![];
+        let mut num_rows = 0;
+        while let Some(batch) = data.next().await.transpose()? {
+            num_rows += batch.num_rows() as u64;
+            batches.push(RecordBatch::try_new(
+                self.schema.clone(),
+                batch.columns().to_vec(),
+            )?);
+        }
+
+        let mut actions = vec![];
+        if self.overwrite {
+            actions.extend(snapshot.files.iter().map(|file| Action::Remove {
+                path: file.path.clone(),
+            }));
+        }
+        if num_rows > 0 {
+            let path =
+                write_file(store.as_ref(), table_path, DATA_DIR, &self.schema, &batches)
+                    .await?;
+            actions.push(Action::Add { path, num_rows });
+        }
+        if !actions.is_empty() {
+            commit_or_cleanup(store.as_ref(), table_path, snapshot.version, actions)
+                .await?;
+        }
+        Ok(num_rows)
+    }
+}
+
+/// Writes `batches` to a new Arrow IPC file in the directory `dir` of the
+/// table, returning its path relative to the table location
+async fn write_file(
+    store: &dyn ObjectStore,
+    table_path: &object_store::path::Path,
+    dir: &str,
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+) -> Result<String> {
+    let mut writer = FileWriter::try_new(Vec::new(), schema)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    let buffer = writer.into_inner()?;
+
+    let path = format!("{dir}/{}{DEFAULT_ARROW_EXTENSION}", Uuid::new_v4());
+    store
+        .put(&file_location(table_path, &path)?, buffer.into())
+        .await?;
+    Ok(path)
+}
+
+/// Commits `actions`, deleting the files they add if the commit fails
+async fn commit_or_cleanup(
+    store: &dyn ObjectStore,
+    table_path: &object_store::path::Path,
+    read_version: u64,
+    actions: Vec<Action>,
+) -> Result<u64> {
+    let added: Vec<String> = actions
+        .iter()
+        .filter_map(|action| match action {
+            Action::Add { path, .. } | Action::Delete { path, .. } => Some(path.clone()),
+            Action::Remove { .. } => None,
+        })
+        .collect();
+    let result = manifest::commit(store, table_path, read_version, actions).await;
+    if result.is_err() {
+        for path in added {
+            let _ = store.delete(&file_location(table_path, &path)?).await;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::stats::Precision;
+    use datafusion_expr::{col, lit};
+    use tempfile::TempDir;
+
+    use crate::assert_batches_sorted_eq;
+    use crate::physical_plan::{collect, displayable};
+    use crate::prelude::{SessionConfig, SessionContext};
+
+    use super::*;
+
+    async fn create_table(ctx: &SessionContext, dir: &TempDir) -> Result<SnapshotTable> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let location = ListingTableUrl::parse(dir.path().to_str().unwrap())?;
+        let table = SnapshotTable::try_create(&ctx.state(), location, schema).await?;
+        ctx.register_table("t", Arc::new(table.clone()))?;
+        Ok(table)
+    }
+
+    async fn sql(ctx: &SessionContext, sql: &str) -> Result<Vec<RecordBatch>> {
+        ctx.sql(sql).await?.collect().await
+    }
+
+    #[tokio::test]
+    async fn insert_and_time_travel() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        let table = create_table(&ctx, &dir).await?;
+
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x'), (2, 'y')").await?;
+        sql(&ctx, "INSERT INTO t VALUES (3, 'z')").await?;
+
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "| 3 | z |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &sql(&ctx, "SELECT * FROM t").await?);
+
+        let history = table.history(&ctx.state()).await?;
+        let versions: Vec<_> = history.iter().map(|commit| commit.version).collect();
+        assert_eq!(versions, vec![0, 1, 2]);
+
+        // the table can be opened again from its location
+        let reopened = SnapshotTable::try_open(&ctx.state(), table.location().clone())
+            .await?
+            .with_version(1);
+        assert_eq!(reopened.schema(), table.schema());
+        ctx.register_table("t_v1", Arc::new(reopened))?;
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &sql(&ctx, "SELECT * FROM t_v1").await?);
+
+        let at_time = table
+            .clone()
+            .with_timestamp(history[1].timestamp_millis)?
+            .snapshot(&ctx.state())
+            .await?;
+        assert!(at_time.version >= 1);
+        let err = table.clone().with_timestamp(i64::MAX).unwrap_err();
+        assert!(err.to_string().contains("is out of range"));
+
+        let err = sql(&ctx, "INSERT INTO t_v1 VALUES (4, 'w')")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("VERSION AS OF 1"));
+
+        let err = SnapshotTable::try_create(
+            &ctx.state(),
+            table.location().clone(),
+            table.schema(),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn time_travel_sql() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        create_table(&ctx, &dir).await?;
+
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x')").await?;
+        sql(&ctx, "INSERT INTO t VALUES (2, 'y')").await?;
+
+        let expected = ["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+        let batches =
+            sql(&ctx, "SELECT a FROM t VERSION AS OF 1 AS v WHERE v.a > 0").await?;
+        assert_batches_sorted_eq!(expected, &batches);
+
+        let expected = ["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+        let batches = sql(
+            &ctx,
+            "SELECT a FROM t FOR SYSTEM_TIME AS OF TIMESTAMP '2200-01-01T00:00:00'",
+        )
+        .await?;
+        assert_batches_sorted_eq!(expected, &batches);
+
+        // the latest and an earlier version can be joined
+        let expected = [
+            "+---+---+",
+            "| a | a |",
+            "+---+---+",
+            "| 1 | 1 |",
+            "+---+---+",
+        ];
+        let batches = sql(
+            &ctx,
+            "SELECT t.a, v.a FROM t JOIN t VERSION AS OF 1 AS v ON t.a = v.a",
+        )
+        .await?;
+        assert_batches_sorted_eq!(expected, &batches);
+
+        let err = sql(&ctx, "SELECT a FROM t VERSION AS OF 9")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Version 9 of snapshot table"));
+
+        let err = sql(&ctx, "SELECT a FROM t FOR SYSTEM_TIME AS OF '1970-01-01'")
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("has no version committed at or before"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn positional_deletes() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        let table = create_table(&ctx, &dir).await?;
+
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x'), (2, 'y'), (3, 'z')").await?;
+        sql(&ctx, "INSERT INTO t VALUES (4, 'w')").await?;
+
+        let state = ctx.state();
+        assert_eq!(table.delete(&state, col("a").gt_eq(lit(2i64))).await?, 3);
+        // deleted rows are not deleted again
+        assert_eq!(table.delete(&state, col("a").gt_eq(lit(2i64))).await?, 0);
+
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &sql(&ctx, "SELECT * FROM t").await?);
+
+        let snapshot = table.snapshot(&state).await?;
+        assert_eq!(snapshot.version, 3);
+        assert_eq!(snapshot.num_rows(), 1);
+        assert_eq!(snapshot.files.len(), 2);
+
+        // rows inserted after the delete are visible
+        sql(&ctx, "INSERT INTO t VALUES (5, 'v')").await?;
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 5 | v |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &sql(&ctx, "SELECT * FROM t").await?);
+
+        let before_delete = table.clone().with_version(2).snapshot(&state).await?;
+        assert_eq!(before_delete.num_rows(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn scans_read_a_snapshot() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        create_table(&ctx, &dir).await?;
+
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x')").await?;
+        let plan = ctx
+            .sql("SELECT a FROM t")
+            .await?
+            .create_physical_plan()
+            .await?;
+        sql(&ctx, "INSERT OVERWRITE t VALUES (2, 'y')").await?;
+
+        let expected = ["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+        assert_batches_sorted_eq!(expected, &collect(plan, ctx.task_ctx()).await?);
+
+        let expected = ["+---+", "| a |", "+---+", "| 2 |", "+---+"];
+        assert_batches_sorted_eq!(expected, &sql(&ctx, "SELECT a FROM t").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_commits() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        let table = create_table(&ctx, &dir).await?;
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x')").await?;
+
+        let store = table.store(&ctx.state())?;
+        let table_path = table.location().prefix();
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        let data_file = snapshot.files[0].path.clone();
+
+        // appends made from the same version are both committed
+        let add = |path: &str| Action::Add {
+            path: path.to_string(),
+            num_rows: 0,
+        };
+        let v1 =
+            manifest::commit(store.as_ref(), table_path, 1, vec![add("data/a.arrow")])
+                .await?;
+        let v2 =
+            manifest::commit(store.as_ref(), table_path, 1, vec![add("data/b.arrow")])
+                .await?;
+        assert_eq!((v1, v2), (2, 3));
+
+        // a delete from a file removed by a concurrent commit fails
+        let remove = Action::Remove {
+            path: data_file.clone(),
+        };
+        manifest::commit(store.as_ref(), table_path, 3, vec![remove]).await?;
+        let delete = Action::Delete {
+            path: "deletes/c.arrow".to_string(),
+            target: data_file.clone(),
+            num_rows: 1,
+        };
+        let err = manifest::commit(store.as_ref(), table_path, 3, vec![delete])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains(&format!(
+            "Conflicting commit: data file {data_file} of snapshot table {table_path} \
+             was removed by version 4"
+        )));
+
+        let versions = manifest::list_versions(store.as_ref(), table_path).await?;
+        assert_eq!(versions, vec![0, 1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_deletes() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        let table = create_table(&ctx, &dir).await?;
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x'), (2, 'y'), (3, 'z')").await?;
+
+        let store = table.store(&ctx.state())?;
+        let table_path = table.location().prefix();
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        let data_file = snapshot.files[0].path.clone();
+
+        // two deletes of overlapping rows made from the same version
+        let delete = |path: &str, num_rows| Action::Delete {
+            path: path.to_string(),
+            target: data_file.clone(),
+            num_rows,
+        };
+        let v2 = manifest::commit(
+            store.as_ref(),
+            table_path,
+            1,
+            vec![delete("deletes/a.arrow", 2)],
+        )
+        .await?;
+        assert_eq!(v2, 2);
+        let err = manifest::commit(
+            store.as_ref(),
+            table_path,
+            1,
+            vec![delete("deletes/b.arrow", 2)],
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains(&format!(
+            "Conflicting commit: rows of data file {data_file} of snapshot table \
+             {table_path} were deleted by version 2"
+        )));
+
+        // the rows deleted by the committed delete are counted once
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        assert_eq!(snapshot.version, 2);
+        assert_eq!(snapshot.num_rows(), 1);
+
+        // a delete made from the latest version is committed
+        let v3 = manifest::commit(
+            store.as_ref(),
+            table_path,
+            2,
+            vec![delete("deletes/c.arrow", 1)],
+        )
+        .await?;
+        assert_eq!(v3, 3);
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        assert_eq!(snapshot.num_rows(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn scan_with_limit() -> Result<()> {
+        let config = SessionConfig::new().with_target_partitions(1);
+        let ctx = SessionContext::new_with_config(config);
+        let dir = TempDir::new()?;
+        let table = create_table(&ctx, &dir).await?;
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x'), (2, 'y')").await?;
+        sql(&ctx, "INSERT INTO t VALUES (3, 'z'), (4, 'w')").await?;
+        sql(&ctx, "INSERT INTO t VALUES (5, 'v'), (6, 'u')").await?;
+
+        // only the files holding the first rows are read
+        let plan = table.scan(&ctx.state(), None, &[], Some(3)).await?;
+        let display = displayable(plan.as_ref()).one_line().to_string();
+        assert!(display.contains("files=2, partitions=1, limit=3"));
+        assert_eq!(plan.statistics()?.num_rows, Precision::Exact(3));
+        let batches = collect(plan, ctx.task_ctx()).await?;
+        let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(num_rows, 3);
+
+        let batches = sql(&ctx, "SELECT * FROM t LIMIT 1").await?;
+        let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(num_rows, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn checkpoints() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = TempDir::new()?;
+        let table = create_table(&ctx, &dir).await?;
+        sql(&ctx, "INSERT INTO t VALUES (1, 'x'), (2, 'y')").await?;
+
+        let store = table.store(&ctx.state())?;
+        let table_path = table.location().prefix();
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        let data_file = snapshot.files[0].path.clone();
+
+        let delete = Action::Delete {
+            path: "deletes/a.arrow".to_string(),
+            target: data_file.clone(),
+            num_rows: 1,
+        };
+        manifest::commit(store.as_ref(), table_path, 1, vec![delete]).await?;
+        for version in 2..12 {
+            let add = Action::Add {
+                path: format!("data/{version}.arrow"),
+                num_rows: 1,
+            };
+            manifest::commit(store.as_ref(), table_path, version, vec![add]).await?;
+        }
+        let checkpoints = manifest::list_checkpoints(store.as_ref(), table_path).await?;
+        assert_eq!(checkpoints, vec![manifest::CHECKPOINT_INTERVAL]);
+        let expected = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        assert_eq!(expected.version, 12);
+        assert_eq!(expected.files.len(), 11);
+        assert_eq!(
+            expected.files[0].deletes,
+            vec!["deletes/a.arrow".to_string()]
+        );
+        assert_eq!(expected.num_rows(), 11);
+
+        // the commits up to the checkpoint are no longer read
+        for version in 0..=manifest::CHECKPOINT_INTERVAL {
+            let path = dir
+                .path()
+                .join(manifest::LOG_DIR)
+                .join(format!("{version:020}.arrow"));
+            std::fs::remove_file(path)?;
+        }
+        let snapshot = manifest::read_snapshot(store.as_ref(), table_path, None).await?;
+        assert_eq!(snapshot, expected);
+        let at_checkpoint = manifest::read_snapshot(
+            store.as_ref(),
+            table_path,
+            Some(manifest::CHECKPOINT_INTERVAL),
+        )
+        .await?;
+        assert_eq!(at_checkpoint.files.len(), 9);
+        Ok(())
+    }
+}
@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan reading the data files of a [`Snapshot`].
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::array::BooleanArray;
+use arrow::compute::filter_record_batch;
+use arrow::error::ArrowError;
+use arrow::ipc::reader::FileReader;
+use arrow::record_batch::RecordBatch;
+use arrow_schema::SchemaRef;
+use datafusion_common::cast::as_uint64_array;
+use datafusion_common::stats::Precision;
+use datafusion_common::{internal_err, Statistics};
+use datafusion_execution::object_store::ObjectStoreUrl;
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::PhysicalSortExpr;
+use futures::{stream, StreamExt, TryStreamExt};
+use object_store::path::Path;
+use object_store::{GetResultPayload, ObjectStore};
+
+use super::manifest::{DataFile, Snapshot};
+use crate::error::Result;
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
+use crate::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
+};
+
+/// Name of the column of the files of positions of deleted rows
+pub(crate) const POSITION_COLUMN: &str = "pos";
+
+/// Execution plan reading the data files of a [`Snapshot`] of a
+/// [`SnapshotTable`](super::SnapshotTable), skipping deleted rows
+#[derive(Debug, Clone)]
+pub struct SnapshotScanExec {
+    /// Object store of the table
+    store_url: ObjectStoreUrl,
+    /// Location of the table in the object store
+    table_path: Path,
+    /// Version of the snapshot being read
+    version: u64,
+    /// Data files read by each partition
+    file_groups: Vec<Vec<DataFile>>,
+    /// Columns to read
+    projection: Option<Vec<usize>>,
+    projected_schema: SchemaRef,
+    /// Maximum number of rows read by each partition
+    limit: Option<usize>,
+    /// Number of rows read by all partitions
+    num_rows: usize,
+}
+
+impl SnapshotScanExec {
+    /// Create a plan reading `snapshot` in at most `target_partitions`
+    /// partitions.
+    ///
+    /// With a `limit`, only the first files holding at least `limit` rows
+    /// are read, and each partition stops after reading `limit` rows.
+    pub fn new(
+        store_url: ObjectStoreUrl,
+        table_path: Path,
+        snapshot: &Snapshot,
+        schema: &SchemaRef,
+        projection: Option<&Vec<usize>>,
+        limit: Option<usize>,
+        target_partitions: usize,
+    ) -> Result<Self> {
+        let projected_schema = match projection {
+            Some(projection) => Arc::new(schema.project(projection)?),
+            None => schema.clone(),
+        };
+
+        let mut files = vec![];
+        let mut num_rows = 0;
+        for file in &snapshot.files {
+            if limit.map_or(false, |limit| num_rows >= limit as u64) {
+                break;
+            }
+            num_rows += file.num_live_rows();
+            files.push(file.clone());
+        }
+
+        let num_groups = files.len().clamp(1, target_partitions.max(1));
+        let mut file_groups = vec![vec![]; num_groups];
+        for (i, file) in files.into_iter().enumerate() {
+            file_groups[i % num_groups].push(file);
+        }
+        let num_rows = file_groups
+            .iter()
+            .map(|files| {
+                let num_rows: u64 = files.iter().map(DataFile::num_live_rows).sum();
+                let num_rows = num_rows as usize;
+                limit.map_or(num_rows, |limit| num_rows.min(limit))
+            })
+            .sum();
+        Ok(Self {
+            store_url,
+            table_path,
+            version: snapshot.version,
+            file_groups,
+            projection: projection.cloned(),
+            projected_schema,
+            limit,
+            num_rows,
+        })
+    }
+
+    /// Version of the snapshot being read
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl DisplayAs for SnapshotScanExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let num_files: usize = self.file_groups.iter().map(Vec::len).sum();
+                write!(
+                    f,
+                    "SnapshotScanExec: version={}, files={num_files}, partitions={}",
+                    self.version,
+                    self.file_groups.len()
+                )?;
+                if let Some(limit) = self.limit {
+                    write!(f, ", limit={limit}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for SnapshotScanExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.projected_schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(self.file_groups.len())
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if children.is_empty() {
+            Ok(self)
+        } else {
+            internal_err!("Children cannot be replaced in {self:?}")
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let store = context.runtime_env().object_store(&self.store_url)?;
+        let table_path = self.table_path.clone();
+        let projection = self.projection.clone();
+        let files = self.file_groups[partition].clone();
+
+        // files are only opened once the batches of the previous ones have
+        // been read, and each file is read one batch at a time
+        let batches = stream::iter(files)
+            .then(move |file| {
+                let store = store.clone();
+                let table_path = table_path.clone();
+                let projection = projection.clone();
+                async move {
+                    read_data_file(store.as_ref(), &table_path, &file, projection).await
+                }
+            })
+            .map_ok(stream::iter)
+            .try_flatten()
+            .scan(self.limit.unwrap_or(usize::MAX), |remaining, batch| {
+                let batch = match batch {
+                    Ok(_) if *remaining == 0 => None,
+                    Ok(batch) => {
+                        let batch = batch.slice(0, batch.num_rows().min(*remaining));
+                        *remaining -= batch.num_rows();
+                        Some(Ok(batch))
+                    }
+                    Err(e) => Some(Err(e)),
+                };
+                futures::future::ready(batch)
+            });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.projected_schema.clone(),
+            batches,
+        )))
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        Ok(Statistics {
+            num_rows: Precision::Exact(self.num_rows),
+            ..Statistics::new_unknown(&self.projected_schema)
+        })
+    }
+}
+
+/// Returns the location of the file at `path`, relative to the table location
+pub(crate) fn file_location(table_path: &Path, path: &str) -> Result<Path> {
+    Ok(Path::parse(format!("{table_path}/{path}"))?)
+}
+
+/// Reads the batches of an Arrow IPC file one at a time
+pub(crate) type BatchReader =
+    Box<dyn Iterator<Item = std::result::Result<RecordBatch, ArrowError>> + Send>;
+
+/// Reads the file at `path`, relative to the table location. Local files
+/// are read one batch at a time, while files of other object stores are
+/// fetched at once as IPC files can not be decoded from a stream.
+pub(crate) async fn read_file(
+    store: &dyn ObjectStore,
+    table_path: &Path,
+    path: &str,
+    projection: Option<Vec<usize>>,
+) -> Result<BatchReader> {
+    let result = store.get(&file_location(table_path, path)?).await?;
+    match result.payload {
+        GetResultPayload::File(file, _) => {
+            Ok(Box::new(FileReader::try_new(file, projection)?))
+        }
+        GetResultPayload::Stream(_) => {
+            let bytes = result.bytes().await?;
+            Ok(Box::new(FileReader::try_new(
+                Cursor::new(bytes),
+                projection,
+            )?))
+        }
+    }
+}
+
+/// Reads the positions of the rows deleted from `file`
+pub(crate) async fn read_deleted_positions(
+    store: &dyn ObjectStore,
+    table_path: &Path,
+    file: &DataFile,
+) -> Result<HashSet<u64>> {
+    let mut deleted = HashSet::new();
+    for path in &file.deletes {
+        for batch in read_file(store, table_path, path, None).await? {
+            let batch = batch?;
+            let Some(positions) = batch.column_by_name(POSITION_COLUMN) else {
+                return internal_err!(
+                    "Delete file {path} has no {POSITION_COLUMN} column"
+                );
+            };
+            deleted.extend(as_uint64_array(positions)?.values().iter());
+        }
+    }
+    Ok(deleted)
+}
+
+/// Returns an iterator over the rows of `file` that were not deleted,
+/// reading one batch of the file at a time
+pub(crate) async fn read_data_file(
+    store: &dyn ObjectStore,
+    table_path: &Path,
+    file: &DataFile,
+    projection: Option<Vec<usize>>,
+) -> Result<impl Iterator<Item = Result<RecordBatch>> + Send> {
+    let deleted = read_deleted_positions(store, table_path, file).await?;
+    let reader = read_file(store, table_path, &file.path, projection).await?;
+    let mut offset = 0;
+    let batches = reader
+        .map(move |batch| -> Result<RecordBatch> {
+            let batch = batch?;
+            let num_rows = batch.num_rows() as u64;
+            let batch = if deleted.is_empty() {
+                batch
+            } else {
+                let keep: BooleanArray = (offset..offset + num_rows)
+                    .map(|position| Some(!deleted.contains(&position)))
+                    .collect();
+                filter_record_batch(&batch, &keep)?
+            };
+            offset += num_rows;
+            Ok(batch)
+        })
+        .filter(|batch| !matches!(batch, Ok(batch) if batch.num_rows() == 0));
+    Ok(batches)
+}
@@ -30,7 +30,9 @@ use crate::{
         listing_table_factory::ListingTableFactory,
         provider::TableProviderFactory,
     },
-    datasource::{MemTable, ViewTable},
+    datasource::{
+        materialized_view::MaterializedViewRewrite, MaterializedView, MemTable, ViewTable,
+    },
     logical_expr::{PlanType, ToStringifiedPlan},
     optimizer::optimizer::Optimizer,
     physical_optimizer::optimizer::{PhysicalOptimizer, PhysicalOptimizerRule},
@@ -71,9 +73,10 @@ use crate::datasource::{
 };
 use crate::error::{DataFusionError, Result};
 use crate::logical_expr::{
-    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateMemoryTable,
-    CreateView, DropCatalogSchema, DropTable, DropView, Explain, LogicalPlan,
-    LogicalPlanBuilder, SetVariable, TableSource, TableType, UNNAMED_TABLE,
+    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateMaterializedView,
+    CreateMemoryTable, CreateView, DropCatalogSchema, DropTable, DropView, Explain,
+    LogicalPlan, LogicalPlanBuilder, RefreshMaterializedView, SetVariable, TableSource,
+    TableType, UNNAMED_TABLE,
 };
 use crate::optimizer::OptimizerRule;
 use datafusion_sql::{
@@ -479,6 +482,12 @@ impl SessionContext {
                     self.create_memory_table(cmd).await
                 }
                 DdlStatement::CreateView(cmd) => self.create_view(cmd).await,
+                DdlStatement::CreateMaterializedView(cmd) => {
+                    self.create_materialized_view(cmd).await
+                }
+                DdlStatement::RefreshMaterializedView(cmd) => {
+                    self.refresh_materialized_view(cmd).await
+                }
                 DdlStatement::CreateCatalogSchema(cmd) => {
                     self.create_catalog_schema(cmd).await
                 }
@@ -596,6 +605,63 @@ impl SessionContext {
         }
     }
 
+    async fn create_materialized_view(
+        &self,
+        cmd: CreateMaterializedView,
+    ) -> Result<DataFrame> {
+        let CreateMaterializedView {
+            name,
+            input,
+            or_replace,
+            location,
+            definition,
+        } = cmd;
+
+        if !or_replace && self.table(&name).await.is_ok() {
+            return exec_err!("Table '{name}' already exists");
+        }
+
+        let state = self.state();
+        // queries are matched against the view before they are optimized
+        let input = state.analyzer.execute_and_check(
+            &input,
+            state.config_options(),
+            |_, _| {},
+        )?;
+        let view = match location {
+            Some(location) => {
+                let location =
+                    ListingTableUrl::parse_create_local_if_not_exists(location, true)?;
+                MaterializedView::try_new_with_location(input, definition, location)?
+            }
+            None => MaterializedView::try_new(input, definition)?,
+        };
+        let view = Arc::new(view);
+        view.refresh(&state).await?;
+
+        if or_replace {
+            self.deregister_table(&name)?;
+        }
+        self.register_table(&name, view.clone())?;
+        state
+            .materialized_views
+            .register_view(state.resolve_table_name(&name), view);
+        self.return_empty_dataframe()
+    }
+
+    async fn refresh_materialized_view(
+        &self,
+        cmd: RefreshMaterializedView,
+    ) -> Result<DataFrame> {
+        let RefreshMaterializedView { name, .. } = cmd;
+        let table = self.table_provider(&name).await?;
+        match table.as_any().downcast_ref::<MaterializedView>() {
+            Some(view) => view.refresh(&self.state()).await?,
+            None => return exec_err!("'{name}' is not a materialized view"),
+        }
+        self.return_empty_dataframe()
+    }
+
     async fn create_catalog_schema(&self, cmd: CreateCatalogSchema) -> Result<DataFrame> {
         let CreateCatalogSchema {
             schema_name,
@@ -678,6 +744,12 @@ impl SessionContext {
             name, if_exists, ..
         } = cmd;
         let result = self.find_and_deregister(&name, TableType::View).await;
+        if let Ok(true) = result {
+            let state = self.state.read();
+            state
+                .materialized_views
+                .deregister_view(&state.resolve_table_name(&name));
+        }
         match (result, if_exists) {
             (Ok(true), _) => self.return_empty_dataframe(),
             (_, true) => self.return_empty_dataframe(),
@@ -1034,10 +1106,11 @@ impl SessionContext {
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        self.state
-            .read()
-            .schema_for_ref(table_ref)?
-            .deregister_table(&table)
+        let state = self.state.read();
+        state
+            .materialized_views
+            .deregister_view(&state.resolve_table_name(table_ref.clone()));
+        state.schema_for_ref(table_ref)?.deregister_table(&table)
     }
 
     /// Return `true` if the specified table exists in the schema provider.
@@ -1246,6 +1319,8 @@ pub struct SessionState {
     table_factories: HashMap<String, Arc<dyn TableProviderFactory>>,
     /// Runtime environment
     runtime_env: Arc<RuntimeEnv>,
+    /// Materialized views that queries may be rewritten to read from
+    materialized_views: Arc<MaterializedViewRewrite>,
 }
 
 impl Debug for SessionState {
@@ -1315,10 +1390,16 @@ impl SessionState {
             );
         }
 
+        // must run first, as queries are matched against the analyzed plans
+        // of materialized views
+        let materialized_views = Arc::new(MaterializedViewRewrite::new());
+        let mut optimizer = Optimizer::new();
+        optimizer.rules.insert(0, materialized_views.clone());
+
         SessionState {
             session_id,
             analyzer: Analyzer::new(),
-            optimizer: Optimizer::new(),
+            optimizer,
             physical_optimizers: PhysicalOptimizer::new(),
             query_planner: Arc::new(DefaultQueryPlanner {}),
             catalog_list,
@@ -1330,6 +1411,7 @@ impl SessionState {
             execution_props: ExecutionProps::new(),
             runtime_env: runtime,
             table_factories,
+            materialized_views,
         }
     }
     /// Returns new [`SessionState`] using the provided
@@ -1391,6 +1473,19 @@ impl SessionState {
             .expect("Failed to register default schema");
     }
 
+    /// Returns the fully qualified name of `table_ref`
+    fn resolve_table_name<'a>(
+        &'a self,
+        table_ref: impl Into<TableReference<'a>>,
+    ) -> OwnedTableReference {
+        let resolved = self.resolve_table_ref(table_ref);
+        OwnedTableReference::full(
+            resolved.catalog.to_string(),
+            resolved.schema.to_string(),
+            resolved.table.to_string(),
+        )
+    }
+
     fn resolve_table_ref<'a>(
         &'a self,
         table_ref: impl Into<TableReference<'a>>,
@@ -1451,10 +1546,17 @@ impl SessionState {
     }
 
     /// Replace the optimizer rules
+    ///
+    /// The rule rewriting queries to read materialized views is kept as
+    /// the first rule, replacing any rule with the same name in `rules`,
+    /// as the views of this session are registered with it.
     pub fn with_optimizer_rules(
         mut self,
-        rules: Vec<Arc<dyn OptimizerRule + Send + Sync>>,
+        mut rules: Vec<Arc<dyn OptimizerRule + Send + Sync>>,
     ) -> Self {
+        let materialized_views = self.materialized_views.clone();
+        rules.retain(|rule| rule.name() != materialized_views.name());
+        rules.insert(0, materialized_views);
         self.optimizer = Optimizer::with_rules(rules);
         self
     }
@@ -1615,6 +1717,9 @@ impl SessionState {
                 DFStatement::Explain(explain) => {
                     visit_statement(&explain.statement, visitor)
                 }
+                DFStatement::RefreshMaterializedView(refresh) => {
+                    visitor.insert(&refresh.name)
+                }
             }
         }
 
@@ -1800,6 +1905,11 @@ impl SessionState {
         &self.config
     }
 
+    /// Return a mutable reference to the [`SessionConfig`]
+    pub fn config_mut(&mut self) -> &mut SessionConfig {
+        &mut self.config
+    }
+
     /// Return the physical optimizers
     pub fn physical_optimizers(&self) -> &[Arc<dyn PhysicalOptimizerRule + Send + Sync>] {
         &self.physical_optimizers.rules
@@ -2128,6 +2238,19 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn with_optimizer_rules_keeps_materialized_view_rewrite() {
+        let state = SessionContext::new().state();
+        let rules = state.optimizer.rules.clone();
+        // the rule is not duplicated
+        let state = state.with_optimizer_rules(rules.clone());
+        assert_eq!(state.optimizer.rules.len(), rules.len());
+
+        let state = state.with_optimizer_rules(vec![]);
+        let names: Vec<_> = state.optimizer.rules.iter().map(|r| r.name()).collect();
+        assert_eq!(names, vec!["materialized_view_rewrite"]);
+    }
+
     #[tokio::test]
     async fn create_variable_expr() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -41,7 +41,9 @@ use datafusion_common::{
     tree_node::{Transformed, TreeNode},
 };
 use datafusion_physical_expr::utils::collect_columns;
-use datafusion_physical_expr::{expressions as phys_expr, PhysicalExprRef};
+use datafusion_physical_expr::{
+    expressions as phys_expr, PhysicalExprRef, ScalarFunctionExpr,
+};
 use log::trace;
 
 /// Interface to pass statistics (min/max/nulls) information to [`PruningPredicate`].
@@ -748,6 +750,79 @@ fn build_is_null_column_expr(
     }
 }
 
+/// Given `f(column, path...) op scalar` where `f` is one of the JSON
+/// functions, such as `json_get_str` which `payload->>'type'` is planned as,
+/// returns a pruning expression that evaluates the comparison on the value
+/// of containers whose min and max are equal.
+///
+/// JSON functions are not monotonic, so nothing can be derived from the
+/// bounds of a container holding several distinct values. A container
+/// holding a single value, and nulls for which the function is null, can
+/// however be pruned exactly:
+///
+/// `payload->>'type' = 'x'` becomes
+/// `payload_min != payload_max OR json_get_str(payload_min, 'type') = 'x'`
+fn build_json_function_expr(
+    left: &Arc<dyn PhysicalExpr>,
+    op: Operator,
+    right: &Arc<dyn PhysicalExpr>,
+    schema: &Schema,
+    required_columns: &mut RequiredStatColumns,
+) -> Option<Arc<dyn PhysicalExpr>> {
+    fn json_function(expr: &Arc<dyn PhysicalExpr>) -> Option<&ScalarFunctionExpr> {
+        let function = expr.as_any().downcast_ref::<ScalarFunctionExpr>()?;
+        matches!(
+            function.name(),
+            "json_get"
+                | "json_get_str"
+                | "json_get_int"
+                | "json_get_float"
+                | "json_get_bool"
+                | "json_contains"
+                | "json_length"
+        )
+        .then_some(function)
+    }
+
+    if !is_compare_op(op) {
+        return None;
+    }
+    let (function_expr, op, scalar_expr) = if json_function(left).is_some() {
+        (left, op, right)
+    } else {
+        (right, op.swap()?, left)
+    };
+    let function = json_function(function_expr)?;
+    let (column, path) = function.args().split_first()?;
+    let column = column.as_any().downcast_ref::<phys_expr::Column>()?;
+    if !collect_columns(scalar_expr).is_empty()
+        || path.iter().any(|arg| !collect_columns(arg).is_empty())
+    {
+        return None;
+    }
+
+    let field = schema.field_with_name(column.name()).ok()?;
+    let column_expr = Arc::new(column.clone()) as _;
+    let min = required_columns
+        .min_column_expr(column, &column_expr, field)
+        .ok()?;
+    let max = required_columns
+        .max_column_expr(column, &column_expr, field)
+        .ok()?;
+    let function_of_min = required_columns
+        .min_column_expr(column, function_expr, field)
+        .ok()?;
+    Some(Arc::new(phys_expr::BinaryExpr::new(
+        Arc::new(phys_expr::BinaryExpr::new(min, Operator::NotEq, max)),
+        Operator::Or,
+        Arc::new(phys_expr::BinaryExpr::new(
+            function_of_min,
+            op,
+            scalar_expr.clone(),
+        )),
+    )))
+}
+
 /// Translate logical filter expression into pruning predicate
 /// expression that will evaluate to FALSE if it can be determined no
 /// rows between the min/max values could pass the predicates.
@@ -841,6 +916,12 @@ fn build_predicate_expression(
         return expr;
     }
 
+    if let Some(expr) =
+        build_json_function_expr(&left, op, &right, schema, required_columns)
+    {
+        return expr;
+    }
+
     let expr_builder =
         PruningExpressionBuilder::try_new(&left, &right, op, schema, required_columns);
     let mut expr_builder = match expr_builder {
@@ -959,7 +1040,7 @@ mod tests {
     };
     use datafusion_common::{ScalarValue, ToDFSchema};
     use datafusion_expr::expr::InList;
-    use datafusion_expr::{cast, is_null, try_cast, Expr};
+    use datafusion_expr::{cast, is_null, json_get_str, try_cast, Expr};
     use datafusion_physical_expr::create_physical_expr;
     use datafusion_physical_expr::execution_props::ExecutionProps;
     use std::collections::HashMap;
@@ -1385,6 +1466,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn row_group_predicate_json_function() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("payload", DataType::Utf8, true)]);
+        let expected_expr = "payload_min@0 != payload_max@1 \
+            OR json_get_str(payload_min@0, type) = click";
+
+        // test function on the left
+        let expr = json_get_str(vec![col("payload"), lit("type")]).eq(lit("click"));
+        let predicate_expr = test_build_predicate_expression(
+            &expr,
+            &schema,
+            &mut RequiredStatColumns::new(),
+        );
+        assert_eq!(predicate_expr.to_string(), expected_expr);
+
+        // test function on the right
+        let expr = lit("click").eq(json_get_str(vec![col("payload"), lit("type")]));
+        let predicate_expr = test_build_predicate_expression(
+            &expr,
+            &schema,
+            &mut RequiredStatColumns::new(),
+        );
+        assert_eq!(predicate_expr.to_string(), expected_expr);
+
+        // paths computed from columns are not supported
+        let expr = json_get_str(vec![col("payload"), col("payload")]).eq(lit("click"));
+        let predicate_expr = test_build_predicate_expression(
+            &expr,
+            &schema,
+            &mut RequiredStatColumns::new(),
+        );
+        assert_eq!(predicate_expr.to_string(), "true");
+
+        Ok(())
+    }
+
     #[test]
     fn row_group_predicate_not_eq() -> Result<()> {
         let schema = Schema::new(vec![Field::new("c1", DataType::Int32, false)]);
@@ -1999,6 +2116,40 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn prune_json_function() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "payload",
+            DataType::Utf8,
+            true,
+        )]));
+
+        // Prune using payload->>'type' = 'click'
+        let expr = json_get_str(vec![col("payload"), lit("type")]).eq(lit("click"));
+        let expr = logical2physical(&expr, &schema);
+
+        let click = r#"{"type": "click"}"#;
+        let view = r#"{"type": "view"}"#;
+        let statistics = TestStatistics::new().with(
+            "payload",
+            ContainerStats::new_utf8(
+                vec![Some(click), Some(view), Some(click), Some("x"), None], // min
+                vec![Some(click), Some(view), Some(view), Some("x"), None],  // max
+            ),
+        );
+
+        // payload [click, click] ==> all rows pass the predicate
+        // payload [view, view] ==> no rows pass the predicate
+        // payload [click, view] ==> some rows could pass
+        // payload [x, x] (not JSON) ==> no rows pass, but the function is null
+        // No stats for payload ==> some rows could pass
+
+        let p = PruningPredicate::try_new(expr, schema).unwrap();
+        let result = p.prune(&statistics).unwrap();
+        let expected = vec![true, false, true, true, true];
+        assert_eq!(result, expected);
+    }
+
     /// Creates setup for boolean chunk pruning
     ///
     /// For predicate "b1" (boolean expr)
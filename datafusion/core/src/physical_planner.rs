@@ -44,13 +44,14 @@ use crate::logical_expr::{
     Repartition, Union, UserDefinedLogicalNode,
 };
 use crate::logical_expr::{Limit, Values};
-use crate::physical_expr::create_physical_expr;
+use crate::optimizer::share_common_subplans::SharedSubplan;
+use crate::physical_expr::create_physical_expr_with_overflow_mode;
 use crate::physical_optimizer::optimizer::PhysicalOptimizerRule;
 use crate::physical_plan::aggregates::{AggregateExec, AggregateMode, PhysicalGroupBy};
 use crate::physical_plan::analyze::AnalyzeExec;
 use crate::physical_plan::empty::EmptyExec;
 use crate::physical_plan::explain::ExplainExec;
-use crate::physical_plan::expressions::{Column, PhysicalSortExpr};
+use crate::physical_plan::expressions::{collation_of, lit, Column, PhysicalSortExpr};
 use crate::physical_plan::filter::FilterExec;
 use crate::physical_plan::joins::utils as join_utils;
 use crate::physical_plan::joins::{
@@ -60,6 +61,7 @@ use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use crate::physical_plan::memory::MemoryExec;
 use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::shared::{share_common_subplans, SharedExec};
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::union::UnionExec;
 use crate::physical_plan::unnest::UnnestExec;
@@ -68,14 +70,15 @@ use crate::physical_plan::windows::{
     BoundedWindowAggExec, PartitionSearchMode, WindowAggExec,
 };
 use crate::physical_plan::{
-    aggregates, displayable, udaf, windows, AggregateExpr, ExecutionPlan, Partitioning,
-    PhysicalExpr, WindowExpr,
+    aggregates, displayable, functions, udaf, windows, AggregateExpr, ExecutionPlan,
+    Partitioning, PhysicalExpr, WindowExpr,
 };
 
 use arrow::compute::SortOptions;
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow_array::builder::StringBuilder;
 use arrow_array::RecordBatch;
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::display::ToStringifiedPlan;
 use datafusion_common::file_options::FileTypeWriterOptions;
 use datafusion_common::{
@@ -90,7 +93,8 @@ use datafusion_expr::expr::{
 use datafusion_expr::expr_rewriter::{unalias, unnormalize_cols};
 use datafusion_expr::logical_plan::builder::wrap_projection_for_join_if_necessary;
 use datafusion_expr::{
-    DescribeTable, DmlStatement, StringifiedPlan, WindowFrame, WindowFrameBound, WriteOp,
+    BuiltinScalarFunction, DescribeTable, DmlStatement, StringifiedPlan, WindowFrame,
+    WindowFrameBound, WriteOp,
 };
 use datafusion_physical_expr::expressions::Literal;
 use datafusion_sql::utils::window_expr_common_partition_keys;
@@ -374,6 +378,7 @@ fn create_physical_name(e: &Expr, is_first_expr: bool) -> Result<String> {
         Expr::OuterReferenceColumn(_, _) => {
             internal_err!("Create physical name does not support OuterReferenceColumn")
         }
+        Expr::Lambda(_) => e.display_name(),
     }
 }
 
@@ -469,11 +474,12 @@ impl PhysicalPlanner for DefaultPhysicalPlanner {
         input_schema: &Schema,
         session_state: &SessionState,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        create_physical_expr(
+        create_physical_expr_with_overflow_mode(
             expr,
             input_dfschema,
             input_schema,
             session_state.execution_props(),
+            session_state.config_options().execution.arithmetic_overflow,
         )
     }
 }
@@ -736,11 +742,12 @@ impl DefaultPhysicalPlanner {
                     let window_expr = window_expr
                         .iter()
                         .map(|e| {
-                            create_window_expr(
+                            create_window_expr_with_overflow_mode(
                                 e,
                                 logical_input_schema,
                                 &physical_input_schema,
                                 session_state.execution_props(),
+                                session_state.config_options().execution.arithmetic_overflow,
                             )
                         })
                         .collect::<Result<Vec<_>>>()?;
@@ -785,11 +792,12 @@ impl DefaultPhysicalPlanner {
                     let agg_filter = aggr_expr
                         .iter()
                         .map(|e| {
-                            create_aggregate_expr_and_maybe_filter(
+                            create_aggregate_expr_and_maybe_filter_with_overflow_mode(
                                 e,
                                 logical_input_schema,
                                 &physical_input_schema,
                                 session_state.execution_props(),
+                                session_state.config_options().execution.arithmetic_overflow,
                             )
                         })
                         .collect::<Result<Vec<_>>>()?;
@@ -968,11 +976,12 @@ impl DefaultPhysicalPlanner {
                     let input_dfschema = input.as_ref().schema();
                     let sort_expr = expr
                         .iter()
-                        .map(|e| create_physical_sort_expr(
+                        .map(|e| create_physical_sort_expr_with_overflow_mode(
                             e,
                             input_dfschema,
                             &input_schema,
                             session_state.execution_props(),
+                            session_state.config_options().execution.arithmetic_overflow,
                         ))
                         .collect::<Result<Vec<_>>>()?;
                     let new_sort = SortExec::new(sort_expr, physical_input)
@@ -1115,11 +1124,12 @@ impl DefaultPhysicalPlanner {
                             // convert logical expression to physical according to filter schema
                             let filter_df_schema = DFSchema::new_with_metadata(filter_df_fields, HashMap::new())?;
                             let filter_schema = Schema::new_with_metadata(filter_fields, HashMap::new());
-                            let filter_expr = create_physical_expr(
+                            let filter_expr = create_physical_expr_with_overflow_mode(
                                 expr,
                                 &filter_df_schema,
                                 &filter_schema,
                                 session_state.execution_props(),
+                                session_state.config_options().execution.arithmetic_overflow,
                             )?;
                             let column_indices = join_utils::JoinFilter::build_column_indices(left_field_indices, right_field_indices);
 
@@ -1283,7 +1293,9 @@ impl DefaultPhysicalPlanner {
                 LogicalPlan::Extension(e) => {
                     let physical_inputs = self.create_initial_plan_multi(e.node.inputs(), session_state).await?;
 
-                    let mut maybe_plan = None;
+                    let mut maybe_plan = e.node.as_any().downcast_ref::<SharedSubplan>().map(|shared| {
+                        Arc::new(SharedExec::new(shared.id(), physical_inputs[0].clone())) as Arc<dyn ExecutionPlan>
+                    });
                     for planner in &self.extension_planners {
                         if maybe_plan.is_some() {
                             break;
@@ -1547,11 +1559,12 @@ fn get_null_physical_expr_pair(
     input_schema: &Schema,
     session_state: &SessionState,
 ) -> Result<(Arc<dyn PhysicalExpr>, String)> {
-    let physical_expr = create_physical_expr(
+    let physical_expr = create_physical_expr_with_overflow_mode(
         expr,
         input_dfschema,
         input_schema,
         session_state.execution_props(),
+        session_state.config_options().execution.arithmetic_overflow,
     )?;
     let physical_name = physical_name(&expr.clone())?;
 
@@ -1568,11 +1581,12 @@ fn get_physical_expr_pair(
     input_schema: &Schema,
     session_state: &SessionState,
 ) -> Result<(Arc<dyn PhysicalExpr>, String)> {
-    let physical_expr = create_physical_expr(
+    let physical_expr = create_physical_expr_with_overflow_mode(
         expr,
         input_dfschema,
         input_schema,
         session_state.execution_props(),
+        session_state.config_options().execution.arithmetic_overflow,
     )?;
     let physical_name = physical_name(expr)?;
     Ok((physical_expr, physical_name))
@@ -1605,6 +1619,26 @@ pub fn create_window_expr_with_name(
     logical_input_schema: &DFSchema,
     physical_input_schema: &Schema,
     execution_props: &ExecutionProps,
+) -> Result<Arc<dyn WindowExpr>> {
+    create_window_expr_with_name_and_overflow_mode(
+        e,
+        name,
+        logical_input_schema,
+        physical_input_schema,
+        execution_props,
+        ArithmeticOverflowMode::default(),
+    )
+}
+
+/// Create a window expression with a name from a logical expression, handling
+/// arithmetic overflow according to `arithmetic_overflow`
+fn create_window_expr_with_name_and_overflow_mode(
+    e: &Expr,
+    name: impl Into<String>,
+    logical_input_schema: &DFSchema,
+    physical_input_schema: &Schema,
+    execution_props: &ExecutionProps,
+    arithmetic_overflow: ArithmeticOverflowMode,
 ) -> Result<Arc<dyn WindowExpr>> {
     let name = name.into();
     match e {
@@ -1618,33 +1652,36 @@ pub fn create_window_expr_with_name(
             let args = args
                 .iter()
                 .map(|e| {
-                    create_physical_expr(
+                    create_physical_expr_with_overflow_mode(
                         e,
                         logical_input_schema,
                         physical_input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
             let partition_by = partition_by
                 .iter()
                 .map(|e| {
-                    create_physical_expr(
+                    create_physical_expr_with_overflow_mode(
                         e,
                         logical_input_schema,
                         physical_input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
             let order_by = order_by
                 .iter()
                 .map(|e| {
-                    create_physical_sort_expr(
+                    create_physical_sort_expr_with_overflow_mode(
                         e,
                         logical_input_schema,
                         physical_input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -1676,18 +1713,37 @@ pub fn create_window_expr(
     logical_input_schema: &DFSchema,
     physical_input_schema: &Schema,
     execution_props: &ExecutionProps,
+) -> Result<Arc<dyn WindowExpr>> {
+    create_window_expr_with_overflow_mode(
+        e,
+        logical_input_schema,
+        physical_input_schema,
+        execution_props,
+        ArithmeticOverflowMode::default(),
+    )
+}
+
+/// Create a window expression from a logical expression or an alias, handling
+/// arithmetic overflow according to `arithmetic_overflow`
+fn create_window_expr_with_overflow_mode(
+    e: &Expr,
+    logical_input_schema: &DFSchema,
+    physical_input_schema: &Schema,
+    execution_props: &ExecutionProps,
+    arithmetic_overflow: ArithmeticOverflowMode,
 ) -> Result<Arc<dyn WindowExpr>> {
     // unpack aliased logical expressions, e.g. "sum(col) over () as total"
     let (name, e) = match e {
         Expr::Alias(Alias { expr, name, .. }) => (name.clone(), expr.as_ref()),
         _ => (e.display_name()?, e),
     };
-    create_window_expr_with_name(
+    create_window_expr_with_name_and_overflow_mode(
         e,
         name,
         logical_input_schema,
         physical_input_schema,
         execution_props,
+        arithmetic_overflow,
     )
 }
 
@@ -1706,6 +1762,26 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
     logical_input_schema: &DFSchema,
     physical_input_schema: &Schema,
     execution_props: &ExecutionProps,
+) -> Result<AggregateExprWithOptionalArgs> {
+    create_aggregate_expr_with_name_and_overflow_mode(
+        e,
+        name,
+        logical_input_schema,
+        physical_input_schema,
+        execution_props,
+        ArithmeticOverflowMode::default(),
+    )
+}
+
+/// Create an aggregate expression with a name from a logical expression,
+/// handling arithmetic overflow according to `arithmetic_overflow`
+fn create_aggregate_expr_with_name_and_overflow_mode(
+    e: &Expr,
+    name: impl Into<String>,
+    logical_input_schema: &DFSchema,
+    physical_input_schema: &Schema,
+    execution_props: &ExecutionProps,
+    arithmetic_overflow: ArithmeticOverflowMode,
 ) -> Result<AggregateExprWithOptionalArgs> {
     match e {
         Expr::AggregateFunction(AggregateFunction {
@@ -1718,20 +1794,38 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
             let args = args
                 .iter()
                 .map(|e| {
-                    create_physical_expr(
+                    create_physical_expr_with_overflow_mode(
                         e,
                         logical_input_schema,
                         physical_input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
+            // distinct collated values are counted by their collation keys
+            let args = if *distinct && *fun == datafusion_expr::AggregateFunction::Count {
+                args.into_iter()
+                    .map(|arg| match collation_of(&arg, physical_input_schema) {
+                        Some(collation) => functions::create_physical_expr(
+                            &BuiltinScalarFunction::CollationKey,
+                            &[arg, lit(collation.to_string())],
+                            physical_input_schema,
+                            execution_props,
+                        ),
+                        None => Ok(arg),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            } else {
+                args
+            };
             let filter = match filter {
-                Some(e) => Some(create_physical_expr(
+                Some(e) => Some(create_physical_expr_with_overflow_mode(
                     e,
                     logical_input_schema,
                     physical_input_schema,
                     execution_props,
+                    arithmetic_overflow,
                 )?),
                 None => None,
             };
@@ -1739,11 +1833,12 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
                 Some(e) => Some(
                     e.iter()
                         .map(|expr| {
-                            create_physical_sort_expr(
+                            create_physical_sort_expr_with_overflow_mode(
                                 expr,
                                 logical_input_schema,
                                 physical_input_schema,
                                 execution_props,
+                                arithmetic_overflow,
                             )
                         })
                         .collect::<Result<Vec<_>>>()?,
@@ -1751,13 +1846,14 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
                 None => None,
             };
             let ordering_reqs = order_by.clone().unwrap_or(vec![]);
-            let agg_expr = aggregates::create_aggregate_expr(
+            let agg_expr = aggregates::create_aggregate_expr_with_overflow_mode(
                 fun,
                 *distinct,
                 &args,
                 &ordering_reqs,
                 physical_input_schema,
                 name,
+                arithmetic_overflow,
             )?;
             Ok((agg_expr, filter, order_by))
         }
@@ -1770,21 +1866,23 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
             let args = args
                 .iter()
                 .map(|e| {
-                    create_physical_expr(
+                    create_physical_expr_with_overflow_mode(
                         e,
                         logical_input_schema,
                         physical_input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
 
             let filter = match filter {
-                Some(e) => Some(create_physical_expr(
+                Some(e) => Some(create_physical_expr_with_overflow_mode(
                     e,
                     logical_input_schema,
                     physical_input_schema,
                     execution_props,
+                    arithmetic_overflow,
                 )?),
                 None => None,
             };
@@ -1792,11 +1890,12 @@ pub fn create_aggregate_expr_with_name_and_maybe_filter(
                 Some(e) => Some(
                     e.iter()
                         .map(|expr| {
-                            create_physical_sort_expr(
+                            create_physical_sort_expr_with_overflow_mode(
                                 expr,
                                 logical_input_schema,
                                 physical_input_schema,
                                 execution_props,
+                                arithmetic_overflow,
                             )
                         })
                         .collect::<Result<Vec<_>>>()?,
@@ -1818,6 +1917,24 @@ pub fn create_aggregate_expr_and_maybe_filter(
     logical_input_schema: &DFSchema,
     physical_input_schema: &Schema,
     execution_props: &ExecutionProps,
+) -> Result<AggregateExprWithOptionalArgs> {
+    create_aggregate_expr_and_maybe_filter_with_overflow_mode(
+        e,
+        logical_input_schema,
+        physical_input_schema,
+        execution_props,
+        ArithmeticOverflowMode::default(),
+    )
+}
+
+/// Create an aggregate expression from a logical expression or an alias,
+/// handling arithmetic overflow according to `arithmetic_overflow`
+fn create_aggregate_expr_and_maybe_filter_with_overflow_mode(
+    e: &Expr,
+    logical_input_schema: &DFSchema,
+    physical_input_schema: &Schema,
+    execution_props: &ExecutionProps,
+    arithmetic_overflow: ArithmeticOverflowMode,
 ) -> Result<AggregateExprWithOptionalArgs> {
     // unpack (nested) aliased logical expressions, e.g. "sum(col) as total"
     let (name, e) = match e {
@@ -1825,12 +1942,13 @@ pub fn create_aggregate_expr_and_maybe_filter(
         _ => (physical_name(e)?, e),
     };
 
-    create_aggregate_expr_with_name_and_maybe_filter(
+    create_aggregate_expr_with_name_and_overflow_mode(
         e,
         name,
         logical_input_schema,
         physical_input_schema,
         execution_props,
+        arithmetic_overflow,
     )
 }
 
@@ -1840,6 +1958,24 @@ pub fn create_physical_sort_expr(
     input_dfschema: &DFSchema,
     input_schema: &Schema,
     execution_props: &ExecutionProps,
+) -> Result<PhysicalSortExpr> {
+    create_physical_sort_expr_with_overflow_mode(
+        e,
+        input_dfschema,
+        input_schema,
+        execution_props,
+        ArithmeticOverflowMode::default(),
+    )
+}
+
+/// Create a physical sort expression from a logical expression, handling
+/// arithmetic overflow according to `arithmetic_overflow`
+fn create_physical_sort_expr_with_overflow_mode(
+    e: &Expr,
+    input_dfschema: &DFSchema,
+    input_schema: &Schema,
+    execution_props: &ExecutionProps,
+    arithmetic_overflow: ArithmeticOverflowMode,
 ) -> Result<PhysicalSortExpr> {
     if let Expr::Sort(expr::Sort {
         expr,
@@ -1848,11 +1984,12 @@ pub fn create_physical_sort_expr(
     }) = e
     {
         Ok(PhysicalSortExpr {
-            expr: create_physical_expr(
+            expr: create_physical_expr_with_overflow_mode(
                 expr,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )?,
             options: SortOptions {
                 descending: !asc,
@@ -1999,6 +2136,9 @@ impl DefaultPhysicalPlanner {
             );
             observer(new_plan.as_ref(), optimizer.as_ref())
         }
+        // the optimizers may have copied the shared subplans, so their
+        // consumers are connected once all optimizers have run
+        let new_plan = share_common_subplans(new_plan)?;
         debug!(
             "Optimized physical plan:\n{}\n",
             displayable(new_plan.as_ref()).indent(false)
@@ -2195,6 +2335,36 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_create_expr_with_configured_overflow_mode() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let dfschema = DFSchema::try_from(schema.clone())?;
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(Int32Array::from(vec![1]))],
+        )?;
+
+        // the mode is read from the configuration of the state being planned with
+        let mut session_state = make_session_state();
+        session_state
+            .config_mut()
+            .options_mut()
+            .execution
+            .arithmetic_overflow = ArithmeticOverflowMode::Error;
+
+        let planner = DefaultPhysicalPlanner::default();
+        let expr = planner.create_physical_expr(
+            &(col("a") + lit(i32::MAX)),
+            &dfschema,
+            &schema,
+            &session_state,
+        )?;
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert_contains!(err.to_string(), "Arithmetic overflow in");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_with_csv_plan() -> Result<()> {
         let logical_plan = test_csv_scan()
@@ -18,11 +18,13 @@
 //! Manages files generated during query execution, files are
 //! hashed among the directories listed in RuntimeConfig::local_dirs.
 
+use crate::memory_pool::human_readable_size;
 use datafusion_common::{DataFusionError, Result};
 use log::debug;
 use parking_lot::Mutex;
 use rand::{thread_rng, Rng};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tempfile::{Builder, NamedTempFile, TempDir};
 
@@ -42,6 +44,23 @@ pub enum DiskManagerConfig {
 
     /// Disable disk manager, attempts to create temporary files will error
     Disabled,
+
+    /// Create a [DiskManager] as described by `config` that errors
+    /// once the temporary files it created use more than
+    /// `max_temp_directory_size` bytes on disk.
+    ///
+    /// If `config` is [DiskManagerConfig::Existing], the new
+    /// [DiskManager] shares the directories of the existing one and
+    /// its limit applies in addition to the limit of the existing
+    /// one. This can be used to limit the disk usage of each query,
+    /// while limiting the total disk usage of all queries with the
+    /// shared [DiskManager].
+    Limited {
+        /// The configuration of the underlying [DiskManager]
+        config: Box<DiskManagerConfig>,
+        /// The maximum number of bytes of temporary files
+        max_temp_directory_size: u64,
+    },
 }
 
 impl Default for DiskManagerConfig {
@@ -65,6 +84,15 @@ impl DiskManagerConfig {
     pub fn new_specified(paths: Vec<PathBuf>) -> Self {
         Self::NewSpecified(paths)
     }
+
+    /// Limit the total size of the temporary files created by the
+    /// [DiskManager] to `max_temp_directory_size` bytes
+    pub fn with_max_temp_directory_size(self, max_temp_directory_size: u64) -> Self {
+        Self::Limited {
+            config: Box::new(self),
+            max_temp_directory_size,
+        }
+    }
 }
 
 /// Manages files generated during query execution, e.g. spill files generated
@@ -76,15 +104,42 @@ pub struct DiskManager {
     /// If `Some(vec![])` a new OS specified temporary directory will be created
     /// If `None` an error will be returned (configured not to spill)
     local_dirs: Mutex<Option<Vec<Arc<TempDir>>>>,
+
+    /// The [DiskManager] whose directories are used to create
+    /// temporary files, if this manager was created from an existing one
+    parent: Option<Arc<DiskManager>>,
+
+    /// The disk space used by the temporary files created by this manager
+    usage: Arc<DiskUsage>,
 }
 
 impl DiskManager {
     /// Create a DiskManager given the configuration
     pub fn try_new(config: DiskManagerConfig) -> Result<Arc<Self>> {
+        Self::try_new_with_limit(config, u64::MAX)
+    }
+
+    fn try_new_with_limit(
+        config: DiskManagerConfig,
+        max_temp_directory_size: u64,
+    ) -> Result<Arc<Self>> {
+        let usage = Arc::new(DiskUsage::new(max_temp_directory_size));
         match config {
-            DiskManagerConfig::Existing(manager) => Ok(manager),
+            DiskManagerConfig::Existing(manager) => {
+                if max_temp_directory_size == u64::MAX {
+                    Ok(manager)
+                } else {
+                    Ok(Arc::new(Self {
+                        local_dirs: Mutex::new(None),
+                        parent: Some(manager),
+                        usage,
+                    }))
+                }
+            }
             DiskManagerConfig::NewOs => Ok(Arc::new(Self {
                 local_dirs: Mutex::new(Some(vec![])),
+                parent: None,
+                usage,
             })),
             DiskManagerConfig::NewSpecified(conf_dirs) => {
                 let local_dirs = create_local_dirs(conf_dirs)?;
@@ -94,11 +149,19 @@ impl DiskManager {
                 );
                 Ok(Arc::new(Self {
                     local_dirs: Mutex::new(Some(local_dirs)),
+                    parent: None,
+                    usage,
                 }))
             }
             DiskManagerConfig::Disabled => Ok(Arc::new(Self {
                 local_dirs: Mutex::new(None),
+                parent: None,
+                usage,
             })),
+            DiskManagerConfig::Limited {
+                config,
+                max_temp_directory_size: limit,
+            } => Self::try_new_with_limit(*config, limit.min(max_temp_directory_size)),
         }
     }
 
@@ -106,7 +169,23 @@ impl DiskManager {
     /// files. If this returns false, any call to `create_tmp_file`
     /// will error.
     pub fn tmp_files_enabled(&self) -> bool {
-        self.local_dirs.lock().is_some()
+        match &self.parent {
+            Some(parent) => parent.tmp_files_enabled(),
+            None => self.local_dirs.lock().is_some(),
+        }
+    }
+
+    /// Returns the maximum number of bytes the temporary files created
+    /// by this manager may use on disk
+    pub fn max_temp_directory_size(&self) -> u64 {
+        self.usage.limit
+    }
+
+    /// Returns the number of bytes the temporary files created by this
+    /// manager currently use on disk, as of their last call to
+    /// [`RefCountedTempFile::update_disk_usage`]
+    pub fn used_disk_space(&self) -> u64 {
+        self.usage.used.load(Ordering::Relaxed)
     }
 
     /// Return a temporary file from a randomized choice in the configured locations
@@ -117,6 +196,12 @@ impl DiskManager {
         &self,
         request_description: &str,
     ) -> Result<RefCountedTempFile> {
+        if let Some(parent) = &self.parent {
+            let mut file = parent.create_tmp_file(request_description)?;
+            file.usages.push(Arc::clone(&self.usage));
+            return Ok(file);
+        }
+
         let mut guard = self.local_dirs.lock();
         let local_dirs = guard.as_mut().ok_or_else(|| {
             DataFusionError::ResourcesExhausted(format!(
@@ -143,10 +228,30 @@ impl DiskManager {
             tempfile: Builder::new()
                 .tempfile_in(local_dirs[dir_index].as_ref())
                 .map_err(DataFusionError::IoError)?,
+            usages: vec![Arc::clone(&self.usage)],
+            current_file_disk_usage: 0,
         })
     }
 }
 
+/// The disk space used by the temporary files of a [DiskManager]
+#[derive(Debug)]
+struct DiskUsage {
+    /// The maximum number of bytes that may be used
+    limit: u64,
+    /// The number of bytes currently used
+    used: AtomicU64,
+}
+
+impl DiskUsage {
+    fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            used: AtomicU64::new(0),
+        }
+    }
+}
+
 /// A wrapper around a [`NamedTempFile`] that also contains
 /// a reference to its parent temporary directory
 #[derive(Debug)]
@@ -156,6 +261,10 @@ pub struct RefCountedTempFile {
     #[allow(dead_code)]
     parent_temp_dir: Arc<TempDir>,
     tempfile: NamedTempFile,
+    /// The disk usage of the [DiskManager]s this file counts against
+    usages: Vec<Arc<DiskUsage>>,
+    /// The size of this file as of the last call to [`Self::update_disk_usage`]
+    current_file_disk_usage: u64,
 }
 
 impl RefCountedTempFile {
@@ -166,6 +275,59 @@ impl RefCountedTempFile {
     pub fn inner(&self) -> &NamedTempFile {
         &self.tempfile
     }
+
+    /// Updates the disk usage of the [DiskManager] that created this
+    /// file with the current size of the file on disk.
+    ///
+    /// This should be called after every batch written to the file, so
+    /// that writing stops as soon as the temporary files exceed the
+    /// configured `max_temp_directory_size`, rather than once the file is
+    /// complete. Returns a [`DataFusionError::ResourcesExhausted`] if they
+    /// now exceed it.
+    pub fn update_disk_usage(&mut self) -> Result<()> {
+        let previous = self.current_file_disk_usage;
+        let current = self.tempfile.as_file().metadata()?.len();
+        self.current_file_disk_usage = current;
+
+        let mut exceeded = None;
+        for usage in &self.usages {
+            let used = if current >= previous {
+                usage.used.fetch_add(current - previous, Ordering::Relaxed)
+                    + (current - previous)
+            } else {
+                usage.used.fetch_sub(previous - current, Ordering::Relaxed)
+                    - (previous - current)
+            };
+            if used > usage.limit && exceeded.is_none() {
+                exceeded = Some(usage.limit);
+            }
+        }
+
+        match exceeded {
+            Some(limit) => Err(DataFusionError::ResourcesExhausted(format!(
+                "The used disk space of temporary files has exceeded the allowable limit of {}. \
+                 Try increasing the `max_temp_directory_size` of the DiskManager",
+                human_readable_size(limit as usize)
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the size of this file on disk, as of the last call to
+    /// [`Self::update_disk_usage`]
+    pub fn current_disk_usage(&self) -> u64 {
+        self.current_file_disk_usage
+    }
+}
+
+impl Drop for RefCountedTempFile {
+    fn drop(&mut self) {
+        for usage in &self.usages {
+            usage
+                .used
+                .fetch_sub(self.current_file_disk_usage, Ordering::Relaxed);
+        }
+    }
 }
 
 /// Setup local dirs by creating one new dir in each of the given dirs
@@ -263,6 +425,72 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_max_temp_directory_size() -> Result<()> {
+        use std::io::Write;
+
+        let config = DiskManagerConfig::new().with_max_temp_directory_size(10);
+        let dm = DiskManager::try_new(config)?;
+        assert_eq!(dm.max_temp_directory_size(), 10);
+
+        let mut f1 = dm.create_tmp_file("Testing")?;
+        f1.inner().as_file().write_all(&[0; 6])?;
+        f1.update_disk_usage()?;
+        assert_eq!(f1.current_disk_usage(), 6);
+        assert_eq!(dm.used_disk_space(), 6);
+
+        let mut f2 = dm.create_tmp_file("Testing")?;
+        f2.inner().as_file().write_all(&[0; 6])?;
+        assert_eq!(
+            f2.update_disk_usage().unwrap_err().strip_backtrace(),
+            "Resources exhausted: The used disk space of temporary files has exceeded the allowable limit of 10.0 B. \
+             Try increasing the `max_temp_directory_size` of the DiskManager",
+        );
+
+        // dropping the files returns their space
+        drop(f1);
+        assert_eq!(dm.used_disk_space(), 6);
+        drop(f2);
+        assert_eq!(dm.used_disk_space(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_temp_directory_size_existing() -> Result<()> {
+        use std::io::Write;
+
+        let global = DiskManager::try_new(
+            DiskManagerConfig::new().with_max_temp_directory_size(100),
+        )?;
+        let query = DiskManager::try_new(
+            DiskManagerConfig::new_existing(Arc::clone(&global))
+                .with_max_temp_directory_size(10),
+        )?;
+        assert!(query.tmp_files_enabled());
+
+        let mut f1 = query.create_tmp_file("Testing")?;
+        f1.inner().as_file().write_all(&[0; 8])?;
+        f1.update_disk_usage()?;
+        assert_eq!(query.used_disk_space(), 8);
+        assert_eq!(global.used_disk_space(), 8);
+
+        // the file is created in the directories of the existing manager
+        let local_dirs = local_dir_snapshot(&global);
+        assert_path_in_dirs(f1.path(), local_dirs.iter().map(|p| p.as_path()));
+
+        f1.inner().as_file().write_all(&[0; 8])?;
+        f1.update_disk_usage().unwrap_err();
+        assert_eq!(query.used_disk_space(), 16);
+        assert_eq!(global.used_disk_space(), 16);
+
+        drop(f1);
+        assert_eq!(query.used_disk_space(), 0);
+        assert_eq!(global.used_disk_space(), 0);
+
+        Ok(())
+    }
+
     /// Asserts that `file_path` is found anywhere in any of `dir` directories
     fn assert_path_in_dirs<'a>(
         file_path: &'a Path,
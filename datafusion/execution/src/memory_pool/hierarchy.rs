@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use datafusion_common::{DataFusionError, Result};
+use hashbrown::HashMap;
+use log::debug;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// A [`MemoryPool`] that can be composed into a tree of pools, each
+/// with their own limit and allocation policy.
+///
+/// Every allocation is first checked against the `policy` pool of
+/// this node (e.g. a [`GreedyMemoryPool`] or [`FairSpillPool`] sized
+/// to the per-session or per-query limit) and then, if this pool has
+/// a parent, reserved in the parent as well. An allocation therefore
+/// only succeeds if every pool on the path to the root has capacity
+/// for it.
+///
+/// A child pool registers itself with its parent as a single,
+/// spillable [`MemoryConsumer`] named after the child. With a
+/// [`FairSpillPool`] as the policy of the parent, this divides the
+/// parent's memory evenly between its children.
+///
+/// ```text
+///                 ┌──────────────────────────┐
+///                 │ "global" (Greedy, 10 GB) │
+///                 └──────────────────────────┘
+///                    ▲                    ▲
+///   ┌────────────────┴─────┐   ┌──────────┴───────────┐
+///   │ "tenant_a" (Fair, 4G)│   │ "tenant_b" (Fair, 4G)│
+///   └──────────────────────┘   └──────────────────────┘
+///        ▲           ▲                    ▲
+///     SortExec   HashJoin             SortExec
+/// ```
+///
+/// Each pool also keeps track of the memory reserved by each of its
+/// consumers, see [`Self::top_consumers`].
+///
+/// [`GreedyMemoryPool`]: crate::memory_pool::GreedyMemoryPool
+/// [`FairSpillPool`]: crate::memory_pool::FairSpillPool
+#[derive(Debug)]
+pub struct HierarchicalMemoryPool {
+    /// The name of this pool, used as the consumer name in the parent
+    name: String,
+
+    /// The pool enforcing the limit and policy of this node
+    policy: Arc<dyn MemoryPool>,
+
+    /// The reservation of this pool in its parent, if any
+    parent: Option<Mutex<MemoryReservation>>,
+
+    /// The memory reserved by each consumer, keyed by consumer name
+    consumers: Mutex<HashMap<String, usize>>,
+}
+
+impl HierarchicalMemoryPool {
+    /// Create a new root pool named `name`, whose limit and
+    /// allocation policy are enforced by `policy`
+    pub fn new(name: impl Into<String>, policy: Arc<dyn MemoryPool>) -> Self {
+        let name = name.into();
+        debug!("Created new HierarchicalMemoryPool(name={name})");
+        Self {
+            name,
+            policy,
+            parent: None,
+            consumers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a new pool named `name` that reserves all of its memory
+    /// from `parent`, in addition to the limit and allocation policy
+    /// enforced by `policy`
+    pub fn new_child(
+        name: impl Into<String>,
+        policy: Arc<dyn MemoryPool>,
+        parent: &Arc<dyn MemoryPool>,
+    ) -> Self {
+        let name = name.into();
+        debug!("Created new child HierarchicalMemoryPool(name={name})");
+        let reservation = MemoryConsumer::new(name.clone())
+            .with_can_spill(true)
+            .register(parent);
+        Self {
+            name,
+            policy,
+            parent: Some(Mutex::new(reservation)),
+            consumers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the name of this pool
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns up to `k` consumers of this pool with the largest
+    /// reservations, in descending order of reserved bytes.
+    ///
+    /// Reservations of consumers with the same name are summed. Child
+    /// pools are reported as a single consumer with the name of the
+    /// child.
+    pub fn top_consumers(&self, k: usize) -> Vec<(String, usize)> {
+        let mut consumers: Vec<_> = self
+            .consumers
+            .lock()
+            .iter()
+            .map(|(name, size)| (name.clone(), *size))
+            .collect();
+        consumers.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        consumers.truncate(k);
+        consumers
+    }
+
+    fn track_grow(&self, reservation: &MemoryReservation, additional: usize) {
+        let mut consumers = self.consumers.lock();
+        let name = reservation.consumer().name();
+        match consumers.get_mut(name) {
+            Some(size) => *size += additional,
+            None => {
+                consumers.insert(name.to_string(), additional);
+            }
+        }
+    }
+
+    fn track_shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        let mut consumers = self.consumers.lock();
+        let name = reservation.consumer().name();
+        if let Some(size) = consumers.get_mut(name) {
+            *size = size.saturating_sub(shrink);
+            if *size == 0 {
+                consumers.remove(name);
+            }
+        }
+    }
+}
+
+impl MemoryPool for HierarchicalMemoryPool {
+    fn register(&self, consumer: &MemoryConsumer) {
+        self.policy.register(consumer)
+    }
+
+    fn unregister(&self, consumer: &MemoryConsumer) {
+        self.policy.unregister(consumer)
+    }
+
+    fn grow(&self, reservation: &MemoryReservation, additional: usize) {
+        self.policy.grow(reservation, additional);
+        if let Some(parent) = &self.parent {
+            parent.lock().grow(additional);
+        }
+        self.track_grow(reservation, additional);
+    }
+
+    fn shrink(&self, reservation: &MemoryReservation, shrink: usize) {
+        self.policy.shrink(reservation, shrink);
+        if let Some(parent) = &self.parent {
+            parent.lock().shrink(shrink);
+        }
+        self.track_shrink(reservation, shrink);
+    }
+
+    fn try_grow(&self, reservation: &MemoryReservation, additional: usize) -> Result<()> {
+        self.policy.try_grow(reservation, additional)?;
+        if let Some(parent) = &self.parent {
+            if let Err(e) = parent.lock().try_grow(additional) {
+                self.policy.shrink(reservation, additional);
+                return Err(DataFusionError::ResourcesExhausted(format!(
+                    "Failed to allocate additional {} bytes for {} in memory pool {}: {}",
+                    additional,
+                    reservation.consumer().name(),
+                    self.name,
+                    e.strip_backtrace()
+                )));
+            }
+        }
+        self.track_grow(reservation, additional);
+        Ok(())
+    }
+
+    fn reserved(&self) -> usize {
+        self.policy.reserved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_pool::{FairSpillPool, GreedyMemoryPool, UnboundedMemoryPool};
+
+    #[test]
+    fn test_child_limits() {
+        let root: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new(
+            "global",
+            Arc::new(GreedyMemoryPool::new(100)),
+        ));
+        let a: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new_child(
+            "a",
+            Arc::new(GreedyMemoryPool::new(60)),
+            &root,
+        ));
+        let b: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new_child(
+            "b",
+            Arc::new(GreedyMemoryPool::new(60)),
+            &root,
+        ));
+
+        let mut r1 = MemoryConsumer::new("r1").register(&a);
+        let mut r2 = MemoryConsumer::new("r2").register(&b);
+
+        // limited by the child
+        let err = r1.try_grow(70).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 70 bytes for r1 with 0 bytes already allocated - maximum available is 60");
+        assert_eq!(a.reserved(), 0);
+        assert_eq!(root.reserved(), 0);
+
+        r1.try_grow(60).unwrap();
+        assert_eq!(a.reserved(), 60);
+        assert_eq!(root.reserved(), 60);
+
+        // limited by the parent, and rolled back in the child
+        let err = r2.try_grow(50).unwrap_err().strip_backtrace();
+        assert_eq!(err, "Resources exhausted: Failed to allocate additional 50 bytes for r2 in memory pool b: Resources exhausted: Failed to allocate additional 50 bytes for b with 0 bytes already allocated - maximum available is 40");
+        assert_eq!(b.reserved(), 0);
+        assert_eq!(root.reserved(), 60);
+
+        r2.try_grow(40).unwrap();
+        assert_eq!(b.reserved(), 40);
+        assert_eq!(root.reserved(), 100);
+
+        drop(r1);
+        assert_eq!(a.reserved(), 0);
+        assert_eq!(root.reserved(), 40);
+
+        r2.try_grow(20).unwrap();
+        assert_eq!(b.reserved(), 60);
+        assert_eq!(root.reserved(), 60);
+    }
+
+    #[test]
+    fn test_fair_children() {
+        let root: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new(
+            "global",
+            Arc::new(FairSpillPool::new(100)),
+        ));
+        let a: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new_child(
+            "a",
+            Arc::new(UnboundedMemoryPool::default()),
+            &root,
+        ));
+        let b: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new_child(
+            "b",
+            Arc::new(UnboundedMemoryPool::default()),
+            &root,
+        ));
+
+        let mut r1 = MemoryConsumer::new("r1").register(&a);
+        let mut r2 = MemoryConsumer::new("r2").register(&b);
+
+        // each child may only use its fair share of the parent
+        r1.try_grow(50).unwrap();
+        r1.try_grow(1).unwrap_err();
+        r2.try_grow(50).unwrap();
+        assert_eq!(root.reserved(), 100);
+    }
+
+    #[test]
+    fn test_top_consumers() {
+        let root = Arc::new(HierarchicalMemoryPool::new(
+            "global",
+            Arc::new(UnboundedMemoryPool::default()),
+        ));
+        let root_dyn: Arc<dyn MemoryPool> = root.clone();
+        let child: Arc<dyn MemoryPool> = Arc::new(HierarchicalMemoryPool::new_child(
+            "query_1",
+            Arc::new(UnboundedMemoryPool::default()),
+            &root_dyn,
+        ));
+
+        let mut r1 = MemoryConsumer::new("sort").register(&root_dyn);
+        let mut r2 = MemoryConsumer::new("sort").register(&root_dyn);
+        let mut r3 = MemoryConsumer::new("join").register(&root_dyn);
+        let mut r4 = MemoryConsumer::new("agg").register(&child);
+
+        r1.grow(10);
+        r2.grow(20);
+        r3.grow(25);
+        r4.grow(40);
+
+        assert_eq!(
+            root.top_consumers(2),
+            vec![("query_1".to_string(), 40), ("sort".to_string(), 30)]
+        );
+
+        drop(r4);
+        r1.free();
+        assert_eq!(
+            root.top_consumers(10),
+            vec![("join".to_string(), 25), ("sort".to_string(), 20)]
+        );
+        assert_eq!(root.reserved(), 45);
+        r3.shrink(5);
+        assert_eq!(root.top_consumers(1), vec![("join".to_string(), 20)]);
+    }
+}
@@ -20,9 +20,11 @@
 use datafusion_common::Result;
 use std::{cmp::Ordering, sync::Arc};
 
+mod hierarchy;
 mod pool;
 pub mod proxy;
 
+pub use hierarchy::*;
 pub use pool::*;
 
 /// The pool of memory on which [`MemoryReservation`]s record their
@@ -49,6 +51,9 @@ pub use pool::*;
 /// * [`UnboundedMemoryPool`]
 /// * [`GreedyMemoryPool`]
 /// * [`FairSpillPool`]
+///
+/// These can be composed into a tree of per-session or per-query
+/// pools sharing a global limit using [`HierarchicalMemoryPool`].
 pub trait MemoryPool: Send + Sync + std::fmt::Debug {
     /// Registers a new [`MemoryConsumer`]
     ///
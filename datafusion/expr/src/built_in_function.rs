@@ -33,6 +33,7 @@ use crate::{
 };
 
 use arrow::datatypes::{DataType, Field, Fields, IntervalUnit, TimeUnit};
+use datafusion_common::utils::map_entries_field;
 use datafusion_common::{internal_err, plan_err, DataFusionError, Result};
 
 use strum::IntoEnumIterator;
@@ -182,11 +183,75 @@ pub enum BuiltinScalarFunction {
     MakeArray,
     /// Flatten
     Flatten,
+    /// array_sort
+    ArraySort,
+    /// array_distinct
+    ArrayDistinct,
+    /// array_union
+    ArrayUnion,
+    /// array_except
+    ArrayExcept,
+    /// array_max
+    ArrayMax,
+    /// array_min
+    ArrayMin,
+    /// array_zip
+    ArrayZip,
+    /// array_resize
+    ArrayResize,
+    /// range
+    Range,
+    /// generate_series
+    GenerateSeries,
+
+    // higher-order array functions, which take a lambda argument
+    /// array_transform
+    ArrayTransform,
+    /// array_filter
+    ArrayFilter,
+    /// array_reduce
+    ArrayReduce,
+    /// array_any_match
+    ArrayAnyMatch,
+    /// array_all_match
+    ArrayAllMatch,
 
     // struct functions
     /// struct
     Struct,
 
+    // map functions
+    /// construct a map from a list of keys and a list of values
+    MakeMap,
+    /// map_keys
+    MapKeys,
+    /// map_values
+    MapValues,
+    /// map_entries
+    MapEntries,
+    /// element_at
+    ElementAt,
+
+    // json functions
+    /// json_get
+    JsonGet,
+    /// json_get_str
+    JsonGetStr,
+    /// json_get_int
+    JsonGetInt,
+    /// json_get_float
+    JsonGetFloat,
+    /// json_get_bool
+    JsonGetBool,
+    /// json_contains
+    JsonContains,
+    /// json_length
+    JsonLength,
+    /// json_keys
+    JsonKeys,
+    /// json_array_elements
+    JsonArrayElements,
+
     // string functions
     /// ascii
     Ascii,
@@ -198,6 +263,10 @@ pub enum BuiltinScalarFunction {
     CharacterLength,
     /// chr
     Chr,
+    /// collate
+    Collate,
+    /// collation_key
+    CollationKey,
     /// concat
     Concat,
     /// concat_ws
@@ -228,6 +297,20 @@ pub enum BuiltinScalarFunction {
     Random,
     /// regexp_replace
     RegexpReplace,
+    /// regexp_like
+    RegexpLike,
+    /// regexp_count
+    RegexpCount,
+    /// regexp_instr
+    RegexpInstr,
+    /// regexp_substr
+    RegexpSubstr,
+    /// regexp_extract
+    RegexpExtract,
+    /// regexp_split_to_array
+    RegexpSplitToArray,
+    /// regexp_matches
+    RegexpMatches,
     /// repeat
     Repeat,
     /// replace
@@ -270,8 +353,20 @@ pub enum BuiltinScalarFunction {
     ToTimestampNanos,
     /// to_timestamp_seconds
     ToTimestampSeconds,
+    /// try_to_timestamp
+    TryToTimestamp,
+    /// to_date
+    ToDate,
+    /// try_to_date
+    TryToDate,
+    /// to_char
+    ToChar,
     /// from_unixtime
     FromUnixtime,
+    /// to_local_time
+    ToLocalTime,
+    /// make_timestamp
+    MakeTimestamp,
     ///now
     Now,
     ///current_date
@@ -398,16 +493,47 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArrayReplaceN => Volatility::Immutable,
             BuiltinScalarFunction::ArrayReplaceAll => Volatility::Immutable,
             BuiltinScalarFunction::Flatten => Volatility::Immutable,
+            BuiltinScalarFunction::ArraySort => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayDistinct => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayUnion => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayExcept => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayMax => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayMin => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayZip => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayResize => Volatility::Immutable,
+            BuiltinScalarFunction::Range => Volatility::Immutable,
+            BuiltinScalarFunction::GenerateSeries => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayTransform => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayFilter => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayReduce => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayAnyMatch => Volatility::Immutable,
+            BuiltinScalarFunction::ArrayAllMatch => Volatility::Immutable,
             BuiltinScalarFunction::ArraySlice => Volatility::Immutable,
             BuiltinScalarFunction::ArrayToString => Volatility::Immutable,
             BuiltinScalarFunction::ArrayIntersect => Volatility::Immutable,
             BuiltinScalarFunction::Cardinality => Volatility::Immutable,
             BuiltinScalarFunction::MakeArray => Volatility::Immutable,
+            BuiltinScalarFunction::MakeMap => Volatility::Immutable,
+            BuiltinScalarFunction::MapKeys => Volatility::Immutable,
+            BuiltinScalarFunction::MapValues => Volatility::Immutable,
+            BuiltinScalarFunction::MapEntries => Volatility::Immutable,
+            BuiltinScalarFunction::ElementAt => Volatility::Immutable,
+            BuiltinScalarFunction::JsonGet => Volatility::Immutable,
+            BuiltinScalarFunction::JsonGetStr => Volatility::Immutable,
+            BuiltinScalarFunction::JsonGetInt => Volatility::Immutable,
+            BuiltinScalarFunction::JsonGetFloat => Volatility::Immutable,
+            BuiltinScalarFunction::JsonGetBool => Volatility::Immutable,
+            BuiltinScalarFunction::JsonContains => Volatility::Immutable,
+            BuiltinScalarFunction::JsonLength => Volatility::Immutable,
+            BuiltinScalarFunction::JsonKeys => Volatility::Immutable,
+            BuiltinScalarFunction::JsonArrayElements => Volatility::Immutable,
             BuiltinScalarFunction::Ascii => Volatility::Immutable,
             BuiltinScalarFunction::BitLength => Volatility::Immutable,
             BuiltinScalarFunction::Btrim => Volatility::Immutable,
             BuiltinScalarFunction::CharacterLength => Volatility::Immutable,
             BuiltinScalarFunction::Chr => Volatility::Immutable,
+            BuiltinScalarFunction::Collate => Volatility::Immutable,
+            BuiltinScalarFunction::CollationKey => Volatility::Immutable,
             BuiltinScalarFunction::Concat => Volatility::Immutable,
             BuiltinScalarFunction::ConcatWithSeparator => Volatility::Immutable,
             BuiltinScalarFunction::DatePart => Volatility::Immutable,
@@ -423,6 +549,13 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::OctetLength => Volatility::Immutable,
             BuiltinScalarFunction::Radians => Volatility::Immutable,
             BuiltinScalarFunction::RegexpReplace => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpLike => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpCount => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpInstr => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpSubstr => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpExtract => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpSplitToArray => Volatility::Immutable,
+            BuiltinScalarFunction::RegexpMatches => Volatility::Immutable,
             BuiltinScalarFunction::Repeat => Volatility::Immutable,
             BuiltinScalarFunction::Replace => Volatility::Immutable,
             BuiltinScalarFunction::Reverse => Volatility::Immutable,
@@ -445,12 +578,18 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ToTimestampMicros => Volatility::Immutable,
             BuiltinScalarFunction::ToTimestampNanos => Volatility::Immutable,
             BuiltinScalarFunction::ToTimestampSeconds => Volatility::Immutable,
+            BuiltinScalarFunction::TryToTimestamp => Volatility::Immutable,
+            BuiltinScalarFunction::ToDate => Volatility::Immutable,
+            BuiltinScalarFunction::TryToDate => Volatility::Immutable,
+            BuiltinScalarFunction::ToChar => Volatility::Immutable,
             BuiltinScalarFunction::Translate => Volatility::Immutable,
             BuiltinScalarFunction::Trim => Volatility::Immutable,
             BuiltinScalarFunction::Upper => Volatility::Immutable,
             BuiltinScalarFunction::RegexpMatch => Volatility::Immutable,
             BuiltinScalarFunction::Struct => Volatility::Immutable,
             BuiltinScalarFunction::FromUnixtime => Volatility::Immutable,
+            BuiltinScalarFunction::ToLocalTime => Volatility::Immutable,
+            BuiltinScalarFunction::MakeTimestamp => Volatility::Immutable,
             BuiltinScalarFunction::ArrowTypeof => Volatility::Immutable,
 
             // Stable builtin functions
@@ -581,7 +720,133 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ArraySlice => Ok(input_expr_types[0].clone()),
             BuiltinScalarFunction::ArrayToString => Ok(Utf8),
             BuiltinScalarFunction::ArrayIntersect => Ok(input_expr_types[0].clone()),
+            BuiltinScalarFunction::ArraySort => Ok(input_expr_types[0].clone()),
+            BuiltinScalarFunction::ArrayDistinct => Ok(input_expr_types[0].clone()),
+            // the arguments of array_union and array_except are coerced to a common type
+            BuiltinScalarFunction::ArrayUnion | BuiltinScalarFunction::ArrayExcept => {
+                match &input_expr_types[0] {
+                    Null => Ok(input_expr_types[1].clone()),
+                    data_type => Ok(data_type.clone()),
+                }
+            }
+            BuiltinScalarFunction::ArrayMax | BuiltinScalarFunction::ArrayMin => {
+                match &input_expr_types[0] {
+                    List(field) | LargeList(field) => Ok(field.data_type().clone()),
+                    Null => Ok(Null),
+                    other => plan_err!(
+                        "The {self} function can only accept a list, got {other}"
+                    ),
+                }
+            }
+            BuiltinScalarFunction::ArrayZip => {
+                let fields = input_expr_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, data_type)| match data_type {
+                        List(field) | LargeList(field) => Ok(Field::new(
+                            format!("c{i}"),
+                            field.data_type().clone(),
+                            true,
+                        )),
+                        Null => Ok(Field::new(format!("c{i}"), Null, true)),
+                        other => plan_err!(
+                            "The {self} function can only accept lists, got {other}"
+                        ),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(List(Arc::new(Field::new(
+                    "item",
+                    Struct(fields.into()),
+                    true,
+                ))))
+            }
+            BuiltinScalarFunction::ArrayResize => Ok(input_expr_types[0].clone()),
+            BuiltinScalarFunction::Range | BuiltinScalarFunction::GenerateSeries => {
+                Ok(List(Arc::new(Field::new("item", Int64, true))))
+            }
+            // the second argument is the lambda, whose type is the type of its body
+            BuiltinScalarFunction::ArrayTransform => Ok(List(Arc::new(Field::new(
+                "item",
+                input_expr_types[1].clone(),
+                true,
+            )))),
+            BuiltinScalarFunction::ArrayFilter
+            | BuiltinScalarFunction::ArrayAnyMatch
+            | BuiltinScalarFunction::ArrayAllMatch => match &input_expr_types[1] {
+                Boolean | Null if self == BuiltinScalarFunction::ArrayFilter => {
+                    Ok(input_expr_types[0].clone())
+                }
+                Boolean | Null => Ok(Boolean),
+                other => plan_err!(
+                    "The lambda of the {self} function must return a boolean, got {other}"
+                ),
+            },
+            // the type of the accumulator, which is the type of the initial value
+            BuiltinScalarFunction::ArrayReduce => Ok(input_expr_types[1].clone()),
             BuiltinScalarFunction::Cardinality => Ok(UInt64),
+            BuiltinScalarFunction::MakeMap => {
+                match (&input_expr_types[0], &input_expr_types[1]) {
+                    (List(keys), List(values)) => Ok(Map(
+                        Arc::new(map_entries_field(
+                            keys.data_type().clone(),
+                            values.data_type().clone(),
+                        )),
+                        false,
+                    )),
+                    (keys, values) => plan_err!(
+                        "The map function requires a list of keys and a list of values, got {keys} and {values}"
+                    ),
+                }
+            }
+            BuiltinScalarFunction::MapKeys
+            | BuiltinScalarFunction::MapValues
+            | BuiltinScalarFunction::MapEntries
+            | BuiltinScalarFunction::ElementAt => {
+                let (key_type, value_type) = match &input_expr_types[0] {
+                    Map(field, _) => match field.data_type() {
+                        Struct(fields) if fields.len() == 2 => {
+                            (fields[0].data_type().clone(), fields[1].data_type().clone())
+                        }
+                        _ => {
+                            return plan_err!(
+                                "Map fields must contain a Struct with exactly 2 fields"
+                            )
+                        }
+                    },
+                    other => {
+                        return plan_err!(
+                            "The {self} function can only accept a map, got {other}"
+                        )
+                    }
+                };
+                match self {
+                    BuiltinScalarFunction::MapKeys => {
+                        Ok(List(Arc::new(Field::new("item", key_type, true))))
+                    }
+                    BuiltinScalarFunction::MapValues => {
+                        Ok(List(Arc::new(Field::new("item", value_type, true))))
+                    }
+                    BuiltinScalarFunction::MapEntries => Ok(List(Arc::new(Field::new(
+                        "item",
+                        map_entries_field(key_type, value_type).data_type().clone(),
+                        true,
+                    )))),
+                    _ => Ok(value_type),
+                }
+            }
+            BuiltinScalarFunction::JsonGet | BuiltinScalarFunction::JsonGetStr => {
+                Ok(Utf8)
+            }
+            BuiltinScalarFunction::JsonGetInt => Ok(Int64),
+            BuiltinScalarFunction::JsonGetFloat => Ok(Float64),
+            BuiltinScalarFunction::JsonGetBool | BuiltinScalarFunction::JsonContains => {
+                Ok(Boolean)
+            }
+            BuiltinScalarFunction::JsonLength => Ok(UInt64),
+            BuiltinScalarFunction::JsonKeys
+            | BuiltinScalarFunction::JsonArrayElements => {
+                Ok(List(Arc::new(Field::new("item", Utf8, true))))
+            }
             BuiltinScalarFunction::MakeArray => match input_expr_types.len() {
                 0 => Ok(List(Arc::new(Field::new("item", Null, true)))),
                 _ => {
@@ -607,6 +872,12 @@ impl BuiltinScalarFunction {
                 utf8_to_int_type(&input_expr_types[0], "character_length")
             }
             BuiltinScalarFunction::Chr => Ok(Utf8),
+            BuiltinScalarFunction::Collate => {
+                utf8_to_str_type(&input_expr_types[0], "collate")
+            }
+            BuiltinScalarFunction::CollationKey => {
+                utf8_to_str_type(&input_expr_types[0], "collation_key")
+            }
             BuiltinScalarFunction::Coalesce => {
                 // COALESCE has multiple args and they might get coerced, get a preview of this
                 let coerced_types = data_types(input_expr_types, &self.signature());
@@ -737,7 +1008,8 @@ impl BuiltinScalarFunction {
                     return plan_err!("The to_hex function can only accept integers.");
                 }
             }),
-            BuiltinScalarFunction::ToTimestamp => Ok(match &input_expr_types[0] {
+            BuiltinScalarFunction::ToTimestamp
+            | BuiltinScalarFunction::TryToTimestamp => Ok(match &input_expr_types[0] {
                 Int64 => Timestamp(Second, None),
                 _ => Timestamp(Nanosecond, None),
             }),
@@ -745,7 +1017,21 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ToTimestampMicros => Ok(Timestamp(Microsecond, None)),
             BuiltinScalarFunction::ToTimestampNanos => Ok(Timestamp(Nanosecond, None)),
             BuiltinScalarFunction::ToTimestampSeconds => Ok(Timestamp(Second, None)),
+            BuiltinScalarFunction::ToDate | BuiltinScalarFunction::TryToDate => {
+                Ok(Date32)
+            }
+            BuiltinScalarFunction::ToChar => Ok(Utf8),
             BuiltinScalarFunction::FromUnixtime => Ok(Timestamp(Second, None)),
+            BuiltinScalarFunction::ToLocalTime => match &input_expr_types[0] {
+                Timestamp(unit, _) => Ok(Timestamp(unit.clone(), None)),
+                other => plan_err!(
+                    "The {self} function can only accept a timestamp, got {other}"
+                ),
+            },
+            BuiltinScalarFunction::MakeTimestamp => match input_expr_types.len() {
+                7 => Ok(Timestamp(Nanosecond, Some("+00:00".into()))),
+                _ => Ok(Timestamp(Nanosecond, None)),
+            },
             BuiltinScalarFunction::Now => {
                 Ok(Timestamp(Nanosecond, Some("+00:00".into())))
             }
@@ -768,6 +1054,30 @@ impl BuiltinScalarFunction {
                     );
                 }
             }),
+            BuiltinScalarFunction::RegexpLike => Ok(Boolean),
+            BuiltinScalarFunction::RegexpCount | BuiltinScalarFunction::RegexpInstr => {
+                Ok(Int64)
+            }
+            BuiltinScalarFunction::RegexpSubstr => {
+                utf8_to_str_type(&input_expr_types[0], "regexp_substr")
+            }
+            BuiltinScalarFunction::RegexpExtract => {
+                utf8_to_str_type(&input_expr_types[0], "regexp_extract")
+            }
+            BuiltinScalarFunction::RegexpSplitToArray => Ok(List(Arc::new(Field::new(
+                "item",
+                utf8_to_str_type(&input_expr_types[0], "regexp_split_to_array")?,
+                true,
+            )))),
+            BuiltinScalarFunction::RegexpMatches => Ok(List(Arc::new(Field::new(
+                "item",
+                List(Arc::new(Field::new(
+                    "item",
+                    utf8_to_str_type(&input_expr_types[0], "regexp_matches")?,
+                    true,
+                ))),
+                true,
+            )))),
 
             BuiltinScalarFunction::Factorial
             | BuiltinScalarFunction::Gcd
@@ -885,7 +1195,55 @@ impl BuiltinScalarFunction {
                 Signature::variadic_any(self.volatility())
             }
             BuiltinScalarFunction::ArrayIntersect => Signature::any(2, self.volatility()),
+            BuiltinScalarFunction::ArraySort => {
+                Signature::variadic_any(self.volatility())
+            }
+            BuiltinScalarFunction::ArrayDistinct => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ArrayUnion | BuiltinScalarFunction::ArrayExcept => {
+                Signature::any(2, self.volatility())
+            }
+            BuiltinScalarFunction::ArrayMax | BuiltinScalarFunction::ArrayMin => {
+                Signature::any(1, self.volatility())
+            }
+            BuiltinScalarFunction::ArrayZip => Signature::variadic_any(self.volatility()),
+            BuiltinScalarFunction::ArrayResize => {
+                Signature::variadic_any(self.volatility())
+            }
+            BuiltinScalarFunction::Range | BuiltinScalarFunction::GenerateSeries => {
+                Signature::one_of(
+                    vec![
+                        Exact(vec![Int64]),
+                        Exact(vec![Int64, Int64]),
+                        Exact(vec![Int64, Int64, Int64]),
+                    ],
+                    self.volatility(),
+                )
+            }
+            BuiltinScalarFunction::ArrayTransform
+            | BuiltinScalarFunction::ArrayFilter
+            | BuiltinScalarFunction::ArrayAnyMatch
+            | BuiltinScalarFunction::ArrayAllMatch => {
+                Signature::any(2, self.volatility())
+            }
+            BuiltinScalarFunction::ArrayReduce => Signature::any(3, self.volatility()),
             BuiltinScalarFunction::Cardinality => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::MakeMap => Signature::any(2, self.volatility()),
+            BuiltinScalarFunction::MapKeys
+            | BuiltinScalarFunction::MapValues
+            | BuiltinScalarFunction::MapEntries => Signature::any(1, self.volatility()),
+            BuiltinScalarFunction::ElementAt => Signature::any(2, self.volatility()),
+            // a json string followed by the keys and indices of a path
+            BuiltinScalarFunction::JsonGet
+            | BuiltinScalarFunction::JsonGetStr
+            | BuiltinScalarFunction::JsonGetInt
+            | BuiltinScalarFunction::JsonGetFloat
+            | BuiltinScalarFunction::JsonGetBool
+            | BuiltinScalarFunction::JsonContains
+            | BuiltinScalarFunction::JsonLength
+            | BuiltinScalarFunction::JsonKeys
+            | BuiltinScalarFunction::JsonArrayElements => {
+                Signature::variadic_any(self.volatility())
+            }
             BuiltinScalarFunction::MakeArray => {
                 // 0 or more arguments of arbitrary type
                 Signature::one_of(vec![VariadicAny, Any(0)], self.volatility())
@@ -950,69 +1308,100 @@ impl BuiltinScalarFunction {
                 vec![Exact(vec![Utf8, Int64]), Exact(vec![LargeUtf8, Int64])],
                 self.volatility(),
             ),
-            BuiltinScalarFunction::ToTimestamp => Signature::uniform(
-                1,
-                vec![
-                    Int64,
-                    Timestamp(Nanosecond, None),
-                    Timestamp(Microsecond, None),
-                    Timestamp(Millisecond, None),
-                    Timestamp(Second, None),
-                    Utf8,
-                ],
-                self.volatility(),
-            ),
-            BuiltinScalarFunction::ToTimestampMillis => Signature::uniform(
-                1,
-                vec![
-                    Int64,
-                    Timestamp(Nanosecond, None),
-                    Timestamp(Microsecond, None),
-                    Timestamp(Millisecond, None),
-                    Timestamp(Second, None),
-                    Utf8,
-                ],
-                self.volatility(),
-            ),
-            BuiltinScalarFunction::ToTimestampMicros => Signature::uniform(
-                1,
-                vec![
-                    Int64,
-                    Timestamp(Nanosecond, None),
-                    Timestamp(Microsecond, None),
-                    Timestamp(Millisecond, None),
-                    Timestamp(Second, None),
-                    Utf8,
-                ],
-                self.volatility(),
-            ),
-            BuiltinScalarFunction::ToTimestampNanos => Signature::uniform(
-                1,
+            BuiltinScalarFunction::ToTimestamp
+            | BuiltinScalarFunction::ToTimestampMillis
+            | BuiltinScalarFunction::ToTimestampMicros
+            | BuiltinScalarFunction::ToTimestampNanos
+            | BuiltinScalarFunction::ToTimestampSeconds
+            | BuiltinScalarFunction::TryToTimestamp => Signature::one_of(
                 vec![
-                    Int64,
-                    Timestamp(Nanosecond, None),
-                    Timestamp(Microsecond, None),
-                    Timestamp(Millisecond, None),
-                    Timestamp(Second, None),
-                    Utf8,
-                ],
-                self.volatility(),
-            ),
-            BuiltinScalarFunction::ToTimestampSeconds => Signature::uniform(
-                1,
-                vec![
-                    Int64,
-                    Timestamp(Nanosecond, None),
-                    Timestamp(Microsecond, None),
-                    Timestamp(Millisecond, None),
-                    Timestamp(Second, None),
-                    Utf8,
+                    Uniform(
+                        1,
+                        vec![
+                            Int64,
+                            Timestamp(Nanosecond, None),
+                            Timestamp(Microsecond, None),
+                            Timestamp(Millisecond, None),
+                            Timestamp(Second, None),
+                            Utf8,
+                        ],
+                    ),
+                    // a string followed by the formats to try, in order
+                    Variadic(vec![Utf8]),
                 ],
                 self.volatility(),
             ),
+            BuiltinScalarFunction::ToDate | BuiltinScalarFunction::TryToDate => {
+                Signature::one_of(
+                    vec![
+                        Uniform(
+                            1,
+                            vec![
+                                Date32,
+                                Date64,
+                                Timestamp(Nanosecond, None),
+                                Timestamp(Microsecond, None),
+                                Timestamp(Millisecond, None),
+                                Timestamp(Second, None),
+                                Utf8,
+                            ],
+                        ),
+                        // a string followed by the formats to try, in order
+                        Variadic(vec![Utf8]),
+                    ],
+                    self.volatility(),
+                )
+            }
+            BuiltinScalarFunction::ToChar => {
+                // the finest unit comes first, so that coercion keeps the precision
+                let units = [Nanosecond, Microsecond, Millisecond, Second];
+                let timestamps = units.iter().flat_map(|unit| {
+                    [
+                        Timestamp(unit.clone(), None),
+                        Timestamp(unit.clone(), Some(TIMEZONE_WILDCARD.into())),
+                    ]
+                });
+                let durations = units.iter().map(|unit| Duration(unit.clone()));
+                let types = [
+                    Date32,
+                    Date64,
+                    Time32(Second),
+                    Time32(Millisecond),
+                    Time64(Microsecond),
+                    Time64(Nanosecond),
+                ];
+                Signature::one_of(
+                    types
+                        .into_iter()
+                        .chain(timestamps)
+                        .chain(durations)
+                        .map(|data_type| Exact(vec![data_type, Utf8]))
+                        .collect(),
+                    self.volatility(),
+                )
+            }
             BuiltinScalarFunction::FromUnixtime => {
                 Signature::uniform(1, vec![Int64], self.volatility())
             }
+            BuiltinScalarFunction::ToLocalTime => Signature::one_of(
+                [Nanosecond, Microsecond, Millisecond, Second]
+                    .into_iter()
+                    .flat_map(|unit| {
+                        [
+                            Exact(vec![Timestamp(unit.clone(), None)]),
+                            Exact(vec![Timestamp(unit, Some(TIMEZONE_WILDCARD.into()))]),
+                        ]
+                    })
+                    .collect(),
+                self.volatility(),
+            ),
+            BuiltinScalarFunction::MakeTimestamp => {
+                let fields = vec![Int64, Int64, Int64, Int64, Int64, Float64];
+                Signature::one_of(
+                    vec![Exact(fields.clone()), Exact([fields, vec![Utf8]].concat())],
+                    self.volatility(),
+                )
+            }
             BuiltinScalarFunction::Digest => Signature::one_of(
                 vec![
                     Exact(vec![Utf8, Utf8]),
@@ -1158,6 +1547,12 @@ impl BuiltinScalarFunction {
                 ],
                 self.volatility(),
             ),
+            BuiltinScalarFunction::Collate | BuiltinScalarFunction::CollationKey => {
+                Signature::one_of(
+                    vec![Exact(vec![Utf8, Utf8]), Exact(vec![LargeUtf8, Utf8])],
+                    self.volatility(),
+                )
+            }
             BuiltinScalarFunction::Strpos | BuiltinScalarFunction::StartsWith => {
                 Signature::one_of(
                     vec![
@@ -1203,6 +1598,23 @@ impl BuiltinScalarFunction {
                 ],
                 self.volatility(),
             ),
+            BuiltinScalarFunction::RegexpLike
+            | BuiltinScalarFunction::RegexpSplitToArray
+            | BuiltinScalarFunction::RegexpMatches => {
+                regexp_signature(&[Utf8], self.volatility())
+            }
+            BuiltinScalarFunction::RegexpCount => {
+                regexp_signature(&[Int64, Utf8], self.volatility())
+            }
+            BuiltinScalarFunction::RegexpInstr => {
+                regexp_signature(&[Int64, Int64, Int64, Utf8, Int64], self.volatility())
+            }
+            BuiltinScalarFunction::RegexpSubstr => {
+                regexp_signature(&[Int64, Int64, Utf8, Int64], self.volatility())
+            }
+            BuiltinScalarFunction::RegexpExtract => {
+                regexp_signature(&[Int64], self.volatility())
+            }
             BuiltinScalarFunction::Pi => Signature::exact(vec![], self.volatility()),
             BuiltinScalarFunction::Random => Signature::exact(vec![], self.volatility()),
             BuiltinScalarFunction::Uuid => Signature::exact(vec![], self.volatility()),
@@ -1396,6 +1808,8 @@ fn aliases(func: &BuiltinScalarFunction) -> &'static [&'static str] {
         BuiltinScalarFunction::Concat => &["concat"],
         BuiltinScalarFunction::ConcatWithSeparator => &["concat_ws"],
         BuiltinScalarFunction::Chr => &["chr"],
+        BuiltinScalarFunction::Collate => &["collate"],
+        BuiltinScalarFunction::CollationKey => &["collation_key"],
         BuiltinScalarFunction::InitCap => &["initcap"],
         BuiltinScalarFunction::Left => &["left"],
         BuiltinScalarFunction::Lower => &["lower"],
@@ -1422,6 +1836,13 @@ fn aliases(func: &BuiltinScalarFunction) -> &'static [&'static str] {
         // regex functions
         BuiltinScalarFunction::RegexpMatch => &["regexp_match"],
         BuiltinScalarFunction::RegexpReplace => &["regexp_replace"],
+        BuiltinScalarFunction::RegexpLike => &["regexp_like"],
+        BuiltinScalarFunction::RegexpCount => &["regexp_count"],
+        BuiltinScalarFunction::RegexpInstr => &["regexp_instr"],
+        BuiltinScalarFunction::RegexpSubstr => &["regexp_substr"],
+        BuiltinScalarFunction::RegexpExtract => &["regexp_extract"],
+        BuiltinScalarFunction::RegexpSplitToArray => &["regexp_split_to_array"],
+        BuiltinScalarFunction::RegexpMatches => &["regexp_matches"],
 
         // time/date functions
         BuiltinScalarFunction::Now => &["now"],
@@ -1435,7 +1856,13 @@ fn aliases(func: &BuiltinScalarFunction) -> &'static [&'static str] {
         BuiltinScalarFunction::ToTimestampMicros => &["to_timestamp_micros"],
         BuiltinScalarFunction::ToTimestampSeconds => &["to_timestamp_seconds"],
         BuiltinScalarFunction::ToTimestampNanos => &["to_timestamp_nanos"],
+        BuiltinScalarFunction::TryToTimestamp => &["try_to_timestamp"],
+        BuiltinScalarFunction::ToDate => &["to_date"],
+        BuiltinScalarFunction::TryToDate => &["try_to_date"],
+        BuiltinScalarFunction::ToChar => &["to_char", "date_format", "strftime"],
         BuiltinScalarFunction::FromUnixtime => &["from_unixtime"],
+        BuiltinScalarFunction::ToLocalTime => &["to_local_time"],
+        BuiltinScalarFunction::MakeTimestamp => &["make_timestamp"],
 
         // hashing functions
         BuiltinScalarFunction::Digest => &["digest"],
@@ -1511,9 +1938,44 @@ fn aliases(func: &BuiltinScalarFunction) -> &'static [&'static str] {
         BuiltinScalarFunction::Cardinality => &["cardinality"],
         BuiltinScalarFunction::MakeArray => &["make_array", "make_list"],
         BuiltinScalarFunction::ArrayIntersect => &["array_intersect", "list_intersect"],
+        BuiltinScalarFunction::ArraySort => &["array_sort", "list_sort"],
+        BuiltinScalarFunction::ArrayDistinct => &["array_distinct", "list_distinct"],
+        BuiltinScalarFunction::ArrayUnion => &["array_union", "list_union"],
+        BuiltinScalarFunction::ArrayExcept => &["array_except", "list_except"],
+        BuiltinScalarFunction::ArrayMax => &["array_max", "list_max"],
+        BuiltinScalarFunction::ArrayMin => &["array_min", "list_min"],
+        BuiltinScalarFunction::ArrayZip => &["array_zip", "arrays_zip", "list_zip"],
+        BuiltinScalarFunction::ArrayResize => &["array_resize", "list_resize"],
+        BuiltinScalarFunction::Range => &["range"],
+        BuiltinScalarFunction::GenerateSeries => &["generate_series"],
+        BuiltinScalarFunction::ArrayTransform => &["array_transform", "list_transform"],
+        BuiltinScalarFunction::ArrayFilter => &["array_filter", "list_filter"],
+        BuiltinScalarFunction::ArrayReduce => {
+            &["array_reduce", "list_reduce", "aggregate"]
+        }
+        BuiltinScalarFunction::ArrayAnyMatch => &["array_any_match", "any_match"],
+        BuiltinScalarFunction::ArrayAllMatch => &["array_all_match", "all_match"],
 
         // struct functions
         BuiltinScalarFunction::Struct => &["struct"],
+
+        // map functions
+        BuiltinScalarFunction::MakeMap => &["map"],
+        BuiltinScalarFunction::MapKeys => &["map_keys"],
+        BuiltinScalarFunction::MapValues => &["map_values"],
+        BuiltinScalarFunction::MapEntries => &["map_entries"],
+        BuiltinScalarFunction::ElementAt => &["element_at"],
+
+        // json functions
+        BuiltinScalarFunction::JsonGet => &["json_get"],
+        BuiltinScalarFunction::JsonGetStr => &["json_get_str"],
+        BuiltinScalarFunction::JsonGetInt => &["json_get_int"],
+        BuiltinScalarFunction::JsonGetFloat => &["json_get_float"],
+        BuiltinScalarFunction::JsonGetBool => &["json_get_bool"],
+        BuiltinScalarFunction::JsonContains => &["json_contains"],
+        BuiltinScalarFunction::JsonLength => &["json_length"],
+        BuiltinScalarFunction::JsonKeys => &["json_keys"],
+        BuiltinScalarFunction::JsonArrayElements => &["json_array_elements"],
     }
 }
 
@@ -1584,6 +2046,22 @@ make_utf8_to_return_type!(utf8_to_str_type, DataType::LargeUtf8, DataType::Utf8)
 // `utf8_to_int_type`: returns either a Int32 or Int64 based on the input type size.
 make_utf8_to_return_type!(utf8_to_int_type, DataType::Int64, DataType::Int32);
 
+/// Returns the signature of a regular expression function taking a string, a
+/// pattern and any prefix of the `optional` arguments
+fn regexp_signature(optional: &[DataType], volatility: Volatility) -> Signature {
+    let signatures = [DataType::Utf8, DataType::LargeUtf8]
+        .into_iter()
+        .flat_map(|string| {
+            (0..=optional.len()).map(move |n| {
+                let mut args = vec![string.clone(), DataType::Utf8];
+                args.extend_from_slice(&optional[..n]);
+                TypeSignature::Exact(args)
+            })
+        })
+        .collect();
+    Signature::one_of(signatures, volatility)
+}
+
 fn utf8_or_binary_to_binary_type(arg_type: &DataType, name: &str) -> Result<DataType> {
     Ok(match arg_type {
         DataType::LargeUtf8
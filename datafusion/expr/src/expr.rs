@@ -28,9 +28,9 @@ use crate::Operator;
 use crate::{aggregate_function, ExprSchemable};
 use arrow::datatypes::DataType;
 use datafusion_common::tree_node::{Transformed, TreeNode};
-use datafusion_common::{internal_err, DFSchema};
+use datafusion_common::{internal_err, DFField, DFSchema};
 use datafusion_common::{plan_err, Column, DataFusionError, Result, ScalarValue};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 use std::hash::{BuildHasher, Hash, Hasher};
@@ -185,6 +185,9 @@ pub enum Expr {
     /// A place holder which hold a reference to a qualified field
     /// in the outer query, used for correlated sub queries.
     OuterReferenceColumn(DataType, Column),
+    /// A lambda function such as `x -> x + 1`. Only valid as an argument of
+    /// a higher-order function such as `array_transform`
+    Lambda(Lambda),
 }
 
 /// Alias expression
@@ -625,6 +628,60 @@ impl Placeholder {
     }
 }
 
+/// Lambda function, the argument of higher-order functions such as
+/// `array_transform(a, x -> x + 1)`.
+///
+/// The body refers to the parameters as unqualified columns, and may not
+/// reference any other column. Columns of the enclosing query, such as
+/// `t.threshold` in `array_filter(t.a, x -> x > t.threshold)`, are passed to
+/// the body as additional trailing parameters bound to `captures`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Lambda {
+    /// The name and type of each parameter, followed by the captured ones
+    pub params: Vec<(String, DataType)>,
+    /// The expression evaluated for each set of parameter values
+    pub body: Box<Expr>,
+    /// The values of the last `captures.len()` parameters, evaluated against
+    /// the input of the higher-order function
+    pub captures: Vec<Expr>,
+}
+
+impl Lambda {
+    /// Create a new Lambda expression
+    pub fn new(params: Vec<(String, DataType)>, body: Expr) -> Self {
+        Self {
+            params,
+            body: Box::new(body),
+            captures: vec![],
+        }
+    }
+
+    /// Binds the last `captures.len()` parameters to `captures`
+    pub fn with_captures(mut self, captures: Vec<Expr>) -> Self {
+        self.captures = captures;
+        self
+    }
+
+    /// The parameters the higher-order function passes, without the
+    /// captured ones
+    pub fn declared_params(&self) -> &[(String, DataType)] {
+        &self.params[..self.params.len() - self.captures.len()]
+    }
+
+    /// The schema of the parameters, which the body is planned against
+    pub fn params_schema(&self) -> Result<DFSchema> {
+        DFSchema::new_with_metadata(
+            self.params
+                .iter()
+                .map(|(name, data_type)| {
+                    DFField::new_unqualified(name, data_type.clone(), true)
+                })
+                .collect(),
+            HashMap::new(),
+        )
+    }
+}
+
 /// Grouping sets
 /// See <https://www.postgresql.org/docs/current/queries-table-expressions.html#QUERIES-GROUPING-SETS>
 /// for Postgres definition.
@@ -710,6 +767,7 @@ impl Expr {
             Expr::Cast { .. } => "Cast",
             Expr::Column(..) => "Column",
             Expr::OuterReferenceColumn(_, _) => "Outer",
+            Expr::Lambda { .. } => "Lambda",
             Expr::Exists { .. } => "Exists",
             Expr::GetIndexedField { .. } => "GetIndexedField",
             Expr::GroupingSet(..) => "GroupingSet",
@@ -1326,6 +1384,14 @@ impl fmt::Display for Expr {
                 }
             },
             Expr::Placeholder(Placeholder { id, .. }) => write!(f, "{id}"),
+            Expr::Lambda(lambda) => {
+                write!(
+                    f,
+                    "{} -> {}",
+                    lambda_params(lambda.declared_params()),
+                    lambda.body
+                )
+            }
         }
     }
 }
@@ -1350,6 +1416,21 @@ fn fmt_function(
     write!(f, "{}({}{})", fun, distinct_str, args.join(", "))
 }
 
+/// Formats lambda parameters as `x` or `(acc, x)`
+fn lambda_params(params: &[(String, DataType)]) -> String {
+    match params {
+        [(name, _)] => name.clone(),
+        params => format!(
+            "({})",
+            params
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
 fn create_function_name(fun: &str, distinct: bool, args: &[Expr]) -> Result<String> {
     let names: Vec<String> = args.iter().map(create_name).collect::<Result<_>>()?;
     let distinct_str = match distinct {
@@ -1618,6 +1699,11 @@ fn create_name(e: &Expr) -> Result<String> {
             internal_err!("Create name does not support qualified wildcard")
         }
         Expr::Placeholder(Placeholder { id, .. }) => Ok((*id).to_string()),
+        Expr::Lambda(lambda) => Ok(format!(
+            "{} -> {}",
+            lambda_params(lambda.declared_params()),
+            create_name(&lambda.body)?
+        )),
     }
 }
 
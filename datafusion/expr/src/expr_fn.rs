@@ -18,7 +18,7 @@
 //! Functions for creating logical expressions
 
 use crate::expr::{
-    AggregateFunction, BinaryExpr, Cast, Exists, GroupingSet, InList, InSubquery,
+    AggregateFunction, BinaryExpr, Cast, Exists, GroupingSet, InList, InSubquery, Lambda,
     Placeholder, ScalarFunction, TryCast,
 };
 use crate::function::PartitionEvaluatorFactory;
@@ -99,6 +99,28 @@ pub fn placeholder(id: impl Into<String>) -> Expr {
     })
 }
 
+/// Create a lambda expression with the given typed parameters, for use as
+/// the argument of a higher-order function such as [`array_transform`].
+/// The body refers to the parameters with [`col`].
+///
+/// # Example
+///
+/// ```rust
+/// # use arrow::datatypes::DataType;
+/// # use datafusion_expr::{col, lambda, lit};
+/// let f = lambda(vec![("x", DataType::Int64)], col("x") + lit(1i64));
+/// assert_eq!(f.to_string(), "x -> x + Int64(1)")
+/// ```
+pub fn lambda(params: Vec<(impl Into<String>, DataType)>, body: Expr) -> Expr {
+    Expr::Lambda(Lambda::new(
+        params
+            .into_iter()
+            .map(|(name, data_type)| (name.into(), data_type))
+            .collect(),
+        body,
+    ))
+}
+
 /// Return a new expression `left <op> right`
 pub fn binary_expr(left: Expr, op: Operator, right: Expr) -> Expr {
     Expr::BinaryExpr(BinaryExpr::new(Box::new(left), op, Box::new(right)))
@@ -721,6 +743,172 @@ scalar_expr!(
     first_array second_array,
     "Returns an array of the elements in the intersection of array1 and array2."
 );
+nary_scalar_expr!(
+    ArraySort,
+    array_sort,
+    "sorts an array, in the given `ASC`/`DESC` and `NULLS FIRST`/`NULLS LAST` order or using a comparator lambda."
+);
+scalar_expr!(
+    ArrayDistinct,
+    array_distinct,
+    array,
+    "returns the distinct elements of an array, in order of first occurrence."
+);
+scalar_expr!(
+    ArrayUnion,
+    array_union,
+    first_array second_array,
+    "returns the distinct elements of array1 and array2."
+);
+scalar_expr!(
+    ArrayExcept,
+    array_except,
+    first_array second_array,
+    "returns the distinct elements of array1 that are not in array2."
+);
+scalar_expr!(
+    ArrayMax,
+    array_max,
+    array,
+    "returns the largest non-null element of an array."
+);
+scalar_expr!(
+    ArrayMin,
+    array_min,
+    array,
+    "returns the smallest non-null element of an array."
+);
+nary_scalar_expr!(
+    ArrayZip,
+    array_zip,
+    "merges arrays into an array of structs, padding the shorter arrays with nulls."
+);
+nary_scalar_expr!(
+    ArrayResize,
+    array_resize,
+    "resizes an array to the given size, padding it with nulls or the given value."
+);
+nary_scalar_expr!(
+    Range,
+    range,
+    "returns an array of integers from start up to, but excluding, stop."
+);
+nary_scalar_expr!(
+    GenerateSeries,
+    generate_series,
+    "returns an array of integers from start up to and including stop."
+);
+
+// higher-order array functions
+scalar_expr!(
+    ArrayTransform,
+    array_transform,
+    array lambda,
+    "applies a lambda to each element of an array."
+);
+scalar_expr!(
+    ArrayFilter,
+    array_filter,
+    array lambda,
+    "returns the elements of an array for which a lambda returns true."
+);
+scalar_expr!(
+    ArrayReduce,
+    array_reduce,
+    array initial_value lambda,
+    "folds the elements of an array into a value using a `(acc, x)` lambda."
+);
+scalar_expr!(
+    ArrayAnyMatch,
+    array_any_match,
+    array lambda,
+    "returns true if a lambda returns true for any element of an array."
+);
+scalar_expr!(
+    ArrayAllMatch,
+    array_all_match,
+    array lambda,
+    "returns true if a lambda returns true for all elements of an array."
+);
+
+// map functions
+scalar_expr!(
+    MakeMap,
+    make_map,
+    keys values,
+    "returns a map built from a list of keys and a list of values."
+);
+scalar_expr!(
+    MapKeys,
+    map_keys,
+    map,
+    "returns the keys of a map as a list."
+);
+scalar_expr!(
+    MapValues,
+    map_values,
+    map,
+    "returns the values of a map as a list."
+);
+scalar_expr!(
+    MapEntries,
+    map_entries,
+    map,
+    "returns the entries of a map as a list of key value structs."
+);
+scalar_expr!(
+    ElementAt,
+    element_at,
+    map key,
+    "returns the value of a map for a key, or null if the key is missing."
+);
+
+// json functions
+nary_scalar_expr!(
+    JsonGet,
+    json_get,
+    "returns the JSON value at the path of keys and indices in a JSON string, as JSON."
+);
+nary_scalar_expr!(
+    JsonGetStr,
+    json_get_str,
+    "returns the JSON value at the path of keys and indices in a JSON string, as text."
+);
+nary_scalar_expr!(
+    JsonGetInt,
+    json_get_int,
+    "returns the JSON integer at the path of keys and indices in a JSON string."
+);
+nary_scalar_expr!(
+    JsonGetFloat,
+    json_get_float,
+    "returns the JSON number at the path of keys and indices in a JSON string."
+);
+nary_scalar_expr!(
+    JsonGetBool,
+    json_get_bool,
+    "returns the JSON boolean at the path of keys and indices in a JSON string."
+);
+nary_scalar_expr!(
+    JsonContains,
+    json_contains,
+    "returns true if a JSON string has a value at the path of keys and indices."
+);
+nary_scalar_expr!(
+    JsonLength,
+    json_length,
+    "returns the length of the JSON array or object at a path in a JSON string."
+);
+nary_scalar_expr!(
+    JsonKeys,
+    json_keys,
+    "returns the keys of the JSON object at a path in a JSON string."
+);
+nary_scalar_expr!(
+    JsonArrayElements,
+    json_array_elements,
+    "returns the elements of the JSON array at a path in a JSON string, as JSON."
+);
 
 // string functions
 scalar_expr!(Ascii, ascii, chr, "ASCII code value of the character");
@@ -742,6 +930,18 @@ scalar_expr!(
     code_point,
     "converts the Unicode code point to a UTF8 character"
 );
+scalar_expr!(
+    Collate,
+    collate,
+    string collation,
+    "returns `string` unchanged, marked with `collation`, such as `nocase` or `und-ci`, which comparisons, sorts, grouping and joins on it honor"
+);
+scalar_expr!(
+    CollationKey,
+    collation_key,
+    string collation,
+    "returns the key of `string` under `collation`, such as `nocase` or `und-ci`, which compares byte-wise as the string compares under the collation"
+);
 scalar_expr!(Digest, digest, input algorithm, "compute the binary hash of `input`, using the `algorithm`");
 scalar_expr!(Encode, encode, input encoding, "encode the `input`, using the `encoding`. encoding can be base64 or hex");
 scalar_expr!(Decode, decode, input encoding, "decode the`input`, using the `encoding`. encoding can be base64 or hex");
@@ -810,6 +1010,41 @@ nary_scalar_expr!(
     regexp_match,
     "matches a regular expression against a string and returns matched substrings."
 );
+nary_scalar_expr!(
+    RegexpLike,
+    regexp_like,
+    "returns true if a regular expression matches a string"
+);
+nary_scalar_expr!(
+    RegexpCount,
+    regexp_count,
+    "returns the number of times a regular expression matches a string"
+);
+nary_scalar_expr!(
+    RegexpInstr,
+    regexp_instr,
+    "returns the position of the n-th match of a regular expression in a string"
+);
+nary_scalar_expr!(
+    RegexpSubstr,
+    regexp_substr,
+    "returns the n-th substring matching a regular expression"
+);
+nary_scalar_expr!(
+    RegexpExtract,
+    regexp_extract,
+    "extracts a capture group of the first match of a regular expression"
+);
+nary_scalar_expr!(
+    RegexpSplitToArray,
+    regexp_split_to_array,
+    "splits a string into an array using a regular expression as the delimiter"
+);
+nary_scalar_expr!(
+    RegexpMatches,
+    regexp_matches,
+    "returns the matches of a regular expression against a string, one row per match"
+);
 nary_scalar_expr!(
     Btrim,
     btrim,
@@ -828,36 +1063,99 @@ nary_scalar_expr!(Concat, concat_expr, "concatenates several strings");
 scalar_expr!(DatePart, date_part, part date, "extracts a subfield from the date");
 scalar_expr!(DateTrunc, date_trunc, part date, "truncates the date to a specified level of precision");
 scalar_expr!(DateBin, date_bin, stride source origin, "coerces an arbitrary timestamp to the start of the nearest specified interval");
+scalar_expr!(
+    ToTimestamp,
+    to_timestamp,
+    date,
+    "converts a string to a `Timestamp(Nanoseconds, None)`"
+);
+nary_scalar_expr!(
+    ToTimestamp,
+    to_timestamp_with_formats,
+    "converts a string to a `Timestamp(Nanoseconds, None)`, trying the formats in order"
+);
 scalar_expr!(
     ToTimestampMillis,
     to_timestamp_millis,
     date,
     "converts a string to a `Timestamp(Milliseconds, None)`"
 );
+nary_scalar_expr!(
+    ToTimestampMillis,
+    to_timestamp_millis_with_formats,
+    "converts a string to a `Timestamp(Milliseconds, None)`, trying the formats in order"
+);
 scalar_expr!(
     ToTimestampMicros,
     to_timestamp_micros,
     date,
     "converts a string to a `Timestamp(Microseconds, None)`"
 );
+nary_scalar_expr!(
+    ToTimestampMicros,
+    to_timestamp_micros_with_formats,
+    "converts a string to a `Timestamp(Microseconds, None)`, trying the formats in order"
+);
 scalar_expr!(
     ToTimestampNanos,
     to_timestamp_nanos,
     date,
     "converts a string to a `Timestamp(Nanoseconds, None)`"
 );
+nary_scalar_expr!(
+    ToTimestampNanos,
+    to_timestamp_nanos_with_formats,
+    "converts a string to a `Timestamp(Nanoseconds, None)`, trying the formats in order"
+);
 scalar_expr!(
     ToTimestampSeconds,
     to_timestamp_seconds,
     date,
     "converts a string to a `Timestamp(Seconds, None)`"
 );
+nary_scalar_expr!(
+    ToTimestampSeconds,
+    to_timestamp_seconds_with_formats,
+    "converts a string to a `Timestamp(Seconds, None)`, trying the formats in order"
+);
+nary_scalar_expr!(
+    TryToTimestamp,
+    try_to_timestamp,
+    "like `to_timestamp`, but returns NULL instead of an error when no format matches"
+);
+nary_scalar_expr!(
+    ToDate,
+    to_date,
+    "converts a string to a `Date32`, trying the optional formats in order"
+);
+nary_scalar_expr!(
+    TryToDate,
+    try_to_date,
+    "like `to_date`, but returns NULL instead of an error when no format matches"
+);
+scalar_expr!(
+    ToChar,
+    to_char,
+    datetime format,
+    "formats a date, time, timestamp or duration as a string using a chrono format"
+);
 scalar_expr!(
     FromUnixtime,
     from_unixtime,
     unixtime,
     "returns the unix time in format"
 );
+scalar_expr!(
+    ToLocalTime,
+    to_local_time,
+    timestamp,
+    "converts a timestamp with a timezone to the wall clock time in that timezone, without a timezone"
+);
+nary_scalar_expr!(
+    MakeTimestamp,
+    make_timestamp,
+    "builds a timestamp from its year, month, day, hour, minute and second fields, optionally in a given timezone"
+);
 scalar_expr!(CurrentDate, current_date, ,"returns current UTC date as a [`DataType::Date32`] value");
 scalar_expr!(Now, now, ,"returns current timestamp in nanoseconds, using the same value for all instances of now() in same statement");
 scalar_expr!(CurrentTime, current_time, , "returns current UTC time as a [`DataType::Time64`] value");
@@ -1082,6 +1380,8 @@ mod test {
         test_nary_scalar_expr!(Btrim, btrim, string, characters);
         test_scalar_expr!(CharacterLength, character_length, string);
         test_scalar_expr!(Chr, chr, string);
+        test_scalar_expr!(Collate, collate, string, collation);
+        test_scalar_expr!(CollationKey, collation_key, string, collation);
         test_scalar_expr!(Digest, digest, string, algorithm);
         test_scalar_expr!(Encode, encode, string, encoding);
         test_scalar_expr!(Decode, decode, string, encoding);
@@ -1097,6 +1397,18 @@ mod test {
         test_scalar_expr!(OctetLength, octet_length, string);
         test_nary_scalar_expr!(RegexpMatch, regexp_match, string, pattern);
         test_nary_scalar_expr!(RegexpMatch, regexp_match, string, pattern, flags);
+        test_nary_scalar_expr!(RegexpLike, regexp_like, string, pattern, flags);
+        test_nary_scalar_expr!(RegexpCount, regexp_count, string, pattern, start);
+        test_nary_scalar_expr!(RegexpInstr, regexp_instr, string, pattern, start, n);
+        test_nary_scalar_expr!(RegexpSubstr, regexp_substr, string, pattern);
+        test_nary_scalar_expr!(RegexpExtract, regexp_extract, string, pattern, group);
+        test_nary_scalar_expr!(
+            RegexpSplitToArray,
+            regexp_split_to_array,
+            string,
+            pattern
+        );
+        test_nary_scalar_expr!(RegexpMatches, regexp_matches, string, pattern);
         test_nary_scalar_expr!(
             RegexpReplace,
             regexp_replace,
@@ -1137,7 +1449,31 @@ mod test {
         test_scalar_expr!(DatePart, date_part, part, date);
         test_scalar_expr!(DateTrunc, date_trunc, part, date);
         test_scalar_expr!(DateBin, date_bin, stride, source, origin);
+        test_unary_scalar_expr!(ToTimestamp, to_timestamp);
+        test_nary_scalar_expr!(ToTimestamp, to_timestamp_with_formats, string, format);
+        test_unary_scalar_expr!(ToTimestampMillis, to_timestamp_millis);
+        test_nary_scalar_expr!(
+            ToTimestampSeconds,
+            to_timestamp_seconds_with_formats,
+            string,
+            format
+        );
+        test_nary_scalar_expr!(TryToTimestamp, try_to_timestamp, string, format);
+        test_nary_scalar_expr!(ToDate, to_date, string);
+        test_nary_scalar_expr!(TryToDate, try_to_date, string, format);
+        test_scalar_expr!(ToChar, to_char, datetime, format);
         test_scalar_expr!(FromUnixtime, from_unixtime, unixtime);
+        test_scalar_expr!(ToLocalTime, to_local_time, timestamp);
+        test_nary_scalar_expr!(
+            MakeTimestamp,
+            make_timestamp,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second
+        );
 
         test_scalar_expr!(ArrayAppend, array_append, array, element);
         test_scalar_expr!(ArrayPopBack, array_pop_back, array);
@@ -1157,8 +1493,39 @@ mod test {
         test_scalar_expr!(ArrayToString, array_to_string, array, delimiter);
         test_unary_scalar_expr!(Cardinality, cardinality);
         test_nary_scalar_expr!(MakeArray, array, input);
+        test_nary_scalar_expr!(ArraySort, array_sort, array, comparator);
+        test_unary_scalar_expr!(ArrayDistinct, array_distinct);
+        test_scalar_expr!(ArrayUnion, array_union, first_array, second_array);
+        test_scalar_expr!(ArrayExcept, array_except, first_array, second_array);
+        test_unary_scalar_expr!(ArrayMax, array_max);
+        test_unary_scalar_expr!(ArrayMin, array_min);
+        test_nary_scalar_expr!(ArrayZip, array_zip, first_array, second_array);
+        test_nary_scalar_expr!(ArrayResize, array_resize, array, size, value);
+        test_nary_scalar_expr!(Range, range, start, stop, step);
+        test_nary_scalar_expr!(GenerateSeries, generate_series, start, stop, step);
+        test_scalar_expr!(ArrayTransform, array_transform, array, lambda);
+        test_scalar_expr!(ArrayFilter, array_filter, array, lambda);
+        test_scalar_expr!(ArrayReduce, array_reduce, array, initial_value, lambda);
+        test_scalar_expr!(ArrayAnyMatch, array_any_match, array, lambda);
+        test_scalar_expr!(ArrayAllMatch, array_all_match, array, lambda);
 
         test_unary_scalar_expr!(ArrowTypeof, arrow_typeof);
+
+        test_scalar_expr!(MakeMap, make_map, keys, values);
+        test_unary_scalar_expr!(MapKeys, map_keys);
+        test_unary_scalar_expr!(MapValues, map_values);
+        test_unary_scalar_expr!(MapEntries, map_entries);
+        test_scalar_expr!(ElementAt, element_at, map, key);
+
+        test_nary_scalar_expr!(JsonGet, json_get, json, key);
+        test_nary_scalar_expr!(JsonGetStr, json_get_str, json, key, index);
+        test_nary_scalar_expr!(JsonGetInt, json_get_int, json, key);
+        test_nary_scalar_expr!(JsonGetFloat, json_get_float, json, key);
+        test_nary_scalar_expr!(JsonGetBool, json_get_bool, json, key);
+        test_nary_scalar_expr!(JsonContains, json_contains, json, key);
+        test_nary_scalar_expr!(JsonLength, json_length, json);
+        test_nary_scalar_expr!(JsonKeys, json_keys, json);
+        test_nary_scalar_expr!(JsonArrayElements, json_array_elements, json);
     }
 
     #[test]
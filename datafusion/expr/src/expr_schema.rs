@@ -24,12 +24,12 @@ use crate::expr::{
 use crate::field_util::GetFieldAccessSchema;
 use crate::type_coercion::binary::get_result_type;
 use crate::type_coercion::functions::data_types;
-use crate::{utils, LogicalPlan, Projection, Subquery};
+use crate::{utils, BuiltinScalarFunction, LogicalPlan, Projection, Subquery};
 use arrow::compute::can_cast_types;
 use arrow::datatypes::{DataType, Field};
 use datafusion_common::{
-    internal_err, plan_datafusion_err, plan_err, Column, DFField, DFSchema,
-    DataFusionError, ExprSchema, Result,
+    internal_err, plan_datafusion_err, plan_err, Collation, Column, DFField, DFSchema,
+    DataFusionError, ExprSchema, Result, ScalarValue,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -77,6 +77,7 @@ impl ExprSchemable for Expr {
             Expr::Sort(Sort { expr, .. }) | Expr::Negative(expr) => expr.get_type(schema),
             Expr::Column(c) => Ok(schema.data_type(c)?.clone()),
             Expr::OuterReferenceColumn(ty, _) => Ok(ty.clone()),
+            Expr::Lambda(lambda) => lambda.body.get_type(&lambda.params_schema()?),
             Expr::ScalarVariable(ty, _) => Ok(ty.clone()),
             Expr::Literal(l) => Ok(l.data_type()),
             Expr::Case(case) => case.when_then_expr[0].1.get_type(schema),
@@ -247,7 +248,8 @@ impl ExprSchemable for Expr {
             | Expr::WindowFunction { .. }
             | Expr::AggregateFunction { .. }
             | Expr::AggregateUDF { .. }
-            | Expr::Placeholder(_) => Ok(true),
+            | Expr::Placeholder(_)
+            | Expr::Lambda(_) => Ok(true),
             Expr::IsNull(_)
             | Expr::IsNotNull(_)
             | Expr::IsTrue(_)
@@ -291,6 +293,17 @@ impl ExprSchemable for Expr {
         match self {
             Expr::Column(c) => Ok(schema.metadata(c)?.clone()),
             Expr::Alias(Alias { expr, .. }) => expr.metadata(schema),
+            // the collation of `expr COLLATE c` is carried in its field metadata
+            Expr::ScalarFunction(ScalarFunction {
+                fun: BuiltinScalarFunction::Collate,
+                args,
+            }) => {
+                let mut metadata = args[0].metadata(schema)?;
+                if let Expr::Literal(ScalarValue::Utf8(Some(name))) = &args[1] {
+                    metadata.extend(name.parse::<Collation>()?.to_metadata());
+                }
+                Ok(metadata)
+            }
             _ => Ok(HashMap::new()),
         }
     }
@@ -403,7 +416,7 @@ pub fn cast_subquery(subquery: Subquery, cast_to_type: &DataType) -> Result<Subq
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{col, lit};
+    use crate::{col, collate, lit};
     use arrow::datatypes::DataType;
     use datafusion_common::{Column, ScalarValue};
 
@@ -539,6 +552,12 @@ mod tests {
 
         // verify to_field method populates metadata
         assert_eq!(&meta, expr.to_field(&schema).unwrap().metadata());
+
+        // collate should add the collation to the input metadata
+        let mut collated_meta = meta.clone();
+        collated_meta.extend(Collation::NoCase.to_metadata());
+        let expr = collate(col("foo"), lit("nocase"));
+        assert_eq!(collated_meta, expr.metadata(&schema).unwrap());
     }
 
     #[derive(Debug)]
@@ -77,7 +77,7 @@ pub use partition_evaluator::PartitionEvaluator;
 pub use signature::{
     FuncMonotonicity, Signature, TypeSignature, Volatility, TIMEZONE_WILDCARD,
 };
-pub use table_source::{TableProviderFilterPushDown, TableSource, TableType};
+pub use table_source::{AsOf, TableProviderFilterPushDown, TableSource, TableType};
 pub use udaf::AggregateUDF;
 pub use udf::ScalarUDF;
 pub use udwf::WindowUDF;
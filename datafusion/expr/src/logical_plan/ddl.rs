@@ -38,6 +38,10 @@ pub enum DdlStatement {
     CreateMemoryTable(CreateMemoryTable),
     /// Creates a new view.
     CreateView(CreateView),
+    /// Creates a new materialized view.
+    CreateMaterializedView(CreateMaterializedView),
+    /// Recomputes the results of a materialized view.
+    RefreshMaterializedView(RefreshMaterializedView),
     /// Creates a new catalog schema.
     CreateCatalogSchema(CreateCatalogSchema),
     /// Creates a new catalog (aka "Database").
@@ -58,7 +62,15 @@ impl DdlStatement {
                 schema
             }
             DdlStatement::CreateMemoryTable(CreateMemoryTable { input, .. })
-            | DdlStatement::CreateView(CreateView { input, .. }) => input.schema(),
+            | DdlStatement::CreateView(CreateView { input, .. })
+            | DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => input.schema(),
+            DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                schema,
+                ..
+            }) => schema,
             DdlStatement::CreateCatalogSchema(CreateCatalogSchema { schema, .. }) => {
                 schema
             }
@@ -76,6 +88,8 @@ impl DdlStatement {
             DdlStatement::CreateExternalTable(_) => "CreateExternalTable",
             DdlStatement::CreateMemoryTable(_) => "CreateMemoryTable",
             DdlStatement::CreateView(_) => "CreateView",
+            DdlStatement::CreateMaterializedView(_) => "CreateMaterializedView",
+            DdlStatement::RefreshMaterializedView(_) => "RefreshMaterializedView",
             DdlStatement::CreateCatalogSchema(_) => "CreateCatalogSchema",
             DdlStatement::CreateCatalog(_) => "CreateCatalog",
             DdlStatement::DropTable(_) => "DropTable",
@@ -94,6 +108,11 @@ impl DdlStatement {
                 vec![input]
             }
             DdlStatement::CreateView(CreateView { input, .. }) => vec![input],
+            DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => vec![input],
+            DdlStatement::RefreshMaterializedView(_) => vec![],
             DdlStatement::DropTable(_) => vec![],
             DdlStatement::DropView(_) => vec![],
             DdlStatement::DropCatalogSchema(_) => vec![],
@@ -127,6 +146,23 @@ impl DdlStatement {
                     DdlStatement::CreateView(CreateView { name, .. }) => {
                         write!(f, "CreateView: {name:?}")
                     }
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        location,
+                        ..
+                    }) => match location {
+                        Some(location) => write!(
+                            f,
+                            "CreateMaterializedView: {name:?} location={location}"
+                        ),
+                        None => write!(f, "CreateMaterializedView: {name:?}"),
+                    },
+                    DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                        name,
+                        ..
+                    }) => {
+                        write!(f, "RefreshMaterializedView: {name:?}")
+                    }
                     DdlStatement::CreateCatalogSchema(CreateCatalogSchema {
                         schema_name,
                         ..
@@ -243,6 +279,31 @@ pub struct CreateView {
     pub definition: Option<String>,
 }
 
+/// Creates a materialized view.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CreateMaterializedView {
+    /// The view name
+    pub name: OwnedTableReference,
+    /// The logical plan
+    pub input: Arc<LogicalPlan>,
+    /// Option to replace the view if it already exists
+    pub or_replace: bool,
+    /// The location the results are stored in, or `None` to store
+    /// them in memory
+    pub location: Option<String>,
+    /// SQL used to create the view, if available
+    pub definition: Option<String>,
+}
+
+/// Recomputes the results of a materialized view.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RefreshMaterializedView {
+    /// The view name
+    pub name: OwnedTableReference,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
 /// Creates a catalog (aka "Database").
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CreateCatalog {
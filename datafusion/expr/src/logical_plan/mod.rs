@@ -28,8 +28,9 @@ pub use builder::{
     LogicalPlanBuilder, UNNAMED_TABLE,
 };
 pub use ddl::{
-    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateMemoryTable,
-    CreateView, DdlStatement, DropCatalogSchema, DropTable, DropView,
+    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateMaterializedView,
+    CreateMemoryTable, CreateView, DdlStatement, DropCatalogSchema, DropTable, DropView,
+    RefreshMaterializedView,
 };
 pub use dml::{DmlStatement, WriteOp};
 pub use plan::{
@@ -35,9 +35,9 @@ use crate::utils::{
     grouping_set_expr_count, grouping_set_to_exprlist, inspect_expr_pre,
 };
 use crate::{
-    build_join_schema, expr_vec_fmt, BinaryExpr, CreateMemoryTable, CreateView, Expr,
-    ExprSchemable, LogicalPlanBuilder, Operator, TableProviderFilterPushDown,
-    TableSource,
+    build_join_schema, expr_vec_fmt, BinaryExpr, CreateMaterializedView,
+    CreateMemoryTable, CreateView, Expr, ExprSchemable, LogicalPlanBuilder, Operator,
+    TableProviderFilterPushDown, TableSource,
 };
 
 use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
@@ -816,6 +816,23 @@ impl LogicalPlan {
                 or_replace: *or_replace,
                 definition: definition.clone(),
             }))),
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                CreateMaterializedView {
+                    name,
+                    or_replace,
+                    location,
+                    definition,
+                    ..
+                },
+            )) => Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                CreateMaterializedView {
+                    input: Arc::new(inputs[0].clone()),
+                    name: name.clone(),
+                    or_replace: *or_replace,
+                    location: location.clone(),
+                    definition: definition.clone(),
+                },
+            ))),
             LogicalPlan::Extension(e) => Ok(LogicalPlan::Extension(Extension {
                 node: e.node.from_template(&expr, inputs),
             })),
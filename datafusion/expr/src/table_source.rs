@@ -20,9 +20,12 @@
 use crate::{Expr, LogicalPlan};
 
 use arrow::datatypes::SchemaRef;
-use datafusion_common::{Constraints, Result};
+use arrow::temporal_conversions::timestamp_ns_to_datetime;
+use datafusion_common::{plan_err, Constraints, Result};
 
 use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
 
 /// Indicates whether and how a filter expression can be handled by a
 /// TableProvider for table scans.
@@ -52,6 +55,32 @@ pub enum TableType {
     Temporary,
 }
 
+/// The version of a table to read, specified with `VERSION AS OF` or
+/// `FOR SYSTEM_TIME AS OF` after the name of a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsOf {
+    /// A version number of the table
+    Version(u64),
+    /// A UTC timestamp, in nanoseconds since the epoch
+    Timestamp(i64),
+}
+
+impl fmt::Display for AsOf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AsOf::Version(version) => write!(f, "VERSION AS OF {version}"),
+            AsOf::Timestamp(nanos) => match timestamp_ns_to_datetime(*nanos) {
+                Some(datetime) => write!(
+                    f,
+                    "FOR SYSTEM_TIME AS OF '{}'",
+                    datetime.format("%Y-%m-%dT%H:%M:%S%.f")
+                ),
+                None => write!(f, "FOR SYSTEM_TIME AS OF {nanos}"),
+            },
+        }
+    }
+}
+
 /// The TableSource trait is used during logical query planning and optimizations and
 /// provides access to schema information and filter push-down capabilities. This trait
 /// provides a subset of the functionality of the TableProvider trait in the core
@@ -103,4 +132,13 @@ pub trait TableSource: Sync + Send {
     fn get_logical_plan(&self) -> Option<&LogicalPlan> {
         None
     }
+
+    /// Returns a source reading the version of this table selected by
+    /// `as_of`, for `FROM t VERSION AS OF ...` and
+    /// `FROM t FOR SYSTEM_TIME AS OF ...`.
+    ///
+    /// Returns an error by default, for tables without versions.
+    fn as_of(&self, as_of: &AsOf) -> Result<Arc<dyn TableSource>> {
+        plan_err!("Time travel ({as_of}) is not supported by this table")
+    }
 }
@@ -19,8 +19,8 @@
 
 use crate::expr::{
     AggregateFunction, AggregateUDF, Alias, Between, BinaryExpr, Case, Cast,
-    GetIndexedField, GroupingSet, InList, InSubquery, Like, Placeholder, ScalarFunction,
-    ScalarUDF, Sort, TryCast, WindowFunction,
+    GetIndexedField, GroupingSet, InList, InSubquery, Lambda, Like, Placeholder,
+    ScalarFunction, ScalarUDF, Sort, TryCast, WindowFunction,
 };
 use crate::{Expr, GetFieldAccess};
 
@@ -80,6 +80,9 @@ impl TreeNode for Expr {
             | Expr::Wildcard
             | Expr::QualifiedWildcard { .. }
             | Expr::Placeholder (_) => vec![],
+            // The body of a lambda refers to its parameters rather than to
+            // the input columns, so only the captured values are visited
+            Expr::Lambda(Lambda { captures, .. }) => captures.clone(),
             Expr::BinaryExpr(BinaryExpr { left, right, .. }) => {
                 vec![left.as_ref().clone(), right.as_ref().clone()]
             }
@@ -163,6 +166,15 @@ impl TreeNode for Expr {
             }
             Expr::Column(_) => self,
             Expr::OuterReferenceColumn(_, _) => self,
+            Expr::Lambda(Lambda {
+                params,
+                body,
+                captures,
+            }) => Expr::Lambda(Lambda {
+                params,
+                body,
+                captures: transform_vec(captures, &mut transform)?,
+            }),
             Expr::Exists { .. } => self,
             Expr::InSubquery(InSubquery {
                 expr,
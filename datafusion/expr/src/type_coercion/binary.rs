@@ -220,12 +220,20 @@ fn math_decimal_coercion(
             let (lhs_type, value_type) = math_decimal_coercion(lhs_type, value_type)?;
             Some((lhs_type, value_type))
         }
-        (Null, dec_type @ Decimal128(_, _)) | (dec_type @ Decimal128(_, _), Null) => {
+        (Null, dec_type @ (Decimal128(_, _) | Decimal256(_, _)))
+        | (dec_type @ (Decimal128(_, _) | Decimal256(_, _)), Null) => {
             Some((dec_type.clone(), dec_type.clone()))
         }
         (Decimal128(_, _), Decimal128(_, _)) | (Decimal256(_, _), Decimal256(_, _)) => {
             Some((lhs_type.clone(), rhs_type.clone()))
         }
+        // A Decimal128 is widened to a Decimal256 with the same precision and scale
+        (Decimal128(p, s), Decimal256(_, _)) => {
+            Some((Decimal256(*p, *s), rhs_type.clone()))
+        }
+        (Decimal256(_, _), Decimal128(p, s)) => {
+            Some((lhs_type.clone(), Decimal256(*p, *s)))
+        }
         // Unlike with comparison we don't coerce to a decimal in the case of floating point
         // numbers, instead falling back to floating point arithmetic instead
         (Decimal128(_, _), Int8 | Int16 | Int32 | Int64) => {
@@ -376,6 +384,12 @@ fn comparison_binary_numeric_coercion(
         (Decimal128(_, _), Decimal128(_, _)) => {
             get_wider_decimal_type(lhs_type, rhs_type)
         }
+        (Decimal128(p, s), Decimal256(_, _)) => {
+            get_wider_decimal_type(&Decimal256(*p, *s), rhs_type)
+        }
+        (Decimal256(_, _), Decimal128(p, s)) => {
+            get_wider_decimal_type(lhs_type, &Decimal256(*p, *s))
+        }
         (Decimal128(_, _), _) => get_comparison_common_decimal_type(lhs_type, rhs_type),
         (_, Decimal128(_, _)) => get_comparison_common_decimal_type(rhs_type, lhs_type),
         (Decimal256(_, _), Decimal256(_, _)) => {
@@ -440,8 +454,8 @@ fn get_comparison_common_decimal_type(
     }
 }
 
-/// Returns a `DataType::Decimal128` that can store any value from either
-/// `lhs_decimal_type` and `rhs_decimal_type`
+/// Returns a `DataType::Decimal128` or `DataType::Decimal256` that can store
+/// any value from either `lhs_decimal_type` and `rhs_decimal_type`
 ///
 /// The result decimal type is `(max(s1, s2) + max(p1-s1, p2-s2), max(s1, s2))`.
 fn get_wider_decimal_type(
@@ -1316,6 +1330,47 @@ mod tests {
             DataType::Decimal128(10, 2),
         );
 
+        test_math_decimal_coercion_rule(
+            DataType::Decimal128(10, 2),
+            DataType::Decimal256(50, 4),
+            DataType::Decimal256(10, 2),
+            DataType::Decimal256(50, 4),
+        );
+
+        test_math_decimal_coercion_rule(
+            DataType::Decimal256(50, 4),
+            DataType::Int64,
+            DataType::Decimal256(50, 4),
+            DataType::Decimal256(20, 0),
+        );
+
+        test_math_decimal_coercion_rule(
+            DataType::Null,
+            DataType::Decimal256(50, 4),
+            DataType::Decimal256(50, 4),
+            DataType::Decimal256(50, 4),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal256_arithmetic_result_type() -> Result<()> {
+        let cases = [
+            (Operator::Plus, DataType::Decimal256(51, 4)),
+            (Operator::Minus, DataType::Decimal256(51, 4)),
+            (Operator::Multiply, DataType::Decimal256(61, 6)),
+            (Operator::Divide, DataType::Decimal256(56, 8)),
+            (Operator::Modulo, DataType::Decimal256(12, 4)),
+        ];
+        for (op, expected) in cases {
+            let result = get_result_type(
+                &DataType::Decimal256(50, 4),
+                &op,
+                &DataType::Decimal128(10, 2),
+            )?;
+            assert_eq!(result, expected, "{op}");
+        }
         Ok(())
     }
 
@@ -1392,6 +1447,18 @@ mod tests {
             Operator::GtEq,
             DataType::Decimal128(15, 3)
         );
+        test_coercion_binary_rule!(
+            DataType::Decimal128(14, 2),
+            DataType::Decimal256(50, 3),
+            Operator::Eq,
+            DataType::Decimal256(50, 3)
+        );
+        test_coercion_binary_rule!(
+            DataType::Decimal256(40, 0),
+            DataType::Int64,
+            Operator::Lt,
+            DataType::Decimal256(40, 0)
+        );
 
         // Binary
         test_coercion_binary_rule!(
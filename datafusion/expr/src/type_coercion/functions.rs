@@ -224,6 +224,7 @@ fn coerced_from<'a>(
                     | Float32
                     | Float64
                     | Decimal128(_, _)
+                    | Decimal256(_, _)
             ) =>
         {
             Some(type_into.clone())
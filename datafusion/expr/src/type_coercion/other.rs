@@ -33,6 +33,24 @@ pub fn get_coerce_type_for_list(
         })
 }
 
+/// Returns the type to coerce `key_type` to for looking it up in a map of
+/// `map_type`, which is the key type of the map if the two are comparable
+pub fn get_coerce_type_for_map_key(
+    map_type: &DataType,
+    key_type: &DataType,
+) -> Option<DataType> {
+    match map_type {
+        DataType::Map(field, _) => match field.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                let map_key_type = fields[0].data_type();
+                comparison_coercion(map_key_type, key_type).map(|_| map_key_type.clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Find a common coerceable type for all `when_or_then_types` as well
 /// and the `case_or_else_type`, if specified.
 /// Returns the common data type for `when_or_then_types` and `case_or_else_type`
@@ -296,7 +296,8 @@ pub fn expr_to_columns(expr: &Expr, accum: &mut HashSet<Column>) -> Result<()> {
             | Expr::QualifiedWildcard { .. }
             | Expr::GetIndexedField { .. }
             | Expr::Placeholder(_)
-            | Expr::OuterReferenceColumn { .. } => {}
+            | Expr::OuterReferenceColumn { .. }
+            | Expr::Lambda(_) => {}
         }
         Ok(())
     })
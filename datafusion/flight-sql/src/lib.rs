@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [Arrow Flight SQL] server for DataFusion
+//!
+//! [`FlightSqlServiceImpl`] implements the Flight SQL protocol on top of
+//! [`SessionContext`]s, so that JDBC, ODBC and ADBC clients can run queries
+//! with DataFusion. Each client session gets its own context, created by a
+//! [`SessionContextProvider`] when the client performs the handshake. Headers
+//! of the handshake request that start with `datafusion.` set configuration
+//! options of the new session, as do `SET` statements, if the options are
+//! allowed with [`FlightSqlServiceImpl::with_allowed_config_options`].
+//!
+//! ```no_run
+//! use datafusion::error::Result;
+//! use datafusion::prelude::*;
+//! use datafusion_flight_sql::FlightSqlServiceImpl;
+//! use tonic::transport::Server;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let service =
+//!     FlightSqlServiceImpl::new(|config: SessionConfig| -> Result<SessionContext> {
+//!         Ok(SessionContext::new_with_config(config))
+//!     })
+//!     .with_allowed_config_options(["datafusion.execution.batch_size"]);
+//! Server::builder()
+//!     .add_service(service.into_server())
+//!     .serve("0.0.0.0:50051".parse()?)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [Arrow Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
+//! [`SessionContext`]: datafusion::prelude::SessionContext
+
+mod metadata;
+mod service;
+mod session;
+
+pub use service::FlightSqlServiceImpl;
+pub use session::SessionContextProvider;
@@ -0,0 +1,153 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Catalog metadata of Flight SQL sessions
+//!
+//! The metadata describes the same catalogs, schemas and tables as the
+//! `information_schema` of the session, including the `information_schema`
+//! itself when it is enabled.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, StringArray};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use arrow_flight::sql::{CommandGetCatalogs, CommandGetDbSchemas, CommandGetTables};
+use datafusion::catalog::information_schema::InformationSchemaProvider;
+use datafusion::catalog::schema::SchemaProvider;
+use datafusion::catalog::CatalogProvider;
+use datafusion::datasource::TableType;
+use datafusion::error::Result;
+use datafusion::prelude::SessionContext;
+use once_cell::sync::Lazy;
+
+/// Name of the schema describing the catalogs of a session
+const INFORMATION_SCHEMA: &str = "information_schema";
+
+/// All table types, as named by `information_schema.tables`
+const TABLE_TYPES: &[TableType] =
+    &[TableType::Base, TableType::View, TableType::Temporary];
+
+/// Schema of the result of `CommandGetTableTypes`
+pub(crate) static TABLE_TYPES_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![Field::new(
+        "table_type",
+        DataType::Utf8,
+        false,
+    )]))
+});
+
+/// Return the name of a table type used by `information_schema.tables`
+pub(crate) fn table_type_name(table_type: TableType) -> &'static str {
+    match table_type {
+        TableType::Base => "BASE TABLE",
+        TableType::View => "VIEW",
+        TableType::Temporary => "LOCAL TEMPORARY",
+    }
+}
+
+/// Return the catalogs of a session
+pub(crate) fn catalogs(
+    ctx: &SessionContext,
+    query: CommandGetCatalogs,
+) -> Result<RecordBatch> {
+    let mut builder = query.into_builder();
+    for catalog_name in ctx.catalog_names() {
+        builder.append(catalog_name);
+    }
+    Ok(builder.build()?)
+}
+
+/// Return the schemas of `catalog`, including the `information_schema`
+/// when it is enabled
+fn schemas(
+    ctx: &SessionContext,
+    catalog: &dyn CatalogProvider,
+) -> Vec<(String, Arc<dyn SchemaProvider>)> {
+    let mut schemas: Vec<_> = catalog
+        .schema_names()
+        .into_iter()
+        .filter_map(|schema_name| {
+            // schema name may not exist in the catalog, so we need to check
+            let schema = catalog.schema(&schema_name)?;
+            Some((schema_name, schema))
+        })
+        .collect();
+    let state = ctx.state();
+    if state.config().information_schema() {
+        let information_schema = InformationSchemaProvider::new(state.catalog_list());
+        schemas.push((INFORMATION_SCHEMA.to_string(), Arc::new(information_schema)));
+    }
+    schemas
+}
+
+/// Return the schemas of a session matching the filters of the query
+pub(crate) fn db_schemas(
+    ctx: &SessionContext,
+    query: CommandGetDbSchemas,
+) -> Result<RecordBatch> {
+    let mut builder = query.into_builder();
+    for catalog_name in ctx.catalog_names() {
+        // catalogs may be deregistered concurrently
+        let Some(catalog) = ctx.catalog(&catalog_name) else {
+            continue;
+        };
+        for (schema_name, _) in schemas(ctx, catalog.as_ref()) {
+            builder.append(&catalog_name, schema_name);
+        }
+    }
+    Ok(builder.build()?)
+}
+
+/// Return the tables of a session matching the filters of the query
+pub(crate) async fn tables(
+    ctx: &SessionContext,
+    query: CommandGetTables,
+) -> Result<RecordBatch> {
+    let mut builder = query.into_builder();
+    for catalog_name in ctx.catalog_names() {
+        let Some(catalog) = ctx.catalog(&catalog_name) else {
+            continue;
+        };
+        for (schema_name, schema) in schemas(ctx, catalog.as_ref()) {
+            for table_name in schema.table_names() {
+                if let Some(table) = schema.table(&table_name).await {
+                    builder.append(
+                        &catalog_name,
+                        &schema_name,
+                        &table_name,
+                        table_type_name(table.table_type()),
+                        table.schema().as_ref(),
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(builder.build()?)
+}
+
+/// Return all table types
+pub(crate) fn table_types() -> Result<RecordBatch> {
+    let table_types: StringArray = TABLE_TYPES
+        .iter()
+        .map(|table_type| Some(table_type_name(*table_type)))
+        .collect();
+    Ok(RecordBatch::try_new(
+        TABLE_TYPES_SCHEMA.clone(),
+        vec![Arc::new(table_types) as ArrayRef],
+    )?)
+}
@@ -0,0 +1,941 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`FlightSqlService`] implementation running queries with DataFusion
+
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow::array::{Array, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::sql::metadata::{SqlInfoData, SqlInfoDataBuilder};
+use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
+use arrow_flight::sql::{
+    ActionBeginSavepointRequest, ActionBeginSavepointResult,
+    ActionBeginTransactionRequest, ActionBeginTransactionResult,
+    ActionCancelQueryRequest, ActionCancelQueryResult,
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, ActionCreatePreparedSubstraitPlanRequest,
+    ActionEndSavepointRequest, ActionEndTransactionRequest, CommandGetCatalogs,
+    CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys,
+    CommandGetImportedKeys, CommandGetPrimaryKeys, CommandGetSqlInfo,
+    CommandGetTableTypes, CommandGetTables, CommandGetXdbcTypeInfo,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
+    CommandStatementSubstraitPlan, CommandStatementUpdate, ProstMessageExt, SqlInfo,
+    TicketStatementQuery,
+};
+use arrow_flight::{
+    Action, ActionType, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
+    HandshakeResponse, IpcMessage, SchemaAsIpc, Ticket,
+};
+use dashmap::mapref::one::RefMut;
+use dashmap::DashMap;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::{LogicalPlan, SetVariable, Statement};
+use datafusion::prelude::SessionConfig;
+use datafusion::scalar::ScalarValue;
+use futures::{stream, Stream, TryStreamExt};
+use log::debug;
+use once_cell::sync::Lazy;
+use prost::Message;
+use tonic::metadata::MetadataValue;
+use tonic::{Request, Response, Status, Streaming};
+use uuid::Uuid;
+
+use crate::metadata;
+use crate::session::{
+    PendingStatement, PreparedStatement, Session, SessionContextProvider,
+};
+
+/// Prefix of the handshake request headers that set configuration options
+const CONFIG_HEADER_PREFIX: &str = "datafusion.";
+
+/// Type of the action closing the session of the request
+const CLOSE_SESSION_ACTION: &str = "CloseSession";
+
+/// Default time after which idle sessions are closed
+const DEFAULT_SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Default maximum number of open sessions
+const DEFAULT_MAX_SESSIONS: usize = 1024;
+
+/// Default time after which statements that were not fetched are discarded
+const DEFAULT_STATEMENT_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Default maximum number of statements waiting to be fetched, and of
+/// prepared statements, of each session
+const DEFAULT_MAX_STATEMENTS: usize = 1024;
+
+/// Information about the server returned for `CommandGetSqlInfo`
+static SQL_INFO: Lazy<SqlInfoData> = Lazy::new(|| {
+    let mut builder = SqlInfoDataBuilder::new();
+    builder.append(SqlInfo::FlightSqlServerName, "DataFusion");
+    builder.append(SqlInfo::FlightSqlServerVersion, env!("CARGO_PKG_VERSION"));
+    // Flight SQL protocol version
+    builder.append(SqlInfo::FlightSqlServerArrowVersion, "1.3");
+    builder.append(SqlInfo::FlightSqlServerReadOnly, false);
+    builder.build().unwrap()
+});
+
+type FlightDataStream = Pin<
+    Box<dyn Stream<Item = Result<arrow_flight::FlightData, Status>> + Send + 'static>,
+>;
+
+/// A Flight SQL service running queries with DataFusion
+///
+/// Every handshake starts a new session, whose [`SessionContext`] is created
+/// by the [`SessionContextProvider`]. The handshake response contains a
+/// bearer token identifying the session, which clients send in the
+/// `authorization` header of all further requests. No authentication takes
+/// place.
+///
+/// Sessions end with the `CloseSession` action, or are closed once idle for
+/// longer than the session idle timeout. When the maximum number of sessions
+/// is reached, a handshake closes the least recently used session.
+/// Statements are discarded if they are not fetched within the statement
+/// time to live, and prepared statements if they are not used within it.
+/// When a session reaches the maximum number of statements, or of prepared
+/// statements, planning another one discards the least recently used.
+///
+/// [`SessionContext`]: datafusion::prelude::SessionContext
+pub struct FlightSqlServiceImpl {
+    provider: Arc<dyn SessionContextProvider>,
+    /// Configuration new sessions start with
+    config: SessionConfig,
+    /// Configuration options clients may set in the handshake request
+    allowed_config_options: HashSet<String>,
+    /// Sessions by bearer token
+    sessions: Arc<DashMap<String, Arc<Session>>>,
+    /// Time after which idle sessions are closed
+    session_idle_timeout: Duration,
+    /// Maximum number of open sessions
+    max_sessions: usize,
+    /// Time after which statements that were not fetched, and prepared
+    /// statements that were not used, are discarded
+    statement_ttl: Duration,
+    /// Maximum number of statements waiting to be fetched, and of prepared
+    /// statements, of each session
+    max_statements: usize,
+}
+
+impl FlightSqlServiceImpl {
+    /// Create a service whose sessions are created by `provider`
+    pub fn new(provider: impl SessionContextProvider) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            config: SessionConfig::new(),
+            allowed_config_options: HashSet::new(),
+            sessions: Arc::new(DashMap::new()),
+            session_idle_timeout: DEFAULT_SESSION_IDLE_TIMEOUT,
+            max_sessions: DEFAULT_MAX_SESSIONS,
+            statement_ttl: DEFAULT_STATEMENT_TTL,
+            max_statements: DEFAULT_MAX_STATEMENTS,
+        }
+    }
+
+    /// Set the configuration new sessions start with, before options from the
+    /// handshake request are applied
+    pub fn with_config(mut self, config: SessionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the configuration options, such as `datafusion.execution.batch_size`,
+    /// that clients may set with headers of the handshake request or `SET`
+    /// statements. By default clients may not set any option.
+    pub fn with_allowed_config_options(
+        mut self,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.allowed_config_options = options.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the time after which idle sessions are closed, one hour by default
+    pub fn with_session_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.session_idle_timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of open sessions, 1024 by default
+    pub fn with_max_sessions(mut self, max_sessions: usize) -> Self {
+        self.max_sessions = max_sessions.max(1);
+        self
+    }
+
+    /// Set the time after which statements that were not fetched, and
+    /// prepared statements that were not used, are discarded, ten minutes by
+    /// default
+    pub fn with_statement_ttl(mut self, ttl: Duration) -> Self {
+        self.statement_ttl = ttl;
+        self
+    }
+
+    /// Set the maximum number of statements waiting to be fetched, and of
+    /// prepared statements, of each session, 1024 by default
+    pub fn with_max_statements(mut self, max_statements: usize) -> Self {
+        self.max_statements = max_statements.max(1);
+        self
+    }
+
+    /// Wrap the service into a tonic server
+    pub fn into_server(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    /// Return the bearer token in the `authorization` header
+    fn token<T>(request: &Request<T>) -> Result<&str, Status> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .ok_or_else(|| Status::unauthenticated("Missing authorization header"))?
+            .to_str()
+            .map_err(|e| {
+                Status::unauthenticated(format!("Invalid authorization header: {e}"))
+            })?;
+        authorization
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| Status::unauthenticated("Expected bearer token"))
+    }
+
+    /// Return the session of the bearer token in the `authorization` header
+    fn session<T>(&self, request: &Request<T>) -> Result<Arc<Session>, Status> {
+        let token = Self::token(request)?;
+        let session = match self.sessions.get(token) {
+            Some(session) => session.clone(),
+            None => {
+                return Err(Status::unauthenticated(format!(
+                    "Session not found: {token}"
+                )))
+            }
+        };
+        if session.is_idle(self.session_idle_timeout) {
+            self.sessions.remove(token);
+            debug!("Closed idle session {token}");
+            return Err(Status::unauthenticated(format!(
+                "Session not found: {token}"
+            )));
+        }
+        session.touch();
+        Ok(session)
+    }
+
+    /// Close idle sessions, and the least recently used sessions until a new
+    /// session can be started without exceeding the maximum number of
+    /// sessions
+    fn expire_sessions(&self) {
+        self.sessions
+            .retain(|_, session| !session.is_idle(self.session_idle_timeout));
+        while self.sessions.len() >= self.max_sessions {
+            let least_recently_used = self
+                .sessions
+                .iter()
+                .min_by_key(|entry| entry.value().last_used())
+                .map(|entry| entry.key().clone());
+            let Some(token) = least_recently_used else {
+                break;
+            };
+            self.sessions.remove(&token);
+            debug!("Closed least recently used session {token}");
+        }
+    }
+
+    /// Plan a SQL statement without executing it
+    async fn create_plan(
+        &self,
+        session: &Session,
+        sql: &str,
+    ) -> Result<LogicalPlan, Status> {
+        let plan = session
+            .ctx
+            .state()
+            .create_logical_plan(sql)
+            .await
+            .map_err(to_status)?;
+        // `SET` may only change the options clients may set in the handshake
+        if let LogicalPlan::Statement(Statement::SetVariable(SetVariable {
+            variable,
+            ..
+        })) = &plan
+        {
+            self.check_config_option(&variable.to_lowercase())?;
+        }
+        Ok(plan)
+    }
+
+    /// Return an error if clients may not set the configuration option `key`
+    fn check_config_option(&self, key: &str) -> Result<(), Status> {
+        if self.allowed_config_options.contains(key) {
+            Ok(())
+        } else {
+            Err(Status::permission_denied(format!(
+                "Configuration option {key} may not be set by clients"
+            )))
+        }
+    }
+
+    /// Store a plan to be executed when the client fetches the returned ticket
+    fn flight_info_for_plan(
+        &self,
+        session: &Session,
+        plan: LogicalPlan,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        session.expire_statements(self.statement_ttl, self.max_statements);
+        let schema: Schema = plan.schema().as_ref().into();
+        let handle = Uuid::new_v4().hyphenated().to_string();
+        session
+            .statements
+            .insert(handle.clone(), PendingStatement::new(plan));
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.into(),
+        };
+        flight_info(&schema, ticket.as_any(), descriptor)
+    }
+
+    /// Return the prepared statement of `handle`, recording that it is used
+    fn prepared_statement<'a>(
+        &self,
+        session: &'a Session,
+        handle: &str,
+    ) -> Result<RefMut<'a, String, PreparedStatement>, Status> {
+        match session.prepared_statements.get_mut(handle) {
+            Some(mut statement) if !statement.is_expired(self.statement_ttl) => {
+                statement.touch();
+                Ok(statement)
+            }
+            _ => Err(Status::not_found(format!(
+                "Prepared statement not found: {handle}"
+            ))),
+        }
+    }
+
+    /// Execute a statement, returning the number of affected rows, or -1 for
+    /// statements other than `INSERT`, `UPDATE` and `DELETE`
+    async fn execute_update(session: &Session, plan: LogicalPlan) -> Result<i64, Status> {
+        let is_dml = matches!(plan, LogicalPlan::Dml(_));
+        let batches = session
+            .ctx
+            .execute_logical_plan(plan)
+            .await
+            .map_err(to_status)?
+            .collect()
+            .await
+            .map_err(to_status)?;
+        if !is_dml {
+            return Ok(-1);
+        }
+        let count = batches
+            .iter()
+            .filter_map(|batch| batch.column(0).as_any().downcast_ref::<UInt64Array>())
+            .flat_map(|counts| counts.iter().flatten())
+            .sum::<u64>();
+        Ok(count as i64)
+    }
+
+    fn prepared_statement_handle(handle: &[u8]) -> Result<&str, Status> {
+        std::str::from_utf8(handle).map_err(|e| {
+            Status::invalid_argument(format!("Invalid prepared statement handle: {e}"))
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for FlightSqlServiceImpl {
+    type FlightService = FlightSqlServiceImpl;
+
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        let mut config = self.config.clone();
+        for entry in request.metadata().iter() {
+            let tonic::metadata::KeyAndValueRef::Ascii(key, value) = entry else {
+                continue;
+            };
+            let key = key.as_str();
+            if key.starts_with(CONFIG_HEADER_PREFIX) {
+                self.check_config_option(key)?;
+                let value = value.to_str().map_err(|e| {
+                    Status::invalid_argument(format!("Invalid value of {key}: {e}"))
+                })?;
+                config.options_mut().set(key, value).map_err(to_status)?;
+            }
+        }
+        let ctx = self.provider.new_context(config).await.map_err(to_status)?;
+
+        self.expire_sessions();
+        let token = Uuid::new_v4().hyphenated().to_string();
+        self.sessions
+            .insert(token.clone(), Arc::new(Session::new(ctx)));
+        debug!("Started session {token}");
+
+        let response = HandshakeResponse {
+            protocol_version: 0,
+            payload: token.as_bytes().to_vec().into(),
+        };
+        let output = stream::iter(vec![Ok(response)]);
+        let mut response: Response<
+            Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>,
+        > = Response::new(Box::pin(output));
+        let authorization = MetadataValue::try_from(format!("Bearer {token}"))
+            .map_err(|_| Status::internal("Invalid session token"))?;
+        response
+            .metadata_mut()
+            .insert("authorization", authorization);
+        Ok(response)
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let session = self.session(&request)?;
+        let plan = self.create_plan(&session, &query.query).await?;
+        let info = self.flight_info_for_plan(&session, plan, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_substrait_plan(
+        &self,
+        _query: CommandStatementSubstraitPlan,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("Substrait plans are not supported"))
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        cmd: CommandPreparedStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let session = self.session(&request)?;
+        let handle = Self::prepared_statement_handle(&cmd.prepared_statement_handle)?;
+        let plan = self
+            .prepared_statement(&session, handle)?
+            .bound_plan()
+            .map_err(to_status)?;
+        let info = self.flight_info_for_plan(&session, plan, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let ticket = query.as_any();
+        let schema = query.into_builder().schema();
+        let info = flight_info(&schema, ticket, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let ticket = query.as_any();
+        let schema = query.into_builder().schema();
+        let info = flight_info(&schema, ticket, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let ticket = query.as_any();
+        let schema = query.into_builder().schema();
+        let info = flight_info(&schema, ticket, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_table_types(
+        &self,
+        query: CommandGetTableTypes,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let info = flight_info(
+            &metadata::TABLE_TYPES_SCHEMA,
+            query.as_any(),
+            request.into_inner(),
+        )?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_sql_info(
+        &self,
+        query: CommandGetSqlInfo,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let ticket = query.as_any();
+        let schema = query.into_builder(&SQL_INFO).schema();
+        let info = flight_info(&schema, ticket, request.into_inner())?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_flight_info_primary_keys(
+        &self,
+        _query: CommandGetPrimaryKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("Primary keys are not supported"))
+    }
+
+    async fn get_flight_info_exported_keys(
+        &self,
+        _query: CommandGetExportedKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("Foreign keys are not supported"))
+    }
+
+    async fn get_flight_info_imported_keys(
+        &self,
+        _query: CommandGetImportedKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("Foreign keys are not supported"))
+    }
+
+    async fn get_flight_info_cross_reference(
+        &self,
+        _query: CommandGetCrossReference,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("Foreign keys are not supported"))
+    }
+
+    async fn get_flight_info_xdbc_type_info(
+        &self,
+        _query: CommandGetXdbcTypeInfo,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("XDBC type info is not supported"))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let session = self.session(&request)?;
+        let handle = std::str::from_utf8(&ticket.statement_handle)
+            .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))?;
+        // Tickets are fetched once
+        let plan = match session.statements.remove(handle) {
+            Some((_, statement)) if !statement.is_expired(self.statement_ttl) => {
+                statement.plan
+            }
+            _ => return Err(Status::not_found(format!("Statement not found: {handle}"))),
+        };
+        let stream = session
+            .ctx
+            .execute_logical_plan(plan)
+            .await
+            .map_err(to_status)?
+            .execute_stream()
+            .await
+            .map_err(to_status)?;
+        let schema = stream.schema();
+        let stream = stream.map_err(|e| FlightError::ExternalError(Box::new(e)));
+        Ok(Response::new(encode_stream(schema, stream)))
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        // Prepared statements are fetched with a `TicketStatementQuery`
+        Err(Status::unimplemented(
+            "Fetch prepared statements with the ticket returned by GetFlightInfo",
+        ))
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let session = self.session(&request)?;
+        let batch = metadata::catalogs(&session.ctx, query).map_err(to_status)?;
+        Ok(Response::new(encode_batch(batch)))
+    }
+
+    async fn do_get_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let session = self.session(&request)?;
+        let batch = metadata::db_schemas(&session.ctx, query).map_err(to_status)?;
+        Ok(Response::new(encode_batch(batch)))
+    }
+
+    async fn do_get_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let session = self.session(&request)?;
+        let batch = metadata::tables(&session.ctx, query)
+            .await
+            .map_err(to_status)?;
+        Ok(Response::new(encode_batch(batch)))
+    }
+
+    async fn do_get_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let batch = metadata::table_types().map_err(to_status)?;
+        Ok(Response::new(encode_batch(batch)))
+    }
+
+    async fn do_get_sql_info(
+        &self,
+        query: CommandGetSqlInfo,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let batch = query
+            .into_builder(&SQL_INFO)
+            .build()
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(encode_batch(batch)))
+    }
+
+    async fn do_get_primary_keys(
+        &self,
+        _query: CommandGetPrimaryKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("Primary keys are not supported"))
+    }
+
+    async fn do_get_exported_keys(
+        &self,
+        _query: CommandGetExportedKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("Foreign keys are not supported"))
+    }
+
+    async fn do_get_imported_keys(
+        &self,
+        _query: CommandGetImportedKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("Foreign keys are not supported"))
+    }
+
+    async fn do_get_cross_reference(
+        &self,
+        _query: CommandGetCrossReference,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("Foreign keys are not supported"))
+    }
+
+    async fn do_get_xdbc_type_info(
+        &self,
+        _query: CommandGetXdbcTypeInfo,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        Err(Status::unimplemented("XDBC type info is not supported"))
+    }
+
+    async fn do_put_statement_update(
+        &self,
+        ticket: CommandStatementUpdate,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        let session = self.session(&request)?;
+        let plan = self.create_plan(&session, &ticket.query).await?;
+        Self::execute_update(&session, plan).await
+    }
+
+    async fn do_put_prepared_statement_query(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
+        let session = self.session(&request)?;
+        let handle = Self::prepared_statement_handle(&query.prepared_statement_handle)?;
+        let parameters = decode_parameters(request.into_inner()).await?;
+        self.prepared_statement(&session, handle)?.parameters = Some(parameters);
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+
+    async fn do_put_prepared_statement_update(
+        &self,
+        query: CommandPreparedStatementUpdate,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        let session = self.session(&request)?;
+        let handle = Self::prepared_statement_handle(&query.prepared_statement_handle)?;
+        let parameters = decode_parameters(request.into_inner()).await?;
+        let plan = {
+            let statement = self.prepared_statement(&session, handle)?;
+            let plan = if parameters.is_empty() {
+                statement.bound_plan()
+            } else {
+                statement.plan.clone().with_param_values(parameters)
+            };
+            plan.map_err(to_status)?
+        };
+        Self::execute_update(&session, plan).await
+    }
+
+    async fn do_put_substrait_plan(
+        &self,
+        _query: CommandStatementSubstraitPlan,
+        _request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        Err(Status::unimplemented("Substrait plans are not supported"))
+    }
+
+    async fn do_action_create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let session = self.session(&request)?;
+        let plan = self.create_plan(&session, &query.query).await?;
+
+        let dataset_schema: Schema = plan.schema().as_ref().into();
+        let parameter_schema = parameter_schema(&plan)?;
+
+        session.expire_prepared_statements(self.statement_ttl, self.max_statements);
+        let handle = Uuid::new_v4().hyphenated().to_string();
+        session
+            .prepared_statements
+            .insert(handle.clone(), PreparedStatement::new(plan));
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: handle.into(),
+            dataset_schema: schema_to_ipc(&dataset_schema)?,
+            parameter_schema: schema_to_ipc(&parameter_schema)?,
+        })
+    }
+
+    async fn do_action_close_prepared_statement(
+        &self,
+        query: ActionClosePreparedStatementRequest,
+        request: Request<Action>,
+    ) -> Result<(), Status> {
+        let session = self.session(&request)?;
+        let handle = Self::prepared_statement_handle(&query.prepared_statement_handle)?;
+        session.prepared_statements.remove(handle);
+        Ok(())
+    }
+
+    async fn do_action_create_prepared_substrait_plan(
+        &self,
+        _query: ActionCreatePreparedSubstraitPlanRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        Err(Status::unimplemented("Substrait plans are not supported"))
+    }
+
+    async fn do_action_begin_transaction(
+        &self,
+        _query: ActionBeginTransactionRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionBeginTransactionResult, Status> {
+        Err(Status::unimplemented("Transactions are not supported"))
+    }
+
+    async fn do_action_end_transaction(
+        &self,
+        _query: ActionEndTransactionRequest,
+        _request: Request<Action>,
+    ) -> Result<(), Status> {
+        Err(Status::unimplemented("Transactions are not supported"))
+    }
+
+    async fn do_action_begin_savepoint(
+        &self,
+        _query: ActionBeginSavepointRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionBeginSavepointResult, Status> {
+        Err(Status::unimplemented("Savepoints are not supported"))
+    }
+
+    async fn do_action_end_savepoint(
+        &self,
+        _query: ActionEndSavepointRequest,
+        _request: Request<Action>,
+    ) -> Result<(), Status> {
+        Err(Status::unimplemented("Savepoints are not supported"))
+    }
+
+    async fn do_action_cancel_query(
+        &self,
+        _query: ActionCancelQueryRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCancelQueryResult, Status> {
+        Err(Status::unimplemented("Cancelling queries is not supported"))
+    }
+
+    async fn do_action_fallback(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<<Self as FlightService>::DoActionStream>, Status> {
+        let action_type = &request.get_ref().r#type;
+        if action_type != CLOSE_SESSION_ACTION {
+            return Err(Status::invalid_argument(format!(
+                "Unknown action: {action_type}"
+            )));
+        }
+        let token = Self::token(&request)?;
+        if self.sessions.remove(token).is_none() {
+            return Err(Status::unauthenticated(format!(
+                "Session not found: {token}"
+            )));
+        }
+        debug!("Closed session {token}");
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+
+    async fn list_custom_actions(&self) -> Option<Vec<Result<ActionType, Status>>> {
+        Some(vec![Ok(ActionType {
+            r#type: CLOSE_SESSION_ACTION.to_string(),
+            description: "Close the session of the request".to_string(),
+        })])
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+/// Convert a [`DataFusionError`] to a gRPC [`Status`]
+fn to_status(e: DataFusionError) -> Status {
+    match e {
+        DataFusionError::SQL(_)
+        | DataFusionError::Plan(_)
+        | DataFusionError::SchemaError(_)
+        | DataFusionError::Configuration(_) => Status::invalid_argument(e.to_string()),
+        DataFusionError::NotImplemented(_) => Status::unimplemented(e.to_string()),
+        e => Status::internal(e.to_string()),
+    }
+}
+
+/// Return a [`FlightInfo`] with a single endpoint, whose ticket is the
+/// given command
+fn flight_info(
+    schema: &Schema,
+    ticket: arrow_flight::sql::Any,
+    descriptor: FlightDescriptor,
+) -> Result<FlightInfo, Status> {
+    let endpoint = FlightEndpoint::new().with_ticket(Ticket::new(ticket.encode_to_vec()));
+    Ok(FlightInfo::new()
+        .try_with_schema(schema)
+        .map_err(|e| Status::internal(format!("Unable to encode schema: {e}")))?
+        .with_endpoint(endpoint)
+        .with_descriptor(descriptor))
+}
+
+fn schema_to_ipc(schema: &Schema) -> Result<bytes::Bytes, Status> {
+    let IpcMessage(schema_bytes) = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| Status::internal(format!("Unable to encode schema: {e}")))?;
+    Ok(schema_bytes)
+}
+
+/// Return the schema of the parameters of a plan, whose fields are named
+/// after the placeholders `$1`, `$2`, ...
+fn parameter_schema(plan: &LogicalPlan) -> Result<Schema, Status> {
+    let mut parameters = plan
+        .get_parameter_types()
+        .map_err(to_status)?
+        .into_iter()
+        .map(|(id, data_type)| {
+            let index = id[1..].parse::<usize>().map_err(|_| {
+                Status::invalid_argument(format!("Invalid placeholder: {id}"))
+            })?;
+            Ok((index, id, data_type))
+        })
+        .collect::<Result<Vec<_>, Status>>()?;
+    parameters.sort_by_key(|(index, _, _)| *index);
+    Ok(Schema::new(
+        parameters
+            .into_iter()
+            .map(|(_, id, data_type)| {
+                Field::new(id, data_type.unwrap_or(DataType::Null), true)
+            })
+            .collect::<Vec<_>>(),
+    ))
+}
+
+/// Decode the values of the parameters of a prepared statement, which are a
+/// single row sent by the client
+async fn decode_parameters(
+    stream: PeekableFlightDataStream,
+) -> Result<Vec<ScalarValue>, Status> {
+    let batches: Vec<RecordBatch> =
+        arrow_flight::decode::FlightRecordBatchStream::new_from_flight_data(
+            stream.map_err(FlightError::from),
+        )
+        .try_collect()
+        .await
+        .map_err(Status::from)?;
+    let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+    match num_rows {
+        0 => Ok(vec![]),
+        1 => {
+            let batch = batches.iter().find(|batch| batch.num_rows() == 1).unwrap();
+            batch
+                .columns()
+                .iter()
+                .map(|column| ScalarValue::try_from_array(column, 0))
+                .collect::<datafusion::error::Result<Vec<_>>>()
+                .map_err(to_status)
+        }
+        _ => Err(Status::invalid_argument(format!(
+            "Expected a single row of parameters, got {num_rows}"
+        ))),
+    }
+}
+
+fn encode_stream(
+    schema: SchemaRef,
+    stream: impl Stream<Item = Result<RecordBatch, FlightError>> + Send + 'static,
+) -> FlightDataStream {
+    Box::pin(
+        FlightDataEncoderBuilder::new()
+            .with_schema(schema)
+            .build(stream)
+            .map_err(Status::from),
+    )
+}
+
+fn encode_batch(batch: RecordBatch) -> FlightDataStream {
+    encode_stream(batch.schema(), stream::once(async { Ok(batch) }))
+}
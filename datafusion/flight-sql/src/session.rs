@@ -0,0 +1,187 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Flight SQL sessions
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use datafusion::error::Result;
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion::scalar::ScalarValue;
+
+/// Creates the [`SessionContext`] of each Flight SQL session
+///
+/// This is where tables, functions and other state that clients can use is
+/// registered. It is implemented for closures taking the [`SessionConfig`]
+/// of the session.
+#[async_trait]
+pub trait SessionContextProvider: Send + Sync + 'static {
+    /// Create the context of a new session with the given configuration
+    async fn new_context(&self, config: SessionConfig) -> Result<SessionContext>;
+}
+
+#[async_trait]
+impl<F> SessionContextProvider for F
+where
+    F: Fn(SessionConfig) -> Result<SessionContext> + Send + Sync + 'static,
+{
+    async fn new_context(&self, config: SessionConfig) -> Result<SessionContext> {
+        self(config)
+    }
+}
+
+/// A prepared statement of a session
+pub(crate) struct PreparedStatement {
+    /// The plan, which may contain placeholders
+    pub plan: LogicalPlan,
+    /// Values of the placeholders bound by the client, if any
+    pub parameters: Option<Vec<ScalarValue>>,
+    /// When the statement was last used by a request
+    last_used: Instant,
+}
+
+impl PreparedStatement {
+    pub fn new(plan: LogicalPlan) -> Self {
+        Self {
+            plan,
+            parameters: None,
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Record that the statement is used by a request
+    pub fn touch(&mut self) {
+        self.last_used = Instant::now();
+    }
+
+    /// Return true if the statement was not used for more than `ttl`
+    pub fn is_expired(&self, ttl: Duration) -> bool {
+        self.last_used.elapsed() > ttl
+    }
+
+    /// Return the plan with the bound parameters
+    pub fn bound_plan(&self) -> Result<LogicalPlan> {
+        match &self.parameters {
+            Some(parameters) => self.plan.clone().with_param_values(parameters.clone()),
+            None => Ok(self.plan.clone()),
+        }
+    }
+}
+
+/// A planned statement waiting to be fetched
+pub(crate) struct PendingStatement {
+    pub plan: LogicalPlan,
+    /// When the statement was planned
+    pub created: Instant,
+}
+
+impl PendingStatement {
+    pub fn new(plan: LogicalPlan) -> Self {
+        Self {
+            plan,
+            created: Instant::now(),
+        }
+    }
+
+    /// Return true if the statement was planned more than `ttl` ago
+    pub fn is_expired(&self, ttl: Duration) -> bool {
+        self.created.elapsed() > ttl
+    }
+}
+
+/// State of a client session, identified by the bearer token returned by
+/// the handshake
+pub(crate) struct Session {
+    pub ctx: SessionContext,
+    /// Prepared statements by handle
+    pub prepared_statements: DashMap<String, PreparedStatement>,
+    /// Planned statements waiting to be fetched, by ticket handle
+    pub statements: DashMap<String, PendingStatement>,
+    /// When the session was last used by a request
+    last_used: Mutex<Instant>,
+}
+
+impl Session {
+    pub fn new(ctx: SessionContext) -> Self {
+        Self {
+            ctx,
+            prepared_statements: DashMap::new(),
+            statements: DashMap::new(),
+            last_used: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Record that the session is used by a request
+    pub fn touch(&self) {
+        *self.last_used.lock().unwrap() = Instant::now();
+    }
+
+    /// When the session was last used by a request
+    pub fn last_used(&self) -> Instant {
+        *self.last_used.lock().unwrap()
+    }
+
+    /// Return true if the session was not used for more than `timeout`
+    pub fn is_idle(&self, timeout: Duration) -> bool {
+        self.last_used().elapsed() > timeout
+    }
+
+    /// Discard the statements planned more than `ttl` ago that were never
+    /// fetched, and the oldest ones until another statement can be planned
+    /// without exceeding `max_statements`
+    pub fn expire_statements(&self, ttl: Duration, max_statements: usize) {
+        self.statements
+            .retain(|_, statement| !statement.is_expired(ttl));
+        evict_oldest(&self.statements, max_statements, |statement| {
+            statement.created
+        });
+    }
+
+    /// Discard the prepared statements not used for more than `ttl`, and the
+    /// least recently used ones until another statement can be prepared
+    /// without exceeding `max_statements`
+    pub fn expire_prepared_statements(&self, ttl: Duration, max_statements: usize) {
+        self.prepared_statements
+            .retain(|_, statement| !statement.is_expired(ttl));
+        evict_oldest(&self.prepared_statements, max_statements, |statement| {
+            statement.last_used
+        });
+    }
+}
+
+/// Remove the entries of `map` with the earliest `time` until it has less
+/// than `max_len` entries
+fn evict_oldest<V>(
+    map: &DashMap<String, V>,
+    max_len: usize,
+    time: impl Fn(&V) -> Instant,
+) {
+    while map.len() >= max_len {
+        let oldest = map
+            .iter()
+            .min_by_key(|entry| time(entry.value()))
+            .map(|entry| entry.key().clone());
+        let Some(handle) = oldest else {
+            break;
+        };
+        map.remove(&handle);
+    }
+}
@@ -0,0 +1,431 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow::array::{Int64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::{CommandGetDbSchemas, CommandGetTables};
+use arrow_flight::{Action, FlightInfo};
+use datafusion::assert_batches_eq;
+use datafusion::error::Result;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion_flight_sql::FlightSqlServiceImpl;
+use futures::TryStreamExt;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::{Channel, Endpoint, Server};
+
+#[tokio::test]
+async fn statement() {
+    let mut client = connect().await;
+    let info = client
+        .execute(
+            "SELECT a, b FROM t WHERE a > 1 ORDER BY a".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 2 | y |",
+        "| 3 | z |",
+        "+---+---+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+}
+
+#[tokio::test]
+async fn statement_error() {
+    let mut client = connect().await;
+    let err = client
+        .execute("SELECT * FROM missing".to_string(), None)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("table 'datafusion.public.missing' not found"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn prepared_statement() {
+    let mut client = connect().await;
+    let mut statement = client
+        .prepare("SELECT b FROM t WHERE a = $1".to_string(), None)
+        .await
+        .unwrap();
+    let parameter_schema = statement.parameter_schema().unwrap().clone();
+    assert_eq!(
+        parameter_schema,
+        Schema::new(vec![Field::new("$1", DataType::Int64, true)])
+    );
+
+    for (value, expected) in [(1, "x"), (3, "z")] {
+        let parameters = RecordBatch::try_new(
+            Arc::new(parameter_schema.clone()),
+            vec![Arc::new(Int64Array::from(vec![value]))],
+        )
+        .unwrap();
+        statement.set_parameters(parameters).unwrap();
+        let info = statement.execute().await.unwrap();
+        let batches = fetch(&mut client, info).await;
+        let row = format!("| {expected} |");
+        let expected = vec!["+---+", "| b |", "+---+", row.as_str(), "+---+"];
+        assert_batches_eq!(expected, &batches);
+    }
+    statement.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn update() {
+    let mut client = connect().await;
+    let count = client
+        .execute_update("CREATE TABLE u (x BIGINT)".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(count, -1);
+    let count = client
+        .execute_update("INSERT INTO u VALUES (1), (2)".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+
+    let info = client
+        .execute("SELECT SUM(x) AS s FROM u".to_string(), None)
+        .await
+        .unwrap();
+    let expected = vec!["+---+", "| s |", "+---+", "| 3 |", "+---+"];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+}
+
+#[tokio::test]
+async fn catalog_metadata() {
+    let mut client = connect().await;
+
+    let info = client.get_catalogs().await.unwrap();
+    let expected = vec![
+        "+--------------+",
+        "| catalog_name |",
+        "+--------------+",
+        "| datafusion   |",
+        "+--------------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+
+    // the information_schema is enabled by the configuration of the service
+    let info = client
+        .get_db_schemas(CommandGetDbSchemas {
+            catalog: None,
+            db_schema_filter_pattern: None,
+        })
+        .await
+        .unwrap();
+    let expected = vec![
+        "+--------------+--------------------+",
+        "| catalog_name | db_schema_name     |",
+        "+--------------+--------------------+",
+        "| datafusion   | information_schema |",
+        "| datafusion   | public             |",
+        "+--------------+--------------------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+
+    let info = client
+        .get_tables(CommandGetTables {
+            catalog: None,
+            db_schema_filter_pattern: Some("information_schema".to_string()),
+            table_name_filter_pattern: Some("t%".to_string()),
+            table_types: vec![],
+            include_schema: false,
+        })
+        .await
+        .unwrap();
+    let expected = vec![
+        "+--------------+--------------------+------------+------------+",
+        "| catalog_name | db_schema_name     | table_name | table_type |",
+        "+--------------+--------------------+------------+------------+",
+        "| datafusion   | information_schema | tables     | VIEW       |",
+        "+--------------+--------------------+------------+------------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+
+    let info = client
+        .get_tables(CommandGetTables {
+            catalog: None,
+            db_schema_filter_pattern: Some("public".to_string()),
+            table_name_filter_pattern: None,
+            table_types: vec![],
+            include_schema: false,
+        })
+        .await
+        .unwrap();
+    let expected = vec![
+        "+--------------+----------------+------------+------------+",
+        "| catalog_name | db_schema_name | table_name | table_type |",
+        "+--------------+----------------+------------+------------+",
+        "| datafusion   | public         | t          | BASE TABLE |",
+        "+--------------+----------------+------------+------------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+
+    let info = client.get_table_types().await.unwrap();
+    let expected = vec![
+        "+-----------------+",
+        "| table_type      |",
+        "+-----------------+",
+        "| BASE TABLE      |",
+        "| VIEW            |",
+        "| LOCAL TEMPORARY |",
+        "+-----------------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut client, info).await);
+}
+
+#[tokio::test]
+async fn session_config() {
+    let addr = start_server().await;
+    let mut configured = client(&addr, &[("datafusion.execution.batch_size", "1")]).await;
+    let mut default = client(&addr, &[]).await;
+
+    let sql = "SELECT value FROM information_schema.df_settings \
+               WHERE name = 'datafusion.execution.batch_size'";
+    let info = configured.execute(sql.to_string(), None).await.unwrap();
+    let expected = vec![
+        "+-------+",
+        "| value |",
+        "+-------+",
+        "| 1     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut configured, info).await);
+
+    let info = default.execute(sql.to_string(), None).await.unwrap();
+    let expected = vec![
+        "+-------+",
+        "| value |",
+        "+-------+",
+        "| 8192  |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &fetch(&mut default, info).await);
+
+    // Sessions do not share tables
+    configured
+        .execute_update("CREATE VIEW v AS SELECT 1".to_string(), None)
+        .await
+        .unwrap();
+    let err = default
+        .execute("SELECT * FROM v".to_string(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not found"), "{err}");
+}
+
+#[tokio::test]
+async fn session_config_not_allowed() {
+    let addr = start_server().await;
+    let channel = Endpoint::from_shared(addr)
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = FlightSqlServiceClient::new(channel);
+    client.set_header("datafusion.execution.target_partitions", "1");
+    let err = client.handshake("", "").await.unwrap_err();
+    assert!(
+        err.to_string().contains(
+            "Configuration option datafusion.execution.target_partitions \
+             may not be set by clients"
+        ),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn set_config_not_allowed() {
+    let mut client = connect().await;
+    let err = client
+        .execute(
+            "SET datafusion.execution.target_partitions = 1".to_string(),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains(
+            "Configuration option datafusion.execution.target_partitions \
+             may not be set by clients"
+        ),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn close_session() {
+    let mut client = connect().await;
+    client
+        .do_action(Action::new("CloseSession", ""))
+        .await
+        .unwrap();
+    let err = client
+        .execute("SELECT 1".to_string(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Session not found"), "{err}");
+}
+
+#[tokio::test]
+async fn max_sessions() {
+    let addr = start_server_with(service().with_max_sessions(1)).await;
+    let mut first = client(&addr, &[]).await;
+    let mut second = client(&addr, &[]).await;
+
+    // the least recently used session is closed
+    let err = first
+        .execute("SELECT 1".to_string(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Session not found"), "{err}");
+    second.execute("SELECT 1".to_string(), None).await.unwrap();
+}
+
+#[tokio::test]
+async fn expired_sessions_and_statements() {
+    let addr = start_server_with(
+        service()
+            .with_session_idle_timeout(Duration::from_millis(100))
+            .with_statement_ttl(Duration::ZERO),
+    )
+    .await;
+    let mut client = client(&addr, &[]).await;
+
+    // statements that are not fetched in time are discarded
+    let info = client.execute("SELECT 1".to_string(), None).await.unwrap();
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let err = client.do_get(ticket).await.unwrap_err();
+    assert!(err.to_string().contains("Statement not found"), "{err}");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let err = client
+        .execute("SELECT 1".to_string(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Session not found"), "{err}");
+}
+
+#[tokio::test]
+async fn max_prepared_statements() {
+    let addr = start_server_with(service().with_max_statements(1)).await;
+    let mut client = client(&addr, &[]).await;
+
+    // preparing a statement discards the least recently used one
+    let mut first = client.prepare("SELECT 1".to_string(), None).await.unwrap();
+    let mut second = client.prepare("SELECT 2".to_string(), None).await.unwrap();
+    let err = first.execute().await.unwrap_err();
+    assert!(
+        err.to_string().contains("Prepared statement not found"),
+        "{err}"
+    );
+    second.execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn expired_prepared_statements() {
+    let addr = start_server_with(service().with_statement_ttl(Duration::ZERO)).await;
+    let mut client = client(&addr, &[]).await;
+
+    // prepared statements that are not used in time are discarded
+    let mut statement = client.prepare("SELECT 1".to_string(), None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let err = statement.execute().await.unwrap_err();
+    assert!(
+        err.to_string().contains("Prepared statement not found"),
+        "{err}"
+    );
+}
+
+/// Return a service with the table `t` registered in every session
+fn service() -> FlightSqlServiceImpl {
+    FlightSqlServiceImpl::new(|config: SessionConfig| -> Result<SessionContext> {
+        let ctx = SessionContext::new_with_config(config);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int64Array::from(vec![1, 2, 3])) as _),
+            ("b", Arc::new(StringArray::from(vec!["x", "y", "z"])) as _),
+        ])?;
+        ctx.register_batch("t", batch)?;
+        Ok(ctx)
+    })
+    .with_config(SessionConfig::new().with_information_schema(true))
+    .with_allowed_config_options(["datafusion.execution.batch_size"])
+}
+
+/// Start a server running [`service`], returning its address
+async fn start_server() -> String {
+    start_server_with(service()).await
+}
+
+/// Start a server running `service`, returning its address
+async fn start_server_with(service: FlightSqlServiceImpl) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(service.into_server())
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    format!("http://{addr}")
+}
+
+/// Connect a client starting a new session with the given headers
+async fn client(addr: &str, headers: &[(&str, &str)]) -> FlightSqlServiceClient<Channel> {
+    let channel = Endpoint::from_shared(addr.to_string())
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = FlightSqlServiceClient::new(channel);
+    for (key, value) in headers {
+        client.set_header(*key, *value);
+    }
+    client.handshake("", "").await.unwrap();
+    client
+}
+
+async fn connect() -> FlightSqlServiceClient<Channel> {
+    client(&start_server().await, &[]).await
+}
+
+async fn fetch(
+    client: &mut FlightSqlServiceClient<Channel>,
+    info: FlightInfo,
+) -> Vec<RecordBatch> {
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    client
+        .do_get(ticket)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap()
+}
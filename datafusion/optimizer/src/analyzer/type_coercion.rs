@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 
-use arrow::datatypes::{DataType, IntervalUnit};
+use arrow::datatypes::{DataType, Field, IntervalUnit};
 
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::tree_node::{RewriteRecursion, TreeNodeRewriter};
@@ -28,8 +28,8 @@ use datafusion_common::{
     DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::expr::{
-    self, Between, BinaryExpr, Case, Exists, InList, InSubquery, Like, ScalarFunction,
-    ScalarUDF, WindowFunction,
+    self, Between, BinaryExpr, Case, Exists, InList, InSubquery, Lambda, Like,
+    ScalarFunction, ScalarUDF, WindowFunction,
 };
 use datafusion_expr::expr_rewriter::rewrite_preserving_name;
 use datafusion_expr::expr_schema::cast_subquery;
@@ -40,6 +40,7 @@ use datafusion_expr::type_coercion::binary::{
 use datafusion_expr::type_coercion::functions::data_types;
 use datafusion_expr::type_coercion::other::{
     get_coerce_type_for_case_expression, get_coerce_type_for_list,
+    get_coerce_type_for_map_key,
 };
 use datafusion_expr::type_coercion::{is_datetime, is_utf8_or_large_utf8};
 use datafusion_expr::{
@@ -176,6 +177,18 @@ impl TreeNodeRewriter for TypeCoercionRewriter {
                     negated,
                 )))
             }
+            Expr::Lambda(lambda) => {
+                // the body refers to the lambda parameters rather than to
+                // the input columns
+                let mut rewriter = TypeCoercionRewriter {
+                    schema: Arc::new(lambda.params_schema()?),
+                };
+                let body = rewrite_preserving_name(*lambda.body, &mut rewriter)?;
+                Ok(Expr::Lambda(Lambda {
+                    body: Box::new(body),
+                    ..lambda
+                }))
+            }
             Expr::IsTrue(expr) => {
                 let expr = is_true(get_casted_expr_for_bool_op(&expr, &self.schema)?);
                 Ok(expr)
@@ -579,6 +592,37 @@ fn coerce_arguments_for_fun(
             .collect::<Result<Vec<_>>>()?;
     }
 
+    if *fun == BuiltinScalarFunction::ElementAt && expressions.len() == 2 {
+        // Cast the key to the key type of the map
+        let map_type = expressions[0].get_type(schema)?;
+        let key_type = expressions[1].get_type(schema)?;
+        if let Some(to_type) = get_coerce_type_for_map_key(&map_type, &key_type) {
+            expressions[1] = cast_expr(&expressions[1], &to_type, schema)?;
+        }
+    }
+
+    if matches!(
+        fun,
+        BuiltinScalarFunction::ArrayUnion | BuiltinScalarFunction::ArrayExcept
+    ) && expressions.len() == 2
+    {
+        // Cast both lists to a common element type
+        let left_type = expressions[0].get_type(schema)?;
+        let right_type = expressions[1].get_type(schema)?;
+        if let (DataType::List(left), DataType::List(right)) = (&left_type, &right_type) {
+            if let Some(element_type) =
+                comparison_coercion(left.data_type(), right.data_type())
+            {
+                let to_type =
+                    DataType::List(Arc::new(Field::new("item", element_type, true)));
+                expressions = expressions
+                    .iter()
+                    .map(|expr| cast_expr(expr, &to_type, schema))
+                    .collect::<Result<Vec<_>>>()?;
+            }
+        }
+    }
+
     if *fun == BuiltinScalarFunction::MakeArray {
         // Find the final data type for the function arguments
         let current_types = expressions
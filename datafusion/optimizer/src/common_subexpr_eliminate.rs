@@ -497,6 +497,7 @@ enum ExprMask {
     /// - [`Alias`](Expr::Alias)
     /// - [`Sort`](Expr::Sort)
     /// - [`Wildcard`](Expr::Wildcard)
+    /// - [`Lambda`](Expr::Lambda), which can only be evaluated as an argument
     /// - [`AggregateFunction`](Expr::AggregateFunction)
     /// - [`AggregateUDF`](Expr::AggregateUDF)
     Normal,
@@ -515,6 +516,7 @@ impl ExprMask {
                 | Expr::Alias(..)
                 | Expr::Sort { .. }
                 | Expr::Wildcard
+                | Expr::Lambda(..)
         );
 
         let is_aggr = matches!(
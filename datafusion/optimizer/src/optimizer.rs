@@ -39,6 +39,7 @@ use crate::push_down_projection::PushDownProjection;
 use crate::replace_distinct_aggregate::ReplaceDistinctWithAggregate;
 use crate::rewrite_disjunctive_predicate::RewriteDisjunctivePredicate;
 use crate::scalar_subquery_to_join::ScalarSubqueryToJoin;
+use crate::share_common_subplans::ShareCommonSubplans;
 use crate::simplify_expressions::SimplifyExpressions;
 use crate::single_distinct_to_groupby::SingleDistinctToGroupBy;
 use crate::unwrap_cast_in_comparison::UnwrapCastInComparison;
@@ -122,6 +123,12 @@ impl OptimizerContext {
         self
     }
 
+    /// Specify whether to enable the share_common_subplans rule
+    pub fn with_share_common_subplans(mut self, share_common_subplans: bool) -> Self {
+        self.options.optimizer.share_common_subplans = share_common_subplans;
+        self
+    }
+
     /// Specify whether the optimizer should skip rules that produce
     /// errors, or fail the query
     pub fn with_query_execution_start_time(
@@ -259,6 +266,9 @@ impl Optimizer {
             Arc::new(EliminateProjection::new()),
             // PushDownProjection can pushdown Projections through Limits, do PushDownLimit again.
             Arc::new(PushDownLimit::new()),
+            // Must be last, so that only subplans that are still identical
+            // after all other rewrites are shared
+            Arc::new(ShareCommonSubplans::new()),
         ];
 
         Self::with_rules(rules)
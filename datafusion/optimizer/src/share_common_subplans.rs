@@ -0,0 +1,474 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Optimizer rule to execute identical subplans, such as CTEs that are
+//! referenced several times, only once.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::optimizer::ApplyOrder;
+use crate::{OptimizerConfig, OptimizerRule};
+use datafusion_common::tree_node::{TreeNode, VisitRecursion};
+use datafusion_common::{DFSchemaRef, Result};
+use datafusion_expr::expr::{ScalarFunction, ScalarUDF};
+use datafusion_expr::{
+    Expr, Extension, LogicalPlan, UserDefinedLogicalNodeCore, Volatility,
+};
+
+/// A subplan whose output is shared by all places it is used in the plan.
+///
+/// All [`SharedSubplan`]s with the same `id` have the same input, which
+/// is executed only once. The results are returned to every consumer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SharedSubplan {
+    id: usize,
+    input: Arc<LogicalPlan>,
+}
+
+impl SharedSubplan {
+    /// Create a new shared subplan `id` of `input`
+    pub fn new(id: usize, input: Arc<LogicalPlan>) -> Self {
+        Self { id, input }
+    }
+
+    /// Identifies the shared subplan
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The shared subplan
+    pub fn input(&self) -> &Arc<LogicalPlan> {
+        &self.input
+    }
+}
+
+impl UserDefinedLogicalNodeCore for SharedSubplan {
+    fn name(&self) -> &str {
+        "SharedSubplan"
+    }
+
+    fn inputs(&self) -> Vec<&LogicalPlan> {
+        vec![&self.input]
+    }
+
+    fn schema(&self) -> &DFSchemaRef {
+        self.input.schema()
+    }
+
+    fn expressions(&self) -> Vec<Expr> {
+        vec![]
+    }
+
+    fn fmt_for_explain(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SharedSubplan: id={}", self.id)
+    }
+
+    fn from_template(&self, _exprs: &[Expr], inputs: &[LogicalPlan]) -> Self {
+        Self {
+            id: self.id,
+            input: Arc::new(inputs[0].clone()),
+        }
+    }
+}
+
+/// Optimization rule that finds identical subplans, such as a CTE that
+/// is referenced several times or a repeated subquery, and replaces
+/// every occurrence with a [`SharedSubplan`] so that it is only
+/// executed once.
+///
+/// For example:
+///
+/// ```text
+/// WITH t AS (SELECT a, count(*) AS c FROM x GROUP BY a)
+/// SELECT * FROM t WHERE c > 10 UNION ALL SELECT * FROM t WHERE c < 2
+/// ```
+///
+/// Is rewritten so the aggregation of `x` is executed once:
+///
+/// ```text
+/// Union
+///   Filter: t.c > 10
+///     SharedSubplan: id=0
+///       SubqueryAlias: t
+///         Aggregate: groupBy=[[x.a]], aggr=[[COUNT(*)]]
+///           TableScan: x
+///   Filter: t.c < 2
+///     SharedSubplan: id=0
+///       SubqueryAlias: t
+///         Aggregate: groupBy=[[x.a]], aggr=[[COUNT(*)]]
+///           TableScan: x
+/// ```
+///
+/// As predicates are not pushed down through a [`SharedSubplan`], only
+/// subplans that do more than scan a table are shared, and subplans
+/// with volatile functions or outer references are never shared.
+#[derive(Default)]
+pub struct ShareCommonSubplans {}
+
+impl ShareCommonSubplans {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for ShareCommonSubplans {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        if !config.options().optimizer.share_common_subplans
+            || contains_shared_subplan(plan)
+        {
+            return Ok(None);
+        }
+
+        let mut counts = HashMap::new();
+        count_subplans(plan, &mut counts);
+        if !counts.values().any(|count| *count > 1) {
+            return Ok(None);
+        }
+
+        let mut sharer = SubplanSharer {
+            counts,
+            shared: HashMap::new(),
+            next_id: 0,
+        };
+        let Some(new_plan) = sharer.share(plan)? else {
+            return Ok(None);
+        };
+
+        // a shared subplan nested in another shared subplan may only be
+        // executed once, in which case there is nothing to share
+        let mut consumers = HashMap::new();
+        count_consumers(&new_plan, &mut consumers);
+        if consumers.values().all(|count| *count > 1) {
+            return Ok(Some(new_plan));
+        }
+        Ok(Some(unshare(&new_plan, &consumers)?.unwrap_or(new_plan)))
+    }
+
+    fn name(&self) -> &str {
+        "share_common_subplans"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        None
+    }
+}
+
+/// Counts the occurrences of each subplan of `plan`
+fn count_subplans<'a>(
+    plan: &'a LogicalPlan,
+    counts: &mut HashMap<&'a LogicalPlan, usize>,
+) {
+    *counts.entry(plan).or_insert(0) += 1;
+    for input in plan.inputs() {
+        count_subplans(input, counts);
+    }
+}
+
+/// Replaces the subplans that occur more than once with a [`SharedSubplan`]
+struct SubplanSharer<'a> {
+    /// The number of occurrences of each subplan
+    counts: HashMap<&'a LogicalPlan, usize>,
+    /// The [`SharedSubplan`] replacing each shared subplan
+    shared: HashMap<&'a LogicalPlan, LogicalPlan>,
+    /// The id of the next [`SharedSubplan`]
+    next_id: usize,
+}
+
+impl<'a> SubplanSharer<'a> {
+    /// Rewrites `plan`, returning `None` if nothing was replaced
+    fn share(&mut self, plan: &'a LogicalPlan) -> Result<Option<LogicalPlan>> {
+        if self.counts.get(plan).copied().unwrap_or(0) > 1 && is_shareable(plan) {
+            if let Some(existing) = self.shared.get(plan) {
+                return Ok(Some(existing.clone()));
+            }
+            let id = self.next_id;
+            self.next_id += 1;
+            let input = self.share_inputs(plan)?.unwrap_or_else(|| plan.clone());
+            let new_plan = LogicalPlan::Extension(Extension {
+                node: Arc::new(SharedSubplan::new(id, Arc::new(input))),
+            });
+            self.shared.insert(plan, new_plan.clone());
+            return Ok(Some(new_plan));
+        }
+        self.share_inputs(plan)
+    }
+
+    fn share_inputs(&mut self, plan: &'a LogicalPlan) -> Result<Option<LogicalPlan>> {
+        let inputs = plan.inputs();
+        let mut new_inputs = Vec::with_capacity(inputs.len());
+        let mut changed = false;
+        for input in inputs {
+            match self.share(input)? {
+                Some(new_input) => {
+                    changed = true;
+                    new_inputs.push(new_input);
+                }
+                None => new_inputs.push(input.clone()),
+            }
+        }
+        if changed {
+            Ok(Some(plan.with_new_inputs(&new_inputs)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Counts the consumers of each [`SharedSubplan`], visiting the input
+/// of each [`SharedSubplan`] once as it is only executed once
+fn count_consumers(plan: &LogicalPlan, consumers: &mut HashMap<usize, usize>) {
+    if let Some(shared) = as_shared_subplan(plan) {
+        let count = consumers.entry(shared.id).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            return;
+        }
+    }
+    for input in plan.inputs() {
+        count_consumers(input, consumers);
+    }
+}
+
+/// Replaces the [`SharedSubplan`]s with a single consumer with their input
+fn unshare(
+    plan: &LogicalPlan,
+    consumers: &HashMap<usize, usize>,
+) -> Result<Option<LogicalPlan>> {
+    if let Some(shared) = as_shared_subplan(plan) {
+        if consumers[&shared.id] == 1 {
+            let input = shared.input.as_ref();
+            return Ok(Some(unshare(input, consumers)?.unwrap_or(input.clone())));
+        }
+    }
+
+    let inputs = plan.inputs();
+    let mut new_inputs = Vec::with_capacity(inputs.len());
+    let mut changed = false;
+    for input in inputs {
+        match unshare(input, consumers)? {
+            Some(new_input) => {
+                changed = true;
+                new_inputs.push(new_input);
+            }
+            None => new_inputs.push(input.clone()),
+        }
+    }
+    if changed {
+        Ok(Some(plan.with_new_inputs(&new_inputs)?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn as_shared_subplan(plan: &LogicalPlan) -> Option<&SharedSubplan> {
+    match plan {
+        LogicalPlan::Extension(Extension { node }) => {
+            node.as_any().downcast_ref::<SharedSubplan>()
+        }
+        _ => None,
+    }
+}
+
+fn contains_shared_subplan(plan: &LogicalPlan) -> bool {
+    as_shared_subplan(plan).is_some()
+        || plan.inputs().into_iter().any(contains_shared_subplan)
+}
+
+/// Returns true if executing `plan` once for all of its occurrences is
+/// both correct and worthwhile
+fn is_shareable(plan: &LogicalPlan) -> bool {
+    match plan {
+        // scanning a table twice allows different predicates and
+        // projections to be pushed into each scan
+        LogicalPlan::TableScan(_)
+        | LogicalPlan::EmptyRelation(_)
+        | LogicalPlan::Values(_)
+        | LogicalPlan::Extension(_)
+        | LogicalPlan::Dml(_)
+        | LogicalPlan::Ddl(_)
+        | LogicalPlan::Copy(_)
+        | LogicalPlan::Statement(_)
+        | LogicalPlan::Prepare(_)
+        | LogicalPlan::Explain(_)
+        | LogicalPlan::Analyze(_)
+        | LogicalPlan::DescribeTable(_) => false,
+        _ => {
+            contains_table_scan(plan)
+                && plan.all_out_ref_exprs().is_empty()
+                && !contains_volatile_expr(plan)
+        }
+    }
+}
+
+fn contains_table_scan(plan: &LogicalPlan) -> bool {
+    matches!(plan, LogicalPlan::TableScan(_))
+        || plan.inputs().into_iter().any(contains_table_scan)
+}
+
+fn contains_volatile_expr(plan: &LogicalPlan) -> bool {
+    let mut is_volatile = false;
+    plan.inspect_expressions(|expr| {
+        expr.apply(&mut |expr| {
+            let volatility = match expr {
+                Expr::ScalarFunction(ScalarFunction { fun, .. }) => fun.volatility(),
+                Expr::ScalarUDF(ScalarUDF { fun, .. }) => fun.signature.volatility,
+                _ => return Ok(VisitRecursion::Continue),
+            };
+            if volatility == Volatility::Volatile {
+                is_volatile = true;
+                return Ok(VisitRecursion::Stop);
+            }
+            Ok(VisitRecursion::Continue)
+        })?;
+        Ok(()) as Result<()>
+    })
+    // closure always returns OK
+    .unwrap();
+    is_volatile || plan.inputs().into_iter().any(contains_volatile_expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::*;
+    use crate::OptimizerContext;
+    use datafusion_expr::{col, count, lit, random, LogicalPlanBuilder};
+
+    fn optimize(plan: &LogicalPlan) -> Result<LogicalPlan> {
+        let config = OptimizerContext::new().with_share_common_subplans(true);
+        Ok(ShareCommonSubplans::new()
+            .try_optimize(plan, &config)?
+            .unwrap_or_else(|| plan.clone()))
+    }
+
+    fn assert_optimized_plan_equal(plan: &LogicalPlan, expected: &str) -> Result<()> {
+        let optimized_plan = optimize(plan)?;
+        assert_eq!(format!("{optimized_plan:?}"), expected);
+        Ok(())
+    }
+
+    fn cte() -> Result<LogicalPlan> {
+        LogicalPlanBuilder::from(test_table_scan()?)
+            .aggregate(vec![col("a")], vec![count(col("b"))])?
+            .alias("t")?
+            .build()
+    }
+
+    #[test]
+    fn share_repeated_cte() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(cte()?)
+            .filter(col("a").gt(lit(1)))?
+            .union(
+                LogicalPlanBuilder::from(cte()?)
+                    .filter(col("a").lt(lit(1)))?
+                    .build()?,
+            )?
+            .build()?;
+
+        let expected = "Union\
+        \n  Filter: t.a > Int32(1)\
+        \n    SharedSubplan: id=0\
+        \n      SubqueryAlias: t\
+        \n        Aggregate: groupBy=[[test.a]], aggr=[[COUNT(test.b)]]\
+        \n          TableScan: test\
+        \n  Filter: t.a < Int32(1)\
+        \n    SharedSubplan: id=0\
+        \n      SubqueryAlias: t\
+        \n        Aggregate: groupBy=[[test.a]], aggr=[[COUNT(test.b)]]\
+        \n          TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn do_not_share_table_scan() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .union(test_table_scan()?)?
+            .build()?;
+
+        let expected = "Union\
+        \n  TableScan: test\
+        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn do_not_share_volatile() -> Result<()> {
+        let subplan = || {
+            LogicalPlanBuilder::from(test_table_scan()?)
+                .project(vec![col("a"), random().alias("r")])?
+                .build()
+        };
+        let plan = LogicalPlanBuilder::from(subplan()?)
+            .union(subplan()?)?
+            .build()?;
+
+        let expected = "Union\
+        \n  Projection: test.a, random() AS r\
+        \n    TableScan: test\
+        \n  Projection: test.a, random() AS r\
+        \n    TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn do_not_share_nested_single_consumer() -> Result<()> {
+        // the aggregate occurs twice, but only within the shared CTE
+        let left = LogicalPlanBuilder::from(cte()?).alias("l")?.build()?;
+        let right = LogicalPlanBuilder::from(cte()?).alias("r")?.build()?;
+        let plan = LogicalPlanBuilder::from(left).cross_join(right)?.build()?;
+
+        let expected = "CrossJoin:\
+        \n  SubqueryAlias: l\
+        \n    SharedSubplan: id=0\
+        \n      SubqueryAlias: t\
+        \n        Aggregate: groupBy=[[test.a]], aggr=[[COUNT(test.b)]]\
+        \n          TableScan: test\
+        \n  SubqueryAlias: r\
+        \n    SharedSubplan: id=0\
+        \n      SubqueryAlias: t\
+        \n        Aggregate: groupBy=[[test.a]], aggr=[[COUNT(test.b)]]\
+        \n          TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn disabled_by_default() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(cte()?).union(cte()?)?.build()?;
+        let optimized =
+            ShareCommonSubplans::new().try_optimize(&plan, &OptimizerContext::new())?;
+        assert!(optimized.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn idempotent() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(cte()?).union(cte()?)?.build()?;
+        let optimized = optimize(&plan)?;
+        let config = OptimizerContext::new().with_share_common_subplans(true);
+        assert!(ShareCommonSubplans::new()
+            .try_optimize(&optimized, &config)?
+            .is_none());
+        Ok(())
+    }
+}
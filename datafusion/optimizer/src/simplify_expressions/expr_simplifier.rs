@@ -30,6 +30,7 @@ use arrow::{
     error::ArrowError,
     record_batch::RecordBatch,
 };
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{
     cast::{as_large_list_array, as_list_array},
     tree_node::{RewriteRecursion, TreeNode, TreeNodeRewriter},
@@ -43,7 +44,8 @@ use datafusion_expr::{
     Like, Volatility,
 };
 use datafusion_physical_expr::{
-    create_physical_expr, execution_props::ExecutionProps, intervals::NullableInterval,
+    create_physical_expr_with_overflow_mode, execution_props::ExecutionProps,
+    intervals::NullableInterval,
 };
 
 use crate::simplify_expressions::SimplifyInfo;
@@ -56,6 +58,9 @@ pub struct ExprSimplifier<S> {
     /// Guarantees about the values of columns. This is provided by the user
     /// in [ExprSimplifier::with_guarantees()].
     guarantees: Vec<(Expr, NullableInterval)>,
+    /// How constant arithmetic that overflows is folded. This is provided
+    /// by the user in [ExprSimplifier::with_arithmetic_overflow()].
+    arithmetic_overflow: ArithmeticOverflowMode,
 }
 
 pub const THRESHOLD_INLINE_INLIST: usize = 3;
@@ -70,6 +75,7 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
         Self {
             info,
             guarantees: vec![],
+            arithmetic_overflow: ArithmeticOverflowMode::default(),
         }
     }
 
@@ -132,7 +138,8 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
     /// ```
     pub fn simplify(&self, expr: Expr) -> Result<Expr> {
         let mut simplifier = Simplifier::new(&self.info);
-        let mut const_evaluator = ConstEvaluator::try_new(self.info.execution_props())?;
+        let mut const_evaluator = ConstEvaluator::try_new(self.info.execution_props())?
+            .with_arithmetic_overflow(self.arithmetic_overflow);
         let mut or_in_list_simplifier = OrInListSimplifier::new();
         let mut guarantee_rewriter = GuaranteeRewriter::new(&self.guarantees);
 
@@ -223,6 +230,17 @@ impl<S: SimplifyInfo> ExprSimplifier<S> {
         self.guarantees = guarantees;
         self
     }
+
+    /// Set how constant expressions whose arithmetic overflows are folded,
+    /// which should match how the session evaluates them at execution time
+    /// (`datafusion.execution.arithmetic_overflow`)
+    pub fn with_arithmetic_overflow(
+        mut self,
+        arithmetic_overflow: ArithmeticOverflowMode,
+    ) -> Self {
+        self.arithmetic_overflow = arithmetic_overflow;
+        self
+    }
 }
 
 #[allow(rustdoc::private_intra_doc_links)]
@@ -248,6 +266,7 @@ struct ConstEvaluator<'a> {
     execution_props: &'a ExecutionProps,
     input_schema: DFSchema,
     input_batch: RecordBatch,
+    arithmetic_overflow: ArithmeticOverflowMode,
 }
 
 impl<'a> TreeNodeRewriter for ConstEvaluator<'a> {
@@ -308,9 +327,19 @@ impl<'a> ConstEvaluator<'a> {
             execution_props,
             input_schema,
             input_batch,
+            arithmetic_overflow: ArithmeticOverflowMode::default(),
         })
     }
 
+    /// Set how arithmetic that overflows is evaluated
+    pub fn with_arithmetic_overflow(
+        mut self,
+        arithmetic_overflow: ArithmeticOverflowMode,
+    ) -> Self {
+        self.arithmetic_overflow = arithmetic_overflow;
+        self
+    }
+
     /// Can a function of the specified volatility be evaluated?
     fn volatility_ok(volatility: Volatility) -> bool {
         match volatility {
@@ -345,7 +374,14 @@ impl<'a> ConstEvaluator<'a> {
             | Expr::GroupingSet(_)
             | Expr::Wildcard
             | Expr::QualifiedWildcard { .. }
-            | Expr::Placeholder(_) => false,
+            | Expr::Placeholder(_)
+            | Expr::Lambda(_) => false,
+            // folding would drop the collation, which is only carried by the
+            // expression's field metadata
+            Expr::ScalarFunction(ScalarFunction {
+                fun: BuiltinScalarFunction::Collate,
+                ..
+            }) => false,
             Expr::ScalarFunction(ScalarFunction { fun, .. }) => {
                 Self::volatility_ok(fun.volatility())
             }
@@ -381,11 +417,12 @@ impl<'a> ConstEvaluator<'a> {
             return Ok(s);
         }
 
-        let phys_expr = create_physical_expr(
+        let phys_expr = create_physical_expr_with_overflow_mode(
             &expr,
             &self.input_schema,
             &self.input_batch.schema(),
             self.execution_props,
+            self.arithmetic_overflow,
         )?;
         let col_val = phys_expr.evaluate(&self.input_batch)?;
         match col_val {
@@ -1335,6 +1372,31 @@ mod tests {
         assert_eq!(expected, simplifier.simplify(expr).unwrap());
     }
 
+    #[test]
+    fn api_arithmetic_overflow() {
+        let props = ExecutionProps::new();
+        let simplifier = |mode| {
+            ExprSimplifier::new(SimplifyContext::new(&props).with_schema(test_schema()))
+                .with_arithmetic_overflow(mode)
+        };
+        let expr = lit(i64::MAX) + lit(1i64);
+
+        let simplified = simplifier(ArithmeticOverflowMode::Wrap)
+            .simplify(expr.clone())
+            .unwrap();
+        assert_eq!(simplified, lit(i64::MIN));
+
+        let simplified = simplifier(ArithmeticOverflowMode::Null)
+            .simplify(expr.clone())
+            .unwrap();
+        assert_eq!(simplified, lit(ScalarValue::Int64(None)));
+
+        let err = simplifier(ArithmeticOverflowMode::Error)
+            .simplify(expr)
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Arithmetic overflow in");
+    }
+
     #[test]
     fn basic_coercion() {
         let schema = test_schema();
@@ -1528,6 +1590,13 @@ mod tests {
         test_evaluate(expr.clone(), expr);
     }
 
+    #[test]
+    fn test_const_evaluator_collate() {
+        // collate('ABC', 'nocase') is not folded, as that would drop the collation
+        let expr = call_fn("collate", vec![lit("ABC"), lit("nocase")]).unwrap();
+        test_evaluate(expr.clone(), expr);
+    }
+
     #[test]
     fn test_const_evaluator_now() {
         let ts_nanos = 1599566400000000000i64;
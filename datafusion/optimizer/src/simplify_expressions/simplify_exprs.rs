@@ -22,6 +22,7 @@ use std::sync::Arc;
 use super::{ExprSimplifier, SimplifyContext};
 use crate::utils::merge_schema;
 use crate::{OptimizerConfig, OptimizerRule};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{DFSchema, DFSchemaRef, Result};
 use datafusion_expr::logical_plan::LogicalPlan;
 use datafusion_physical_expr::execution_props::ExecutionProps;
@@ -54,7 +55,12 @@ impl OptimizerRule for SimplifyExpressions {
     ) -> Result<Option<LogicalPlan>> {
         let mut execution_props = ExecutionProps::new();
         execution_props.query_execution_start_time = config.query_execution_start_time();
-        Ok(Some(Self::optimize_internal(plan, &execution_props)?))
+        let arithmetic_overflow = config.options().execution.arithmetic_overflow;
+        Ok(Some(Self::optimize_internal(
+            plan,
+            &execution_props,
+            arithmetic_overflow,
+        )?))
     }
 }
 
@@ -62,6 +68,7 @@ impl SimplifyExpressions {
     fn optimize_internal(
         plan: &LogicalPlan,
         execution_props: &ExecutionProps,
+        arithmetic_overflow: ArithmeticOverflowMode,
     ) -> Result<LogicalPlan> {
         let schema = if !plan.inputs().is_empty() {
             DFSchemaRef::new(merge_schema(plan.inputs()))
@@ -85,12 +92,15 @@ impl SimplifyExpressions {
         };
         let info = SimplifyContext::new(execution_props).with_schema(schema);
 
-        let simplifier = ExprSimplifier::new(info);
+        let simplifier =
+            ExprSimplifier::new(info).with_arithmetic_overflow(arithmetic_overflow);
 
         let new_inputs = plan
             .inputs()
             .iter()
-            .map(|input| Self::optimize_internal(input, execution_props))
+            .map(|input| {
+                Self::optimize_internal(input, execution_props, arithmetic_overflow)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         let expr = plan
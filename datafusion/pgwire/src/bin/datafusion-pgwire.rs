@@ -0,0 +1,47 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serve an empty [`SessionContext`] over the PostgreSQL wire protocol
+//!
+//! Usage: `datafusion-pgwire [ADDRESS]`, where the address defaults to
+//! `127.0.0.1:5432`. Tables can be created by clients, for example with
+//! `CREATE EXTERNAL TABLE`.
+
+use datafusion::error::Result;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use tokio::net::TcpListener;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:5432";
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init();
+
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let listener = TcpListener::bind(&address).await?;
+    println!("Listening on {address}");
+
+    datafusion_pgwire::serve(
+        listener,
+        |config: SessionConfig| -> Result<SessionContext> {
+            Ok(SessionContext::new_with_config(config))
+        },
+    )
+    .await
+}
@@ -0,0 +1,365 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Handlers of the simple and extended query protocols
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::{Array, AsArray, RecordBatch};
+use arrow::datatypes::{DataType, Schema, UInt64Type};
+use async_trait::async_trait;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{
+    DdlStatement, DmlStatement, LogicalPlan, Statement, TransactionConclusion,
+    TransactionEnd, WriteOp,
+};
+use datafusion::prelude::SessionContext;
+use datafusion::sql::parser::{DFParser, Statement as DFStatement};
+use datafusion::sql::sqlparser::dialect::PostgreSqlDialect;
+use futures::{stream, StreamExt};
+use pgwire::api::portal::Portal;
+use pgwire::api::query::{ExtendedQueryHandler, SimpleQueryHandler};
+use pgwire::api::results::{DescribeResponse, FieldFormat, QueryResponse, Response, Tag};
+use pgwire::api::stmt::QueryParser;
+use pgwire::api::{ClientInfo, StatementOrPortal, Type};
+use pgwire::error::PgWireResult;
+
+use crate::types::{
+    decode_parameter, encode_batch, into_pg_fields, into_pg_type, parameter_types,
+    to_pg_error,
+};
+
+/// A planned statement
+#[derive(Clone, Debug)]
+pub enum PgStatement {
+    /// A statement executed by DataFusion
+    Plan(LogicalPlan),
+    /// A statement that is accepted for compatibility but has no effect,
+    /// such as `BEGIN` or setting a PostgreSQL specific variable, with the
+    /// command tag returned for it
+    Ignored(&'static str),
+    /// A query string without statements
+    Empty,
+}
+
+/// Plans statements against the context of a session
+pub struct PgQueryParser {
+    ctx: SessionContext,
+}
+
+#[async_trait]
+impl QueryParser for PgQueryParser {
+    type Statement = PgStatement;
+
+    async fn parse_sql(&self, sql: &str, _types: &[Type]) -> PgWireResult<PgStatement> {
+        let mut statements = parse_statements(sql).map_err(to_pg_error)?;
+        match statements.len() {
+            0 => Ok(PgStatement::Empty),
+            1 => plan_statement(&self.ctx, statements.pop_front().unwrap())
+                .await
+                .map_err(to_pg_error),
+            _ => Err(to_pg_error(DataFusionError::Plan(
+                "Prepared statements must contain a single statement".to_string(),
+            ))),
+        }
+    }
+}
+
+/// Parse the statements of a query string
+fn parse_statements(sql: &str) -> Result<VecDeque<DFStatement>> {
+    Ok(DFParser::parse_sql_with_dialect(
+        sql,
+        &PostgreSqlDialect {},
+    )?)
+}
+
+/// Plan a statement against the current state of the session, so that it
+/// sees the tables created by the statements executed before it
+async fn plan_statement(
+    ctx: &SessionContext,
+    statement: DFStatement,
+) -> Result<PgStatement> {
+    let plan = ctx.state().statement_to_plan(statement).await?;
+    Ok(match plan {
+        LogicalPlan::Statement(Statement::TransactionStart(_)) => {
+            PgStatement::Ignored("BEGIN")
+        }
+        LogicalPlan::Statement(Statement::TransactionEnd(TransactionEnd {
+            conclusion,
+            ..
+        })) => PgStatement::Ignored(match conclusion {
+            TransactionConclusion::Commit => "COMMIT",
+            TransactionConclusion::Rollback => "ROLLBACK",
+        }),
+        // Drivers set PostgreSQL variables such as `client_encoding`
+        // when connecting
+        LogicalPlan::Statement(Statement::SetVariable(set))
+            if !set.variable.starts_with("datafusion.") =>
+        {
+            PgStatement::Ignored("SET")
+        }
+        plan => PgStatement::Plan(plan),
+    })
+}
+
+/// Return the command tag of a plan that does not return rows, or `None`
+/// for queries
+fn command_tag(plan: &LogicalPlan) -> Option<&'static str> {
+    let tag = match plan {
+        LogicalPlan::Dml(DmlStatement { op, .. }) => match op {
+            WriteOp::InsertInto | WriteOp::InsertOverwrite => "INSERT 0",
+            WriteOp::Delete => "DELETE",
+            WriteOp::Update => "UPDATE",
+            WriteOp::Ctas => "SELECT",
+        },
+        LogicalPlan::Copy(_) => "COPY",
+        LogicalPlan::Ddl(ddl) => match ddl {
+            DdlStatement::CreateExternalTable(_) | DdlStatement::CreateMemoryTable(_) => {
+                "CREATE TABLE"
+            }
+            DdlStatement::CreateView(_) => "CREATE VIEW",
+            DdlStatement::CreateMaterializedView(_) => "CREATE MATERIALIZED VIEW",
+            DdlStatement::RefreshMaterializedView(_) => "REFRESH MATERIALIZED VIEW",
+            DdlStatement::CreateCatalogSchema(_) => "CREATE SCHEMA",
+            DdlStatement::CreateCatalog(_) => "CREATE DATABASE",
+            DdlStatement::DropTable(_) => "DROP TABLE",
+            DdlStatement::DropView(_) => "DROP VIEW",
+            DdlStatement::DropCatalogSchema(_) => "DROP SCHEMA",
+        },
+        LogicalPlan::Statement(_) => "SET",
+        _ => return None,
+    };
+    Some(tag)
+}
+
+/// The handler of a client connection, which owns the context of the session
+pub struct PgSession {
+    ctx: SessionContext,
+    query_parser: Arc<PgQueryParser>,
+}
+
+impl PgSession {
+    /// Create a handler for a session with the given context
+    pub fn new(ctx: SessionContext) -> Self {
+        Self {
+            query_parser: Arc::new(PgQueryParser { ctx: ctx.clone() }),
+            ctx,
+        }
+    }
+
+    /// Execute a plan, returning its rows in the given formats
+    async fn execute<'a>(
+        &self,
+        plan: LogicalPlan,
+        format: impl Fn(usize) -> FieldFormat,
+    ) -> Result<Response<'a>> {
+        let tag = command_tag(&plan);
+        let df = self.ctx.execute_logical_plan(plan).await?;
+        match tag {
+            Some(tag) => {
+                let batches = df.collect().await?;
+                Ok(Response::Execution(Tag::new_for_execution(
+                    tag,
+                    row_count(tag, &batches),
+                )))
+            }
+            None => {
+                let schema = Schema::from(df.schema());
+                let fields = Arc::new(into_pg_fields(&schema, format));
+                let batches = df.execute_stream().await?;
+                let rows_fields = fields.clone();
+                let rows = batches
+                    .map(move |batch| match batch {
+                        Ok(batch) => stream::iter(encode_batch(&batch, &rows_fields)),
+                        Err(e) => stream::iter(vec![Err(to_pg_error(e))]),
+                    })
+                    .flatten();
+                Ok(Response::Query(QueryResponse::new(fields, Box::pin(rows))))
+            }
+        }
+    }
+}
+
+/// Return the number of rows affected by a statement with the given tag,
+/// which DML statements return as their single value
+fn row_count(tag: &str, batches: &[RecordBatch]) -> Option<usize> {
+    if !matches!(tag, "INSERT 0" | "DELETE" | "UPDATE" | "SELECT") {
+        return None;
+    }
+    let count = batches
+        .iter()
+        .filter(|batch| batch.num_columns() == 1)
+        .filter_map(|batch| batch.column(0).as_primitive_opt::<UInt64Type>())
+        .flat_map(|counts| (0..counts.len()).map(move |i| counts.value(i)))
+        .sum::<u64>();
+    Some(count as usize)
+}
+
+#[async_trait]
+impl SimpleQueryHandler for PgSession {
+    async fn do_query<'a, C>(
+        &self,
+        _client: &mut C,
+        query: &'a str,
+    ) -> PgWireResult<Vec<Response<'a>>>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        let statements = parse_statements(query).map_err(to_pg_error)?;
+        if statements.is_empty() {
+            return Ok(vec![Response::EmptyQuery]);
+        }
+
+        // Each statement is planned once the ones before it have run, e.g.
+        // an INSERT into a table created earlier in the same query
+        let mut responses = Vec::with_capacity(statements.len());
+        for statement in statements {
+            let statement = plan_statement(&self.ctx, statement)
+                .await
+                .map_err(to_pg_error)?;
+            let response = match statement {
+                PgStatement::Plan(plan) => self
+                    .execute(plan, |_| FieldFormat::Text)
+                    .await
+                    .map_err(to_pg_error)?,
+                PgStatement::Ignored(tag) => {
+                    Response::Execution(Tag::new_for_execution(tag, None))
+                }
+                PgStatement::Empty => Response::EmptyQuery,
+            };
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+}
+
+#[async_trait]
+impl ExtendedQueryHandler for PgSession {
+    type Statement = PgStatement;
+    type QueryParser = PgQueryParser;
+
+    fn query_parser(&self) -> Arc<Self::QueryParser> {
+        self.query_parser.clone()
+    }
+
+    async fn do_query<'a, 'b: 'a, C>(
+        &'b self,
+        _client: &mut C,
+        portal: &'a Portal<PgStatement>,
+        _max_rows: usize,
+    ) -> PgWireResult<Response<'a>>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        let plan = match &portal.statement.statement {
+            PgStatement::Plan(plan) => plan,
+            PgStatement::Ignored(tag) => {
+                return Ok(Response::Execution(Tag::new_for_execution(tag, None)))
+            }
+            PgStatement::Empty => return Ok(Response::EmptyQuery),
+        };
+
+        let types = parameter_types(plan).map_err(to_pg_error)?;
+        if portal.parameters.len() != types.len() {
+            return Err(to_pg_error(DataFusionError::Plan(format!(
+                "Expected {} parameters but got {}",
+                types.len(),
+                portal.parameters.len()
+            ))));
+        }
+        let parameters =
+            resolve_parameter_types(&portal.statement.parameter_types, &types)
+                .into_iter()
+                .enumerate()
+                .map(|(idx, pg_type)| {
+                    let value = decode_parameter(
+                        portal.parameters[idx].as_deref(),
+                        portal.parameter_format.format_for(idx),
+                        &pg_type,
+                    )?;
+                    match &types[idx] {
+                        Some(data_type) => value.cast_to(data_type).map_err(to_pg_error),
+                        None => Ok(value),
+                    }
+                })
+                .collect::<PgWireResult<Vec<_>>>()?;
+        let plan = plan
+            .clone()
+            .with_param_values(parameters)
+            .map_err(to_pg_error)?;
+
+        self.execute(plan, |idx| portal.result_column_format.format_for(idx))
+            .await
+            .map_err(to_pg_error)
+    }
+
+    async fn do_describe<C>(
+        &self,
+        _client: &mut C,
+        target: StatementOrPortal<'_, PgStatement>,
+    ) -> PgWireResult<DescribeResponse>
+    where
+        C: ClientInfo + Unpin + Send + Sync,
+    {
+        let (statement, client_types, format): (_, _, Box<dyn Fn(usize) -> FieldFormat>) =
+            match &target {
+                StatementOrPortal::Statement(statement) => (
+                    &statement.statement,
+                    &statement.parameter_types,
+                    Box::new(|_| FieldFormat::Text),
+                ),
+                StatementOrPortal::Portal(portal) => (
+                    &portal.statement.statement,
+                    &portal.statement.parameter_types,
+                    Box::new(|idx| portal.result_column_format.format_for(idx)),
+                ),
+            };
+        let PgStatement::Plan(plan) = statement else {
+            return Ok(DescribeResponse::new(Some(vec![]), vec![]));
+        };
+
+        let types = parameter_types(plan).map_err(to_pg_error)?;
+        let parameter_types = resolve_parameter_types(client_types, &types);
+        let fields = match command_tag(plan) {
+            Some(_) => vec![],
+            None => into_pg_fields(&Schema::from(plan.schema().as_ref()), format),
+        };
+        Ok(DescribeResponse::new(Some(parameter_types), fields))
+    }
+}
+
+/// Return the PostgreSQL type of each parameter of a plan, which is the type
+/// given by the client when preparing the statement or else the type
+/// inferred by the planner
+fn resolve_parameter_types(
+    client_types: &[Type],
+    types: &[Option<DataType>],
+) -> Vec<Type> {
+    types
+        .iter()
+        .enumerate()
+        .map(|(idx, data_type)| {
+            client_types
+                .get(idx)
+                .filter(|t| **t != Type::UNKNOWN)
+                .cloned()
+                .or_else(|| data_type.as_ref().map(into_pg_type))
+                .unwrap_or(Type::UNKNOWN)
+        })
+        .collect()
+}
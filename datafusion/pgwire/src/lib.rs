@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A PostgreSQL wire protocol frontend for DataFusion
+//!
+//! This crate serves the simple and extended query protocols of PostgreSQL
+//! over a [`SessionContext`], so that `psql` and PostgreSQL drivers can run
+//! queries against it:
+//!
+//! * Every connection has its own [`SessionContext`], created by a
+//!   [`SessionContextProvider`].
+//! * Arrow types are sent as the closest PostgreSQL type, see
+//!   [`types::into_pg_type`]. Types without a PostgreSQL equivalent, such
+//!   as lists and structs, are sent as text.
+//! * Parse, Bind and Execute messages map onto prepared logical plans whose
+//!   placeholders `$1`, `$2`, ... are bound to the parameters of the portal.
+//! * Views in the `pg_catalog` schema, derived from `information_schema`,
+//!   describe the tables of the default catalog.
+//! * Transaction statements and `SET` of PostgreSQL specific variables are
+//!   accepted but have no effect.
+//!
+//! ```no_run
+//! use datafusion::error::Result;
+//! use datafusion::prelude::{SessionConfig, SessionContext};
+//! use tokio::net::TcpListener;
+//!
+//! # async fn example() -> std::io::Result<()> {
+//! let listener = TcpListener::bind("127.0.0.1:5432").await?;
+//! datafusion_pgwire::serve(listener, |config: SessionConfig| -> Result<SessionContext> {
+//!     Ok(SessionContext::new_with_config(config))
+//! })
+//! .await
+//! # }
+//! ```
+
+pub mod handler;
+pub mod pg_catalog;
+pub mod types;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::error::Result;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use log::{debug, warn};
+use pgwire::api::auth::noop::NoopStartupHandler;
+use pgwire::tokio::process_socket;
+use tokio::net::TcpListener;
+
+pub use handler::PgSession;
+
+/// Creates the [`SessionContext`] of each connection
+///
+/// This is where tables, functions and other state that clients can use is
+/// registered. It is implemented for closures taking the [`SessionConfig`]
+/// of the connection, which has `information_schema` enabled as the
+/// `pg_catalog` views are derived from it.
+#[async_trait]
+pub trait SessionContextProvider: Send + Sync + 'static {
+    /// Create the context of a new connection with the given configuration
+    async fn new_context(&self, config: SessionConfig) -> Result<SessionContext>;
+}
+
+#[async_trait]
+impl<F> SessionContextProvider for F
+where
+    F: Fn(SessionConfig) -> Result<SessionContext> + Send + Sync + 'static,
+{
+    async fn new_context(&self, config: SessionConfig) -> Result<SessionContext> {
+        self(config)
+    }
+}
+
+/// Create the context of a new connection, including its `pg_catalog`
+pub async fn new_session(
+    provider: &dyn SessionContextProvider,
+) -> Result<SessionContext> {
+    let config = SessionConfig::new().with_information_schema(true);
+    let ctx = provider.new_context(config).await?;
+    pg_catalog::register_pg_catalog(&ctx).await?;
+    Ok(ctx)
+}
+
+/// Accept connections on `listener` and serve them until accepting fails
+///
+/// Connections are served concurrently, each with a context created by
+/// `provider`. TLS and authentication are not supported, so the listener
+/// should only be reachable by trusted clients.
+pub async fn serve(
+    listener: TcpListener,
+    provider: impl SessionContextProvider,
+) -> std::io::Result<()> {
+    let provider = Arc::new(provider);
+    loop {
+        let (socket, addr) = listener.accept().await?;
+        debug!("Accepted connection from {addr}");
+        let provider = provider.clone();
+        tokio::spawn(async move {
+            let ctx = match new_session(provider.as_ref()).await {
+                Ok(ctx) => ctx,
+                Err(e) => {
+                    warn!("Failed to create session for {addr}: {e}");
+                    return;
+                }
+            };
+            let handler = Arc::new(PgSession::new(ctx));
+            let startup = Arc::new(NoopStartupHandler);
+            if let Err(e) =
+                process_socket(socket, None, startup, handler.clone(), handler).await
+            {
+                warn!("Connection from {addr} failed: {e}");
+            }
+        });
+    }
+}
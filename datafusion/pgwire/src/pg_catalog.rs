@@ -0,0 +1,180 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `pg_catalog` views derived from `information_schema`
+//!
+//! Drivers and tools such as psql look up tables, columns and types in the
+//! PostgreSQL system catalogs. The views registered here expose the subset
+//! of the catalogs they commonly query, for the tables of the default
+//! catalog. Object identifiers are assigned by position, so they are only
+//! stable while the set of tables does not change.
+
+use arrow::datatypes::{DataType, TimeUnit};
+use datafusion::error::Result;
+use datafusion::prelude::SessionContext;
+
+use crate::types::{into_pg_type, PG_TYPES};
+
+/// Name of the schema holding the views
+pub const PG_CATALOG: &str = "pg_catalog";
+
+/// First object identifier assigned to namespaces and relations, as in
+/// PostgreSQL object identifiers below this are reserved for built-in objects
+const FIRST_NORMAL_OID: u32 = 16384;
+
+/// Arrow types with a fixed display name that columns are commonly of
+const COLUMN_TYPES: &[DataType] = &[
+    DataType::Boolean,
+    DataType::Int8,
+    DataType::Int16,
+    DataType::Int32,
+    DataType::Int64,
+    DataType::UInt8,
+    DataType::UInt16,
+    DataType::UInt32,
+    DataType::UInt64,
+    DataType::Float16,
+    DataType::Float32,
+    DataType::Float64,
+    DataType::Utf8,
+    DataType::LargeUtf8,
+    DataType::Binary,
+    DataType::LargeBinary,
+    DataType::Date32,
+    DataType::Date64,
+    DataType::Time32(TimeUnit::Second),
+    DataType::Time32(TimeUnit::Millisecond),
+    DataType::Time64(TimeUnit::Microsecond),
+    DataType::Time64(TimeUnit::Nanosecond),
+];
+
+/// Create the `pg_catalog` schema and its views in the default catalog of
+/// the context
+///
+/// The context must have `information_schema` enabled.
+pub async fn register_pg_catalog(ctx: &SessionContext) -> Result<()> {
+    let catalog = ctx.state().config_options().catalog.default_catalog.clone();
+
+    ctx.sql(&format!("CREATE SCHEMA IF NOT EXISTS {PG_CATALOG}"))
+        .await?;
+    for (name, query) in views(&catalog) {
+        ctx.sql(&format!("CREATE VIEW {PG_CATALOG}.{name} AS {query}"))
+            .await?;
+    }
+    Ok(())
+}
+
+/// Return the name and query of each view
+fn views(catalog: &str) -> Vec<(&'static str, String)> {
+    let catalog = catalog.replace('\'', "''");
+    vec![
+        (
+            "pg_database",
+            format!(
+                "SELECT CAST({FIRST_NORMAL_OID} AS BIGINT) AS oid, \
+                 '{catalog}' AS datname, \
+                 CAST(6 AS INT) AS encoding, \
+                 true AS datallowconn"
+            ),
+        ),
+        (
+            "pg_namespace",
+            format!(
+                "SELECT CAST({FIRST_NORMAL_OID} + row_number() OVER (ORDER BY schema_name) AS BIGINT) AS oid, \
+                 schema_name AS nspname \
+                 FROM information_schema.schemata \
+                 WHERE catalog_name = '{catalog}'"
+            ),
+        ),
+        (
+            "pg_class",
+            format!(
+                "SELECT CAST({FIRST_NORMAL_OID} + row_number() OVER (ORDER BY t.table_schema, t.table_name) AS BIGINT) AS oid, \
+                 t.table_name AS relname, \
+                 n.oid AS relnamespace, \
+                 CASE WHEN t.table_type = 'VIEW' THEN 'v' ELSE 'r' END AS relkind \
+                 FROM information_schema.tables t \
+                 JOIN {PG_CATALOG}.pg_namespace n ON t.table_schema = n.nspname \
+                 WHERE t.table_catalog = '{catalog}'"
+            ),
+        ),
+        (
+            "pg_attribute",
+            format!(
+                "SELECT c.oid AS attrelid, \
+                 col.column_name AS attname, \
+                 {} AS atttypid, \
+                 CAST(col.ordinal_position + 1 AS INT) AS attnum, \
+                 col.is_nullable = 'NO' AS attnotnull, \
+                 false AS attisdropped \
+                 FROM information_schema.columns col \
+                 JOIN {PG_CATALOG}.pg_namespace n ON col.table_schema = n.nspname \
+                 JOIN {PG_CATALOG}.pg_class c \
+                 ON c.relnamespace = n.oid AND c.relname = col.table_name \
+                 WHERE col.table_catalog = '{catalog}'",
+                type_oid_expr("col.data_type")
+            ),
+        ),
+        (
+            "pg_type",
+            format!(
+                "SELECT column1 AS oid, column2 AS typname \
+                 FROM (VALUES {})",
+                PG_TYPES
+                    .iter()
+                    .map(|t| format!("(CAST({} AS BIGINT), '{}')", t.oid(), t.name()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ),
+    ]
+}
+
+/// Return an expression mapping the display name of an Arrow type in
+/// `column` to the object identifier of its PostgreSQL type
+fn type_oid_expr(column: &str) -> String {
+    let mut cases = COLUMN_TYPES
+        .iter()
+        .map(|t| format!("WHEN {column} = '{t}' THEN {}", into_pg_type(t).oid()))
+        .collect::<Vec<_>>();
+    let timestamp = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let timestamp_tz = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()));
+    let decimal = DataType::Decimal128(38, 10);
+    cases.extend([
+        format!(
+            "WHEN {column} LIKE 'Timestamp(%, None)' THEN {}",
+            into_pg_type(&timestamp).oid()
+        ),
+        format!(
+            "WHEN {column} LIKE 'Timestamp(%' THEN {}",
+            into_pg_type(&timestamp_tz).oid()
+        ),
+        format!(
+            "WHEN {column} LIKE 'Decimal%' THEN {}",
+            into_pg_type(&decimal).oid()
+        ),
+        format!(
+            "WHEN {column} LIKE 'Dictionary(%, Utf8)' THEN {}",
+            into_pg_type(&DataType::Utf8).oid()
+        ),
+    ]);
+    format!(
+        "CAST(CASE {} ELSE {} END AS BIGINT)",
+        cases.join(" "),
+        into_pg_type(&DataType::Null).oid()
+    )
+}
@@ -0,0 +1,502 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Mapping between Arrow and PostgreSQL types and values
+
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, RecordBatch};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    DataType, Date32Type, Date64Type, Float16Type, Float32Type, Float64Type, Int16Type,
+    Int32Type, Int64Type, Int8Type, Schema, Time32MillisecondType, Time32SecondType,
+    Time64MicrosecondType, Time64NanosecondType, TimeUnit, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type,
+    UInt32Type, UInt8Type,
+};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::scalar::ScalarValue;
+use pgwire::api::results::{DataRowEncoder, FieldFormat, FieldInfo};
+use pgwire::api::Type;
+use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
+use pgwire::messages::data::DataRow;
+
+/// Types whose values are always sent in text format
+///
+/// Values of these types are formatted by Arrow, since there is no binary
+/// encoding for them.
+const TEXT_ONLY_TYPES: &[Type] = &[Type::NUMERIC, Type::INTERVAL, Type::TEXT];
+
+/// Types listed in `pg_catalog.pg_type`
+pub(crate) const PG_TYPES: &[Type] = &[
+    Type::BOOL,
+    Type::BYTEA,
+    Type::INT2,
+    Type::INT4,
+    Type::INT8,
+    Type::FLOAT4,
+    Type::FLOAT8,
+    Type::NUMERIC,
+    Type::TEXT,
+    Type::VARCHAR,
+    Type::DATE,
+    Type::TIME,
+    Type::TIMESTAMP,
+    Type::TIMESTAMPTZ,
+    Type::INTERVAL,
+];
+
+/// Return the PostgreSQL type values of an Arrow type are sent as
+pub fn into_pg_type(data_type: &DataType) -> Type {
+    match data_type {
+        DataType::Boolean => Type::BOOL,
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => Type::INT2,
+        DataType::Int32 | DataType::UInt16 => Type::INT4,
+        DataType::Int64 | DataType::UInt32 => Type::INT8,
+        // Values may not fit into a BIGINT
+        DataType::UInt64 => Type::NUMERIC,
+        DataType::Float16 | DataType::Float32 => Type::FLOAT4,
+        DataType::Float64 => Type::FLOAT8,
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => Type::NUMERIC,
+        DataType::Utf8 | DataType::LargeUtf8 => Type::VARCHAR,
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
+            Type::BYTEA
+        }
+        DataType::Date32 | DataType::Date64 => Type::DATE,
+        DataType::Time32(_) | DataType::Time64(_) => Type::TIME,
+        DataType::Timestamp(_, None) => Type::TIMESTAMP,
+        DataType::Timestamp(_, Some(_)) => Type::TIMESTAMPTZ,
+        DataType::Interval(_) | DataType::Duration(_) => Type::INTERVAL,
+        DataType::Dictionary(_, value_type) => into_pg_type(value_type),
+        // Nested types are formatted by Arrow
+        _ => Type::TEXT,
+    }
+}
+
+/// Return the Arrow type values of a PostgreSQL type are read as
+pub fn from_pg_type(pg_type: &Type) -> Option<DataType> {
+    match *pg_type {
+        Type::BOOL => Some(DataType::Boolean),
+        Type::INT2 => Some(DataType::Int16),
+        Type::INT4 => Some(DataType::Int32),
+        Type::INT8 => Some(DataType::Int64),
+        Type::FLOAT4 => Some(DataType::Float32),
+        Type::FLOAT8 => Some(DataType::Float64),
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME => Some(DataType::Utf8),
+        Type::BYTEA => Some(DataType::Binary),
+        Type::DATE => Some(DataType::Date32),
+        Type::TIMESTAMP => Some(DataType::Timestamp(TimeUnit::Microsecond, None)),
+        Type::TIMESTAMPTZ => Some(DataType::Timestamp(
+            TimeUnit::Microsecond,
+            Some("+00:00".into()),
+        )),
+        _ => None,
+    }
+}
+
+/// Return the fields of a query result with the given schema
+///
+/// `format` returns the format the client requested for each column.
+pub fn into_pg_fields(
+    schema: &Schema,
+    format: impl Fn(usize) -> FieldFormat,
+) -> Vec<FieldInfo> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let pg_type = into_pg_type(field.data_type());
+            let format = if TEXT_ONLY_TYPES.contains(&pg_type) {
+                FieldFormat::Text
+            } else {
+                format(idx)
+            };
+            FieldInfo::new(field.name().clone(), None, None, pg_type, format)
+        })
+        .collect()
+}
+
+/// Encode the rows of a batch
+pub fn encode_batch(
+    batch: &RecordBatch,
+    fields: &Arc<Vec<FieldInfo>>,
+) -> Vec<PgWireResult<DataRow>> {
+    let columns = match batch
+        .columns()
+        .iter()
+        .map(|column| match column.data_type() {
+            DataType::Dictionary(_, value_type) => cast(column, value_type),
+            _ => Ok(column.clone()),
+        })
+        .collect::<std::result::Result<Vec<ArrayRef>, _>>()
+    {
+        Ok(columns) => columns,
+        Err(e) => return vec![Err(to_pg_error(e.into()))],
+    };
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let mut encoder = DataRowEncoder::new(fields.clone());
+            for column in &columns {
+                encode_value(&mut encoder, column.as_ref(), row)?;
+            }
+            encoder.finish()
+        })
+        .collect()
+}
+
+fn encode_value(
+    encoder: &mut DataRowEncoder,
+    array: &dyn Array,
+    idx: usize,
+) -> PgWireResult<()> {
+    if array.is_null(idx) {
+        return encoder.encode_field(&None::<i8>);
+    }
+    match array.data_type() {
+        DataType::Boolean => encoder.encode_field(&array.as_boolean().value(idx)),
+        DataType::Int8 => {
+            encoder.encode_field(&(array.as_primitive::<Int8Type>().value(idx) as i16))
+        }
+        DataType::Int16 => {
+            encoder.encode_field(&array.as_primitive::<Int16Type>().value(idx))
+        }
+        DataType::Int32 => {
+            encoder.encode_field(&array.as_primitive::<Int32Type>().value(idx))
+        }
+        DataType::Int64 => {
+            encoder.encode_field(&array.as_primitive::<Int64Type>().value(idx))
+        }
+        DataType::UInt8 => {
+            encoder.encode_field(&(array.as_primitive::<UInt8Type>().value(idx) as i16))
+        }
+        DataType::UInt16 => {
+            encoder.encode_field(&(array.as_primitive::<UInt16Type>().value(idx) as i32))
+        }
+        DataType::UInt32 => {
+            encoder.encode_field(&(array.as_primitive::<UInt32Type>().value(idx) as i64))
+        }
+        DataType::Float16 => {
+            encoder.encode_field(&array.as_primitive::<Float16Type>().value(idx).to_f32())
+        }
+        DataType::Float32 => {
+            encoder.encode_field(&array.as_primitive::<Float32Type>().value(idx))
+        }
+        DataType::Float64 => {
+            encoder.encode_field(&array.as_primitive::<Float64Type>().value(idx))
+        }
+        DataType::Utf8 => encoder.encode_field(&array.as_string::<i32>().value(idx)),
+        DataType::LargeUtf8 => encoder.encode_field(&array.as_string::<i64>().value(idx)),
+        DataType::Binary => encoder.encode_field(&array.as_binary::<i32>().value(idx)),
+        DataType::LargeBinary => {
+            encoder.encode_field(&array.as_binary::<i64>().value(idx))
+        }
+        DataType::FixedSizeBinary(_) => {
+            encoder.encode_field(&array.as_fixed_size_binary().value(idx))
+        }
+        DataType::Date32 => {
+            encoder.encode_field(&array.as_primitive::<Date32Type>().value_as_date(idx))
+        }
+        DataType::Date64 => {
+            encoder.encode_field(&array.as_primitive::<Date64Type>().value_as_date(idx))
+        }
+        DataType::Time32(TimeUnit::Second) => encoder
+            .encode_field(&array.as_primitive::<Time32SecondType>().value_as_time(idx)),
+        DataType::Time32(TimeUnit::Millisecond) => encoder.encode_field(
+            &array
+                .as_primitive::<Time32MillisecondType>()
+                .value_as_time(idx),
+        ),
+        DataType::Time64(TimeUnit::Microsecond) => encoder.encode_field(
+            &array
+                .as_primitive::<Time64MicrosecondType>()
+                .value_as_time(idx),
+        ),
+        DataType::Time64(TimeUnit::Nanosecond) => encoder.encode_field(
+            &array
+                .as_primitive::<Time64NanosecondType>()
+                .value_as_time(idx),
+        ),
+        DataType::Timestamp(unit, tz) => {
+            let datetime = match unit {
+                TimeUnit::Second => array
+                    .as_primitive::<TimestampSecondType>()
+                    .value_as_datetime(idx),
+                TimeUnit::Millisecond => array
+                    .as_primitive::<TimestampMillisecondType>()
+                    .value_as_datetime(idx),
+                TimeUnit::Microsecond => array
+                    .as_primitive::<TimestampMicrosecondType>()
+                    .value_as_datetime(idx),
+                TimeUnit::Nanosecond => array
+                    .as_primitive::<TimestampNanosecondType>()
+                    .value_as_datetime(idx),
+            };
+            match tz {
+                // Values are UTC, which is the time zone of sent TIMESTAMPTZ values
+                Some(_) => encoder.encode_field(
+                    &datetime.map(|d| DateTime::<Utc>::from_naive_utc_and_offset(d, Utc)),
+                ),
+                None => encoder.encode_field(&datetime),
+            }
+        }
+        _ => {
+            let formatter = ArrayFormatter::try_new(array, &FormatOptions::default())
+                .map_err(|e| to_pg_error(e.into()))?;
+            encoder.encode_field(&formatter.value(idx).to_string())
+        }
+    }
+}
+
+/// Read a parameter bound to a portal as the given PostgreSQL type
+///
+/// Parameters of unknown types are read as text.
+pub fn decode_parameter(
+    value: Option<&[u8]>,
+    format: FieldFormat,
+    pg_type: &Type,
+) -> PgWireResult<ScalarValue> {
+    let data_type = from_pg_type(pg_type).unwrap_or(DataType::Utf8);
+    let Some(value) = value else {
+        return ScalarValue::try_from(&data_type).map_err(to_pg_error);
+    };
+    let value = match format {
+        FieldFormat::Text => {
+            let text = std::str::from_utf8(value).map_err(|e| {
+                to_pg_error(DataFusionError::Execution(format!(
+                    "Invalid text parameter: {e}"
+                )))
+            })?;
+            return decode_text_parameter(text, pg_type, &data_type);
+        }
+        FieldFormat::Binary => decode_binary_parameter(value, pg_type)?,
+    };
+    Ok(value)
+}
+
+fn decode_text_parameter(
+    text: &str,
+    pg_type: &Type,
+    data_type: &DataType,
+) -> PgWireResult<ScalarValue> {
+    let value = match *pg_type {
+        Type::BOOL => ScalarValue::Boolean(Some(decode_bool_text(text)?)),
+        Type::BYTEA => ScalarValue::Binary(Some(decode_bytea_text(text)?)),
+        _ => ScalarValue::Utf8(Some(text.to_string()))
+            .cast_to(data_type)
+            .map_err(to_pg_error)?,
+    };
+    Ok(value)
+}
+
+/// Decode the text format of BOOL values, which PostgreSQL sends as `t` and
+/// `f` but also accepts in the other spellings of its boolean input
+fn decode_bool_text(text: &str) -> PgWireResult<bool> {
+    match text.trim().to_lowercase().as_str() {
+        "t" | "true" | "y" | "yes" | "on" | "1" => Ok(true),
+        "f" | "false" | "n" | "no" | "off" | "0" => Ok(false),
+        _ => Err(to_pg_error(DataFusionError::Execution(format!(
+            "Invalid BOOL value: {text}"
+        )))),
+    }
+}
+
+/// Decode the hex format of BYTEA values, e.g. `\x0a0b`
+fn decode_bytea_text(text: &str) -> PgWireResult<Vec<u8>> {
+    let Some(hex) = text.strip_prefix("\\x") else {
+        return Ok(text.as_bytes().to_vec());
+    };
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| {
+                    to_pg_error(DataFusionError::Execution(format!(
+                        "Invalid BYTEA value: {text}"
+                    )))
+                })
+        })
+        .collect()
+}
+
+fn decode_binary_parameter(value: &[u8], pg_type: &Type) -> PgWireResult<ScalarValue> {
+    use pgwire::types::FromSql;
+
+    let err = |e: Box<dyn std::error::Error + Sync + Send>| PgWireError::ApiError(e);
+    let value = match *pg_type {
+        Type::BOOL => {
+            ScalarValue::Boolean(Some(bool::from_sql(pg_type, value).map_err(err)?))
+        }
+        Type::INT2 => {
+            ScalarValue::Int16(Some(i16::from_sql(pg_type, value).map_err(err)?))
+        }
+        Type::INT4 => {
+            ScalarValue::Int32(Some(i32::from_sql(pg_type, value).map_err(err)?))
+        }
+        Type::INT8 => {
+            ScalarValue::Int64(Some(i64::from_sql(pg_type, value).map_err(err)?))
+        }
+        Type::FLOAT4 => {
+            ScalarValue::Float32(Some(f32::from_sql(pg_type, value).map_err(err)?))
+        }
+        Type::FLOAT8 => {
+            ScalarValue::Float64(Some(f64::from_sql(pg_type, value).map_err(err)?))
+        }
+        Type::BYTEA => ScalarValue::Binary(Some(value.to_vec())),
+        Type::DATE => {
+            let date = NaiveDate::from_sql(pg_type, value).map_err(err)?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            ScalarValue::Date32(Some((date - epoch).num_days() as i32))
+        }
+        Type::TIMESTAMP => {
+            let timestamp = NaiveDateTime::from_sql(pg_type, value).map_err(err)?;
+            ScalarValue::TimestampMicrosecond(Some(timestamp.timestamp_micros()), None)
+        }
+        Type::TIMESTAMPTZ => {
+            let timestamp = DateTime::<Utc>::from_sql(pg_type, value).map_err(err)?;
+            ScalarValue::TimestampMicrosecond(
+                Some(timestamp.timestamp_micros()),
+                Some("+00:00".into()),
+            )
+        }
+        Type::TIME => {
+            let time = NaiveTime::from_sql(pg_type, value).map_err(err)?;
+            let micros = (time - NaiveTime::MIN)
+                .num_microseconds()
+                .unwrap_or_default();
+            ScalarValue::Time64Microsecond(Some(micros))
+        }
+        _ => ScalarValue::Utf8(Some(String::from_sql(pg_type, value).map_err(err)?)),
+    };
+    Ok(value)
+}
+
+/// Convert a [`DataFusionError`] to an error sent to the client, with the
+/// closest matching SQLSTATE code
+pub fn to_pg_error(e: DataFusionError) -> PgWireError {
+    let code = match &e {
+        DataFusionError::SQL(_) => "42601",
+        DataFusionError::NotImplemented(_) => "0A000",
+        DataFusionError::Plan(_) | DataFusionError::SchemaError(_) => "42000",
+        DataFusionError::Configuration(_) => "22023",
+        _ => "XX000",
+    };
+    PgWireError::UserError(Box::new(ErrorInfo::new(
+        "ERROR".to_string(),
+        code.to_string(),
+        e.to_string(),
+    )))
+}
+
+/// Return the type of each parameter `$1`, `$2`, ... of a plan, or `None`
+/// if it could not be inferred
+pub fn parameter_types(
+    plan: &datafusion::logical_expr::LogicalPlan,
+) -> Result<Vec<Option<DataType>>> {
+    let types = plan.get_parameter_types()?;
+    let mut params = types
+        .into_iter()
+        .map(|(id, data_type)| {
+            let index = id
+                .strip_prefix('$')
+                .and_then(|index| index.parse::<usize>().ok())
+                .ok_or_else(|| {
+                    DataFusionError::Plan(format!("Invalid placeholder: {id}"))
+                })?;
+            Ok((index, data_type))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    params.sort_by_key(|(index, _)| *index);
+    Ok(params.into_iter().map(|(_, data_type)| data_type).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pg_types() {
+        assert_eq!(into_pg_type(&DataType::Int64), Type::INT8);
+        assert_eq!(into_pg_type(&DataType::UInt64), Type::NUMERIC);
+        assert_eq!(into_pg_type(&DataType::Utf8), Type::VARCHAR);
+        assert_eq!(
+            into_pg_type(&DataType::Timestamp(
+                TimeUnit::Nanosecond,
+                Some("UTC".into())
+            )),
+            Type::TIMESTAMPTZ
+        );
+        assert_eq!(
+            into_pg_type(&DataType::Dictionary(
+                Box::new(DataType::Int32),
+                Box::new(DataType::Utf8)
+            )),
+            Type::VARCHAR
+        );
+        assert_eq!(
+            into_pg_type(&DataType::new_list(DataType::Int64, true)),
+            Type::TEXT
+        );
+
+        for pg_type in [Type::BOOL, Type::INT2, Type::INT4, Type::INT8, Type::DATE] {
+            let data_type = from_pg_type(&pg_type).unwrap();
+            assert_eq!(into_pg_type(&data_type), pg_type);
+        }
+    }
+
+    #[test]
+    fn text_parameters() {
+        let cases = [
+            ("t", Type::BOOL, ScalarValue::Boolean(Some(true))),
+            ("f", Type::BOOL, ScalarValue::Boolean(Some(false))),
+            ("off", Type::BOOL, ScalarValue::Boolean(Some(false))),
+            ("42", Type::INT4, ScalarValue::Int32(Some(42))),
+            ("1.5", Type::FLOAT8, ScalarValue::Float64(Some(1.5))),
+            (
+                "abc",
+                Type::UNKNOWN,
+                ScalarValue::Utf8(Some("abc".to_string())),
+            ),
+            (
+                "\\x0aff",
+                Type::BYTEA,
+                ScalarValue::Binary(Some(vec![10, 255])),
+            ),
+            ("1970-01-02", Type::DATE, ScalarValue::Date32(Some(1))),
+        ];
+        for (text, pg_type, expected) in cases {
+            let value =
+                decode_parameter(Some(text.as_bytes()), FieldFormat::Text, &pg_type)
+                    .unwrap();
+            assert_eq!(value, expected, "{text}");
+        }
+
+        let null = decode_parameter(None, FieldFormat::Text, &Type::INT8).unwrap();
+        assert_eq!(null, ScalarValue::Int64(None));
+
+        let err =
+            decode_parameter(Some(b"maybe"), FieldFormat::Text, &Type::BOOL).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid BOOL value: maybe"),
+            "{err}"
+        );
+    }
+}
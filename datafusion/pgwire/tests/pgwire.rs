@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow::array::{Int64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion::error::Result;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use tokio::net::TcpListener;
+use tokio_postgres::error::SqlState;
+use tokio_postgres::types::Type;
+use tokio_postgres::{Client, NoTls, SimpleQueryMessage};
+
+#[tokio::test]
+async fn simple_query() {
+    let client = connect().await;
+    let rows = client
+        .simple_query("SELECT a, b FROM t WHERE a > 1 ORDER BY a")
+        .await
+        .unwrap();
+    let rows = rows
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some((
+                row.get(0).unwrap().to_string(),
+                row.get(1).map(String::from),
+            )),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            ("2".to_string(), Some("bar".to_string())),
+            ("3".to_string(), None)
+        ]
+    );
+
+    let err = client.simple_query("SELECT c FROM t").await.unwrap_err();
+    assert_eq!(
+        err.code(),
+        Some(&SqlState::SYNTAX_ERROR_OR_ACCESS_RULE_VIOLATION)
+    );
+}
+
+#[tokio::test]
+async fn extended_query() {
+    let client = connect().await;
+    let statement = client
+        .prepare("SELECT a, b FROM t WHERE a > $1 ORDER BY a")
+        .await
+        .unwrap();
+    assert_eq!(statement.params(), &[Type::INT8]);
+
+    let rows = client.query(&statement, &[&2_i64]).await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<_, i64>("a"), 3);
+    assert_eq!(rows[0].get::<_, Option<&str>>("b"), None);
+
+    let rows = client.query(&statement, &[&0_i64]).await.unwrap();
+    let names = rows
+        .iter()
+        .map(|row| row.get::<_, Option<String>>(1))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![Some("foo".to_string()), Some("bar".to_string()), None]
+    );
+}
+
+#[tokio::test]
+async fn statements() {
+    let client = connect().await;
+    client
+        .batch_execute(
+            "SET extra_float_digits = 3; \
+             BEGIN; \
+             CREATE TABLE u (x BIGINT); \
+             COMMIT",
+        )
+        .await
+        .unwrap();
+
+    let inserted = client
+        .execute("INSERT INTO u VALUES (1), (2)", &[])
+        .await
+        .unwrap();
+    assert_eq!(inserted, 2);
+
+    let row = client.query_one("SELECT sum(x) FROM u", &[]).await.unwrap();
+    assert_eq!(row.get::<_, i64>(0), 3);
+}
+
+#[tokio::test]
+async fn dependent_statements() {
+    let client = connect().await;
+    let messages = client
+        .simple_query(
+            "CREATE TABLE v (x BIGINT); \
+             INSERT INTO v VALUES (1), (2); \
+             SELECT sum(x) FROM v",
+        )
+        .await
+        .unwrap();
+    let rows = messages
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => row.get(0).map(String::from),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec!["3".to_string()]);
+}
+
+#[tokio::test]
+async fn pg_catalog() {
+    let client = connect().await;
+    let rows = client
+        .query(
+            "SELECT c.relname, a.attname, t.typname \
+             FROM pg_catalog.pg_class c \
+             JOIN pg_catalog.pg_namespace n ON c.relnamespace = n.oid \
+             JOIN pg_catalog.pg_attribute a ON a.attrelid = c.oid \
+             JOIN pg_catalog.pg_type t ON a.atttypid = t.oid \
+             WHERE n.nspname = 'public' \
+             ORDER BY c.relname, a.attnum",
+            &[],
+        )
+        .await
+        .unwrap();
+    let columns = rows
+        .iter()
+        .map(|row| {
+            (
+                row.get::<_, String>(0),
+                row.get::<_, String>(1),
+                row.get::<_, String>(2),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        vec![
+            ("t".to_string(), "a".to_string(), "int8".to_string()),
+            ("t".to_string(), "b".to_string(), "varchar".to_string()),
+        ]
+    );
+}
+
+/// Serve a context with a table `t` and connect to it
+async fn connect() -> Client {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(datafusion_pgwire::serve(
+        listener,
+        |config: SessionConfig| -> Result<SessionContext> {
+            let ctx = SessionContext::new_with_config(config);
+            ctx.register_batch("t", batch())?;
+            Ok(ctx)
+        },
+    ));
+
+    let (client, connection) =
+        tokio_postgres::connect(&format!("host=127.0.0.1 port={port} user=test"), NoTls)
+            .await
+            .unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn batch() -> RecordBatch {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+            Arc::new(StringArray::from(vec![Some("foo"), Some("bar"), None])),
+        ],
+    )
+    .unwrap()
+}
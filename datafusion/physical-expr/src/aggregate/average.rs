@@ -28,7 +28,7 @@ use crate::aggregate::groups_accumulator::accumulate::NullState;
 use crate::aggregate::utils::down_cast_any_ref;
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, GroupsAccumulator, PhysicalExpr};
-use arrow::compute::sum;
+use arrow::compute::{sum, sum_checked};
 use arrow::datatypes::{DataType, Decimal128Type, Float64Type, UInt64Type};
 use arrow::{
     array::{ArrayRef, UInt64Array},
@@ -39,11 +39,16 @@ use arrow_array::{
     Array, ArrowNativeTypeOp, ArrowNumericType, ArrowPrimitiveType, PrimitiveArray,
 };
 use arrow_buffer::{i256, ArrowNativeType};
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{not_impl_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::type_coercion::aggregates::avg_return_type;
 use datafusion_expr::Accumulator;
 
 use super::groups_accumulator::EmitTo;
+use super::overflow::{
+    add_batch, aggregate_overflow_error, overflowed_state_field, sum_overflow_mode,
+    GroupsOverflowState,
+};
 use super::utils::DecimalAverager;
 
 /// AVG aggregate expression
@@ -53,6 +58,7 @@ pub struct Avg {
     expr: Arc<dyn PhysicalExpr>,
     input_data_type: DataType,
     result_data_type: DataType,
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl Avg {
@@ -63,14 +69,29 @@ impl Avg {
         data_type: DataType,
     ) -> Self {
         let result_data_type = avg_return_type(&data_type).unwrap();
+        let overflow_mode = sum_overflow_mode(&data_type, ArithmeticOverflowMode::Wrap);
 
         Self {
             name: name.into(),
             expr,
             input_data_type: data_type,
             result_data_type,
+            overflow_mode,
         }
     }
+
+    /// Set how overflow of the decimal sum is handled, which is always
+    /// checked, raising an error in [`ArithmeticOverflowMode::Wrap`]. Float
+    /// sums never overflow, so the mode is ignored for them
+    pub fn with_overflow_mode(mut self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        if matches!(
+            self.input_data_type,
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+        ) {
+            self.overflow_mode = sum_overflow_mode(&self.input_data_type, overflow_mode);
+        }
+        self
+    }
 }
 
 impl AggregateExpr for Avg {
@@ -98,6 +119,9 @@ impl AggregateExpr for Avg {
                 sum_precision: *sum_precision,
                 target_precision: *target_precision,
                 target_scale: *target_scale,
+                overflow_mode: self.overflow_mode,
+                overflowed: false,
+                name: self.name.clone(),
             })),
 
             (
@@ -110,6 +134,9 @@ impl AggregateExpr for Avg {
                 sum_precision: *sum_precision,
                 target_precision: *target_precision,
                 target_scale: *target_scale,
+                overflow_mode: self.overflow_mode,
+                overflowed: false,
+                name: self.name.clone(),
             })),
             _ => not_impl_err!(
                 "AvgAccumulator for ({} --> {})",
@@ -120,7 +147,7 @@ impl AggregateExpr for Avg {
     }
 
     fn state_fields(&self) -> Result<Vec<Field>> {
-        Ok(vec![
+        let mut fields = vec![
             Field::new(
                 format_state_name(&self.name, "count"),
                 DataType::UInt64,
@@ -131,7 +158,9 @@ impl AggregateExpr for Avg {
                 self.input_data_type.clone(),
                 true,
             ),
-        ])
+        ];
+        fields.extend(overflowed_state_field(&self.name, self.overflow_mode));
+        Ok(fields)
     }
 
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
@@ -153,7 +182,10 @@ impl AggregateExpr for Avg {
     fn groups_accumulator_supported(&self) -> bool {
         use DataType::*;
 
-        matches!(&self.result_data_type, Float64 | Decimal128(_, _))
+        matches!(
+            &self.result_data_type,
+            Float64 | Decimal128(_, _) | Decimal256(_, _)
+        )
     }
 
     fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
@@ -165,6 +197,7 @@ impl AggregateExpr for Avg {
                     &self.input_data_type,
                     &self.result_data_type,
                     |sum: f64, count: u64| Ok(sum / count as f64),
+                    GroupsOverflowState::new(self.overflow_mode, &self.name),
                 )))
             }
             (
@@ -184,6 +217,7 @@ impl AggregateExpr for Avg {
                     &self.input_data_type,
                     &self.result_data_type,
                     avg_fn,
+                    GroupsOverflowState::new(self.overflow_mode, &self.name),
                 )))
             }
 
@@ -205,6 +239,7 @@ impl AggregateExpr for Avg {
                     &self.input_data_type,
                     &self.result_data_type,
                     avg_fn,
+                    GroupsOverflowState::new(self.overflow_mode, &self.name),
                 )))
             }
 
@@ -225,6 +260,7 @@ impl PartialEq<dyn Any> for Avg {
                 self.name == x.name
                     && self.input_data_type == x.input_data_type
                     && self.result_data_type == x.result_data_type
+                    && self.overflow_mode == x.overflow_mode
                     && self.expr.eq(&x.expr)
             })
             .unwrap_or(false)
@@ -299,6 +335,11 @@ struct DecimalAvgAccumulator<T: DecimalType + ArrowNumericType> {
     sum_precision: u8,
     target_precision: u8,
     target_scale: i8,
+    overflow_mode: ArithmeticOverflowMode,
+    /// Set once the sum overflowed in [`ArithmeticOverflowMode::Null`]
+    overflowed: bool,
+    /// Name of the aggregate, used in error messages
+    name: String,
 }
 
 impl<T: DecimalType + ArrowNumericType> Debug for DecimalAvgAccumulator<T> {
@@ -310,28 +351,35 @@ impl<T: DecimalType + ArrowNumericType> Debug for DecimalAvgAccumulator<T> {
             .field("sum_precision", &self.sum_precision)
             .field("target_precision", &self.target_precision)
             .field("target_scale", &self.target_scale)
+            .field("overflow_mode", &self.overflow_mode)
+            .field("overflowed", &self.overflowed)
             .finish()
     }
 }
 
 impl<T: DecimalType + ArrowNumericType> Accumulator for DecimalAvgAccumulator<T> {
     fn state(&self) -> Result<Vec<ScalarValue>> {
-        Ok(vec![
+        let sum = if self.overflowed { None } else { self.sum };
+        let mut state = vec![
             ScalarValue::from(self.count),
             ScalarValue::new_primitive::<T>(
-                self.sum,
+                sum,
                 &T::TYPE_CONSTRUCTOR(self.sum_precision, self.sum_scale),
             )?,
-        ])
+        ];
+        if self.overflow_mode == ArithmeticOverflowMode::Null {
+            state.push(ScalarValue::Boolean(Some(self.overflowed)));
+        }
+        Ok(state)
     }
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
 
         self.count += (values.len() - values.null_count()) as u64;
-        if let Some(x) = sum(values) {
-            let v = self.sum.get_or_insert(T::Native::default());
-            self.sum = Some(v.add_wrapping(x));
+        if !self.overflowed {
+            self.overflowed =
+                !add_batch(&mut self.sum, values, self.overflow_mode, &self.name)?;
         }
         Ok(())
     }
@@ -339,8 +387,9 @@ impl<T: DecimalType + ArrowNumericType> Accumulator for DecimalAvgAccumulator<T>
     fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
         self.count -= (values.len() - values.null_count()) as u64;
-        if let Some(x) = sum(values) {
-            self.sum = Some(self.sum.unwrap().sub_wrapping(x));
+        let overflow = |e| aggregate_overflow_error(&self.name, e);
+        if let Some(x) = sum_checked(values).map_err(overflow)? {
+            self.sum = Some(self.sum.unwrap().sub_checked(x).map_err(overflow)?);
         }
         Ok(())
     }
@@ -349,17 +398,27 @@ impl<T: DecimalType + ArrowNumericType> Accumulator for DecimalAvgAccumulator<T>
         // counts are summed
         self.count += sum(states[0].as_primitive::<UInt64Type>()).unwrap_or_default();
 
+        if self.overflow_mode == ArithmeticOverflowMode::Null
+            && states[2].as_boolean().true_count() > 0
+        {
+            self.overflowed = true;
+        }
+
         // sums are summed
-        if let Some(x) = sum(states[1].as_primitive::<T>()) {
-            let v = self.sum.get_or_insert(T::Native::default());
-            self.sum = Some(v.add_wrapping(x));
+        if !self.overflowed {
+            self.overflowed = !add_batch(
+                &mut self.sum,
+                states[1].as_primitive::<T>(),
+                self.overflow_mode,
+                &self.name,
+            )?;
         }
         Ok(())
     }
 
     fn evaluate(&self) -> Result<ScalarValue> {
-        let v = self
-            .sum
+        let sum = if self.overflowed { None } else { self.sum };
+        let v = sum
             .map(|v| {
                 DecimalAverager::<T>::try_new(
                     self.sum_scale,
@@ -380,7 +439,7 @@ impl<T: DecimalType + ArrowNumericType> Accumulator for DecimalAvgAccumulator<T>
     }
 
     fn size(&self) -> usize {
-        std::mem::size_of_val(self)
+        std::mem::size_of_val(self) + self.name.capacity()
     }
 }
 
@@ -412,6 +471,9 @@ where
 
     /// Function that computes the final average (value / count)
     avg_fn: F,
+
+    /// Track the groups whose sum overflowed
+    overflow: GroupsOverflowState,
 }
 
 impl<T, F> AvgGroupsAccumulator<T, F>
@@ -419,7 +481,12 @@ where
     T: ArrowNumericType + Send,
     F: Fn(T::Native, u64) -> Result<T::Native> + Send,
 {
-    pub fn new(sum_data_type: &DataType, return_data_type: &DataType, avg_fn: F) -> Self {
+    pub fn new(
+        sum_data_type: &DataType,
+        return_data_type: &DataType,
+        avg_fn: F,
+        overflow: GroupsOverflowState,
+    ) -> Self {
         debug!(
             "AvgGroupsAccumulator ({}, sum type: {sum_data_type:?}) --> {return_data_type:?}",
             std::any::type_name::<T>()
@@ -432,6 +499,7 @@ where
             sums: vec![],
             null_state: NullState::new(),
             avg_fn,
+            overflow,
         }
     }
 }
@@ -454,6 +522,7 @@ where
         // increment counts, update sums
         self.counts.resize(total_num_groups, 0);
         self.sums.resize(total_num_groups, T::default_value());
+        self.overflow.resize(total_num_groups);
         self.null_state.accumulate(
             group_indices,
             values,
@@ -461,13 +530,13 @@ where
             total_num_groups,
             |group_index, new_value| {
                 let sum = &mut self.sums[group_index];
-                *sum = sum.add_wrapping(new_value);
+                self.overflow.add(group_index, sum, new_value);
 
                 self.counts[group_index] += 1;
             },
         );
 
-        Ok(())
+        self.overflow.check()
     }
 
     fn merge_batch(
@@ -477,8 +546,8 @@ where
         opt_filter: Option<&arrow_array::BooleanArray>,
         total_num_groups: usize,
     ) -> Result<()> {
-        assert_eq!(values.len(), 2, "two arguments to merge_batch");
-        // first batch is counts, second is partial sums
+        // first batch is counts, second is partial sums, the optional third
+        // is the overflow flags
         let partial_counts = values[0].as_primitive::<UInt64Type>();
         let partial_sums = values[1].as_primitive::<T>();
         // update counts with partial counts
@@ -493,6 +562,16 @@ where
             },
         );
 
+        // update overflow flags
+        self.overflow.resize(total_num_groups);
+        if let Some(partial_overflowed) = values.get(2) {
+            self.overflow.merge(
+                partial_overflowed.as_boolean(),
+                group_indices,
+                opt_filter,
+            );
+        }
+
         // update sums
         self.sums.resize(total_num_groups, T::default_value());
         self.null_state.accumulate(
@@ -502,17 +581,18 @@ where
             total_num_groups,
             |group_index, new_value: <T as ArrowPrimitiveType>::Native| {
                 let sum = &mut self.sums[group_index];
-                *sum = sum.add_wrapping(new_value);
+                self.overflow.add(group_index, sum, new_value);
             },
         );
 
-        Ok(())
+        self.overflow.check()
     }
 
     fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
         let counts = emit_to.take_needed(&mut self.counts);
         let sums = emit_to.take_needed(&mut self.sums);
         let nulls = self.null_state.build(emit_to);
+        let nulls = self.overflow.null_overflowed(emit_to, nulls);
 
         assert_eq!(nulls.len(), sums.len());
         assert_eq!(counts.len(), sums.len());
@@ -520,7 +600,8 @@ where
         // don't evaluate averages with null inputs to avoid errors on null values
 
         let array: PrimitiveArray<T> = if nulls.null_count() > 0 {
-            let mut builder = PrimitiveBuilder::<T>::with_capacity(nulls.len());
+            let mut builder = PrimitiveBuilder::<T>::with_capacity(nulls.len())
+                .with_data_type(self.return_data_type.clone());
             let iter = sums.into_iter().zip(counts).zip(nulls.iter());
 
             for ((sum, count), is_valid) in iter {
@@ -556,15 +637,15 @@ where
         let sums = PrimitiveArray::<T>::new(sums.into(), nulls) // zero copy
             .with_data_type(self.sum_data_type.clone());
 
-        Ok(vec![
-            Arc::new(counts) as ArrayRef,
-            Arc::new(sums) as ArrayRef,
-        ])
+        let mut state = vec![Arc::new(counts) as ArrayRef, Arc::new(sums) as ArrayRef];
+        state.extend(self.overflow.state(emit_to));
+        Ok(state)
     }
 
     fn size(&self) -> usize {
         self.counts.capacity() * std::mem::size_of::<u64>()
             + self.sums.capacity() * std::mem::size_of::<T>()
+            + self.overflow.size()
     }
 }
 
@@ -29,6 +29,7 @@
 use crate::aggregate::regr::RegrType;
 use crate::{expressions, AggregateExpr, PhysicalExpr, PhysicalSortExpr};
 use arrow::datatypes::Schema;
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{not_impl_err, DataFusionError, Result};
 pub use datafusion_expr::AggregateFunction;
 use std::sync::Arc;
@@ -42,6 +43,29 @@ pub fn create_aggregate_expr(
     ordering_req: &[PhysicalSortExpr],
     input_schema: &Schema,
     name: impl Into<String>,
+) -> Result<Arc<dyn AggregateExpr>> {
+    create_aggregate_expr_with_overflow_mode(
+        fun,
+        distinct,
+        input_phy_exprs,
+        ordering_req,
+        input_schema,
+        name,
+        ArithmeticOverflowMode::Wrap,
+    )
+}
+
+/// Create a physical aggregation expression whose `SUM` and `AVG`
+/// accumulators handle overflow according to `overflow_mode`.
+/// This function errors when `input_phy_exprs`' can't be coerced to a valid argument type of the aggregation function.
+pub fn create_aggregate_expr_with_overflow_mode(
+    fun: &AggregateFunction,
+    distinct: bool,
+    input_phy_exprs: &[Arc<dyn PhysicalExpr>],
+    ordering_req: &[PhysicalSortExpr],
+    input_schema: &Schema,
+    name: impl Into<String>,
+    overflow_mode: ArithmeticOverflowMode,
 ) -> Result<Arc<dyn AggregateExpr>> {
     let name = name.into();
     // get the result data type for this aggregate function
@@ -99,11 +123,14 @@ pub fn create_aggregate_expr(
             name,
             data_type,
         )),
-        (AggregateFunction::Sum, false) => Arc::new(expressions::Sum::new(
-            input_phy_exprs[0].clone(),
-            name,
-            input_phy_types[0].clone(),
-        )),
+        (AggregateFunction::Sum, false) => Arc::new(
+            expressions::Sum::new(
+                input_phy_exprs[0].clone(),
+                name,
+                input_phy_types[0].clone(),
+            )
+            .with_overflow_mode(overflow_mode),
+        ),
         (AggregateFunction::Sum, true) => Arc::new(expressions::DistinctSum::new(
             vec![input_phy_exprs[0].clone()],
             name,
@@ -154,11 +181,10 @@ pub fn create_aggregate_expr(
             name,
             data_type,
         )),
-        (AggregateFunction::Avg, false) => Arc::new(expressions::Avg::new(
-            input_phy_exprs[0].clone(),
-            name,
-            data_type,
-        )),
+        (AggregateFunction::Avg, false) => Arc::new(
+            expressions::Avg::new(input_phy_exprs[0].clone(), name, data_type)
+                .with_overflow_mode(overflow_mode),
+        ),
         (AggregateFunction::Avg, true) => {
             return not_impl_err!("AVG(DISTINCT) aggregations are not available");
         }
@@ -45,6 +45,7 @@ pub(crate) mod grouping;
 pub(crate) mod median;
 #[macro_use]
 pub(crate) mod min_max;
+mod overflow;
 pub mod build_in;
 pub(crate) mod groups_accumulator;
 mod hyperloglog;
@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Overflow handling of the `SUM` and `AVG` accumulators, as configured by
+//! [`ArithmeticOverflowMode`]
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::compute::{sum, sum_checked};
+use arrow::datatypes::{DataType, Field};
+use arrow::error::ArrowError;
+use arrow_array::{Array, ArrowNativeTypeOp, ArrowNumericType, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, NullBuffer};
+use datafusion_common::config::ArithmeticOverflowMode;
+use datafusion_common::{DataFusionError, Result};
+
+use crate::expressions::format_state_name;
+
+use super::groups_accumulator::EmitTo;
+
+/// Returns the error raised when the aggregate `name` overflows
+pub(crate) fn aggregate_overflow_error(name: &str, e: ArrowError) -> DataFusionError {
+    DataFusionError::Execution(format!("Arithmetic overflow in {name}: {e}"))
+}
+
+/// Returns the overflow mode a sum of `data_type` is computed in.
+///
+/// A wrapped decimal is not a meaningful value, so decimal sums are checked
+/// in [`ArithmeticOverflowMode::Wrap`] too, raising an error on overflow
+pub(crate) fn sum_overflow_mode(
+    data_type: &DataType,
+    overflow_mode: ArithmeticOverflowMode,
+) -> ArithmeticOverflowMode {
+    match (data_type, overflow_mode) {
+        (
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _),
+            ArithmeticOverflowMode::Wrap,
+        ) => ArithmeticOverflowMode::Error,
+        _ => overflow_mode,
+    }
+}
+
+/// Returns the extra state field used to carry overflowed partial results
+/// of the aggregate `name` in [`ArithmeticOverflowMode::Null`]
+pub(crate) fn overflowed_state_field(
+    name: &str,
+    overflow_mode: ArithmeticOverflowMode,
+) -> Option<Field> {
+    (overflow_mode == ArithmeticOverflowMode::Null).then(|| {
+        Field::new(
+            format_state_name(name, "overflowed"),
+            DataType::Boolean,
+            true,
+        )
+    })
+}
+
+/// Adds `value` to the running `sum` of a single group according to
+/// `overflow_mode`
+///
+/// Returns `Ok(false)` if the sum overflowed in
+/// [`ArithmeticOverflowMode::Null`], in which case `sum` is left unchanged
+pub(crate) fn add_sum<N: ArrowNativeTypeOp>(
+    sum: &mut N,
+    value: N,
+    overflow_mode: ArithmeticOverflowMode,
+    name: &str,
+) -> Result<bool> {
+    match overflow_mode {
+        ArithmeticOverflowMode::Wrap => {
+            *sum = sum.add_wrapping(value);
+            Ok(true)
+        }
+        ArithmeticOverflowMode::Error => {
+            *sum = sum
+                .add_checked(value)
+                .map_err(|e| aggregate_overflow_error(name, e))?;
+            Ok(true)
+        }
+        ArithmeticOverflowMode::Null => match sum.add_checked(value) {
+            Ok(v) => {
+                *sum = v;
+                Ok(true)
+            }
+            Err(_) => Ok(false),
+        },
+    }
+}
+
+/// Adds the sum of `values` to the running `acc` according to `overflow_mode`
+///
+/// Returns `Ok(false)` if the sum overflowed in
+/// [`ArithmeticOverflowMode::Null`]
+pub(crate) fn add_batch<T: ArrowNumericType>(
+    acc: &mut Option<T::Native>,
+    values: &PrimitiveArray<T>,
+    overflow_mode: ArithmeticOverflowMode,
+    name: &str,
+) -> Result<bool> {
+    let batch_sum = match overflow_mode {
+        ArithmeticOverflowMode::Wrap => sum(values),
+        ArithmeticOverflowMode::Error => {
+            sum_checked(values).map_err(|e| aggregate_overflow_error(name, e))?
+        }
+        ArithmeticOverflowMode::Null => match sum_checked(values) {
+            Ok(batch_sum) => batch_sum,
+            Err(_) => return Ok(false),
+        },
+    };
+    match batch_sum {
+        Some(x) => {
+            let v = acc.get_or_insert(T::Native::usize_as(0));
+            add_sum(v, x, overflow_mode, name)
+        }
+        None => Ok(true),
+    }
+}
+
+/// Tracks the groups of a [`GroupsAccumulator`] whose running sum overflowed
+///
+/// [`GroupsAccumulator`]: crate::GroupsAccumulator
+#[derive(Debug)]
+pub(crate) struct GroupsOverflowState {
+    overflow_mode: ArithmeticOverflowMode,
+    /// Name of the aggregate, used in error messages
+    name: String,
+    /// Whether the sum of each group overflowed, only maintained in
+    /// [`ArithmeticOverflowMode::Null`]
+    overflowed: Vec<bool>,
+    /// The first overflow seen in [`ArithmeticOverflowMode::Error`]
+    error: Option<ArrowError>,
+}
+
+impl GroupsOverflowState {
+    pub(crate) fn new(overflow_mode: ArithmeticOverflowMode, name: &str) -> Self {
+        Self {
+            overflow_mode,
+            name: name.to_string(),
+            overflowed: vec![],
+            error: None,
+        }
+    }
+
+    pub(crate) fn resize(&mut self, total_num_groups: usize) {
+        if self.overflow_mode == ArithmeticOverflowMode::Null {
+            self.overflowed.resize(total_num_groups, false);
+        }
+    }
+
+    /// Adds `value` to `sum`, the running sum of `group_index`
+    pub(crate) fn add<N: ArrowNativeTypeOp>(
+        &mut self,
+        group_index: usize,
+        sum: &mut N,
+        value: N,
+    ) {
+        match self.overflow_mode {
+            ArithmeticOverflowMode::Wrap => *sum = sum.add_wrapping(value),
+            ArithmeticOverflowMode::Error => match sum.add_checked(value) {
+                Ok(v) => *sum = v,
+                Err(e) => {
+                    self.error.get_or_insert(e);
+                }
+            },
+            ArithmeticOverflowMode::Null => {
+                if self.overflowed[group_index] {
+                    return;
+                }
+                match sum.add_checked(value) {
+                    Ok(v) => *sum = v,
+                    Err(_) => self.overflowed[group_index] = true,
+                }
+            }
+        }
+    }
+
+    /// Returns the error for the first overflow seen since the last call, if any
+    pub(crate) fn check(&mut self) -> Result<()> {
+        match self.error.take() {
+            Some(e) => Err(aggregate_overflow_error(&self.name, e)),
+            None => Ok(()),
+        }
+    }
+
+    /// Marks the groups flagged in `partial_overflowed`, the state produced by
+    /// [`Self::state`] of another accumulator, as overflowed
+    pub(crate) fn merge(
+        &mut self,
+        partial_overflowed: &BooleanArray,
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+    ) {
+        for (idx, &group_index) in group_indices.iter().enumerate() {
+            let selected = opt_filter
+                .map(|filter| filter.is_valid(idx) && filter.value(idx))
+                .unwrap_or(true);
+            if selected
+                && partial_overflowed.is_valid(idx)
+                && partial_overflowed.value(idx)
+            {
+                self.overflowed[group_index] = true;
+            }
+        }
+    }
+
+    /// Emits the overflow flags as an extra state column in
+    /// [`ArithmeticOverflowMode::Null`]
+    pub(crate) fn state(&mut self, emit_to: EmitTo) -> Option<ArrayRef> {
+        (self.overflow_mode == ArithmeticOverflowMode::Null).then(|| {
+            let overflowed = emit_to.take_needed(&mut self.overflowed);
+            Arc::new(BooleanArray::from(overflowed)) as ArrayRef
+        })
+    }
+
+    /// Marks the groups that overflowed as null in `nulls`
+    pub(crate) fn null_overflowed(
+        &mut self,
+        emit_to: EmitTo,
+        nulls: NullBuffer,
+    ) -> NullBuffer {
+        if self.overflow_mode != ArithmeticOverflowMode::Null {
+            return nulls;
+        }
+        let overflowed = emit_to.take_needed(&mut self.overflowed);
+        let valid = BooleanBuffer::collect_bool(nulls.len(), |i| {
+            nulls.is_valid(i) && !overflowed[i]
+        });
+        NullBuffer::new(valid)
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.overflowed.capacity() + self.name.capacity()
+    }
+}
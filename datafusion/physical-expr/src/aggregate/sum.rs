@@ -20,19 +20,28 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use super::groups_accumulator::accumulate::NullState;
 use super::groups_accumulator::prim_op::PrimitiveGroupsAccumulator;
+use super::groups_accumulator::EmitTo;
+use super::overflow::{
+    add_batch, aggregate_overflow_error, overflowed_state_field, sum_overflow_mode,
+    GroupsOverflowState,
+};
 use crate::aggregate::utils::down_cast_any_ref;
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, GroupsAccumulator, PhysicalExpr};
-use arrow::compute::sum;
+use arrow::compute::{sum, sum_checked};
 use arrow::datatypes::DataType;
 use arrow::{array::ArrayRef, datatypes::Field};
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
     Decimal128Type, Decimal256Type, Float64Type, Int64Type, UInt64Type,
 };
-use arrow_array::{Array, ArrowNativeTypeOp, ArrowNumericType};
+use arrow_array::{
+    Array, ArrowNativeTypeOp, ArrowNumericType, BooleanArray, PrimitiveArray,
+};
 use arrow_buffer::ArrowNativeType;
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{not_impl_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::type_coercion::aggregates::sum_return_type;
 use datafusion_expr::Accumulator;
@@ -44,6 +53,7 @@ pub struct Sum {
     data_type: DataType,
     expr: Arc<dyn PhysicalExpr>,
     nullable: bool,
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl Sum {
@@ -54,13 +64,22 @@ impl Sum {
         data_type: DataType,
     ) -> Self {
         let data_type = sum_return_type(&data_type).unwrap();
+        let overflow_mode = sum_overflow_mode(&data_type, ArithmeticOverflowMode::Wrap);
         Self {
             name: name.into(),
             expr,
             data_type,
             nullable: true,
+            overflow_mode,
         }
     }
+
+    /// Set how overflow of the sum is handled. Decimal sums are always
+    /// checked, raising an error in [`ArithmeticOverflowMode::Wrap`]
+    pub fn with_overflow_mode(mut self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        self.overflow_mode = sum_overflow_mode(&self.data_type, overflow_mode);
+        self
+    }
 }
 
 /// Sum only supports a subset of numeric types, instead relying on type coercion
@@ -99,18 +118,24 @@ impl AggregateExpr for Sum {
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(SumAccumulator::<$t>::new($dt.clone())))
+                Ok(Box::new(SumAccumulator::<$t>::new(
+                    $dt.clone(),
+                    self.overflow_mode,
+                    &self.name,
+                )))
             };
         }
         downcast_sum!(self, helper)
     }
 
     fn state_fields(&self) -> Result<Vec<Field>> {
-        Ok(vec![Field::new(
+        let mut fields = vec![Field::new(
             format_state_name(&self.name, "sum"),
             self.data_type.clone(),
             self.nullable,
-        )])
+        )];
+        fields.extend(overflowed_state_field(&self.name, self.overflow_mode));
+        Ok(fields)
     }
 
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
@@ -126,6 +151,19 @@ impl AggregateExpr for Sum {
     }
 
     fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
+        if self.overflow_mode != ArithmeticOverflowMode::Wrap {
+            macro_rules! helper {
+                ($t:ty, $dt:expr) => {
+                    Ok(Box::new(CheckedSumGroupsAccumulator::<$t>::new(
+                        &$dt,
+                        self.overflow_mode,
+                        &self.name,
+                    )))
+                };
+            }
+            return downcast_sum!(self, helper);
+        }
+
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
                 Ok(Box::new(PrimitiveGroupsAccumulator::<$t, _>::new(
@@ -144,7 +182,11 @@ impl AggregateExpr for Sum {
     fn create_sliding_accumulator(&self) -> Result<Box<dyn Accumulator>> {
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(SlidingSumAccumulator::<$t>::new($dt.clone())))
+                Ok(Box::new(SlidingSumAccumulator::<$t>::new(
+                    $dt.clone(),
+                    self.overflow_mode,
+                    &self.name,
+                )))
             };
         }
         downcast_sum!(self, helper)
@@ -159,6 +201,7 @@ impl PartialEq<dyn Any> for Sum {
                 self.name == x.name
                     && self.data_type == x.data_type
                     && self.nullable == x.nullable
+                    && self.overflow_mode == x.overflow_mode
                     && self.expr.eq(&x.expr)
             })
             .unwrap_or(false)
@@ -169,6 +212,11 @@ impl PartialEq<dyn Any> for Sum {
 struct SumAccumulator<T: ArrowNumericType> {
     sum: Option<T::Native>,
     data_type: DataType,
+    overflow_mode: ArithmeticOverflowMode,
+    /// Set once the sum overflowed in [`ArithmeticOverflowMode::Null`]
+    overflowed: bool,
+    /// Name of the aggregate, used in error messages
+    name: String,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
@@ -178,38 +226,160 @@ impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(
+        data_type: DataType,
+        overflow_mode: ArithmeticOverflowMode,
+        name: &str,
+    ) -> Self {
         Self {
             sum: None,
             data_type,
+            overflow_mode,
+            overflowed: false,
+            name: name.to_string(),
         }
     }
 }
 
 impl<T: ArrowNumericType> Accumulator for SumAccumulator<T> {
     fn state(&self) -> Result<Vec<ScalarValue>> {
-        Ok(vec![self.evaluate()?])
+        let mut state = vec![self.evaluate()?];
+        if self.overflow_mode == ArithmeticOverflowMode::Null {
+            state.push(ScalarValue::Boolean(Some(self.overflowed)));
+        }
+        Ok(state)
     }
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
-        let values = values[0].as_primitive::<T>();
-        if let Some(x) = sum(values) {
-            let v = self.sum.get_or_insert(T::Native::usize_as(0));
-            *v = v.add_wrapping(x);
+        if self.overflowed {
+            return Ok(());
         }
+        let values = values[0].as_primitive::<T>();
+        self.overflowed =
+            !add_batch(&mut self.sum, values, self.overflow_mode, &self.name)?;
         Ok(())
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
-        self.update_batch(states)
+        if self.overflow_mode == ArithmeticOverflowMode::Null
+            && states[1].as_boolean().true_count() > 0
+        {
+            self.overflowed = true;
+        }
+        self.update_batch(&states[..1])
     }
 
     fn evaluate(&self) -> Result<ScalarValue> {
-        ScalarValue::new_primitive::<T>(self.sum, &self.data_type)
+        let sum = if self.overflowed { None } else { self.sum };
+        ScalarValue::new_primitive::<T>(sum, &self.data_type)
     }
 
     fn size(&self) -> usize {
-        std::mem::size_of_val(self)
+        std::mem::size_of_val(self) + self.name.capacity()
+    }
+}
+
+/// A [`GroupsAccumulator`] computing SUM with overflow checks, either raising
+/// an error or producing NULL for the groups whose sum overflowed
+#[derive(Debug)]
+struct CheckedSumGroupsAccumulator<T: ArrowNumericType + Send> {
+    /// Sums per group, stored as the native type
+    sums: Vec<T::Native>,
+
+    /// The output type (needed for Decimal precision and scale)
+    data_type: DataType,
+
+    /// Track nulls in the input / filters
+    null_state: NullState,
+
+    /// Track the groups whose sum overflowed
+    overflow: GroupsOverflowState,
+}
+
+impl<T: ArrowNumericType + Send> CheckedSumGroupsAccumulator<T> {
+    fn new(
+        data_type: &DataType,
+        overflow_mode: ArithmeticOverflowMode,
+        name: &str,
+    ) -> Self {
+        Self {
+            sums: vec![],
+            data_type: data_type.clone(),
+            null_state: NullState::new(),
+            overflow: GroupsOverflowState::new(overflow_mode, name),
+        }
+    }
+}
+
+impl<T: ArrowNumericType + Send> GroupsAccumulator for CheckedSumGroupsAccumulator<T> {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 1, "single argument to update_batch");
+        let values = values[0].as_primitive::<T>();
+
+        self.sums.resize(total_num_groups, T::default_value());
+        self.overflow.resize(total_num_groups);
+        self.null_state.accumulate(
+            group_indices,
+            values,
+            opt_filter,
+            total_num_groups,
+            |group_index, new_value| {
+                let sum = &mut self.sums[group_index];
+                self.overflow.add(group_index, sum, new_value);
+            },
+        );
+
+        self.overflow.check()
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let sums = emit_to.take_needed(&mut self.sums);
+        let nulls = self.null_state.build(emit_to);
+        let nulls = self.overflow.null_overflowed(emit_to, nulls);
+        let sums = PrimitiveArray::<T>::new(sums.into(), Some(nulls)) // no copy
+            .with_data_type(self.data_type.clone());
+        Ok(Arc::new(sums))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        let sums = emit_to.take_needed(&mut self.sums);
+        let nulls = self.null_state.build(emit_to);
+        let sums = PrimitiveArray::<T>::new(sums.into(), Some(nulls)) // no copy
+            .with_data_type(self.data_type.clone());
+
+        let mut state: Vec<ArrayRef> = vec![Arc::new(sums)];
+        state.extend(self.overflow.state(emit_to));
+        Ok(state)
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        self.overflow.resize(total_num_groups);
+        if let Some(partial_overflowed) = values.get(1) {
+            self.overflow.merge(
+                partial_overflowed.as_boolean(),
+                group_indices,
+                opt_filter,
+            );
+        }
+        self.update_batch(&values[..1], group_indices, opt_filter, total_num_groups)
+    }
+
+    fn size(&self) -> usize {
+        self.sums.capacity() * std::mem::size_of::<T::Native>()
+            + self.null_state.size()
+            + self.overflow.size()
     }
 }
 
@@ -220,6 +390,10 @@ struct SlidingSumAccumulator<T: ArrowNumericType> {
     sum: T::Native,
     count: u64,
     data_type: DataType,
+    /// Whether the sum fails on overflow instead of wrapping around
+    checked: bool,
+    /// Name of the aggregate, used in error messages
+    name: String,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
@@ -229,13 +403,43 @@ impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SlidingSumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(
+        data_type: DataType,
+        overflow_mode: ArithmeticOverflowMode,
+        name: &str,
+    ) -> Self {
         Self {
             sum: T::Native::usize_as(0),
             count: 0,
             data_type,
+            checked: overflow_mode != ArithmeticOverflowMode::Wrap,
+            name: name.to_string(),
         }
     }
+
+    /// Adds (or subtracts, if `retract`) the sum of `values` to the running sum
+    fn apply(&mut self, values: &PrimitiveArray<T>, retract: bool) -> Result<()> {
+        if !self.checked {
+            if let Some(x) = sum(values) {
+                self.sum = if retract {
+                    self.sum.sub_wrapping(x)
+                } else {
+                    self.sum.add_wrapping(x)
+                };
+            }
+            return Ok(());
+        }
+        let overflow = |e| aggregate_overflow_error(&self.name, e);
+        if let Some(x) = sum_checked(values).map_err(overflow)? {
+            self.sum = if retract {
+                self.sum.sub_checked(x)
+            } else {
+                self.sum.add_checked(x)
+            }
+            .map_err(overflow)?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
@@ -246,17 +450,11 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
         self.count += (values.len() - values.null_count()) as u64;
-        if let Some(x) = sum(values) {
-            self.sum = self.sum.add_wrapping(x)
-        }
-        Ok(())
+        self.apply(values, false)
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
-        let values = states[0].as_primitive::<T>();
-        if let Some(x) = sum(values) {
-            self.sum = self.sum.add_wrapping(x)
-        }
+        self.apply(states[0].as_primitive::<T>(), false)?;
         if let Some(x) = sum(states[1].as_primitive::<UInt64Type>()) {
             self.count += x;
         }
@@ -274,9 +472,7 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
 
     fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
         let values = values[0].as_primitive::<T>();
-        if let Some(x) = sum(values) {
-            self.sum = self.sum.sub_wrapping(x)
-        }
+        self.apply(values, true)?;
         self.count -= (values.len() - values.null_count()) as u64;
         Ok(())
     }
@@ -289,7 +485,10 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::expressions::tests::assert_aggregate;
+    use crate::expressions::col;
+    use crate::expressions::tests::{aggregate, aggregate_new, assert_aggregate};
+    use arrow::datatypes::Schema;
+    use arrow::record_batch::RecordBatch;
     use arrow_array::*;
     use datafusion_expr::AggregateFunction;
 
@@ -395,4 +594,43 @@ mod tests {
             Arc::new(Float64Array::from(vec![1_f64, 2_f64, 3_f64, 4_f64, 5_f64]));
         assert_aggregate(a, AggregateFunction::Sum, false, ScalarValue::from(15_f64));
     }
+
+    #[test]
+    fn sum_i64_overflow_modes() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let a: ArrayRef = Arc::new(Int64Array::from(vec![i64::MAX, 1]));
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![a])?;
+        let sum = |mode| {
+            Arc::new(
+                Sum::new(col("a", &schema).unwrap(), "sum", DataType::Int64)
+                    .with_overflow_mode(mode),
+            )
+        };
+
+        let actual = aggregate(&batch, sum(ArithmeticOverflowMode::Wrap))?;
+        assert_eq!(actual, ScalarValue::Int64(Some(i64::MIN)));
+
+        let err = aggregate(&batch, sum(ArithmeticOverflowMode::Error)).unwrap_err();
+        assert!(err.to_string().contains("Arithmetic overflow in sum"));
+        let err = aggregate_new(&batch, sum(ArithmeticOverflowMode::Error)).unwrap_err();
+        assert!(err.to_string().contains("Arithmetic overflow in sum"));
+
+        let actual = aggregate(&batch, sum(ArithmeticOverflowMode::Null))?;
+        assert_eq!(actual, ScalarValue::Int64(None));
+        let actual = aggregate_new(&batch, sum(ArithmeticOverflowMode::Null))?;
+        assert_eq!(actual.as_ref(), &Int64Array::from(vec![None]));
+
+        // the overflow of a partial sum survives the merge
+        let agg = sum(ArithmeticOverflowMode::Null);
+        assert_eq!(agg.state_fields()?.len(), 2);
+        let mut partial = agg.create_groups_accumulator()?;
+        partial.update_batch(&[batch.column(0).clone()], &[0, 0], None, 1)?;
+        let state = partial.state(EmitTo::All)?;
+        let mut accum = agg.create_groups_accumulator()?;
+        accum.update_batch(&[Arc::new(Int64Array::from(vec![1]))], &[0], None, 1)?;
+        accum.merge_batch(&state, &[0], None, 1)?;
+        let actual = accum.evaluate(EmitTo::All)?;
+        assert_eq!(actual.as_ref(), &Int64Array::from(vec![None]));
+        Ok(())
+    }
 }
@@ -28,6 +28,7 @@ use arrow_array::{ArrowNativeTypeOp, ArrowPrimitiveType};
 use arrow_buffer::{ArrowNativeType, ToByteSlice};
 use std::collections::HashSet;
 
+use crate::aggregate::overflow::aggregate_overflow_error;
 use crate::aggregate::sum::downcast_sum;
 use crate::aggregate::utils::down_cast_any_ref;
 use crate::{AggregateExpr, PhysicalExpr};
@@ -91,7 +92,9 @@ impl AggregateExpr for DistinctSum {
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(DistinctSumAccumulator::<$t>::try_new(&$dt)?))
+                Ok(Box::new(DistinctSumAccumulator::<$t>::try_new(
+                    &$dt, &self.name,
+                )?))
             };
         }
         downcast_sum!(self, helper)
@@ -137,6 +140,8 @@ impl<T: ArrowNativeTypeOp> Eq for Hashable<T> {}
 struct DistinctSumAccumulator<T: ArrowPrimitiveType> {
     values: HashSet<Hashable<T::Native>, RandomState>,
     data_type: DataType,
+    /// Name of the aggregate, used in error messages
+    name: String,
 }
 
 impl<T: ArrowPrimitiveType> std::fmt::Debug for DistinctSumAccumulator<T> {
@@ -146,10 +151,11 @@ impl<T: ArrowPrimitiveType> std::fmt::Debug for DistinctSumAccumulator<T> {
 }
 
 impl<T: ArrowPrimitiveType> DistinctSumAccumulator<T> {
-    pub fn try_new(data_type: &DataType) -> Result<Self> {
+    pub fn try_new(data_type: &DataType, name: &str) -> Result<Self> {
         Ok(Self {
             values: HashSet::default(),
             data_type: data_type.clone(),
+            name: name.to_string(),
         })
     }
 }
@@ -202,9 +208,19 @@ impl<T: ArrowPrimitiveType> Accumulator for DistinctSumAccumulator<T> {
     }
 
     fn evaluate(&self) -> Result<ScalarValue> {
+        // a wrapped decimal is not a meaningful value, so decimal sums are checked
+        let checked = matches!(
+            self.data_type,
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+        );
         let mut acc = T::Native::usize_as(0);
         for distinct_value in self.values.iter() {
-            acc = acc.add_wrapping(distinct_value.0)
+            acc = if checked {
+                acc.add_checked(distinct_value.0)
+                    .map_err(|e| aggregate_overflow_error(&self.name, e))?
+            } else {
+                acc.add_wrapping(distinct_value.0)
+            }
         }
         let v = (!self.values.is_empty()).then_some(acc);
         ScalarValue::new_primitive::<T>(v, &self.data_type)
@@ -21,8 +21,8 @@ use crate::{AggregateExpr, PhysicalSortExpr};
 use arrow::array::ArrayRef;
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
-    Decimal128Type, DecimalType, TimestampMicrosecondType, TimestampMillisecondType,
-    TimestampNanosecondType, TimestampSecondType,
+    Decimal128Type, Decimal256Type, DecimalType, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
 };
 use arrow_array::ArrowNativeTypeOp;
 use arrow_buffer::ArrowNativeType;
@@ -139,6 +139,12 @@ pub fn adjust_output_array(
                 .clone()
                 .with_precision_and_scale(*p, *s)?,
         ) as ArrayRef,
+        DataType::Decimal256(p, s) => Arc::new(
+            array
+                .as_primitive::<Decimal256Type>()
+                .clone()
+                .with_precision_and_scale(*p, *s)?,
+        ) as ArrayRef,
         DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, tz) => Arc::new(
             array
                 .as_primitive::<TimestampNanosecondType>()
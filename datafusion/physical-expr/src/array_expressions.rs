@@ -18,11 +18,13 @@
 //! Array expressions
 
 use std::any::type_name;
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow::array::*;
 use arrow::buffer::OffsetBuffer;
 use arrow::compute;
+use arrow::compute::SortOptions;
 use arrow::datatypes::{DataType, Field, UInt64Type};
 use arrow::row::{RowConverter, SortField};
 use arrow_buffer::NullBuffer;
@@ -31,6 +33,8 @@ use datafusion_common::cast::{
     as_generic_string_array, as_int64_array, as_list_array, as_string_array,
 };
 use datafusion_common::utils::array_into_list_array;
+
+use crate::map_expressions::map_cardinality;
 use datafusion_common::{
     exec_err, internal_datafusion_err, internal_err, not_impl_err, plan_err,
     DataFusionError, Result,
@@ -1340,6 +1344,19 @@ macro_rules! to_string {
     }};
 }
 
+/// Returns the rows of a `List`, `LargeList` or `FixedSizeList` array, or
+/// `None` if `array` is not a list
+fn list_rows(array: &ArrayRef) -> Option<Vec<Option<ArrayRef>>> {
+    match array.data_type() {
+        DataType::List(_) => Some(array.as_list::<i32>().iter().collect()),
+        DataType::LargeList(_) => Some(array.as_list::<i64>().iter().collect()),
+        DataType::FixedSizeList(_, _) => {
+            Some(array.as_fixed_size_list().iter().collect())
+        }
+        _ => None,
+    }
+}
+
 /// Array_to_string SQL function
 pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
     let arr = &args[0];
@@ -1361,22 +1378,21 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
         null_string: String,
         with_null_string: bool,
     ) -> Result<&mut String> {
-        match arr.data_type() {
-            DataType::List(..) => {
-                let list_array = downcast_arg!(arr, ListArray);
+        if let Some(rows) = list_rows(&arr) {
+            for value in rows.into_iter().flatten() {
+                compute_array_to_string(
+                    arg,
+                    value,
+                    delimiter.clone(),
+                    null_string.clone(),
+                    with_null_string,
+                )?;
+            }
 
-                for i in 0..list_array.len() {
-                    compute_array_to_string(
-                        arg,
-                        list_array.value(i),
-                        delimiter.clone(),
-                        null_string.clone(),
-                        with_null_string,
-                    )?;
-                }
+            return Ok(arg);
+        }
 
-                Ok(arg)
-            }
+        match arr.data_type() {
             DataType::Null => Ok(arg),
             data_type => {
                 macro_rules! array_function {
@@ -1399,10 +1415,9 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
     let mut arg = String::from("");
     let mut res: Vec<Option<String>> = Vec::new();
 
-    match arr.data_type() {
-        DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => {
-            let list_array = arr.as_list::<i32>();
-            for (arr, &delimiter) in list_array.iter().zip(delimiters.iter()) {
+    match list_rows(arr) {
+        Some(rows) => {
+            for (arr, &delimiter) in rows.into_iter().zip(delimiters.iter()) {
                 if let (Some(arr), Some(delimiter)) = (arr, delimiter) {
                     arg = String::from("");
                     let s = compute_array_to_string(
@@ -1424,7 +1439,7 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
                 }
             }
         }
-        _ => {
+        None => {
             // delimiter length is 1
             assert_eq!(delimiters.len(), 1);
             let delimiter = delimiters[0].unwrap();
@@ -1451,6 +1466,9 @@ pub fn array_to_string(args: &[ArrayRef]) -> Result<ArrayRef> {
 
 /// Cardinality SQL function
 pub fn cardinality(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if let DataType::Map(_, _) = args[0].data_type() {
+        return map_cardinality(args);
+    }
     let list_array = as_list_array(&args[0])?.clone();
 
     let result = list_array
@@ -1855,6 +1873,503 @@ pub fn array_intersect(args: &[ArrayRef]) -> Result<ArrayRef> {
     Ok(arr)
 }
 
+/// Returns the values of a `List` or `LargeList` array together with the
+/// range of values of each row, which is `None` for null rows
+fn list_ranges(
+    name: &str,
+    array: &ArrayRef,
+) -> Result<(ArrayRef, Vec<Option<Range<usize>>>)> {
+    fn ranges<O: OffsetSizeTrait>(
+        array: &GenericListArray<O>,
+    ) -> (ArrayRef, Vec<Option<Range<usize>>>) {
+        let ranges = array
+            .value_offsets()
+            .windows(2)
+            .enumerate()
+            .map(|(row, window)| {
+                array
+                    .is_valid(row)
+                    .then(|| window[0].as_usize()..window[1].as_usize())
+            })
+            .collect();
+        (array.values().clone(), ranges)
+    }
+
+    match array.data_type() {
+        DataType::List(_) => Ok(ranges(array.as_list::<i32>())),
+        DataType::LargeList(_) => Ok(ranges(array.as_list::<i64>())),
+        DataType::Null => Ok((new_empty_array(&DataType::Null), vec![None; array.len()])),
+        other => exec_err!("{name} does not support type '{other:?}'"),
+    }
+}
+
+/// Builds a `List` or `LargeList` array of type `data_type` from `values`
+/// taken at `indices`, where row `i` holds the next `lengths[i]` indices or
+/// is null when `lengths[i]` is `None`
+fn build_list(
+    data_type: &DataType,
+    values: &dyn Array,
+    indices: Vec<Option<u64>>,
+    lengths: &[Option<usize>],
+) -> Result<ArrayRef> {
+    let values = compute::take(values, &UInt64Array::from(indices), None)?;
+    new_list(data_type, values, lengths)
+}
+
+/// Builds a `List` or `LargeList` array of type `data_type` whose row `i`
+/// holds the next `lengths[i]` of `values`, or is null when it is `None`
+fn new_list(
+    data_type: &DataType,
+    values: ArrayRef,
+    lengths: &[Option<usize>],
+) -> Result<ArrayRef> {
+    let nulls = NullBuffer::from(lengths.iter().map(Option::is_some).collect::<Vec<_>>());
+    let nulls = (nulls.null_count() > 0).then_some(nulls);
+    let lengths = lengths.iter().map(|length| length.unwrap_or(0));
+    match data_type {
+        DataType::List(field) => Ok(Arc::new(ListArray::try_new(
+            field.clone(),
+            OffsetBuffer::from_lengths(lengths),
+            values,
+            nulls,
+        )?)),
+        DataType::LargeList(field) => Ok(Arc::new(LargeListArray::try_new(
+            field.clone(),
+            OffsetBuffer::from_lengths(lengths),
+            values,
+            nulls,
+        )?)),
+        other => internal_err!("Expected a list type, got '{other:?}'"),
+    }
+}
+
+/// Returns whether the element at `index` of `values` is null
+fn is_null_element(values: &ArrayRef, index: usize) -> bool {
+    values.data_type().is_null() || values.is_null(index)
+}
+
+/// Parses the `ASC`/`DESC` and `NULLS FIRST`/`NULLS LAST` arguments of
+/// array_sort. Like `ORDER BY`, nulls come first when sorting in descending
+/// order unless specified otherwise.
+fn array_sort_options(
+    order: Option<&str>,
+    null_order: Option<&str>,
+) -> Result<SortOptions> {
+    let descending = match order.map(|order| order.trim().to_uppercase()).as_deref() {
+        None | Some("ASC") => false,
+        Some("DESC") => true,
+        Some(other) => {
+            return exec_err!(
+                "array_sort expects 'ASC' or 'DESC' as its order, got '{other}'"
+            )
+        }
+    };
+    let nulls_first = match null_order
+        .map(|null_order| null_order.split_whitespace().join(" ").to_uppercase())
+        .as_deref()
+    {
+        None => descending,
+        Some("NULLS FIRST") => true,
+        Some("NULLS LAST") => false,
+        Some(other) => {
+            return exec_err!(
+                "array_sort expects 'NULLS FIRST' or 'NULLS LAST' as its null order, got '{other}'"
+            )
+        }
+    };
+    Ok(SortOptions {
+        descending,
+        nulls_first,
+    })
+}
+
+/// array_sort SQL function, without a comparator: sorts each array in
+/// ascending order with nulls last, or in the order given by the optional
+/// `ASC`/`DESC` and `NULLS FIRST`/`NULLS LAST` arguments
+pub fn array_sort(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.is_empty() || args.len() > 3 {
+        return exec_err!(
+            "array_sort expects one to three arguments, or a comparator lambda"
+        );
+    }
+    if args[0].data_type().is_null() {
+        return Ok(args[0].clone());
+    }
+    let order = args.get(1).map(|arg| as_string_array(arg)).transpose()?;
+    let null_order = args.get(2).map(|arg| as_string_array(arg)).transpose()?;
+
+    let (values, ranges) = list_ranges("array_sort", &args[0])?;
+    let converter = RowConverter::new(vec![SortField::new(values.data_type().clone())])?;
+    let rows = converter.convert_columns(&[values.clone()])?;
+
+    let mut indices = vec![];
+    let mut lengths = vec![];
+    for (row, range) in ranges.into_iter().enumerate() {
+        let Some(range) = range else {
+            lengths.push(None);
+            continue;
+        };
+        let order = order.filter(|order| order.is_valid(row));
+        let null_order = null_order.filter(|null_order| null_order.is_valid(row));
+        let options = array_sort_options(
+            order.map(|order| order.value(row)),
+            null_order.map(|null_order| null_order.value(row)),
+        )?;
+
+        lengths.push(Some(range.len()));
+        let (nulls, mut sorted): (Vec<_>, Vec<_>) =
+            range.partition(|&i| is_null_element(&values, i));
+        if options.descending {
+            sorted.sort_by(|&a, &b| rows.row(b).cmp(&rows.row(a)));
+        } else {
+            sorted.sort_by(|&a, &b| rows.row(a).cmp(&rows.row(b)));
+        }
+        let (first, last) = if options.nulls_first {
+            (nulls, sorted)
+        } else {
+            (sorted, nulls)
+        };
+        indices.extend(first.into_iter().chain(last).map(|i| Some(i as u64)));
+    }
+    build_list(args[0].data_type(), values.as_ref(), indices, &lengths)
+}
+
+/// array_distinct SQL function: the distinct elements of each array, in order
+/// of first occurrence
+pub fn array_distinct(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("array_distinct expects one argument");
+    }
+    if args[0].data_type().is_null() {
+        return Ok(args[0].clone());
+    }
+    let (values, ranges) = list_ranges("array_distinct", &args[0])?;
+    let converter = RowConverter::new(vec![SortField::new(values.data_type().clone())])?;
+    let rows = converter.convert_columns(&[values.clone()])?;
+
+    let mut indices = vec![];
+    let mut lengths = vec![];
+    for range in ranges {
+        let Some(range) = range else {
+            lengths.push(None);
+            continue;
+        };
+        let start = indices.len();
+        let mut seen = HashSet::new();
+        indices.extend(
+            range
+                .filter(|&i| seen.insert(rows.row(i)))
+                .map(|i| Some(i as u64)),
+        );
+        lengths.push(Some(indices.len() - start));
+    }
+    build_list(args[0].data_type(), values.as_ref(), indices, &lengths)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SetOp {
+    Union,
+    Except,
+}
+
+impl std::fmt::Display for SetOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetOp::Union => write!(f, "array_union"),
+            SetOp::Except => write!(f, "array_except"),
+        }
+    }
+}
+
+/// The distinct elements of each pair of arrays that are in either array
+/// (union) or only in the first array (except). The result is null when
+/// either array is null.
+fn general_set_op(args: &[ArrayRef], set_op: SetOp) -> Result<ArrayRef> {
+    if args.len() != 2 {
+        return exec_err!("{set_op} expects two arguments");
+    }
+    let (left, right) = (&args[0], &args[1]);
+    let data_type = match (left.data_type(), right.data_type()) {
+        (DataType::Null, DataType::Null) => return Ok(left.clone()),
+        (DataType::Null, _) => right.data_type(),
+        (_, DataType::Null) => left.data_type(),
+        (l, r) if l.equals_datatype(r) => l,
+        (l, r) => {
+            return exec_err!("{set_op} is not implemented for '{l:?}' and '{r:?}'")
+        }
+    };
+    let (left_values, left_ranges) = list_ranges(&set_op.to_string(), left)?;
+    let (right_values, right_ranges) = list_ranges(&set_op.to_string(), right)?;
+    let element_type = match data_type {
+        DataType::List(field) | DataType::LargeList(field) => field.data_type(),
+        _ => return internal_err!("{set_op}: expected a list type"),
+    };
+    let left_values = compute::cast(&left_values, element_type)?;
+    let right_values = compute::cast(&right_values, element_type)?;
+
+    let converter = RowConverter::new(vec![SortField::new(element_type.clone())])?;
+    let left_rows = converter.convert_columns(&[left_values.clone()])?;
+    let right_rows = converter.convert_columns(&[right_values.clone()])?;
+    // indices into the right values are offset by the length of the left values
+    let right_offset = left_values.len();
+
+    let mut indices = vec![];
+    let mut lengths = vec![];
+    for (left_range, right_range) in left_ranges.into_iter().zip(right_ranges) {
+        let (Some(left_range), Some(right_range)) = (left_range, right_range) else {
+            lengths.push(None);
+            continue;
+        };
+        let start = indices.len();
+        let mut seen = HashSet::new();
+        match set_op {
+            SetOp::Union => {
+                indices.extend(
+                    left_range
+                        .filter(|&i| seen.insert(left_rows.row(i)))
+                        .map(|i| Some(i as u64)),
+                );
+                indices.extend(
+                    right_range
+                        .filter(|&i| seen.insert(right_rows.row(i)))
+                        .map(|i| Some((right_offset + i) as u64)),
+                );
+            }
+            SetOp::Except => {
+                seen.extend(right_range.map(|i| right_rows.row(i)));
+                indices.extend(
+                    left_range
+                        .filter(|&i| seen.insert(left_rows.row(i)))
+                        .map(|i| Some(i as u64)),
+                );
+            }
+        }
+        lengths.push(Some(indices.len() - start));
+    }
+    let values = compute::concat(&[left_values.as_ref(), right_values.as_ref()])?;
+    build_list(data_type, values.as_ref(), indices, &lengths)
+}
+
+/// array_union SQL function
+pub fn array_union(args: &[ArrayRef]) -> Result<ArrayRef> {
+    general_set_op(args, SetOp::Union)
+}
+
+/// array_except SQL function
+pub fn array_except(args: &[ArrayRef]) -> Result<ArrayRef> {
+    general_set_op(args, SetOp::Except)
+}
+
+/// The non-null element of each array that compares as `ordering` to all the
+/// others, or null for empty arrays and arrays of nulls
+fn general_array_extremum(
+    name: &str,
+    args: &[ArrayRef],
+    ordering: std::cmp::Ordering,
+) -> Result<ArrayRef> {
+    if args.len() != 1 {
+        return exec_err!("{name} expects one argument");
+    }
+    if args[0].data_type().is_null() {
+        return Ok(args[0].clone());
+    }
+    let (values, ranges) = list_ranges(name, &args[0])?;
+    if values.data_type().is_null() {
+        return Ok(new_null_array(&DataType::Null, args[0].len()));
+    }
+    let converter = RowConverter::new(vec![SortField::new(values.data_type().clone())])?;
+    let rows = converter.convert_columns(&[values.clone()])?;
+
+    let indices = ranges
+        .into_iter()
+        .map(|range| {
+            range?
+                .filter(|&i| values.is_valid(i))
+                .reduce(|best, i| {
+                    if rows.row(i).cmp(&rows.row(best)) == ordering {
+                        i
+                    } else {
+                        best
+                    }
+                })
+                .map(|i| i as u64)
+        })
+        .collect::<UInt64Array>();
+    Ok(compute::take(values.as_ref(), &indices, None)?)
+}
+
+/// array_max SQL function
+pub fn array_max(args: &[ArrayRef]) -> Result<ArrayRef> {
+    general_array_extremum("array_max", args, std::cmp::Ordering::Greater)
+}
+
+/// array_min SQL function
+pub fn array_min(args: &[ArrayRef]) -> Result<ArrayRef> {
+    general_array_extremum("array_min", args, std::cmp::Ordering::Less)
+}
+
+/// array_zip SQL function: merges the arrays of each row into an array of
+/// structs with fields `c0`, `c1`, ..., padding the shorter arrays with nulls.
+/// The result is null when any of the arrays is null.
+pub fn array_zip(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.is_empty() {
+        return exec_err!("array_zip expects at least one argument");
+    }
+    let lists = args
+        .iter()
+        .map(|arg| list_ranges("array_zip", arg))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut indices = vec![vec![]; lists.len()];
+    let mut lengths = vec![];
+    for row in 0..args[0].len() {
+        let Some(ranges) = lists
+            .iter()
+            .map(|(_, ranges)| ranges[row].clone())
+            .collect::<Option<Vec<_>>>()
+        else {
+            lengths.push(None);
+            continue;
+        };
+        let length = ranges.iter().map(|range| range.len()).max().unwrap_or(0);
+        for (indices, range) in indices.iter_mut().zip(ranges) {
+            indices.extend(
+                (0..length)
+                    .map(|i| (i < range.len()).then_some((range.start + i) as u64)),
+            );
+        }
+        lengths.push(Some(length));
+    }
+
+    let mut fields = vec![];
+    let mut columns = vec![];
+    for (i, ((values, _), indices)) in lists.iter().zip(indices).enumerate() {
+        fields.push(Field::new(
+            format!("c{i}"),
+            values.data_type().clone(),
+            true,
+        ));
+        columns.push(compute::take(
+            values.as_ref(),
+            &UInt64Array::from(indices),
+            None,
+        )?);
+    }
+    let values: ArrayRef = Arc::new(StructArray::try_new(fields.into(), columns, None)?);
+    let data_type = DataType::List(Arc::new(Field::new(
+        "item",
+        values.data_type().clone(),
+        true,
+    )));
+    new_list(&data_type, values, &lengths)
+}
+
+/// array_resize SQL function: truncates or pads each array to the given
+/// size, padding with nulls or with the optional value
+pub fn array_resize(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 2 && args.len() != 3 {
+        return exec_err!("array_resize expects two or three arguments");
+    }
+    if args[0].data_type().is_null() {
+        return Ok(args[0].clone());
+    }
+    let (values, ranges) = list_ranges("array_resize", &args[0])?;
+    let sizes = compute::cast(&args[1], &DataType::Int64)?;
+    let sizes = as_int64_array(&sizes)?;
+    let padding = match args.get(2) {
+        Some(value) => compute::cast(value, values.data_type())?,
+        None => new_null_array(values.data_type(), args[0].len()),
+    };
+    // the padding of row `i` is at index `padding_offset + i` of the values
+    let padding_offset = values.len();
+
+    let mut indices = vec![];
+    let mut lengths = vec![];
+    for (row, range) in ranges.into_iter().enumerate() {
+        let (Some(range), true) = (range, sizes.is_valid(row)) else {
+            lengths.push(None);
+            continue;
+        };
+        let size = match usize::try_from(sizes.value(row)) {
+            Ok(size) => size,
+            Err(_) => {
+                return exec_err!(
+                    "array_resize expects a non-negative size, got {}",
+                    sizes.value(row)
+                )
+            }
+        };
+        let kept = range.len().min(size);
+        indices.extend((range.start..range.start + kept).map(|i| Some(i as u64)));
+        indices.extend((kept..size).map(|_| Some((padding_offset + row) as u64)));
+        lengths.push(Some(size));
+    }
+    let values = compute::concat(&[values.as_ref(), padding.as_ref()])?;
+    build_list(args[0].data_type(), values.as_ref(), indices, &lengths)
+}
+
+/// The arrays of integers from start to stop by step, including stop when
+/// `include_stop` is true. The start and step default to 0 and 1.
+fn general_range(name: &str, args: &[ArrayRef], include_stop: bool) -> Result<ArrayRef> {
+    let (start, stop, step) = match args {
+        [stop] => (None, as_int64_array(stop)?, None),
+        [start, stop] => (Some(as_int64_array(start)?), as_int64_array(stop)?, None),
+        [start, stop, step] => (
+            Some(as_int64_array(start)?),
+            as_int64_array(stop)?,
+            Some(as_int64_array(step)?),
+        ),
+        _ => return exec_err!("{name} expects one to three arguments"),
+    };
+
+    let mut values = vec![];
+    let mut lengths = vec![];
+    for row in 0..stop.len() {
+        let start = start.map_or(Some(0), |start| {
+            start.is_valid(row).then(|| start.value(row))
+        });
+        let stop = stop.is_valid(row).then(|| stop.value(row));
+        let step =
+            step.map_or(Some(1), |step| step.is_valid(row).then(|| step.value(row)));
+        let (Some(start), Some(stop), Some(step)) = (start, stop, step) else {
+            lengths.push(None);
+            continue;
+        };
+        if step == 0 {
+            return exec_err!("{name} expects a non-zero step");
+        }
+
+        let in_range = |value: i64| {
+            let before_stop = if step > 0 { value < stop } else { value > stop };
+            before_stop || include_stop && value == stop
+        };
+        let length = values.len();
+        let mut value = start;
+        while in_range(value) {
+            values.push(value);
+            match value.checked_add(step) {
+                Some(next) => value = next,
+                None => break,
+            }
+        }
+        lengths.push(Some(values.len() - length));
+    }
+
+    let data_type = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
+    new_list(&data_type, Arc::new(Int64Array::from(values)), &lengths)
+}
+
+/// range SQL function: the integers from start up to, but excluding, stop
+pub fn range(args: &[ArrayRef]) -> Result<ArrayRef> {
+    general_range("range", args, false)
+}
+
+/// generate_series SQL function: the integers from start up to and
+/// including stop
+pub fn generate_series(args: &[ArrayRef]) -> Result<ArrayRef> {
+    general_range("generate_series", args, true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2629,6 +3144,256 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_array_sort() {
+        // array_sort([3, 1, 2, 3, 2, 3]) = [1, 2, 2, 3, 3, 3]
+        let list_array = return_array_with_repeating_elements();
+        let array =
+            array_sort(&[list_array]).expect("failed to initialize function array_sort");
+        let result =
+            as_list_array(&array).expect("failed to initialize function array_sort");
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            &[1, 2, 2, 3, 3, 3],
+            result
+                .value(0)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap()
+                .values()
+        );
+    }
+
+    #[test]
+    fn test_array_sort_with_order() {
+        // array_sort([3, NULL, 1], 'DESC') = [NULL, 3, 1]
+        // array_sort([3, NULL, 1], 'ASC', 'NULLS FIRST') = [NULL, 1, 3]
+        let list_array: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(3), None, Some(1)]),
+                None,
+            ]));
+        let strings =
+            |value: &str| Arc::new(StringArray::from(vec![value; 2])) as ArrayRef;
+
+        let array = array_sort(&[list_array.clone(), strings("DESC")]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![None, Some(3), Some(1)]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        let array =
+            array_sort(&[list_array.clone(), strings("asc"), strings("nulls first")])
+                .unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![None, Some(1), Some(3)]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        let err = array_sort(&[list_array, strings("UP")]).unwrap_err();
+        assert!(err.to_string().contains("expects 'ASC' or 'DESC'"));
+    }
+
+    #[test]
+    fn test_array_distinct_large_list() {
+        // array_distinct([3, 1, NULL, 3, NULL, 1]) = [3, 1, NULL]
+        let list_array: ArrayRef =
+            Arc::new(LargeListArray::from_iter_primitive::<Int64Type, _, _>(
+                vec![
+                    Some(vec![Some(3), Some(1), None, Some(3), None, Some(1)]),
+                    None,
+                    Some(vec![]),
+                ],
+            ));
+        let array = array_distinct(&[list_array]).unwrap();
+        let expected = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3), Some(1), None]),
+            None,
+            Some(vec![]),
+        ]);
+        assert_eq!(array.as_list::<i64>(), &expected);
+    }
+
+    #[test]
+    fn test_array_union_and_except() {
+        let left: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(2), None]),
+                Some(vec![Some(1)]),
+            ]));
+        let right: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(3), Some(2), None]),
+                None,
+            ]));
+
+        // array_union([1, 2, 2, NULL], [3, 2, NULL]) = [1, 2, NULL, 3]
+        let array = array_union(&[left.clone(), right.clone()]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), None, Some(3)]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        // array_except([1, 2, 2, NULL], [3, 2, NULL]) = [1]
+        let array = array_except(&[left, right]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_array_max_and_min() {
+        let list_array: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(3), None, Some(7), Some(-1)]),
+                Some(vec![None]),
+                Some(vec![]),
+                None,
+            ]));
+        let array = array_max(&[list_array.clone()]).unwrap();
+        assert_eq!(
+            as_int64_array(&array).unwrap(),
+            &Int64Array::from(vec![Some(7), None, None, None])
+        );
+        let array = array_min(&[list_array]).unwrap();
+        assert_eq!(
+            as_int64_array(&array).unwrap(),
+            &Int64Array::from(vec![Some(-1), None, None, None])
+        );
+    }
+
+    #[test]
+    fn test_array_zip() {
+        // array_zip([1, 2], ['a']) = [{c0: 1, c1: a}, {c0: 2, c1: NULL}]
+        let numbers: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(3)]),
+            ]));
+        let strings = Arc::new(StringArray::from(vec![Some("a")])) as ArrayRef;
+        let strings = Arc::new(LargeListArray::new(
+            Arc::new(Field::new("item", DataType::Utf8, true)),
+            OffsetBuffer::new(vec![0, 1, 1].into()),
+            strings,
+            Some(NullBuffer::from(vec![true, false])),
+        )) as ArrayRef;
+
+        let array = array_zip(&[numbers, strings]).unwrap();
+        let result = as_list_array(&array).unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.is_null(1));
+
+        let structs = result.value(0);
+        let structs = structs.as_struct();
+        assert_eq!(
+            structs.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 2])
+        );
+        assert_eq!(
+            structs.column(1).as_string::<i32>(),
+            &StringArray::from(vec![Some("a"), None])
+        );
+    }
+
+    #[test]
+    fn test_array_resize() {
+        let list_array: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2), Some(3)]),
+                Some(vec![Some(4)]),
+                None,
+            ]));
+        let sizes = Arc::new(Int64Array::from(vec![2, 3, 1])) as ArrayRef;
+
+        // array_resize([1, 2, 3], 2) = [1, 2], array_resize([4], 3) = [4, NULL, NULL]
+        let array = array_resize(&[list_array.clone(), sizes.clone()]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(4), None, None]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        // array_resize([4], 3, 0) = [4, 0, 0]
+        let values = Arc::new(Int64Array::from(vec![0, 0, 0])) as ArrayRef;
+        let array = array_resize(&[list_array.clone(), sizes, values]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(4), Some(0), Some(0)]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        let sizes = Arc::new(Int64Array::from(vec![-1, 0, 0])) as ArrayRef;
+        let err = array_resize(&[list_array, sizes]).unwrap_err();
+        assert!(err.to_string().contains("non-negative size, got -1"));
+    }
+
+    #[test]
+    fn test_range_and_generate_series() {
+        let ints =
+            |values: Vec<Option<i64>>| Arc::new(Int64Array::from(values)) as ArrayRef;
+        let start = ints(vec![Some(0), Some(5), Some(1), None]);
+        let stop = ints(vec![Some(9), Some(1), Some(1), Some(3)]);
+        let step = ints(vec![Some(3), Some(-2), Some(1), Some(1)]);
+
+        let array = range(&[start.clone(), stop.clone(), step.clone()]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0), Some(3), Some(6)]),
+            Some(vec![Some(5), Some(3)]),
+            Some(vec![]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        let array = generate_series(&[start, stop, step]).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0), Some(3), Some(6), Some(9)]),
+            Some(vec![Some(5), Some(3), Some(1)]),
+            Some(vec![Some(1)]),
+            None,
+        ]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        let array = range(&[ints(vec![Some(3)])]).unwrap();
+        let expected =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![
+                Some(0),
+                Some(1),
+                Some(2),
+            ])]);
+        assert_eq!(as_list_array(&array).unwrap(), &expected);
+
+        let zero = ints(vec![Some(0)]);
+        let err = range(&[zero.clone(), zero.clone(), zero]).unwrap_err();
+        assert!(err.to_string().contains("range expects a non-zero step"));
+    }
+
+    #[test]
+    fn test_array_to_string_large_list() {
+        // array_to_string([[1, 2], [3]], '-') = 1-2-3 over nested large lists
+        let inner: ArrayRef =
+            Arc::new(LargeListArray::from_iter_primitive::<Int64Type, _, _>(
+                vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(3)])],
+            ));
+        let outer = Arc::new(LargeListArray::new(
+            Arc::new(Field::new("item", inner.data_type().clone(), true)),
+            OffsetBuffer::new(vec![0i64, 2].into()),
+            inner,
+            None,
+        )) as ArrayRef;
+        let delimiters = Arc::new(StringArray::from(vec![Some("-")])) as ArrayRef;
+
+        let array = array_to_string(&[outer, delimiters]).unwrap();
+        assert_eq!(as_string_array(&array).unwrap().value(0), "1-2-3");
+    }
+
     #[test]
     fn test_nested_array_remove() {
         // array_remove(
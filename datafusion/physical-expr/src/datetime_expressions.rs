@@ -19,21 +19,25 @@
 
 use crate::datetime_expressions;
 use crate::expressions::cast_column;
-use arrow::array::Float64Builder;
+use arrow::array::{
+    Float64Builder, PrimitiveBuilder, StringBuilder, TimestampNanosecondBuilder,
+};
 use arrow::compute::cast;
 use arrow::{
     array::TimestampNanosecondArray,
     compute::kernels::temporal,
     datatypes::TimeUnit,
-    temporal_conversions::{as_datetime_with_timezone, timestamp_ns_to_datetime},
+    temporal_conversions::{timestamp_ns_to_datetime, EPOCH_DAYS_FROM_CE},
+    util::display::{ArrayFormatter, DurationFormat, FormatOptions},
 };
 use arrow::{
     array::{Array, ArrayRef, Float64Array, OffsetSizeTrait, PrimitiveArray},
     compute::kernels::cast_utils::string_to_timestamp_nanos,
     datatypes::{
-        ArrowNumericType, ArrowPrimitiveType, ArrowTemporalType, DataType,
-        IntervalDayTimeType, IntervalMonthDayNanoType, TimestampMicrosecondType,
-        TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
+        ArrowNumericType, ArrowPrimitiveType, ArrowTemporalType, DataType, Date32Type,
+        Int64Type, IntervalDayTimeType, IntervalMonthDayNanoType,
+        TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+        TimestampSecondType,
     },
 };
 use arrow_array::{
@@ -41,11 +45,12 @@ use arrow_array::{
     TimestampSecondArray,
 };
 use chrono::prelude::*;
-use chrono::{Duration, Months, NaiveDate};
+use chrono::{Duration, LocalResult, Months, NaiveDate};
 use datafusion_common::cast::{
-    as_date32_array, as_date64_array, as_generic_string_array,
-    as_timestamp_microsecond_array, as_timestamp_millisecond_array,
-    as_timestamp_nanosecond_array, as_timestamp_second_array,
+    as_date32_array, as_date64_array, as_float64_array, as_generic_string_array,
+    as_int64_array, as_string_array, as_timestamp_microsecond_array,
+    as_timestamp_millisecond_array, as_timestamp_nanosecond_array,
+    as_timestamp_second_array,
 };
 use datafusion_common::{
     exec_err, internal_err, not_impl_err, DataFusionError, Result, ScalarType,
@@ -124,56 +129,304 @@ where
     }
 }
 
+// given a function that parses a `&str` with an optional format to an arrow native type,
+// returns a `ColumnarValue` where each string in `args[0]` is parsed with the formats in
+// `args[1..]`, in order, until one of them succeeds. When none does, the error of the
+// last format is returned, or NULL if `try_parse` is set.
+fn handle_multiple<O, F>(
+    args: &[ColumnarValue],
+    op: F,
+    name: &str,
+    try_parse: bool,
+) -> Result<ColumnarValue>
+where
+    O: ArrowPrimitiveType,
+    F: Fn(&str, Option<&str>) -> Result<O::Native>,
+{
+    let len = args.iter().fold(None, |len, arg| match arg {
+        ColumnarValue::Array(array) => Some(array.len()),
+        ColumnarValue::Scalar(_) => len,
+    });
+    let arrays = args
+        .iter()
+        .map(|arg| arg.clone().into_array(len.unwrap_or(1)))
+        .collect::<Result<Vec<_>>>()?;
+    let strings = arrays
+        .iter()
+        .map(|array| match array.data_type() {
+            DataType::Utf8 => as_string_array(array),
+            other => internal_err!("Unsupported data type {other:?} for function {name}"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (values, formats) = (strings[0], &strings[1..]);
+
+    let mut builder = PrimitiveBuilder::<O>::with_capacity(values.len());
+    for row in 0..values.len() {
+        if values.is_null(row) {
+            builder.append_null();
+            continue;
+        }
+
+        let value = values.value(row);
+        let result = if formats.is_empty() {
+            Some(op(value, None))
+        } else {
+            let mut result = None;
+            for format in formats.iter().filter(|format| format.is_valid(row)) {
+                result = Some(op(value, Some(format.value(row))));
+                if matches!(result, Some(Ok(_))) {
+                    break;
+                }
+            }
+            result
+        };
+        match result {
+            Some(Ok(value)) => builder.append_value(value),
+            Some(Err(e)) if !try_parse => return Err(e),
+            _ => builder.append_null(),
+        }
+    }
+
+    let array: ArrayRef = Arc::new(builder.finish());
+    Ok(match len {
+        Some(_) => ColumnarValue::Array(array),
+        None => ColumnarValue::Scalar(ScalarValue::try_from_array(&array, 0)?),
+    })
+}
+
 /// Calls string_to_timestamp_nanos and converts the error type
 fn string_to_timestamp_nanos_shim(s: &str) -> Result<i64> {
     string_to_timestamp_nanos(s).map_err(|e| e.into())
 }
 
-/// to_timestamp SQL function
-pub fn to_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    handle::<TimestampNanosecondType, _, TimestampNanosecondType>(
+/// Parses `s` with the chrono `format`, returning the UTC date and time.
+///
+/// Strings without an offset are interpreted as UTC, and formats without a
+/// time of day parse to midnight.
+fn string_to_datetime_formatted(s: &str, format: &str) -> Result<NaiveDateTime> {
+    if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+        return Ok(datetime.naive_utc());
+    }
+    NaiveDateTime::parse_from_str(s, format).or_else(|e| {
+        NaiveDate::parse_from_str(s, format)
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "Error parsing timestamp from '{s}' using format '{format}': {e}"
+                ))
+            })
+    })
+}
+
+/// Parses `s` with the chrono `format`, returning nanoseconds since the epoch
+fn string_to_timestamp_nanos_formatted(s: &str, format: &str) -> Result<i64> {
+    string_to_datetime_formatted(s, format)?
+        .timestamp_nanos_opt()
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "Timestamp '{s}' parsed with format '{format}' is out of range"
+            ))
+        })
+}
+
+/// Parses the strings in `args[0]` to timestamps of type `T`, which has `factor`
+/// nanoseconds per unit, using the optional formats in `args[1..]`
+fn to_timestamp_impl<T>(
+    args: &[ColumnarValue],
+    factor: i64,
+    name: &str,
+    try_parse: bool,
+) -> Result<ColumnarValue>
+where
+    T: ArrowPrimitiveType<Native = i64> + ScalarType<i64>,
+{
+    if args.len() == 1 && !try_parse {
+        return handle::<T, _, T>(
+            args,
+            |s| string_to_timestamp_nanos_shim(s).map(|n| n / factor),
+            name,
+        );
+    }
+
+    handle_multiple::<T, _>(
         args,
-        string_to_timestamp_nanos_shim,
-        "to_timestamp",
+        |s, format| {
+            let nanos = match format {
+                Some(format) => string_to_timestamp_nanos_formatted(s, format)?,
+                None => string_to_timestamp_nanos_shim(s)?,
+            };
+            Ok(nanos / factor)
+        },
+        name,
+        try_parse,
     )
 }
 
+/// to_timestamp SQL function
+pub fn to_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    to_timestamp_impl::<TimestampNanosecondType>(args, 1, "to_timestamp", false)
+}
+
+/// try_to_timestamp SQL function
+pub fn try_to_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    to_timestamp_impl::<TimestampNanosecondType>(args, 1, "try_to_timestamp", true)
+}
+
 /// to_timestamp_millis SQL function
 pub fn to_timestamp_millis(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    handle::<TimestampMillisecondType, _, TimestampMillisecondType>(
+    to_timestamp_impl::<TimestampMillisecondType>(
         args,
-        |s| string_to_timestamp_nanos_shim(s).map(|n| n / 1_000_000),
+        1_000_000,
         "to_timestamp_millis",
+        false,
     )
 }
 
 /// to_timestamp_micros SQL function
 pub fn to_timestamp_micros(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    handle::<TimestampMicrosecondType, _, TimestampMicrosecondType>(
+    to_timestamp_impl::<TimestampMicrosecondType>(
         args,
-        |s| string_to_timestamp_nanos_shim(s).map(|n| n / 1_000),
+        1_000,
         "to_timestamp_micros",
+        false,
     )
 }
 
 /// to_timestamp_nanos SQL function
 pub fn to_timestamp_nanos(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    handle::<TimestampNanosecondType, _, TimestampNanosecondType>(
-        args,
-        string_to_timestamp_nanos_shim,
-        "to_timestamp_nanos",
-    )
+    to_timestamp_impl::<TimestampNanosecondType>(args, 1, "to_timestamp_nanos", false)
 }
 
 /// to_timestamp_seconds SQL function
 pub fn to_timestamp_seconds(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    handle::<TimestampSecondType, _, TimestampSecondType>(
+    to_timestamp_impl::<TimestampSecondType>(
         args,
-        |s| string_to_timestamp_nanos_shim(s).map(|n| n / 1_000_000_000),
+        1_000_000_000,
         "to_timestamp_seconds",
+        false,
+    )
+}
+
+/// Parses the strings in `args[0]` to dates, using the optional formats in `args[1..]`
+fn to_date_impl(
+    args: &[ColumnarValue],
+    name: &str,
+    try_parse: bool,
+) -> Result<ColumnarValue> {
+    handle_multiple::<Date32Type, _>(
+        args,
+        |s, format| {
+            let date = match format {
+                Some(format) => string_to_datetime_formatted(s, format)?.date(),
+                None => match NaiveDate::from_str(s) {
+                    Ok(date) => date,
+                    Err(_) => {
+                        let nanos = string_to_timestamp_nanos_shim(s)?;
+                        timestamp_ns_to_datetime(nanos)
+                            .ok_or_else(|| {
+                                DataFusionError::Execution(format!(
+                                    "Timestamp {nanos} out of range"
+                                ))
+                            })?
+                            .date()
+                    }
+                },
+            };
+            Ok(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        },
+        name,
+        try_parse,
     )
 }
 
+/// to_date SQL function
+pub fn to_date(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    to_date_impl(args, "to_date", false)
+}
+
+/// try_to_date SQL function
+pub fn try_to_date(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    to_date_impl(args, "try_to_date", true)
+}
+
+/// Returns the [`FormatOptions`] that format values of `data_type` with `format`
+fn to_char_format_options<'a>(
+    data_type: &DataType,
+    format: &'a str,
+) -> Result<FormatOptions<'a>> {
+    let options = FormatOptions::new().with_display_error(false);
+    Ok(match data_type {
+        DataType::Date32 => options.with_date_format(Some(format)),
+        DataType::Date64 => options.with_datetime_format(Some(format)),
+        DataType::Time32(_) | DataType::Time64(_) => {
+            options.with_time_format(Some(format))
+        }
+        DataType::Timestamp(_, _) => options
+            .with_timestamp_format(Some(format))
+            .with_timestamp_tz_format(Some(format)),
+        // durations have no chrono format, only a choice of representation
+        DataType::Duration(_) => {
+            let duration_format = if format.eq_ignore_ascii_case("iso8601") {
+                DurationFormat::ISO8601
+            } else if format.eq_ignore_ascii_case("pretty") {
+                DurationFormat::Pretty
+            } else {
+                return exec_err!(
+                    "to_char expects 'ISO8601' or 'pretty' as the format of a duration, got '{format}'"
+                );
+            };
+            options.with_duration_format(duration_format)
+        }
+        other => return exec_err!("to_char does not support values of type {other}"),
+    })
+}
+
+/// TO_CHAR SQL function: formats dates, times and timestamps with a chrono
+/// format string, and durations in the ISO 8601 or pretty representation
+pub fn to_char(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let len = args.iter().fold(None, |len, arg| match arg {
+        ColumnarValue::Array(array) => Some(array.len()),
+        ColumnarValue::Scalar(_) => len,
+    });
+    let values = args[0].clone().into_array(len.unwrap_or(1))?;
+    let formats = args[1].clone().into_array(len.unwrap_or(1))?;
+    let formats = as_string_array(&formats)?;
+
+    // the formatter is reused for as long as the format does not change
+    let mut current: Option<(&str, ArrayFormatter)> = None;
+    let mut builder = StringBuilder::with_capacity(values.len(), values.len() * 16);
+    for row in 0..values.len() {
+        if values.is_null(row) || formats.is_null(row) {
+            builder.append_null();
+            continue;
+        }
+
+        let format = formats.value(row);
+        let formatter = match current.take() {
+            Some((current_format, formatter)) if current_format == format => formatter,
+            _ => ArrayFormatter::try_new(
+                values.as_ref(),
+                &to_char_format_options(values.data_type(), format)?,
+            )?,
+        };
+        let value = formatter.value(row).try_to_string().map_err(|e| {
+            DataFusionError::Execution(format!(
+                "Error formatting {} value using format '{format}': {e}",
+                values.data_type()
+            ))
+        })?;
+        builder.append_value(value);
+        current = Some((format, formatter));
+    }
+
+    let array: ArrayRef = Arc::new(builder.finish());
+    Ok(match len {
+        Some(_) => ColumnarValue::Array(array),
+        None => ColumnarValue::Scalar(ScalarValue::try_from_array(&array, 0)?),
+    })
+}
+
 /// Create an implementation of `now()` that always returns the
 /// specified timestamp.
 ///
@@ -286,20 +539,40 @@ where
     Ok(value)
 }
 
-fn _date_trunc_coarse_with_tz(
-    granularity: &str,
-    value: Option<DateTime<Tz>>,
-) -> Result<Option<i64>> {
-    let value = _date_trunc_coarse::<DateTime<Tz>>(granularity, value)?;
-    Ok(value.and_then(|value| value.timestamp_nanos_opt()))
+/// Returns the UTC offset of `tz` at the instant `nanos` since the epoch
+fn offset_at(tz: &Tz, nanos: i64) -> Result<FixedOffset> {
+    let value = timestamp_ns_to_datetime(nanos).ok_or_else(|| {
+        DataFusionError::Execution(format!("Timestamp {nanos} out of range"))
+    })?;
+    Ok(tz.offset_from_utc_datetime(&value).fix())
 }
 
-fn _date_trunc_coarse_without_tz(
-    granularity: &str,
-    value: Option<NaiveDateTime>,
-) -> Result<Option<i64>> {
-    let value = _date_trunc_coarse::<NaiveDateTime>(granularity, value)?;
-    Ok(value.and_then(|value| value.timestamp_nanos_opt()))
+/// Converts the wall clock time `local` in `tz` to UTC.
+///
+/// Local times that occur twice because the clocks were turned back resolve
+/// to `preferred` when it is one of the candidate offsets, and to the earliest
+/// instant otherwise. Local times skipped because the clocks were turned
+/// forward are interpreted with the offset in effect before the transition.
+fn local_to_utc(
+    tz: &Tz,
+    local: NaiveDateTime,
+    preferred: Option<FixedOffset>,
+) -> NaiveDateTime {
+    let offset = match tz.offset_from_local_datetime(&local) {
+        LocalResult::Single(offset) => offset.fix(),
+        LocalResult::Ambiguous(first, second) => {
+            let (first, second) = (first.fix(), second.fix());
+            match preferred {
+                Some(preferred) if preferred == first || preferred == second => preferred,
+                _ if first.local_minus_utc() >= second.local_minus_utc() => first,
+                _ => second,
+            }
+        }
+        LocalResult::None => tz
+            .offset_from_utc_datetime(&(local - Duration::days(1)))
+            .fix(),
+    };
+    local - offset
 }
 
 /// Tuncates the single `value`, expressed in nanoseconds since the
@@ -307,27 +580,26 @@ fn _date_trunc_coarse_without_tz(
 /// account that some granularities are not uniform durations of time
 /// (e.g. months are not always the same lengths, leap seconds, etc)
 fn date_trunc_coarse(granularity: &str, value: i64, tz: Option<Tz>) -> Result<i64> {
-    let value = match tz {
+    let out_of_range =
+        || DataFusionError::Execution(format!("Timestamp {value} out of range"));
+    let utc = timestamp_ns_to_datetime(value).ok_or_else(out_of_range)?;
+
+    let truncated = match tz {
         Some(tz) => {
-            // Use chrono DateTime<Tz> to clear the various fields because need to clear per timezone,
-            // and NaiveDateTime (ISO 8601) has no concept of timezones
-            let value = as_datetime_with_timezone::<TimestampNanosecondType>(value, tz)
-                .ok_or(DataFusionError::Execution(format!(
-                "Timestamp {value} out of range"
-            )))?;
-            _date_trunc_coarse_with_tz(granularity, Some(value))
-        }
-        None => {
-            // Use chrono NaiveDateTime to clear the various fields, if we don't have a timezone.
-            let value = timestamp_ns_to_datetime(value).ok_or_else(|| {
-                DataFusionError::Execution(format!("Timestamp {value} out of range"))
-            })?;
-            _date_trunc_coarse_without_tz(granularity, Some(value))
+            // Clear the fields of the local wall clock time, so that e.g. days
+            // start at local midnight, then map the result back to UTC, which
+            // may use a different offset if a DST transition lies in between
+            let offset = tz.offset_from_utc_datetime(&utc).fix();
+            _date_trunc_coarse::<NaiveDateTime>(granularity, Some(utc + offset))?
+                .map(|local| local_to_utc(&tz, local, Some(offset)))
         }
-    }?;
+        None => _date_trunc_coarse::<NaiveDateTime>(granularity, Some(utc))?,
+    };
 
     // `with_x(0)` are infallible because `0` are always a valid
-    Ok(value.unwrap())
+    truncated
+        .and_then(|value| value.timestamp_nanos_opt())
+        .ok_or_else(out_of_range)
 }
 
 // truncates a single value with the given timeunit to the specified granularity
@@ -583,8 +855,8 @@ fn date_bin_months_interval(stride_months: i64, source: i64, origin: i64) -> i64
 }
 
 fn to_utc_date_time(nanos: i64) -> DateTime<Utc> {
-    let secs = nanos / 1_000_000_000;
-    let nsec = (nanos % 1_000_000_000) as u32;
+    let secs = nanos.div_euclid(1_000_000_000);
+    let nsec = nanos.rem_euclid(1_000_000_000) as u32;
     let date = NaiveDateTime::from_timestamp_opt(secs, nsec).unwrap();
     DateTime::<Utc>::from_naive_utc_and_offset(date, Utc)
 }
@@ -592,14 +864,10 @@ fn to_utc_date_time(nanos: i64) -> DateTime<Utc> {
 /// DATE_BIN sql function
 pub fn date_bin(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     if args.len() == 2 {
-        // Default to unix EPOCH
-        let origin = ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
-            Some(0),
-            Some("+00:00".into()),
-        ));
-        date_bin_impl(&args[0], &args[1], &origin)
+        // Default to unix EPOCH in the local time of the source
+        date_bin_impl(&args[0], &args[1], None)
     } else if args.len() == 3 {
-        date_bin_impl(&args[0], &args[1], &args[2])
+        date_bin_impl(&args[0], &args[1], Some(&args[2]))
     } else {
         exec_err!("DATE_BIN expected two or three arguments")
     }
@@ -636,7 +904,7 @@ impl Interval {
 fn date_bin_impl(
     stride: &ColumnarValue,
     array: &ColumnarValue,
-    origin: &ColumnarValue,
+    origin: Option<&ColumnarValue>,
 ) -> Result<ColumnarValue> {
     let stride = match stride {
         ColumnarValue::Scalar(ScalarValue::IntervalDayTime(Some(v))) => {
@@ -685,14 +953,17 @@ fn date_bin_impl(
     };
 
     let origin = match origin {
-        ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(v), _)) => *v,
-        ColumnarValue::Scalar(v) => {
+        None => None,
+        Some(ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(v), _))) => {
+            Some(*v)
+        }
+        Some(ColumnarValue::Scalar(v)) => {
             return exec_err!(
                 "DATE_BIN expects origin argument to be a TIMESTAMP with nanosececond precision but got {}",
                 v.data_type()
             )
         }
-        ColumnarValue::Array(_) => return not_impl_err!(
+        Some(ColumnarValue::Array(_)) => return not_impl_err!(
             "DATE_BIN only supports literal values for the origin argument, not arrays"
         ),
     };
@@ -704,78 +975,100 @@ fn date_bin_impl(
         return exec_err!("DATE_BIN stride must be non-zero");
     }
 
-    let f_nanos = |x: Option<i64>| x.map(|x| stride_fn(stride, x, origin));
-    let f_micros = |x: Option<i64>| {
-        let scale = 1_000;
-        x.map(|x| stride_fn(stride, x * scale, origin) / scale)
-    };
-    let f_millis = |x: Option<i64>| {
-        let scale = 1_000_000;
-        x.map(|x| stride_fn(stride, x * scale, origin) / scale)
+    // Sources with a timezone are binned in their local time, so that e.g.
+    // daily bins start at local midnight on both sides of a DST transition
+    let bin = |source: i64, tz: Option<Tz>| -> Result<i64> {
+        let Some(tz) = tz else {
+            return Ok(stride_fn(stride, source, origin.unwrap_or(0)));
+        };
+        let to_nanos =
+            |offset: FixedOffset| offset.local_minus_utc() as i64 * 1_000_000_000;
+
+        let source_offset = offset_at(&tz, source)?;
+        let local_origin = match origin {
+            Some(origin) => origin + to_nanos(offset_at(&tz, origin)?),
+            None => 0,
+        };
+        let local_bin = stride_fn(stride, source + to_nanos(source_offset), local_origin);
+        let local_bin = timestamp_ns_to_datetime(local_bin).ok_or_else(|| {
+            DataFusionError::Execution(format!("Timestamp {local_bin} out of range"))
+        })?;
+        local_to_utc(&tz, local_bin, Some(source_offset))
+            .timestamp_nanos_opt()
+            .ok_or_else(|| {
+                DataFusionError::Execution(format!("Timestamp {source} out of range"))
+            })
     };
-    let f_secs = |x: Option<i64>| {
-        let scale = 1_000_000_000;
-        x.map(|x| stride_fn(stride, x * scale, origin) / scale)
+    let f = |x: Option<i64>, tz: Option<Tz>, scale: i64| {
+        x.map(|x| bin(x * scale, tz).map(|v| v / scale)).transpose()
     };
+    let f_nanos = |x: Option<i64>, tz: Option<Tz>| f(x, tz, 1);
+    let f_micros = |x: Option<i64>, tz: Option<Tz>| f(x, tz, 1_000);
+    let f_millis = |x: Option<i64>, tz: Option<Tz>| f(x, tz, 1_000_000);
+    let f_secs = |x: Option<i64>, tz: Option<Tz>| f(x, tz, 1_000_000_000);
 
     Ok(match array {
         ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, tz_opt)) => {
             ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
-                f_nanos(*v),
+                f_nanos(*v, parse_tz(tz_opt)?)?,
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Scalar(ScalarValue::TimestampMicrosecond(v, tz_opt)) => {
             ColumnarValue::Scalar(ScalarValue::TimestampMicrosecond(
-                f_micros(*v),
+                f_micros(*v, parse_tz(tz_opt)?)?,
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Scalar(ScalarValue::TimestampMillisecond(v, tz_opt)) => {
             ColumnarValue::Scalar(ScalarValue::TimestampMillisecond(
-                f_millis(*v),
+                f_millis(*v, parse_tz(tz_opt)?)?,
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Scalar(ScalarValue::TimestampSecond(v, tz_opt)) => {
             ColumnarValue::Scalar(ScalarValue::TimestampSecond(
-                f_secs(*v),
+                f_secs(*v, parse_tz(tz_opt)?)?,
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Array(array) => match array.data_type() {
             DataType::Timestamp(TimeUnit::Nanosecond, tz_opt) => {
+                let tz = parse_tz(tz_opt)?;
                 let array = as_timestamp_nanosecond_array(array)?
                     .iter()
-                    .map(f_nanos)
-                    .collect::<TimestampNanosecondArray>()
+                    .map(|x| f_nanos(x, tz))
+                    .collect::<Result<TimestampNanosecondArray>>()?
                     .with_timezone_opt(tz_opt.clone());
 
                 ColumnarValue::Array(Arc::new(array))
             }
             DataType::Timestamp(TimeUnit::Microsecond, tz_opt) => {
+                let tz = parse_tz(tz_opt)?;
                 let array = as_timestamp_microsecond_array(array)?
                     .iter()
-                    .map(f_micros)
-                    .collect::<TimestampMicrosecondArray>()
+                    .map(|x| f_micros(x, tz))
+                    .collect::<Result<TimestampMicrosecondArray>>()?
                     .with_timezone_opt(tz_opt.clone());
 
                 ColumnarValue::Array(Arc::new(array))
             }
             DataType::Timestamp(TimeUnit::Millisecond, tz_opt) => {
+                let tz = parse_tz(tz_opt)?;
                 let array = as_timestamp_millisecond_array(array)?
                     .iter()
-                    .map(f_millis)
-                    .collect::<TimestampMillisecondArray>()
+                    .map(|x| f_millis(x, tz))
+                    .collect::<Result<TimestampMillisecondArray>>()?
                     .with_timezone_opt(tz_opt.clone());
 
                 ColumnarValue::Array(Arc::new(array))
             }
             DataType::Timestamp(TimeUnit::Second, tz_opt) => {
+                let tz = parse_tz(tz_opt)?;
                 let array = as_timestamp_second_array(array)?
                     .iter()
-                    .map(f_secs)
-                    .collect::<TimestampSecondArray>()
+                    .map(|x| f_secs(x, tz))
+                    .collect::<Result<TimestampSecondArray>>()?
                     .with_timezone_opt(tz_opt.clone());
 
                 ColumnarValue::Array(Arc::new(array))
@@ -958,18 +1251,18 @@ where
 
 /// to_timestammp() SQL function implementation
 pub fn to_timestamp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    if args.len() != 1 {
+    if args.is_empty() {
         return internal_err!(
-            "to_timestamp function requires 1 arguments, got {}",
+            "to_timestamp function requires 1 or more arguments, got {}",
             args.len()
         );
     }
 
     match args[0].data_type() {
-        DataType::Int64 => {
+        DataType::Int64 if args.len() == 1 => {
             cast_column(&args[0], &DataType::Timestamp(TimeUnit::Second, None), None)
         }
-        DataType::Timestamp(_, None) => cast_column(
+        DataType::Timestamp(_, None) if args.len() == 1 => cast_column(
             &args[0],
             &DataType::Timestamp(TimeUnit::Nanosecond, None),
             None,
@@ -986,15 +1279,15 @@ pub fn to_timestamp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
 
 /// to_timestamp_millis() SQL function implementation
 pub fn to_timestamp_millis_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    if args.len() != 1 {
+    if args.is_empty() {
         return internal_err!(
-            "to_timestamp_millis function requires 1 argument, got {}",
+            "to_timestamp_millis function requires 1 or more arguments, got {}",
             args.len()
         );
     }
 
     match args[0].data_type() {
-        DataType::Int64 | DataType::Timestamp(_, None) => cast_column(
+        DataType::Int64 | DataType::Timestamp(_, None) if args.len() == 1 => cast_column(
             &args[0],
             &DataType::Timestamp(TimeUnit::Millisecond, None),
             None,
@@ -1011,15 +1304,15 @@ pub fn to_timestamp_millis_invoke(args: &[ColumnarValue]) -> Result<ColumnarValu
 
 /// to_timestamp_micros() SQL function implementation
 pub fn to_timestamp_micros_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    if args.len() != 1 {
+    if args.is_empty() {
         return internal_err!(
-            "to_timestamp_micros function requires 1 argument, got {}",
+            "to_timestamp_micros function requires 1 or more arguments, got {}",
             args.len()
         );
     }
 
     match args[0].data_type() {
-        DataType::Int64 | DataType::Timestamp(_, None) => cast_column(
+        DataType::Int64 | DataType::Timestamp(_, None) if args.len() == 1 => cast_column(
             &args[0],
             &DataType::Timestamp(TimeUnit::Microsecond, None),
             None,
@@ -1036,15 +1329,15 @@ pub fn to_timestamp_micros_invoke(args: &[ColumnarValue]) -> Result<ColumnarValu
 
 /// to_timestamp_nanos() SQL function implementation
 pub fn to_timestamp_nanos_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    if args.len() != 1 {
+    if args.is_empty() {
         return internal_err!(
-            "to_timestamp_nanos function requires 1 argument, got {}",
+            "to_timestamp_nanos function requires 1 or more arguments, got {}",
             args.len()
         );
     }
 
     match args[0].data_type() {
-        DataType::Int64 | DataType::Timestamp(_, None) => cast_column(
+        DataType::Int64 | DataType::Timestamp(_, None) if args.len() == 1 => cast_column(
             &args[0],
             &DataType::Timestamp(TimeUnit::Nanosecond, None),
             None,
@@ -1061,15 +1354,15 @@ pub fn to_timestamp_nanos_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue
 
 /// to_timestamp_seconds() SQL function implementation
 pub fn to_timestamp_seconds_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
-    if args.len() != 1 {
+    if args.is_empty() {
         return internal_err!(
-            "to_timestamp_seconds function requires 1 argument, got {}",
+            "to_timestamp_seconds function requires 1 or more arguments, got {}",
             args.len()
         );
     }
 
     match args[0].data_type() {
-        DataType::Int64 | DataType::Timestamp(_, None) => {
+        DataType::Int64 | DataType::Timestamp(_, None) if args.len() == 1 => {
             cast_column(&args[0], &DataType::Timestamp(TimeUnit::Second, None), None)
         }
         DataType::Utf8 => datetime_expressions::to_timestamp_seconds(args),
@@ -1082,6 +1375,41 @@ pub fn to_timestamp_seconds_invoke(args: &[ColumnarValue]) -> Result<ColumnarVal
     }
 }
 
+/// try_to_timestamp() SQL function implementation
+pub fn try_to_timestamp_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    match args.first().map(|arg| arg.data_type()) {
+        Some(DataType::Utf8) => datetime_expressions::try_to_timestamp(args),
+        _ => to_timestamp_invoke(args),
+    }
+}
+
+/// to_date() SQL function implementation
+pub fn to_date_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.is_empty() {
+        return internal_err!("to_date function requires 1 or more arguments, got 0");
+    }
+
+    match args[0].data_type() {
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)
+            if args.len() == 1 =>
+        {
+            cast_column(&args[0], &DataType::Date32, None)
+        }
+        DataType::Utf8 => datetime_expressions::to_date(args),
+        other => {
+            internal_err!("Unsupported data type {:?} for function to_date", other)
+        }
+    }
+}
+
+/// try_to_date() SQL function implementation
+pub fn try_to_date_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    match args.first().map(|arg| arg.data_type()) {
+        Some(DataType::Utf8) => datetime_expressions::try_to_date(args),
+        _ => to_date_invoke(args),
+    }
+}
+
 /// from_unixtime() SQL function implementation
 pub fn from_unixtime_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     if args.len() != 1 {
@@ -1104,12 +1432,141 @@ pub fn from_unixtime_invoke(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     }
 }
 
+/// TO_LOCAL_TIME sql function: converts a timestamp with a timezone to the
+/// wall clock time in that timezone, as a timestamp without a timezone
+pub fn to_local_time(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let array = &args[0];
+    let DataType::Timestamp(unit, tz_opt) = array.data_type() else {
+        return exec_err!(
+            "to_local_time expects a TIMESTAMP argument, got {}",
+            array.data_type()
+        );
+    };
+    let Some(tz) = parse_tz(tz_opt)? else {
+        return Ok(array.clone());
+    };
+
+    let units_per_second: i64 = match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+    let values = cast(array, &DataType::Int64)?;
+    let local =
+        as_int64_array(&values)?.try_unary::<_, Int64Type, DataFusionError>(|v| {
+            let out_of_range =
+                || DataFusionError::Execution(format!("Timestamp {v} out of range"));
+            let utc =
+                NaiveDateTime::from_timestamp_opt(v.div_euclid(units_per_second), 0)
+                    .ok_or_else(out_of_range)?;
+            let offset = tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() as i64;
+            v.checked_add(offset * units_per_second)
+                .ok_or_else(out_of_range)
+        })?;
+
+    Ok(cast(&local, &DataType::Timestamp(unit.clone(), None))?)
+}
+
+/// Builds the wall clock time for the fields passed to `make_timestamp`
+fn make_local_datetime(
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: f64,
+) -> Result<NaiveDateTime> {
+    let nanos = (second * 1_000_000_000.0).round();
+    let date = || {
+        NaiveDate::from_ymd_opt(
+            i32::try_from(year).ok()?,
+            u32::try_from(month).ok()?,
+            u32::try_from(day).ok()?,
+        )
+    };
+    let time = || {
+        if !(0.0..60_000_000_000.0).contains(&nanos) {
+            return None;
+        }
+        NaiveTime::from_hms_nano_opt(
+            u32::try_from(hour).ok()?,
+            u32::try_from(minute).ok()?,
+            (nanos as i64 / 1_000_000_000) as u32,
+            (nanos as i64 % 1_000_000_000) as u32,
+        )
+    };
+
+    match (date(), time()) {
+        (Some(date), Some(time)) => Ok(date.and_time(time)),
+        _ => exec_err!(
+            "make_timestamp got an invalid date or time: {year}-{month}-{day} {hour}:{minute}:{second}"
+        ),
+    }
+}
+
+/// MAKE_TIMESTAMP sql function: builds a timestamp from its year, month, day,
+/// hour, minute and fractional second fields. When a timezone is passed, the
+/// fields are interpreted as the wall clock time in that timezone.
+pub fn make_timestamp(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let fields = args[..5]
+        .iter()
+        .map(|array| as_int64_array(array))
+        .collect::<Result<Vec<_>>>()?;
+    let seconds = as_float64_array(&args[5])?;
+    let timezones = args
+        .get(6)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+
+    let mut builder = TimestampNanosecondBuilder::with_capacity(seconds.len());
+    for row in 0..seconds.len() {
+        if args.iter().any(|array| array.is_null(row)) {
+            builder.append_null();
+            continue;
+        }
+
+        let field = |i: usize| fields[i].value(row);
+        let local = make_local_datetime(
+            field(0),
+            field(1),
+            field(2),
+            field(3),
+            field(4),
+            seconds.value(row),
+        )?;
+        let value = match timezones {
+            Some(timezones) => {
+                let tz = timezones.value(row);
+                let tz = Tz::from_str(tz).map_err(|e| {
+                    DataFusionError::Execution(format!(
+                        "make_timestamp got an invalid timezone '{tz}': {e}"
+                    ))
+                })?;
+                local_to_utc(&tz, local, None)
+            }
+            None => local,
+        };
+        match value.timestamp_nanos_opt() {
+            Some(value) => builder.append_value(value),
+            None => return exec_err!("make_timestamp result {value} out of range"),
+        }
+    }
+
+    let array = builder.finish();
+    Ok(match timezones {
+        Some(_) => Arc::new(array.with_timezone("+00:00")),
+        None => Arc::new(array),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use arrow::array::{
-        as_primitive_array, ArrayRef, Int64Array, IntervalDayTimeArray, StringBuilder,
+        as_primitive_array, ArrayRef, Date32Array, Int64Array, IntervalDayTimeArray,
+        StringArray, StringBuilder,
     };
 
     use super::*;
@@ -1355,6 +1812,77 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_date_trunc_dst() {
+        let cases = vec![
+            // days start at local midnight on both sides of a DST transition
+            (
+                "day",
+                "2023-03-12T12:00:00Z",
+                "America/New_York",
+                "2023-03-12T05:00:00Z",
+            ),
+            (
+                "day",
+                "2023-11-05T12:00:00Z",
+                "America/New_York",
+                "2023-11-05T04:00:00Z",
+            ),
+            // 01:30 occurs twice when the clocks are turned back
+            (
+                "hour",
+                "2023-11-05T05:30:00Z",
+                "America/New_York",
+                "2023-11-05T05:00:00Z",
+            ),
+            (
+                "hour",
+                "2023-11-05T06:30:00Z",
+                "America/New_York",
+                "2023-11-05T06:00:00Z",
+            ),
+            (
+                "month",
+                "2023-11-05T06:30:00Z",
+                "America/New_York",
+                "2023-11-01T04:00:00Z",
+            ),
+            (
+                "day",
+                "2020-09-08T20:00:00Z",
+                "Asia/Kolkata",
+                "2020-09-08T18:30:00Z",
+            ),
+        ];
+
+        cases
+            .iter()
+            .for_each(|(granularity, original, tz, expected)| {
+                let tz: Option<Arc<str>> = Some((*tz).into());
+                let result = date_trunc(&[
+                    ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+                        granularity.to_string(),
+                    ))),
+                    ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                        Some(string_to_timestamp_nanos(original).unwrap()),
+                        tz.clone(),
+                    )),
+                ])
+                .unwrap();
+                let ColumnarValue::Scalar(result) = result else {
+                    panic!("unexpected column type");
+                };
+                assert_eq!(
+                    result,
+                    ScalarValue::TimestampNanosecond(
+                        Some(string_to_timestamp_nanos(expected).unwrap()),
+                        tz
+                    ),
+                    "{granularity} {original} = {expected}"
+                );
+            });
+    }
+
     #[test]
     fn test_date_bin_single() {
         use chrono::Duration;
@@ -1686,6 +2214,322 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_date_bin_dst() {
+        let day = ScalarValue::new_interval_dt(1, 0);
+        let hour = ScalarValue::new_interval_dt(0, 3_600_000);
+        let cases = vec![
+            // daily bins start at local midnight on both sides of a DST transition
+            (&day, "2023-03-12T12:00:00Z", None, "2023-03-12T05:00:00Z"),
+            (&day, "2023-11-05T12:00:00Z", None, "2023-11-05T04:00:00Z"),
+            (
+                &day,
+                "2023-07-01T12:00:00Z",
+                Some("2023-01-01T05:00:00Z"),
+                "2023-07-01T04:00:00Z",
+            ),
+            // 01:30 occurs twice when the clocks are turned back
+            (&hour, "2023-11-05T05:30:00Z", None, "2023-11-05T05:00:00Z"),
+            (&hour, "2023-11-05T06:30:00Z", None, "2023-11-05T06:00:00Z"),
+        ];
+
+        cases.iter().for_each(|(stride, source, origin, expected)| {
+            let tz: Option<Arc<str>> = Some("America/New_York".into());
+            let mut args = vec![
+                ColumnarValue::Scalar((*stride).clone()),
+                ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                    Some(string_to_timestamp_nanos(source).unwrap()),
+                    tz.clone(),
+                )),
+            ];
+            if let Some(origin) = origin {
+                args.push(ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                    Some(string_to_timestamp_nanos(origin).unwrap()),
+                    tz.clone(),
+                )));
+            }
+            let ColumnarValue::Scalar(result) = date_bin(&args).unwrap() else {
+                panic!("unexpected column type");
+            };
+            assert_eq!(
+                result,
+                ScalarValue::TimestampNanosecond(
+                    Some(string_to_timestamp_nanos(expected).unwrap()),
+                    tz
+                ),
+                "{source} = {expected}"
+            );
+        });
+    }
+
+    #[test]
+    fn test_to_local_time() {
+        let input = TimestampNanosecondArray::from(vec![
+            Some(string_to_timestamp_nanos("2023-07-01T12:00:00Z").unwrap()),
+            Some(string_to_timestamp_nanos("2023-12-01T12:00:00Z").unwrap()),
+            None,
+        ])
+        .with_timezone("America/New_York");
+        let result = to_local_time(&[Arc::new(input)]).unwrap();
+        let expected: ArrayRef = Arc::new(TimestampNanosecondArray::from(vec![
+            Some(string_to_timestamp_nanos("2023-07-01T08:00:00").unwrap()),
+            Some(string_to_timestamp_nanos("2023-12-01T07:00:00").unwrap()),
+            None,
+        ]));
+        assert_eq!(&result, &expected);
+
+        let input = TimestampSecondArray::from(vec![Some(0)]).with_timezone("+05:30");
+        let result = to_local_time(&[Arc::new(input)]).unwrap();
+        let expected: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![Some(5 * 3600 + 30 * 60)]));
+        assert_eq!(&result, &expected);
+
+        // timestamps without a timezone are returned unchanged
+        let input: ArrayRef = Arc::new(TimestampSecondArray::from(vec![Some(0)]));
+        let result = to_local_time(&[input.clone()]).unwrap();
+        assert_eq!(&result, &input);
+    }
+
+    #[test]
+    fn test_make_timestamp() {
+        let args = |fields: [Option<i64>; 5], second: f64, tz: Option<&str>| {
+            let mut args: Vec<ArrayRef> = fields
+                .iter()
+                .map(|field| Arc::new(Int64Array::from(vec![*field])) as ArrayRef)
+                .collect();
+            args.push(Arc::new(Float64Array::from(vec![second])));
+            if let Some(tz) = tz {
+                args.push(Arc::new(StringArray::from(vec![tz])));
+            }
+            args
+        };
+        let timestamp = |s: &str| Some(string_to_timestamp_nanos(s).unwrap());
+
+        let result =
+            make_timestamp(&args([2023, 3, 12, 2, 30].map(Some), 15.5, None)).unwrap();
+        let expected: ArrayRef =
+            Arc::new(TimestampNanosecondArray::from(vec![timestamp(
+                "2023-03-12T02:30:15.5",
+            )]));
+        assert_eq!(&result, &expected);
+
+        let result = make_timestamp(&args(
+            [2023, 7, 1, 8, 0].map(Some),
+            0.0,
+            Some("America/New_York"),
+        ))
+        .unwrap();
+        let expected: ArrayRef = Arc::new(
+            TimestampNanosecondArray::from(vec![timestamp("2023-07-01T12:00:00Z")])
+                .with_timezone("+00:00"),
+        );
+        assert_eq!(&result, &expected);
+
+        // 02:30 is skipped when the clocks are turned forward
+        let result = make_timestamp(&args(
+            [2023, 3, 12, 2, 30].map(Some),
+            0.0,
+            Some("America/New_York"),
+        ))
+        .unwrap();
+        assert_eq!(
+            as_timestamp_nanosecond_array(&result).unwrap().value(0),
+            timestamp("2023-03-12T07:30:00Z").unwrap()
+        );
+
+        // 01:30 occurs twice when the clocks are turned back
+        let result = make_timestamp(&args(
+            [2023, 11, 5, 1, 30].map(Some),
+            0.0,
+            Some("America/New_York"),
+        ))
+        .unwrap();
+        assert_eq!(
+            as_timestamp_nanosecond_array(&result).unwrap().value(0),
+            timestamp("2023-11-05T05:30:00Z").unwrap()
+        );
+
+        let result = make_timestamp(&args(
+            [Some(2023), None, Some(1), Some(0), Some(0)],
+            0.0,
+            None,
+        ))
+        .unwrap();
+        assert!(result.is_null(0));
+
+        let result = make_timestamp(&args([2023, 13, 1, 0, 0].map(Some), 0.0, None));
+        assert_eq!(
+            result.err().unwrap().strip_backtrace(),
+            "Execution error: make_timestamp got an invalid date or time: 2023-13-1 0:0:0"
+        );
+
+        let result = make_timestamp(&args(
+            [2023, 1, 1, 0, 0].map(Some),
+            0.0,
+            Some("Mars/Olympus_Mons"),
+        ));
+        assert!(result.err().unwrap().strip_backtrace().starts_with(
+            "Execution error: make_timestamp got an invalid timezone 'Mars/Olympus_Mons'"
+        ));
+    }
+
+    #[test]
+    fn to_timestamp_with_formats() -> Result<()> {
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("2023-01-31 09:26:56"),
+            Some("31/01/2023 10:30"),
+            Some("20230131"),
+            None,
+        ]));
+        let args = [
+            ColumnarValue::Array(strings),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%Y-%m-%d %H:%M:%S".into()))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%d/%m/%Y %H:%M".into()))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%Y%m%d".into()))),
+        ];
+        let expected: ArrayRef = Arc::new(TimestampNanosecondArray::from(vec![
+            Some(string_to_timestamp_nanos("2023-01-31T09:26:56").unwrap()),
+            Some(string_to_timestamp_nanos("2023-01-31T10:30:00").unwrap()),
+            Some(string_to_timestamp_nanos("2023-01-31T00:00:00").unwrap()),
+            None,
+        ]));
+        let result = to_timestamp(&args)?.into_array(4)?;
+        assert_eq!(&result, &expected);
+
+        // formats with an offset are converted to UTC
+        let args = [
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+                "2023-01-31 09:26:56 +0100".into(),
+            ))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%Y-%m-%d %H:%M:%S %z".into()))),
+        ];
+        let result = to_timestamp_seconds(&args)?;
+        let ColumnarValue::Scalar(ScalarValue::TimestampSecond(Some(seconds), None)) =
+            result
+        else {
+            panic!("Expected a scalar timestamp, got {result:?}")
+        };
+        assert_eq!(
+            seconds,
+            string_to_timestamp_nanos("2023-01-31T08:26:56Z").unwrap() / 1_000_000_000
+        );
+
+        // the error of the last format is returned when none match
+        let args = [
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("31.01.2023".into()))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%Y-%m-%d".into()))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%d/%m/%Y".into()))),
+        ];
+        let err = to_timestamp(&args).unwrap_err();
+        assert!(err.strip_backtrace().starts_with(
+            "Execution error: Error parsing timestamp from '31.01.2023' using format '%d/%m/%Y'"
+        ));
+
+        // try_to_timestamp returns NULL instead
+        let result = try_to_timestamp(&args)?;
+        assert_eq!(
+            result,
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(None, None))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn try_to_timestamp_without_format() -> Result<()> {
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("2020-09-08T13:42:29Z"),
+            Some("not a timestamp"),
+        ]));
+        let result = try_to_timestamp(&[ColumnarValue::Array(strings.clone())])?;
+        let expected: ArrayRef = Arc::new(TimestampNanosecondArray::from(vec![
+            Some(1599572549000000000),
+            None,
+        ]));
+        assert_eq!(&result.into_array(2)?, &expected);
+
+        assert!(to_timestamp(&[ColumnarValue::Array(strings)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn to_date_with_formats() -> Result<()> {
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("2023-01-31"),
+            Some("2023-01-31T23:59:59Z"),
+            None,
+        ]));
+        let result = to_date(&[ColumnarValue::Array(strings)])?.into_array(3)?;
+        let expected: ArrayRef =
+            Arc::new(Date32Array::from(vec![Some(19388), Some(19388), None]));
+        assert_eq!(&result, &expected);
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("31/01/2023"),
+            Some("Jan 31, 2023"),
+            Some("January"),
+        ]));
+        let args = [
+            ColumnarValue::Array(strings),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%d/%m/%Y".into()))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("%b %d, %Y".into()))),
+        ];
+        assert!(to_date(&args).is_err());
+
+        let result = try_to_date(&args)?.into_array(3)?;
+        let expected: ArrayRef =
+            Arc::new(Date32Array::from(vec![Some(19388), Some(19388), None]));
+        assert_eq!(&result, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn to_char_formats() -> Result<()> {
+        let format = |f: &str| ColumnarValue::Scalar(ScalarValue::Utf8(Some(f.into())));
+
+        let dates: ArrayRef = Arc::new(Date32Array::from(vec![Some(19388), None]));
+        let result = to_char(&[ColumnarValue::Array(dates), format("%d/%m/%Y")])?;
+        let expected: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("31/01/2023"), None]));
+        assert_eq!(&result.into_array(2)?, &expected);
+
+        let timestamp = string_to_timestamp_nanos("2023-01-31T09:26:56.123Z").unwrap();
+        let timestamps: ArrayRef = Arc::new(
+            TimestampNanosecondArray::from(vec![timestamp])
+                .with_timezone("America/New_York"),
+        );
+        let formats: ArrayRef =
+            Arc::new(StringArray::from(vec!["%Y-%m-%d %H:%M:%S%.3f %:z"]));
+        let result = to_char(&[
+            ColumnarValue::Array(timestamps),
+            ColumnarValue::Array(formats),
+        ])?;
+        let expected: ArrayRef =
+            Arc::new(StringArray::from(vec!["2023-01-31 04:26:56.123 -05:00"]));
+        assert_eq!(&result.into_array(1)?, &expected);
+
+        let result = to_char(&[
+            ColumnarValue::Scalar(ScalarValue::DurationSecond(Some(3661))),
+            format("pretty"),
+        ])?;
+        assert_eq!(
+            result,
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+                "0 days 1 hours 1 mins 1 secs".into()
+            )))
+        );
+
+        let err = to_char(&[
+            ColumnarValue::Scalar(ScalarValue::DurationSecond(Some(3661))),
+            format("%H:%M"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: to_char expects 'ISO8601' or 'pretty' as the format of a duration, got '%H:%M'"
+        );
+        Ok(())
+    }
+
     #[test]
     fn to_timestamp_invalid_input_type() -> Result<()> {
         // pass the wrong type of input array to to_timestamp and test
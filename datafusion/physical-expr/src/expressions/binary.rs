@@ -37,13 +37,19 @@ use arrow::compute::kernels::comparison::regexp_is_match_utf8;
 use arrow::compute::kernels::comparison::regexp_is_match_utf8_scalar;
 use arrow::compute::kernels::concat_elements::concat_elements_utf8;
 use arrow::datatypes::*;
+use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::cast::as_boolean_array;
-use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
+use datafusion_common::config::ArithmeticOverflowMode;
+use datafusion_common::{internal_err, Collation, DataFusionError, Result, ScalarValue};
 use datafusion_expr::type_coercion::binary::get_result_type;
 use datafusion_expr::{ColumnarValue, Operator};
 
 use crate::expressions::datum::{apply, apply_cmp};
+use crate::expressions::overflow::{
+    binary_null_on_overflow, decimal_null_on_overflow, is_divide_by_zero, overflow_error,
+};
+use crate::expressions::{collation_keys, collation_of};
 use kernels::{
     bitwise_and_dyn, bitwise_and_dyn_scalar, bitwise_or_dyn, bitwise_or_dyn_scalar,
     bitwise_shift_left_dyn, bitwise_shift_left_dyn_scalar, bitwise_shift_right_dyn,
@@ -56,6 +62,8 @@ pub struct BinaryExpr {
     left: Arc<dyn PhysicalExpr>,
     op: Operator,
     right: Arc<dyn PhysicalExpr>,
+    /// How overflow of arithmetic operators is handled
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl BinaryExpr {
@@ -65,7 +73,18 @@ impl BinaryExpr {
         op: Operator,
         right: Arc<dyn PhysicalExpr>,
     ) -> Self {
-        Self { left, op, right }
+        Self {
+            left,
+            op,
+            right,
+            overflow_mode: ArithmeticOverflowMode::Wrap,
+        }
+    }
+
+    /// Set how overflow of arithmetic operators is handled
+    pub fn with_overflow_mode(mut self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        self.overflow_mode = overflow_mode;
+        self
     }
 
     /// Get the left side of the binary expression
@@ -82,6 +101,11 @@ impl BinaryExpr {
     pub fn op(&self) -> &Operator {
         &self.op
     }
+
+    /// Get how overflow of arithmetic operators is handled
+    pub fn overflow_mode(&self) -> ArithmeticOverflowMode {
+        self.overflow_mode
+    }
 }
 
 impl std::fmt::Display for BinaryExpr {
@@ -254,26 +278,36 @@ impl PhysicalExpr for BinaryExpr {
     }
 
     fn nullable(&self, input_schema: &Schema) -> Result<bool> {
+        // overflowing arithmetic produces nulls in `ArithmeticOverflowMode::Null`
+        if self.overflow_mode == ArithmeticOverflowMode::Null
+            && self.op.is_numerical_operators()
+        {
+            return Ok(true);
+        }
         Ok(self.left.nullable(input_schema)? || self.right.nullable(input_schema)?)
     }
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
-        use arrow::compute::kernels::numeric::*;
+        let schema = batch.schema();
+        let input_schema = schema.as_ref();
 
         let lhs = self.left.evaluate(batch)?;
         let rhs = self.right.evaluate(batch)?;
+        // collated strings compare by their collation keys
+        let collation = self.collation(input_schema);
+        let lhs = collation_keys(lhs, collation)?;
+        let rhs = collation_keys(rhs, collation)?;
         let left_data_type = lhs.data_type();
         let right_data_type = rhs.data_type();
 
-        let schema = batch.schema();
-        let input_schema = schema.as_ref();
-
         match self.op {
-            Operator::Plus => return apply(&lhs, &rhs, add_wrapping),
-            Operator::Minus => return apply(&lhs, &rhs, sub_wrapping),
-            Operator::Multiply => return apply(&lhs, &rhs, mul_wrapping),
-            Operator::Divide => return apply(&lhs, &rhs, div),
-            Operator::Modulo => return apply(&lhs, &rhs, rem),
+            Operator::Plus
+            | Operator::Minus
+            | Operator::Multiply
+            | Operator::Divide
+            | Operator::Modulo => {
+                return self.evaluate_arithmetic(&lhs, &rhs, batch.num_rows())
+            }
             Operator::Eq => return apply_cmp(&lhs, &rhs, eq),
             Operator::NotEq => return apply_cmp(&lhs, &rhs, neq),
             Operator::Lt => return apply_cmp(&lhs, &rhs, lt),
@@ -319,11 +353,10 @@ impl PhysicalExpr for BinaryExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(BinaryExpr::new(
-            children[0].clone(),
-            self.op,
-            children[1].clone(),
-        )))
+        Ok(Arc::new(
+            BinaryExpr::new(children[0].clone(), self.op, children[1].clone())
+                .with_overflow_mode(self.overflow_mode),
+        ))
     }
 
     fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
@@ -390,11 +423,37 @@ impl PartialEq<dyn Any> for BinaryExpr {
     fn eq(&self, other: &dyn Any) -> bool {
         down_cast_any_ref(other)
             .downcast_ref::<Self>()
-            .map(|x| self.left.eq(&x.left) && self.op == x.op && self.right.eq(&x.right))
+            .map(|x| {
+                self.left.eq(&x.left)
+                    && self.op == x.op
+                    && self.right.eq(&x.right)
+                    && self.overflow_mode == x.overflow_mode
+            })
             .unwrap_or(false)
     }
 }
 
+/// Wraps an arithmetic kernel so that decimal results which exceed the
+/// precision of their output type are reported as an error instead of
+/// silently producing out of range values.
+fn decimal_checked(
+    f: impl Fn(&dyn Datum, &dyn Datum) -> Result<ArrayRef, ArrowError>,
+) -> impl Fn(&dyn Datum, &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    move |lhs, rhs| {
+        let array = f(lhs, rhs)?;
+        match array.data_type() {
+            DataType::Decimal128(precision, _) => array
+                .as_primitive::<Decimal128Type>()
+                .validate_decimal_precision(*precision)?,
+            DataType::Decimal256(precision, _) => array
+                .as_primitive::<Decimal256Type>()
+                .validate_decimal_precision(*precision)?,
+            _ => {}
+        }
+        Ok(array)
+    }
+}
+
 /// Casts dictionary array to result type for binary numerical operators. Such operators
 /// between array and scalar produce a dictionary array other than primitive array of the
 /// same operators between array and array. This leads to inconsistent result types causing
@@ -426,6 +485,92 @@ fn to_result_type_array(
 }
 
 impl BinaryExpr {
+    /// Returns the collation both sides are compared under, if this is a
+    /// comparison and either side is collated
+    fn collation(&self, input_schema: &Schema) -> Option<Collation> {
+        match self.op {
+            Operator::Eq
+            | Operator::NotEq
+            | Operator::Lt
+            | Operator::LtEq
+            | Operator::Gt
+            | Operator::GtEq
+            | Operator::IsDistinctFrom
+            | Operator::IsNotDistinctFrom => collation_of(&self.left, input_schema)
+                .or_else(|| collation_of(&self.right, input_schema)),
+            _ => None,
+        }
+    }
+
+    /// Evaluate an arithmetic operator, handling overflow according to
+    /// `self.overflow_mode`
+    fn evaluate_arithmetic(
+        &self,
+        lhs: &ColumnarValue,
+        rhs: &ColumnarValue,
+        num_rows: usize,
+    ) -> Result<ColumnarValue> {
+        use arrow::compute::kernels::numeric::*;
+
+        type Kernel = fn(&dyn Datum, &dyn Datum) -> Result<ArrayRef, ArrowError>;
+        let (wrapping, checked): (Kernel, Kernel) = match self.op {
+            Operator::Plus => (add_wrapping, add),
+            Operator::Minus => (sub_wrapping, sub),
+            Operator::Multiply => (mul_wrapping, mul),
+            Operator::Divide => (div, div),
+            Operator::Modulo => (rem, rem),
+            op => return internal_err!("{op} is not an arithmetic operator"),
+        };
+
+        // A wrapped decimal is not a meaningful value, so decimal arithmetic
+        // always reports overflow as an error unless nulls were requested
+        let is_decimal = |v: &ColumnarValue| {
+            matches!(
+                v.data_type(),
+                DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+            )
+        };
+        let overflow_mode = match self.overflow_mode {
+            ArithmeticOverflowMode::Wrap if is_decimal(lhs) || is_decimal(rhs) => {
+                ArithmeticOverflowMode::Error
+            }
+            mode => mode,
+        };
+
+        match overflow_mode {
+            ArithmeticOverflowMode::Wrap => apply(lhs, rhs, wrapping),
+            ArithmeticOverflowMode::Error => apply(lhs, rhs, decimal_checked(checked))
+                .map_err(|e| overflow_error(self, e)),
+            ArithmeticOverflowMode::Null => {
+                let err = match apply(lhs, rhs, |l, r| {
+                    checked(l, r).map(decimal_null_on_overflow)
+                }) {
+                    Err(e) if !is_divide_by_zero(&e) => e,
+                    result => return result,
+                };
+
+                // Fall back to evaluating row by row, replacing overflowing
+                // values with nulls
+                let left = lhs.clone().into_array(num_rows)?;
+                let right = rhs.clone().into_array(num_rows)?;
+                let Some(result) =
+                    binary_null_on_overflow(left.as_ref(), self.op, right.as_ref())?
+                else {
+                    return Err(overflow_error(self, err));
+                };
+                match (lhs, rhs) {
+                    (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_)) => {
+                        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                            result.as_ref(),
+                            0,
+                        )?))
+                    }
+                    _ => Ok(ColumnarValue::Array(result)),
+                }
+            }
+        }
+    }
+
     /// Evaluate the expression of the left input is an array and
     /// right is literal - use scalar operations
     fn evaluate_array_scalar(
@@ -3570,6 +3715,125 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn decimal256_arithmetic_overflow() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal256(76, 0), true),
+            Field::new("b", DataType::Decimal256(76, 0), true),
+        ]));
+        let max = i256::from_string(&"9".repeat(76)).unwrap();
+        let a = Arc::new(
+            Decimal256Array::from(vec![Some(max), Some(i256::from_i128(1))])
+                .with_precision_and_scale(76, 0)?,
+        ) as ArrayRef;
+        let b = Arc::new(
+            Decimal256Array::from(vec![None, Some(i256::from_i128(2))])
+                .with_precision_and_scale(76, 0)?,
+        ) as ArrayRef;
+        let batch = RecordBatch::try_new(schema.clone(), vec![a.clone(), b])?;
+
+        // null results are not validated
+        let expr = binary(
+            col("a", &schema)?,
+            Operator::Plus,
+            col("b", &schema)?,
+            &schema,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = Decimal256Array::from(vec![None, Some(i256::from_i128(3))])
+            .with_precision_and_scale(76, 0)?;
+        assert_eq!(result.as_primitive::<Decimal256Type>(), &expected);
+
+        // max + max exceeds the maximum precision of Decimal256
+        let expr = binary(
+            col("a", &schema)?,
+            Operator::Plus,
+            col("a", &schema)?,
+            &schema,
+        )?;
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("is too large to store in a Decimal256 of precision 76"),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_overflow_modes() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let a = Arc::new(Int64Array::from(vec![1, i64::MAX])) as ArrayRef;
+        let b = Arc::new(Int64Array::from(vec![2, 1])) as ArrayRef;
+        let batch = RecordBatch::try_new(schema.clone(), vec![a, b])?;
+
+        let expr = |mode| {
+            Arc::new(
+                BinaryExpr::new(
+                    col("a", &schema).unwrap(),
+                    Operator::Plus,
+                    col("b", &schema).unwrap(),
+                )
+                .with_overflow_mode(mode),
+            )
+        };
+
+        let result = expr(ArithmeticOverflowMode::Wrap)
+            .evaluate(&batch)?
+            .into_array(batch.num_rows())?;
+        assert_eq!(
+            result.as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![3, i64::MIN])
+        );
+
+        let err = expr(ArithmeticOverflowMode::Error)
+            .evaluate(&batch)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Arithmetic overflow in a@0 + b@1"),
+            "{err}"
+        );
+
+        let expr = expr(ArithmeticOverflowMode::Null);
+        assert!(expr.nullable(&schema)?);
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        assert_eq!(
+            result.as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(3), None])
+        );
+
+        // scalar operands produce a scalar result
+        let expr = BinaryExpr::new(
+            lit(ScalarValue::Int64(Some(i64::MIN))),
+            Operator::Minus,
+            lit(ScalarValue::Int64(Some(1))),
+        )
+        .with_overflow_mode(ArithmeticOverflowMode::Null);
+        match expr.evaluate(&batch)? {
+            ColumnarValue::Scalar(scalar) => assert_eq!(scalar, ScalarValue::Int64(None)),
+            other => panic!("expected a scalar, got {other:?}"),
+        }
+
+        // division by zero is not an overflow
+        let expr = BinaryExpr::new(
+            col("a", &schema)?,
+            Operator::Divide,
+            lit(ScalarValue::Int64(Some(0))),
+        )
+        .with_overflow_mode(ArithmeticOverflowMode::Null);
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ArrowError(ArrowError::DivideByZero)),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn bitwise_array_test() -> Result<()> {
         let left = Arc::new(Int32Array::from(vec![Some(12), None, Some(11)])) as ArrayRef;
@@ -3805,4 +4069,41 @@ mod tests {
                 .unwrap();
         assert_eq!(&casted, &dictionary);
     }
+
+    #[test]
+    fn collated_comparison() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true)
+                .with_metadata(Collation::NoCase.to_metadata()),
+        ]);
+        let a = StringArray::from(vec![Some("Alice"), Some("alice"), Some("Bob"), None]);
+        let b = StringArray::from(vec![Some("ALICE"), Some("Alice"), Some("bob"), None]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(a), Arc::new(b)],
+        )?;
+
+        // an explicit collation on either side applies to both
+        let collated = crate::expressions::collate(col("a", &schema)?, Collation::NoCase);
+        let expr = BinaryExpr::new(collated, Operator::Eq, lit("ALICE"));
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), Some(false), None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        // as does the collation of a column
+        let expr = BinaryExpr::new(col("a", &schema)?, Operator::Eq, col("b", &schema)?);
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = BooleanArray::from(vec![Some(true), Some(true), Some(true), None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        // without a collation strings compare byte-wise
+        let expr = BinaryExpr::new(col("a", &schema)?, Operator::Lt, lit("alice"));
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), Some(true), None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+        Ok(())
+    }
 }
@@ -20,6 +20,7 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use crate::expressions::overflow::overflow_error;
 use crate::intervals::Interval;
 use crate::physical_expr::down_cast_any_ref;
 use crate::sort_properties::SortProperties;
@@ -30,6 +31,7 @@ use arrow::compute::{kernels, CastOptions};
 use arrow::datatypes::{DataType, Schema};
 use arrow::record_batch::RecordBatch;
 use compute::can_cast_types;
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::format::DEFAULT_FORMAT_OPTIONS;
 use datafusion_common::{not_impl_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::ColumnarValue;
@@ -48,6 +50,8 @@ pub struct CastExpr {
     cast_type: DataType,
     /// Cast options
     cast_options: CastOptions<'static>,
+    /// How values that overflow a numeric target type are handled
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl CastExpr {
@@ -61,9 +65,23 @@ impl CastExpr {
             expr,
             cast_type,
             cast_options: cast_options.unwrap_or(DEFAULT_CAST_OPTIONS),
+            overflow_mode: ArithmeticOverflowMode::Wrap,
         }
     }
 
+    /// Set how values that overflow a numeric target type are handled.
+    ///
+    /// [`ArithmeticOverflowMode::Null`] casts them to NULL, while
+    /// [`ArithmeticOverflowMode::Error`] fails naming this expression.
+    /// [`ArithmeticOverflowMode::Wrap`] leaves it to the cast options.
+    pub fn with_overflow_mode(mut self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        if overflow_mode == ArithmeticOverflowMode::Null {
+            self.cast_options.safe = true;
+        }
+        self.overflow_mode = overflow_mode;
+        self
+    }
+
     /// The expression to cast
     pub fn expr(&self) -> &Arc<dyn PhysicalExpr> {
         &self.expr
@@ -78,6 +96,11 @@ impl CastExpr {
     pub fn cast_options(&self) -> &CastOptions<'static> {
         &self.cast_options
     }
+
+    /// How values that overflow a numeric target type are handled
+    pub fn overflow_mode(&self) -> ArithmeticOverflowMode {
+        self.overflow_mode
+    }
 }
 
 impl fmt::Display for CastExpr {
@@ -102,7 +125,11 @@ impl PhysicalExpr for CastExpr {
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
         let value = self.expr.evaluate(batch)?;
-        cast_column(&value, &self.cast_type, Some(&self.cast_options))
+        let result = cast_column(&value, &self.cast_type, Some(&self.cast_options));
+        match self.overflow_mode {
+            ArithmeticOverflowMode::Error => result.map_err(|e| overflow_error(self, e)),
+            _ => result,
+        }
     }
 
     fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
@@ -113,11 +140,14 @@ impl PhysicalExpr for CastExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(CastExpr::new(
-            children[0].clone(),
-            self.cast_type.clone(),
-            Some(self.cast_options.clone()),
-        )))
+        Ok(Arc::new(
+            CastExpr::new(
+                children[0].clone(),
+                self.cast_type.clone(),
+                Some(self.cast_options.clone()),
+            )
+            .with_overflow_mode(self.overflow_mode),
+        ))
     }
 
     fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
@@ -161,6 +191,7 @@ impl PartialEq<dyn Any> for CastExpr {
                     && self.cast_type == x.cast_type
                     // TODO: Use https://github.com/apache/arrow-rs/issues/2966 when available
                     && self.cast_options.safe == x.cast_options.safe
+                    && self.overflow_mode == x.overflow_mode
             })
             .unwrap_or(false)
     }
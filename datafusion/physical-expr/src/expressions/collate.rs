@@ -0,0 +1,207 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Collate expression
+
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::expressions::{CastExpr, Column, TryCastExpr};
+use crate::physical_expr::down_cast_any_ref;
+use crate::PhysicalExpr;
+use arrow::datatypes::{DataType, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{Collation, Result};
+use datafusion_expr::ColumnarValue;
+
+/// `expr COLLATE collation`: evaluates to the value of `expr` unchanged,
+/// marked with a [`Collation`].
+///
+/// Comparisons, sorts, grouping, repartitioning and joins on the
+/// expression compare the collation keys of its values rather than the
+/// values themselves, see [`collation_of`].
+#[derive(Debug, Hash)]
+pub struct CollateExpr {
+    /// The collated expression
+    expr: Arc<dyn PhysicalExpr>,
+    /// The collation of `expr`
+    collation: Collation,
+}
+
+impl CollateExpr {
+    /// Create a new CollateExpr
+    pub fn new(expr: Arc<dyn PhysicalExpr>, collation: Collation) -> Self {
+        Self { expr, collation }
+    }
+
+    /// The collated expression
+    pub fn expr(&self) -> &Arc<dyn PhysicalExpr> {
+        &self.expr
+    }
+
+    /// The collation of the expression
+    pub fn collation(&self) -> Collation {
+        self.collation
+    }
+}
+
+impl fmt::Display for CollateExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} COLLATE {}", self.expr, self.collation)
+    }
+}
+
+impl PhysicalExpr for CollateExpr {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, input_schema: &Schema) -> Result<DataType> {
+        self.expr.data_type(input_schema)
+    }
+
+    fn nullable(&self, input_schema: &Schema) -> Result<bool> {
+        self.expr.nullable(input_schema)
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
+        self.expr.evaluate(batch)
+    }
+
+    fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        vec![self.expr.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        Ok(Arc::new(CollateExpr::new(
+            children[0].clone(),
+            self.collation,
+        )))
+    }
+
+    fn dyn_hash(&self, state: &mut dyn Hasher) {
+        let mut s = state;
+        self.hash(&mut s);
+    }
+}
+
+impl PartialEq<dyn Any> for CollateExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| self.expr.eq(&x.expr) && self.collation == x.collation)
+            .unwrap_or(false)
+    }
+}
+
+/// Return a PhysicalExpression marking `expr` with `collation`
+pub fn collate(
+    expr: Arc<dyn PhysicalExpr>,
+    collation: Collation,
+) -> Arc<dyn PhysicalExpr> {
+    Arc::new(CollateExpr::new(expr, collation))
+}
+
+/// Returns the collation of `expr`: either explicit with a
+/// [`CollateExpr`], or that of the column it references, as stored in the
+/// field metadata of `schema`
+pub fn collation_of(expr: &Arc<dyn PhysicalExpr>, schema: &Schema) -> Option<Collation> {
+    let any = expr.as_any();
+    if let Some(collate) = any.downcast_ref::<CollateExpr>() {
+        Some(collate.collation())
+    } else if let Some(column) = any.downcast_ref::<Column>() {
+        schema
+            .fields()
+            .get(column.index())
+            .and_then(|field| Collation::from_metadata(field.metadata()))
+    } else if let Some(cast) = any.downcast_ref::<CastExpr>() {
+        collation_of(cast.expr(), schema)
+    } else if let Some(cast) = any.downcast_ref::<TryCastExpr>() {
+        collation_of(cast.expr(), schema)
+    } else {
+        None
+    }
+}
+
+/// Returns the collation keys of `value` under `collation`, if any
+pub fn collation_keys(
+    value: ColumnarValue,
+    collation: Option<Collation>,
+) -> Result<ColumnarValue> {
+    match (collation, value) {
+        (None | Some(Collation::Binary), value) => Ok(value),
+        (Some(collation), ColumnarValue::Array(array)) => {
+            Ok(ColumnarValue::Array(collation.keys(&array)?))
+        }
+        (Some(collation), ColumnarValue::Scalar(scalar)) => {
+            Ok(ColumnarValue::Scalar(collation.scalar_key(&scalar)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{col, lit};
+    use arrow::array::StringArray;
+    use arrow::datatypes::Field;
+    use datafusion_common::cast::as_string_array;
+
+    #[test]
+    fn collate_keeps_values() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(StringArray::from(vec!["Alice", "ÉCOLE"]))],
+        )?;
+        let expr = collate(col("a", &schema)?, Collation::UndCi);
+        assert_eq!(expr.to_string(), "a@0 COLLATE und-ci");
+
+        let value = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        assert_eq!(&value, batch.column(0));
+
+        let keys = collation_keys(expr.evaluate(&batch)?, collation_of(&expr, &schema))?
+            .into_array(batch.num_rows())?;
+        assert_eq!(
+            as_string_array(&keys)?,
+            &StringArray::from(vec!["alice", "ecole"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn collation_of_column_metadata() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true)
+                .with_metadata(Collation::NoCase.to_metadata()),
+        ]);
+        assert_eq!(collation_of(&col("a", &schema)?, &schema), None);
+        assert_eq!(
+            collation_of(&col("b", &schema)?, &schema),
+            Some(Collation::NoCase)
+        );
+        assert_eq!(collation_of(&lit("x"), &schema), None);
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@ use std::{any::Any, sync::Arc};
 use crate::{physical_expr::down_cast_any_ref, PhysicalExpr};
 
 use crate::expressions::datum::apply_cmp;
+use crate::expressions::{collation_keys, collation_of};
 use arrow::record_batch::RecordBatch;
 use arrow_schema::{DataType, Schema};
 use datafusion_common::{internal_err, DataFusionError, Result};
@@ -102,8 +103,13 @@ impl PhysicalExpr for LikeExpr {
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
         use arrow::compute::*;
-        let lhs = self.expr.evaluate(batch)?;
-        let rhs = self.pattern.evaluate(batch)?;
+        // collated strings match by their collation keys, which keep the
+        // `%` and `_` wildcards of the pattern
+        let schema = batch.schema();
+        let collation = collation_of(&self.expr, &schema)
+            .or_else(|| collation_of(&self.pattern, &schema));
+        let lhs = collation_keys(self.expr.evaluate(batch)?, collation)?;
+        let rhs = collation_keys(self.pattern.evaluate(batch)?, collation)?;
         match (self.negated, self.case_insensitive) {
             (false, false) => apply_cmp(&lhs, &rhs, like),
             (false, true) => apply_cmp(&lhs, &rhs, ilike),
@@ -174,10 +180,11 @@ pub fn like(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::expressions::col;
+    use crate::expressions::{col, collate, lit};
     use arrow::array::*;
     use arrow_schema::Field;
     use datafusion_common::cast::as_boolean_array;
+    use datafusion_common::Collation;
 
     macro_rules! test_like {
         ($A_VEC:expr, $B_VEC:expr, $VEC:expr, $NULLABLE: expr, $NEGATED:expr, $CASE_INSENSITIVE:expr,) => {{
@@ -249,4 +256,24 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn collated_like() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+        let a = StringArray::from(vec![Some("École"), Some("ecole"), Some("Bob"), None]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(a)])?;
+
+        let expr = like(
+            false,
+            false,
+            collate(col("a", &schema)?, Collation::UndCi),
+            lit("ECO%"),
+            &schema,
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), Some(false), None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+        Ok(())
+    }
 }
@@ -21,6 +21,7 @@
 mod binary;
 mod case;
 mod cast;
+mod collate;
 mod column;
 mod datum;
 mod get_indexed_field;
@@ -33,6 +34,7 @@ mod negative;
 mod no_op;
 mod not;
 mod nullif;
+mod overflow;
 mod try_cast;
 
 /// Module with some convenient methods used in expression building
@@ -50,7 +52,9 @@ pub use crate::aggregate::array_agg_ordered::OrderSensitiveArrayAgg;
 pub use crate::aggregate::average::{Avg, AvgAccumulator};
 pub use crate::aggregate::bit_and_or_xor::{BitAnd, BitOr, BitXor, DistinctBitXor};
 pub use crate::aggregate::bool_and_or::{BoolAnd, BoolOr};
-pub use crate::aggregate::build_in::create_aggregate_expr;
+pub use crate::aggregate::build_in::{
+    create_aggregate_expr, create_aggregate_expr_with_overflow_mode,
+};
 pub use crate::aggregate::correlation::Correlation;
 pub use crate::aggregate::count::Count;
 pub use crate::aggregate::count_distinct::DistinctCount;
@@ -80,6 +84,7 @@ pub use crate::window::row_number::RowNumber;
 pub use binary::{binary, BinaryExpr};
 pub use case::{case, CaseExpr};
 pub use cast::{cast, cast_column, cast_with_options, CastExpr};
+pub use collate::{collate, collation_keys, collation_of, CollateExpr};
 pub use column::{col, Column, UnKnownColumn};
 pub use get_indexed_field::{GetFieldAccessExpr, GetIndexedFieldExpr};
 pub use in_list::{in_list, InListExpr};
@@ -87,7 +92,7 @@ pub use is_not_null::{is_not_null, IsNotNullExpr};
 pub use is_null::{is_null, IsNullExpr};
 pub use like::{like, LikeExpr};
 pub use literal::{lit, Literal};
-pub use negative::{negative, NegativeExpr};
+pub use negative::{negative, negative_with_overflow_mode, NegativeExpr};
 pub use no_op::NoOp;
 pub use not::{not, NotExpr};
 pub use nullif::nullif_func;
@@ -17,16 +17,19 @@
 
 //! Negation (-) expression
 
+use crate::expressions::overflow::{neg_null_on_overflow, overflow_error};
 use crate::intervals::Interval;
 use crate::physical_expr::down_cast_any_ref;
 use crate::sort_properties::SortProperties;
 use crate::PhysicalExpr;
 use arrow::{
-    compute::kernels::numeric::neg_wrapping,
+    array::{Array, ArrayRef},
+    compute::kernels::numeric::{neg, neg_wrapping},
     datatypes::{DataType, Schema},
     record_batch::RecordBatch,
 };
-use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_common::config::ArithmeticOverflowMode;
+use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::{
     type_coercion::{is_interval, is_null, is_signed_numeric},
     ColumnarValue,
@@ -41,18 +44,46 @@ use std::sync::Arc;
 pub struct NegativeExpr {
     /// Input expression
     arg: Arc<dyn PhysicalExpr>,
+    /// How overflow of the negation is handled
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl NegativeExpr {
     /// Create new not expression
     pub fn new(arg: Arc<dyn PhysicalExpr>) -> Self {
-        Self { arg }
+        Self {
+            arg,
+            overflow_mode: ArithmeticOverflowMode::Wrap,
+        }
+    }
+
+    /// Set how overflow of the negation is handled
+    pub fn with_overflow_mode(mut self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        self.overflow_mode = overflow_mode;
+        self
     }
 
     /// Get the input expression
     pub fn arg(&self) -> &Arc<dyn PhysicalExpr> {
         &self.arg
     }
+
+    /// Negate `array` with overflow checks, either raising an error or
+    /// producing nulls for the overflowing values
+    fn negate_checked(&self, array: &dyn Array) -> Result<ArrayRef> {
+        match neg(array) {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                let e = DataFusionError::ArrowError(e);
+                match self.overflow_mode {
+                    ArithmeticOverflowMode::Null => {
+                        neg_null_on_overflow(array).ok_or_else(|| overflow_error(self, e))
+                    }
+                    _ => Err(overflow_error(self, e)),
+                }
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for NegativeExpr {
@@ -72,19 +103,30 @@ impl PhysicalExpr for NegativeExpr {
     }
 
     fn nullable(&self, input_schema: &Schema) -> Result<bool> {
-        self.arg.nullable(input_schema)
+        Ok(self.overflow_mode == ArithmeticOverflowMode::Null
+            || self.arg.nullable(input_schema)?)
     }
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
         let arg = self.arg.evaluate(batch)?;
-        match arg {
-            ColumnarValue::Array(array) => {
+        match (arg, self.overflow_mode) {
+            (ColumnarValue::Array(array), ArithmeticOverflowMode::Wrap) => {
                 let result = neg_wrapping(array.as_ref())?;
                 Ok(ColumnarValue::Array(result))
             }
-            ColumnarValue::Scalar(scalar) => {
+            (ColumnarValue::Scalar(scalar), ArithmeticOverflowMode::Wrap) => {
                 Ok(ColumnarValue::Scalar((scalar.arithmetic_negate())?))
             }
+            (ColumnarValue::Array(array), _) => {
+                Ok(ColumnarValue::Array(self.negate_checked(array.as_ref())?))
+            }
+            (ColumnarValue::Scalar(scalar), _) => {
+                let result = self.negate_checked(scalar.to_array()?.as_ref())?;
+                Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                    result.as_ref(),
+                    0,
+                )?))
+            }
         }
     }
 
@@ -96,7 +138,9 @@ impl PhysicalExpr for NegativeExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(NegativeExpr::new(children[0].clone())))
+        Ok(Arc::new(
+            NegativeExpr::new(children[0].clone()).with_overflow_mode(self.overflow_mode),
+        ))
     }
 
     fn dyn_hash(&self, state: &mut dyn Hasher) {
@@ -138,7 +182,7 @@ impl PartialEq<dyn Any> for NegativeExpr {
     fn eq(&self, other: &dyn Any) -> bool {
         down_cast_any_ref(other)
             .downcast_ref::<Self>()
-            .map(|x| self.arg.eq(&x.arg))
+            .map(|x| self.arg.eq(&x.arg) && self.overflow_mode == x.overflow_mode)
             .unwrap_or(false)
     }
 }
@@ -151,6 +195,20 @@ impl PartialEq<dyn Any> for NegativeExpr {
 pub fn negative(
     arg: Arc<dyn PhysicalExpr>,
     input_schema: &Schema,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    negative_with_overflow_mode(arg, input_schema, ArithmeticOverflowMode::Wrap)
+}
+
+/// Creates a unary expression NEGATIVE, handling overflow according to
+/// `overflow_mode`
+///
+/// # Errors
+///
+/// This function errors when the argument's type is not signed numeric
+pub fn negative_with_overflow_mode(
+    arg: Arc<dyn PhysicalExpr>,
+    input_schema: &Schema,
+    overflow_mode: ArithmeticOverflowMode,
 ) -> Result<Arc<dyn PhysicalExpr>> {
     let data_type = arg.data_type(input_schema)?;
     if is_null(&data_type) {
@@ -160,7 +218,9 @@ pub fn negative(
             "Can't create negative physical expr for (- '{arg:?}'), the type of child expr is {data_type}, not signed numeric"
         )
     } else {
-        Ok(Arc::new(NegativeExpr::new(arg)))
+        Ok(Arc::new(
+            NegativeExpr::new(arg).with_overflow_mode(overflow_mode),
+        ))
     }
 }
 
@@ -213,11 +273,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn negative_overflow() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let input = Int32Array::from(vec![1, i32::MIN]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(input)])?;
+
+        let expr = negative_with_overflow_mode(
+            col("a", &schema)?,
+            &schema,
+            ArithmeticOverflowMode::Error,
+        )?;
+        assert!(!expr.nullable(&schema)?);
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert!(
+            err.to_string().contains("Arithmetic overflow in (- a@0)"),
+            "{err}"
+        );
+
+        let expr = negative_with_overflow_mode(
+            col("a", &schema)?,
+            &schema,
+            ArithmeticOverflowMode::Null,
+        )?;
+        assert!(expr.nullable(&schema)?);
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        assert_eq!(
+            as_primitive_array::<Int32Type>(&result)?,
+            &Int32Array::from(vec![Some(-1), None])
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_evaluate_bounds() -> Result<()> {
-        let negative_expr = NegativeExpr {
-            arg: Arc::new(Column::new("a", 0)),
-        };
+        let negative_expr = NegativeExpr::new(Arc::new(Column::new("a", 0)));
         let child_interval = Interval::make(Some(-2), Some(1), (true, false));
         let negative_expr_interval = Interval::make(Some(-1), Some(2), (false, true));
         assert_eq!(
@@ -229,9 +320,7 @@ mod tests {
 
     #[test]
     fn test_propagate_constraints() -> Result<()> {
-        let negative_expr = NegativeExpr {
-            arg: Arc::new(Column::new("a", 0)),
-        };
+        let negative_expr = NegativeExpr::new(Arc::new(Column::new("a", 0)));
         let original_child_interval = Interval::make(Some(-2), Some(3), (false, false));
         let negative_expr_interval = Interval::make(Some(0), Some(4), (true, false));
         let after_propagation =
@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers to evaluate arithmetic according to the configured
+//! [`ArithmeticOverflowMode`]
+//!
+//! [`ArithmeticOverflowMode`]: datafusion_common::config::ArithmeticOverflowMode
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, PrimitiveArray};
+use arrow::datatypes::*;
+use arrow::error::ArrowError;
+use arrow_array::ArrowNativeTypeOp;
+use datafusion_common::{DataFusionError, Result};
+use datafusion_expr::Operator;
+
+/// Adds the offending expression to an error raised by a checked arithmetic
+/// kernel. Division by zero is not an overflow and is returned unchanged
+pub(crate) fn overflow_error(expr: &dyn Display, e: DataFusionError) -> DataFusionError {
+    match e {
+        DataFusionError::ArrowError(ArrowError::DivideByZero) => e,
+        DataFusionError::ArrowError(e) => {
+            DataFusionError::Execution(format!("Arithmetic overflow in {expr}: {e}"))
+        }
+        e => e,
+    }
+}
+
+/// Returns true if `e` was raised by a division by zero
+pub(crate) fn is_divide_by_zero(e: &DataFusionError) -> bool {
+    matches!(e, DataFusionError::ArrowError(ArrowError::DivideByZero))
+}
+
+/// Evaluates `left op right` row by row, producing NULL for the rows whose
+/// result overflows the integer type of the operands
+///
+/// Returns `None` if the operands are not integers of the same type
+pub(crate) fn binary_null_on_overflow(
+    left: &dyn Array,
+    op: Operator,
+    right: &dyn Array,
+) -> Result<Option<ArrayRef>> {
+    macro_rules! typed {
+        ($t:ty) => {{
+            let left = left.as_primitive::<$t>();
+            let right = right.as_primitive::<$t>();
+            let result = match op {
+                Operator::Plus => null_on_overflow(left, right, |l, r| l.add_checked(r)),
+                Operator::Minus => null_on_overflow(left, right, |l, r| l.sub_checked(r)),
+                Operator::Multiply => {
+                    null_on_overflow(left, right, |l, r| l.mul_checked(r))
+                }
+                Operator::Divide => {
+                    null_on_overflow(left, right, |l, r| l.div_checked(r))
+                }
+                Operator::Modulo => {
+                    null_on_overflow(left, right, |l, r| l.mod_checked(r))
+                }
+                _ => return Ok(None),
+            }?;
+            Ok(Some(Arc::new(result) as ArrayRef))
+        }};
+    }
+
+    if left.data_type() != right.data_type() {
+        return Ok(None);
+    }
+    match left.data_type() {
+        DataType::Int8 => typed!(Int8Type),
+        DataType::Int16 => typed!(Int16Type),
+        DataType::Int32 => typed!(Int32Type),
+        DataType::Int64 => typed!(Int64Type),
+        DataType::UInt8 => typed!(UInt8Type),
+        DataType::UInt16 => typed!(UInt16Type),
+        DataType::UInt32 => typed!(UInt32Type),
+        DataType::UInt64 => typed!(UInt64Type),
+        _ => Ok(None),
+    }
+}
+
+/// Negates `array` row by row, producing NULL for the rows whose result
+/// overflows its integer type
+///
+/// Returns `None` if `array` is not a signed integer array
+pub(crate) fn neg_null_on_overflow(array: &dyn Array) -> Option<ArrayRef> {
+    macro_rules! typed {
+        ($t:ty) => {{
+            let result: PrimitiveArray<$t> = array
+                .as_primitive::<$t>()
+                .iter()
+                .map(|v| v.and_then(|v| v.neg_checked().ok()))
+                .collect();
+            Some(Arc::new(result) as ArrayRef)
+        }};
+    }
+
+    match array.data_type() {
+        DataType::Int8 => typed!(Int8Type),
+        DataType::Int16 => typed!(Int16Type),
+        DataType::Int32 => typed!(Int32Type),
+        DataType::Int64 => typed!(Int64Type),
+        _ => None,
+    }
+}
+
+/// Replaces the decimal values of `array` that do not fit its precision by NULL
+pub(crate) fn decimal_null_on_overflow(array: ArrayRef) -> ArrayRef {
+    match array.data_type() {
+        DataType::Decimal128(precision, _) => Arc::new(
+            array
+                .as_primitive::<Decimal128Type>()
+                .null_if_overflow_precision(*precision),
+        ),
+        DataType::Decimal256(precision, _) => Arc::new(
+            array
+                .as_primitive::<Decimal256Type>()
+                .null_if_overflow_precision(*precision),
+        ),
+        _ => array,
+    }
+}
+
+fn null_on_overflow<T: ArrowPrimitiveType>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: impl Fn(T::Native, T::Native) -> Result<T::Native, ArrowError>,
+) -> Result<PrimitiveArray<T>> {
+    left.iter()
+        .zip(right.iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => match op(l, r) {
+                Ok(v) => Ok(Some(v)),
+                Err(ArrowError::DivideByZero) => {
+                    Err(DataFusionError::ArrowError(ArrowError::DivideByZero))
+                }
+                Err(_) => Ok(None),
+            },
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int32Array;
+
+    #[test]
+    fn binary_overflow_to_null() -> Result<()> {
+        let left = Int32Array::from(vec![Some(i32::MAX), Some(1), None, Some(i32::MIN)]);
+        let right = Int32Array::from(vec![Some(1), Some(2), Some(3), Some(-1)]);
+
+        let result = binary_null_on_overflow(&left, Operator::Plus, &right)?.unwrap();
+        let expected = Int32Array::from(vec![None, Some(3), None, Some(i32::MAX)]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let result = binary_null_on_overflow(&left, Operator::Divide, &right)?.unwrap();
+        let expected = Int32Array::from(vec![Some(i32::MAX), Some(0), None, None]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        // division by zero is still an error
+        let zero = Int32Array::from(vec![0, 0, 0, 0]);
+        assert!(binary_null_on_overflow(&left, Operator::Divide, &zero).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn neg_overflow_to_null() {
+        let array = Int32Array::from(vec![Some(i32::MIN), Some(1), None]);
+        let result = neg_null_on_overflow(&array).unwrap();
+        let expected = Int32Array::from(vec![None, Some(-1), None]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+    }
+}
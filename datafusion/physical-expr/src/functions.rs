@@ -34,15 +34,15 @@ use crate::execution_props::ExecutionProps;
 use crate::sort_properties::SortProperties;
 use crate::{
     array_expressions, conditional_expressions, datetime_expressions,
-    expressions::nullif_func, math_expressions, string_expressions, struct_expressions,
-    PhysicalExpr, ScalarFunctionExpr,
+    expressions::nullif_func, map_expressions, math_expressions, string_expressions,
+    struct_expressions, PhysicalExpr, ScalarFunctionExpr,
 };
 use arrow::{
     array::ArrayRef,
     compute::kernels::length::{bit_length, length},
     datatypes::{DataType, Int32Type, Int64Type, Schema},
 };
-use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
+use datafusion_common::{internal_err, plan_err, DataFusionError, Result, ScalarValue};
 pub use datafusion_expr::FuncMonotonicity;
 use datafusion_expr::{
     type_coercion::functions::data_types, BuiltinScalarFunction, ColumnarValue,
@@ -103,6 +103,26 @@ macro_rules! invoke_if_encoding_expressions_feature_flag {
     };
 }
 
+#[cfg(feature = "json_expressions")]
+macro_rules! invoke_if_json_expressions_feature_flag {
+    ($FUNC:ident, $NAME:expr) => {{
+        use crate::json_expressions;
+        make_scalar_function(json_expressions::$FUNC)
+    }};
+}
+
+#[cfg(not(feature = "json_expressions"))]
+macro_rules! invoke_if_json_expressions_feature_flag {
+    ($FUNC:ident, $NAME:expr) => {
+        |_: &[ColumnarValue]| -> Result<ColumnarValue> {
+            internal_err!(
+                "function {} requires compilation with feature flag: json_expressions.",
+                $NAME
+            )
+        }
+    };
+}
+
 #[cfg(feature = "crypto_expressions")]
 macro_rules! invoke_if_crypto_expressions_feature_flag {
     ($FUNC:ident, $NAME:expr) => {{
@@ -407,10 +427,97 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::MakeArray => {
             Arc::new(|args| make_scalar_function(array_expressions::make_array)(args))
         }
+        BuiltinScalarFunction::ArraySort => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_sort)(args))
+        }
+        BuiltinScalarFunction::ArrayDistinct => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_distinct)(args))
+        }
+        BuiltinScalarFunction::ArrayUnion => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_union)(args))
+        }
+        BuiltinScalarFunction::ArrayExcept => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_except)(args))
+        }
+        BuiltinScalarFunction::ArrayMax => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_max)(args))
+        }
+        BuiltinScalarFunction::ArrayMin => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_min)(args))
+        }
+        BuiltinScalarFunction::ArrayZip => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_zip)(args))
+        }
+        BuiltinScalarFunction::ArrayResize => {
+            Arc::new(|args| make_scalar_function(array_expressions::array_resize)(args))
+        }
+        BuiltinScalarFunction::Range => {
+            Arc::new(|args| make_scalar_function(array_expressions::range)(args))
+        }
+        BuiltinScalarFunction::GenerateSeries => Arc::new(|args| {
+            make_scalar_function(array_expressions::generate_series)(args)
+        }),
+        // called with a lambda, these are planned as a HigherOrderFunctionExpr
+        BuiltinScalarFunction::ArrayTransform
+        | BuiltinScalarFunction::ArrayFilter
+        | BuiltinScalarFunction::ArrayReduce
+        | BuiltinScalarFunction::ArrayAnyMatch
+        | BuiltinScalarFunction::ArrayAllMatch => {
+            return plan_err!("The {fun} function requires a lambda as its last argument")
+        }
 
         // struct functions
         BuiltinScalarFunction::Struct => Arc::new(struct_expressions::struct_expr),
 
+        // map functions
+        BuiltinScalarFunction::MakeMap => {
+            Arc::new(|args| make_scalar_function(map_expressions::make_map)(args))
+        }
+        BuiltinScalarFunction::MapKeys => {
+            Arc::new(|args| make_scalar_function(map_expressions::map_keys)(args))
+        }
+        BuiltinScalarFunction::MapValues => {
+            Arc::new(|args| make_scalar_function(map_expressions::map_values)(args))
+        }
+        BuiltinScalarFunction::MapEntries => {
+            Arc::new(|args| make_scalar_function(map_expressions::map_entries)(args))
+        }
+        BuiltinScalarFunction::ElementAt => {
+            Arc::new(|args| make_scalar_function(map_expressions::map_element)(args))
+        }
+
+        // json functions
+        BuiltinScalarFunction::JsonGet => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_get, "json_get"),
+        ),
+        BuiltinScalarFunction::JsonGetStr => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_get_str, "json_get_str"),
+        ),
+        BuiltinScalarFunction::JsonGetInt => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_get_int, "json_get_int"),
+        ),
+        BuiltinScalarFunction::JsonGetFloat => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_get_float, "json_get_float"),
+        ),
+        BuiltinScalarFunction::JsonGetBool => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_get_bool, "json_get_bool"),
+        ),
+        BuiltinScalarFunction::JsonContains => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_contains, "json_contains"),
+        ),
+        BuiltinScalarFunction::JsonLength => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_length, "json_length"),
+        ),
+        BuiltinScalarFunction::JsonKeys => Arc::new(
+            invoke_if_json_expressions_feature_flag!(json_keys, "json_keys"),
+        ),
+        BuiltinScalarFunction::JsonArrayElements => {
+            Arc::new(invoke_if_json_expressions_feature_flag!(
+                json_array_elements,
+                "json_array_elements"
+            ))
+        }
+
         // string functions
         BuiltinScalarFunction::Ascii => Arc::new(|args| match args[0].data_type() {
             DataType::Utf8 => {
@@ -509,9 +616,41 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::ToTimestampSeconds => {
             Arc::new(datetime_expressions::to_timestamp_seconds_invoke)
         }
+        BuiltinScalarFunction::TryToTimestamp => {
+            Arc::new(datetime_expressions::try_to_timestamp_invoke)
+        }
+        BuiltinScalarFunction::ToDate => Arc::new(datetime_expressions::to_date_invoke),
+        BuiltinScalarFunction::TryToDate => {
+            Arc::new(datetime_expressions::try_to_date_invoke)
+        }
+        BuiltinScalarFunction::ToChar => Arc::new(datetime_expressions::to_char),
         BuiltinScalarFunction::FromUnixtime => {
             Arc::new(datetime_expressions::from_unixtime_invoke)
         }
+        BuiltinScalarFunction::ToLocalTime => Arc::new(|args| {
+            make_scalar_function(datetime_expressions::to_local_time)(args)
+        }),
+        BuiltinScalarFunction::MakeTimestamp => Arc::new(|args| {
+            make_scalar_function(datetime_expressions::make_timestamp)(args)
+        }),
+        // `expr COLLATE c` is planned as a `CollateExpr`; as a function it
+        // returns its argument unchanged
+        BuiltinScalarFunction::Collate => Arc::new(|args| Ok(args[0].clone())),
+        BuiltinScalarFunction::CollationKey => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    make_scalar_function(string_expressions::collation_key::<i32>)(args)
+                }
+                DataType::LargeUtf8 => {
+                    make_scalar_function(string_expressions::collation_key::<i64>)(args)
+                }
+                other => {
+                    internal_err!(
+                        "Unsupported data type {other:?} for function collation_key"
+                    )
+                }
+            })
+        }
         BuiltinScalarFunction::InitCap => Arc::new(|args| match args[0].data_type() {
             DataType::Utf8 => {
                 make_scalar_function(string_expressions::initcap::<i32>)(args)
@@ -603,6 +742,165 @@ pub fn create_physical_fun(
                 ),
             })
         }
+        BuiltinScalarFunction::RegexpLike => Arc::new(|args| match args[0].data_type() {
+            DataType::Utf8 => {
+                let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                    regexp_like,
+                    i32,
+                    "regexp_like"
+                );
+                make_scalar_function(func)(args)
+            }
+            DataType::LargeUtf8 => {
+                let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                    regexp_like,
+                    i64,
+                    "regexp_like"
+                );
+                make_scalar_function(func)(args)
+            }
+            other => {
+                internal_err!("Unsupported data type {other:?} for function regexp_like")
+            }
+        }),
+        BuiltinScalarFunction::RegexpCount => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_count,
+                        i32,
+                        "regexp_count"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                DataType::LargeUtf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_count,
+                        i64,
+                        "regexp_count"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                other => internal_err!(
+                    "Unsupported data type {other:?} for function regexp_count"
+                ),
+            })
+        }
+        BuiltinScalarFunction::RegexpInstr => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_instr,
+                        i32,
+                        "regexp_instr"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                DataType::LargeUtf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_instr,
+                        i64,
+                        "regexp_instr"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                other => internal_err!(
+                    "Unsupported data type {other:?} for function regexp_instr"
+                ),
+            })
+        }
+        BuiltinScalarFunction::RegexpSubstr => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_substr,
+                        i32,
+                        "regexp_substr"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                DataType::LargeUtf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_substr,
+                        i64,
+                        "regexp_substr"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                other => internal_err!(
+                    "Unsupported data type {other:?} for function regexp_substr"
+                ),
+            })
+        }
+        BuiltinScalarFunction::RegexpExtract => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_extract,
+                        i32,
+                        "regexp_extract"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                DataType::LargeUtf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_extract,
+                        i64,
+                        "regexp_extract"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                other => internal_err!(
+                    "Unsupported data type {other:?} for function regexp_extract"
+                ),
+            })
+        }
+        BuiltinScalarFunction::RegexpSplitToArray => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_split_to_array,
+                        i32,
+                        "regexp_split_to_array"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                DataType::LargeUtf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_split_to_array,
+                        i64,
+                        "regexp_split_to_array"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                other => internal_err!(
+                    "Unsupported data type {other:?} for function regexp_split_to_array"
+                ),
+            })
+        }
+        BuiltinScalarFunction::RegexpMatches => {
+            Arc::new(|args| match args[0].data_type() {
+                DataType::Utf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_matches,
+                        i32,
+                        "regexp_matches"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                DataType::LargeUtf8 => {
+                    let func = invoke_on_array_if_regex_expressions_feature_flag!(
+                        regexp_matches,
+                        i64,
+                        "regexp_matches"
+                    );
+                    make_scalar_function(func)(args)
+                }
+                other => internal_err!(
+                    "Unsupported data type {other:?} for function regexp_matches"
+                ),
+            })
+        }
         BuiltinScalarFunction::RegexpReplace => {
             Arc::new(|args| match args[0].data_type() {
                 DataType::Utf8 => {
@@ -899,7 +1197,7 @@ mod tests {
     use arrow::{
         array::{
             Array, ArrayRef, BinaryArray, BooleanArray, Float32Array, Float64Array,
-            Int32Array, StringArray, UInt64Array,
+            Int32Array, Int64Array, StringArray, UInt64Array,
         },
         datatypes::Field,
         record_batch::RecordBatch,
@@ -1172,6 +1470,30 @@ mod tests {
             Utf8,
             StringArray
         );
+        test_function!(
+            CollationKey,
+            &[lit("Éclair"), lit("nocase")],
+            Ok(Some("éclair")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            CollationKey,
+            &[lit("Éclair"), lit("und-ci")],
+            Ok(Some("eclair")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        test_function!(
+            CollationKey,
+            &[lit(ScalarValue::Utf8(None)), lit("nocase")],
+            Ok(None),
+            &str,
+            Utf8,
+            StringArray
+        );
         test_function!(
             Concat,
             &[lit("aa"), lit("bb"), lit("cc"),],
@@ -1776,6 +2098,79 @@ mod tests {
             Utf8,
             StringArray
         );
+        #[cfg(feature = "regex_expressions")]
+        test_function!(
+            RegexpLike,
+            &[lit("Thomas"), lit(".*thom.*"), lit("i"),],
+            Ok(Some(true)),
+            bool,
+            Boolean,
+            BooleanArray
+        );
+        #[cfg(feature = "regex_expressions")]
+        test_function!(
+            RegexpCount,
+            &[lit("ABCABCAXYaxy"), lit("A."), lit(1_i64), lit("i"),],
+            Ok(Some(4)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        #[cfg(feature = "regex_expressions")]
+        test_function!(
+            RegexpInstr,
+            &[
+                lit("ABCDEFGHI"),
+                lit("(c..)(...)"),
+                lit(1_i64),
+                lit(1_i64),
+                lit(0_i64),
+                lit("i"),
+                lit(2_i64),
+            ],
+            Ok(Some(6)),
+            i64,
+            Int64,
+            Int64Array
+        );
+        #[cfg(feature = "regex_expressions")]
+        test_function!(
+            RegexpSubstr,
+            &[lit("foobarbaz"), lit("b.."), lit(1_i64), lit(2_i64),],
+            Ok(Some("baz")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "regex_expressions")]
+        test_function!(
+            RegexpSubstr,
+            &[lit("foobarbaz"), lit("x.."),],
+            Ok(None),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(feature = "regex_expressions")]
+        test_function!(
+            RegexpExtract,
+            &[lit("100-200"), lit("(\\d+)-(\\d+)"), lit(2_i64),],
+            Ok(Some("200")),
+            &str,
+            Utf8,
+            StringArray
+        );
+        #[cfg(not(feature = "regex_expressions"))]
+        test_function!(
+            RegexpLike,
+            &[lit("Thomas"), lit(".*thom.*"),],
+            internal_err!(
+                "function regexp_like requires compilation with feature flag: regex_expressions."
+            ),
+            bool,
+            Boolean,
+            BooleanArray
+        );
         test_function!(
             Repeat,
             &[lit("Pg"), lit(ScalarValue::Int64(Some(4))),],
@@ -0,0 +1,914 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Higher-order array functions such as `array_transform`, which take a
+//! lambda argument.
+//!
+//! The lambda is evaluated once per batch over the flattened elements of
+//! the list argument, rather than once per row.
+
+use std::any::Any;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter;
+use std::sync::Arc;
+
+use crate::expressions::cast;
+use crate::physical_expr::{down_cast_any_ref, physical_exprs_equal};
+use crate::PhysicalExpr;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, Int64Array, ListArray, UInt32Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::kernels::nullif::nullif;
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{self, prep_null_mask_filter};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use datafusion_common::cast::{as_boolean_array, as_int64_array, as_list_array};
+use datafusion_common::{internal_err, plan_err, DataFusionError, Result};
+use datafusion_expr::{expr_vec_fmt, BuiltinScalarFunction, ColumnarValue};
+
+/// Physical expression of a lambda, evaluated against columns of parameter
+/// values
+#[derive(Debug)]
+pub struct LambdaExpr {
+    params: SchemaRef,
+    body: Arc<dyn PhysicalExpr>,
+    /// The values of the last `captures.len()` parameters, evaluated against
+    /// the input of the higher-order function
+    captures: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl LambdaExpr {
+    /// Create a new lambda with a body planned against the `params` schema
+    pub fn new(params: SchemaRef, body: Arc<dyn PhysicalExpr>) -> Self {
+        Self {
+            params,
+            body,
+            captures: vec![],
+        }
+    }
+
+    /// Binds the last `captures.len()` parameters to `captures`
+    pub fn with_captures(mut self, captures: Vec<Arc<dyn PhysicalExpr>>) -> Self {
+        self.captures = captures;
+        self
+    }
+
+    /// The name and type of each parameter, including the captured ones
+    pub fn params(&self) -> &SchemaRef {
+        &self.params
+    }
+
+    /// The body of the lambda
+    pub fn body(&self) -> &Arc<dyn PhysicalExpr> {
+        &self.body
+    }
+
+    /// The values of the captured parameters
+    pub fn captures(&self) -> &[Arc<dyn PhysicalExpr>] {
+        &self.captures
+    }
+
+    /// The number of parameters the higher-order function passes
+    fn num_declared_params(&self) -> usize {
+        self.params.fields().len() - self.captures.len()
+    }
+
+    /// Evaluates the body once for each row of parameter values, which are
+    /// followed by the values of the captured parameters
+    fn evaluate(&self, values: Vec<ArrayRef>) -> Result<ArrayRef> {
+        let num_rows = values.first().map(|values| values.len()).unwrap_or(0);
+        let values = values
+            .into_iter()
+            .zip(self.params.fields())
+            .map(|(values, field)| {
+                if values.data_type() == field.data_type() {
+                    Ok(values)
+                } else {
+                    Ok(compute::cast(&values, field.data_type())?)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let batch = RecordBatch::try_new_with_options(
+            self.params.clone(),
+            values,
+            &RecordBatchOptions::new().with_row_count(Some(num_rows)),
+        )?;
+        self.body.evaluate(&batch)?.into_array(num_rows)
+    }
+}
+
+impl fmt::Display for LambdaExpr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let params = self
+            .params
+            .fields()
+            .iter()
+            .take(self.num_declared_params())
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        write!(f, "({}) -> {}", params.join(", "), self.body)
+    }
+}
+
+/// Physical expression of a higher-order function such as
+/// `array_transform(array, x -> x + 1)`
+pub struct HigherOrderFunctionExpr {
+    fun: BuiltinScalarFunction,
+    /// The arguments other than the lambda, starting with the list
+    args: Vec<Arc<dyn PhysicalExpr>>,
+    lambda: Arc<LambdaExpr>,
+    return_type: DataType,
+}
+
+impl Debug for HigherOrderFunctionExpr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("HigherOrderFunctionExpr")
+            .field("fun", &self.fun)
+            .field("args", &self.args)
+            .field("lambda", &self.lambda)
+            .field("return_type", &self.return_type)
+            .finish()
+    }
+}
+
+impl HigherOrderFunctionExpr {
+    /// Create a new higher-order function expression, checking the number
+    /// of arguments and lambda parameters of `fun`. The lambda body is cast
+    /// to the type `fun` requires, e.g. boolean for `array_filter`.
+    pub fn try_new(
+        fun: BuiltinScalarFunction,
+        args: Vec<Arc<dyn PhysicalExpr>>,
+        lambda: LambdaExpr,
+        return_type: DataType,
+    ) -> Result<Self> {
+        let (num_args, num_params, body_type) = match fun {
+            BuiltinScalarFunction::ArrayTransform => (1, 1, None),
+            BuiltinScalarFunction::ArrayFilter
+            | BuiltinScalarFunction::ArrayAnyMatch
+            | BuiltinScalarFunction::ArrayAllMatch => (1, 1, Some(DataType::Boolean)),
+            // the accumulator keeps the type of the initial value
+            BuiltinScalarFunction::ArrayReduce => (
+                2,
+                2,
+                lambda
+                    .params
+                    .fields()
+                    .first()
+                    .map(|field| field.data_type().clone()),
+            ),
+            // comparators return a negative, zero or positive integer
+            BuiltinScalarFunction::ArraySort => (1, 2, Some(DataType::Int64)),
+            _ => return plan_err!("The {fun} function does not accept a lambda"),
+        };
+        if args.len() != num_args {
+            return plan_err!(
+                "The {fun} function expects {} arguments, got {}",
+                num_args + 1,
+                args.len() + 1
+            );
+        }
+        let params = lambda.num_declared_params();
+        if params != num_params {
+            return plan_err!(
+                "The lambda of the {fun} function expects {num_params} parameters, got {params}"
+            );
+        }
+
+        let lambda = match body_type {
+            Some(body_type) if lambda.body.data_type(&lambda.params)? != body_type => {
+                let body = cast(lambda.body.clone(), &lambda.params, body_type)?;
+                LambdaExpr { body, ..lambda }
+            }
+            _ => lambda,
+        };
+        Ok(Self {
+            fun,
+            args,
+            lambda: Arc::new(lambda),
+            return_type,
+        })
+    }
+
+    pub fn fun(&self) -> &BuiltinScalarFunction {
+        &self.fun
+    }
+
+    pub fn args(&self) -> &[Arc<dyn PhysicalExpr>] {
+        &self.args
+    }
+
+    pub fn lambda(&self) -> &LambdaExpr {
+        &self.lambda
+    }
+
+    pub fn return_type(&self) -> &DataType {
+        &self.return_type
+    }
+}
+
+impl fmt::Display for HigherOrderFunctionExpr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}({}, {})",
+            self.fun,
+            expr_vec_fmt!(self.args),
+            self.lambda
+        )
+    }
+}
+
+impl PhysicalExpr for HigherOrderFunctionExpr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.evaluate(batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<_>>>()?;
+        let captures = self
+            .lambda
+            .captures
+            .iter()
+            .map(|capture| capture.evaluate(batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<_>>>()?;
+        let list = as_list_array(&args[0])?;
+        let lambda = &self.lambda;
+        let result = match self.fun {
+            BuiltinScalarFunction::ArrayTransform => {
+                array_transform(list, lambda, &captures)
+            }
+            BuiltinScalarFunction::ArrayFilter => array_filter(list, lambda, &captures),
+            BuiltinScalarFunction::ArrayReduce => {
+                array_reduce(list, &args[1], lambda, &captures)
+            }
+            BuiltinScalarFunction::ArrayAnyMatch => {
+                array_match(list, lambda, &captures, true)
+            }
+            BuiltinScalarFunction::ArrayAllMatch => {
+                array_match(list, lambda, &captures, false)
+            }
+            BuiltinScalarFunction::ArraySort => array_sort_by(list, lambda, &captures),
+            fun => internal_err!("{fun} is not a higher-order function"),
+        }?;
+        Ok(ColumnarValue::Array(result))
+    }
+
+    /// The arguments followed by the captured values of the lambda, which
+    /// are both evaluated against the input
+    fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        let mut children = self.args.clone();
+        children.extend(self.lambda.captures.iter().cloned());
+        children
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        let captures = children.split_off(self.args.len());
+        let lambda =
+            LambdaExpr::new(self.lambda.params.clone(), self.lambda.body.clone())
+                .with_captures(captures);
+        Ok(Arc::new(HigherOrderFunctionExpr {
+            fun: self.fun,
+            args: children,
+            lambda: Arc::new(lambda),
+            return_type: self.return_type.clone(),
+        }))
+    }
+
+    fn dyn_hash(&self, state: &mut dyn Hasher) {
+        let mut s = state;
+        self.fun.hash(&mut s);
+        self.args.hash(&mut s);
+        self.lambda.body.hash(&mut s);
+        self.lambda.captures.hash(&mut s);
+        self.return_type.hash(&mut s);
+    }
+}
+
+impl PartialEq<dyn Any> for HigherOrderFunctionExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                self.fun == x.fun
+                    && physical_exprs_equal(&self.args, &x.args)
+                    && self.lambda.params == x.lambda.params
+                    && self.lambda.body.eq(&x.lambda.body)
+                    && physical_exprs_equal(&self.lambda.captures, &x.lambda.captures)
+                    && self.return_type == x.return_type
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// Returns the elements of all the lists, and the offsets of each list
+/// into them
+fn flatten(list: &ListArray) -> (ArrayRef, OffsetBuffer<i32>) {
+    let offsets = list.value_offsets();
+    let first = offsets[0];
+    let last = offsets[offsets.len() - 1];
+    let values = list.values().slice(first as usize, (last - first) as usize);
+    let offsets = offsets
+        .iter()
+        .map(|offset| offset - first)
+        .collect::<Vec<_>>();
+    (values, OffsetBuffer::new(offsets.into()))
+}
+
+fn list_field(list: &ListArray) -> Result<Arc<Field>> {
+    match list.data_type() {
+        DataType::List(field) => Ok(field.clone()),
+        other => internal_err!("Expected a list, got {other}"),
+    }
+}
+
+/// The index of the list of each element
+fn element_rows(offsets: &OffsetBuffer<i32>) -> UInt32Array {
+    UInt32Array::from_iter_values(offsets.windows(2).enumerate().flat_map(
+        |(row, window)| iter::repeat(row as u32).take((window[1] - window[0]) as usize),
+    ))
+}
+
+/// Appends the captured values to the parameter values of the elements,
+/// repeating the value of each row for every element of its list
+fn with_captures(
+    mut values: Vec<ArrayRef>,
+    captures: &[ArrayRef],
+    offsets: &OffsetBuffer<i32>,
+) -> Result<Vec<ArrayRef>> {
+    if !captures.is_empty() {
+        let rows = element_rows(offsets);
+        for capture in captures {
+            values.push(compute::take(capture, &rows, None)?);
+        }
+    }
+    Ok(values)
+}
+
+/// Evaluates a boolean lambda over the elements, treating nulls as false
+fn evaluate_predicate(
+    lambda: &LambdaExpr,
+    values: &ArrayRef,
+    captures: &[ArrayRef],
+    offsets: &OffsetBuffer<i32>,
+) -> Result<BooleanArray> {
+    let predicate =
+        lambda.evaluate(with_captures(vec![values.clone()], captures, offsets)?)?;
+    let predicate = as_boolean_array(&predicate)?;
+    Ok(match predicate.null_count() {
+        0 => predicate.clone(),
+        _ => prep_null_mask_filter(predicate),
+    })
+}
+
+/// array_transform SQL function
+fn array_transform(
+    list: &ListArray,
+    lambda: &LambdaExpr,
+    captures: &[ArrayRef],
+) -> Result<ArrayRef> {
+    let (values, offsets) = flatten(list);
+    let values = lambda.evaluate(with_captures(vec![values], captures, &offsets)?)?;
+    let field = Arc::new(Field::new("item", values.data_type().clone(), true));
+    Ok(Arc::new(ListArray::try_new(
+        field,
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+/// array_filter SQL function
+fn array_filter(
+    list: &ListArray,
+    lambda: &LambdaExpr,
+    captures: &[ArrayRef],
+) -> Result<ArrayRef> {
+    let (values, offsets) = flatten(list);
+    let predicate = evaluate_predicate(lambda, &values, captures, &offsets)?;
+
+    let mut new_offsets = Vec::with_capacity(offsets.len());
+    new_offsets.push(0);
+    let mut kept = 0;
+    for window in offsets.windows(2) {
+        kept += (window[0]..window[1])
+            .filter(|&i| predicate.value(i as usize))
+            .count() as i32;
+        new_offsets.push(kept);
+    }
+    let values = compute::filter(&values, &predicate)?;
+    Ok(Arc::new(ListArray::try_new(
+        list_field(list)?,
+        OffsetBuffer::new(new_offsets.into()),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+/// array_any_match and array_all_match SQL functions. Like `ANY` and `ALL`,
+/// the result is null rather than false or true when the lambda returns
+/// null for some element and the other elements do not decide the result.
+fn array_match(
+    list: &ListArray,
+    lambda: &LambdaExpr,
+    captures: &[ArrayRef],
+    any: bool,
+) -> Result<ArrayRef> {
+    let (values, offsets) = flatten(list);
+    let predicate = lambda.evaluate(with_captures(vec![values], captures, &offsets)?)?;
+    let predicate = as_boolean_array(&predicate)?;
+
+    let result = offsets
+        .windows(2)
+        .enumerate()
+        .map(|(row, window)| {
+            if list.is_null(row) {
+                return None;
+            }
+            let mut saw_null = false;
+            for i in window[0] as usize..window[1] as usize {
+                if predicate.is_null(i) {
+                    saw_null = true;
+                } else if predicate.value(i) == any {
+                    return Some(any);
+                }
+            }
+            (!saw_null).then_some(!any)
+        })
+        .collect::<BooleanArray>();
+    Ok(Arc::new(result))
+}
+
+/// array_reduce SQL function.
+///
+/// Each step folds the next element of every list into the accumulators
+/// with a single evaluation of the lambda, so the lambda is evaluated as
+/// many times as the length of the longest list.
+fn array_reduce(
+    list: &ListArray,
+    initial: &ArrayRef,
+    lambda: &LambdaExpr,
+    captures: &[ArrayRef],
+) -> Result<ArrayRef> {
+    let acc_type = lambda.params.field(0).data_type();
+    let mut acc = if initial.data_type() == acc_type {
+        initial.clone()
+    } else {
+        compute::cast(initial, acc_type)?
+    };
+
+    let (values, offsets) = flatten(list);
+    let max_len = offsets
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .max()
+        .unwrap_or(0);
+    for step in 0..max_len {
+        // the element at `step` of each list, or null if the list is shorter
+        let indices = offsets
+            .windows(2)
+            .map(|window| {
+                (window[0] + step < window[1]).then_some((window[0] + step) as u32)
+            })
+            .collect::<UInt32Array>();
+        let elements = compute::take(&values, &indices, None)?;
+        // there is one accumulator per row, like the captured values
+        let mut params = vec![acc.clone(), elements];
+        params.extend(captures.iter().cloned());
+        let next = lambda.evaluate(params)?;
+        // keep the accumulators of the lists that are exhausted
+        let active = compute::is_not_null(&indices)?;
+        acc = zip(&active, next.as_ref(), acc.as_ref())?;
+    }
+
+    match list.null_count() {
+        0 => Ok(acc),
+        _ => Ok(nullif(acc.as_ref(), &compute::is_null(list)?)?),
+    }
+}
+
+/// Calls `f(left, mid, end)` for each pair of adjacent runs `left..mid` and
+/// `mid..end` of `width` elements in every list, in order
+fn for_each_run_pair(
+    offsets: &OffsetBuffer<i32>,
+    width: usize,
+    mut f: impl FnMut(usize, usize, usize),
+) {
+    for window in offsets.windows(2) {
+        let (start, end) = (window[0] as usize, window[1] as usize);
+        for left in (start..end).step_by(2 * width) {
+            let mid = (left + width).min(end);
+            f(left, mid, (mid + width).min(end));
+        }
+    }
+}
+
+/// array_sort SQL function with a comparator lambda `(a, b) -> int`, which
+/// returns a negative number when `a` sorts before `b`, zero when they are
+/// equal and a positive number otherwise.
+///
+/// All the lists are merge sorted together, bottom-up. Each pass merges the
+/// adjacent runs of every list by placing each element of a left run with a
+/// binary search of its right run, and every step of the binary searches
+/// evaluates the comparator once, for one pair per element being placed.
+/// Sorting lists of up to `n` elements thus takes `log n` passes of at most
+/// `log n + 1` evaluations. The sort is stable, and a null comparison
+/// counts as equal.
+fn array_sort_by(
+    list: &ListArray,
+    lambda: &LambdaExpr,
+    captures: &[ArrayRef],
+) -> Result<ArrayRef> {
+    let (values, offsets) = flatten(list);
+    let rows = element_rows(&offsets);
+    let max_len = offsets
+        .windows(2)
+        .map(|window| (window[1] - window[0]) as usize)
+        .max()
+        .unwrap_or(0);
+
+    // the index into `values` of the element at each sorted position
+    let mut order = (0..values.len() as u32).collect::<Vec<_>>();
+    let mut width = 1;
+    while width < max_len {
+        // for each element of a left run, the range of its right run in
+        // which the elements sorting before it are searched for
+        let mut placed = vec![];
+        let mut lo = vec![];
+        let mut hi = vec![];
+        for_each_run_pair(&offsets, width, |left, mid, end| {
+            placed.extend(left..mid);
+            lo.extend(iter::repeat(mid).take(mid - left));
+            hi.extend(iter::repeat(end).take(mid - left));
+        });
+
+        loop {
+            let searching = (0..placed.len())
+                .filter(|&k| lo[k] < hi[k])
+                .collect::<Vec<_>>();
+            if searching.is_empty() {
+                break;
+            }
+            let probes = searching
+                .iter()
+                .map(|&k| (lo[k] + hi[k]) / 2)
+                .collect::<Vec<_>>();
+            let left = UInt32Array::from_iter_values(
+                searching.iter().map(|&k| order[placed[k]]),
+            );
+            let right = UInt32Array::from_iter_values(probes.iter().map(|&p| order[p]));
+            let mut params = vec![
+                compute::take(&values, &left, None)?,
+                compute::take(&values, &right, None)?,
+            ];
+            if !captures.is_empty() {
+                // both elements of a pair are in the same list
+                let pair_rows = UInt32Array::from_iter_values(
+                    left.values().iter().map(|&i| rows.value(i as usize)),
+                );
+                for capture in captures {
+                    params.push(compute::take(capture, &pair_rows, None)?);
+                }
+            }
+            let comparisons = lambda.evaluate(params)?;
+            let comparisons: &Int64Array = as_int64_array(&comparisons)?;
+            for (i, (&k, &probe)) in searching.iter().zip(&probes).enumerate() {
+                if comparisons.is_valid(i) && comparisons.value(i) > 0 {
+                    lo[k] = probe + 1;
+                } else {
+                    hi[k] = probe;
+                }
+            }
+        }
+
+        // each element of a left run follows the elements of its right run
+        // before `lo`. A comparator that is not a total order may place an
+        // element before the elements placed before the previous one, which
+        // then keep their order.
+        let mut merged = Vec::with_capacity(order.len());
+        let mut k = 0;
+        for_each_run_pair(&offsets, width, |left, mid, end| {
+            let mut right = mid;
+            for i in left..mid {
+                while right < lo[k] {
+                    merged.push(order[right]);
+                    right += 1;
+                }
+                merged.push(order[i]);
+                k += 1;
+            }
+            merged.extend_from_slice(&order[right..end]);
+        });
+        order = merged;
+        width *= 2;
+    }
+
+    let values = compute::take(&values, &UInt32Array::from(order), None)?;
+    Ok(Arc::new(ListArray::try_new(
+        list_field(list)?,
+        offsets,
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{binary, col, lit};
+    use arrow::datatypes::Int64Type;
+    use datafusion_common::ScalarValue;
+    use datafusion_expr::Operator;
+
+    /// `[[3, 1, 2], NULL, [], [4, NULL]]`
+    fn batch() -> Result<RecordBatch> {
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(3), Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), None]),
+        ]);
+        let schema = Schema::new(vec![Field::new("a", list.data_type().clone(), true)]);
+        Ok(RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(list)],
+        )?)
+    }
+
+    /// Evaluates `fun(a, [initial,] (params) -> op(params))` over [`batch`]
+    fn evaluate(
+        fun: BuiltinScalarFunction,
+        initial: Option<i64>,
+        params: &[&str],
+        op: Operator,
+        right: Option<i64>,
+    ) -> Result<ArrayRef> {
+        let batch = batch()?;
+        let params = Arc::new(Schema::new(
+            params
+                .iter()
+                .map(|name| Field::new(*name, DataType::Int64, true))
+                .collect::<Vec<_>>(),
+        ));
+        let left = col(params.field(0).name(), &params)?;
+        let right = match right {
+            Some(value) => lit(value),
+            None => col(params.field(1).name(), &params)?,
+        };
+        let body = binary(left, op, right, &params)?;
+
+        let mut args = vec![col("a", &batch.schema())?];
+        args.extend(initial.map(|value| lit(ScalarValue::Int64(Some(value)))));
+        let expr = HigherOrderFunctionExpr::try_new(
+            fun,
+            args,
+            LambdaExpr::new(params, body),
+            batch.schema().field(0).data_type().clone(),
+        )?;
+        expr.evaluate(&batch)?.into_array(batch.num_rows())
+    }
+
+    fn list(values: Vec<Option<Vec<Option<i64>>>>) -> ListArray {
+        ListArray::from_iter_primitive::<Int64Type, _, _>(values)
+    }
+
+    #[test]
+    fn transform() -> Result<()> {
+        let result = evaluate(
+            BuiltinScalarFunction::ArrayTransform,
+            None,
+            &["x"],
+            Operator::Multiply,
+            Some(2),
+        )?;
+        let expected = list(vec![
+            Some(vec![Some(6), Some(2), Some(4)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(8), None]),
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn filter() -> Result<()> {
+        let result = evaluate(
+            BuiltinScalarFunction::ArrayFilter,
+            None,
+            &["x"],
+            Operator::Gt,
+            Some(1),
+        )?;
+        let expected = list(vec![
+            Some(vec![Some(3), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn reduce() -> Result<()> {
+        let result = evaluate(
+            BuiltinScalarFunction::ArrayReduce,
+            Some(10),
+            &["acc", "x"],
+            Operator::Plus,
+            None,
+        )?;
+        let expected = Int64Array::from(vec![Some(16), None, Some(10), None]);
+        assert_eq!(as_int64_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn any_and_all_match() -> Result<()> {
+        let result = evaluate(
+            BuiltinScalarFunction::ArrayAnyMatch,
+            None,
+            &["x"],
+            Operator::Gt,
+            Some(3),
+        )?;
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        let result = evaluate(
+            BuiltinScalarFunction::ArrayAllMatch,
+            None,
+            &["x"],
+            Operator::Gt,
+            Some(0),
+        )?;
+        let expected = BooleanArray::from(vec![Some(true), None, Some(true), None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sort_with_comparator() -> Result<()> {
+        // (a, b) -> a - b sorts in ascending order, and a null comparison
+        // keeps the original order
+        let result = evaluate(
+            BuiltinScalarFunction::ArraySort,
+            None,
+            &["a", "b"],
+            Operator::Minus,
+            None,
+        )?;
+        let expected = list(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), None]),
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sort_long_lists_stably() -> Result<()> {
+        // (a, b) -> a / 10 - b / 10 compares the tens only, so the order of
+        // the elements with the same tens must be kept
+        let lists = (0..4)
+            .map(|row| {
+                let len = [1000, 0, 37, 2][row];
+                Some(
+                    (0..len)
+                        .map(|i| Some((i * 7919 + row as i64) % 100))
+                        .collect(),
+                )
+            })
+            .collect::<Vec<Option<Vec<Option<i64>>>>>();
+        let list_array = list(lists.clone());
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            list_array.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(list_array)])?;
+
+        let params = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let tens =
+            |name| binary(col(name, &params)?, Operator::Divide, lit(10i64), &params);
+        let body = binary(tens("a")?, Operator::Minus, tens("b")?, &params)?;
+        let expr = HigherOrderFunctionExpr::try_new(
+            BuiltinScalarFunction::ArraySort,
+            vec![col("a", &schema)?],
+            LambdaExpr::new(params, body),
+            schema.field(0).data_type().clone(),
+        )?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+
+        let expected = list(
+            lists
+                .into_iter()
+                .map(|values| {
+                    values.map(|mut values| {
+                        values.sort_by_key(|value| value.unwrap() / 10);
+                        values
+                    })
+                })
+                .collect(),
+        );
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn capture_columns() -> Result<()> {
+        // array_filter(a, x -> x > t), where t is a column of the input
+        let batch = batch()?;
+        let thresholds = Int64Array::from(vec![Some(1), Some(0), Some(0), None]);
+        let schema = Arc::new(Schema::new(vec![
+            batch.schema().field(0).clone(),
+            Field::new("t", DataType::Int64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![batch.column(0).clone(), Arc::new(thresholds)],
+        )?;
+
+        let params = Arc::new(Schema::new(vec![
+            Field::new("x", DataType::Int64, true),
+            Field::new("t", DataType::Int64, true),
+        ]));
+        let body = binary(
+            col("x", &params)?,
+            Operator::Gt,
+            col("t", &params)?,
+            &params,
+        )?;
+        let expr = HigherOrderFunctionExpr::try_new(
+            BuiltinScalarFunction::ArrayFilter,
+            vec![col("a", &schema)?],
+            LambdaExpr::new(params, body).with_captures(vec![col("t", &schema)?]),
+            schema.field(0).data_type().clone(),
+        )?;
+        assert_eq!(expr.to_string(), "array_filter(a@0, (x) -> x@0 > t@1)");
+        assert_eq!(expr.children().len(), 2);
+
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows())?;
+        let expected = list(vec![
+            Some(vec![Some(3), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![]),
+        ]);
+        assert_eq!(as_list_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn lambda_arity() -> Result<()> {
+        let err = evaluate(
+            BuiltinScalarFunction::ArrayTransform,
+            None,
+            &["x", "y"],
+            Operator::Plus,
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(
+            "The lambda of the array_transform function expects 1 parameters, got 2"
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,399 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! JSON expressions operating on JSON strings
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Float64Array, Int64Array, ListBuilder, StringArray,
+    StringBuilder, UInt64Array,
+};
+use arrow::buffer::Buffer;
+use arrow::compute::cast;
+use arrow::datatypes::DataType;
+use datafusion_common::cast::{as_int64_array, as_string_array};
+use datafusion_common::{exec_err, DataFusionError, Result};
+use serde_json::Value;
+
+/// An argument of a JSON function selecting a step of the path into a
+/// JSON value
+enum PathArg {
+    /// The key of an object, or the index of an array if it is an integer
+    Key(StringArray),
+    /// The index of an array, from the end if negative
+    Index(Int64Array),
+}
+
+impl PathArg {
+    fn try_new(array: &ArrayRef, name: &str) -> Result<Self> {
+        match array.data_type() {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Null => {
+                let array = cast(array, &DataType::Utf8)?;
+                Ok(Self::Key(as_string_array(&array)?.clone()))
+            }
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64 => {
+                let array = cast(array, &DataType::Int64)?;
+                Ok(Self::Index(as_int64_array(&array)?.clone()))
+            }
+            other => exec_err!(
+                "{name} path elements must be strings or integers, got {other:?}"
+            ),
+        }
+    }
+
+    /// Returns the value selected by this step in row `row`
+    fn step<'a>(&self, value: &'a Value, row: usize) -> Option<&'a Value> {
+        match (self, value) {
+            (Self::Key(keys), _) if keys.is_null(row) => None,
+            (Self::Key(keys), Value::Object(object)) => object.get(keys.value(row)),
+            (Self::Key(keys), Value::Array(array)) => {
+                let index = keys.value(row).parse().ok()?;
+                array_element(array, index)
+            }
+            (Self::Index(indices), Value::Array(array)) if indices.is_valid(row) => {
+                array_element(array, indices.value(row))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn array_element(array: &[Value], index: i64) -> Option<&Value> {
+    let index = if index < 0 {
+        array.len().checked_sub(index.unsigned_abs() as usize)?
+    } else {
+        index as usize
+    };
+    array.get(index)
+}
+
+/// The number of JSON string arrays whose parsed values are kept per thread
+const PARSED_JSON_CACHE_SIZE: usize = 4;
+
+/// The parsed values of each row of a JSON string array, `None` for rows
+/// that are null or not valid JSON
+type ParsedJson = Arc<Vec<Option<Value>>>;
+
+thread_local! {
+    /// The JSON string arrays most recently parsed on this thread, so that
+    /// several JSON functions of the same column of a batch, such as
+    /// `payload->>'type'` and `payload->'user'`, parse each value once.
+    /// Keeping the arrays alive guarantees that their buffers are not
+    /// reused while they are cached.
+    static PARSED_JSON: RefCell<VecDeque<(ArrayRef, ParsedJson)>> =
+        RefCell::new(VecDeque::with_capacity(PARSED_JSON_CACHE_SIZE));
+}
+
+/// Returns true if `a` and `b` are the same slice of the same buffers
+fn same_array(a: &dyn Array, b: &dyn Array) -> bool {
+    let (a, b) = (a.to_data(), b.to_data());
+    a.data_type() == b.data_type()
+        && a.len() == b.len()
+        && a.offset() == b.offset()
+        && a.nulls() == b.nulls()
+        && a.buffers()
+            .iter()
+            .map(Buffer::as_ptr)
+            .eq(b.buffers().iter().map(Buffer::as_ptr))
+}
+
+/// Parses the JSON string of each row of `json`, reusing the values parsed
+/// by a previous call on the same array
+fn parse_json(json: &ArrayRef, name: &str) -> Result<ParsedJson> {
+    let cached = PARSED_JSON.with(|parsed| {
+        parsed
+            .borrow()
+            .iter()
+            .find(|(array, _)| same_array(array.as_ref(), json.as_ref()))
+            .map(|(_, values)| Arc::clone(values))
+    });
+    if let Some(values) = cached {
+        return Ok(values);
+    }
+
+    let strings = match json.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Null => {
+            cast(json, &DataType::Utf8)?
+        }
+        other => return exec_err!("{name} requires a JSON string, got {other:?}"),
+    };
+    let values: ParsedJson = Arc::new(
+        as_string_array(&strings)?
+            .iter()
+            .map(|s| serde_json::from_str(s?).ok())
+            .collect(),
+    );
+
+    PARSED_JSON.with(|parsed| {
+        let mut parsed = parsed.borrow_mut();
+        if parsed.len() == PARSED_JSON_CACHE_SIZE {
+            parsed.pop_front();
+        }
+        parsed.push_back((Arc::clone(json), Arc::clone(&values)));
+    });
+    Ok(values)
+}
+
+/// Calls `f` with the value at the path given by the remaining arguments
+/// in each row, or `None` if there is no value at the path. Rows that are
+/// null or not valid JSON are null.
+fn map_json_paths<T>(
+    args: &[ArrayRef],
+    name: &str,
+    mut f: impl FnMut(Option<&Value>) -> Option<T>,
+) -> Result<Vec<Option<T>>> {
+    let Some((json, path)) = args.split_first() else {
+        return exec_err!("{name} requires a JSON string argument");
+    };
+    let values = parse_json(json, name)?;
+    let path = path
+        .iter()
+        .map(|arg| PathArg::try_new(arg, name))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(values
+        .iter()
+        .enumerate()
+        .map(|(row, value)| {
+            let selected = path
+                .iter()
+                .try_fold(value.as_ref()?, |value, arg| arg.step(value, row));
+            f(selected)
+        })
+        .collect())
+}
+
+/// json_get(json, path...) returns the value at the path, as JSON
+pub fn json_get(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_get", |value| Some(value?.to_string()))?;
+    Ok(Arc::new(StringArray::from(values)))
+}
+
+/// json_get_str(json, path...) returns the value at the path, as text.
+/// Strings are returned without quotes, and JSON null as null.
+pub fn json_get_str(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_get_str", |value| match value? {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    })?;
+    Ok(Arc::new(StringArray::from(values)))
+}
+
+/// json_get_int(json, path...) returns the integer at the path
+pub fn json_get_int(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_get_int", |value| value?.as_i64())?;
+    Ok(Arc::new(Int64Array::from(values)))
+}
+
+/// json_get_float(json, path...) returns the number at the path
+pub fn json_get_float(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_get_float", |value| value?.as_f64())?;
+    Ok(Arc::new(Float64Array::from(values)))
+}
+
+/// json_get_bool(json, path...) returns the boolean at the path
+pub fn json_get_bool(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_get_bool", |value| value?.as_bool())?;
+    Ok(Arc::new(BooleanArray::from(values)))
+}
+
+/// json_contains(json, path...) returns true if there is a value at the path
+pub fn json_contains(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_contains", |value| Some(value.is_some()))?;
+    Ok(Arc::new(BooleanArray::from(values)))
+}
+
+/// json_length(json, path...) returns the number of elements of the array
+/// or keys of the object at the path
+pub fn json_length(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_length", |value| match value? {
+        Value::Array(array) => Some(array.len() as u64),
+        Value::Object(object) => Some(object.len() as u64),
+        _ => None,
+    })?;
+    Ok(Arc::new(UInt64Array::from(values)))
+}
+
+fn string_lists(values: Vec<Option<Vec<String>>>) -> ArrayRef {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for value in values {
+        match value {
+            Some(strings) => {
+                for s in strings {
+                    builder.values().append_value(s);
+                }
+                builder.append(true);
+            }
+            None => builder.append(false),
+        }
+    }
+    Arc::new(builder.finish())
+}
+
+/// json_keys(json, path...) returns the keys of the object at the path
+pub fn json_keys(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_keys", |value| match value? {
+        Value::Object(object) => Some(object.keys().cloned().collect()),
+        _ => None,
+    })?;
+    Ok(string_lists(values))
+}
+
+/// json_array_elements(json, path...) returns the elements of the array at
+/// the path, as JSON
+pub fn json_array_elements(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let values = map_json_paths(args, "json_array_elements", |value| match value? {
+        Value::Array(array) => Some(array.iter().map(Value::to_string).collect()),
+        _ => None,
+    })?;
+    Ok(string_lists(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion_common::cast::{
+        as_boolean_array, as_float64_array, as_list_array, as_uint64_array,
+    };
+
+    fn json() -> ArrayRef {
+        Arc::new(StringArray::from(vec![
+            Some(r#"{"type": "click", "n": 3, "tags": ["a", "b"], "ok": true}"#),
+            Some(
+                r#"{"type": null, "n": 1.5, "tags": [], "nested": {"x": [1, {"y": "z"}]}}"#,
+            ),
+            Some("not json"),
+            None,
+        ]))
+    }
+
+    fn key(key: &str) -> ArrayRef {
+        Arc::new(StringArray::from(vec![key; 4]))
+    }
+
+    fn index(index: i64) -> ArrayRef {
+        Arc::new(Int64Array::from(vec![index; 4]))
+    }
+
+    #[test]
+    fn get() -> Result<()> {
+        let result = json_get(&[json(), key("tags")])?;
+        let expected =
+            StringArray::from(vec![Some(r#"["a","b"]"#), Some("[]"), None, None]);
+        assert_eq!(as_string_array(&result)?, &expected);
+
+        let result = json_get_str(&[json(), key("type")])?;
+        let expected = StringArray::from(vec![Some("click"), None, None, None]);
+        assert_eq!(as_string_array(&result)?, &expected);
+
+        let result =
+            json_get_str(&[json(), key("nested"), key("x"), index(-1), key("y")])?;
+        let expected = StringArray::from(vec![None, Some("z"), None, None]);
+        assert_eq!(as_string_array(&result)?, &expected);
+
+        // string keys index arrays
+        let result = json_get_str(&[json(), key("tags"), key("1")])?;
+        let expected = StringArray::from(vec![Some("b"), None, None, None]);
+        assert_eq!(as_string_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn get_typed() -> Result<()> {
+        let result = json_get_int(&[json(), key("n")])?;
+        let expected = Int64Array::from(vec![Some(3), None, None, None]);
+        assert_eq!(as_int64_array(&result)?, &expected);
+
+        let result = json_get_float(&[json(), key("n")])?;
+        let expected = Float64Array::from(vec![Some(3.0), Some(1.5), None, None]);
+        assert_eq!(as_float64_array(&result)?, &expected);
+
+        let result = json_get_bool(&[json(), key("ok")])?;
+        let expected = BooleanArray::from(vec![Some(true), None, None, None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn contains_and_length() -> Result<()> {
+        let result = json_contains(&[json(), key("nested")])?;
+        let expected = BooleanArray::from(vec![Some(false), Some(true), None, None]);
+        assert_eq!(as_boolean_array(&result)?, &expected);
+
+        let result = json_length(&[json(), key("tags")])?;
+        let expected = UInt64Array::from(vec![Some(2), Some(0), None, None]);
+        assert_eq!(as_uint64_array(&result)?, &expected);
+
+        let result = json_length(&[json()])?;
+        let expected = UInt64Array::from(vec![Some(4), Some(4), None, None]);
+        assert_eq!(as_uint64_array(&result)?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_once_per_array() -> Result<()> {
+        let array = json();
+        let first = parse_json(&array, "json_get")?;
+        let second = parse_json(&array, "json_get_str")?;
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // a slice of the same buffers is a different array
+        let sliced = parse_json(&array.slice(1, 2), "json_get")?;
+        assert!(!Arc::ptr_eq(&first, &sliced));
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced[0], first[1]);
+        assert_eq!(sliced[1], None);
+
+        // an array with the same contents is parsed again
+        let other = parse_json(&json(), "json_get")?;
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(first, other);
+        Ok(())
+    }
+
+    #[test]
+    fn keys_and_elements() -> Result<()> {
+        let result = json_keys(&[json(), key("nested")])?;
+        let result = as_list_array(&result)?;
+        assert_eq!(result.len(), 4);
+        assert!(result.is_null(0));
+        let keys = result.value(1);
+        assert_eq!(as_string_array(&keys)?, &StringArray::from(vec!["x"]));
+
+        let result = json_array_elements(&[json(), key("tags")])?;
+        let result = as_list_array(&result)?;
+        let elements = result.value(0);
+        assert_eq!(
+            as_string_array(&elements)?,
+            &StringArray::from(vec![r#""a""#, r#""b""#])
+        );
+        assert_eq!(result.value(1).len(), 0);
+        assert!(result.is_null(2));
+        Ok(())
+    }
+}
@@ -28,7 +28,11 @@ pub mod equivalence;
 pub mod execution_props;
 pub mod expressions;
 pub mod functions;
+mod higher_order_function;
 pub mod intervals;
+#[cfg(feature = "json_expressions")]
+pub mod json_expressions;
+pub mod map_expressions;
 pub mod math_expressions;
 mod partitioning;
 mod physical_expr;
@@ -54,12 +58,13 @@ pub use aggregate::groups_accumulator::{
 pub use aggregate::AggregateExpr;
 pub use analysis::{analyze, AnalysisContext, ExprBoundaries};
 pub use equivalence::EquivalenceProperties;
+pub use higher_order_function::{HigherOrderFunctionExpr, LambdaExpr};
 pub use partitioning::{Distribution, Partitioning};
 pub use physical_expr::{
     physical_exprs_bag_equal, physical_exprs_contains, physical_exprs_equal,
     PhysicalExpr, PhysicalExprRef,
 };
-pub use planner::create_physical_expr;
+pub use planner::{create_physical_expr, create_physical_expr_with_overflow_mode};
 pub use scalar_function::ScalarFunctionExpr;
 pub use sort_expr::{
     LexOrdering, LexOrderingRef, LexRequirement, LexRequirementRef, PhysicalSortExpr,
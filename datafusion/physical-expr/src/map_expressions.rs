@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Map expressions
+
+use std::sync::Arc;
+
+use arrow::array::*;
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::kernels::cmp::eq;
+use arrow::compute::{cast, take};
+use arrow::datatypes::{DataType, Field};
+
+use datafusion_common::cast::{as_list_array, as_map_array};
+use datafusion_common::utils::map_entries_field;
+use datafusion_common::{exec_err, DataFusionError, Result};
+
+/// map(keys, values) builds a map from a list of keys and a list of values
+/// of the same length. The map is null if either list is null.
+pub fn make_map(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 2 {
+        return exec_err!("map expects two arguments");
+    }
+    let keys = as_list_array(&args[0])?;
+    let values = as_list_array(&args[1])?;
+
+    let mut key_indices = vec![];
+    let mut value_indices = vec![];
+    let mut offsets = vec![0i32];
+    let mut nulls = Vec::with_capacity(keys.len());
+    for row in 0..keys.len() {
+        let valid = keys.is_valid(row) && values.is_valid(row);
+        if valid {
+            let key_range = keys.value_offsets()[row]..keys.value_offsets()[row + 1];
+            let value_range =
+                values.value_offsets()[row]..values.value_offsets()[row + 1];
+            if key_range.len() != value_range.len() {
+                return exec_err!(
+                    "map requires the same number of keys and values, got {} keys and {} values",
+                    key_range.len(),
+                    value_range.len()
+                );
+            }
+            key_indices.extend(key_range.map(|i| i as u64));
+            value_indices.extend(value_range.map(|i| i as u64));
+        }
+        offsets.push(key_indices.len() as i32);
+        nulls.push(valid);
+    }
+
+    let map_keys = take(keys.values(), &UInt64Array::from(key_indices), None)?;
+    if map_keys.null_count() > 0 {
+        return exec_err!("map keys must not be null");
+    }
+    let map_values = take(values.values(), &UInt64Array::from(value_indices), None)?;
+
+    let entries_field =
+        map_entries_field(map_keys.data_type().clone(), map_values.data_type().clone());
+    let DataType::Struct(fields) = entries_field.data_type() else {
+        unreachable!("map entries are a struct")
+    };
+    let entries = StructArray::try_new(fields.clone(), vec![map_keys, map_values], None)?;
+    let map = MapArray::try_new(
+        Arc::new(entries_field),
+        OffsetBuffer::new(offsets.into()),
+        entries,
+        Some(NullBuffer::from(nulls)),
+        false,
+    )?;
+    Ok(Arc::new(map))
+}
+
+/// Builds a list with the same offsets and nulls as `map` from a child
+/// array of its entries
+fn map_to_list(map: &MapArray, values: ArrayRef) -> ArrayRef {
+    Arc::new(ListArray::new(
+        Arc::new(Field::new("item", values.data_type().clone(), true)),
+        map.offsets().clone(),
+        values,
+        map.nulls().cloned(),
+    ))
+}
+
+/// map_keys(map) returns the keys of the map as a list
+pub fn map_keys(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let map = as_map_array(&args[0])?;
+    Ok(map_to_list(map, map.keys().clone()))
+}
+
+/// map_values(map) returns the values of the map as a list
+pub fn map_values(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let map = as_map_array(&args[0])?;
+    Ok(map_to_list(map, map.values().clone()))
+}
+
+/// map_entries(map) returns the entries of the map as a list of structs
+/// with a key and a value field
+pub fn map_entries(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let map = as_map_array(&args[0])?;
+    Ok(map_to_list(map, Arc::new(map.entries().clone())))
+}
+
+/// element_at(map, key) returns the value of the entry with the key in each
+/// row, or null if there is none
+pub fn map_element(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if args.len() != 2 {
+        return exec_err!("element_at expects two arguments");
+    }
+    let map = as_map_array(&args[0])?;
+    let key = cast(&args[1], map.keys().data_type())?;
+
+    // compare the keys of all entries with the key of their row
+    let offsets = map.value_offsets();
+    let first = offsets[0] as usize;
+    let entry_rows = (0..map.len())
+        .flat_map(|row| {
+            let len = (offsets[row + 1] - offsets[row]) as usize;
+            std::iter::repeat(row as u64).take(len)
+        })
+        .collect::<UInt64Array>();
+    let entry_keys = map.keys().slice(first, offsets[map.len()] as usize - first);
+    let matches = eq(&entry_keys, &take(&key, &entry_rows, None)?)?;
+
+    let indices = (0..map.len())
+        .map(|row| {
+            if map.is_null(row) {
+                return None;
+            }
+            let start = offsets[row] as usize;
+            let end = offsets[row + 1] as usize;
+            (start..end)
+                .find(|&i| matches.is_valid(i - first) && matches.value(i - first))
+                .map(|i| i as u64)
+        })
+        .collect::<UInt64Array>();
+    Ok(take(map.values(), &indices, None)?)
+}
+
+/// cardinality(map) returns the number of entries of the map
+pub fn map_cardinality(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let map = as_map_array(&args[0])?;
+    let lengths = (0..map.len())
+        .map(|row| map.is_valid(row).then(|| map.value_length(row) as u64))
+        .collect::<UInt64Array>();
+    Ok(Arc::new(lengths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::Int32Type;
+    use datafusion_common::cast::{as_int32_array, as_uint64_array};
+
+    fn test_map() -> ArrayRef {
+        let keys = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3)]),
+        ]);
+        let values = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(10), None]),
+            Some(vec![Some(0)]),
+            Some(vec![]),
+            Some(vec![Some(30)]),
+        ]);
+        make_map(&[Arc::new(keys), Arc::new(values)]).unwrap()
+    }
+
+    #[test]
+    fn test_make_map() {
+        let map = test_map();
+        let map = as_map_array(&map).unwrap();
+        assert_eq!(map.len(), 4);
+        assert!(map.is_null(1));
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 2, 3]);
+        assert_eq!(
+            as_int32_array(map.keys()).unwrap(),
+            &Int32Array::from(vec![1, 2, 3])
+        );
+
+        let keys = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![
+            Some(1),
+            Some(2),
+        ])]);
+        let values =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let err = make_map(&[Arc::new(keys), Arc::new(values)]).unwrap_err();
+        assert!(err.to_string().contains("same number of keys and values"));
+    }
+
+    #[test]
+    fn test_map_element() {
+        let map = test_map();
+        let key = Arc::new(Int64Array::from(vec![Some(1), Some(1), Some(1), None]));
+        let result = map_element(&[map.clone(), key]).unwrap();
+        assert_eq!(
+            as_int32_array(&result).unwrap(),
+            &Int32Array::from(vec![Some(10), None, None, None])
+        );
+
+        // works on sliced maps
+        let key = Arc::new(Int64Array::from(vec![Some(3)]));
+        let result = map_element(&[map.slice(3, 1), key]).unwrap();
+        assert_eq!(
+            as_int32_array(&result).unwrap(),
+            &Int32Array::from(vec![Some(30)])
+        );
+    }
+
+    #[test]
+    fn test_map_keys_values_cardinality() {
+        let map = test_map();
+        let keys = map_keys(&[map.clone()]).unwrap();
+        let keys = as_list_array(&keys).unwrap();
+        assert!(keys.is_null(1));
+        assert_eq!(
+            as_int32_array(&keys.value(0)).unwrap(),
+            &Int32Array::from(vec![1, 2])
+        );
+
+        let values = map_values(&[map.clone()]).unwrap();
+        let values = as_list_array(&values).unwrap();
+        assert_eq!(
+            as_int32_array(&values.value(0)).unwrap(),
+            &Int32Array::from(vec![Some(10), None])
+        );
+
+        let cardinality = map_cardinality(&[map]).unwrap();
+        assert_eq!(
+            as_uint64_array(&cardinality).unwrap(),
+            &UInt64Array::from(vec![Some(2), None, Some(0), Some(1)])
+        );
+    }
+}
@@ -19,20 +19,23 @@ use crate::expressions::GetFieldAccessExpr;
 use crate::var_provider::is_system_variables;
 use crate::{
     execution_props::ExecutionProps,
-    expressions::{self, binary, like, Column, GetIndexedFieldExpr, Literal},
+    expressions::{
+        self, binary, collation_of, like, Column, GetIndexedFieldExpr, Literal,
+    },
     functions, udf,
     var_provider::VarType,
-    PhysicalExpr,
+    HigherOrderFunctionExpr, LambdaExpr, PhysicalExpr,
 };
 use arrow::datatypes::Schema;
+use datafusion_common::config::ArithmeticOverflowMode;
 use datafusion_common::{
     exec_err, internal_err, not_impl_err, plan_err, DFSchema, DataFusionError, Result,
     ScalarValue,
 };
 use datafusion_expr::expr::{Alias, Cast, InList, ScalarFunction, ScalarUDF};
 use datafusion_expr::{
-    binary_expr, Between, BinaryExpr, Expr, GetFieldAccess, GetIndexedField, Like,
-    Operator, TryCast,
+    binary_expr, Between, BinaryExpr, BuiltinScalarFunction, Expr, ExprSchemable,
+    GetFieldAccess, GetIndexedField, Like, Operator, TryCast,
 };
 use std::sync::Arc;
 
@@ -50,6 +53,27 @@ pub fn create_physical_expr(
     input_dfschema: &DFSchema,
     input_schema: &Schema,
     execution_props: &ExecutionProps,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    create_physical_expr_with_overflow_mode(
+        e,
+        input_dfschema,
+        input_schema,
+        execution_props,
+        ArithmeticOverflowMode::default(),
+    )
+}
+
+/// Create a physical expression from a logical expression ([Expr]) whose
+/// arithmetic, negations and numeric casts handle overflow according to
+/// `arithmetic_overflow`, as configured by `datafusion.execution.arithmetic_overflow`.
+///
+/// See [`create_physical_expr`] for the other arguments.
+pub fn create_physical_expr_with_overflow_mode(
+    e: &Expr,
+    input_dfschema: &DFSchema,
+    input_schema: &Schema,
+    execution_props: &ExecutionProps,
+    arithmetic_overflow: ArithmeticOverflowMode,
 ) -> Result<Arc<dyn PhysicalExpr>> {
     if input_schema.fields.len() != input_dfschema.fields().len() {
         return internal_err!(
@@ -60,11 +84,12 @@ pub fn create_physical_expr(
         );
     }
     match e {
-        Expr::Alias(Alias { expr, .. }) => Ok(create_physical_expr(
+        Expr::Alias(Alias { expr, .. }) => Ok(create_physical_expr_with_overflow_mode(
             expr,
             input_dfschema,
             input_schema,
             execution_props,
+            arithmetic_overflow,
         )?),
         Expr::Column(c) => {
             let idx = input_dfschema.index_of_column(c)?;
@@ -96,11 +121,12 @@ pub fn create_physical_expr(
                 Operator::IsNotDistinctFrom,
                 Expr::Literal(ScalarValue::Boolean(Some(true))),
             );
-            create_physical_expr(
+            create_physical_expr_with_overflow_mode(
                 &binary_op,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )
         }
         Expr::IsNotTrue(expr) => {
@@ -109,11 +135,12 @@ pub fn create_physical_expr(
                 Operator::IsDistinctFrom,
                 Expr::Literal(ScalarValue::Boolean(Some(true))),
             );
-            create_physical_expr(
+            create_physical_expr_with_overflow_mode(
                 &binary_op,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )
         }
         Expr::IsFalse(expr) => {
@@ -122,11 +149,12 @@ pub fn create_physical_expr(
                 Operator::IsNotDistinctFrom,
                 Expr::Literal(ScalarValue::Boolean(Some(false))),
             );
-            create_physical_expr(
+            create_physical_expr_with_overflow_mode(
                 &binary_op,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )
         }
         Expr::IsNotFalse(expr) => {
@@ -135,11 +163,12 @@ pub fn create_physical_expr(
                 Operator::IsDistinctFrom,
                 Expr::Literal(ScalarValue::Boolean(Some(false))),
             );
-            create_physical_expr(
+            create_physical_expr_with_overflow_mode(
                 &binary_op,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )
         }
         Expr::IsUnknown(expr) => {
@@ -148,11 +177,12 @@ pub fn create_physical_expr(
                 Operator::IsNotDistinctFrom,
                 Expr::Literal(ScalarValue::Boolean(None)),
             );
-            create_physical_expr(
+            create_physical_expr_with_overflow_mode(
                 &binary_op,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )
         }
         Expr::IsNotUnknown(expr) => {
@@ -161,26 +191,29 @@ pub fn create_physical_expr(
                 Operator::IsDistinctFrom,
                 Expr::Literal(ScalarValue::Boolean(None)),
             );
-            create_physical_expr(
+            create_physical_expr_with_overflow_mode(
                 &binary_op,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )
         }
         Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
             // Create physical expressions for left and right operands
-            let lhs = create_physical_expr(
+            let lhs = create_physical_expr_with_overflow_mode(
                 left,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )?;
-            let rhs = create_physical_expr(
+            let rhs = create_physical_expr_with_overflow_mode(
                 right,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )?;
             // Note that the logical planner is responsible
             // for type coercion on the arguments (e.g. if one
@@ -189,7 +222,10 @@ pub fn create_physical_expr(
             //
             // There should be no coercion during physical
             // planning.
-            binary(lhs, *op, rhs, input_schema)
+            Ok(Arc::new(
+                expressions::BinaryExpr::new(lhs, *op, rhs)
+                    .with_overflow_mode(arithmetic_overflow),
+            ))
         }
         Expr::Like(Like {
             negated,
@@ -201,17 +237,19 @@ pub fn create_physical_expr(
             if escape_char.is_some() {
                 return exec_err!("LIKE does not support escape_char");
             }
-            let physical_expr = create_physical_expr(
+            let physical_expr = create_physical_expr_with_overflow_mode(
                 expr,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )?;
-            let physical_pattern = create_physical_expr(
+            let physical_pattern = create_physical_expr_with_overflow_mode(
                 pattern,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )?;
             like(
                 *negated,
@@ -223,11 +261,12 @@ pub fn create_physical_expr(
         }
         Expr::Case(case) => {
             let expr: Option<Arc<dyn PhysicalExpr>> = if let Some(e) = &case.expr {
-                Some(create_physical_expr(
+                Some(create_physical_expr_with_overflow_mode(
                     e.as_ref(),
                     input_dfschema,
                     input_schema,
                     execution_props,
+                    arithmetic_overflow,
                 )?)
             } else {
                 None
@@ -236,11 +275,12 @@ pub fn create_physical_expr(
                 .when_then_expr
                 .iter()
                 .map(|(w, _)| {
-                    create_physical_expr(
+                    create_physical_expr_with_overflow_mode(
                         w.as_ref(),
                         input_dfschema,
                         input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -248,11 +288,12 @@ pub fn create_physical_expr(
                 .when_then_expr
                 .iter()
                 .map(|(_, t)| {
-                    create_physical_expr(
+                    create_physical_expr_with_overflow_mode(
                         t.as_ref(),
                         input_dfschema,
                         input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -264,95 +305,205 @@ pub fn create_physical_expr(
                     .collect();
             let else_expr: Option<Arc<dyn PhysicalExpr>> =
                 if let Some(e) = &case.else_expr {
-                    Some(create_physical_expr(
+                    Some(create_physical_expr_with_overflow_mode(
                         e.as_ref(),
                         input_dfschema,
                         input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )?)
                 } else {
                     None
                 };
             Ok(expressions::case(expr, when_then_expr, else_expr)?)
         }
-        Expr::Cast(Cast { expr, data_type }) => expressions::cast(
-            create_physical_expr(expr, input_dfschema, input_schema, execution_props)?,
-            input_schema,
-            data_type.clone(),
-        ),
+        Expr::Cast(Cast { expr, data_type }) => {
+            let expr = create_physical_expr_with_overflow_mode(
+                expr,
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
+            )?;
+            let numeric =
+                expr.data_type(input_schema)?.is_numeric() && data_type.is_numeric();
+            let cast = expressions::cast(expr, input_schema, data_type.clone())?;
+            // numeric casts handle values that overflow the target type
+            // like the arithmetic operators
+            match cast.as_any().downcast_ref::<expressions::CastExpr>() {
+                Some(cast_expr) if numeric => Ok(Arc::new(
+                    cast_expr.clone().with_overflow_mode(arithmetic_overflow),
+                )),
+                _ => Ok(cast),
+            }
+        }
         Expr::TryCast(TryCast { expr, data_type }) => expressions::try_cast(
-            create_physical_expr(expr, input_dfschema, input_schema, execution_props)?,
+            create_physical_expr_with_overflow_mode(
+                expr,
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
+            )?,
             input_schema,
             data_type.clone(),
         ),
-        Expr::Not(expr) => expressions::not(create_physical_expr(
+        Expr::Not(expr) => expressions::not(create_physical_expr_with_overflow_mode(
             expr,
             input_dfschema,
             input_schema,
             execution_props,
+            arithmetic_overflow,
         )?),
-        Expr::Negative(expr) => expressions::negative(
-            create_physical_expr(expr, input_dfschema, input_schema, execution_props)?,
+        Expr::Negative(expr) => expressions::negative_with_overflow_mode(
+            create_physical_expr_with_overflow_mode(
+                expr,
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
+            )?,
             input_schema,
+            arithmetic_overflow,
         ),
-        Expr::IsNull(expr) => expressions::is_null(create_physical_expr(
-            expr,
-            input_dfschema,
-            input_schema,
-            execution_props,
-        )?),
-        Expr::IsNotNull(expr) => expressions::is_not_null(create_physical_expr(
-            expr,
-            input_dfschema,
-            input_schema,
-            execution_props,
-        )?),
+        Expr::IsNull(expr) => {
+            expressions::is_null(create_physical_expr_with_overflow_mode(
+                expr,
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
+            )?)
+        }
+        Expr::IsNotNull(expr) => {
+            expressions::is_not_null(create_physical_expr_with_overflow_mode(
+                expr,
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
+            )?)
+        }
         Expr::GetIndexedField(GetIndexedField { expr, field }) => {
             let field = match field {
                 GetFieldAccess::NamedStructField { name } => {
                     GetFieldAccessExpr::NamedStructField { name: name.clone() }
                 }
                 GetFieldAccess::ListIndex { key } => GetFieldAccessExpr::ListIndex {
-                    key: create_physical_expr(
+                    key: create_physical_expr_with_overflow_mode(
                         key,
                         input_dfschema,
                         input_schema,
                         execution_props,
+                        arithmetic_overflow,
                     )?,
                 },
                 GetFieldAccess::ListRange { start, stop } => {
                     GetFieldAccessExpr::ListRange {
-                        start: create_physical_expr(
+                        start: create_physical_expr_with_overflow_mode(
                             start,
                             input_dfschema,
                             input_schema,
                             execution_props,
+                            arithmetic_overflow,
                         )?,
-                        stop: create_physical_expr(
+                        stop: create_physical_expr_with_overflow_mode(
                             stop,
                             input_dfschema,
                             input_schema,
                             execution_props,
+                            arithmetic_overflow,
                         )?,
                     }
                 }
             };
             Ok(Arc::new(GetIndexedFieldExpr::new(
-                create_physical_expr(
+                create_physical_expr_with_overflow_mode(
                     expr,
                     input_dfschema,
                     input_schema,
                     execution_props,
+                    arithmetic_overflow,
                 )?,
                 field,
             )))
         }
 
+        Expr::ScalarFunction(ScalarFunction {
+            fun: BuiltinScalarFunction::Collate,
+            args,
+        }) => {
+            let Expr::Literal(ScalarValue::Utf8(Some(name))) = &args[1] else {
+                return not_impl_err!(
+                    "COLLATE requires a literal collation, got {}",
+                    args[1]
+                );
+            };
+            let expr = create_physical_expr_with_overflow_mode(
+                &args[0],
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
+            )?;
+            Ok(expressions::collate(expr, name.parse()?))
+        }
         Expr::ScalarFunction(ScalarFunction { fun, args }) => {
+            // higher-order functions such as array_transform take a lambda
+            // as their last argument
+            if let Some((Expr::Lambda(lambda), args)) = args.split_last() {
+                let physical_args = args
+                    .iter()
+                    .map(|e| {
+                        create_physical_expr_with_overflow_mode(
+                            e,
+                            input_dfschema,
+                            input_schema,
+                            execution_props,
+                            arithmetic_overflow,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let params_dfschema = lambda.params_schema()?;
+                let params = Arc::new(Schema::from(&params_dfschema));
+                let body = create_physical_expr_with_overflow_mode(
+                    &lambda.body,
+                    &params_dfschema,
+                    &params,
+                    execution_props,
+                    arithmetic_overflow,
+                )?;
+                let captures = lambda
+                    .captures
+                    .iter()
+                    .map(|e| {
+                        create_physical_expr_with_overflow_mode(
+                            e,
+                            input_dfschema,
+                            input_schema,
+                            execution_props,
+                            arithmetic_overflow,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                return Ok(Arc::new(HigherOrderFunctionExpr::try_new(
+                    *fun,
+                    physical_args,
+                    LambdaExpr::new(params, body).with_captures(captures),
+                    e.get_type(input_dfschema)?,
+                )?));
+            }
+
             let physical_args = args
                 .iter()
                 .map(|e| {
-                    create_physical_expr(e, input_dfschema, input_schema, execution_props)
+                    create_physical_expr_with_overflow_mode(
+                        e,
+                        input_dfschema,
+                        input_schema,
+                        execution_props,
+                        arithmetic_overflow,
+                    )
                 })
                 .collect::<Result<Vec<_>>>()?;
             functions::create_physical_expr(
@@ -365,11 +516,12 @@ pub fn create_physical_expr(
         Expr::ScalarUDF(ScalarUDF { fun, args }) => {
             let mut physical_args = vec![];
             for e in args {
-                physical_args.push(create_physical_expr(
+                physical_args.push(create_physical_expr_with_overflow_mode(
                     e,
                     input_dfschema,
                     input_schema,
                     execution_props,
+                    arithmetic_overflow,
                 )?);
             }
             // udfs with zero params expect null array as input
@@ -384,19 +536,26 @@ pub fn create_physical_expr(
             low,
             high,
         }) => {
-            let value_expr = create_physical_expr(
+            let value_expr = create_physical_expr_with_overflow_mode(
                 expr,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
+            )?;
+            let low_expr = create_physical_expr_with_overflow_mode(
+                low,
+                input_dfschema,
+                input_schema,
+                execution_props,
+                arithmetic_overflow,
             )?;
-            let low_expr =
-                create_physical_expr(low, input_dfschema, input_schema, execution_props)?;
-            let high_expr = create_physical_expr(
+            let high_expr = create_physical_expr_with_overflow_mode(
                 high,
                 input_dfschema,
                 input_schema,
                 execution_props,
+                arithmetic_overflow,
             )?;
 
             // rewrite the between into the two binary operators
@@ -422,24 +581,46 @@ pub fn create_physical_expr(
                 Ok(expressions::lit(ScalarValue::Boolean(None)))
             }
             _ => {
-                let value_expr = create_physical_expr(
+                let mut value_expr = create_physical_expr_with_overflow_mode(
                     expr,
                     input_dfschema,
                     input_schema,
                     execution_props,
+                    arithmetic_overflow,
                 )?;
 
-                let list_exprs = list
+                let mut list_exprs = list
                     .iter()
                     .map(|expr| {
-                        create_physical_expr(
+                        create_physical_expr_with_overflow_mode(
                             expr,
                             input_dfschema,
                             input_schema,
                             execution_props,
+                            arithmetic_overflow,
                         )
                     })
                     .collect::<Result<Vec<_>>>()?;
+
+                // collated strings are compared by their collation keys
+                let collation = collation_of(&value_expr, input_schema).or_else(|| {
+                    list_exprs
+                        .iter()
+                        .find_map(|e| collation_of(e, input_schema))
+                });
+                if let Some(collation) = collation {
+                    let key = |expr| {
+                        functions::create_physical_expr(
+                            &BuiltinScalarFunction::CollationKey,
+                            &[expr, expressions::lit(collation.to_string())],
+                            input_schema,
+                            execution_props,
+                        )
+                    };
+                    value_expr = key(value_expr)?;
+                    list_exprs =
+                        list_exprs.into_iter().map(key).collect::<Result<_>>()?;
+                }
                 expressions::in_list(value_expr, list_exprs, negated, input_schema)
             }
         },
@@ -22,14 +22,16 @@
 //! Regex expressions
 
 use arrow::array::{
-    new_null_array, Array, ArrayDataBuilder, ArrayRef, BufferBuilder, GenericStringArray,
+    new_null_array, Array, ArrayAccessor, ArrayDataBuilder, ArrayRef, BooleanArray,
+    BufferBuilder, GenericStringArray, GenericStringBuilder, Int64Array, ListBuilder,
     OffsetSizeTrait,
 };
 use arrow::compute;
-use datafusion_common::plan_err;
+use datafusion_common::cast::{as_int64_array, as_string_array};
 use datafusion_common::{
     cast::as_generic_string_array, internal_err, DataFusionError, Result,
 };
+use datafusion_common::{exec_err, plan_err};
 use datafusion_expr::{ColumnarValue, ScalarFunctionImplementation};
 use hashbrown::HashMap;
 use regex::Regex;
@@ -52,6 +54,474 @@ macro_rules! fetch_string_arg {
     }};
 }
 
+/// Maximum number of compiled regular expressions kept by a [`RegexCache`]
+const REGEX_CACHE_CAPACITY: usize = 64;
+
+/// A bounded least recently used cache of compiled regular expressions.
+///
+/// Creating a [`Regex`] is expensive, so functions whose pattern is a column
+/// rather than a literal compile each distinct pattern of a batch once,
+/// while bounding the memory used by batches with many distinct patterns.
+struct RegexCache {
+    /// Compiled regular expressions by flags and pattern, along with the
+    /// tick at which they were last used
+    entries: HashMap<String, HashMap<String, (Regex, usize)>>,
+    len: usize,
+    capacity: usize,
+    tick: usize,
+}
+
+impl RegexCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            len: 0,
+            capacity,
+            tick: 0,
+        }
+    }
+
+    /// Returns the compiled `pattern`, with `flags` such as `i` embedded
+    fn get(&mut self, pattern: &str, flags: &str) -> Result<&Regex> {
+        self.tick += 1;
+        let cached = self
+            .entries
+            .get(flags)
+            .map_or(false, |patterns| patterns.contains_key(pattern));
+        if !cached {
+            let re = if flags.is_empty() {
+                Regex::new(pattern)
+            } else {
+                Regex::new(&format!("(?{flags}){pattern}"))
+            }
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+            if self.len >= self.capacity {
+                self.evict_least_recently_used();
+            }
+            self.entries
+                .entry_ref(flags)
+                .or_default()
+                .insert(pattern.to_string(), (re, self.tick));
+            self.len += 1;
+        }
+
+        let (re, last_used) = self
+            .entries
+            .get_mut(flags)
+            .and_then(|patterns| patterns.get_mut(pattern))
+            .unwrap();
+        *last_used = self.tick;
+        Ok(re)
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let least_recently_used = self
+            .entries
+            .iter()
+            .flat_map(|(flags, patterns)| {
+                patterns
+                    .iter()
+                    .map(move |(pattern, (_, last_used))| (flags, pattern, *last_used))
+            })
+            .min_by_key(|(_, _, last_used)| *last_used)
+            .map(|(flags, pattern, _)| (flags.clone(), pattern.clone()));
+        if let Some((flags, pattern)) = least_recently_used {
+            let patterns = self.entries.get_mut(&flags).unwrap();
+            patterns.remove(&pattern);
+            if patterns.is_empty() {
+                self.entries.remove(&flags);
+            }
+            self.len -= 1;
+        }
+    }
+}
+
+/// Returns the value of `array` at `row`, or `None` if it is null
+fn value_at<A: ArrayAccessor>(array: A, row: usize) -> Option<A::Item> {
+    array.is_valid(row).then(|| array.value(row))
+}
+
+/// Returns the value of the optional argument `array` at `row`, `default` if
+/// the argument was not given, or `None` if it is null
+fn arg_at<A: ArrayAccessor>(
+    array: Option<A>,
+    row: usize,
+    default: A::Item,
+) -> Option<A::Item> {
+    match array {
+        Some(array) => value_at(array, row),
+        None => Some(default),
+    }
+}
+
+/// Returns `flags`, or an error if they contain the "global" option `g`
+fn non_global_flags<'a>(flags: &'a str, name: &str) -> Result<&'a str> {
+    if flags.contains('g') {
+        return plan_err!("{name}() does not support the \"global\" option");
+    }
+    Ok(flags)
+}
+
+/// Returns the byte offset in `string` of the 1-based character position
+/// `start`, or `None` if it is past the end of `string`
+fn byte_offset(string: &str, start: i64, name: &str) -> Result<Option<usize>> {
+    if start < 1 {
+        return exec_err!("{name}() requires start to be at least 1, got {start}");
+    }
+    let start = (start - 1) as usize;
+    Ok(string
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(string.len()))
+        .nth(start))
+}
+
+/// Returns the 1-based character position of the byte offset `offset` in `string`
+fn char_position(string: &str, offset: usize) -> i64 {
+    string[..offset].chars().count() as i64 + 1
+}
+
+/// Returns true if the string matches the regular expression.
+///
+/// example: `regexp_like('Thomas', '.*thom.*', 'i') = true`
+pub fn regexp_like<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let flags_array = args
+        .get(2)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    let result = (0..string_array.len())
+        .map(|row| {
+            match (
+                value_at(string_array, row),
+                value_at(pattern_array, row),
+                arg_at(flags_array, row, ""),
+            ) {
+                (Some(string), Some(pattern), Some(flags)) => {
+                    let flags = non_global_flags(flags, "regexp_like")?;
+                    Ok(Some(cache.get(pattern, flags)?.is_match(string)))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<BooleanArray>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the number of times the regular expression matches the string,
+/// starting the search at the 1-based character position `start`.
+///
+/// example: `regexp_count('ABCABCAXYaxy', 'A.', 1, 'i') = 4`
+pub fn regexp_count<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let start_array = args.get(2).map(|array| as_int64_array(array)).transpose()?;
+    let flags_array = args
+        .get(3)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    let result = (0..string_array.len())
+        .map(|row| {
+            match (
+                value_at(string_array, row),
+                value_at(pattern_array, row),
+                arg_at(start_array, row, 1),
+                arg_at(flags_array, row, ""),
+            ) {
+                (Some(string), Some(pattern), Some(start), Some(flags)) => {
+                    let flags = non_global_flags(flags, "regexp_count")?;
+                    let re = cache.get(pattern, flags)?;
+                    let count = match byte_offset(string, start, "regexp_count")? {
+                        Some(offset) => re.find_iter(&string[offset..]).count(),
+                        None => 0,
+                    };
+                    Ok(Some(count as i64))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<Int64Array>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the `n`-th match of `re` in `string` starting at the 1-based
+/// character position `start`, as the byte range of its capture group
+/// `subexpr` (0 for the whole match)
+fn nth_match(
+    re: &Regex,
+    string: &str,
+    start: i64,
+    n: i64,
+    subexpr: i64,
+    name: &str,
+) -> Result<Option<(usize, usize)>> {
+    if n < 1 {
+        return exec_err!("{name}() requires n to be at least 1, got {n}");
+    }
+    if subexpr < 0 {
+        return exec_err!("{name}() requires subexpr to be at least 0, got {subexpr}");
+    }
+    let Some(offset) = byte_offset(string, start, name)? else {
+        return Ok(None);
+    };
+    Ok(re
+        .captures_iter(&string[offset..])
+        .nth((n - 1) as usize)
+        .and_then(|captures| captures.get(subexpr as usize))
+        .map(|m| (offset + m.start(), offset + m.end())))
+}
+
+/// Returns the 1-based character position of the `n`-th match of the
+/// regular expression in the string, or 0 if there is no such match.
+/// If `endoption` is 1 the position after the match is returned instead.
+///
+/// example: `regexp_instr('ABCDEFGHI', '(c..)(...)', 1, 1, 0, 'i', 2) = 6`
+pub fn regexp_instr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let start_array = args.get(2).map(|array| as_int64_array(array)).transpose()?;
+    let n_array = args.get(3).map(|array| as_int64_array(array)).transpose()?;
+    let endoption_array = args.get(4).map(|array| as_int64_array(array)).transpose()?;
+    let flags_array = args
+        .get(5)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+    let subexpr_array = args.get(6).map(|array| as_int64_array(array)).transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    let result = (0..string_array.len())
+        .map(|row| {
+            match (
+                value_at(string_array, row),
+                value_at(pattern_array, row),
+                arg_at(start_array, row, 1),
+                arg_at(n_array, row, 1),
+                arg_at(endoption_array, row, 0),
+                arg_at(flags_array, row, ""),
+                arg_at(subexpr_array, row, 0),
+            ) {
+                (
+                    Some(string),
+                    Some(pattern),
+                    Some(start),
+                    Some(n),
+                    Some(endoption),
+                    Some(flags),
+                    Some(subexpr),
+                ) => {
+                    let flags = non_global_flags(flags, "regexp_instr")?;
+                    let re = cache.get(pattern, flags)?;
+                    let position =
+                        match nth_match(re, string, start, n, subexpr, "regexp_instr")? {
+                            Some((_, end)) if endoption != 0 => {
+                                char_position(string, end)
+                            }
+                            Some((begin, _)) => char_position(string, begin),
+                            None => 0,
+                        };
+                    Ok(Some(position))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<Int64Array>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Returns the substring matching the `n`-th match of the regular expression
+/// in the string, or its capture group `subexpr`, or NULL if there is no
+/// such match.
+///
+/// example: `regexp_substr('ABCDEFGHI', '(c..)(...)', 1, 1, 'i', 2) = 'FGH'`
+pub fn regexp_substr<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let start_array = args.get(2).map(|array| as_int64_array(array)).transpose()?;
+    let n_array = args.get(3).map(|array| as_int64_array(array)).transpose()?;
+    let flags_array = args
+        .get(4)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+    let subexpr_array = args.get(5).map(|array| as_int64_array(array)).transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    let result = (0..string_array.len())
+        .map(|row| {
+            match (
+                value_at(string_array, row),
+                value_at(pattern_array, row),
+                arg_at(start_array, row, 1),
+                arg_at(n_array, row, 1),
+                arg_at(flags_array, row, ""),
+                arg_at(subexpr_array, row, 0),
+            ) {
+                (
+                    Some(string),
+                    Some(pattern),
+                    Some(start),
+                    Some(n),
+                    Some(flags),
+                    Some(subexpr),
+                ) => {
+                    let flags = non_global_flags(flags, "regexp_substr")?;
+                    let re = cache.get(pattern, flags)?;
+                    Ok(nth_match(re, string, start, n, subexpr, "regexp_substr")?
+                        .map(|(begin, end)| &string[begin..end]))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<GenericStringArray<T>>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Extracts the capture group `group` (1 by default, 0 for the whole match)
+/// of the first match of the regular expression in the string, or an empty
+/// string if there is no match or the group did not participate in it.
+///
+/// example: `regexp_extract('100-200', '(\d+)-(\d+)', 2) = '200'`
+pub fn regexp_extract<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let group_array = args.get(2).map(|array| as_int64_array(array)).transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    let result = (0..string_array.len())
+        .map(|row| {
+            match (
+                value_at(string_array, row),
+                value_at(pattern_array, row),
+                arg_at(group_array, row, 1),
+            ) {
+                (Some(string), Some(pattern), Some(group)) => {
+                    let re = cache.get(pattern, "")?;
+                    let groups = re.captures_len() as i64 - 1;
+                    if group < 0 || group > groups {
+                        return exec_err!(
+                            "regexp_extract() pattern has {groups} groups, but group {group} was requested"
+                        );
+                    }
+                    let extracted = re
+                        .captures(string)
+                        .and_then(|captures| captures.get(group as usize))
+                        .map_or("", |m| m.as_str());
+                    Ok(Some(extracted))
+                }
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<GenericStringArray<T>>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
+/// Splits the string using the regular expression as the delimiter.
+/// Like PostgreSQL, empty matches at the start or end of the string or right
+/// after a previous match are ignored.
+///
+/// example: `regexp_split_to_array('hello  world', '\s+') = ['hello', 'world']`
+pub fn regexp_split_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let flags_array = args
+        .get(2)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    let mut builder = ListBuilder::new(GenericStringBuilder::<T>::new());
+    for row in 0..string_array.len() {
+        match (
+            value_at(string_array, row),
+            value_at(pattern_array, row),
+            arg_at(flags_array, row, ""),
+        ) {
+            (Some(string), Some(pattern), Some(flags)) => {
+                let flags = non_global_flags(flags, "regexp_split_to_array")?;
+                let re = cache.get(pattern, flags)?;
+                let mut last = 0;
+                for m in re.find_iter(string) {
+                    if m.start() == m.end()
+                        && (m.start() == 0
+                            || m.start() == string.len()
+                            || m.start() == last)
+                    {
+                        continue;
+                    }
+                    builder.values().append_value(&string[last..m.start()]);
+                    last = m.end();
+                }
+                builder.values().append_value(&string[last..]);
+                builder.append(true);
+            }
+            _ => builder.append(false),
+        }
+    }
+
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
+/// Returns the matches of the regular expression in the string: only the
+/// first one, or all of them with the "global" flag `g`. Each match is a list
+/// of its capture groups, or of the whole match if the regular expression has
+/// no capture groups.
+///
+/// `regexp_matches` is a set returning function: the SQL planner unnests
+/// its result into one row per match, as in PostgreSQL.
+///
+/// example: `regexp_matches('a1b2', '([a-z])(\d)', 'g') = [['a', '1'], ['b', '2']]`
+pub fn regexp_matches<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let pattern_array = as_string_array(&args[1])?;
+    let flags_array = args
+        .get(2)
+        .map(|array| as_string_array(array))
+        .transpose()?;
+
+    let mut cache = RegexCache::new(REGEX_CACHE_CAPACITY);
+    // the flags without `g` and whether `g` was given, by distinct flags
+    let mut parsed_flags: HashMap<&str, (String, bool)> = HashMap::new();
+    let mut builder =
+        ListBuilder::new(ListBuilder::new(GenericStringBuilder::<T>::new()));
+    for row in 0..string_array.len() {
+        match (
+            value_at(string_array, row),
+            value_at(pattern_array, row),
+            arg_at(flags_array, row, ""),
+        ) {
+            (Some(string), Some(pattern), Some(flags)) => {
+                let (flags, global) = parsed_flags
+                    .entry(flags)
+                    .or_insert_with(|| (flags.replace('g', ""), flags.contains('g')));
+                let re = cache.get(pattern, flags)?;
+                // skip the whole match if there are capture groups
+                let skip = (re.captures_len() > 1) as usize;
+                let limit = if *global { usize::MAX } else { 1 };
+                for captures in re.captures_iter(string).take(limit) {
+                    let groups = builder.values();
+                    for group in captures.iter().skip(skip) {
+                        groups.values().append_option(group.map(|m| m.as_str()));
+                    }
+                    groups.append(true);
+                }
+                builder.append(true);
+            }
+            _ => builder.append(false),
+        }
+    }
+
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
 /// extract a specific group from a string column, using a regular expression
 pub fn regexp_match<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
     match args.len() {
@@ -97,8 +567,8 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
     // Default implementation for regexp_replace, assumes all args are arrays
     // and args is a sequence of 3 or 4 elements.
 
-    // creating Regex is expensive so create hashmap for memoization
-    let mut patterns: HashMap<String, Regex> = HashMap::new();
+    // creating Regex is expensive so cache the compiled patterns
+    let mut patterns = RegexCache::new(REGEX_CACHE_CAPACITY);
 
     match args.len() {
         3 => {
@@ -114,19 +584,7 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
                 (Some(string), Some(pattern), Some(replacement)) => {
                     let replacement = regex_replace_posix_groups(replacement);
 
-                    // if patterns hashmap already has regexp then use else else create and return
-                    let re = match patterns.get(pattern) {
-                        Some(re) => Ok(re.clone()),
-                        None => {
-                            match Regex::new(pattern) {
-                                Ok(re) => {
-                                    patterns.insert(pattern.to_string(), re.clone());
-                                    Ok(re)
-                                },
-                                Err(err) => Err(DataFusionError::External(Box::new(err))),
-                            }
-                        }
-                    };
+                    let re = patterns.get(pattern, "");
 
                     Some(re.map(|re| re.replace(string, replacement.as_str()))).transpose()
                 }
@@ -160,19 +618,7 @@ pub fn regexp_replace<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
                         (format!("(?{flags}){pattern}"), false)
                     };
 
-                    // if patterns hashmap already has regexp then use else else create and return
-                    let re = match patterns.get(&pattern) {
-                        Some(re) => Ok(re.clone()),
-                        None => {
-                            match Regex::new(pattern.as_str()) {
-                                Ok(re) => {
-                                    patterns.insert(pattern, re.clone());
-                                    Ok(re)
-                                },
-                                Err(err) => Err(DataFusionError::External(Box::new(err))),
-                            }
-                        }
-                    };
+                    let re = patterns.get(&pattern, "");
 
                     Some(re.map(|re| {
                         if replace_all {
@@ -395,6 +841,213 @@ mod tests {
         assert_eq!(re_err.strip_backtrace(), "Error during planning: regexp_match() does not support the \"global\" option");
     }
 
+    #[test]
+    fn test_regexp_like() {
+        let values = StringArray::from(vec![Some("abc"), Some("ABC"), Some("xyz"), None]);
+        let patterns = StringArray::from(vec!["^a", "^a", "^a", "^a"]);
+
+        let re =
+            regexp_like::<i32>(&[Arc::new(values.clone()), Arc::new(patterns.clone())])
+                .unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), Some(false), None]);
+        assert_eq!(re.as_ref(), &expected);
+
+        let flags = StringArray::from(vec!["i"; 4]);
+        let re =
+            regexp_like::<i32>(&[Arc::new(values), Arc::new(patterns), Arc::new(flags)])
+                .unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), Some(false), None]);
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_count_and_instr() {
+        let values = StringArray::from(vec!["ABCABCAXYaxy"; 2]);
+        let patterns = StringArray::from(vec!["A.", "(c..)(...)"]);
+        let starts = Int64Array::from(vec![1, 1]);
+        let flags = StringArray::from(vec!["i"; 2]);
+
+        let re = regexp_count::<i32>(&[
+            Arc::new(values.clone()),
+            Arc::new(patterns.clone()),
+            Arc::new(starts.clone()),
+            Arc::new(flags.clone()),
+        ])
+        .unwrap();
+        assert_eq!(re.as_ref(), &Int64Array::from(vec![4, 1]));
+
+        let re = regexp_instr::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(starts),
+            Arc::new(Int64Array::from(vec![2, 1])),
+            Arc::new(Int64Array::from(vec![1, 0])),
+            Arc::new(flags),
+            Arc::new(Int64Array::from(vec![0, 2])),
+        ])
+        .unwrap();
+        assert_eq!(re.as_ref(), &Int64Array::from(vec![6, 6]));
+    }
+
+    #[test]
+    fn test_regexp_count_invalid_start() {
+        let values = StringArray::from(vec!["abc"]);
+        let patterns = StringArray::from(vec!["a"]);
+        let starts = Int64Array::from(vec![0]);
+
+        let err = regexp_count::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(starts),
+        ])
+        .expect_err("start 0 should have failed");
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: regexp_count() requires start to be at least 1, got 0"
+        );
+    }
+
+    #[test]
+    fn test_regexp_substr_and_extract() {
+        let values = StringArray::from(vec![Some("100-200"), Some("abc"), None]);
+        let patterns = StringArray::from(vec![r"(\d+)-(\d+)"; 3]);
+
+        let re = regexp_substr::<i32>(&[
+            Arc::new(values.clone()),
+            Arc::new(patterns.clone()),
+            Arc::new(Int64Array::from(vec![1; 3])),
+            Arc::new(Int64Array::from(vec![1; 3])),
+            Arc::new(StringArray::from(vec![""; 3])),
+            Arc::new(Int64Array::from(vec![2; 3])),
+        ])
+        .unwrap();
+        assert_eq!(
+            re.as_ref(),
+            &StringArray::from(vec![Some("200"), None, None])
+        );
+
+        let re = regexp_extract::<i32>(&[Arc::new(values), Arc::new(patterns)]).unwrap();
+        assert_eq!(
+            re.as_ref(),
+            &StringArray::from(vec![Some("100"), Some(""), None])
+        );
+    }
+
+    #[test]
+    fn test_regexp_split_to_array() {
+        let values = StringArray::from(vec![Some("hello  world"), Some("abc"), None]);
+        let patterns = StringArray::from(vec![r"\s+", "", "x"]);
+
+        let re = regexp_split_to_array::<i32>(&[Arc::new(values), Arc::new(patterns)])
+            .unwrap();
+
+        let mut expected_builder = ListBuilder::new(StringBuilder::new());
+        expected_builder.values().append_value("hello");
+        expected_builder.values().append_value("world");
+        expected_builder.append(true);
+        expected_builder.values().append_value("a");
+        expected_builder.values().append_value("b");
+        expected_builder.values().append_value("c");
+        expected_builder.append(true);
+        expected_builder.append(false);
+        let expected = expected_builder.finish();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_matches() {
+        let values = StringArray::from(vec!["a1b2", "a1b2"]);
+        let patterns = StringArray::from(vec![r"([a-z])(\d)", r"\d"]);
+        let flags = StringArray::from(vec!["g"; 2]);
+
+        let re = regexp_matches::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(flags),
+        ])
+        .unwrap();
+
+        let mut expected_builder =
+            ListBuilder::new(ListBuilder::new(StringBuilder::new()));
+        for groups in [vec!["a", "1"], vec!["b", "2"]] {
+            for group in groups {
+                expected_builder.values().values().append_value(group);
+            }
+            expected_builder.values().append(true);
+        }
+        expected_builder.append(true);
+        for group in ["1", "2"] {
+            expected_builder.values().values().append_value(group);
+            expected_builder.values().append(true);
+        }
+        expected_builder.append(true);
+        let expected = expected_builder.finish();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regexp_matches_first_match_without_global_flag() {
+        let values = StringArray::from(vec!["a1b2", "a1b2", "abc"]);
+        let patterns = StringArray::from(vec![r"([a-z])(\d)", r"\d", r"\d"]);
+        let flags = StringArray::from(vec!["", "i", ""]);
+
+        let re = regexp_matches::<i32>(&[
+            Arc::new(values),
+            Arc::new(patterns),
+            Arc::new(flags),
+        ])
+        .unwrap();
+
+        let mut expected_builder =
+            ListBuilder::new(ListBuilder::new(StringBuilder::new()));
+        for group in ["a", "1"] {
+            expected_builder.values().values().append_value(group);
+        }
+        expected_builder.values().append(true);
+        expected_builder.append(true);
+        expected_builder.values().values().append_value("1");
+        expected_builder.values().append(true);
+        expected_builder.append(true);
+        // no match
+        expected_builder.append(true);
+        let expected = expected_builder.finish();
+
+        assert_eq!(re.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_regex_cache_evicts_least_recently_used() {
+        let mut cache = RegexCache::new(2);
+        cache.get("a", "").unwrap();
+        cache.get("b", "").unwrap();
+        cache.get("a", "").unwrap();
+        cache.get("c", "i").unwrap();
+
+        let mut patterns = cache
+            .entries
+            .iter()
+            .flat_map(|(flags, patterns)| {
+                patterns
+                    .keys()
+                    .map(move |pattern| (flags.clone(), pattern.clone()))
+            })
+            .collect::<Vec<_>>();
+        patterns.sort();
+        assert_eq!(
+            patterns,
+            vec![
+                ("".to_string(), "a".to_string()),
+                ("i".to_string(), "c".to_string())
+            ]
+        );
+        assert_eq!(cache.len, 2);
+        assert!(cache.get("(", "").is_err());
+    }
+
     #[test]
     fn test_static_pattern_regexp_replace() {
         let values = StringArray::from(vec!["abc"; 5]);
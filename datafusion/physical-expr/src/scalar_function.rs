@@ -44,7 +44,7 @@ use arrow::record_batch::RecordBatch;
 use datafusion_common::Result;
 use datafusion_expr::{
     expr_vec_fmt, BuiltinScalarFunction, ColumnarValue, FuncMonotonicity,
-    ScalarFunctionImplementation,
+    ScalarFunctionImplementation, ScalarUDF,
 };
 
 /// Physical expression of a scalar function
@@ -58,6 +58,8 @@ pub struct ScalarFunctionExpr {
     // and it specifies the effect of an increase or decrease in
     // the corresponding `arg` to the function value.
     monotonicity: Option<FuncMonotonicity>,
+    // The user-defined function this expression was created from, if any
+    udf: Option<Arc<ScalarUDF>>,
 }
 
 impl Debug for ScalarFunctionExpr {
@@ -86,9 +88,16 @@ impl ScalarFunctionExpr {
             args,
             return_type: return_type.clone(),
             monotonicity,
+            udf: None,
         }
     }
 
+    /// Set the user-defined function this expression was created from
+    pub fn with_udf(mut self, udf: Arc<ScalarUDF>) -> Self {
+        self.udf = Some(udf);
+        self
+    }
+
     /// Get the scalar function implementation
     pub fn fun(&self) -> &ScalarFunctionImplementation {
         &self.fun
@@ -113,6 +122,11 @@ impl ScalarFunctionExpr {
     pub fn monotonicity(&self) -> &Option<FuncMonotonicity> {
         &self.monotonicity
     }
+
+    /// The user-defined function this expression was created from, if any
+    pub fn udf(&self) -> Option<&Arc<ScalarUDF>> {
+        self.udf.as_ref()
+    }
 }
 
 impl fmt::Display for ScalarFunctionExpr {
@@ -169,13 +183,14 @@ impl PhysicalExpr for ScalarFunctionExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(ScalarFunctionExpr::new(
-            &self.name,
-            self.fun.clone(),
-            children,
-            self.return_type(),
-            self.monotonicity.clone(),
-        )))
+        Ok(Arc::new(ScalarFunctionExpr {
+            fun: self.fun.clone(),
+            name: self.name.clone(),
+            args: children,
+            return_type: self.return_type.clone(),
+            monotonicity: self.monotonicity.clone(),
+            udf: self.udf.clone(),
+        }))
     }
 
     fn dyn_hash(&self, state: &mut dyn Hasher) {
@@ -21,6 +21,7 @@ use std::fmt::Display;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use crate::expressions::{collation_keys, collation_of};
 use crate::PhysicalExpr;
 
 use arrow::compute::kernels::sort::{SortColumn, SortOptions};
@@ -61,9 +62,14 @@ impl std::fmt::Display for PhysicalSortExpr {
 
 impl PhysicalSortExpr {
     /// evaluate the sort expression into SortColumn that can be passed into arrow sort kernel
+    ///
+    /// Collated strings are replaced by their collation keys, which sort
+    /// byte-wise as the strings sort under the collation
     pub fn evaluate_to_sort_column(&self, batch: &RecordBatch) -> Result<SortColumn> {
         let value_to_sort = self.expr.evaluate(batch)?;
-        let array_to_sort = match value_to_sort {
+        // collated strings sort by their collation keys
+        let collation = collation_of(&self.expr, &batch.schema());
+        let array_to_sort = match collation_keys(value_to_sort, collation)? {
             ColumnarValue::Array(array) => array,
             ColumnarValue::Scalar(scalar) => {
                 return exec_err!(
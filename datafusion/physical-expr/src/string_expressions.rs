@@ -32,7 +32,7 @@ use datafusion_common::{
     cast::{
         as_generic_string_array, as_int64_array, as_primitive_array, as_string_array,
     },
-    exec_err, ScalarValue,
+    exec_err, Collation, ScalarValue,
 };
 use datafusion_common::{internal_err, DataFusionError, Result};
 use datafusion_expr::ColumnarValue;
@@ -208,6 +208,36 @@ pub fn chr(args: &[ArrayRef]) -> Result<ArrayRef> {
     Ok(Arc::new(result) as ArrayRef)
 }
 
+/// Returns the key of the string under the collation, which compares byte-wise as the string compares under the collation.
+/// collation_key('École', 'und-ci') = 'ecole'
+pub fn collation_key<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let string_array = as_generic_string_array::<T>(&args[0])?;
+    let collation_array = as_string_array(&args[1])?;
+
+    // the collation is almost always a literal, only parse it when it changes
+    let mut collation: Option<(&str, Collation)> = None;
+    let result = string_array
+        .iter()
+        .zip(collation_array.iter())
+        .map(|(string, name)| match (string, name) {
+            (Some(string), Some(name)) => {
+                let c = match collation {
+                    Some((previous, c)) if previous == name => c,
+                    _ => {
+                        let c = name.parse::<Collation>()?;
+                        collation = Some((name, c));
+                        c
+                    }
+                };
+                Ok(Some(c.key(string)))
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<GenericStringArray<T>>>()?;
+
+    Ok(Arc::new(result) as ArrayRef)
+}
+
 /// Concatenates the text representations of all the arguments. NULL arguments are ignored.
 /// concat('abcde', 2, NULL, 22) = 'abcde222'
 pub fn concat(args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -23,10 +23,11 @@ use arrow::row::{RowConverter, Rows, SortField};
 use arrow_array::{Array, ArrayRef};
 use arrow_schema::{DataType, SchemaRef};
 use datafusion_common::hash_utils::create_hashes;
-use datafusion_common::{DataFusionError, Result};
+use datafusion_common::{Collation, DataFusionError, Result};
 use datafusion_execution::memory_pool::proxy::{RawTableAllocExt, VecAllocExt};
 use datafusion_physical_expr::EmitTo;
 use hashbrown::raw::RawTable;
+use std::sync::Arc;
 
 /// A [`GroupValues`] making use of [`Rows`]
 pub struct GroupValuesRows {
@@ -59,6 +60,17 @@ pub struct GroupValuesRows {
     /// [`Row`]: arrow::row::Row
     group_values: Option<Rows>,
 
+    /// The collation of each group column with a non-binary collation.
+    /// Such columns are grouped by their collation keys
+    collations: Vec<Option<Collation>>,
+
+    /// The first value seen of each group, stored in arrow [`Row`] format,
+    /// if any group column is collated. `group_values` then holds the
+    /// collation keys, which are not output.
+    ///
+    /// [`Row`]: arrow::row::Row
+    original_values: Option<Rows>,
+
     // buffer to be reused to store hashes
     hashes_buffer: Vec<u64>,
 
@@ -78,23 +90,49 @@ impl GroupValuesRows {
 
         let map = RawTable::with_capacity(0);
 
+        let collations = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                Collation::from_metadata(f.metadata())
+                    .filter(|collation| *collation != Collation::Binary)
+            })
+            .collect();
+
         Ok(Self {
             schema,
             row_converter,
             map,
             map_size: 0,
             group_values: None,
+            collations,
+            original_values: None,
             hashes_buffer: Default::default(),
             random_state: Default::default(),
         })
     }
+
+    /// Returns true if any group column is collated
+    fn is_collated(&self) -> bool {
+        self.collations.iter().any(Option::is_some)
+    }
 }
 
 impl GroupValues for GroupValuesRows {
     fn intern(&mut self, cols: &[ArrayRef], groups: &mut Vec<usize>) -> Result<()> {
+        // Collated columns are grouped by their collation keys
+        let keys = cols
+            .iter()
+            .zip(&self.collations)
+            .map(|(col, collation)| match collation {
+                Some(collation) => collation.keys(col),
+                None => Ok(Arc::clone(col)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         // Convert the group keys into the row format
         // Avoid reallocation when https://github.com/apache/arrow-rs/issues/4479 is available
-        let group_rows = self.row_converter.convert_columns(cols)?;
+        let group_rows = self.row_converter.convert_columns(&keys)?;
         let n_rows = group_rows.num_rows();
 
         let mut group_values = match self.group_values.take() {
@@ -102,6 +140,19 @@ impl GroupValues for GroupValuesRows {
             None => self.row_converter.empty_rows(0, 0),
         };
 
+        // the values of collated columns, of which the first of each group
+        // is output
+        let original_rows = if self.is_collated() {
+            Some(self.row_converter.convert_columns(cols)?)
+        } else {
+            None
+        };
+        let mut original_values = match self.original_values.take() {
+            Some(original_values) => Some(original_values),
+            None if self.is_collated() => Some(self.row_converter.empty_rows(0, 0)),
+            None => None,
+        };
+
         // tracks to which group each of the input rows belongs
         groups.clear();
 
@@ -109,7 +160,7 @@ impl GroupValues for GroupValuesRows {
         let batch_hashes = &mut self.hashes_buffer;
         batch_hashes.clear();
         batch_hashes.resize(n_rows, 0);
-        create_hashes(cols, &self.random_state, batch_hashes)?;
+        create_hashes(&keys, &self.random_state, batch_hashes)?;
 
         for (row, &hash) in batch_hashes.iter().enumerate() {
             let entry = self.map.get_mut(hash, |(_hash, group_idx)| {
@@ -127,6 +178,11 @@ impl GroupValues for GroupValuesRows {
                     // Add new entry to aggr_state and save newly created index
                     let group_idx = group_values.num_rows();
                     group_values.push(group_rows.row(row));
+                    if let (Some(original_values), Some(original_rows)) =
+                        (original_values.as_mut(), original_rows.as_ref())
+                    {
+                        original_values.push(original_rows.row(row));
+                    }
 
                     // for hasher function, use precomputed hash value
                     self.map.insert_accounted(
@@ -141,14 +197,18 @@ impl GroupValues for GroupValuesRows {
         }
 
         self.group_values = Some(group_values);
+        self.original_values = original_values;
 
         Ok(())
     }
 
     fn size(&self) -> usize {
         let group_values_size = self.group_values.as_ref().map(|v| v.size()).unwrap_or(0);
+        let original_values_size =
+            self.original_values.as_ref().map(|v| v.size()).unwrap_or(0);
         self.row_converter.size()
             + group_values_size
+            + original_values_size
             + self.map_size
             + self.hashes_buffer.allocated_size()
     }
@@ -170,15 +230,41 @@ impl GroupValues for GroupValuesRows {
             .take()
             .expect("Can not emit from empty rows");
 
+        let mut original_values = self.original_values.take();
+
         let mut output = match emit_to {
             EmitTo::All => {
-                let output = self.row_converter.convert_rows(&group_values)?;
+                // output the original values of collated groups, not their keys
+                let output = match original_values.as_mut() {
+                    Some(original_values) => {
+                        let output =
+                            self.row_converter.convert_rows(&*original_values)?;
+                        original_values.clear();
+                        output
+                    }
+                    None => self.row_converter.convert_rows(&group_values)?,
+                };
                 group_values.clear();
                 output
             }
             EmitTo::First(n) => {
-                let groups_rows = group_values.iter().take(n);
-                let output = self.row_converter.convert_rows(groups_rows)?;
+                let output = match original_values.as_mut() {
+                    Some(original_values) => {
+                        let output = self
+                            .row_converter
+                            .convert_rows(original_values.iter().take(n))?;
+                        let mut new_original_values = self.row_converter.empty_rows(0, 0);
+                        for row in original_values.iter().skip(n) {
+                            new_original_values.push(row);
+                        }
+                        *original_values = new_original_values;
+                        output
+                    }
+                    None => {
+                        let groups_rows = group_values.iter().take(n);
+                        self.row_converter.convert_rows(groups_rows)?
+                    }
+                };
                 // Clear out first n group keys by copying them to a new Rows.
                 // TODO file some ticket in arrow-rs to make this more efficent?
                 let mut new_group_values = self.row_converter.empty_rows(0, 0);
@@ -218,6 +304,7 @@ impl GroupValues for GroupValuesRows {
         }
 
         self.group_values = Some(group_values);
+        self.original_values = original_values;
         Ok(output)
     }
 
@@ -227,6 +314,10 @@ impl GroupValues for GroupValuesRows {
             rows.clear();
             rows
         });
+        self.original_values = self.original_values.take().map(|mut rows| {
+            rows.clear();
+            rows
+        });
         self.map.clear();
         self.map.shrink_to(count, |_| 0); // hasher does not matter since the map is cleared
         self.map_size = self.map.capacity() * std::mem::size_of::<(u64, usize)>();
@@ -45,7 +45,7 @@ use datafusion_expr::Accumulator;
 use datafusion_physical_expr::{
     aggregate::is_order_sensitive,
     equivalence::collapse_lex_req,
-    expressions::{Column, Max, Min, UnKnownColumn},
+    expressions::{collation_of, Column, Max, Min, UnKnownColumn},
     physical_exprs_contains, reverse_order_bys, AggregateExpr, EquivalenceProperties,
     LexOrdering, LexRequirement, PhysicalExpr, PhysicalSortExpr, PhysicalSortRequirement,
 };
@@ -61,7 +61,9 @@ mod topk_stream;
 
 pub use datafusion_expr::AggregateFunction;
 use datafusion_physical_expr::equivalence::ProjectionMapping;
-pub use datafusion_physical_expr::expressions::create_aggregate_expr;
+pub use datafusion_physical_expr::expressions::{
+    create_aggregate_expr, create_aggregate_expr_with_overflow_mode,
+};
 
 /// Hash aggregate modes
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -942,14 +944,20 @@ fn create_schema(
 ) -> Result<Schema> {
     let mut fields = Vec::with_capacity(group_expr.len() + aggr_expr.len());
     for (expr, name) in group_expr {
-        fields.push(Field::new(
+        let field = Field::new(
             name,
             expr.data_type(input_schema)?,
             // In cases where we have multiple grouping sets, we will use NULL expressions in
             // order to align the grouping sets. So the field must be nullable even if the underlying
             // schema field is not.
             contains_null_expr || expr.nullable(input_schema)?,
-        ))
+        );
+        // keep the collation of the group values, so that the final
+        // aggregation and repartitioning group them the same way
+        fields.push(match collation_of(expr, input_schema) {
+            Some(collation) => field.with_metadata(collation.to_metadata()),
+            None => field,
+        })
     }
 
     match mode {
@@ -1590,6 +1598,8 @@ mod tests {
             // When spilling, the output rows metrics become partial output size + final output size
             // This is because final aggregation starts while partial aggregation is still emitting
             assert_eq!(8, output_rows);
+            assert!(metrics.spill_count().unwrap() > 0);
+            assert!(metrics.spilled_bytes().unwrap() > 0);
         } else {
             assert_eq!(3, output_rows);
         }
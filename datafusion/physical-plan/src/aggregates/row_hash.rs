@@ -27,8 +27,8 @@ use crate::aggregates::{
     evaluate_group_by, evaluate_many, evaluate_optional, group_schema, AggregateMode,
     PhysicalGroupBy,
 };
-use crate::common::IPCWriter;
-use crate::metrics::{BaselineMetrics, RecordOutput};
+use crate::common::{spill_write_options, IPCWriter};
+use crate::metrics::{BaselineMetrics, Count, MetricBuilder, RecordOutput};
 use crate::sorts::sort::{read_spill_as_stream, sort_batch};
 use crate::sorts::streaming_merge;
 use crate::stream::RecordBatchStreamAdapter;
@@ -36,6 +36,7 @@ use crate::{aggregates, ExecutionPlan, PhysicalExpr};
 use crate::{RecordBatchStream, SendableRecordBatchStream};
 
 use arrow::array::*;
+use arrow::ipc::writer::IpcWriteOptions;
 use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
 use arrow_schema::SortOptions;
 use datafusion_common::{DataFusionError, Result};
@@ -86,6 +87,15 @@ struct SpillState {
 
     /// GROUP BY expressions for merging spilled data
     merging_group_by: PhysicalGroupBy,
+
+    /// Options used to write spill files, including their compression
+    spill_write_options: IpcWriteOptions,
+
+    /// count of spills during the execution of the operator
+    spill_count: Count,
+
+    /// total spilled bytes during the execution of the operator
+    spilled_bytes: Count,
 }
 
 /// HashTable based Grouping Aggregator
@@ -353,6 +363,8 @@ impl GroupedHashAggregateStream {
 
         let exec_state = ExecutionState::ReadingInput;
 
+        let execution_options = &context.session_config().options().execution;
+        let write_options = spill_write_options(&execution_options.spill_compression)?;
         let spill_state = SpillState {
             spills: vec![],
             spill_expr,
@@ -360,6 +372,9 @@ impl GroupedHashAggregateStream {
             is_stream_merging: false,
             merging_aggregate_arguments,
             merging_group_by: PhysicalGroupBy::new_single(agg_group_by.expr.clone()),
+            spill_write_options: write_options,
+            spill_count: MetricBuilder::new(&agg.metrics).spill_count(partition),
+            spilled_bytes: MetricBuilder::new(&agg.metrics).spilled_bytes(partition),
         };
 
         Ok(GroupedHashAggregateStream {
@@ -684,8 +699,12 @@ impl GroupedHashAggregateStream {
     fn spill(&mut self) -> Result<()> {
         let emit = self.emit(EmitTo::All, true)?;
         let sorted = sort_batch(&emit, &self.spill_state.spill_expr, None)?;
-        let spillfile = self.runtime.disk_manager.create_tmp_file("HashAggSpill")?;
-        let mut writer = IPCWriter::new(spillfile.path(), &emit.schema())?;
+        let mut spillfile = self.runtime.disk_manager.create_tmp_file("HashAggSpill")?;
+        let mut writer = IPCWriter::new_with_options(
+            spillfile.path(),
+            &emit.schema(),
+            self.spill_state.spill_write_options.clone(),
+        )?;
         // TODO: slice large `sorted` and write to multiple files in parallel
         let mut offset = 0;
         let total_rows = sorted.num_rows();
@@ -695,9 +714,16 @@ impl GroupedHashAggregateStream {
             let batch = sorted.slice(offset, length);
             offset += batch.num_rows();
             writer.write(&batch)?;
+            // fail as soon as the temporary files exceed their size limit
+            spillfile.update_disk_usage()?;
         }
 
         writer.finish()?;
+        spillfile.update_disk_usage()?;
+        self.spill_state.spill_count.add(1);
+        self.spill_state
+            .spilled_bytes
+            .add(spillfile.current_disk_usage() as usize);
         self.spill_state.spills.push(spillfile);
         Ok(())
     }
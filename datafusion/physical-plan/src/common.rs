@@ -29,6 +29,7 @@ use crate::{ColumnStatistics, ExecutionPlan, Statistics};
 
 use arrow::datatypes::Schema;
 use arrow::ipc::writer::{FileWriter, IpcWriteOptions};
+use arrow::ipc::CompressionType;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::stats::Precision;
 use datafusion_common::{plan_err, DataFusionError, Result};
@@ -367,6 +368,22 @@ impl IPCWriter {
     }
 }
 
+/// Returns the [`IpcWriteOptions`] for writing spill files compressed with
+/// `compression`, as configured by `datafusion.execution.spill_compression`
+pub fn spill_write_options(compression: &str) -> Result<IpcWriteOptions> {
+    let compression = match compression.to_lowercase().as_str() {
+        "uncompressed" => None,
+        "lz4_frame" => Some(CompressionType::LZ4_FRAME),
+        "zstd" => Some(CompressionType::ZSTD),
+        other => {
+            return plan_err!(
+                "Unknown spill compression {other}, valid values are: uncompressed, lz4_frame and zstd"
+            )
+        }
+    };
+    Ok(IpcWriteOptions::default().try_with_compression(compression)?)
+}
+
 /// Returns the total number of bytes of memory occupied physically by this batch.
 #[deprecated(since = "28.0.0", note = "RecordBatch::get_array_memory_size")]
 pub fn batch_byte_size(batch: &RecordBatch) -> usize {
@@ -285,6 +285,10 @@ impl ExecutionPlan for FileSinkExec {
             stream,
         )))
     }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        self.sink.metrics()
+    }
 }
 
 /// Create a output record batch with a count
@@ -33,7 +33,8 @@ use std::task::{Context, Poll};
 use crate::expressions::{Column, PhysicalSortExpr};
 use crate::joins::utils::{
     build_join_schema, calculate_join_output_ordering, check_join_is_valid,
-    estimate_join_statistics, partitioned_join_output_partitioning, JoinOn,
+    estimate_join_statistics, evaluate_join_keys, partitioned_join_output_partitioning,
+    JoinOn,
 };
 use crate::metrics::{ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
 use crate::{
@@ -483,15 +484,15 @@ struct StreamedBatch {
 }
 
 impl StreamedBatch {
-    fn new(batch: RecordBatch, on_column: &[Column]) -> Self {
-        let join_arrays = join_arrays(&batch, on_column);
-        StreamedBatch {
+    fn try_new(batch: RecordBatch, on_column: &[Column]) -> Result<Self> {
+        let join_arrays = evaluate_join_keys(on_column, &batch)?;
+        Ok(StreamedBatch {
             batch,
             idx: 0,
             join_arrays,
             output_indices: vec![],
             buffered_batch_idx: None,
-        }
+        })
     }
 
     fn new_empty(schema: SchemaRef) -> Self {
@@ -547,8 +548,12 @@ struct BufferedBatch {
 }
 
 impl BufferedBatch {
-    fn new(batch: RecordBatch, range: Range<usize>, on_column: &[Column]) -> Self {
-        let join_arrays = join_arrays(&batch, on_column);
+    fn try_new(
+        batch: RecordBatch,
+        range: Range<usize>,
+        on_column: &[Column],
+    ) -> Result<Self> {
+        let join_arrays = evaluate_join_keys(on_column, &batch)?;
 
         // Estimation is calculated as
         //   inner batch size
@@ -565,13 +570,13 @@ impl BufferedBatch {
             + mem::size_of::<Range<usize>>()
             + mem::size_of::<usize>();
 
-        BufferedBatch {
+        Ok(BufferedBatch {
             batch,
             range,
             join_arrays,
             null_joined: vec![],
             size_estimation,
-        }
+        })
     }
 }
 
@@ -799,7 +804,7 @@ impl SMJStream {
                             self.join_metrics.input_batches.add(1);
                             self.join_metrics.input_rows.add(batch.num_rows());
                             self.streamed_batch =
-                                StreamedBatch::new(batch, &self.on_streamed);
+                                StreamedBatch::try_new(batch, &self.on_streamed)?;
                             self.streamed_state = StreamedState::Ready;
                         }
                     }
@@ -855,7 +860,7 @@ impl SMJStream {
                         self.join_metrics.input_rows.add(batch.num_rows());
                         if batch.num_rows() > 0 {
                             let buffered_batch =
-                                BufferedBatch::new(batch, 0..1, &self.on_buffered);
+                                BufferedBatch::try_new(batch, 0..1, &self.on_buffered)?;
                             self.reservation.try_grow(buffered_batch.size_estimation)?;
                             self.join_metrics
                                 .peak_mem_used
@@ -897,11 +902,11 @@ impl SMJStream {
                                 self.join_metrics.input_batches.add(1);
                                 self.join_metrics.input_rows.add(batch.num_rows());
                                 if batch.num_rows() > 0 {
-                                    let buffered_batch = BufferedBatch::new(
+                                    let buffered_batch = BufferedBatch::try_new(
                                         batch,
                                         0..0,
                                         &self.on_buffered,
-                                    );
+                                    )?;
                                     self.reservation
                                         .try_grow(buffered_batch.size_estimation)?;
                                     self.join_metrics
@@ -1217,14 +1222,6 @@ impl BufferedData {
     }
 }
 
-/// Get join array refs of given batch and join columns
-fn join_arrays(batch: &RecordBatch, on_column: &[Column]) -> Vec<ArrayRef> {
-    on_column
-        .iter()
-        .map(|c| batch.column(c.index()).clone())
-        .collect()
-}
-
 /// Get comparison result of two rows of join arrays
 fn compare_join_arrays(
     left_arrays: &[ArrayRef],
@@ -1390,12 +1387,13 @@ mod tests {
     use crate::test::build_table_i32;
     use crate::{common, ExecutionPlan};
 
-    use arrow::array::{Date32Array, Date64Array, Int32Array};
+    use arrow::array::{Date32Array, Date64Array, Int32Array, StringArray};
     use arrow::compute::SortOptions;
     use arrow::datatypes::{DataType, Field, Schema};
     use arrow::record_batch::RecordBatch;
     use datafusion_common::{
-        assert_batches_eq, assert_batches_sorted_eq, assert_contains, JoinType, Result,
+        assert_batches_eq, assert_batches_sorted_eq, assert_contains, Collation,
+        JoinType, Result,
     };
     use datafusion_execution::config::SessionConfig;
     use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
@@ -1602,6 +1600,52 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn join_inner_collated() -> Result<()> {
+        // both sides are sorted by their nocase collation keys
+        let build_table =
+            |names: (&str, Vec<&str>), ids: (&str, Vec<i32>)| -> Arc<dyn ExecutionPlan> {
+                let schema = Arc::new(Schema::new(vec![
+                    Field::new(names.0, DataType::Utf8, false)
+                        .with_metadata(Collation::NoCase.to_metadata()),
+                    Field::new(ids.0, DataType::Int32, false),
+                ]));
+                let batch = RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(StringArray::from(names.1)),
+                        Arc::new(Int32Array::from(ids.1)),
+                    ],
+                )
+                .unwrap();
+                Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None).unwrap())
+            };
+        let left =
+            build_table(("n1", vec!["Alice", "bob", "Carol"]), ("a1", vec![1, 2, 3]));
+        let right = build_table(
+            ("n2", vec!["ALICE", "BOB", "dave"]),
+            ("a2", vec![10, 20, 40]),
+        );
+
+        let on = vec![(
+            Column::new_with_schema("n1", &left.schema())?,
+            Column::new_with_schema("n2", &right.schema())?,
+        )];
+
+        let (_, batches) = join_collect(left, right, on, JoinType::Inner).await?;
+
+        let expected = [
+            "+-------+----+-------+----+",
+            "| n1    | a1 | n2    | a2 |",
+            "+-------+----+-------+----+",
+            "| Alice | 1  | ALICE | 10 |",
+            "| bob   | 2  | BOB   | 20 |",
+            "+-------+----+-------+----+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn join_inner_two() -> Result<()> {
         let left = build_table(
@@ -1211,8 +1211,10 @@ mod tests {
         partitioned_sym_join_with_filter, split_record_batches,
     };
 
+    use arrow::array::StringArray;
     use arrow::compute::SortOptions;
     use arrow::datatypes::{DataType, Field, IntervalUnit, Schema, TimeUnit};
+    use datafusion_common::{assert_batches_sorted_eq, Collation};
     use datafusion_execution::config::SessionConfig;
     use datafusion_expr::Operator;
     use datafusion_physical_expr::expressions::{binary, col, Column};
@@ -1517,6 +1519,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn join_collated_keys() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let batch = |name: &str, values: Vec<&str>| {
+            let field = Field::new(name, DataType::Utf8, false)
+                .with_metadata(Collation::NoCase.to_metadata());
+            RecordBatch::try_new(
+                Arc::new(Schema::new(vec![field])),
+                vec![Arc::new(StringArray::from(values))],
+            )
+        };
+        let left_batch = batch("n1", vec!["Alice", "bob"])?;
+        let right_batch = batch("n2", vec!["ALICE", "BOB", "carol"])?;
+        let on = vec![(
+            Column::new_with_schema("n1", &left_batch.schema())?,
+            Column::new_with_schema("n2", &right_batch.schema())?,
+        )];
+        let (left, right) =
+            create_memory_table(vec![left_batch], vec![right_batch], vec![], vec![])?;
+
+        let batches = partitioned_sym_join_with_filter(
+            left,
+            right,
+            on,
+            None,
+            &JoinType::Inner,
+            false,
+            task_ctx,
+        )
+        .await?;
+
+        let expected = [
+            "+-------+-------+",
+            "| n1    | n2    |",
+            "+-------+-------+",
+            "| Alice | ALICE |",
+            "| bob   | BOB   |",
+            "+-------+-------+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[rstest]
     #[tokio::test(flavor = "multi_thread")]
     async fn join_all_one_descending_numeric_particular(
@@ -28,7 +28,7 @@ use crate::metrics::{self, ExecutionPlanMetricsSet, MetricBuilder};
 use crate::{ColumnStatistics, ExecutionPlan, Partitioning, Statistics};
 
 use arrow::array::{
-    downcast_array, new_null_array, Array, BooleanBufferBuilder, UInt32Array,
+    downcast_array, new_null_array, Array, ArrayRef, BooleanBufferBuilder, UInt32Array,
     UInt32Builder, UInt64Array,
 };
 use arrow::compute;
@@ -37,11 +37,11 @@ use arrow::record_batch::{RecordBatch, RecordBatchOptions};
 use datafusion_common::cast::as_boolean_array;
 use datafusion_common::stats::Precision;
 use datafusion_common::{
-    plan_datafusion_err, plan_err, DataFusionError, JoinSide, JoinType, Result,
-    SharedResult,
+    plan_datafusion_err, plan_err, Collation, DataFusionError, JoinSide, JoinType,
+    Result, SharedResult,
 };
 use datafusion_physical_expr::equivalence::add_offset_to_expr;
-use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_expr::expressions::{collation_keys, Column};
 use datafusion_physical_expr::intervals::{ExprIntervalGraph, Interval, IntervalBound};
 use datafusion_physical_expr::utils::merge_vectors;
 use datafusion_physical_expr::{
@@ -57,6 +57,23 @@ pub type JoinOn = Vec<(Column, Column)>;
 /// Reference for JoinOn.
 pub type JoinOnRef<'a> = &'a [(Column, Column)];
 
+/// Evaluates the join key columns `on` against `batch`.
+///
+/// Columns with a collation are replaced by their collation keys, so that
+/// hashing, comparing and merging the keys respects the collation.
+pub(crate) fn evaluate_join_keys(
+    on: &[Column],
+    batch: &RecordBatch,
+) -> Result<Vec<ArrayRef>> {
+    let schema = batch.schema();
+    on.iter()
+        .map(|c| {
+            let collation = Collation::from_metadata(schema.field(c.index()).metadata());
+            collation_keys(c.evaluate(batch)?, collation)?.into_array(batch.num_rows())
+        })
+        .collect()
+}
+
 /// Checks whether the schemas "left" and "right" and columns "on" represent a valid join.
 /// They are valid whenever their columns' intersection equals the set `on`
 pub fn check_join_is_valid(left: &Schema, right: &Schema, on: JoinOnRef) -> Result<()> {
@@ -60,6 +60,7 @@ pub mod memory;
 pub mod metrics;
 pub mod projection;
 pub mod repartition;
+pub mod shared;
 pub mod sorts;
 pub mod stream;
 pub mod streaming;
@@ -209,6 +209,12 @@ impl MemoryExec {
     pub fn original_schema(&self) -> SchemaRef {
         self.schema.clone()
     }
+
+    /// The orderings the partitions are known to satisfy, see
+    /// [`Self::with_sort_information`]
+    pub fn sort_information(&self) -> &[LexOrdering] {
+        &self.sort_information
+    }
 }
 
 /// Iterator over batches
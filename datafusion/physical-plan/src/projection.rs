@@ -38,7 +38,7 @@ use arrow::record_batch::{RecordBatch, RecordBatchOptions};
 use datafusion_common::stats::Precision;
 use datafusion_common::Result;
 use datafusion_execution::TaskContext;
-use datafusion_physical_expr::expressions::{Literal, UnKnownColumn};
+use datafusion_physical_expr::expressions::{CollateExpr, Literal, UnKnownColumn};
 use datafusion_physical_expr::EquivalenceProperties;
 
 use datafusion_physical_expr::equivalence::ProjectionMapping;
@@ -252,11 +252,19 @@ impl ExecutionPlan for ProjectionExec {
 }
 
 /// If e is a direct column reference, returns the field level
-/// metadata for that field, if any. Otherwise returns None
+/// metadata for that field, if any. If e is collated, returns the metadata
+/// holding its collation. Otherwise returns None
 fn get_field_metadata(
     e: &Arc<dyn PhysicalExpr>,
     input_schema: &Schema,
 ) -> Option<HashMap<String, String>> {
+    if let Some(collate) = e.as_any().downcast_ref::<CollateExpr>() {
+        let mut metadata =
+            get_field_metadata(collate.expr(), input_schema).unwrap_or_default();
+        metadata.extend(collate.collation().to_metadata());
+        return Some(metadata);
+    }
+
     let name = if let Some(column) = e.as_any().downcast_ref::<Column>() {
         column.name()
     } else {
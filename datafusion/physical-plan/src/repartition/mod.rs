@@ -34,7 +34,7 @@ use crate::{DisplayFormatType, ExecutionPlan, Partitioning, Statistics};
 use self::distributor_channels::{DistributionReceiver, DistributionSender};
 
 use super::common::{AbortOnDropMany, AbortOnDropSingle, SharedMemoryReservation};
-use super::expressions::PhysicalSortExpr;
+use super::expressions::{collation_keys, collation_of, PhysicalSortExpr};
 use super::metrics::{self, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
 use super::{DisplayAs, RecordBatchStream, SendableRecordBatchStream};
 
@@ -167,9 +167,16 @@ impl BatchPartitioner {
                 } => {
                     let timer = self.timer.timer();
 
+                    // collated expressions are hashed by their collation keys,
+                    // so that equal keys land in the same partition
+                    let schema = batch.schema();
                     let arrays = exprs
                         .iter()
-                        .map(|expr| expr.evaluate(&batch)?.into_array(batch.num_rows()))
+                        .map(|expr| {
+                            let value = expr.evaluate(&batch)?;
+                            collation_keys(value, collation_of(expr, &schema))?
+                                .into_array(batch.num_rows())
+                        })
                         .collect::<Result<Vec<_>>>()?;
 
                     hash_buffer.clear();
@@ -0,0 +1,729 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! SharedExec executes its input once and fans the results out to
+//! several consumers of the same plan.
+
+use std::any::Any;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::File;
+use std::io::BufReader;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+use super::expressions::PhysicalSortExpr;
+use super::metrics::{
+    BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
+};
+use super::{DisplayAs, Statistics};
+use crate::common::IPCWriter;
+use crate::{
+    displayable, DisplayFormatType, ExecutionPlan, Partitioning, RecordBatchStream,
+    SendableRecordBatchStream,
+};
+
+use arrow::datatypes::SchemaRef;
+use arrow::ipc::reader::FileReader;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::runtime_env::RuntimeEnv;
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::EquivalenceProperties;
+
+use futures::stream::{Stream, StreamExt};
+use log::debug;
+use parking_lot::Mutex;
+
+/// Executes its input once and shares the results between several
+/// consumers.
+///
+/// The same [`SharedExec`] instance appears `num_consumers` times in
+/// the plan, once for each place the common subplan is used. Each
+/// partition of the input is executed once, when the first consumer
+/// calls [`ExecutionPlan::execute`] for it, and every batch produced
+/// is returned to all consumers of that partition.
+///
+/// Consumers may progress at different rates. Batches are buffered
+/// until every consumer has read them, and are spilled to disk if
+/// the buffer can not be grown within the [`MemoryPool`].
+///
+/// Use [`share_common_subplans`] to make all [`SharedExec`]s with the
+/// same id refer to the same instance after physical optimization.
+///
+/// [`MemoryPool`]: datafusion_execution::memory_pool::MemoryPool
+#[derive(Debug)]
+pub struct SharedExec {
+    /// Identifies the common subplan
+    id: usize,
+    /// The input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// The number of consumers of each partition
+    num_consumers: usize,
+    /// The state of the currently executing partitions
+    partitions: Mutex<Vec<Option<Arc<Mutex<SharedPartition>>>>>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl SharedExec {
+    /// Create a new SharedExec for the common subplan `id`
+    pub fn new(id: usize, input: Arc<dyn ExecutionPlan>) -> Self {
+        let num_partitions = input.output_partitioning().partition_count();
+        Self {
+            id,
+            input,
+            num_consumers: 1,
+            partitions: Mutex::new(vec![None; num_partitions]),
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Set the number of consumers that read the output of this plan
+    pub fn with_num_consumers(mut self, num_consumers: usize) -> Self {
+        self.num_consumers = num_consumers.max(1);
+        self
+    }
+
+    /// The id of the common subplan
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The number of consumers that read the output of this plan
+    pub fn num_consumers(&self) -> usize {
+        self.num_consumers
+    }
+}
+
+impl DisplayAs for SharedExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(
+                    f,
+                    "SharedExec: id={}, consumers={}",
+                    self.id, self.num_consumers
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for SharedExec {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.input.output_partitioning()
+    }
+
+    fn unbounded_output(&self, children: &[bool]) -> Result<bool> {
+        Ok(children[0])
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        self.input.output_ordering()
+    }
+
+    /// The input is shared by several consumers, so the ordering
+    /// requirements of one consumer must not be pushed into it
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    fn equivalence_properties(&self) -> EquivalenceProperties {
+        self.input.equivalence_properties()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(
+            SharedExec::new(self.id, children[0].clone())
+                .with_num_consumers(self.num_consumers),
+        ))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let mut partitions = self.partitions.lock();
+        let Some(slot) = partitions.get_mut(partition) else {
+            return internal_err!(
+                "SharedExec invalid partition {partition} (expected less than {})",
+                self.input.output_partitioning().partition_count()
+            );
+        };
+
+        // Start a new execution of the input once all consumers of the
+        // previous execution have subscribed
+        let shared = match slot {
+            Some(shared) if shared.lock().subscribed < self.num_consumers => {
+                Arc::clone(shared)
+            }
+            _ => {
+                debug!(
+                    "Start SharedExec::execute of id {} for partition {partition}",
+                    self.id
+                );
+                let input = self.input.execute(partition, context.clone())?;
+                let reservation =
+                    MemoryConsumer::new(format!("SharedExec[{}][{partition}]", self.id))
+                        .with_can_spill(true)
+                        .register(context.memory_pool());
+                let shared = Arc::new(Mutex::new(SharedPartition {
+                    input: Some(input),
+                    buffer: VecDeque::new(),
+                    offset: 0,
+                    cursors: vec![0; self.num_consumers],
+                    subscribed: 0,
+                    error: None,
+                    wakers: vec![],
+                    reservation,
+                    runtime: context.runtime_env(),
+                    spill_count: MetricBuilder::new(&self.metrics).spill_count(partition),
+                    spilled_bytes: MetricBuilder::new(&self.metrics)
+                        .spilled_bytes(partition),
+                }));
+                *slot = Some(Arc::clone(&shared));
+                shared
+            }
+        };
+
+        let consumer = {
+            let mut state = shared.lock();
+            state.subscribed += 1;
+            state.subscribed - 1
+        };
+
+        Ok(Box::pin(SharedStream {
+            shared,
+            consumer,
+            schema: self.input.schema(),
+            baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.input.statistics()
+    }
+}
+
+/// A batch buffered for consumers that have not yet read it
+enum BufferedBatch {
+    /// The batch is held in memory, using `size` reserved bytes
+    InMemory { batch: RecordBatch, size: usize },
+    /// The batch was spilled to a file in Arrow IPC format
+    Spilled(RefCountedTempFile),
+}
+
+impl BufferedBatch {
+    fn read(&self) -> Result<RecordBatch> {
+        match self {
+            Self::InMemory { batch, .. } => Ok(batch.clone()),
+            Self::Spilled(file) => {
+                let file = BufReader::new(File::open(file.path())?);
+                let mut reader = FileReader::try_new(file, None)?;
+                match reader.next() {
+                    Some(batch) => Ok(batch?),
+                    None => internal_err!("SharedExec spill file is empty"),
+                }
+            }
+        }
+    }
+}
+
+/// The state of one partition of a [`SharedExec`] that is shared by
+/// its consumers
+struct SharedPartition {
+    /// The input stream, `None` once it is exhausted
+    input: Option<SendableRecordBatchStream>,
+    /// Batches that have not yet been read by all consumers
+    buffer: VecDeque<BufferedBatch>,
+    /// The index of the first batch in `buffer`
+    offset: usize,
+    /// The index of the next batch to return to each consumer, or
+    /// `usize::MAX` once the consumer is done
+    cursors: Vec<usize>,
+    /// The number of consumers that have called execute
+    subscribed: usize,
+    /// The error returned by the input, if any
+    error: Option<String>,
+    /// The consumers waiting for the next batch of the input
+    wakers: Vec<Waker>,
+    /// Memory used by the batches in `buffer`
+    reservation: MemoryReservation,
+    /// The runtime used to create spill files
+    runtime: Arc<RuntimeEnv>,
+    /// count of spills during the execution of the operator
+    spill_count: Count,
+    /// total spilled bytes during the execution of the operator
+    spilled_bytes: Count,
+}
+
+impl SharedPartition {
+    fn poll_next(
+        &mut self,
+        consumer: usize,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        loop {
+            let cursor = self.cursors[consumer];
+            if cursor == usize::MAX {
+                return Poll::Ready(None);
+            }
+
+            if cursor < self.offset + self.buffer.len() {
+                let batch = self.buffer[cursor - self.offset].read();
+                self.cursors[consumer] += 1;
+                self.trim();
+                return Poll::Ready(Some(batch));
+            }
+
+            if let Some(error) = &self.error {
+                let error =
+                    DataFusionError::Execution(format!("Error in shared input: {error}"));
+                self.finish(consumer);
+                return Poll::Ready(Some(Err(error)));
+            }
+
+            let Some(input) = self.input.as_mut() else {
+                self.finish(consumer);
+                return Poll::Ready(None);
+            };
+
+            match input.poll_next_unpin(cx) {
+                Poll::Pending => {
+                    if !self.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                        self.wakers.push(cx.waker().clone());
+                    }
+                    return Poll::Pending;
+                }
+                Poll::Ready(Some(Ok(batch))) => {
+                    let pushed = self.push(batch);
+                    self.wake_all();
+                    if let Err(e) = pushed {
+                        self.input = None;
+                        self.error = Some(e.to_string());
+                        self.finish(consumer);
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    self.input = None;
+                    self.error = Some(e.to_string());
+                    self.wake_all();
+                    self.finish(consumer);
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => {
+                    self.input = None;
+                    self.wake_all();
+                }
+            }
+        }
+    }
+
+    /// Buffers `batch` for all consumers, spilling it to disk if there
+    /// is not enough memory
+    fn push(&mut self, batch: RecordBatch) -> Result<()> {
+        let size = batch.get_array_memory_size();
+        if self.reservation.try_grow(size).is_ok() {
+            self.buffer
+                .push_back(BufferedBatch::InMemory { batch, size });
+            return Ok(());
+        }
+
+        let mut spillfile = self.runtime.disk_manager.create_tmp_file("SharedExec")?;
+        let mut writer = IPCWriter::new(spillfile.path(), &batch.schema())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        spillfile.update_disk_usage()?;
+        self.spill_count.add(1);
+        self.spilled_bytes
+            .add(spillfile.current_disk_usage() as usize);
+        self.buffer.push_back(BufferedBatch::Spilled(spillfile));
+        Ok(())
+    }
+
+    /// Marks `consumer` as done and releases the batches it pinned
+    fn finish(&mut self, consumer: usize) {
+        self.cursors[consumer] = usize::MAX;
+        self.trim();
+    }
+
+    /// Releases the batches that have been read by all consumers
+    fn trim(&mut self) {
+        let min_cursor = self.cursors.iter().copied().min().unwrap_or(usize::MAX);
+        while self.offset < min_cursor {
+            match self.buffer.pop_front() {
+                Some(BufferedBatch::InMemory { size, .. }) => {
+                    self.reservation.shrink(size)
+                }
+                Some(BufferedBatch::Spilled(_)) => {}
+                None => break,
+            }
+            self.offset += 1;
+        }
+        if self.cursors.iter().all(|c| *c == usize::MAX) {
+            // all consumers are done, stop executing the input
+            self.input = None;
+        }
+    }
+
+    fn wake_all(&mut self) {
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// The stream returned to a single consumer of a [`SharedExec`]
+struct SharedStream {
+    /// The state shared with the other consumers
+    shared: Arc<Mutex<SharedPartition>>,
+    /// The index of this consumer
+    consumer: usize,
+    /// The output schema
+    schema: SchemaRef,
+    /// Execution metrics
+    baseline_metrics: BaselineMetrics,
+}
+
+impl Stream for SharedStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.shared.lock().poll_next(self.consumer, cx);
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
+impl RecordBatchStream for SharedStream {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+impl Drop for SharedStream {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.finish(self.consumer);
+        // another consumer may need to poll the input in our place
+        state.wake_all();
+    }
+}
+
+/// Makes all [`SharedExec`]s in `plan` with the same id refer to the
+/// same instance, and sets their number of consumers.
+///
+/// This must run after physical optimization, as rewriting the plan
+/// creates new instances of [`SharedExec`]. If optimization rewrote the
+/// copies of a common subplan differently, they can no longer be shared,
+/// and each copy is executed on its own instead.
+pub fn share_common_subplans(
+    plan: Arc<dyn ExecutionPlan>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    let mut displays = HashMap::new();
+    let mut diverged = HashSet::new();
+    find_diverged(&plan, &mut displays, &mut diverged);
+    if displays.is_empty() {
+        return Ok(plan);
+    }
+    for id in &diverged {
+        debug!(
+            "SharedExec copies of common subplan {id} differ after optimization, \
+             executing them unshared"
+        );
+    }
+    let mut consumers = HashMap::new();
+    count_consumers(&plan, &diverged, &mut consumers);
+    unify(plan, &consumers, &diverged, &mut HashMap::new())
+}
+
+/// Collects the ids of the [`SharedExec`]s whose copies have different
+/// inputs, along with the display of the input of each id
+fn find_diverged(
+    plan: &Arc<dyn ExecutionPlan>,
+    displays: &mut HashMap<usize, String>,
+    diverged: &mut HashSet<usize>,
+) {
+    if let Some(shared) = plan.as_any().downcast_ref::<SharedExec>() {
+        let display = displayable(shared.input.as_ref()).indent(true).to_string();
+        match displays.get(&shared.id) {
+            Some(existing) if *existing != display => {
+                diverged.insert(shared.id);
+            }
+            Some(_) => {}
+            None => {
+                displays.insert(shared.id, display);
+            }
+        }
+    }
+    for child in plan.children() {
+        find_diverged(&child, displays, diverged);
+    }
+}
+
+/// Counts the consumers of each [`SharedExec`] id, visiting the input
+/// of each [`SharedExec`] only once as it is only executed once. The
+/// inputs of `diverged` ids are executed once per copy instead.
+fn count_consumers(
+    plan: &Arc<dyn ExecutionPlan>,
+    diverged: &HashSet<usize>,
+    consumers: &mut HashMap<usize, usize>,
+) {
+    if let Some(shared) = plan.as_any().downcast_ref::<SharedExec>() {
+        if !diverged.contains(&shared.id) {
+            let count = consumers.entry(shared.id).or_insert(0);
+            *count += 1;
+            if *count > 1 {
+                return;
+            }
+        }
+    }
+    for child in plan.children() {
+        count_consumers(&child, diverged, consumers);
+    }
+}
+
+fn unify(
+    plan: Arc<dyn ExecutionPlan>,
+    consumers: &HashMap<usize, usize>,
+    diverged: &HashSet<usize>,
+    shared_plans: &mut HashMap<usize, Arc<dyn ExecutionPlan>>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if let Some(shared) = plan.as_any().downcast_ref::<SharedExec>() {
+        if diverged.contains(&shared.id) {
+            // execute this copy on its own
+            return unify(shared.input.clone(), consumers, diverged, shared_plans);
+        }
+        if let Some(existing) = shared_plans.get(&shared.id) {
+            return Ok(Arc::clone(existing));
+        }
+        let input = unify(shared.input.clone(), consumers, diverged, shared_plans)?;
+        let new_plan: Arc<dyn ExecutionPlan> = Arc::new(
+            SharedExec::new(shared.id, input).with_num_consumers(consumers[&shared.id]),
+        );
+        shared_plans.insert(shared.id, Arc::clone(&new_plan));
+        return Ok(new_plan);
+    }
+
+    let children = plan.children();
+    if children.is_empty() {
+        return Ok(plan);
+    }
+    let new_children = children
+        .iter()
+        .map(|child| unify(child.clone(), consumers, diverged, shared_plans))
+        .collect::<Result<Vec<_>>>()?;
+    if children
+        .iter()
+        .zip(new_children.iter())
+        .all(|(old, new)| Arc::ptr_eq(old, new))
+    {
+        Ok(plan)
+    } else {
+        plan.with_new_children(new_children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::exec::{BarrierExec, MockExec};
+    use crate::union::UnionExec;
+    use crate::{collect, common, test};
+
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_execution::runtime_env::RuntimeConfig;
+
+    fn count_shared(plan: &Arc<dyn ExecutionPlan>) -> usize {
+        let mut count = 0;
+        let mut stack = vec![Arc::clone(plan)];
+        while let Some(plan) = stack.pop() {
+            if plan.as_any().downcast_ref::<SharedExec>().is_some() {
+                count += 1;
+            }
+            stack.extend(plan.children());
+        }
+        count
+    }
+
+    #[tokio::test]
+    async fn test_share_common_subplans() -> Result<()> {
+        let input = test::scan_partitioned(2);
+        let union: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            Arc::new(SharedExec::new(0, input.clone())) as _,
+            Arc::new(SharedExec::new(0, input.clone())) as _,
+            Arc::new(SharedExec::new(1, input)) as _,
+        ]));
+
+        let plan = share_common_subplans(union)?;
+        assert_eq!(count_shared(&plan), 3);
+
+        let children = plan.children();
+        assert!(Arc::ptr_eq(&children[0], &children[1]));
+        assert!(!Arc::ptr_eq(&children[0], &children[2]));
+
+        let shared = children[0].as_any().downcast_ref::<SharedExec>().unwrap();
+        assert_eq!(shared.num_consumers(), 2);
+        let shared = children[2].as_any().downcast_ref::<SharedExec>().unwrap();
+        assert_eq!(shared.num_consumers(), 1);
+
+        let batches = collect(plan, Arc::new(TaskContext::default())).await?;
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 600);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_share_differing_copies() -> Result<()> {
+        let input = test::scan_partitioned(2);
+        let union: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            Arc::new(SharedExec::new(0, input.clone())) as _,
+            Arc::new(SharedExec::new(0, test::scan_partitioned(1))) as _,
+            Arc::new(SharedExec::new(1, input.clone())) as _,
+            Arc::new(SharedExec::new(1, input)) as _,
+        ]));
+
+        // the differing copies of subplan 0 are executed unshared
+        let plan = share_common_subplans(union)?;
+        assert_eq!(count_shared(&plan), 2);
+        let children = plan.children();
+        assert!(children[0].as_any().downcast_ref::<SharedExec>().is_none());
+        assert!(children[1].as_any().downcast_ref::<SharedExec>().is_none());
+        assert!(Arc::ptr_eq(&children[2], &children[3]));
+        let shared = children[2].as_any().downcast_ref::<SharedExec>().unwrap();
+        assert_eq!(shared.num_consumers(), 2);
+
+        let batches = collect(plan, Arc::new(TaskContext::default())).await?;
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 700);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_is_opaque_to_sort_pushdown() {
+        let shared = SharedExec::new(0, test::scan_partitioned(1));
+        assert_eq!(shared.maintains_input_order(), vec![false]);
+        assert!(shared.required_input_ordering()[0].is_none());
+    }
+
+    #[tokio::test]
+    async fn test_shared_executes_input_once() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let input = Arc::new(MockExec::new(vec![Ok(batch.clone())], schema));
+        let shared = Arc::new(SharedExec::new(0, input).with_num_consumers(2));
+
+        let mut s1 = shared.execute(0, task_ctx.clone())?;
+        let mut s2 = shared.execute(0, task_ctx.clone())?;
+
+        // the first consumer reads everything before the second starts
+        assert_eq!(s1.next().await.unwrap()?, batch);
+        assert!(s1.next().await.is_none());
+        assert_eq!(s2.next().await.unwrap()?, batch);
+        assert!(s2.next().await.is_none());
+
+        // all batches were released
+        assert_eq!(task_ctx.runtime_env().memory_pool.reserved(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shared_spill() -> Result<()> {
+        let runtime = RuntimeEnv::new(RuntimeConfig::new().with_memory_limit(1, 1.0))?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(Arc::new(runtime)));
+
+        let input = test::scan_partitioned(1);
+        let shared = Arc::new(SharedExec::new(0, input).with_num_consumers(2));
+
+        let s1 = shared.execute(0, task_ctx.clone())?;
+        let s2 = shared.execute(0, task_ctx.clone())?;
+        let b1 = common::collect(s1).await?;
+        let b2 = common::collect(s2).await?;
+        assert_eq!(b1, b2);
+        assert_eq!(b1.iter().map(|b| b.num_rows()).sum::<usize>(), 100);
+
+        let metrics = shared.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+        assert_eq!(task_ctx.runtime_env().disk_manager.used_disk_space(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shared_concurrent_consumers() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let schema = test::aggr_test_schema();
+        let batch = RecordBatch::new_empty(schema.clone());
+        let input = Arc::new(BarrierExec::new(vec![vec![batch; 10]], schema));
+        let shared = Arc::new(SharedExec::new(0, input.clone()).with_num_consumers(3));
+
+        let handles = (0..3)
+            .map(|_| {
+                let stream = shared.execute(0, task_ctx.clone()).unwrap();
+                tokio::spawn(common::collect(stream))
+            })
+            .collect::<Vec<_>>();
+        input.wait().await;
+
+        for handle in handles {
+            let batches = handle.await.unwrap()?;
+            assert_eq!(batches.len(), 10);
+        }
+        Ok(())
+    }
+}
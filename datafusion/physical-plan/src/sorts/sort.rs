@@ -24,10 +24,10 @@ use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::fs::File;
 use std::io::BufReader;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::sync::Arc;
 
-use crate::common::{spawn_buffered, IPCWriter};
+use crate::common::{spawn_buffered, spill_write_options, IPCWriter};
 use crate::expressions::PhysicalSortExpr;
 use crate::metrics::{
     BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet,
@@ -43,6 +43,7 @@ use crate::{
 use arrow::compute::{concat_batches, lexsort_to_indices, take};
 use arrow::datatypes::SchemaRef;
 use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::IpcWriteOptions;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::{exec_err, plan_err, DataFusionError, Result};
 use datafusion_execution::disk_manager::RefCountedTempFile;
@@ -230,6 +231,8 @@ struct ExternalSorter {
     /// the data will be concated and sorted in place rather than
     /// sort/merged.
     sort_in_place_threshold_bytes: usize,
+    /// Options used to write spill files, including their compression
+    spill_write_options: IpcWriteOptions,
 }
 
 impl ExternalSorter {
@@ -244,6 +247,7 @@ impl ExternalSorter {
         fetch: Option<usize>,
         sort_spill_reservation_bytes: usize,
         sort_in_place_threshold_bytes: usize,
+        spill_write_options: IpcWriteOptions,
         metrics: &ExecutionPlanMetricsSet,
         runtime: Arc<RuntimeEnv>,
     ) -> Self {
@@ -270,6 +274,7 @@ impl ExternalSorter {
             batch_size,
             sort_spill_reservation_bytes,
             sort_in_place_threshold_bytes,
+            spill_write_options,
         }
     }
 
@@ -388,10 +393,18 @@ impl ExternalSorter {
 
         let spillfile = self.runtime.disk_manager.create_tmp_file("Sorting")?;
         let batches = std::mem::take(&mut self.in_mem_batches);
-        spill_sorted_batches(batches, spillfile.path(), self.schema.clone()).await?;
+        let spillfile = spill_sorted_batches(
+            batches,
+            spillfile,
+            self.schema.clone(),
+            self.spill_write_options.clone(),
+        )
+        .await?;
         let used = self.reservation.free();
         self.metrics.spill_count.add(1);
-        self.metrics.spilled_bytes.add(used);
+        self.metrics
+            .spilled_bytes
+            .add(spillfile.current_disk_usage() as usize);
         self.spills.push(spillfile);
         Ok(used)
     }
@@ -598,13 +611,17 @@ pub(crate) fn sort_batch(
     Ok(RecordBatch::try_new(batch.schema(), columns)?)
 }
 
+/// Writes `batches` to `spillfile`, returning it once written
 async fn spill_sorted_batches(
     batches: Vec<RecordBatch>,
-    path: &Path,
+    mut spillfile: RefCountedTempFile,
     schema: SchemaRef,
-) -> Result<()> {
-    let path: PathBuf = path.into();
-    let handle = task::spawn_blocking(move || write_sorted(batches, path, schema));
+    write_options: IpcWriteOptions,
+) -> Result<RefCountedTempFile> {
+    let handle = task::spawn_blocking(move || {
+        write_sorted(batches, &mut spillfile, schema, write_options)?;
+        Ok(spillfile)
+    });
     match handle.await {
         Ok(r) => r,
         Err(e) => exec_err!("Error occurred while spilling {e}"),
@@ -629,16 +646,23 @@ pub(crate) fn read_spill_as_stream(
     Ok(builder.build())
 }
 
+/// Writes `batches` to `spillfile`, updating its disk usage after every
+/// batch so that writing fails as soon as the temporary files exceed their
+/// size limit
 fn write_sorted(
     batches: Vec<RecordBatch>,
-    path: PathBuf,
+    spillfile: &mut RefCountedTempFile,
     schema: SchemaRef,
+    write_options: IpcWriteOptions,
 ) -> Result<()> {
-    let mut writer = IPCWriter::new(path.as_ref(), schema.as_ref())?;
+    let mut writer =
+        IPCWriter::new_with_options(spillfile.path(), schema.as_ref(), write_options)?;
     for batch in batches {
         writer.write(&batch)?;
+        spillfile.update_disk_usage()?;
     }
     writer.finish()?;
+    spillfile.update_disk_usage()?;
     debug!(
         "Spilled {} batches of total {} rows to disk, memory released {}",
         writer.num_batches,
@@ -897,6 +921,7 @@ impl ExecutionPlan for SortExec {
                 self.fetch,
                 execution_options.sort_spill_reservation_bytes,
                 execution_options.sort_in_place_threshold_bytes,
+                spill_write_options(&execution_options.spill_compression)?,
                 &self.metrics_set,
                 context.runtime_env(),
             );
@@ -942,6 +967,7 @@ mod tests {
     use arrow::datatypes::*;
     use datafusion_common::cast::as_primitive_array;
     use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::disk_manager::{DiskManager, DiskManagerConfig};
     use datafusion_execution::runtime_env::RuntimeConfig;
 
     use futures::FutureExt;
@@ -1031,6 +1057,125 @@ mod tests {
         Ok(())
     }
 
+    /// Sorts 100 batches with a memory limit that forces spilling,
+    /// returning the number of spilled bytes
+    async fn sort_spill_with_config(
+        session_config: SessionConfig,
+        disk_manager: DiskManagerConfig,
+    ) -> Result<usize> {
+        let sort_spill_reservation_bytes = session_config
+            .options()
+            .execution
+            .sort_spill_reservation_bytes;
+        let rt_config = RuntimeConfig::new()
+            .with_memory_limit(sort_spill_reservation_bytes + 12288, 1.0)
+            .with_disk_manager(disk_manager);
+        let runtime = Arc::new(RuntimeEnv::new(rt_config)?);
+        let task_ctx = Arc::new(
+            TaskContext::default()
+                .with_session_config(session_config)
+                .with_runtime(runtime),
+        );
+
+        let input = test::scan_partitioned(100);
+        let schema = input.schema();
+        let sort_exec = Arc::new(SortExec::new(
+            vec![PhysicalSortExpr {
+                expr: col("i", &schema)?,
+                options: SortOptions::default(),
+            }],
+            Arc::new(CoalescePartitionsExec::new(input)),
+        ));
+
+        let result = collect(sort_exec.clone(), task_ctx.clone()).await?;
+        let num_rows: usize = result.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 10000);
+
+        let metrics = sort_exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+        assert_eq!(task_ctx.runtime_env().memory_pool.reserved(), 0);
+        assert_eq!(task_ctx.runtime_env().disk_manager.used_disk_space(), 0);
+        Ok(metrics.spilled_bytes().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_sort_spill_compression() -> Result<()> {
+        let uncompressed =
+            sort_spill_with_config(SessionConfig::new(), DiskManagerConfig::new())
+                .await?;
+
+        for compression in ["lz4_frame", "zstd"] {
+            let session_config = SessionConfig::new()
+                .set_str("datafusion.execution.spill_compression", compression);
+            let compressed =
+                sort_spill_with_config(session_config, DiskManagerConfig::new()).await?;
+            assert!(
+                compressed < uncompressed,
+                "{compression}: {compressed} >= {uncompressed}"
+            );
+        }
+
+        let session_config = SessionConfig::new()
+            .set_str("datafusion.execution.spill_compression", "snappy");
+        let err = sort_spill_with_config(session_config, DiskManagerConfig::new())
+            .await
+            .unwrap_err();
+        assert_eq!(err.strip_backtrace(), "Error during planning: Unknown spill compression snappy, valid values are: uncompressed, lz4_frame and zstd");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sort_spill_max_temp_directory_size() -> Result<()> {
+        let err = sort_spill_with_config(
+            SessionConfig::new(),
+            DiskManagerConfig::new().with_max_temp_directory_size(1024),
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "unexpected error: {err}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_spill_stops_at_max_temp_directory_size() -> Result<()> {
+        let batches: Vec<_> = (0..10)
+            .map(|_| {
+                let array: ArrayRef = Arc::new(Int32Array::from_iter_values(0..10_000));
+                RecordBatch::try_from_iter(vec![("i", array)])
+            })
+            .collect::<Result<_, _>>()?;
+        let schema = batches[0].schema();
+
+        let disk_manager = DiskManager::try_new(DiskManagerConfig::new())?;
+        let mut spillfile = disk_manager.create_tmp_file("test")?;
+        write_sorted(
+            batches.clone(),
+            &mut spillfile,
+            schema.clone(),
+            IpcWriteOptions::default(),
+        )?;
+        let full_size = spillfile.current_disk_usage();
+
+        let disk_manager = DiskManager::try_new(
+            DiskManagerConfig::new().with_max_temp_directory_size(full_size / 4),
+        )?;
+        let mut spillfile = disk_manager.create_tmp_file("test")?;
+        let err =
+            write_sorted(batches, &mut spillfile, schema, IpcWriteOptions::default())
+                .unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "unexpected error: {err}"
+        );
+        // writing stopped soon after the limit was crossed
+        assert!(spillfile.current_disk_usage() < full_size / 2);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_sort_fetch_memory_calculation() -> Result<()> {
         // This test mirrors down the size from the example above.
@@ -26,7 +26,6 @@ use datafusion_common::Result;
 use datafusion_execution::memory_pool::MemoryReservation;
 use futures::stream::{Fuse, StreamExt};
 use std::marker::PhantomData;
-use std::sync::Arc;
 use std::task::{ready, Context, Poll};
 
 /// A [`Stream`](futures::Stream) that has multiple partitions that can
@@ -82,7 +81,7 @@ pub struct RowCursorStream {
     /// Converter to convert output of physical expressions
     converter: RowConverter,
     /// The physical expressions to sort by
-    column_expressions: Vec<Arc<dyn PhysicalExpr>>,
+    column_expressions: Vec<PhysicalSortExpr>,
     /// Input streams
     streams: FusedStreams,
     /// Tracks the memory used by `converter`
@@ -109,7 +108,7 @@ impl RowCursorStream {
         Ok(Self {
             converter,
             reservation,
-            column_expressions: expressions.iter().map(|x| x.expr.clone()).collect(),
+            column_expressions: expressions.to_vec(),
             streams: FusedStreams(streams),
         })
     }
@@ -118,7 +117,7 @@ impl RowCursorStream {
         let cols = self
             .column_expressions
             .iter()
-            .map(|expr| expr.evaluate(batch)?.into_array(batch.num_rows()))
+            .map(|expr| Ok(expr.evaluate_to_sort_column(batch)?.values))
             .collect::<Result<Vec<_>>>()?;
 
         let rows = self.converter.convert_columns(&cols)?;
@@ -180,8 +179,7 @@ impl<T: CursorArray> FieldCursorStream<T> {
     }
 
     fn convert_batch(&mut self, batch: &RecordBatch) -> Result<ArrayValues<T::Values>> {
-        let value = self.sort.expr.evaluate(batch)?;
-        let array = value.into_array(batch.num_rows())?;
+        let array = self.sort.evaluate_to_sort_column(batch)?.values;
         let array = array.as_any().downcast_ref::<T>().expect("field values");
         Ok(ArrayValues::new(self.sort.options, array))
     }
@@ -53,6 +53,7 @@ pub trait PartitionStream: Send + Sync {
 /// use this struct to implement [`ExecutionPlan`].
 pub struct StreamingTableExec {
     partitions: Vec<Arc<dyn PartitionStream>>,
+    partition_schema: SchemaRef,
     projection: Option<Arc<[usize]>>,
     projected_schema: SchemaRef,
     projected_output_ordering: Option<LexOrdering>,
@@ -81,17 +82,43 @@ impl StreamingTableExec {
 
         let projected_schema = match projection {
             Some(p) => Arc::new(schema.project(p)?),
-            None => schema,
+            None => schema.clone(),
         };
 
         Ok(Self {
             partitions,
+            partition_schema: schema,
             projected_schema,
             projection: projection.cloned().map(Into::into),
             projected_output_ordering,
             infinite,
         })
     }
+
+    /// The partition streams of this plan
+    pub fn partitions(&self) -> &[Arc<dyn PartitionStream>] {
+        &self.partitions
+    }
+
+    /// The schema of the partition streams, before the projection
+    pub fn partition_schema(&self) -> &SchemaRef {
+        &self.partition_schema
+    }
+
+    /// The indices of the projected columns of the partition streams
+    pub fn projection(&self) -> Option<&[usize]> {
+        self.projection.as_deref()
+    }
+
+    /// The ordering of the projected output, if known
+    pub fn projected_output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        self.projected_output_ordering.as_deref()
+    }
+
+    /// Whether the partition streams are unbounded
+    pub fn is_infinite(&self) -> bool {
+        self.infinite
+    }
 }
 
 impl std::fmt::Debug for StreamingTableExec {
@@ -151,10 +151,7 @@ impl TopK {
         let sort_keys: Vec<ArrayRef> = self
             .expr
             .iter()
-            .map(|expr| {
-                let value = expr.expr.evaluate(&batch)?;
-                value.into_array(batch.num_rows())
-            })
+            .map(|expr| Ok(expr.evaluate_to_sort_column(&batch)?.values))
             .collect::<Result<Vec<_>>>()?;
 
         // reuse existing `Rows` to avoid reallocations
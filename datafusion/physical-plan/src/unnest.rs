@@ -75,6 +75,21 @@ impl UnnestExec {
             options,
         }
     }
+
+    /// Input execution plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The unnest column
+    pub fn column(&self) -> &Column {
+        &self.column
+    }
+
+    /// Unnest options
+    pub fn options(&self) -> &UnnestOptions {
+        &self.options
+    }
 }
 
 impl DisplayAs for UnnestExec {
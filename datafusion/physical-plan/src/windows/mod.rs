@@ -131,7 +131,7 @@ pub fn create_window_expr(
 }
 
 /// Creates an appropriate [`WindowExpr`] based on the window frame and
-fn window_expr_from_aggregate_expr(
+pub fn window_expr_from_aggregate_expr(
     partition_by: &[Arc<dyn PhysicalExpr>],
     order_by: &[PhysicalSortExpr],
     window_frame: Arc<WindowFrame>,
@@ -266,7 +266,7 @@ fn create_udwf_window_expr(
 
 /// Implements [`BuiltInWindowFunctionExpr`] for [`WindowUDF`]
 #[derive(Clone, Debug)]
-struct WindowUDFExpr {
+pub struct WindowUDFExpr {
     fun: Arc<WindowUDF>,
     args: Vec<Arc<dyn PhysicalExpr>>,
     /// Display name
@@ -275,6 +275,13 @@ struct WindowUDFExpr {
     data_type: Arc<DataType>,
 }
 
+impl WindowUDFExpr {
+    /// The user defined window function
+    pub fn fun(&self) -> &Arc<WindowUDF> {
+        &self.fun
+    }
+}
+
 impl BuiltInWindowFunctionExpr for WindowUDFExpr {
     fn as_any(&self) -> &dyn std::any::Any {
         self
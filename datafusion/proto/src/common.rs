@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use datafusion_common::{internal_err, DataFusionError, Result};
+use std::sync::Arc;
+
+use datafusion::execution::FunctionRegistry;
+use datafusion_common::{internal_err, plan_datafusion_err, DataFusionError, Result};
+use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
 
 pub(crate) fn str_to_byte(s: &String, description: &str) -> Result<u8> {
     if s.len() != 1 {
@@ -26,6 +30,48 @@ pub(crate) fn str_to_byte(s: &String, description: &str) -> Result<u8> {
     Ok(s.as_bytes()[0])
 }
 
+/// Look up a scalar UDF that was encoded by name only
+pub(crate) fn registered_udf(
+    registry: &dyn FunctionRegistry,
+    name: &str,
+) -> Result<Arc<ScalarUDF>> {
+    registry.udf(name).map_err(|e| {
+        plan_datafusion_err!(
+            "Scalar function {name} was encoded by name only and must be registered \
+             with the same name before decoding, or have its definition encoded by \
+             an extension codec: {e}"
+        )
+    })
+}
+
+/// Look up an aggregate UDF that was encoded by name only
+pub(crate) fn registered_udaf(
+    registry: &dyn FunctionRegistry,
+    name: &str,
+) -> Result<Arc<AggregateUDF>> {
+    registry.udaf(name).map_err(|e| {
+        plan_datafusion_err!(
+            "Aggregate function {name} was encoded by name only and must be registered \
+             with the same name before decoding, or have its definition encoded by \
+             an extension codec: {e}"
+        )
+    })
+}
+
+/// Look up a window UDF that was encoded by name only
+pub(crate) fn registered_udwf(
+    registry: &dyn FunctionRegistry,
+    name: &str,
+) -> Result<Arc<WindowUDF>> {
+    registry.udwf(name).map_err(|e| {
+        plan_datafusion_err!(
+            "Window function {name} was encoded by name only and must be registered \
+             with the same name before decoding, or have its definition encoded by \
+             an extension codec: {e}"
+        )
+    })
+}
+
 pub(crate) fn byte_to_string(b: u8, description: &str) -> Result<String> {
     let b = &[b];
     let b = std::str::from_utf8(b).map_err(|_| {
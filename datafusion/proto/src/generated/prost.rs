@@ -612,8 +612,22 @@ pub mod logical_expr_node {
         SimilarTo(::prost::alloc::boxed::Box<super::SimilarToNode>),
         #[prost(message, tag = "34")]
         Placeholder(super::PlaceholderNode),
+        #[prost(message, tag = "35")]
+        Lambda(::prost::alloc::boxed::Box<super::LambdaNode>),
     }
 }
+/// A lambda, the argument of higher-order functions such as array_transform
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LambdaNode {
+    #[prost(message, repeated, tag = "1")]
+    pub params: ::prost::alloc::vec::Vec<Field>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub body: ::core::option::Option<::prost::alloc::boxed::Box<LogicalExprNode>>,
+    /// The values of the trailing params, captured from the enclosing query
+    #[prost(message, repeated, tag = "3")]
+    pub captures: ::prost::alloc::vec::Vec<LogicalExprNode>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlaceholderNode {
@@ -809,6 +823,9 @@ pub struct AggregateUdfExprNode {
     pub filter: ::core::option::Option<::prost::alloc::boxed::Box<LogicalExprNode>>,
     #[prost(message, repeated, tag = "4")]
     pub order_by: ::prost::alloc::vec::Vec<LogicalExprNode>,
+    /// Definition of the function encoded by the extension codec, if any
+    #[prost(bytes = "vec", optional, tag = "5")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -817,6 +834,9 @@ pub struct ScalarUdfExprNode {
     pub fun_name: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
     pub args: ::prost::alloc::vec::Vec<LogicalExprNode>,
+    /// Definition of the function encoded by the extension codec, if any
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -830,6 +850,9 @@ pub struct WindowExprNode {
     /// repeated LogicalExprNode filter = 7;
     #[prost(message, optional, tag = "8")]
     pub window_frame: ::core::option::Option<WindowFrame>,
+    /// Definition of the udaf or udwf encoded by the extension codec, if any
+    #[prost(bytes = "vec", optional, tag = "10")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
     #[prost(oneof = "window_expr_node::WindowFunction", tags = "1, 2, 3, 9")]
     pub window_function: ::core::option::Option<window_expr_node::WindowFunction>,
 }
@@ -1178,7 +1201,7 @@ pub struct ScalarFixedSizeBinary {
 pub struct ScalarValue {
     #[prost(
         oneof = "scalar_value::Value",
-        tags = "33, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 20, 39, 21, 24, 25, 35, 36, 37, 38, 26, 27, 28, 29, 30, 31, 32, 34"
+        tags = "33, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 40, 20, 39, 21, 24, 25, 35, 36, 37, 38, 26, 27, 28, 29, 30, 31, 32, 34"
     )]
     pub value: ::core::option::Option<scalar_value::Value>,
 }
@@ -1224,6 +1247,9 @@ pub mod scalar_value {
         Time32Value(super::ScalarTime32Value),
         #[prost(message, tag = "17")]
         ListValue(super::ScalarListValue),
+        /// ScalarValue::Map is serialized like ScalarValue::List
+        #[prost(message, tag = "40")]
+        MapValue(super::ScalarListValue),
         #[prost(message, tag = "20")]
         Decimal128Value(super::Decimal128),
         #[prost(message, tag = "39")]
@@ -1286,7 +1312,7 @@ pub struct Decimal256 {
 pub struct ArrowType {
     #[prost(
         oneof = "arrow_type::ArrowTypeEnum",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 32, 15, 16, 31, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 33"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 32, 15, 16, 31, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 33, 34"
     )]
     pub arrow_type_enum: ::core::option::Option<arrow_type::ArrowTypeEnum>,
 }
@@ -1366,6 +1392,8 @@ pub mod arrow_type {
         Dictionary(::prost::alloc::boxed::Box<super::Dictionary>),
         #[prost(message, tag = "33")]
         Map(::prost::alloc::boxed::Box<super::Map>),
+        #[prost(message, tag = "34")]
+        Decimal256(super::Decimal),
     }
 }
 /// Useful for representing an empty enum variant in rust
@@ -1486,7 +1514,7 @@ pub mod owned_table_reference {
 pub struct PhysicalPlanNode {
     #[prost(
         oneof = "physical_plan_node::PhysicalPlanType",
-        tags = "1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24"
+        tags = "1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34"
     )]
     pub physical_plan_type: ::core::option::Option<physical_plan_node::PhysicalPlanType>,
 }
@@ -1543,6 +1571,26 @@ pub mod physical_plan_node {
         Analyze(::prost::alloc::boxed::Box<super::AnalyzeExecNode>),
         #[prost(message, tag = "24")]
         JsonSink(::prost::alloc::boxed::Box<super::JsonSinkExecNode>),
+        #[prost(message, tag = "25")]
+        CsvSink(::prost::alloc::boxed::Box<super::CsvSinkExecNode>),
+        #[prost(message, tag = "26")]
+        ParquetSink(::prost::alloc::boxed::Box<super::ParquetSinkExecNode>),
+        #[prost(message, tag = "27")]
+        SortMergeJoin(::prost::alloc::boxed::Box<super::SortMergeJoinExecNode>),
+        #[prost(message, tag = "28")]
+        SymmetricHashJoin(::prost::alloc::boxed::Box<super::SymmetricHashJoinExecNode>),
+        #[prost(message, tag = "29")]
+        Unnest(::prost::alloc::boxed::Box<super::UnnestExecNode>),
+        #[prost(message, tag = "30")]
+        Values(super::ValuesExecNode),
+        #[prost(message, tag = "31")]
+        Memory(super::MemoryExecNode),
+        #[prost(message, tag = "32")]
+        Interleave(super::InterleaveExecNode),
+        #[prost(message, tag = "33")]
+        JsonScan(super::JsonScanExecNode),
+        #[prost(message, tag = "34")]
+        ArrowScan(super::ArrowScanExecNode),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1556,7 +1604,7 @@ pub struct PartitionColumn {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FileTypeWriterOptions {
-    #[prost(oneof = "file_type_writer_options::FileType", tags = "1")]
+    #[prost(oneof = "file_type_writer_options::FileType", tags = "1, 2, 3")]
     pub file_type: ::core::option::Option<file_type_writer_options::FileType>,
 }
 /// Nested message and enum types in `FileTypeWriterOptions`.
@@ -1566,6 +1614,10 @@ pub mod file_type_writer_options {
     pub enum FileType {
         #[prost(message, tag = "1")]
         JsonOptions(super::JsonWriterOptions),
+        #[prost(message, tag = "2")]
+        CsvOptions(super::CsvWriterOptions),
+        #[prost(message, tag = "3")]
+        ParquetOptions(super::ParquetWriterOptions),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1576,6 +1628,135 @@ pub struct JsonWriterOptions {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CsvWriterOptions {
+    #[prost(enumeration = "CompressionTypeVariant", tag = "1")]
+    pub compression: i32,
+    #[prost(bool, tag = "2")]
+    pub has_header: bool,
+    /// Single byte delimiter, quote and escape characters, the arrow defaults
+    /// are used when empty
+    #[prost(bytes = "vec", tag = "3")]
+    pub delimiter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub quote: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub escape: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag = "6")]
+    pub double_quote: bool,
+    /// Optional formats for temporal values, unset when empty
+    #[prost(string, tag = "7")]
+    pub date_format: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub datetime_format: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub timestamp_format: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub timestamp_tz_format: ::prost::alloc::string::String,
+    #[prost(string, tag = "11")]
+    pub time_format: ::prost::alloc::string::String,
+    #[prost(string, tag = "12")]
+    pub null_value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParquetWriterOptions {
+    #[prost(message, optional, tag = "1")]
+    pub writer_properties: ::core::option::Option<WriterProperties>,
+}
+/// Parquet writer properties, using the same string formats as the
+/// `datafusion.execution.parquet` configuration options.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WriterProperties {
+    #[prost(uint64, tag = "1")]
+    pub data_page_size_limit: u64,
+    #[prost(uint64, tag = "2")]
+    pub dictionary_page_size_limit: u64,
+    #[prost(uint64, tag = "3")]
+    pub data_page_row_count_limit: u64,
+    #[prost(uint64, tag = "4")]
+    pub write_batch_size: u64,
+    #[prost(uint64, tag = "5")]
+    pub max_row_group_size: u64,
+    #[prost(string, tag = "6")]
+    pub writer_version: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub created_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub compression: ::prost::alloc::string::String,
+    #[prost(bool, tag = "11")]
+    pub dictionary_enabled: bool,
+    #[prost(string, tag = "12")]
+    pub statistics_enabled: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "13")]
+    pub max_statistics_size: u64,
+    #[prost(bool, tag = "14")]
+    pub bloom_filter_enabled: bool,
+    #[prost(double, tag = "15")]
+    pub bloom_filter_fpp: f64,
+    #[prost(uint64, tag = "16")]
+    pub bloom_filter_ndv: u64,
+    /// Settings of the columns that differ from the defaults above
+    #[prost(message, repeated, tag = "17")]
+    pub column_properties: ::prost::alloc::vec::Vec<ColumnWriterProperties>,
+    #[prost(oneof = "writer_properties::OptionalColumnIndexTruncateLength", tags = "8")]
+    pub optional_column_index_truncate_length: ::core::option::Option<
+        writer_properties::OptionalColumnIndexTruncateLength,
+    >,
+    #[prost(oneof = "writer_properties::OptionalEncoding", tags = "9")]
+    pub optional_encoding: ::core::option::Option<writer_properties::OptionalEncoding>,
+}
+/// Nested message and enum types in `WriterProperties`.
+pub mod writer_properties {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum OptionalColumnIndexTruncateLength {
+        #[prost(uint64, tag = "8")]
+        ColumnIndexTruncateLength(u64),
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum OptionalEncoding {
+        #[prost(string, tag = "9")]
+        Encoding(::prost::alloc::string::String),
+    }
+}
+/// Parquet writer settings for a single (leaf) column
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ColumnWriterProperties {
+    #[prost(string, repeated, tag = "1")]
+    pub path: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "3")]
+    pub compression: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub dictionary_enabled: bool,
+    #[prost(string, tag = "5")]
+    pub statistics_enabled: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "6")]
+    pub max_statistics_size: u64,
+    #[prost(bool, tag = "7")]
+    pub bloom_filter_enabled: bool,
+    #[prost(double, tag = "8")]
+    pub bloom_filter_fpp: f64,
+    #[prost(uint64, tag = "9")]
+    pub bloom_filter_ndv: u64,
+    #[prost(oneof = "column_writer_properties::OptionalEncoding", tags = "2")]
+    pub optional_encoding: ::core::option::Option<
+        column_writer_properties::OptionalEncoding,
+    >,
+}
+/// Nested message and enum types in `ColumnWriterProperties`.
+pub mod column_writer_properties {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum OptionalEncoding {
+        #[prost(string, tag = "2")]
+        Encoding(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FileSinkConfig {
     #[prost(string, tag = "1")]
     pub object_store_url: ::prost::alloc::string::String,
@@ -1618,6 +1799,42 @@ pub struct JsonSinkExecNode {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CsvSink {
+    #[prost(message, optional, tag = "1")]
+    pub config: ::core::option::Option<FileSinkConfig>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CsvSinkExecNode {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, optional, tag = "2")]
+    pub sink: ::core::option::Option<CsvSink>,
+    #[prost(message, optional, tag = "3")]
+    pub sink_schema: ::core::option::Option<Schema>,
+    #[prost(message, optional, tag = "4")]
+    pub sort_order: ::core::option::Option<PhysicalSortExprNodeCollection>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParquetSink {
+    #[prost(message, optional, tag = "1")]
+    pub config: ::core::option::Option<FileSinkConfig>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParquetSinkExecNode {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, optional, tag = "2")]
+    pub sink: ::core::option::Option<ParquetSink>,
+    #[prost(message, optional, tag = "3")]
+    pub sink_schema: ::core::option::Option<Schema>,
+    #[prost(message, optional, tag = "4")]
+    pub sort_order: ::core::option::Option<PhysicalSortExprNodeCollection>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PhysicalExtensionNode {
     #[prost(bytes = "vec", tag = "1")]
     pub node: ::prost::alloc::vec::Vec<u8>,
@@ -1630,7 +1847,7 @@ pub struct PhysicalExtensionNode {
 pub struct PhysicalExprNode {
     #[prost(
         oneof = "physical_expr_node::ExprType",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 19"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 19, 20, 21"
     )]
     pub expr_type: ::core::option::Option<physical_expr_node::ExprType>,
 }
@@ -1682,15 +1899,34 @@ pub mod physical_expr_node {
         GetIndexedFieldExpr(
             ::prost::alloc::boxed::Box<super::PhysicalGetIndexedFieldExprNode>,
         ),
+        #[prost(message, tag = "20")]
+        UnknownColumn(super::PhysicalUnknownColumn),
+        #[prost(message, tag = "21")]
+        NoOp(super::EmptyMessage),
+        #[prost(message, tag = "22")]
+        CollateExpr(::prost::alloc::boxed::Box<super::PhysicalCollateExprNode>),
+        #[prost(message, tag = "23")]
+        HigherOrderFunction(
+            ::prost::alloc::boxed::Box<super::PhysicalHigherOrderFunctionNode>,
+        ),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalUnknownColumn {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PhysicalScalarUdfNode {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
     pub args: ::prost::alloc::vec::Vec<PhysicalExprNode>,
+    /// Definition of the function encoded by the extension codec, if any
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
     #[prost(message, optional, tag = "4")]
     pub return_type: ::core::option::Option<ArrowType>,
 }
@@ -1703,6 +1939,9 @@ pub struct PhysicalAggregateExprNode {
     pub ordering_req: ::prost::alloc::vec::Vec<PhysicalSortExprNode>,
     #[prost(bool, tag = "3")]
     pub distinct: bool,
+    /// Definition of the user-defined function encoded by the extension codec, if any
+    #[prost(bytes = "vec", optional, tag = "6")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
     #[prost(oneof = "physical_aggregate_expr_node::AggregateFunction", tags = "1, 4")]
     pub aggregate_function: ::core::option::Option<
         physical_aggregate_expr_node::AggregateFunction,
@@ -1732,7 +1971,12 @@ pub struct PhysicalWindowExprNode {
     pub window_frame: ::core::option::Option<WindowFrame>,
     #[prost(string, tag = "8")]
     pub name: ::prost::alloc::string::String,
-    #[prost(oneof = "physical_window_expr_node::WindowFunction", tags = "1, 2")]
+    #[prost(bool, tag = "10")]
+    pub distinct: bool,
+    /// Definition of the user-defined function encoded by the extension codec, if any
+    #[prost(bytes = "vec", optional, tag = "11")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(oneof = "physical_window_expr_node::WindowFunction", tags = "1, 2, 3, 9")]
     pub window_function: ::core::option::Option<
         physical_window_expr_node::WindowFunction,
     >,
@@ -1744,9 +1988,12 @@ pub mod physical_window_expr_node {
     pub enum WindowFunction {
         #[prost(enumeration = "super::AggregateFunction", tag = "1")]
         AggrFunction(i32),
-        /// udaf = 3
         #[prost(enumeration = "super::BuiltInWindowFunction", tag = "2")]
         BuiltInFunction(i32),
+        #[prost(string, tag = "3")]
+        UserDefinedAggrFunction(::prost::alloc::string::String),
+        #[prost(string, tag = "9")]
+        UserDefinedWindowFunction(::prost::alloc::string::String),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1859,6 +2106,39 @@ pub struct PhysicalScalarFunctionNode {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalCollateExprNode {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub expr: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalExprNode>>,
+    #[prost(string, tag = "2")]
+    pub collation: ::prost::alloc::string::String,
+}
+/// The body of the lambda is planned against the schema of its parameters
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalLambdaNode {
+    #[prost(message, optional, tag = "1")]
+    pub params: ::core::option::Option<Schema>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub body: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalExprNode>>,
+    /// The values of the trailing params, captured from the enclosing query
+    #[prost(message, repeated, tag = "3")]
+    pub captures: ::prost::alloc::vec::Vec<PhysicalExprNode>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalHigherOrderFunctionNode {
+    #[prost(enumeration = "ScalarFunction", tag = "1")]
+    pub fun: i32,
+    /// The arguments other than the lambda
+    #[prost(message, repeated, tag = "2")]
+    pub args: ::prost::alloc::vec::Vec<PhysicalExprNode>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub lambda: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalLambdaNode>>,
+    #[prost(message, optional, tag = "4")]
+    pub return_type: ::core::option::Option<ArrowType>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PhysicalTryCastNode {
     #[prost(message, optional, boxed, tag = "1")]
     pub expr: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalExprNode>>,
@@ -1947,6 +2227,10 @@ pub struct CsvScanExecNode {
     pub quote: ::prost::alloc::string::String,
     #[prost(oneof = "csv_scan_exec_node::OptionalEscape", tags = "5")]
     pub optional_escape: ::core::option::Option<csv_scan_exec_node::OptionalEscape>,
+    #[prost(oneof = "csv_scan_exec_node::OptionalFileCompressionType", tags = "6")]
+    pub optional_file_compression_type: ::core::option::Option<
+        csv_scan_exec_node::OptionalFileCompressionType,
+    >,
 }
 /// Nested message and enum types in `CsvScanExecNode`.
 pub mod csv_scan_exec_node {
@@ -1956,6 +2240,12 @@ pub mod csv_scan_exec_node {
         #[prost(string, tag = "5")]
         Escape(::prost::alloc::string::String),
     }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum OptionalFileCompressionType {
+        #[prost(enumeration = "super::CompressionTypeVariant", tag = "6")]
+        FileCompressionType(i32),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1965,6 +2255,20 @@ pub struct AvroScanExecNode {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct JsonScanExecNode {
+    #[prost(message, optional, tag = "1")]
+    pub base_conf: ::core::option::Option<FileScanExecConf>,
+    #[prost(enumeration = "CompressionTypeVariant", tag = "2")]
+    pub file_compression_type: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArrowScanExecNode {
+    #[prost(message, optional, tag = "1")]
+    pub base_conf: ::core::option::Option<FileScanExecConf>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HashJoinExecNode {
     #[prost(message, optional, boxed, tag = "1")]
     pub left: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
@@ -1983,6 +2287,101 @@ pub struct HashJoinExecNode {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SortMergeJoinExecNode {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub left: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub right: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, repeated, tag = "3")]
+    pub on: ::prost::alloc::vec::Vec<JoinOn>,
+    #[prost(enumeration = "JoinType", tag = "4")]
+    pub join_type: i32,
+    /// One for each `on` pair
+    #[prost(message, repeated, tag = "5")]
+    pub sort_options: ::prost::alloc::vec::Vec<SortOptions>,
+    #[prost(bool, tag = "6")]
+    pub null_equals_null: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SortOptions {
+    #[prost(bool, tag = "1")]
+    pub descending: bool,
+    #[prost(bool, tag = "2")]
+    pub nulls_first: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SymmetricHashJoinExecNode {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub left: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub right: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, repeated, tag = "3")]
+    pub on: ::prost::alloc::vec::Vec<JoinOn>,
+    #[prost(enumeration = "JoinType", tag = "4")]
+    pub join_type: i32,
+    #[prost(enumeration = "StreamPartitionMode", tag = "5")]
+    pub partition_mode: i32,
+    #[prost(bool, tag = "6")]
+    pub null_equals_null: bool,
+    #[prost(message, optional, tag = "7")]
+    pub filter: ::core::option::Option<JoinFilter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnnestExecNode {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub input: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    #[prost(message, optional, tag = "2")]
+    pub schema: ::core::option::Option<Schema>,
+    #[prost(message, optional, tag = "3")]
+    pub column: ::core::option::Option<PhysicalColumn>,
+    #[prost(message, optional, tag = "4")]
+    pub options: ::core::option::Option<UnnestOptions>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnnestOptions {
+    #[prost(bool, tag = "1")]
+    pub preserve_nulls: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValuesExecNode {
+    #[prost(message, optional, tag = "1")]
+    pub schema: ::core::option::Option<Schema>,
+    /// Arrow IPC stream of the values
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoryExecNode {
+    #[prost(message, optional, tag = "1")]
+    pub schema: ::core::option::Option<Schema>,
+    /// Arrow IPC stream of the batches of each partition
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub partitions: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag = "3")]
+    pub projection: ::core::option::Option<MemoryProjection>,
+    #[prost(message, repeated, tag = "4")]
+    pub sort_information: ::prost::alloc::vec::Vec<PhysicalSortExprNodeCollection>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoryProjection {
+    #[prost(uint32, repeated, tag = "1")]
+    pub columns: ::prost::alloc::vec::Vec<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterleaveExecNode {
+    #[prost(message, repeated, tag = "1")]
+    pub inputs: ::prost::alloc::vec::Vec<PhysicalPlanNode>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnionExecNode {
     #[prost(message, repeated, tag = "1")]
     pub inputs: ::prost::alloc::vec::Vec<PhysicalPlanNode>,
@@ -2540,6 +2939,50 @@ pub enum ScalarFunction {
     StringToArray = 117,
     ToTimestampNanos = 118,
     ArrayIntersect = 119,
+    JsonGet = 120,
+    JsonGetStr = 121,
+    JsonGetInt = 122,
+    JsonGetFloat = 123,
+    JsonGetBool = 124,
+    JsonContains = 125,
+    JsonLength = 126,
+    JsonKeys = 127,
+    JsonArrayElements = 128,
+    MakeMap = 129,
+    MapKeys = 130,
+    MapValues = 131,
+    MapEntries = 132,
+    ElementAt = 133,
+    ArraySort = 134,
+    ArrayTransform = 135,
+    ArrayFilter = 136,
+    ArrayReduce = 137,
+    ArrayAnyMatch = 138,
+    ArrayAllMatch = 139,
+    ArrayDistinct = 140,
+    ArrayUnion = 141,
+    ArrayExcept = 142,
+    ArrayMax = 143,
+    ArrayMin = 144,
+    ArrayZip = 145,
+    ArrayResize = 146,
+    Range = 147,
+    GenerateSeries = 148,
+    ToLocalTime = 149,
+    MakeTimestamp = 150,
+    TryToTimestamp = 151,
+    ToDate = 152,
+    TryToDate = 153,
+    ToChar = 154,
+    CollationKey = 155,
+    RegexpLike = 156,
+    RegexpCount = 157,
+    RegexpInstr = 158,
+    RegexpSubstr = 159,
+    RegexpExtract = 160,
+    RegexpSplitToArray = 161,
+    RegexpMatches = 162,
+    Collate = 163,
 }
 impl ScalarFunction {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2668,6 +3111,50 @@ impl ScalarFunction {
             ScalarFunction::StringToArray => "StringToArray",
             ScalarFunction::ToTimestampNanos => "ToTimestampNanos",
             ScalarFunction::ArrayIntersect => "ArrayIntersect",
+            ScalarFunction::JsonGet => "JsonGet",
+            ScalarFunction::JsonGetStr => "JsonGetStr",
+            ScalarFunction::JsonGetInt => "JsonGetInt",
+            ScalarFunction::JsonGetFloat => "JsonGetFloat",
+            ScalarFunction::JsonGetBool => "JsonGetBool",
+            ScalarFunction::JsonContains => "JsonContains",
+            ScalarFunction::JsonLength => "JsonLength",
+            ScalarFunction::JsonKeys => "JsonKeys",
+            ScalarFunction::JsonArrayElements => "JsonArrayElements",
+            ScalarFunction::MakeMap => "MakeMap",
+            ScalarFunction::MapKeys => "MapKeys",
+            ScalarFunction::MapValues => "MapValues",
+            ScalarFunction::MapEntries => "MapEntries",
+            ScalarFunction::ElementAt => "ElementAt",
+            ScalarFunction::ArraySort => "ArraySort",
+            ScalarFunction::ArrayTransform => "ArrayTransform",
+            ScalarFunction::ArrayFilter => "ArrayFilter",
+            ScalarFunction::ArrayReduce => "ArrayReduce",
+            ScalarFunction::ArrayAnyMatch => "ArrayAnyMatch",
+            ScalarFunction::ArrayAllMatch => "ArrayAllMatch",
+            ScalarFunction::ArrayDistinct => "ArrayDistinct",
+            ScalarFunction::ArrayUnion => "ArrayUnion",
+            ScalarFunction::ArrayExcept => "ArrayExcept",
+            ScalarFunction::ArrayMax => "ArrayMax",
+            ScalarFunction::ArrayMin => "ArrayMin",
+            ScalarFunction::ArrayZip => "ArrayZip",
+            ScalarFunction::ArrayResize => "ArrayResize",
+            ScalarFunction::Range => "Range",
+            ScalarFunction::GenerateSeries => "GenerateSeries",
+            ScalarFunction::ToLocalTime => "ToLocalTime",
+            ScalarFunction::MakeTimestamp => "MakeTimestamp",
+            ScalarFunction::TryToTimestamp => "TryToTimestamp",
+            ScalarFunction::ToDate => "ToDate",
+            ScalarFunction::TryToDate => "TryToDate",
+            ScalarFunction::ToChar => "ToChar",
+            ScalarFunction::CollationKey => "CollationKey",
+            ScalarFunction::RegexpLike => "RegexpLike",
+            ScalarFunction::RegexpCount => "RegexpCount",
+            ScalarFunction::RegexpInstr => "RegexpInstr",
+            ScalarFunction::RegexpSubstr => "RegexpSubstr",
+            ScalarFunction::RegexpExtract => "RegexpExtract",
+            ScalarFunction::RegexpSplitToArray => "RegexpSplitToArray",
+            ScalarFunction::RegexpMatches => "RegexpMatches",
+            ScalarFunction::Collate => "Collate",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2793,6 +3280,50 @@ impl ScalarFunction {
             "StringToArray" => Some(Self::StringToArray),
             "ToTimestampNanos" => Some(Self::ToTimestampNanos),
             "ArrayIntersect" => Some(Self::ArrayIntersect),
+            "JsonGet" => Some(Self::JsonGet),
+            "JsonGetStr" => Some(Self::JsonGetStr),
+            "JsonGetInt" => Some(Self::JsonGetInt),
+            "JsonGetFloat" => Some(Self::JsonGetFloat),
+            "JsonGetBool" => Some(Self::JsonGetBool),
+            "JsonContains" => Some(Self::JsonContains),
+            "JsonLength" => Some(Self::JsonLength),
+            "JsonKeys" => Some(Self::JsonKeys),
+            "JsonArrayElements" => Some(Self::JsonArrayElements),
+            "MakeMap" => Some(Self::MakeMap),
+            "MapKeys" => Some(Self::MapKeys),
+            "MapValues" => Some(Self::MapValues),
+            "MapEntries" => Some(Self::MapEntries),
+            "ElementAt" => Some(Self::ElementAt),
+            "ArraySort" => Some(Self::ArraySort),
+            "ArrayTransform" => Some(Self::ArrayTransform),
+            "ArrayFilter" => Some(Self::ArrayFilter),
+            "ArrayReduce" => Some(Self::ArrayReduce),
+            "ArrayAnyMatch" => Some(Self::ArrayAnyMatch),
+            "ArrayAllMatch" => Some(Self::ArrayAllMatch),
+            "ArrayDistinct" => Some(Self::ArrayDistinct),
+            "ArrayUnion" => Some(Self::ArrayUnion),
+            "ArrayExcept" => Some(Self::ArrayExcept),
+            "ArrayMax" => Some(Self::ArrayMax),
+            "ArrayMin" => Some(Self::ArrayMin),
+            "ArrayZip" => Some(Self::ArrayZip),
+            "ArrayResize" => Some(Self::ArrayResize),
+            "Range" => Some(Self::Range),
+            "GenerateSeries" => Some(Self::GenerateSeries),
+            "ToLocalTime" => Some(Self::ToLocalTime),
+            "MakeTimestamp" => Some(Self::MakeTimestamp),
+            "TryToTimestamp" => Some(Self::TryToTimestamp),
+            "ToDate" => Some(Self::ToDate),
+            "TryToDate" => Some(Self::TryToDate),
+            "ToChar" => Some(Self::ToChar),
+            "CollationKey" => Some(Self::CollationKey),
+            "RegexpLike" => Some(Self::RegexpLike),
+            "RegexpCount" => Some(Self::RegexpCount),
+            "RegexpInstr" => Some(Self::RegexpInstr),
+            "RegexpSubstr" => Some(Self::RegexpSubstr),
+            "RegexpExtract" => Some(Self::RegexpExtract),
+            "RegexpSplitToArray" => Some(Self::RegexpSplitToArray),
+            "RegexpMatches" => Some(Self::RegexpMatches),
+            "Collate" => Some(Self::Collate),
             _ => None,
         }
     }
@@ -3247,6 +3778,32 @@ impl PartitionMode {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum StreamPartitionMode {
+    SinglePartition = 0,
+    PartitionedExec = 1,
+}
+impl StreamPartitionMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StreamPartitionMode::SinglePartition => "SINGLE_PARTITION",
+            StreamPartitionMode::PartitionedExec => "PARTITIONED_EXEC",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SINGLE_PARTITION" => Some(Self::SinglePartition),
+            "PARTITIONED_EXEC" => Some(Self::PartitionedExec),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum AggregateMode {
     Partial = 0,
     Final = 1,
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::common::{registered_udaf, registered_udf, registered_udwf};
+use crate::logical_plan::{DefaultLogicalExtensionCodec, LogicalExtensionCodec};
 use crate::protobuf::{
     self,
     plan_type::PlanTypeEnum::{
@@ -39,24 +41,33 @@ use datafusion_common::{
     DFSchema, DFSchemaRef, DataFusionError, OwnedTableReference, Result, ScalarValue,
 };
 use datafusion_expr::{
-    abs, acos, acosh, array, array_append, array_concat, array_dims, array_element,
-    array_has, array_has_all, array_has_any, array_intersect, array_length, array_ndims,
-    array_position, array_positions, array_prepend, array_remove, array_remove_all,
-    array_remove_n, array_repeat, array_replace, array_replace_all, array_replace_n,
-    array_slice, array_to_string, arrow_typeof, ascii, asin, asinh, atan, atan2, atanh,
-    bit_length, btrim, cardinality, cbrt, ceil, character_length, chr, coalesce,
-    concat_expr, concat_ws_expr, cos, cosh, cot, current_date, current_time, date_bin,
-    date_part, date_trunc, decode, degrees, digest, encode, exp,
+    abs, acos, acosh, array, array_all_match, array_any_match, array_append,
+    array_concat, array_dims, array_distinct, array_element, array_except, array_filter,
+    array_has, array_has_all, array_has_any, array_intersect, array_length, array_max,
+    array_min, array_ndims, array_position, array_positions, array_prepend, array_reduce,
+    array_remove, array_remove_all, array_remove_n, array_repeat, array_replace,
+    array_replace_all, array_replace_n, array_resize, array_slice, array_sort,
+    array_to_string, array_transform, array_union, array_zip, arrow_typeof, ascii, asin,
+    asinh, atan, atan2, atanh, bit_length, btrim, cardinality, cbrt, ceil,
+    character_length, chr, coalesce, collate, collation_key, concat_expr, concat_ws_expr,
+    cos, cosh, cot, current_date, current_time, date_bin, date_part, date_trunc, decode,
+    degrees, digest, element_at, encode, exp,
     expr::{self, InList, Sort, WindowFunction},
-    factorial, flatten, floor, from_unixtime, gcd, isnan, iszero, lcm, left, ln, log,
-    log10, log2,
+    factorial, flatten, floor, from_unixtime, gcd, generate_series, isnan, iszero,
+    json_array_elements, json_contains, json_get, json_get_bool, json_get_float,
+    json_get_int, json_get_str, json_keys, json_length, lcm, left, ln, log, log10, log2,
     logical_plan::{PlanType, StringifiedPlan},
-    lower, lpad, ltrim, md5, nanvl, now, nullif, octet_length, pi, power, radians,
-    random, regexp_match, regexp_replace, repeat, replace, reverse, right, round, rpad,
-    rtrim, sha224, sha256, sha384, sha512, signum, sin, sinh, split_part, sqrt,
-    starts_with, string_to_array, strpos, struct_fun, substr, substring, tan, tanh,
-    to_hex, to_timestamp_micros, to_timestamp_millis, to_timestamp_nanos,
-    to_timestamp_seconds, translate, trim, trunc, upper, uuid,
+    lower, lpad, ltrim, make_map, make_timestamp, map_entries, map_keys, map_values, md5,
+    nanvl, now, nullif, octet_length, pi, power, radians, random, range, regexp_count,
+    regexp_extract, regexp_instr, regexp_like, regexp_match, regexp_matches,
+    regexp_replace, regexp_split_to_array, regexp_substr, repeat, replace, reverse,
+    right, round, rpad, rtrim, sha224, sha256, sha384, sha512, signum, sin, sinh,
+    split_part, sqrt, starts_with, string_to_array, strpos, struct_fun, substr,
+    substring, tan, tanh, to_char, to_date, to_hex, to_local_time,
+    to_timestamp_micros_with_formats, to_timestamp_millis_with_formats,
+    to_timestamp_nanos_with_formats, to_timestamp_seconds_with_formats,
+    to_timestamp_with_formats, translate, trim, trunc, try_to_date, try_to_timestamp,
+    upper, uuid,
     window_frame::regularize,
     AggregateFunction, Between, BinaryExpr, BuiltInWindowFunction, BuiltinScalarFunction,
     Case, Cast, Expr, GetFieldAccess, GetIndexedField, GroupingSet,
@@ -66,7 +77,7 @@ use datafusion_expr::{
 };
 use datafusion_expr::{
     array_empty, array_pop_back,
-    expr::{Alias, Placeholder},
+    expr::{Alias, Lambda, Placeholder},
 };
 use std::sync::Arc;
 
@@ -312,6 +323,10 @@ impl TryFrom<&protobuf::arrow_type::ArrowTypeEnum> for DataType {
                 precision,
                 scale,
             }) => DataType::Decimal128(*precision as u8, *scale as i8),
+            arrow_type::ArrowTypeEnum::Decimal256(protobuf::Decimal {
+                precision,
+                scale,
+            }) => DataType::Decimal256(*precision as u8, *scale as i8),
             arrow_type::ArrowTypeEnum::List(list) => {
                 let list_type =
                     list.as_ref().field_type.as_deref().required("field_type")?;
@@ -537,11 +552,55 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::Power => Self::Power,
             ScalarFunction::StructFun => Self::Struct,
             ScalarFunction::FromUnixtime => Self::FromUnixtime,
+            ScalarFunction::ToLocalTime => Self::ToLocalTime,
+            ScalarFunction::MakeTimestamp => Self::MakeTimestamp,
+            ScalarFunction::TryToTimestamp => Self::TryToTimestamp,
+            ScalarFunction::ToDate => Self::ToDate,
+            ScalarFunction::TryToDate => Self::TryToDate,
+            ScalarFunction::ToChar => Self::ToChar,
+            ScalarFunction::Collate => Self::Collate,
+            ScalarFunction::CollationKey => Self::CollationKey,
+            ScalarFunction::RegexpLike => Self::RegexpLike,
+            ScalarFunction::RegexpCount => Self::RegexpCount,
+            ScalarFunction::RegexpInstr => Self::RegexpInstr,
+            ScalarFunction::RegexpSubstr => Self::RegexpSubstr,
+            ScalarFunction::RegexpExtract => Self::RegexpExtract,
+            ScalarFunction::RegexpSplitToArray => Self::RegexpSplitToArray,
+            ScalarFunction::RegexpMatches => Self::RegexpMatches,
             ScalarFunction::Atan2 => Self::Atan2,
             ScalarFunction::Nanvl => Self::Nanvl,
             ScalarFunction::Isnan => Self::Isnan,
             ScalarFunction::Iszero => Self::Iszero,
             ScalarFunction::ArrowTypeof => Self::ArrowTypeof,
+            ScalarFunction::JsonGet => Self::JsonGet,
+            ScalarFunction::JsonGetStr => Self::JsonGetStr,
+            ScalarFunction::JsonGetInt => Self::JsonGetInt,
+            ScalarFunction::JsonGetFloat => Self::JsonGetFloat,
+            ScalarFunction::JsonGetBool => Self::JsonGetBool,
+            ScalarFunction::JsonContains => Self::JsonContains,
+            ScalarFunction::JsonLength => Self::JsonLength,
+            ScalarFunction::JsonKeys => Self::JsonKeys,
+            ScalarFunction::JsonArrayElements => Self::JsonArrayElements,
+            ScalarFunction::MakeMap => Self::MakeMap,
+            ScalarFunction::MapKeys => Self::MapKeys,
+            ScalarFunction::MapValues => Self::MapValues,
+            ScalarFunction::MapEntries => Self::MapEntries,
+            ScalarFunction::ElementAt => Self::ElementAt,
+            ScalarFunction::ArraySort => Self::ArraySort,
+            ScalarFunction::ArrayTransform => Self::ArrayTransform,
+            ScalarFunction::ArrayFilter => Self::ArrayFilter,
+            ScalarFunction::ArrayReduce => Self::ArrayReduce,
+            ScalarFunction::ArrayAnyMatch => Self::ArrayAnyMatch,
+            ScalarFunction::ArrayAllMatch => Self::ArrayAllMatch,
+            ScalarFunction::ArrayDistinct => Self::ArrayDistinct,
+            ScalarFunction::ArrayUnion => Self::ArrayUnion,
+            ScalarFunction::ArrayExcept => Self::ArrayExcept,
+            ScalarFunction::ArrayMax => Self::ArrayMax,
+            ScalarFunction::ArrayMin => Self::ArrayMin,
+            ScalarFunction::ArrayZip => Self::ArrayZip,
+            ScalarFunction::ArrayResize => Self::ArrayResize,
+            ScalarFunction::Range => Self::Range,
+            ScalarFunction::GenerateSeries => Self::GenerateSeries,
         }
     }
 }
@@ -649,8 +708,8 @@ impl TryFrom<&protobuf::ScalarValue> for ScalarValue {
             Value::Float32Value(v) => Self::Float32(Some(*v)),
             Value::Float64Value(v) => Self::Float64(Some(*v)),
             Value::Date32Value(v) => Self::Date32(Some(*v)),
-            // ScalarValue::List is serialized using arrow IPC format
-            Value::ListValue(scalar_list) => {
+            // ScalarValue::List and ScalarValue::Map are serialized using arrow IPC format
+            Value::ListValue(scalar_list) | Value::MapValue(scalar_list) => {
                 let protobuf::ScalarListValue {
                     ipc_message,
                     arrow_data,
@@ -690,8 +749,11 @@ impl TryFrom<&protobuf::ScalarValue> for ScalarValue {
                 )
                 .map_err(DataFusionError::ArrowError)
                 .map_err(|e| e.context("Decoding ScalarValue::List Value"))?;
-                let arr = record_batch.column(0);
-                Self::List(arr.to_owned())
+                let arr = record_batch.column(0).to_owned();
+                match value {
+                    Value::MapValue(_) => Self::Map(arr),
+                    _ => Self::List(arr),
+                }
             }
             Value::NullValue(v) => {
                 let null_type: DataType = v.try_into()?;
@@ -957,9 +1019,27 @@ pub fn parse_i32_to_aggregate_function(value: &i32) -> Result<AggregateFunction,
         .map_err(|_| Error::unknown("AggregateFunction", *value))
 }
 
+/// Parse a protobuf expression, looking up user-defined functions by name
+/// in `registry`
+///
+/// Use [`parse_expr_with_codec`] to decode the functions whose definition
+/// was encoded by an extension codec.
 pub fn parse_expr(
     proto: &protobuf::LogicalExprNode,
     registry: &dyn FunctionRegistry,
+) -> Result<Expr, Error> {
+    parse_expr_with_codec(proto, registry, &DefaultLogicalExtensionCodec {})
+}
+
+/// Parse a protobuf expression, decoding the definitions of user-defined
+/// functions with `codec`
+///
+/// Functions without an encoded definition are looked up by name in
+/// `registry`.
+pub fn parse_expr_with_codec(
+    proto: &protobuf::LogicalExprNode,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn LogicalExtensionCodec,
 ) -> Result<Expr, Error> {
     use protobuf::{logical_expr_node::ExprType, window_expr_node, ScalarFunction};
 
@@ -974,7 +1054,7 @@ pub fn parse_expr(
             let operands = binary_expr
                 .operands
                 .iter()
-                .map(|expr| parse_expr(expr, registry))
+                .map(|expr| parse_expr_with_codec(expr, registry, codec))
                 .collect::<Result<Vec<_>, _>>()?;
 
             if operands.len() < 2 {
@@ -993,8 +1073,12 @@ pub fn parse_expr(
                 .expect("Binary expression could not be reduced to a single expression."))
         }
         ExprType::GetIndexedField(get_indexed_field) => {
-            let expr =
-                parse_required_expr(get_indexed_field.expr.as_deref(), registry, "expr")?;
+            let expr = parse_required_expr(
+                get_indexed_field.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?;
             let field = match &get_indexed_field.field {
                 Some(protobuf::get_indexed_field::Field::NamedStructField(
                     named_struct_field,
@@ -1010,6 +1094,7 @@ pub fn parse_expr(
                         key: Box::new(parse_required_expr(
                             list_index.key.as_deref(),
                             registry,
+                            codec,
                             "key",
                         )?),
                     }
@@ -1019,11 +1104,13 @@ pub fn parse_expr(
                         start: Box::new(parse_required_expr(
                             list_range.start.as_deref(),
                             registry,
+                            codec,
                             "start",
                         )?),
                         stop: Box::new(parse_required_expr(
                             list_range.stop.as_deref(),
                             registry,
+                            codec,
                             "stop",
                         )?),
                     }
@@ -1049,12 +1136,12 @@ pub fn parse_expr(
             let partition_by = expr
                 .partition_by
                 .iter()
-                .map(|e| parse_expr(e, registry))
+                .map(|e| parse_expr_with_codec(e, registry, codec))
                 .collect::<Result<Vec<_>, _>>()?;
             let order_by = expr
                 .order_by
                 .iter()
-                .map(|e| parse_expr(e, registry))
+                .map(|e| parse_expr_with_codec(e, registry, codec))
                 .collect::<Result<Vec<_>, _>>()?;
             let window_frame = expr
                 .window_frame
@@ -1078,7 +1165,7 @@ pub fn parse_expr(
                         datafusion_expr::window_function::WindowFunction::AggregateFunction(
                             aggr_function,
                         ),
-                        vec![parse_required_expr(expr.expr.as_deref(), registry, "expr")?],
+                        vec![parse_required_expr(expr.expr.as_deref(), registry, codec, "expr")?],
                         partition_by,
                         order_by,
                         window_frame,
@@ -1089,9 +1176,10 @@ pub fn parse_expr(
                         .map_err(|_| Error::unknown("BuiltInWindowFunction", *i))?
                         .into();
 
-                    let args = parse_optional_expr(expr.expr.as_deref(), registry)?
-                        .map(|e| vec![e])
-                        .unwrap_or_else(Vec::new);
+                    let args =
+                        parse_optional_expr(expr.expr.as_deref(), registry, codec)?
+                            .map(|e| vec![e])
+                            .unwrap_or_else(Vec::new);
 
                     Ok(Expr::WindowFunction(WindowFunction::new(
                         datafusion_expr::window_function::WindowFunction::BuiltInWindowFunction(
@@ -1104,10 +1192,14 @@ pub fn parse_expr(
                     )))
                 }
                 window_expr_node::WindowFunction::Udaf(udaf_name) => {
-                    let udaf_function = registry.udaf(udaf_name)?;
-                    let args = parse_optional_expr(expr.expr.as_deref(), registry)?
-                        .map(|e| vec![e])
-                        .unwrap_or_else(Vec::new);
+                    let udaf_function = match &expr.fun_definition {
+                        Some(buf) => codec.try_decode_udaf(udaf_name, buf)?,
+                        None => registered_udaf(registry, udaf_name)?,
+                    };
+                    let args =
+                        parse_optional_expr(expr.expr.as_deref(), registry, codec)?
+                            .map(|e| vec![e])
+                            .unwrap_or_else(Vec::new);
                     Ok(Expr::WindowFunction(WindowFunction::new(
                         datafusion_expr::window_function::WindowFunction::AggregateUDF(
                             udaf_function,
@@ -1119,10 +1211,14 @@ pub fn parse_expr(
                     )))
                 }
                 window_expr_node::WindowFunction::Udwf(udwf_name) => {
-                    let udwf_function = registry.udwf(udwf_name)?;
-                    let args = parse_optional_expr(expr.expr.as_deref(), registry)?
-                        .map(|e| vec![e])
-                        .unwrap_or_else(Vec::new);
+                    let udwf_function = match &expr.fun_definition {
+                        Some(buf) => codec.try_decode_udwf(udwf_name, buf)?,
+                        None => registered_udwf(registry, udwf_name)?,
+                    };
+                    let args =
+                        parse_optional_expr(expr.expr.as_deref(), registry, codec)?
+                            .map(|e| vec![e])
+                            .unwrap_or_else(Vec::new);
                     Ok(Expr::WindowFunction(WindowFunction::new(
                         datafusion_expr::window_function::WindowFunction::WindowUDF(
                             udwf_function,
@@ -1142,82 +1238,99 @@ pub fn parse_expr(
                 fun,
                 expr.expr
                     .iter()
-                    .map(|e| parse_expr(e, registry))
+                    .map(|e| parse_expr_with_codec(e, registry, codec))
                     .collect::<Result<Vec<_>, _>>()?,
                 expr.distinct,
-                parse_optional_expr(expr.filter.as_deref(), registry)?.map(Box::new),
-                parse_vec_expr(&expr.order_by, registry)?,
+                parse_optional_expr(expr.filter.as_deref(), registry, codec)?
+                    .map(Box::new),
+                parse_vec_expr(&expr.order_by, registry, codec)?,
             )))
         }
         ExprType::Alias(alias) => Ok(Expr::Alias(Alias::new(
-            parse_required_expr(alias.expr.as_deref(), registry, "expr")?,
+            parse_required_expr(alias.expr.as_deref(), registry, codec, "expr")?,
             alias.alias.clone(),
         ))),
         ExprType::IsNullExpr(is_null) => Ok(Expr::IsNull(Box::new(parse_required_expr(
             is_null.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsNotNullExpr(is_not_null) => Ok(Expr::IsNotNull(Box::new(
-            parse_required_expr(is_not_null.expr.as_deref(), registry, "expr")?,
+            parse_required_expr(is_not_null.expr.as_deref(), registry, codec, "expr")?,
         ))),
         ExprType::NotExpr(not) => Ok(Expr::Not(Box::new(parse_required_expr(
             not.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsTrue(msg) => Ok(Expr::IsTrue(Box::new(parse_required_expr(
             msg.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsFalse(msg) => Ok(Expr::IsFalse(Box::new(parse_required_expr(
             msg.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsUnknown(msg) => Ok(Expr::IsUnknown(Box::new(parse_required_expr(
             msg.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsNotTrue(msg) => Ok(Expr::IsNotTrue(Box::new(parse_required_expr(
             msg.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsNotFalse(msg) => Ok(Expr::IsNotFalse(Box::new(parse_required_expr(
             msg.expr.as_deref(),
             registry,
+            codec,
             "expr",
         )?))),
         ExprType::IsNotUnknown(msg) => Ok(Expr::IsNotUnknown(Box::new(
-            parse_required_expr(msg.expr.as_deref(), registry, "expr")?,
+            parse_required_expr(msg.expr.as_deref(), registry, codec, "expr")?,
         ))),
         ExprType::Between(between) => Ok(Expr::Between(Between::new(
             Box::new(parse_required_expr(
                 between.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
             )?),
             between.negated,
             Box::new(parse_required_expr(
                 between.low.as_deref(),
                 registry,
+                codec,
                 "expr",
             )?),
             Box::new(parse_required_expr(
                 between.high.as_deref(),
                 registry,
+                codec,
                 "expr",
             )?),
         ))),
         ExprType::Like(like) => Ok(Expr::Like(Like::new(
             like.negated,
-            Box::new(parse_required_expr(like.expr.as_deref(), registry, "expr")?),
+            Box::new(parse_required_expr(
+                like.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?),
             Box::new(parse_required_expr(
                 like.pattern.as_deref(),
                 registry,
+                codec,
                 "pattern",
             )?),
             parse_escape_char(&like.escape_char)?,
@@ -1225,10 +1338,16 @@ pub fn parse_expr(
         ))),
         ExprType::Ilike(like) => Ok(Expr::Like(Like::new(
             like.negated,
-            Box::new(parse_required_expr(like.expr.as_deref(), registry, "expr")?),
+            Box::new(parse_required_expr(
+                like.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?),
             Box::new(parse_required_expr(
                 like.pattern.as_deref(),
                 registry,
+                codec,
                 "pattern",
             )?),
             parse_escape_char(&like.escape_char)?,
@@ -1236,10 +1355,16 @@ pub fn parse_expr(
         ))),
         ExprType::SimilarTo(like) => Ok(Expr::SimilarTo(Like::new(
             like.negated,
-            Box::new(parse_required_expr(like.expr.as_deref(), registry, "expr")?),
+            Box::new(parse_required_expr(
+                like.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?),
             Box::new(parse_required_expr(
                 like.pattern.as_deref(),
                 registry,
+                codec,
                 "pattern",
             )?),
             parse_escape_char(&like.escape_char)?,
@@ -1250,49 +1375,72 @@ pub fn parse_expr(
                 .when_then_expr
                 .iter()
                 .map(|e| {
-                    let when_expr =
-                        parse_required_expr(e.when_expr.as_ref(), registry, "when_expr")?;
-                    let then_expr =
-                        parse_required_expr(e.then_expr.as_ref(), registry, "then_expr")?;
+                    let when_expr = parse_required_expr(
+                        e.when_expr.as_ref(),
+                        registry,
+                        codec,
+                        "when_expr",
+                    )?;
+                    let then_expr = parse_required_expr(
+                        e.then_expr.as_ref(),
+                        registry,
+                        codec,
+                        "then_expr",
+                    )?;
                     Ok((Box::new(when_expr), Box::new(then_expr)))
                 })
                 .collect::<Result<Vec<(Box<Expr>, Box<Expr>)>, Error>>()?;
             Ok(Expr::Case(Case::new(
-                parse_optional_expr(case.expr.as_deref(), registry)?.map(Box::new),
+                parse_optional_expr(case.expr.as_deref(), registry, codec)?.map(Box::new),
                 when_then_expr,
-                parse_optional_expr(case.else_expr.as_deref(), registry)?.map(Box::new),
+                parse_optional_expr(case.else_expr.as_deref(), registry, codec)?
+                    .map(Box::new),
             )))
         }
         ExprType::Cast(cast) => {
-            let expr =
-                Box::new(parse_required_expr(cast.expr.as_deref(), registry, "expr")?);
+            let expr = Box::new(parse_required_expr(
+                cast.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?);
             let data_type = cast.arrow_type.as_ref().required("arrow_type")?;
             Ok(Expr::Cast(Cast::new(expr, data_type)))
         }
         ExprType::TryCast(cast) => {
-            let expr =
-                Box::new(parse_required_expr(cast.expr.as_deref(), registry, "expr")?);
+            let expr = Box::new(parse_required_expr(
+                cast.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?);
             let data_type = cast.arrow_type.as_ref().required("arrow_type")?;
             Ok(Expr::TryCast(TryCast::new(expr, data_type)))
         }
         ExprType::Sort(sort) => Ok(Expr::Sort(Sort::new(
-            Box::new(parse_required_expr(sort.expr.as_deref(), registry, "expr")?),
+            Box::new(parse_required_expr(
+                sort.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+            )?),
             sort.asc,
             sort.nulls_first,
         ))),
         ExprType::Negative(negative) => Ok(Expr::Negative(Box::new(
-            parse_required_expr(negative.expr.as_deref(), registry, "expr")?,
+            parse_required_expr(negative.expr.as_deref(), registry, codec, "expr")?,
         ))),
         ExprType::InList(in_list) => Ok(Expr::InList(InList::new(
             Box::new(parse_required_expr(
                 in_list.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
             )?),
             in_list
                 .list
                 .iter()
-                .map(|expr| parse_expr(expr, registry))
+                .map(|expr| parse_expr_with_codec(expr, registry, codec))
                 .collect::<Result<Vec<_>, _>>()?,
             in_list.negated,
         ))),
@@ -1303,391 +1451,705 @@ pub fn parse_expr(
             let args = &expr.args;
 
             match scalar_function {
-                ScalarFunction::Asin => Ok(asin(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Acos => Ok(acos(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Asinh => Ok(asinh(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Acosh => Ok(acosh(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Asin => {
+                    Ok(asin(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Acos => {
+                    Ok(acos(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Asinh => {
+                    Ok(asinh(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Acosh => {
+                    Ok(acosh(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
                 ScalarFunction::Array => Ok(array(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::ArrayAppend => Ok(array_append(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayPopBack => Ok(array_pop_back(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
                 )),
-                ScalarFunction::ArrayPopBack => {
-                    Ok(array_pop_back(parse_expr(&args[0], registry)?))
-                }
                 ScalarFunction::ArrayPrepend => Ok(array_prepend(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayConcat => Ok(array_concat(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::ArrayHasAll => Ok(array_has_all(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayHasAny => Ok(array_has_any(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayHas => Ok(array_has(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayPosition => Ok(array_position(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::ArrayPositions => Ok(array_positions(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayRepeat => Ok(array_repeat(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayRemove => Ok(array_remove(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayRemoveN => Ok(array_remove_n(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::ArrayRemoveAll => Ok(array_remove_all(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayReplace => Ok(array_replace(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::ArrayReplaceN => Ok(array_replace_n(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
-                    parse_expr(&args[3], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
+                    parse_expr_with_codec(&args[3], registry, codec)?,
                 )),
                 ScalarFunction::ArrayReplaceAll => Ok(array_replace_all(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::ArraySlice => Ok(array_slice(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::ArrayToString => Ok(array_to_string(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::ArrayIntersect => Ok(array_intersect(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
-                ScalarFunction::Cardinality => {
-                    Ok(cardinality(parse_expr(&args[0], registry)?))
-                }
+                ScalarFunction::Cardinality => Ok(cardinality(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
                 ScalarFunction::ArrayLength => Ok(array_length(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
-                ScalarFunction::ArrayDims => {
-                    Ok(array_dims(parse_expr(&args[0], registry)?))
-                }
+                ScalarFunction::ArrayDims => Ok(array_dims(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
                 ScalarFunction::ArrayElement => Ok(array_element(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                )),
-                ScalarFunction::ArrayEmpty => {
-                    Ok(array_empty(parse_expr(&args[0], registry)?))
-                }
-                ScalarFunction::ArrayNdims => {
-                    Ok(array_ndims(parse_expr(&args[0], registry)?))
-                }
-                ScalarFunction::Sqrt => Ok(sqrt(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Cbrt => Ok(cbrt(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sin => Ok(sin(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Cos => Ok(cos(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Tan => Ok(tan(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Atan => Ok(atan(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sinh => Ok(sinh(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Cosh => Ok(cosh(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Tanh => Ok(tanh(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Atanh => Ok(atanh(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Exp => Ok(exp(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Degrees => Ok(degrees(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Radians => Ok(radians(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Log2 => Ok(log2(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Ln => Ok(ln(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Log10 => Ok(log10(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Floor => Ok(floor(parse_expr(&args[0], registry)?)),
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayEmpty => Ok(array_empty(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::ArrayNdims => Ok(array_ndims(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::Sqrt => {
+                    Ok(sqrt(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Cbrt => {
+                    Ok(cbrt(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Sin => {
+                    Ok(sin(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Cos => {
+                    Ok(cos(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Tan => {
+                    Ok(tan(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Atan => {
+                    Ok(atan(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Sinh => {
+                    Ok(sinh(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Cosh => {
+                    Ok(cosh(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Tanh => {
+                    Ok(tanh(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Atanh => {
+                    Ok(atanh(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Exp => {
+                    Ok(exp(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Degrees => {
+                    Ok(degrees(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Radians => {
+                    Ok(radians(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Log2 => {
+                    Ok(log2(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Ln => {
+                    Ok(ln(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Log10 => {
+                    Ok(log10(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Floor => {
+                    Ok(floor(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
                 ScalarFunction::Factorial => {
-                    Ok(factorial(parse_expr(&args[0], registry)?))
+                    Ok(factorial(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Ceil => {
+                    Ok(ceil(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
-                ScalarFunction::Ceil => Ok(ceil(parse_expr(&args[0], registry)?)),
                 ScalarFunction::Round => Ok(round(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Trunc => Ok(trunc(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
-                ScalarFunction::Abs => Ok(abs(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Signum => Ok(signum(parse_expr(&args[0], registry)?)),
-                ScalarFunction::OctetLength => {
-                    Ok(octet_length(parse_expr(&args[0], registry)?))
+                ScalarFunction::Abs => {
+                    Ok(abs(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Signum => {
+                    Ok(signum(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::OctetLength => Ok(octet_length(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::Lower => {
+                    Ok(lower(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Upper => {
+                    Ok(upper(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Trim => {
+                    Ok(trim(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Ltrim => {
+                    Ok(ltrim(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Rtrim => {
+                    Ok(rtrim(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
-                ScalarFunction::Lower => Ok(lower(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Upper => Ok(upper(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Trim => Ok(trim(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Ltrim => Ok(ltrim(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Rtrim => Ok(rtrim(parse_expr(&args[0], registry)?)),
                 ScalarFunction::DatePart => Ok(date_part(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::DateTrunc => Ok(date_trunc(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::DateBin => Ok(date_bin(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
-                )),
-                ScalarFunction::Sha224 => Ok(sha224(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sha256 => Ok(sha256(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sha384 => Ok(sha384(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Sha512 => Ok(sha512(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Md5 => Ok(md5(parse_expr(&args[0], registry)?)),
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
+                )),
+                ScalarFunction::Sha224 => {
+                    Ok(sha224(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Sha256 => {
+                    Ok(sha256(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Sha384 => {
+                    Ok(sha384(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Sha512 => {
+                    Ok(sha512(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::Md5 => {
+                    Ok(md5(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
                 ScalarFunction::Encode => Ok(encode(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Decode => Ok(decode(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::NullIf => Ok(nullif(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Digest => Ok(digest(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::Ascii => {
+                    Ok(ascii(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::BitLength => Ok(bit_length(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::CharacterLength => Ok(character_length(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
                 )),
-                ScalarFunction::Ascii => Ok(ascii(parse_expr(&args[0], registry)?)),
-                ScalarFunction::BitLength => {
-                    Ok(bit_length(parse_expr(&args[0], registry)?))
+                ScalarFunction::Chr => {
+                    Ok(chr(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
-                ScalarFunction::CharacterLength => {
-                    Ok(character_length(parse_expr(&args[0], registry)?))
+                ScalarFunction::InitCap => {
+                    Ok(ascii(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
-                ScalarFunction::Chr => Ok(chr(parse_expr(&args[0], registry)?)),
-                ScalarFunction::InitCap => Ok(ascii(parse_expr(&args[0], registry)?)),
                 ScalarFunction::Gcd => Ok(gcd(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Lcm => Ok(lcm(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Left => Ok(left(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Random => Ok(random()),
                 ScalarFunction::Uuid => Ok(uuid()),
                 ScalarFunction::Repeat => Ok(repeat(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Replace => Ok(replace(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
-                ScalarFunction::Reverse => Ok(reverse(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Reverse => {
+                    Ok(reverse(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
                 ScalarFunction::Right => Ok(right(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Concat => Ok(concat_expr(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::ConcatWithSeparator => Ok(concat_ws_expr(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Lpad => Ok(lpad(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Rpad => Ok(rpad(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::RegexpReplace => Ok(regexp_replace(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::RegexpMatch => Ok(regexp_match(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpLike => Ok(regexp_like(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpCount => Ok(regexp_count(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpInstr => Ok(regexp_instr(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpSubstr => Ok(regexp_substr(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpExtract => Ok(regexp_extract(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpSplitToArray => Ok(regexp_split_to_array(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::RegexpMatches => Ok(regexp_matches(
+                    args.to_owned()
+                        .iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Btrim => Ok(btrim(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::SplitPart => Ok(split_part(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::StartsWith => Ok(starts_with(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Strpos => Ok(strpos(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Substr => {
                     if args.len() > 2 {
                         assert_eq!(args.len(), 3);
                         Ok(substring(
-                            parse_expr(&args[0], registry)?,
-                            parse_expr(&args[1], registry)?,
-                            parse_expr(&args[2], registry)?,
+                            parse_expr_with_codec(&args[0], registry, codec)?,
+                            parse_expr_with_codec(&args[1], registry, codec)?,
+                            parse_expr_with_codec(&args[2], registry, codec)?,
                         ))
                     } else {
                         Ok(substr(
-                            parse_expr(&args[0], registry)?,
-                            parse_expr(&args[1], registry)?,
+                            parse_expr_with_codec(&args[0], registry, codec)?,
+                            parse_expr_with_codec(&args[1], registry, codec)?,
                         ))
                     }
                 }
-                ScalarFunction::ToHex => Ok(to_hex(parse_expr(&args[0], registry)?)),
+                ScalarFunction::ToHex => {
+                    Ok(to_hex(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
                 ScalarFunction::ToTimestampMillis => {
-                    Ok(to_timestamp_millis(parse_expr(&args[0], registry)?))
+                    Ok(to_timestamp_millis_with_formats(
+                        args.iter()
+                            .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    ))
                 }
                 ScalarFunction::ToTimestampMicros => {
-                    Ok(to_timestamp_micros(parse_expr(&args[0], registry)?))
-                }
-                ScalarFunction::ToTimestampNanos => {
-                    Ok(to_timestamp_nanos(parse_expr(&args[0], registry)?))
+                    Ok(to_timestamp_micros_with_formats(
+                        args.iter()
+                            .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    ))
                 }
+                ScalarFunction::ToTimestampNanos => Ok(to_timestamp_nanos_with_formats(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
                 ScalarFunction::ToTimestampSeconds => {
-                    Ok(to_timestamp_seconds(parse_expr(&args[0], registry)?))
+                    Ok(to_timestamp_seconds_with_formats(
+                        args.iter()
+                            .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    ))
                 }
                 ScalarFunction::Now => Ok(now()),
                 ScalarFunction::Translate => Ok(translate(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
                 )),
                 ScalarFunction::Coalesce => Ok(coalesce(
                     args.to_owned()
                         .iter()
-                        .map(|expr| parse_expr(expr, registry))
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
                         .collect::<Result<Vec<_>, _>>()?,
                 )),
                 ScalarFunction::Pi => Ok(pi()),
                 ScalarFunction::Power => Ok(power(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::Log => Ok(log(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::FromUnixtime => Ok(from_unixtime(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::ToLocalTime => Ok(to_local_time(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::MakeTimestamp => Ok(make_timestamp(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
                 )),
-                ScalarFunction::FromUnixtime => {
-                    Ok(from_unixtime(parse_expr(&args[0], registry)?))
-                }
                 ScalarFunction::Atan2 => Ok(atan2(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
                 ScalarFunction::CurrentDate => Ok(current_date()),
                 ScalarFunction::CurrentTime => Ok(current_time()),
-                ScalarFunction::Cot => Ok(cot(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Cot => {
+                    Ok(cot(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
                 ScalarFunction::Nanvl => Ok(nanvl(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
                 )),
-                ScalarFunction::Isnan => Ok(isnan(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Iszero => Ok(iszero(parse_expr(&args[0], registry)?)),
-                ScalarFunction::ArrowTypeof => {
-                    Ok(arrow_typeof(parse_expr(&args[0], registry)?))
+                ScalarFunction::Isnan => {
+                    Ok(isnan(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
-                ScalarFunction::ToTimestamp => {
-                    Ok(to_timestamp_seconds(parse_expr(&args[0], registry)?))
+                ScalarFunction::Iszero => {
+                    Ok(iszero(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::ArrowTypeof => Ok(arrow_typeof(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::ToTimestamp => Ok(to_timestamp_with_formats(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::TryToTimestamp => Ok(try_to_timestamp(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::ToDate => Ok(to_date(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::TryToDate => Ok(try_to_date(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::ToChar => Ok(to_char(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::Collate => Ok(collate(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::CollationKey => Ok(collation_key(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::Flatten => {
+                    Ok(flatten(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
-                ScalarFunction::Flatten => Ok(flatten(parse_expr(&args[0], registry)?)),
                 ScalarFunction::StringToArray => Ok(string_to_array(
-                    parse_expr(&args[0], registry)?,
-                    parse_expr(&args[1], registry)?,
-                    parse_expr(&args[2], registry)?,
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
+                )),
+                ScalarFunction::StructFun => Ok(struct_fun(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::JsonGet => Ok(json_get(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonGetStr => Ok(json_get_str(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonGetInt => Ok(json_get_int(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
                 )),
-                ScalarFunction::StructFun => {
-                    Ok(struct_fun(parse_expr(&args[0], registry)?))
+                ScalarFunction::JsonGetFloat => Ok(json_get_float(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonGetBool => Ok(json_get_bool(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonContains => Ok(json_contains(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonLength => Ok(json_length(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonKeys => Ok(json_keys(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::JsonArrayElements => Ok(json_array_elements(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::MakeMap => Ok(make_map(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::MapKeys => {
+                    Ok(map_keys(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::MapValues => Ok(map_values(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::MapEntries => Ok(map_entries(parse_expr_with_codec(
+                    &args[0], registry, codec,
+                )?)),
+                ScalarFunction::ElementAt => Ok(element_at(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArraySort => Ok(array_sort(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::ArrayTransform => Ok(array_transform(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayFilter => Ok(array_filter(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayReduce => Ok(array_reduce(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                    parse_expr_with_codec(&args[2], registry, codec)?,
+                )),
+                ScalarFunction::ArrayAnyMatch => Ok(array_any_match(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayAllMatch => Ok(array_all_match(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayDistinct => Ok(array_distinct(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                )),
+                ScalarFunction::ArrayUnion => Ok(array_union(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayExcept => Ok(array_except(
+                    parse_expr_with_codec(&args[0], registry, codec)?,
+                    parse_expr_with_codec(&args[1], registry, codec)?,
+                )),
+                ScalarFunction::ArrayMax => {
+                    Ok(array_max(parse_expr_with_codec(&args[0], registry, codec)?))
+                }
+                ScalarFunction::ArrayMin => {
+                    Ok(array_min(parse_expr_with_codec(&args[0], registry, codec)?))
                 }
+                ScalarFunction::ArrayZip => Ok(array_zip(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::ArrayResize => Ok(array_resize(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::Range => Ok(range(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
+                ScalarFunction::GenerateSeries => Ok(generate_series(
+                    args.iter()
+                        .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )),
             }
         }
-        ExprType::ScalarUdfExpr(protobuf::ScalarUdfExprNode { fun_name, args }) => {
-            let scalar_fn = registry.udf(fun_name.as_str())?;
+        ExprType::ScalarUdfExpr(protobuf::ScalarUdfExprNode {
+            fun_name,
+            args,
+            fun_definition,
+        }) => {
+            let scalar_fn = match fun_definition {
+                Some(buf) => codec.try_decode_udf(fun_name, buf)?,
+                None => registered_udf(registry, fun_name)?,
+            };
             Ok(Expr::ScalarUDF(expr::ScalarUDF::new(
                 scalar_fn,
                 args.iter()
-                    .map(|expr| parse_expr(expr, registry))
+                    .map(|expr| parse_expr_with_codec(expr, registry, codec))
                     .collect::<Result<Vec<_>, Error>>()?,
             )))
         }
         ExprType::AggregateUdfExpr(pb) => {
-            let agg_fn = registry.udaf(pb.fun_name.as_str())?;
+            let agg_fn = match &pb.fun_definition {
+                Some(buf) => codec.try_decode_udaf(&pb.fun_name, buf)?,
+                None => registered_udaf(registry, &pb.fun_name)?,
+            };
 
             Ok(Expr::AggregateUDF(expr::AggregateUDF::new(
                 agg_fn,
                 pb.args
                     .iter()
-                    .map(|expr| parse_expr(expr, registry))
+                    .map(|expr| parse_expr_with_codec(expr, registry, codec))
                     .collect::<Result<Vec<_>, Error>>()?,
-                parse_optional_expr(pb.filter.as_deref(), registry)?.map(Box::new),
-                parse_vec_expr(&pb.order_by, registry)?,
+                parse_optional_expr(pb.filter.as_deref(), registry, codec)?.map(Box::new),
+                parse_vec_expr(&pb.order_by, registry, codec)?,
             )))
         }
 
@@ -1698,7 +2160,7 @@ pub fn parse_expr(
                         expr_list
                             .expr
                             .iter()
-                            .map(|expr| parse_expr(expr, registry))
+                            .map(|expr| parse_expr_with_codec(expr, registry, codec))
                             .collect::<Result<Vec<_>, Error>>()
                     })
                     .collect::<Result<Vec<_>, Error>>()?,
@@ -1706,13 +2168,13 @@ pub fn parse_expr(
         }
         ExprType::Cube(CubeNode { expr }) => Ok(Expr::GroupingSet(GroupingSet::Cube(
             expr.iter()
-                .map(|expr| parse_expr(expr, registry))
+                .map(|expr| parse_expr_with_codec(expr, registry, codec))
                 .collect::<Result<Vec<_>, Error>>()?,
         ))),
         ExprType::Rollup(RollupNode { expr }) => {
             Ok(Expr::GroupingSet(GroupingSet::Rollup(
                 expr.iter()
-                    .map(|expr| parse_expr(expr, registry))
+                    .map(|expr| parse_expr_with_codec(expr, registry, codec))
                     .collect::<Result<Vec<_>, Error>>()?,
             )))
         }
@@ -1723,6 +2185,28 @@ pub fn parse_expr(
                 Some(data_type.try_into()?),
             ))),
         },
+        ExprType::Lambda(lambda) => {
+            let params = lambda
+                .params
+                .iter()
+                .map(|field| {
+                    let field: Field = field.try_into()?;
+                    Ok((field.name().clone(), field.data_type().clone()))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            let captures = lambda
+                .captures
+                .iter()
+                .map(|expr| parse_expr_with_codec(expr, registry, codec))
+                .collect::<Result<Vec<_>, Error>>()?;
+            Ok(Expr::Lambda(
+                Lambda::new(
+                    params,
+                    parse_required_expr(lambda.body.as_deref(), registry, codec, "body")?,
+                )
+                .with_captures(captures),
+            ))
+        }
     }
 }
 
@@ -1780,10 +2264,14 @@ pub fn from_proto_binary_op(op: &str) -> Result<Operator, Error> {
 fn parse_vec_expr(
     p: &[protobuf::LogicalExprNode],
     registry: &dyn FunctionRegistry,
+    codec: &dyn LogicalExtensionCodec,
 ) -> Result<Option<Vec<Expr>>, Error> {
     let res = p
         .iter()
-        .map(|elem| parse_expr(elem, registry).map_err(|e| plan_datafusion_err!("{}", e)))
+        .map(|elem| {
+            parse_expr_with_codec(elem, registry, codec)
+                .map_err(|e| plan_datafusion_err!("{}", e))
+        })
         .collect::<Result<Vec<_>>>()?;
     // Convert empty vector to None.
     Ok((!res.is_empty()).then_some(res))
@@ -1792,9 +2280,10 @@ fn parse_vec_expr(
 fn parse_optional_expr(
     p: Option<&protobuf::LogicalExprNode>,
     registry: &dyn FunctionRegistry,
+    codec: &dyn LogicalExtensionCodec,
 ) -> Result<Option<Expr>, Error> {
     match p {
-        Some(expr) => parse_expr(expr, registry).map(Some),
+        Some(expr) => parse_expr_with_codec(expr, registry, codec).map(Some),
         None => Ok(None),
     }
 }
@@ -1802,10 +2291,11 @@ fn parse_optional_expr(
 fn parse_required_expr(
     p: Option<&protobuf::LogicalExprNode>,
     registry: &dyn FunctionRegistry,
+    codec: &dyn LogicalExtensionCodec,
     field: impl Into<String>,
 ) -> Result<Expr, Error> {
     match p {
-        Some(expr) => parse_expr(expr, registry),
+        Some(expr) => parse_expr_with_codec(expr, registry, codec),
         None => Err(Error::required(field)),
     }
 }
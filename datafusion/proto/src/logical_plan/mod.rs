@@ -55,7 +55,7 @@ use datafusion_expr::{
         EmptyRelation, Extension, Join, JoinConstraint, Limit, Prepare, Projection,
         Repartition, Sort, SubqueryAlias, TableScan, Values, Window,
     },
-    DropView, Expr, LogicalPlan, LogicalPlanBuilder,
+    AggregateUDF, DropView, Expr, LogicalPlan, LogicalPlanBuilder, ScalarUDF, WindowUDF,
 };
 
 use prost::bytes::BufMut;
@@ -64,6 +64,8 @@ use prost::Message;
 pub mod from_proto;
 pub mod to_proto;
 
+use self::to_proto::serialize_expr;
+
 impl From<from_proto::Error> for DataFusionError {
     fn from(e: from_proto::Error) -> Self {
         plan_datafusion_err!("{}", e)
@@ -122,6 +124,57 @@ pub trait LogicalExtensionCodec: Debug + Send + Sync {
         node: Arc<dyn TableProvider>,
         buf: &mut Vec<u8>,
     ) -> Result<()>;
+
+    /// Decode a scalar UDF named `name` from the definition written by
+    /// [`Self::try_encode_udf`]
+    ///
+    /// This is only called for functions with a non-empty definition, other
+    /// functions are looked up by name in the function registry.
+    fn try_decode_udf(&self, name: &str, _buf: &[u8]) -> Result<Arc<ScalarUDF>> {
+        not_impl_err!("LogicalExtensionCodec is not provided for scalar function {name}")
+    }
+
+    /// Encode the definition of a scalar UDF, such as its configuration,
+    /// into `buf`
+    ///
+    /// Writing nothing, which is the default, encodes the function by name
+    /// only. Encoded definitions should include a version if their format
+    /// can change.
+    fn try_encode_udf(&self, _node: &ScalarUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decode an aggregate UDF named `name` from the definition written by
+    /// [`Self::try_encode_udaf`]
+    ///
+    /// Like [`Self::try_decode_udf`], this is only called for functions with
+    /// a non-empty definition.
+    fn try_decode_udaf(&self, name: &str, _buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        not_impl_err!(
+            "LogicalExtensionCodec is not provided for aggregate function {name}"
+        )
+    }
+
+    /// Encode the definition of an aggregate UDF into `buf`, or nothing to
+    /// encode it by name only
+    fn try_encode_udaf(&self, _node: &AggregateUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decode a window UDF named `name` from the definition written by
+    /// [`Self::try_encode_udwf`]
+    ///
+    /// Like [`Self::try_decode_udf`], this is only called for functions with
+    /// a non-empty definition.
+    fn try_decode_udwf(&self, name: &str, _buf: &[u8]) -> Result<Arc<WindowUDF>> {
+        not_impl_err!("LogicalExtensionCodec is not provided for window function {name}")
+    }
+
+    /// Encode the definition of a window UDF into `buf`, or nothing to
+    /// encode it by name only
+    fn try_encode_udwf(&self, _node: &WindowUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -230,7 +283,13 @@ impl AsLogicalPlan for LogicalPlanNode {
                         .chunks_exact(n_cols)
                         .map(|r| {
                             r.iter()
-                                .map(|expr| from_proto::parse_expr(expr, ctx))
+                                .map(|expr| {
+                                    from_proto::parse_expr_with_codec(
+                                        expr,
+                                        ctx,
+                                        extension_codec,
+                                    )
+                                })
                                 .collect::<Result<Vec<_>, from_proto::Error>>()
                         })
                         .collect::<Result<Vec<_>, _>>()
@@ -244,7 +303,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let expr: Vec<Expr> = projection
                     .expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<_>, _>>()?;
 
                 let new_proj = project(input, expr)?;
@@ -266,7 +327,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let expr: Expr = selection
                     .expr
                     .as_ref()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .transpose()?
                     .ok_or_else(|| {
                         DataFusionError::Internal("expression required".to_string())
@@ -280,7 +343,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let window_expr = window
                     .window_expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<Expr>, _>>()?;
                 LogicalPlanBuilder::from(input).window(window_expr)?.build()
             }
@@ -290,12 +355,16 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let group_expr = aggregate
                     .group_expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<Expr>, _>>()?;
                 let aggr_expr = aggregate
                     .aggr_expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<Expr>, _>>()?;
                 LogicalPlanBuilder::from(input)
                     .aggregate(group_expr, aggr_expr)?
@@ -317,7 +386,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let filters = scan
                     .filters
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<_>, _>>()?;
 
                 let mut all_sort_orders = vec![];
@@ -325,7 +396,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                     let file_sort_order = order
                         .logical_expr_nodes
                         .iter()
-                        .map(|expr| from_proto::parse_expr(expr, ctx))
+                        .map(|expr| {
+                            from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                        })
                         .collect::<Result<Vec<_>, _>>()?;
                     all_sort_orders.push(file_sort_order)
                 }
@@ -425,7 +498,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let filters = scan
                     .filters
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<_>, _>>()?;
                 let provider = extension_codec.try_decode_table_provider(
                     &scan.custom_table_data,
@@ -450,7 +525,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let sort_expr: Vec<Expr> = sort
                     .expr
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<Expr>, _>>()?;
                 LogicalPlanBuilder::from(input).sort(sort_expr)?.build()
             }
@@ -472,7 +549,13 @@ impl AsLogicalPlan for LogicalPlanNode {
                     }) => Partitioning::Hash(
                         pb_hash_expr
                             .iter()
-                            .map(|expr| from_proto::parse_expr(expr, ctx))
+                            .map(|expr| {
+                                from_proto::parse_expr_with_codec(
+                                    expr,
+                                    ctx,
+                                    extension_codec,
+                                )
+                            })
                             .collect::<Result<Vec<_>, _>>()?,
                         *partition_count as usize,
                     ),
@@ -516,7 +599,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                     let order_expr = expr
                         .logical_expr_nodes
                         .iter()
-                        .map(|expr| from_proto::parse_expr(expr, ctx))
+                        .map(|expr| {
+                            from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                        })
                         .collect::<Result<Vec<Expr>, _>>()?;
                     order_exprs.push(order_expr)
                 }
@@ -634,12 +719,16 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let left_keys: Vec<Expr> = join
                     .left_join_key
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<_>, _>>()?;
                 let right_keys: Vec<Expr> = join
                     .right_join_key
                     .iter()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .collect::<Result<Vec<_>, _>>()?;
                 let join_type =
                     protobuf::JoinType::try_from(join.join_type).map_err(|_| {
@@ -660,7 +749,9 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let filter: Option<Expr> = join
                     .filter
                     .as_ref()
-                    .map(|expr| from_proto::parse_expr(expr, ctx))
+                    .map(|expr| {
+                        from_proto::parse_expr_with_codec(expr, ctx, extension_codec)
+                    })
                     .map_or(Ok(None), |v| v.map(Some))?;
 
                 let builder = LogicalPlanBuilder::from(into_logical_plan!(
@@ -807,7 +898,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let values_list = values
                     .iter()
                     .flatten()
-                    .map(|v| v.try_into())
+                    .map(|v| serialize_expr(v, extension_codec))
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(protobuf::LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Values(
@@ -845,7 +936,7 @@ impl AsLogicalPlan for LogicalPlanNode {
 
                 let filters: Vec<protobuf::LogicalExprNode> = filters
                     .iter()
-                    .map(|filter| filter.try_into())
+                    .map(|filter| serialize_expr(filter, extension_codec))
                     .collect::<Result<Vec<_>, _>>()?;
 
                 if let Some(listing_table) = source.downcast_ref::<ListingTable>() {
@@ -902,7 +993,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         let expr_vec = LogicalExprNodeCollection {
                             logical_expr_nodes: order
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, extension_codec))
                                 .collect::<Result<Vec<_>, to_proto::Error>>()?,
                         };
                         exprs_vec.push(expr_vec);
@@ -983,7 +1074,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                             )),
                             expr: expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, extension_codec))
                                 .collect::<Result<Vec<_>, to_proto::Error>>()?,
                             optional_alias: None,
                         },
@@ -1000,7 +1091,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Selection(Box::new(
                         protobuf::SelectionNode {
                             input: Some(Box::new(input)),
-                            expr: Some((&filter.predicate).try_into()?),
+                            expr: Some(serialize_expr(&filter.predicate, extension_codec)?),
                         },
                     ))),
                 })
@@ -1033,7 +1124,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                             input: Some(Box::new(input)),
                             window_expr: window_expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, extension_codec))
                                 .collect::<Result<Vec<_>, _>>()?,
                         },
                     ))),
@@ -1056,11 +1147,11 @@ impl AsLogicalPlan for LogicalPlanNode {
                             input: Some(Box::new(input)),
                             group_expr: group_expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, extension_codec))
                                 .collect::<Result<Vec<_>, _>>()?,
                             aggr_expr: aggr_expr
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, extension_codec))
                                 .collect::<Result<Vec<_>, _>>()?,
                         },
                     ))),
@@ -1088,7 +1179,12 @@ impl AsLogicalPlan for LogicalPlanNode {
                     )?;
                 let (left_join_key, right_join_key) = on
                     .iter()
-                    .map(|(l, r)| Ok((l.try_into()?, r.try_into()?)))
+                    .map(|(l, r)| {
+                        Ok((
+                            serialize_expr(l, extension_codec)?,
+                            serialize_expr(r, extension_codec)?,
+                        ))
+                    })
                     .collect::<Result<Vec<_>, to_proto::Error>>()?
                     .into_iter()
                     .unzip();
@@ -1097,7 +1193,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     join_constraint.to_owned().into();
                 let filter = filter
                     .as_ref()
-                    .map(|e| e.try_into())
+                    .map(|e| serialize_expr(e, extension_codec))
                     .map_or(Ok(None), |v| v.map(Some))?;
                 Ok(protobuf::LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Join(Box::new(
@@ -1156,7 +1252,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     )?;
                 let selection_expr: Vec<protobuf::LogicalExprNode> = expr
                     .iter()
-                    .map(|expr| expr.try_into())
+                    .map(|expr| serialize_expr(expr, extension_codec))
                     .collect::<Result<Vec<_>, to_proto::Error>>()?;
                 Ok(protobuf::LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Sort(Box::new(
@@ -1188,7 +1284,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         PartitionMethod::Hash(protobuf::HashRepartition {
                             hash_expr: exprs
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, extension_codec))
                                 .collect::<Result<Vec<_>, to_proto::Error>>()?,
                             partition_count: *partition_count as u64,
                         })
@@ -1242,7 +1338,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                     let temp = LogicalExprNodeCollection {
                         logical_expr_nodes: order
                             .iter()
-                            .map(|expr| expr.try_into())
+                            .map(|expr| serialize_expr(expr, extension_codec))
                             .collect::<Result<Vec<_>, to_proto::Error>>(
                         )?,
                     };
@@ -1432,6 +1528,16 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::Ddl(DdlStatement::DropTable(_)) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for DropTable",
             )),
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(_)) => {
+                Err(proto_error(
+                    "LogicalPlan serde is not yet implemented for CreateMaterializedView",
+                ))
+            }
+            LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(_)) => {
+                Err(proto_error(
+                    "LogicalPlan serde is not yet implemented for RefreshMaterializedView",
+                ))
+            }
             LogicalPlan::Ddl(DdlStatement::DropView(DropView {
                 name,
                 if_exists,
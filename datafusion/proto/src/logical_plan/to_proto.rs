@@ -19,6 +19,7 @@
 //! DataFusion logical plans to be serialized and transmitted between
 //! processes.
 
+use crate::logical_plan::{DefaultLogicalExtensionCodec, LogicalExtensionCodec};
 use crate::protobuf::{
     self,
     arrow_type::ArrowTypeEnum,
@@ -44,7 +45,7 @@ use datafusion_common::{
 };
 use datafusion_expr::expr::{
     self, Alias, Between, BinaryExpr, Cast, GetFieldAccess, GetIndexedField, GroupingSet,
-    InList, Like, Placeholder, ScalarFunction, ScalarUDF, Sort,
+    InList, Lambda, Like, Placeholder, ScalarFunction, ScalarUDF, Sort,
 };
 use datafusion_expr::{
     logical_plan::PlanType, logical_plan::StringifiedPlan, AggregateFunction,
@@ -206,9 +207,10 @@ impl TryFrom<&DataType> for protobuf::arrow_type::ArrowTypeEnum {
                 precision: *precision as u32,
                 scale: *scale as i32,
             }),
-            DataType::Decimal256(_, _) => {
-                return Err(Error::General("Proto serialization error: The Decimal256 data type is not yet supported".to_owned()))
-            }
+            DataType::Decimal256(precision, scale) => Self::Decimal256(protobuf::Decimal {
+                precision: *precision as u32,
+                scale: *scale as i32,
+            }),
             DataType::Map(field, sorted) => {
                 Self::Map(Box::new(
                     protobuf::Map {
@@ -470,562 +472,596 @@ impl TryFrom<&Expr> for protobuf::LogicalExprNode {
     type Error = Error;
 
     fn try_from(expr: &Expr) -> Result<Self, Self::Error> {
-        use protobuf::logical_expr_node::ExprType;
+        serialize_expr(expr, &DefaultLogicalExtensionCodec {})
+    }
+}
 
-        let expr_node = match expr {
-            Expr::Column(c) => Self {
-                expr_type: Some(ExprType::Column(c.into())),
-            },
-            Expr::Alias(Alias { expr, name, .. }) => {
-                let alias = Box::new(protobuf::AliasNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    alias: name.to_owned(),
-                });
-                Self {
-                    expr_type: Some(ExprType::Alias(alias)),
-                }
-            }
-            Expr::Literal(value) => {
-                let pb_value: protobuf::ScalarValue = value.try_into()?;
-                Self {
-                    expr_type: Some(ExprType::Literal(pb_value)),
-                }
+/// Serialize an [`Expr`], encoding the definitions of user-defined functions with `codec`
+pub fn serialize_expr(
+    expr: &Expr,
+    codec: &dyn LogicalExtensionCodec,
+) -> Result<protobuf::LogicalExprNode, Error> {
+    use protobuf::logical_expr_node::ExprType;
+
+    let expr_node = match expr {
+        Expr::Column(c) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Column(c.into())),
+        },
+        Expr::Alias(Alias { expr, name, .. }) => {
+            let alias = Box::new(protobuf::AliasNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                alias: name.to_owned(),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Alias(alias)),
             }
-            Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
-                // Try to linerize a nested binary expression tree of the same operator
-                // into a flat vector of expressions.
-                let mut exprs = vec![right.as_ref()];
-                let mut current_expr = left.as_ref();
-                while let Expr::BinaryExpr(BinaryExpr {
-                    left,
-                    op: current_op,
-                    right,
-                }) = current_expr
-                {
-                    if current_op == op {
-                        exprs.push(right.as_ref());
-                        current_expr = left.as_ref();
-                    } else {
-                        break;
-                    }
-                }
-                exprs.push(current_expr);
-
-                let binary_expr = protobuf::BinaryExprNode {
-                    // We need to reverse exprs since operands are expected to be
-                    // linearized from left innermost to right outermost (but while
-                    // traversing the chain we do the exact opposite).
-                    operands: exprs
-                        .into_iter()
-                        .rev()
-                        .map(|expr| expr.try_into())
-                        .collect::<Result<Vec<_>, Error>>()?,
-                    op: format!("{op:?}"),
-                };
-                Self {
-                    expr_type: Some(ExprType::BinaryExpr(binary_expr)),
-                }
+        }
+        Expr::Literal(value) => {
+            let pb_value: protobuf::ScalarValue = value.try_into()?;
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Literal(pb_value)),
             }
-            Expr::Like(Like {
-                negated,
-                expr,
-                pattern,
-                escape_char,
-                case_insensitive,
-            }) => {
-                if *case_insensitive {
-                    let pb = Box::new(protobuf::ILikeNode {
-                        negated: *negated,
-                        expr: Some(Box::new(expr.as_ref().try_into()?)),
-                        pattern: Some(Box::new(pattern.as_ref().try_into()?)),
-                        escape_char: escape_char
-                            .map(|ch| ch.to_string())
-                            .unwrap_or_default(),
-                    });
-
-                    Self {
-                        expr_type: Some(ExprType::Ilike(pb)),
-                    }
+        }
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
+            // Try to linerize a nested binary expression tree of the same operator
+            // into a flat vector of expressions.
+            let mut exprs = vec![right.as_ref()];
+            let mut current_expr = left.as_ref();
+            while let Expr::BinaryExpr(BinaryExpr {
+                left,
+                op: current_op,
+                right,
+            }) = current_expr
+            {
+                if current_op == op {
+                    exprs.push(right.as_ref());
+                    current_expr = left.as_ref();
                 } else {
-                    let pb = Box::new(protobuf::LikeNode {
-                        negated: *negated,
-                        expr: Some(Box::new(expr.as_ref().try_into()?)),
-                        pattern: Some(Box::new(pattern.as_ref().try_into()?)),
-                        escape_char: escape_char
-                            .map(|ch| ch.to_string())
-                            .unwrap_or_default(),
-                    });
-
-                    Self {
-                        expr_type: Some(ExprType::Like(pb)),
-                    }
+                    break;
                 }
             }
-            Expr::SimilarTo(Like {
-                negated,
-                expr,
-                pattern,
-                escape_char,
-                case_insensitive: _,
-            }) => {
-                let pb = Box::new(protobuf::SimilarToNode {
+            exprs.push(current_expr);
+
+            let binary_expr = protobuf::BinaryExprNode {
+                // We need to reverse exprs since operands are expected to be
+                // linearized from left innermost to right outermost (but while
+                // traversing the chain we do the exact opposite).
+                operands: exprs
+                    .into_iter()
+                    .rev()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+                op: format!("{op:?}"),
+            };
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::BinaryExpr(binary_expr)),
+            }
+        }
+        Expr::Like(Like {
+            negated,
+            expr,
+            pattern,
+            escape_char,
+            case_insensitive,
+        }) => {
+            if *case_insensitive {
+                let pb = Box::new(protobuf::ILikeNode {
                     negated: *negated,
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    pattern: Some(Box::new(pattern.as_ref().try_into()?)),
+                    expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                    pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
                     escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
                 });
-                Self {
-                    expr_type: Some(ExprType::SimilarTo(pb)),
+
+                protobuf::LogicalExprNode {
+                    expr_type: Some(ExprType::Ilike(pb)),
                 }
-            }
-            Expr::WindowFunction(expr::WindowFunction {
-                ref fun,
-                ref args,
-                ref partition_by,
-                ref order_by,
-                ref window_frame,
-            }) => {
-                let window_function = match fun {
-                    WindowFunction::AggregateFunction(fun) => {
-                        protobuf::window_expr_node::WindowFunction::AggrFunction(
-                            protobuf::AggregateFunction::from(fun).into(),
-                        )
-                    }
-                    WindowFunction::BuiltInWindowFunction(fun) => {
-                        protobuf::window_expr_node::WindowFunction::BuiltInFunction(
-                            protobuf::BuiltInWindowFunction::from(fun).into(),
-                        )
-                    }
-                    WindowFunction::AggregateUDF(aggr_udf) => {
-                        protobuf::window_expr_node::WindowFunction::Udaf(
-                            aggr_udf.name.clone(),
-                        )
-                    }
-                    WindowFunction::WindowUDF(window_udf) => {
-                        protobuf::window_expr_node::WindowFunction::Udwf(
-                            window_udf.name.clone(),
-                        )
-                    }
-                };
-                let arg_expr: Option<Box<Self>> = if !args.is_empty() {
-                    let arg = &args[0];
-                    Some(Box::new(arg.try_into()?))
-                } else {
-                    None
-                };
-                let partition_by = partition_by
-                    .iter()
-                    .map(|e| e.try_into())
-                    .collect::<Result<Vec<_>, _>>()?;
-                let order_by = order_by
-                    .iter()
-                    .map(|e| e.try_into())
-                    .collect::<Result<Vec<_>, _>>()?;
-
-                let window_frame: Option<protobuf::WindowFrame> =
-                    Some(window_frame.try_into()?);
-                let window_expr = Box::new(protobuf::WindowExprNode {
-                    expr: arg_expr,
-                    window_function: Some(window_function),
-                    partition_by,
-                    order_by,
-                    window_frame,
+            } else {
+                let pb = Box::new(protobuf::LikeNode {
+                    negated: *negated,
+                    expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                    pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
+                    escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
                 });
-                Self {
-                    expr_type: Some(ExprType::WindowExpr(window_expr)),
+
+                protobuf::LogicalExprNode {
+                    expr_type: Some(ExprType::Like(pb)),
                 }
             }
-            Expr::AggregateFunction(expr::AggregateFunction {
-                ref fun,
-                ref args,
-                ref distinct,
-                ref filter,
-                ref order_by,
-            }) => {
-                let aggr_function = match fun {
-                    AggregateFunction::ApproxDistinct => {
-                        protobuf::AggregateFunction::ApproxDistinct
-                    }
-                    AggregateFunction::ApproxPercentileCont => {
-                        protobuf::AggregateFunction::ApproxPercentileCont
-                    }
-                    AggregateFunction::ApproxPercentileContWithWeight => {
-                        protobuf::AggregateFunction::ApproxPercentileContWithWeight
-                    }
-                    AggregateFunction::ArrayAgg => protobuf::AggregateFunction::ArrayAgg,
-                    AggregateFunction::Min => protobuf::AggregateFunction::Min,
-                    AggregateFunction::Max => protobuf::AggregateFunction::Max,
-                    AggregateFunction::Sum => protobuf::AggregateFunction::Sum,
-                    AggregateFunction::BitAnd => protobuf::AggregateFunction::BitAnd,
-                    AggregateFunction::BitOr => protobuf::AggregateFunction::BitOr,
-                    AggregateFunction::BitXor => protobuf::AggregateFunction::BitXor,
-                    AggregateFunction::BoolAnd => protobuf::AggregateFunction::BoolAnd,
-                    AggregateFunction::BoolOr => protobuf::AggregateFunction::BoolOr,
-                    AggregateFunction::Avg => protobuf::AggregateFunction::Avg,
-                    AggregateFunction::Count => protobuf::AggregateFunction::Count,
-                    AggregateFunction::Variance => protobuf::AggregateFunction::Variance,
-                    AggregateFunction::VariancePop => {
-                        protobuf::AggregateFunction::VariancePop
-                    }
-                    AggregateFunction::Covariance => {
-                        protobuf::AggregateFunction::Covariance
-                    }
-                    AggregateFunction::CovariancePop => {
-                        protobuf::AggregateFunction::CovariancePop
-                    }
-                    AggregateFunction::Stddev => protobuf::AggregateFunction::Stddev,
-                    AggregateFunction::StddevPop => {
-                        protobuf::AggregateFunction::StddevPop
-                    }
-                    AggregateFunction::Correlation => {
-                        protobuf::AggregateFunction::Correlation
-                    }
-                    AggregateFunction::RegrSlope => {
-                        protobuf::AggregateFunction::RegrSlope
-                    }
-                    AggregateFunction::RegrIntercept => {
-                        protobuf::AggregateFunction::RegrIntercept
-                    }
-                    AggregateFunction::RegrR2 => protobuf::AggregateFunction::RegrR2,
-                    AggregateFunction::RegrAvgx => protobuf::AggregateFunction::RegrAvgx,
-                    AggregateFunction::RegrAvgy => protobuf::AggregateFunction::RegrAvgy,
-                    AggregateFunction::RegrCount => {
-                        protobuf::AggregateFunction::RegrCount
-                    }
-                    AggregateFunction::RegrSXX => protobuf::AggregateFunction::RegrSxx,
-                    AggregateFunction::RegrSYY => protobuf::AggregateFunction::RegrSyy,
-                    AggregateFunction::RegrSXY => protobuf::AggregateFunction::RegrSxy,
-                    AggregateFunction::ApproxMedian => {
-                        protobuf::AggregateFunction::ApproxMedian
-                    }
-                    AggregateFunction::Grouping => protobuf::AggregateFunction::Grouping,
-                    AggregateFunction::Median => protobuf::AggregateFunction::Median,
-                    AggregateFunction::FirstValue => {
-                        protobuf::AggregateFunction::FirstValueAgg
-                    }
-                    AggregateFunction::LastValue => {
-                        protobuf::AggregateFunction::LastValueAgg
-                    }
-                };
+        }
+        Expr::SimilarTo(Like {
+            negated,
+            expr,
+            pattern,
+            escape_char,
+            case_insensitive: _,
+        }) => {
+            let pb = Box::new(protobuf::SimilarToNode {
+                negated: *negated,
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                pattern: Some(Box::new(serialize_expr(pattern.as_ref(), codec)?)),
+                escape_char: escape_char.map(|ch| ch.to_string()).unwrap_or_default(),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::SimilarTo(pb)),
+            }
+        }
+        Expr::WindowFunction(expr::WindowFunction {
+            ref fun,
+            ref args,
+            ref partition_by,
+            ref order_by,
+            ref window_frame,
+        }) => {
+            let mut buf = Vec::new();
+            let window_function = match fun {
+                WindowFunction::AggregateFunction(fun) => {
+                    protobuf::window_expr_node::WindowFunction::AggrFunction(
+                        protobuf::AggregateFunction::from(fun).into(),
+                    )
+                }
+                WindowFunction::BuiltInWindowFunction(fun) => {
+                    protobuf::window_expr_node::WindowFunction::BuiltInFunction(
+                        protobuf::BuiltInWindowFunction::from(fun).into(),
+                    )
+                }
+                WindowFunction::AggregateUDF(aggr_udf) => {
+                    codec
+                        .try_encode_udaf(aggr_udf, &mut buf)
+                        .map_err(|e| Error::General(e.to_string()))?;
+                    protobuf::window_expr_node::WindowFunction::Udaf(
+                        aggr_udf.name.clone(),
+                    )
+                }
+                WindowFunction::WindowUDF(window_udf) => {
+                    codec
+                        .try_encode_udwf(window_udf, &mut buf)
+                        .map_err(|e| Error::General(e.to_string()))?;
+                    protobuf::window_expr_node::WindowFunction::Udwf(
+                        window_udf.name.clone(),
+                    )
+                }
+            };
+            let arg_expr: Option<Box<protobuf::LogicalExprNode>> = if !args.is_empty() {
+                let arg = &args[0];
+                Some(Box::new(serialize_expr(arg, codec)?))
+            } else {
+                None
+            };
+            let partition_by = partition_by
+                .iter()
+                .map(|e| serialize_expr(e, codec))
+                .collect::<Result<Vec<_>, _>>()?;
+            let order_by = order_by
+                .iter()
+                .map(|e| serialize_expr(e, codec))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let window_frame: Option<protobuf::WindowFrame> =
+                Some(window_frame.try_into()?);
+            let window_expr = Box::new(protobuf::WindowExprNode {
+                expr: arg_expr,
+                window_function: Some(window_function),
+                partition_by,
+                order_by,
+                window_frame,
+                fun_definition: (!buf.is_empty()).then_some(buf),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::WindowExpr(window_expr)),
+            }
+        }
+        Expr::AggregateFunction(expr::AggregateFunction {
+            ref fun,
+            ref args,
+            ref distinct,
+            ref filter,
+            ref order_by,
+        }) => {
+            let aggr_function = match fun {
+                AggregateFunction::ApproxDistinct => {
+                    protobuf::AggregateFunction::ApproxDistinct
+                }
+                AggregateFunction::ApproxPercentileCont => {
+                    protobuf::AggregateFunction::ApproxPercentileCont
+                }
+                AggregateFunction::ApproxPercentileContWithWeight => {
+                    protobuf::AggregateFunction::ApproxPercentileContWithWeight
+                }
+                AggregateFunction::ArrayAgg => protobuf::AggregateFunction::ArrayAgg,
+                AggregateFunction::Min => protobuf::AggregateFunction::Min,
+                AggregateFunction::Max => protobuf::AggregateFunction::Max,
+                AggregateFunction::Sum => protobuf::AggregateFunction::Sum,
+                AggregateFunction::BitAnd => protobuf::AggregateFunction::BitAnd,
+                AggregateFunction::BitOr => protobuf::AggregateFunction::BitOr,
+                AggregateFunction::BitXor => protobuf::AggregateFunction::BitXor,
+                AggregateFunction::BoolAnd => protobuf::AggregateFunction::BoolAnd,
+                AggregateFunction::BoolOr => protobuf::AggregateFunction::BoolOr,
+                AggregateFunction::Avg => protobuf::AggregateFunction::Avg,
+                AggregateFunction::Count => protobuf::AggregateFunction::Count,
+                AggregateFunction::Variance => protobuf::AggregateFunction::Variance,
+                AggregateFunction::VariancePop => {
+                    protobuf::AggregateFunction::VariancePop
+                }
+                AggregateFunction::Covariance => protobuf::AggregateFunction::Covariance,
+                AggregateFunction::CovariancePop => {
+                    protobuf::AggregateFunction::CovariancePop
+                }
+                AggregateFunction::Stddev => protobuf::AggregateFunction::Stddev,
+                AggregateFunction::StddevPop => protobuf::AggregateFunction::StddevPop,
+                AggregateFunction::Correlation => {
+                    protobuf::AggregateFunction::Correlation
+                }
+                AggregateFunction::RegrSlope => protobuf::AggregateFunction::RegrSlope,
+                AggregateFunction::RegrIntercept => {
+                    protobuf::AggregateFunction::RegrIntercept
+                }
+                AggregateFunction::RegrR2 => protobuf::AggregateFunction::RegrR2,
+                AggregateFunction::RegrAvgx => protobuf::AggregateFunction::RegrAvgx,
+                AggregateFunction::RegrAvgy => protobuf::AggregateFunction::RegrAvgy,
+                AggregateFunction::RegrCount => protobuf::AggregateFunction::RegrCount,
+                AggregateFunction::RegrSXX => protobuf::AggregateFunction::RegrSxx,
+                AggregateFunction::RegrSYY => protobuf::AggregateFunction::RegrSyy,
+                AggregateFunction::RegrSXY => protobuf::AggregateFunction::RegrSxy,
+                AggregateFunction::ApproxMedian => {
+                    protobuf::AggregateFunction::ApproxMedian
+                }
+                AggregateFunction::Grouping => protobuf::AggregateFunction::Grouping,
+                AggregateFunction::Median => protobuf::AggregateFunction::Median,
+                AggregateFunction::FirstValue => {
+                    protobuf::AggregateFunction::FirstValueAgg
+                }
+                AggregateFunction::LastValue => protobuf::AggregateFunction::LastValueAgg,
+            };
 
-                let aggregate_expr = protobuf::AggregateExprNode {
-                    aggr_function: aggr_function.into(),
-                    expr: args
+            let aggregate_expr = protobuf::AggregateExprNode {
+                aggr_function: aggr_function.into(),
+                expr: args
+                    .iter()
+                    .map(|v| serialize_expr(v, codec))
+                    .collect::<Result<Vec<_>, _>>()?,
+                distinct: *distinct,
+                filter: match filter {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
+                order_by: match order_by {
+                    Some(e) => e
                         .iter()
-                        .map(|v| v.try_into())
+                        .map(|expr| serialize_expr(expr, codec))
                         .collect::<Result<Vec<_>, _>>()?,
-                    distinct: *distinct,
-                    filter: match filter {
-                        Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                        None => None,
-                    },
-                    order_by: match order_by {
-                        Some(e) => e
-                            .iter()
-                            .map(|expr| expr.try_into())
-                            .collect::<Result<Vec<_>, _>>()?,
-                        None => vec![],
-                    },
-                };
-                Self {
-                    expr_type: Some(ExprType::AggregateExpr(Box::new(aggregate_expr))),
-                }
-            }
-            Expr::ScalarVariable(_, _) => {
-                return Err(Error::General(
-                    "Proto serialization error: Scalar Variable not supported"
-                        .to_string(),
-                ))
+                    None => vec![],
+                },
+            };
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::AggregateExpr(Box::new(aggregate_expr))),
             }
-            Expr::ScalarFunction(ScalarFunction { fun, args }) => {
-                let fun: protobuf::ScalarFunction = fun.try_into()?;
-                let args: Vec<Self> = args
-                    .iter()
-                    .map(|e| e.try_into())
-                    .collect::<Result<Vec<Self>, Error>>()?;
-                Self {
-                    expr_type: Some(ExprType::ScalarFunction(
-                        protobuf::ScalarFunctionNode {
-                            fun: fun.into(),
-                            args,
-                        },
-                    )),
-                }
+        }
+        Expr::ScalarVariable(_, _) => {
+            return Err(Error::General(
+                "Proto serialization error: Scalar Variable not supported".to_string(),
+            ))
+        }
+        Expr::ScalarFunction(ScalarFunction { fun, args }) => {
+            let fun: protobuf::ScalarFunction = fun.try_into()?;
+            let args: Vec<protobuf::LogicalExprNode> = args
+                .iter()
+                .map(|e| serialize_expr(e, codec))
+                .collect::<Result<Vec<protobuf::LogicalExprNode>, Error>>()?;
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::ScalarFunction(protobuf::ScalarFunctionNode {
+                    fun: fun.into(),
+                    args,
+                })),
             }
-            Expr::ScalarUDF(ScalarUDF { fun, args }) => Self {
+        }
+        Expr::ScalarUDF(ScalarUDF { fun, args }) => {
+            let mut buf = Vec::new();
+            codec
+                .try_encode_udf(fun, &mut buf)
+                .map_err(|e| Error::General(e.to_string()))?;
+            protobuf::LogicalExprNode {
                 expr_type: Some(ExprType::ScalarUdfExpr(protobuf::ScalarUdfExprNode {
                     fun_name: fun.name.clone(),
                     args: args
                         .iter()
-                        .map(|expr| expr.try_into())
+                        .map(|expr| serialize_expr(expr, codec))
                         .collect::<Result<Vec<_>, Error>>()?,
+                    fun_definition: (!buf.is_empty()).then_some(buf),
                 })),
-            },
-            Expr::AggregateUDF(expr::AggregateUDF {
-                fun,
-                args,
-                filter,
-                order_by,
-            }) => Self {
+            }
+        }
+        Expr::AggregateUDF(expr::AggregateUDF {
+            fun,
+            args,
+            filter,
+            order_by,
+        }) => {
+            let mut buf = Vec::new();
+            codec
+                .try_encode_udaf(fun, &mut buf)
+                .map_err(|e| Error::General(e.to_string()))?;
+            protobuf::LogicalExprNode {
                 expr_type: Some(ExprType::AggregateUdfExpr(Box::new(
                     protobuf::AggregateUdfExprNode {
                         fun_name: fun.name.clone(),
-                        args: args.iter().map(|expr| expr.try_into()).collect::<Result<
-                            Vec<_>,
-                            Error,
-                        >>(
-                        )?,
+                        args: args
+                            .iter()
+                            .map(|expr| serialize_expr(expr, codec))
+                            .collect::<Result<Vec<_>, Error>>()?,
                         filter: match filter {
-                            Some(e) => Some(Box::new(e.as_ref().try_into()?)),
+                            Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
                             None => None,
                         },
                         order_by: match order_by {
                             Some(e) => e
                                 .iter()
-                                .map(|expr| expr.try_into())
+                                .map(|expr| serialize_expr(expr, codec))
                                 .collect::<Result<Vec<_>, _>>()?,
                             None => vec![],
                         },
+                        fun_definition: (!buf.is_empty()).then_some(buf),
                     },
                 ))),
-            },
-            Expr::Not(expr) => {
-                let expr = Box::new(protobuf::Not {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::NotExpr(expr)),
-                }
             }
-            Expr::IsNull(expr) => {
-                let expr = Box::new(protobuf::IsNull {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNullExpr(expr)),
-                }
+        }
+        Expr::Not(expr) => {
+            let expr = Box::new(protobuf::Not {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::NotExpr(expr)),
             }
-            Expr::IsNotNull(expr) => {
-                let expr = Box::new(protobuf::IsNotNull {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotNullExpr(expr)),
-                }
+        }
+        Expr::IsNull(expr) => {
+            let expr = Box::new(protobuf::IsNull {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNullExpr(expr)),
             }
-            Expr::IsTrue(expr) => {
-                let expr = Box::new(protobuf::IsTrue {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsTrue(expr)),
-                }
+        }
+        Expr::IsNotNull(expr) => {
+            let expr = Box::new(protobuf::IsNotNull {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotNullExpr(expr)),
             }
-            Expr::IsFalse(expr) => {
-                let expr = Box::new(protobuf::IsFalse {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsFalse(expr)),
-                }
+        }
+        Expr::IsTrue(expr) => {
+            let expr = Box::new(protobuf::IsTrue {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsTrue(expr)),
             }
-            Expr::IsUnknown(expr) => {
-                let expr = Box::new(protobuf::IsUnknown {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsUnknown(expr)),
-                }
+        }
+        Expr::IsFalse(expr) => {
+            let expr = Box::new(protobuf::IsFalse {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsFalse(expr)),
             }
-            Expr::IsNotTrue(expr) => {
-                let expr = Box::new(protobuf::IsNotTrue {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotTrue(expr)),
-                }
+        }
+        Expr::IsUnknown(expr) => {
+            let expr = Box::new(protobuf::IsUnknown {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsUnknown(expr)),
             }
-            Expr::IsNotFalse(expr) => {
-                let expr = Box::new(protobuf::IsNotFalse {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotFalse(expr)),
-                }
+        }
+        Expr::IsNotTrue(expr) => {
+            let expr = Box::new(protobuf::IsNotTrue {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotTrue(expr)),
             }
-            Expr::IsNotUnknown(expr) => {
-                let expr = Box::new(protobuf::IsNotUnknown {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::IsNotUnknown(expr)),
-                }
+        }
+        Expr::IsNotFalse(expr) => {
+            let expr = Box::new(protobuf::IsNotFalse {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotFalse(expr)),
             }
-            Expr::Between(Between {
-                expr,
-                negated,
-                low,
-                high,
-            }) => {
-                let expr = Box::new(protobuf::BetweenNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    negated: *negated,
-                    low: Some(Box::new(low.as_ref().try_into()?)),
-                    high: Some(Box::new(high.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::Between(expr)),
-                }
+        }
+        Expr::IsNotUnknown(expr) => {
+            let expr = Box::new(protobuf::IsNotUnknown {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::IsNotUnknown(expr)),
             }
-            Expr::Case(case) => {
-                let when_then_expr = case
-                    .when_then_expr
-                    .iter()
-                    .map(|(w, t)| {
-                        Ok(protobuf::WhenThen {
-                            when_expr: Some(w.as_ref().try_into()?),
-                            then_expr: Some(t.as_ref().try_into()?),
-                        })
+        }
+        Expr::Between(Between {
+            expr,
+            negated,
+            low,
+            high,
+        }) => {
+            let expr = Box::new(protobuf::BetweenNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                negated: *negated,
+                low: Some(Box::new(serialize_expr(low.as_ref(), codec)?)),
+                high: Some(Box::new(serialize_expr(high.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Between(expr)),
+            }
+        }
+        Expr::Case(case) => {
+            let when_then_expr = case
+                .when_then_expr
+                .iter()
+                .map(|(w, t)| {
+                    Ok(protobuf::WhenThen {
+                        when_expr: Some(serialize_expr(w.as_ref(), codec)?),
+                        then_expr: Some(serialize_expr(t.as_ref(), codec)?),
                     })
-                    .collect::<Result<Vec<protobuf::WhenThen>, Error>>()?;
-                let expr = Box::new(protobuf::CaseNode {
-                    expr: match &case.expr {
-                        Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                        None => None,
-                    },
-                    when_then_expr,
-                    else_expr: match &case.else_expr {
-                        Some(e) => Some(Box::new(e.as_ref().try_into()?)),
-                        None => None,
-                    },
-                });
-                Self {
-                    expr_type: Some(ExprType::Case(expr)),
-                }
+                })
+                .collect::<Result<Vec<protobuf::WhenThen>, Error>>()?;
+            let expr = Box::new(protobuf::CaseNode {
+                expr: match &case.expr {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
+                when_then_expr,
+                else_expr: match &case.else_expr {
+                    Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
+                    None => None,
+                },
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Case(expr)),
             }
-            Expr::Cast(Cast { expr, data_type }) => {
-                let expr = Box::new(protobuf::CastNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    arrow_type: Some(data_type.try_into()?),
-                });
-                Self {
-                    expr_type: Some(ExprType::Cast(expr)),
-                }
+        }
+        Expr::Cast(Cast { expr, data_type }) => {
+            let expr = Box::new(protobuf::CastNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                arrow_type: Some(data_type.try_into()?),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Cast(expr)),
             }
-            Expr::TryCast(TryCast { expr, data_type }) => {
-                let expr = Box::new(protobuf::TryCastNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    arrow_type: Some(data_type.try_into()?),
-                });
-                Self {
-                    expr_type: Some(ExprType::TryCast(expr)),
-                }
+        }
+        Expr::TryCast(TryCast { expr, data_type }) => {
+            let expr = Box::new(protobuf::TryCastNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                arrow_type: Some(data_type.try_into()?),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::TryCast(expr)),
             }
-            Expr::Sort(Sort {
-                expr,
-                asc,
-                nulls_first,
-            }) => {
-                let expr = Box::new(protobuf::SortExprNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    asc: *asc,
-                    nulls_first: *nulls_first,
-                });
-                Self {
-                    expr_type: Some(ExprType::Sort(expr)),
-                }
+        }
+        Expr::Sort(Sort {
+            expr,
+            asc,
+            nulls_first,
+        }) => {
+            let expr = Box::new(protobuf::SortExprNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                asc: *asc,
+                nulls_first: *nulls_first,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Sort(expr)),
             }
-            Expr::Negative(expr) => {
-                let expr = Box::new(protobuf::NegativeNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                });
-                Self {
-                    expr_type: Some(ExprType::Negative(expr)),
-                }
+        }
+        Expr::Negative(expr) => {
+            let expr = Box::new(protobuf::NegativeNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Negative(expr)),
             }
-            Expr::InList(InList {
-                expr,
-                list,
-                negated,
-            }) => {
-                let expr = Box::new(protobuf::InListNode {
-                    expr: Some(Box::new(expr.as_ref().try_into()?)),
-                    list: list
+        }
+        Expr::InList(InList {
+            expr,
+            list,
+            negated,
+        }) => {
+            let expr = Box::new(protobuf::InListNode {
+                expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                list: list
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+                negated: *negated,
+            });
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::InList(expr)),
+            }
+        }
+        Expr::Wildcard => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Wildcard(true)),
+        },
+        Expr::ScalarSubquery(_)
+        | Expr::InSubquery(_)
+        | Expr::Exists { .. }
+        | Expr::OuterReferenceColumn { .. } => {
+            // we would need to add logical plan operators to datafusion.proto to support this
+            // see discussion in https://github.com/apache/arrow-datafusion/issues/2565
+            return Err(Error::General("Proto serialization error: Expr::ScalarSubquery(_) | Expr::InSubquery(_) | Expr::Exists { .. } | Exp:OuterReferenceColumn not supported".to_string()));
+        }
+        Expr::Lambda(Lambda {
+            params,
+            body,
+            captures,
+        }) => {
+            let params = params
+                .iter()
+                .map(|(name, data_type)| {
+                    (&Field::new(name, data_type.clone(), true)).try_into()
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Lambda(Box::new(protobuf::LambdaNode {
+                    params,
+                    body: Some(Box::new(serialize_expr(body, codec)?)),
+                    captures: captures
                         .iter()
-                        .map(|expr| expr.try_into())
+                        .map(|expr| serialize_expr(expr, codec))
                         .collect::<Result<Vec<_>, Error>>()?,
-                    negated: *negated,
-                });
-                Self {
-                    expr_type: Some(ExprType::InList(expr)),
-                }
+                }))),
             }
-            Expr::Wildcard => Self {
-                expr_type: Some(ExprType::Wildcard(true)),
-            },
-            Expr::ScalarSubquery(_)
-            | Expr::InSubquery(_)
-            | Expr::Exists { .. }
-            | Expr::OuterReferenceColumn { .. } => {
-                // we would need to add logical plan operators to datafusion.proto to support this
-                // see discussion in https://github.com/apache/arrow-datafusion/issues/2565
-                return Err(Error::General("Proto serialization error: Expr::ScalarSubquery(_) | Expr::InSubquery(_) | Expr::Exists { .. } | Exp:OuterReferenceColumn not supported".to_string()));
-            }
-            Expr::GetIndexedField(GetIndexedField { expr, field }) => {
-                let field = match field {
-                    GetFieldAccess::NamedStructField { name } => {
-                        protobuf::get_indexed_field::Field::NamedStructField(
-                            protobuf::NamedStructField {
-                                name: Some(name.try_into()?),
-                            },
-                        )
-                    }
-                    GetFieldAccess::ListIndex { key } => {
-                        protobuf::get_indexed_field::Field::ListIndex(Box::new(
-                            protobuf::ListIndex {
-                                key: Some(Box::new(key.as_ref().try_into()?)),
-                            },
-                        ))
-                    }
-                    GetFieldAccess::ListRange { start, stop } => {
-                        protobuf::get_indexed_field::Field::ListRange(Box::new(
-                            protobuf::ListRange {
-                                start: Some(Box::new(start.as_ref().try_into()?)),
-                                stop: Some(Box::new(stop.as_ref().try_into()?)),
-                            },
-                        ))
-                    }
-                };
-
-                Self {
-                    expr_type: Some(ExprType::GetIndexedField(Box::new(
-                        protobuf::GetIndexedField {
-                            expr: Some(Box::new(expr.as_ref().try_into()?)),
-                            field: Some(field),
+        }
+        Expr::GetIndexedField(GetIndexedField { expr, field }) => {
+            let field = match field {
+                GetFieldAccess::NamedStructField { name } => {
+                    protobuf::get_indexed_field::Field::NamedStructField(
+                        protobuf::NamedStructField {
+                            name: Some(name.try_into()?),
                         },
-                    ))),
+                    )
+                }
+                GetFieldAccess::ListIndex { key } => {
+                    protobuf::get_indexed_field::Field::ListIndex(Box::new(
+                        protobuf::ListIndex {
+                            key: Some(Box::new(serialize_expr(key.as_ref(), codec)?)),
+                        },
+                    ))
                 }
+                GetFieldAccess::ListRange { start, stop } => {
+                    protobuf::get_indexed_field::Field::ListRange(Box::new(
+                        protobuf::ListRange {
+                            start: Some(Box::new(serialize_expr(start.as_ref(), codec)?)),
+                            stop: Some(Box::new(serialize_expr(stop.as_ref(), codec)?)),
+                        },
+                    ))
+                }
+            };
+
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::GetIndexedField(Box::new(
+                    protobuf::GetIndexedField {
+                        expr: Some(Box::new(serialize_expr(expr.as_ref(), codec)?)),
+                        field: Some(field),
+                    },
+                ))),
             }
+        }
 
-            Expr::GroupingSet(GroupingSet::Cube(exprs)) => Self {
-                expr_type: Some(ExprType::Cube(CubeNode {
-                    expr: exprs.iter().map(|expr| expr.try_into()).collect::<Result<
-                        Vec<_>,
-                        Self::Error,
-                    >>(
-                    )?,
-                })),
-            },
-            Expr::GroupingSet(GroupingSet::Rollup(exprs)) => Self {
-                expr_type: Some(ExprType::Rollup(RollupNode {
-                    expr: exprs.iter().map(|expr| expr.try_into()).collect::<Result<
-                        Vec<_>,
-                        Self::Error,
-                    >>(
-                    )?,
-                })),
-            },
-            Expr::GroupingSet(GroupingSet::GroupingSets(exprs)) => Self {
+        Expr::GroupingSet(GroupingSet::Cube(exprs)) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Cube(CubeNode {
+                expr: exprs
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            })),
+        },
+        Expr::GroupingSet(GroupingSet::Rollup(exprs)) => protobuf::LogicalExprNode {
+            expr_type: Some(ExprType::Rollup(RollupNode {
+                expr: exprs
+                    .iter()
+                    .map(|expr| serialize_expr(expr, codec))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            })),
+        },
+        Expr::GroupingSet(GroupingSet::GroupingSets(exprs)) => {
+            protobuf::LogicalExprNode {
                 expr_type: Some(ExprType::GroupingSet(GroupingSetNode {
                     expr: exprs
                         .iter()
@@ -1033,34 +1069,36 @@ impl TryFrom<&Expr> for protobuf::LogicalExprNode {
                             Ok(LogicalExprList {
                                 expr: expr_list
                                     .iter()
-                                    .map(|expr| expr.try_into())
-                                    .collect::<Result<Vec<_>, Self::Error>>()?,
+                                    .map(|expr| serialize_expr(expr, codec))
+                                    .collect::<Result<Vec<_>, Error>>()?,
                             })
                         })
-                        .collect::<Result<Vec<_>, Self::Error>>()?,
+                        .collect::<Result<Vec<_>, Error>>()?,
+                })),
+            }
+        }
+        Expr::Placeholder(Placeholder { id, data_type }) => {
+            let data_type = match data_type {
+                Some(data_type) => Some(data_type.try_into()?),
+                None => None,
+            };
+            protobuf::LogicalExprNode {
+                expr_type: Some(ExprType::Placeholder(PlaceholderNode {
+                    id: id.clone(),
+                    data_type,
                 })),
-            },
-            Expr::Placeholder(Placeholder { id, data_type }) => {
-                let data_type = match data_type {
-                    Some(data_type) => Some(data_type.try_into()?),
-                    None => None,
-                };
-                Self {
-                    expr_type: Some(ExprType::Placeholder(PlaceholderNode {
-                        id: id.clone(),
-                        data_type,
-                    })),
-                }
             }
+        }
 
-            Expr::QualifiedWildcard { .. } => return Err(Error::General(
+        Expr::QualifiedWildcard { .. } => {
+            return Err(Error::General(
                 "Proto serialization error: Expr::QualifiedWildcard { .. } not supported"
                     .to_string(),
-            )),
-        };
+            ))
+        }
+    };
 
-        Ok(expr_node)
-    }
+    Ok(expr_node)
 }
 
 impl TryFrom<&ScalarValue> for protobuf::ScalarValue {
@@ -1126,9 +1164,9 @@ impl TryFrom<&ScalarValue> for protobuf::ScalarValue {
                 "Proto serialization error: ScalarValue::Fixedsizelist not supported"
                     .to_string(),
             )),
-            // ScalarValue::List is serialized using Arrow IPC messages.
-            // as a single column RecordBatch
-            ScalarValue::List(arr) => {
+            // ScalarValue::List and ScalarValue::Map are serialized using
+            // Arrow IPC messages as a single column RecordBatch
+            ScalarValue::List(arr) | ScalarValue::Map(arr) => {
                 // Wrap in a "field_name" column
                 let batch = RecordBatch::try_from_iter(vec![(
                     "field_name",
@@ -1156,11 +1194,12 @@ impl TryFrom<&ScalarValue> for protobuf::ScalarValue {
                     schema: Some(schema),
                 };
 
-                Ok(protobuf::ScalarValue {
-                    value: Some(protobuf::scalar_value::Value::ListValue(
-                        scalar_list_value,
-                    )),
-                })
+                let value = match val {
+                    ScalarValue::Map(_) => Value::MapValue(scalar_list_value),
+                    _ => Value::ListValue(scalar_list_value),
+                };
+
+                Ok(protobuf::ScalarValue { value: Some(value) })
             }
             ScalarValue::Date32(val) => {
                 create_proto_scalar(val.as_ref(), &data_type, |s| Value::Date32Value(*s))
@@ -1535,11 +1574,55 @@ impl TryFrom<&BuiltinScalarFunction> for protobuf::ScalarFunction {
             BuiltinScalarFunction::Power => Self::Power,
             BuiltinScalarFunction::Struct => Self::StructFun,
             BuiltinScalarFunction::FromUnixtime => Self::FromUnixtime,
+            BuiltinScalarFunction::ToLocalTime => Self::ToLocalTime,
+            BuiltinScalarFunction::MakeTimestamp => Self::MakeTimestamp,
+            BuiltinScalarFunction::TryToTimestamp => Self::TryToTimestamp,
+            BuiltinScalarFunction::ToDate => Self::ToDate,
+            BuiltinScalarFunction::TryToDate => Self::TryToDate,
+            BuiltinScalarFunction::ToChar => Self::ToChar,
+            BuiltinScalarFunction::Collate => Self::Collate,
+            BuiltinScalarFunction::CollationKey => Self::CollationKey,
+            BuiltinScalarFunction::RegexpLike => Self::RegexpLike,
+            BuiltinScalarFunction::RegexpCount => Self::RegexpCount,
+            BuiltinScalarFunction::RegexpInstr => Self::RegexpInstr,
+            BuiltinScalarFunction::RegexpSubstr => Self::RegexpSubstr,
+            BuiltinScalarFunction::RegexpExtract => Self::RegexpExtract,
+            BuiltinScalarFunction::RegexpSplitToArray => Self::RegexpSplitToArray,
+            BuiltinScalarFunction::RegexpMatches => Self::RegexpMatches,
             BuiltinScalarFunction::Atan2 => Self::Atan2,
             BuiltinScalarFunction::Nanvl => Self::Nanvl,
             BuiltinScalarFunction::Isnan => Self::Isnan,
             BuiltinScalarFunction::Iszero => Self::Iszero,
             BuiltinScalarFunction::ArrowTypeof => Self::ArrowTypeof,
+            BuiltinScalarFunction::JsonGet => Self::JsonGet,
+            BuiltinScalarFunction::JsonGetStr => Self::JsonGetStr,
+            BuiltinScalarFunction::JsonGetInt => Self::JsonGetInt,
+            BuiltinScalarFunction::JsonGetFloat => Self::JsonGetFloat,
+            BuiltinScalarFunction::JsonGetBool => Self::JsonGetBool,
+            BuiltinScalarFunction::JsonContains => Self::JsonContains,
+            BuiltinScalarFunction::JsonLength => Self::JsonLength,
+            BuiltinScalarFunction::JsonKeys => Self::JsonKeys,
+            BuiltinScalarFunction::JsonArrayElements => Self::JsonArrayElements,
+            BuiltinScalarFunction::MakeMap => Self::MakeMap,
+            BuiltinScalarFunction::MapKeys => Self::MapKeys,
+            BuiltinScalarFunction::MapValues => Self::MapValues,
+            BuiltinScalarFunction::MapEntries => Self::MapEntries,
+            BuiltinScalarFunction::ElementAt => Self::ElementAt,
+            BuiltinScalarFunction::ArraySort => Self::ArraySort,
+            BuiltinScalarFunction::ArrayTransform => Self::ArrayTransform,
+            BuiltinScalarFunction::ArrayFilter => Self::ArrayFilter,
+            BuiltinScalarFunction::ArrayReduce => Self::ArrayReduce,
+            BuiltinScalarFunction::ArrayAnyMatch => Self::ArrayAnyMatch,
+            BuiltinScalarFunction::ArrayAllMatch => Self::ArrayAllMatch,
+            BuiltinScalarFunction::ArrayDistinct => Self::ArrayDistinct,
+            BuiltinScalarFunction::ArrayUnion => Self::ArrayUnion,
+            BuiltinScalarFunction::ArrayExcept => Self::ArrayExcept,
+            BuiltinScalarFunction::ArrayMax => Self::ArrayMax,
+            BuiltinScalarFunction::ArrayMin => Self::ArrayMin,
+            BuiltinScalarFunction::ArrayZip => Self::ArrayZip,
+            BuiltinScalarFunction::ArrayResize => Self::ArrayResize,
+            BuiltinScalarFunction::Range => Self::Range,
+            BuiltinScalarFunction::GenerateSeries => Self::GenerateSeries,
         };
 
         Ok(scalar_function)
@@ -18,12 +18,15 @@
 //! Serde code to convert from protocol buffers to Rust data structures.
 
 use std::convert::{TryFrom, TryInto};
-use std::ops::Deref;
 use std::sync::Arc;
 
 use arrow::compute::SortOptions;
+use datafusion::arrow::csv::WriterBuilder;
 use datafusion::arrow::datatypes::Schema;
+use datafusion::datasource::file_format::csv::CsvSink;
 use datafusion::datasource::file_format::json::JsonSink;
+#[cfg(feature = "parquet")]
+use datafusion::datasource::file_format::parquet::ParquetSink;
 use datafusion::datasource::file_format::write::FileWriterMode;
 use datafusion::datasource::listing::{FileRange, ListingTableUrl, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
@@ -31,26 +34,43 @@ use datafusion::datasource::physical_plan::{FileScanConfig, FileSinkConfig};
 use datafusion::execution::context::ExecutionProps;
 use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::window_function::WindowFunction;
-use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
+#[cfg(feature = "parquet")]
+use datafusion::parquet::{
+    file::properties::WriterProperties, schema::types::ColumnPath,
+};
+use datafusion::physical_expr::{
+    HigherOrderFunctionExpr, LambdaExpr, PhysicalSortExpr, ScalarFunctionExpr,
+};
+use datafusion::physical_plan::aggregates::create_aggregate_expr;
 use datafusion::physical_plan::expressions::{
-    in_list, BinaryExpr, CaseExpr, CastExpr, Column, IsNotNullExpr, IsNullExpr, LikeExpr,
-    Literal, NegativeExpr, NotExpr, TryCastExpr,
+    in_list, BinaryExpr, CaseExpr, CastExpr, CollateExpr, Column, IsNotNullExpr,
+    IsNullExpr, LikeExpr, Literal, NegativeExpr, NoOp, NotExpr, TryCastExpr,
+    UnKnownColumn,
 };
 use datafusion::physical_plan::expressions::{GetFieldAccessExpr, GetIndexedFieldExpr};
-use datafusion::physical_plan::windows::create_window_expr;
+use datafusion::physical_plan::windows::{
+    create_window_expr, window_expr_from_aggregate_expr,
+};
 use datafusion::physical_plan::{
     functions, ColumnStatistics, Partitioning, PhysicalExpr, Statistics, WindowExpr,
 };
+use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
+#[cfg(feature = "parquet")]
+use datafusion_common::file_options::parquet_writer::{
+    parse_compression_string, parse_encoding_string, parse_statistics_string,
+    parse_version_string, ParquetWriterOptions,
+};
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
 use datafusion_common::{
     not_impl_err, DataFusionError, FileTypeWriterOptions, JoinSide, Result, ScalarValue,
 };
 
-use crate::common::proto_error;
+use crate::common::{proto_error, registered_udaf, registered_udf, registered_udwf};
 use crate::convert_required;
 use crate::logical_plan;
+use crate::physical_plan::{DefaultPhysicalExtensionCodec, PhysicalExtensionCodec};
 use crate::protobuf;
 use crate::protobuf::physical_expr_node::ExprType;
 
@@ -64,21 +84,42 @@ impl From<&protobuf::PhysicalColumn> for Column {
     }
 }
 
+/// Parses a physical sort expression from a protobuf, looking up
+/// user-defined functions by name in `registry`
+///
+/// Use [`parse_physical_sort_expr_with_codec`] to decode the functions whose
+/// definition was encoded by an extension codec.
+pub fn parse_physical_sort_expr(
+    proto: &protobuf::PhysicalSortExprNode,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+) -> Result<PhysicalSortExpr> {
+    parse_physical_sort_expr_with_codec(
+        proto,
+        registry,
+        &DefaultPhysicalExtensionCodec {},
+        input_schema,
+    )
+}
+
 /// Parses a physical sort expression from a protobuf.
 ///
 /// # Arguments
 ///
 /// * `proto` - Input proto with physical sort expression node
 /// * `registry` - A registry knows how to build logical expressions out of user-defined function' names
+/// * `codec` - An extension codec used to decode the definitions of user-defined functions
 /// * `input_schema` - The Arrow schema for the input, used for determining expression data types
 ///                    when performing type coercion.
-pub fn parse_physical_sort_expr(
+pub fn parse_physical_sort_expr_with_codec(
     proto: &protobuf::PhysicalSortExprNode,
     registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
     input_schema: &Schema,
 ) -> Result<PhysicalSortExpr> {
     if let Some(expr) = &proto.expr {
-        let expr = parse_physical_expr(expr.as_ref(), registry, input_schema)?;
+        let expr =
+            parse_physical_expr_with_codec(expr.as_ref(), registry, codec, input_schema)?;
         let options = SortOptions {
             descending: !proto.asc,
             nulls_first: proto.nulls_first,
@@ -89,6 +130,24 @@ pub fn parse_physical_sort_expr(
     }
 }
 
+/// Parses a physical window expr from a protobuf, looking up user-defined
+/// functions by name in `registry`
+///
+/// Use [`parse_physical_window_expr_with_codec`] to decode the functions
+/// whose definition was encoded by an extension codec.
+pub fn parse_physical_window_expr(
+    proto: &protobuf::PhysicalWindowExprNode,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+) -> Result<Arc<dyn WindowExpr>> {
+    parse_physical_window_expr_with_codec(
+        proto,
+        registry,
+        &DefaultPhysicalExtensionCodec {},
+        input_schema,
+    )
+}
+
 /// Parses a physical window expr from a protobuf.
 ///
 /// # Arguments
@@ -96,29 +155,31 @@ pub fn parse_physical_sort_expr(
 /// * `proto` - Input proto with physical window exprression node.
 /// * `name` - Name of the window expression.
 /// * `registry` - A registry knows how to build logical expressions out of user-defined function' names
+/// * `codec` - An extension codec used to decode the definitions of user-defined functions
 /// * `input_schema` - The Arrow schema for the input, used for determining expression data types
 ///                    when performing type coercion.
-pub fn parse_physical_window_expr(
+pub fn parse_physical_window_expr_with_codec(
     proto: &protobuf::PhysicalWindowExprNode,
     registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
     input_schema: &Schema,
 ) -> Result<Arc<dyn WindowExpr>> {
     let window_node_expr = proto
         .args
         .iter()
-        .map(|e| parse_physical_expr(e, registry, input_schema))
+        .map(|e| parse_physical_expr_with_codec(e, registry, codec, input_schema))
         .collect::<Result<Vec<_>>>()?;
 
     let partition_by = proto
         .partition_by
         .iter()
-        .map(|p| parse_physical_expr(p, registry, input_schema))
+        .map(|p| parse_physical_expr_with_codec(p, registry, codec, input_schema))
         .collect::<Result<Vec<_>>>()?;
 
     let order_by = proto
         .order_by
         .iter()
-        .map(|o| parse_physical_sort_expr(o, registry, input_schema))
+        .map(|o| parse_physical_sort_expr_with_codec(o, registry, codec, input_schema))
         .collect::<Result<Vec<_>>>()?;
 
     let window_frame = proto
@@ -133,8 +194,37 @@ pub fn parse_physical_window_expr(
             )
         })?;
 
+    let fun = proto
+        .window_function
+        .as_ref()
+        .ok_or_else(|| proto_error("Missing required field in protobuf"))?;
+
+    let fun =
+        parse_window_function(fun, proto.fun_definition.as_deref(), registry, codec)?;
+    if proto.distinct {
+        let WindowFunction::AggregateFunction(fun) = &fun else {
+            return Err(proto_error(format!(
+                "Window function {fun} can not be distinct"
+            )));
+        };
+        let aggregate = create_aggregate_expr(
+            fun,
+            true,
+            &window_node_expr,
+            &[],
+            input_schema,
+            proto.name.clone(),
+        )?;
+        return Ok(window_expr_from_aggregate_expr(
+            &partition_by,
+            &order_by,
+            Arc::new(window_frame),
+            aggregate,
+        ));
+    }
+
     create_window_expr(
-        &convert_required!(proto.window_function)?,
+        &fun,
         proto.name.clone(),
         &window_node_expr,
         &partition_by,
@@ -144,17 +234,37 @@ pub fn parse_physical_window_expr(
     )
 }
 
+/// Parses a physical expression from a protobuf, looking up user-defined
+/// functions by name in `registry`
+///
+/// Use [`parse_physical_expr_with_codec`] to decode the functions whose
+/// definition was encoded by an extension codec.
+pub fn parse_physical_expr(
+    proto: &protobuf::PhysicalExprNode,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    parse_physical_expr_with_codec(
+        proto,
+        registry,
+        &DefaultPhysicalExtensionCodec {},
+        input_schema,
+    )
+}
+
 /// Parses a physical expression from a protobuf.
 ///
 /// # Arguments
 ///
 /// * `proto` - Input proto with physical expression node
 /// * `registry` - A registry knows how to build logical expressions out of user-defined function' names
+/// * `codec` - An extension codec used to decode the definitions of user-defined functions
 /// * `input_schema` - The Arrow schema for the input, used for determining expression data types
 ///                    when performing type coercion.
-pub fn parse_physical_expr(
+pub fn parse_physical_expr_with_codec(
     proto: &protobuf::PhysicalExprNode,
     registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
     input_schema: &Schema,
 ) -> Result<Arc<dyn PhysicalExpr>> {
     let expr_type = proto
@@ -167,11 +277,14 @@ pub fn parse_physical_expr(
             let pcol: Column = c.into();
             Arc::new(pcol)
         }
+        ExprType::UnknownColumn(c) => Arc::new(UnKnownColumn::new(&c.name)),
+        ExprType::NoOp(_) => Arc::new(NoOp::new()),
         ExprType::Literal(scalar) => Arc::new(Literal::new(scalar.try_into()?)),
         ExprType::BinaryExpr(binary_expr) => Arc::new(BinaryExpr::new(
             parse_required_physical_expr(
                 binary_expr.l.as_deref(),
                 registry,
+                codec,
                 "left",
                 input_schema,
             )?,
@@ -179,6 +292,7 @@ pub fn parse_physical_expr(
             parse_required_physical_expr(
                 binary_expr.r.as_deref(),
                 registry,
+                codec,
                 "right",
                 input_schema,
             )?,
@@ -200,6 +314,7 @@ pub fn parse_physical_expr(
             Arc::new(IsNullExpr::new(parse_required_physical_expr(
                 e.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?))
@@ -208,6 +323,7 @@ pub fn parse_physical_expr(
             Arc::new(IsNotNullExpr::new(parse_required_physical_expr(
                 e.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?))
@@ -215,6 +331,7 @@ pub fn parse_physical_expr(
         ExprType::NotExpr(e) => Arc::new(NotExpr::new(parse_required_physical_expr(
             e.expr.as_deref(),
             registry,
+            codec,
             "expr",
             input_schema,
         )?)),
@@ -222,6 +339,7 @@ pub fn parse_physical_expr(
             Arc::new(NegativeExpr::new(parse_required_physical_expr(
                 e.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?))
@@ -230,12 +348,13 @@ pub fn parse_physical_expr(
             parse_required_physical_expr(
                 e.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?,
             e.list
                 .iter()
-                .map(|x| parse_physical_expr(x, registry, input_schema))
+                .map(|x| parse_physical_expr_with_codec(x, registry, codec, input_schema))
                 .collect::<Result<Vec<_>, _>>()?,
             &e.negated,
             input_schema,
@@ -243,7 +362,14 @@ pub fn parse_physical_expr(
         ExprType::Case(e) => Arc::new(CaseExpr::try_new(
             e.expr
                 .as_ref()
-                .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema))
+                .map(|e| {
+                    parse_physical_expr_with_codec(
+                        e.as_ref(),
+                        registry,
+                        codec,
+                        input_schema,
+                    )
+                })
                 .transpose()?,
             e.when_then_expr
                 .iter()
@@ -252,12 +378,14 @@ pub fn parse_physical_expr(
                         parse_required_physical_expr(
                             e.when_expr.as_ref(),
                             registry,
+                            codec,
                             "when_expr",
                             input_schema,
                         )?,
                         parse_required_physical_expr(
                             e.then_expr.as_ref(),
                             registry,
+                            codec,
                             "then_expr",
                             input_schema,
                         )?,
@@ -266,13 +394,21 @@ pub fn parse_physical_expr(
                 .collect::<Result<Vec<_>>>()?,
             e.else_expr
                 .as_ref()
-                .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema))
+                .map(|e| {
+                    parse_physical_expr_with_codec(
+                        e.as_ref(),
+                        registry,
+                        codec,
+                        input_schema,
+                    )
+                })
                 .transpose()?,
         )?),
         ExprType::Cast(e) => Arc::new(CastExpr::new(
             parse_required_physical_expr(
                 e.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?,
@@ -283,6 +419,7 @@ pub fn parse_physical_expr(
             parse_required_physical_expr(
                 e.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?,
@@ -299,7 +436,7 @@ pub fn parse_physical_expr(
             let args = e
                 .args
                 .iter()
-                .map(|x| parse_physical_expr(x, registry, input_schema))
+                .map(|x| parse_physical_expr_with_codec(x, registry, codec, input_schema))
                 .collect::<Result<Vec<_>, _>>()?;
 
             // TODO Do not create new the ExecutionProps
@@ -319,34 +456,91 @@ pub fn parse_physical_expr(
             ))
         }
         ExprType::ScalarUdf(e) => {
-            let scalar_fun = registry.udf(e.name.as_str())?.deref().clone().fun;
+            let udf = match &e.fun_definition {
+                Some(buf) => codec.try_decode_udf(&e.name, buf)?,
+                None => registered_udf(registry, &e.name)?,
+            };
 
             let args = e
                 .args
                 .iter()
-                .map(|x| parse_physical_expr(x, registry, input_schema))
+                .map(|x| parse_physical_expr_with_codec(x, registry, codec, input_schema))
                 .collect::<Result<Vec<_>, _>>()?;
 
-            Arc::new(ScalarFunctionExpr::new(
-                e.name.as_str(),
-                scalar_fun,
+            Arc::new(
+                ScalarFunctionExpr::new(
+                    e.name.as_str(),
+                    udf.fun.clone(),
+                    args,
+                    &convert_required!(e.return_type)?,
+                    None,
+                )
+                .with_udf(udf),
+            )
+        }
+        ExprType::HigherOrderFunction(e) => {
+            let scalar_function =
+                protobuf::ScalarFunction::try_from(e.fun).map_err(|_| {
+                    proto_error(
+                        format!("Received an unknown scalar function: {}", e.fun,),
+                    )
+                })?;
+
+            let args = e
+                .args
+                .iter()
+                .map(|x| parse_physical_expr_with_codec(x, registry, codec, input_schema))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let lambda = e
+                .lambda
+                .as_deref()
+                .ok_or_else(|| proto_error("Missing required field lambda"))?;
+            let params: Arc<Schema> = Arc::new(convert_required!(lambda.params)?);
+            let body = parse_required_physical_expr(
+                lambda.body.as_deref(),
+                registry,
+                codec,
+                "body",
+                &params,
+            )?;
+            let captures = lambda
+                .captures
+                .iter()
+                .map(|x| parse_physical_expr_with_codec(x, registry, codec, input_schema))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Arc::new(HigherOrderFunctionExpr::try_new(
+                (&scalar_function).into(),
                 args,
-                &convert_required!(e.return_type)?,
-                None,
-            ))
+                LambdaExpr::new(params, body).with_captures(captures),
+                convert_required!(e.return_type)?,
+            )?)
         }
+        ExprType::CollateExpr(e) => Arc::new(CollateExpr::new(
+            parse_required_physical_expr(
+                e.expr.as_deref(),
+                registry,
+                codec,
+                "expr",
+                input_schema,
+            )?,
+            e.collation.parse()?,
+        )),
         ExprType::LikeExpr(like_expr) => Arc::new(LikeExpr::new(
             like_expr.negated,
             like_expr.case_insensitive,
             parse_required_physical_expr(
                 like_expr.expr.as_deref(),
                 registry,
+                codec,
                 "expr",
                 input_schema,
             )?,
             parse_required_physical_expr(
                 like_expr.pattern.as_deref(),
                 registry,
+                codec,
                 "pattern",
                 input_schema,
             )?,
@@ -360,6 +554,7 @@ pub fn parse_physical_expr(
                     key: parse_required_physical_expr(
                         list_index_expr.key.as_deref(),
                         registry,
+                        codec,
                         "key",
                         input_schema,
                     )?},
@@ -367,12 +562,14 @@ pub fn parse_physical_expr(
                     start: parse_required_physical_expr(
                         list_range_expr.start.as_deref(),
                         registry,
+                        codec,
                         "start",
                         input_schema,
                     )?,
                     stop: parse_required_physical_expr(
                         list_range_expr.stop.as_deref(),
                         registry,
+                        codec,
                         "stop",
                         input_schema
                     )?,
@@ -386,6 +583,7 @@ pub fn parse_physical_expr(
                 parse_required_physical_expr(
                     get_indexed_field_expr.arg.as_deref(),
                     registry,
+                    codec,
                     "arg",
                     input_schema,
                 )?,
@@ -400,42 +598,50 @@ pub fn parse_physical_expr(
 fn parse_required_physical_expr(
     expr: Option<&protobuf::PhysicalExprNode>,
     registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
     field: &str,
     input_schema: &Schema,
 ) -> Result<Arc<dyn PhysicalExpr>> {
-    expr.map(|e| parse_physical_expr(e, registry, input_schema))
+    expr.map(|e| parse_physical_expr_with_codec(e, registry, codec, input_schema))
         .transpose()?
         .ok_or_else(|| {
             DataFusionError::Internal(format!("Missing required field {field:?}"))
         })
 }
 
-impl TryFrom<&protobuf::physical_window_expr_node::WindowFunction> for WindowFunction {
-    type Error = DataFusionError;
-
-    fn try_from(
-        expr: &protobuf::physical_window_expr_node::WindowFunction,
-    ) -> Result<Self, Self::Error> {
-        match expr {
-            protobuf::physical_window_expr_node::WindowFunction::AggrFunction(n) => {
-                let f = protobuf::AggregateFunction::try_from(*n).map_err(|_| {
-                    proto_error(format!(
-                        "Received an unknown window aggregate function: {n}"
-                    ))
-                })?;
+fn parse_window_function(
+    proto: &protobuf::physical_window_expr_node::WindowFunction,
+    fun_definition: Option<&[u8]>,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<WindowFunction> {
+    match proto {
+        protobuf::physical_window_expr_node::WindowFunction::AggrFunction(n) => {
+            let f = protobuf::AggregateFunction::try_from(*n).map_err(|_| {
+                proto_error(format!("Received an unknown window aggregate function: {n}"))
+            })?;
 
-                Ok(WindowFunction::AggregateFunction(f.into()))
-            }
-            protobuf::physical_window_expr_node::WindowFunction::BuiltInFunction(n) => {
-                let f = protobuf::BuiltInWindowFunction::try_from(*n).map_err(|_| {
-                    proto_error(format!(
-                        "Received an unknown window builtin function: {n}"
-                    ))
-                })?;
+            Ok(WindowFunction::AggregateFunction(f.into()))
+        }
+        protobuf::physical_window_expr_node::WindowFunction::BuiltInFunction(n) => {
+            let f = protobuf::BuiltInWindowFunction::try_from(*n).map_err(|_| {
+                proto_error(format!("Received an unknown window builtin function: {n}"))
+            })?;
 
-                Ok(WindowFunction::BuiltInWindowFunction(f.into()))
-            }
+            Ok(WindowFunction::BuiltInWindowFunction(f.into()))
         }
+        protobuf::physical_window_expr_node::WindowFunction::UserDefinedAggrFunction(
+            name,
+        ) => Ok(WindowFunction::AggregateUDF(match fun_definition {
+            Some(buf) => codec.try_decode_udaf(name, buf)?,
+            None => registered_udaf(registry, name)?,
+        })),
+        protobuf::physical_window_expr_node::WindowFunction::UserDefinedWindowFunction(
+            name,
+        ) => Ok(WindowFunction::WindowUDF(match fun_definition {
+            Some(buf) => codec.try_decode_udwf(name, buf)?,
+            None => registered_udwf(registry, name)?,
+        })),
     }
 }
 
@@ -443,13 +649,27 @@ pub fn parse_protobuf_hash_partitioning(
     partitioning: Option<&protobuf::PhysicalHashRepartition>,
     registry: &dyn FunctionRegistry,
     input_schema: &Schema,
+) -> Result<Option<Partitioning>> {
+    parse_protobuf_hash_partitioning_with_codec(
+        partitioning,
+        registry,
+        &DefaultPhysicalExtensionCodec {},
+        input_schema,
+    )
+}
+
+pub fn parse_protobuf_hash_partitioning_with_codec(
+    partitioning: Option<&protobuf::PhysicalHashRepartition>,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
+    input_schema: &Schema,
 ) -> Result<Option<Partitioning>> {
     match partitioning {
         Some(hash_part) => {
             let expr = hash_part
                 .hash_expr
                 .iter()
-                .map(|e| parse_physical_expr(e, registry, input_schema))
+                .map(|e| parse_physical_expr_with_codec(e, registry, codec, input_schema))
                 .collect::<Result<Vec<Arc<dyn PhysicalExpr>>, _>>()?;
 
             Ok(Some(Partitioning::Hash(
@@ -464,6 +684,18 @@ pub fn parse_protobuf_hash_partitioning(
 pub fn parse_protobuf_file_scan_config(
     proto: &protobuf::FileScanExecConf,
     registry: &dyn FunctionRegistry,
+) -> Result<FileScanConfig> {
+    parse_protobuf_file_scan_config_with_codec(
+        proto,
+        registry,
+        &DefaultPhysicalExtensionCodec {},
+    )
+}
+
+pub fn parse_protobuf_file_scan_config_with_codec(
+    proto: &protobuf::FileScanExecConf,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<FileScanConfig> {
     let schema: Arc<Schema> = Arc::new(convert_required!(proto.schema)?);
     let projection = proto
@@ -505,7 +737,14 @@ pub fn parse_protobuf_file_scan_config(
                 let expr = node
                     .expr
                     .as_ref()
-                    .map(|e| parse_physical_expr(e.as_ref(), registry, &schema))
+                    .map(|e| {
+                        parse_physical_expr_with_codec(
+                            e.as_ref(),
+                            registry,
+                            codec,
+                            &schema,
+                        )
+                    })
                     .unwrap()?;
                 Ok(PhysicalSortExpr {
                     expr,
@@ -712,6 +951,23 @@ impl TryFrom<&protobuf::JsonSink> for JsonSink {
     }
 }
 
+impl TryFrom<&protobuf::CsvSink> for CsvSink {
+    type Error = DataFusionError;
+
+    fn try_from(value: &protobuf::CsvSink) -> Result<Self, Self::Error> {
+        Ok(Self::new(convert_required!(value.config)?))
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl TryFrom<&protobuf::ParquetSink> for ParquetSink {
+    type Error = DataFusionError;
+
+    fn try_from(value: &protobuf::ParquetSink) -> Result<Self, Self::Error> {
+        Ok(Self::new(convert_required!(value.config)?))
+    }
+}
+
 impl TryFrom<&protobuf::FileSinkConfig> for FileSinkConfig {
     type Error = DataFusionError;
 
@@ -783,6 +1039,140 @@ impl TryFrom<&protobuf::FileTypeWriterOptions> for FileTypeWriterOptions {
             protobuf::file_type_writer_options::FileType::JsonOptions(opts) => Ok(
                 Self::JSON(JsonWriterOptions::new(opts.compression().into())),
             ),
+            protobuf::file_type_writer_options::FileType::CsvOptions(opts) => {
+                let mut builder = WriterBuilder::new()
+                    .with_header(opts.has_header)
+                    .with_double_quote(opts.double_quote)
+                    .with_null(opts.null_value.clone());
+                if let Some(delimiter) = parse_csv_byte("delimiter", &opts.delimiter)? {
+                    builder = builder.with_delimiter(delimiter);
+                }
+                if let Some(quote) = parse_csv_byte("quote", &opts.quote)? {
+                    builder = builder.with_quote(quote);
+                }
+                if let Some(escape) = parse_csv_byte("escape", &opts.escape)? {
+                    builder = builder.with_escape(escape);
+                }
+                if !opts.date_format.is_empty() {
+                    builder = builder.with_date_format(opts.date_format.clone());
+                }
+                if !opts.datetime_format.is_empty() {
+                    builder = builder.with_datetime_format(opts.datetime_format.clone());
+                }
+                if !opts.timestamp_format.is_empty() {
+                    builder =
+                        builder.with_timestamp_format(opts.timestamp_format.clone());
+                }
+                if !opts.timestamp_tz_format.is_empty() {
+                    builder = builder
+                        .with_timestamp_tz_format(opts.timestamp_tz_format.clone());
+                }
+                if !opts.time_format.is_empty() {
+                    builder = builder.with_time_format(opts.time_format.clone());
+                }
+                Ok(Self::CSV(CsvWriterOptions::new(
+                    builder,
+                    opts.compression().into(),
+                )))
+            }
+            #[cfg(feature = "parquet")]
+            protobuf::file_type_writer_options::FileType::ParquetOptions(opts) => {
+                Ok(Self::Parquet(ParquetWriterOptions::new(convert_required!(
+                    opts.writer_properties
+                )?)))
+            }
+            #[cfg(not(feature = "parquet"))]
+            protobuf::file_type_writer_options::FileType::ParquetOptions(_) => {
+                not_impl_err!("Parquet file sink requires the parquet feature")
+            }
         }
     }
 }
+
+#[cfg(feature = "parquet")]
+impl TryFrom<&protobuf::WriterProperties> for WriterProperties {
+    type Error = DataFusionError;
+
+    fn try_from(props: &protobuf::WriterProperties) -> Result<Self, Self::Error> {
+        let mut builder = WriterProperties::builder()
+            .set_data_page_size_limit(props.data_page_size_limit as usize)
+            .set_dictionary_page_size_limit(props.dictionary_page_size_limit as usize)
+            .set_data_page_row_count_limit(props.data_page_row_count_limit as usize)
+            .set_write_batch_size(props.write_batch_size as usize)
+            .set_max_row_group_size(props.max_row_group_size as usize)
+            .set_writer_version(parse_version_string(&props.writer_version)?)
+            .set_created_by(props.created_by.clone())
+            .set_column_index_truncate_length(
+                props
+                    .optional_column_index_truncate_length
+                    .as_ref()
+                    .map(|length| match length {
+                        protobuf::writer_properties::OptionalColumnIndexTruncateLength::ColumnIndexTruncateLength(length) => *length as usize,
+                    }),
+            )
+            .set_compression(parse_compression_string(&props.compression)?)
+            .set_dictionary_enabled(props.dictionary_enabled)
+            .set_statistics_enabled(parse_statistics_string(&props.statistics_enabled)?)
+            .set_max_statistics_size(props.max_statistics_size as usize);
+
+        if let Some(protobuf::writer_properties::OptionalEncoding::Encoding(encoding)) =
+            &props.optional_encoding
+        {
+            builder = builder.set_encoding(parse_encoding_string(encoding)?);
+        }
+
+        if props.bloom_filter_enabled {
+            builder = builder
+                .set_bloom_filter_enabled(true)
+                .set_bloom_filter_fpp(props.bloom_filter_fpp)
+                .set_bloom_filter_ndv(props.bloom_filter_ndv);
+        }
+
+        for column in &props.column_properties {
+            let path = ColumnPath::new(column.path.clone());
+            if let Some(protobuf::column_writer_properties::OptionalEncoding::Encoding(
+                encoding,
+            )) = &column.optional_encoding
+            {
+                builder = builder
+                    .set_column_encoding(path.clone(), parse_encoding_string(encoding)?);
+            }
+            builder = builder
+                .set_column_compression(
+                    path.clone(),
+                    parse_compression_string(&column.compression)?,
+                )
+                .set_column_dictionary_enabled(path.clone(), column.dictionary_enabled)
+                .set_column_statistics_enabled(
+                    path.clone(),
+                    parse_statistics_string(&column.statistics_enabled)?,
+                )
+                .set_column_max_statistics_size(
+                    path.clone(),
+                    column.max_statistics_size as usize,
+                )
+                .set_column_bloom_filter_enabled(
+                    path.clone(),
+                    column.bloom_filter_enabled,
+                );
+            if column.bloom_filter_enabled {
+                builder = builder
+                    .set_column_bloom_filter_fpp(path.clone(), column.bloom_filter_fpp)
+                    .set_column_bloom_filter_ndv(path, column.bloom_filter_ndv);
+            }
+        }
+
+        Ok(builder.build())
+    }
+}
+
+/// Parses a single byte CSV writer option, `None` if it is not set
+fn parse_csv_byte(name: &str, value: &[u8]) -> Result<Option<u8>> {
+    match value {
+        [] => Ok(None),
+        [byte] => Ok(Some(*byte)),
+        _ => Err(proto_error(format!(
+            "CSV writer {name} must be a single byte, got {value:?}"
+        ))),
+    }
+}
@@ -21,11 +21,17 @@ use std::sync::Arc;
 
 use datafusion::arrow::compute::SortOptions;
 use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::reader::StreamReader;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::file_format::csv::CsvSink;
 use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
 use datafusion::datasource::file_format::json::JsonSink;
 #[cfg(feature = "parquet")]
+use datafusion::datasource::file_format::parquet::ParquetSink;
+#[cfg(feature = "parquet")]
 use datafusion::datasource::physical_plan::ParquetExec;
-use datafusion::datasource::physical_plan::{AvroExec, CsvExec};
+use datafusion::datasource::physical_plan::{ArrowExec, AvroExec, CsvExec, NdJsonExec};
 use datafusion::execution::runtime_env::RuntimeEnv;
 use datafusion::execution::FunctionRegistry;
 use datafusion::physical_plan::aggregates::{create_aggregate_expr, AggregateMode};
@@ -41,24 +47,36 @@ use datafusion::physical_plan::insert::FileSinkExec;
 use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use datafusion::physical_plan::joins::{CrossJoinExec, NestedLoopJoinExec};
 use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion::physical_plan::joins::{
+    SortMergeJoinExec, StreamJoinPartitionMode, SymmetricHashJoinExec,
+};
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use datafusion::physical_plan::memory::MemoryExec;
 use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::repartition::RepartitionExec;
+use datafusion::physical_plan::shared::SharedExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
-use datafusion::physical_plan::union::UnionExec;
+use datafusion::physical_plan::streaming::StreamingTableExec;
+use datafusion::physical_plan::union::{InterleaveExec, UnionExec};
+use datafusion::physical_plan::unnest::UnnestExec;
+use datafusion::physical_plan::values::ValuesExec;
 use datafusion::physical_plan::windows::{
     BoundedWindowAggExec, PartitionSearchMode, WindowAggExec,
 };
 use datafusion::physical_plan::{
     udaf, AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr, WindowExpr,
 };
-use datafusion_common::{internal_err, not_impl_err, DataFusionError, Result};
+use datafusion_common::parsers::CompressionTypeVariant;
+use datafusion_common::{
+    internal_err, not_impl_err, DataFusionError, Result, UnnestOptions,
+};
+use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
 use prost::bytes::BufMut;
 use prost::Message;
 
 use crate::common::str_to_byte;
-use crate::common::{byte_to_string, proto_error};
+use crate::common::{byte_to_string, proto_error, registered_udaf};
 use crate::physical_plan::from_proto::{
     parse_physical_expr, parse_physical_sort_expr, parse_protobuf_file_scan_config,
 };
@@ -72,6 +90,12 @@ use crate::protobuf::{
 use crate::{convert_required, into_required};
 
 use self::from_proto::parse_physical_window_expr;
+use self::to_proto::{
+    serialize_file_scan_config, serialize_maybe_filter,
+    serialize_maybe_physical_sort_exprs, serialize_physical_aggr_expr,
+    serialize_physical_expr, serialize_physical_sort_expr,
+    serialize_physical_window_expr,
+};
 
 pub mod from_proto;
 pub mod to_proto;
@@ -130,7 +154,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .zip(projection.expr_name.iter())
                     .map(|(expr, name)| {
                         Ok((
-                            parse_physical_expr(expr, registry, input.schema().as_ref())?,
+                            parse_physical_expr_with_codec(
+                                expr,
+                                registry,
+                                extension_codec,
+                                input.schema().as_ref(),
+                            )?,
                             name.to_string(),
                         ))
                     })
@@ -148,7 +177,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .expr
                     .as_ref()
                     .map(|expr| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
+                        parse_physical_expr_with_codec(
+                            expr,
+                            registry,
+                            extension_codec,
+                            input.schema().as_ref(),
+                        )
                     })
                     .transpose()?
                     .ok_or_else(|| {
@@ -159,49 +193,69 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     })?;
                 Ok(Arc::new(FilterExec::try_new(predicate, input)?))
             }
-            PhysicalPlanType::CsvScan(scan) => Ok(Arc::new(CsvExec::new(
-                parse_protobuf_file_scan_config(
-                    scan.base_conf.as_ref().unwrap(),
-                    registry,
-                )?,
-                scan.has_header,
-                str_to_byte(&scan.delimiter, "delimiter")?,
-                str_to_byte(&scan.quote, "quote")?,
-                if let Some(protobuf::csv_scan_exec_node::OptionalEscape::Escape(
-                    escape,
-                )) = &scan.optional_escape
+            PhysicalPlanType::CsvScan(scan) => {
+                let file_compression_type = if let Some(
+                    protobuf::csv_scan_exec_node::OptionalFileCompressionType::FileCompressionType(variant),
+                ) = &scan.optional_file_compression_type
                 {
-                    Some(str_to_byte(escape, "escape")?)
+                    let variant = protobuf::CompressionTypeVariant::try_from(*variant)
+                        .map_err(|_| {
+                            proto_error(format!(
+                                "Received a CsvScanExecNode message with unknown CompressionTypeVariant {variant}"
+                            ))
+                        })?;
+                    CompressionTypeVariant::from(variant).into()
                 } else {
-                    None
-                },
-                FileCompressionType::UNCOMPRESSED,
-            ))),
+                    FileCompressionType::UNCOMPRESSED
+                };
+                Ok(Arc::new(CsvExec::new(
+                    parse_protobuf_file_scan_config_with_codec(
+                        scan.base_conf.as_ref().unwrap(),
+                        registry,
+                        extension_codec,
+                    )?,
+                    scan.has_header,
+                    str_to_byte(&scan.delimiter, "delimiter")?,
+                    str_to_byte(&scan.quote, "quote")?,
+                    if let Some(protobuf::csv_scan_exec_node::OptionalEscape::Escape(
+                        escape,
+                    )) = &scan.optional_escape
+                    {
+                        Some(str_to_byte(escape, "escape")?)
+                    } else {
+                        None
+                    },
+                    file_compression_type,
+                )))
+            }
             #[cfg(feature = "parquet")]
             PhysicalPlanType::ParquetScan(scan) => {
-                let base_config = parse_protobuf_file_scan_config(
+                let base_config = parse_protobuf_file_scan_config_with_codec(
                     scan.base_conf.as_ref().unwrap(),
                     registry,
+                    extension_codec,
                 )?;
                 let predicate = scan
                     .predicate
                     .as_ref()
                     .map(|expr| {
-                        parse_physical_expr(
+                        parse_physical_expr_with_codec(
                             expr,
                             registry,
+                            extension_codec,
                             base_config.file_schema.as_ref(),
                         )
                     })
                     .transpose()?;
                 Ok(Arc::new(ParquetExec::new(base_config, predicate, None)))
             }
-            PhysicalPlanType::AvroScan(scan) => {
-                Ok(Arc::new(AvroExec::new(parse_protobuf_file_scan_config(
+            PhysicalPlanType::AvroScan(scan) => Ok(Arc::new(AvroExec::new(
+                parse_protobuf_file_scan_config_with_codec(
                     scan.base_conf.as_ref().unwrap(),
                     registry,
-                )?)))
-            }
+                    extension_codec,
+                )?,
+            ))),
             PhysicalPlanType::CoalesceBatches(coalesce_batches) => {
                 let input: Arc<dyn ExecutionPlan> = into_physical_plan(
                     &coalesce_batches.input,
@@ -232,7 +286,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                             .hash_expr
                             .iter()
                             .map(|e| {
-                                parse_physical_expr(e, registry, input.schema().as_ref())
+                                parse_physical_expr_with_codec(
+                                    e,
+                                    registry,
+                                    extension_codec,
+                                    input.schema().as_ref(),
+                                )
                             })
                             .collect::<Result<Vec<Arc<dyn PhysicalExpr>>, _>>()?;
 
@@ -295,9 +354,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .window_expr
                     .iter()
                     .map(|window_expr| {
-                        parse_physical_window_expr(
+                        parse_physical_window_expr_with_codec(
                             window_expr,
                             registry,
+                            extension_codec,
                             input_schema.as_ref(),
                         )
                     })
@@ -307,7 +367,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .partition_keys
                     .iter()
                     .map(|expr| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
+                        parse_physical_expr_with_codec(
+                            expr,
+                            registry,
+                            extension_codec,
+                            input.schema().as_ref(),
+                        )
                     })
                     .collect::<Result<Vec<Arc<dyn PhysicalExpr>>>>()?;
 
@@ -376,8 +441,13 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .iter()
                     .zip(hash_agg.group_expr_name.iter())
                     .map(|(expr, name)| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
-                            .map(|expr| (expr, name.to_string()))
+                        parse_physical_expr_with_codec(
+                            expr,
+                            registry,
+                            extension_codec,
+                            input.schema().as_ref(),
+                        )
+                        .map(|expr| (expr, name.to_string()))
                     })
                     .collect::<Result<Vec<_>, _>>()?;
 
@@ -386,8 +456,13 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .iter()
                     .zip(hash_agg.group_expr_name.iter())
                     .map(|(expr, name)| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
-                            .map(|expr| (expr, name.to_string()))
+                        parse_physical_expr_with_codec(
+                            expr,
+                            registry,
+                            extension_codec,
+                            input.schema().as_ref(),
+                        )
+                        .map(|expr| (expr, name.to_string()))
                     })
                     .collect::<Result<Vec<_>, _>>()?;
 
@@ -414,7 +489,14 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .map(|expr| {
                         expr.expr
                             .as_ref()
-                            .map(|e| parse_physical_expr(e, registry, &physical_schema))
+                            .map(|e| {
+                                parse_physical_expr_with_codec(
+                                    e,
+                                    registry,
+                                    extension_codec,
+                                    &physical_schema,
+                                )
+                            })
                             .transpose()
                     })
                     .collect::<Result<Vec<_>, _>>()?;
@@ -425,7 +507,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         expr.sort_expr
                             .iter()
                             .map(|e| {
-                                parse_physical_sort_expr(e, registry, &physical_schema)
+                                parse_physical_sort_expr_with_codec(
+                                    e,
+                                    registry,
+                                    extension_codec,
+                                    &physical_schema,
+                                )
                             })
                             .collect::<Result<Vec<_>>>()
                             .map(|exprs| (!exprs.is_empty()).then_some(exprs))
@@ -444,9 +531,9 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         match expr_type {
                             ExprType::AggregateExpr(agg_node) => {
                                 let input_phy_expr: Vec<Arc<dyn PhysicalExpr>> = agg_node.expr.iter()
-                                    .map(|e| parse_physical_expr(e, registry, &physical_schema).unwrap()).collect();
+                                    .map(|e| parse_physical_expr_with_codec(e, registry, extension_codec, &physical_schema).unwrap()).collect();
                                 let ordering_req: Vec<PhysicalSortExpr> = agg_node.ordering_req.iter()
-                                    .map(|e| parse_physical_sort_expr(e, registry, &physical_schema).unwrap()).collect();
+                                    .map(|e| parse_physical_sort_expr_with_codec(e, registry, extension_codec, &physical_schema).unwrap()).collect();
                                 agg_node.aggregate_function.as_ref().map(|func| {
                                     match func {
                                         AggregateFunction::AggrFunction(i) => {
@@ -469,7 +556,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                                             )
                                         }
                                         AggregateFunction::UserDefinedAggrFunction(udaf_name) => {
-                                            let agg_udf = registry.udaf(udaf_name)?;
+                                            let agg_udf = match &agg_node.fun_definition {
+                                                Some(buf) => extension_codec.try_decode_udaf(udaf_name, buf)?,
+                                                None => registered_udaf(registry, udaf_name)?,
+                                            };
                                             udaf::create_aggregate_expr(agg_udf.as_ref(), &input_phy_expr, &physical_schema, name)
                                         }
                                     }
@@ -533,11 +623,11 @@ impl AsExecutionPlan for PhysicalPlanNode {
                             .ok_or_else(|| proto_error("Missing JoinFilter schema"))?
                             .try_into()?;
 
-                        let expression = parse_physical_expr(
+                        let expression = parse_physical_expr_with_codec(
                             f.expression.as_ref().ok_or_else(|| {
                                 proto_error("Unexpected empty filter expression")
                             })?,
-                            registry, &schema
+                            registry, extension_codec, &schema
                         )?;
                         let column_indices = f.column_indices
                             .iter()
@@ -636,7 +726,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                                 })?
                                 .as_ref();
                             Ok(PhysicalSortExpr {
-                                expr: parse_physical_expr(expr,registry, input.schema().as_ref())?,
+                                expr: parse_physical_expr_with_codec(expr,registry, extension_codec, input.schema().as_ref())?,
                                 options: SortOptions {
                                     descending: !sort_expr.asc,
                                     nulls_first: sort_expr.nulls_first,
@@ -683,7 +773,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                                 })?
                                 .as_ref();
                             Ok(PhysicalSortExpr {
-                                expr: parse_physical_expr(expr,registry, input.schema().as_ref())?,
+                                expr: parse_physical_expr_with_codec(expr,registry, extension_codec, input.schema().as_ref())?,
                                 options: SortOptions {
                                     descending: !sort_expr.asc,
                                     nulls_first: sort_expr.nulls_first,
@@ -742,11 +832,11 @@ impl AsExecutionPlan for PhysicalPlanNode {
                             .ok_or_else(|| proto_error("Missing JoinFilter schema"))?
                             .try_into()?;
 
-                        let expression = parse_physical_expr(
+                        let expression = parse_physical_expr_with_codec(
                             f.expression.as_ref().ok_or_else(|| {
                                 proto_error("Unexpected empty filter expression")
                             })?,
-                            registry, &schema
+                            registry, extension_codec, &schema
                         )?;
                         let column_indices = f.column_indices
                             .iter()
@@ -807,8 +897,86 @@ impl AsExecutionPlan for PhysicalPlanNode {
                             .physical_sort_expr_nodes
                             .iter()
                             .map(|proto| {
-                                parse_physical_sort_expr(proto, registry, &sink_schema)
-                                    .map(Into::into)
+                                parse_physical_sort_expr_with_codec(
+                                    proto,
+                                    registry,
+                                    extension_codec,
+                                    &sink_schema,
+                                )
+                                .map(Into::into)
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .transpose()?;
+                Ok(Arc::new(FileSinkExec::new(
+                    input,
+                    Arc::new(data_sink),
+                    Arc::new(sink_schema),
+                    sort_order,
+                )))
+            }
+            PhysicalPlanType::CsvSink(sink) => {
+                let input =
+                    into_physical_plan(&sink.input, registry, runtime, extension_codec)?;
+
+                let data_sink: CsvSink = sink
+                    .sink
+                    .as_ref()
+                    .ok_or_else(|| proto_error("Missing required field in protobuf"))?
+                    .try_into()?;
+                let sink_schema = convert_required!(sink.sink_schema)?;
+                let sort_order = sink
+                    .sort_order
+                    .as_ref()
+                    .map(|collection| {
+                        collection
+                            .physical_sort_expr_nodes
+                            .iter()
+                            .map(|proto| {
+                                parse_physical_sort_expr_with_codec(
+                                    proto,
+                                    registry,
+                                    extension_codec,
+                                    &sink_schema,
+                                )
+                                .map(Into::into)
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .transpose()?;
+                Ok(Arc::new(FileSinkExec::new(
+                    input,
+                    Arc::new(data_sink),
+                    Arc::new(sink_schema),
+                    sort_order,
+                )))
+            }
+            #[cfg(feature = "parquet")]
+            PhysicalPlanType::ParquetSink(sink) => {
+                let input =
+                    into_physical_plan(&sink.input, registry, runtime, extension_codec)?;
+
+                let data_sink: ParquetSink = sink
+                    .sink
+                    .as_ref()
+                    .ok_or_else(|| proto_error("Missing required field in protobuf"))?
+                    .try_into()?;
+                let sink_schema = convert_required!(sink.sink_schema)?;
+                let sort_order = sink
+                    .sort_order
+                    .as_ref()
+                    .map(|collection| {
+                        collection
+                            .physical_sort_expr_nodes
+                            .iter()
+                            .map(|proto| {
+                                parse_physical_sort_expr_with_codec(
+                                    proto,
+                                    registry,
+                                    extension_codec,
+                                    &sink_schema,
+                                )
+                                .map(Into::into)
                             })
                             .collect::<Result<Vec<_>>>()
                     })
@@ -820,6 +988,166 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     sort_order,
                 )))
             }
+            #[cfg(not(feature = "parquet"))]
+            PhysicalPlanType::ParquetSink(_) => {
+                not_impl_err!("ParquetSinkExec requires the parquet feature")
+            }
+            PhysicalPlanType::SortMergeJoin(join) => {
+                let left: Arc<dyn ExecutionPlan> =
+                    into_physical_plan(&join.left, registry, runtime, extension_codec)?;
+                let right: Arc<dyn ExecutionPlan> =
+                    into_physical_plan(&join.right, registry, runtime, extension_codec)?;
+                let on = parse_join_on(&join.on)?;
+                let join_type =
+                    protobuf::JoinType::try_from(join.join_type).map_err(|_| {
+                        proto_error(format!(
+                            "Received a SortMergeJoinExecNode message with unknown JoinType {}",
+                            join.join_type
+                        ))
+                    })?;
+                let sort_options = join
+                    .sort_options
+                    .iter()
+                    .map(|options| SortOptions {
+                        descending: options.descending,
+                        nulls_first: options.nulls_first,
+                    })
+                    .collect();
+                Ok(Arc::new(SortMergeJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    join_type.into(),
+                    sort_options,
+                    join.null_equals_null,
+                )?))
+            }
+            PhysicalPlanType::SymmetricHashJoin(join) => {
+                let left: Arc<dyn ExecutionPlan> =
+                    into_physical_plan(&join.left, registry, runtime, extension_codec)?;
+                let right: Arc<dyn ExecutionPlan> =
+                    into_physical_plan(&join.right, registry, runtime, extension_codec)?;
+                let on = parse_join_on(&join.on)?;
+                let join_type =
+                    protobuf::JoinType::try_from(join.join_type).map_err(|_| {
+                        proto_error(format!(
+                            "Received a SymmetricHashJoinExecNode message with unknown JoinType {}",
+                            join.join_type
+                        ))
+                    })?;
+                let filter = join
+                    .filter
+                    .as_ref()
+                    .map(|f| parse_join_filter(f, registry, extension_codec))
+                    .transpose()?;
+                let partition_mode =
+                    protobuf::StreamPartitionMode::try_from(join.partition_mode)
+                        .map_err(|_| {
+                            proto_error(format!(
+                                "Received a SymmetricHashJoinExecNode message with unknown StreamPartitionMode {}",
+                                join.partition_mode
+                            ))
+                        })?;
+                let partition_mode = match partition_mode {
+                    protobuf::StreamPartitionMode::SinglePartition => {
+                        StreamJoinPartitionMode::SinglePartition
+                    }
+                    protobuf::StreamPartitionMode::PartitionedExec => {
+                        StreamJoinPartitionMode::Partitioned
+                    }
+                };
+                Ok(Arc::new(SymmetricHashJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    filter,
+                    &join_type.into(),
+                    join.null_equals_null,
+                    partition_mode,
+                )?))
+            }
+            PhysicalPlanType::Unnest(unnest) => {
+                let input: Arc<dyn ExecutionPlan> = into_physical_plan(
+                    &unnest.input,
+                    registry,
+                    runtime,
+                    extension_codec,
+                )?;
+                let column: Column = into_required!(unnest.column)?;
+                let options = unnest
+                    .options
+                    .as_ref()
+                    .map(|options| {
+                        UnnestOptions::new().with_preserve_nulls(options.preserve_nulls)
+                    })
+                    .unwrap_or_default();
+                Ok(Arc::new(UnnestExec::new(
+                    input,
+                    column,
+                    Arc::new(convert_required!(unnest.schema)?),
+                    options,
+                )))
+            }
+            PhysicalPlanType::Values(values) => {
+                let schema = Arc::new(convert_required!(values.schema)?);
+                let batches = read_ipc_batches(&values.data)?;
+                Ok(Arc::new(ValuesExec::try_new_from_batches(schema, batches)?))
+            }
+            PhysicalPlanType::Memory(memory) => {
+                let schema = Arc::new(convert_required!(memory.schema)?);
+                let partitions = memory
+                    .partitions
+                    .iter()
+                    .map(|data| read_ipc_batches(data))
+                    .collect::<Result<Vec<_>>>()?;
+                let projection = memory.projection.as_ref().map(|projection| {
+                    projection.columns.iter().map(|c| *c as usize).collect()
+                });
+                let exec = MemoryExec::try_new(&partitions, schema, projection)?;
+                let output_schema = exec.schema();
+                let sort_information = memory
+                    .sort_information
+                    .iter()
+                    .map(|collection| {
+                        collection
+                            .physical_sort_expr_nodes
+                            .iter()
+                            .map(|proto| {
+                                parse_physical_sort_expr_with_codec(
+                                    proto,
+                                    registry,
+                                    extension_codec,
+                                    &output_schema,
+                                )
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Arc::new(exec.with_sort_information(sort_information)))
+            }
+            PhysicalPlanType::Interleave(interleave) => {
+                let inputs = interleave
+                    .inputs
+                    .iter()
+                    .map(|i| i.try_into_physical_plan(registry, runtime, extension_codec))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Arc::new(InterleaveExec::try_new(inputs)?))
+            }
+            PhysicalPlanType::JsonScan(scan) => Ok(Arc::new(NdJsonExec::new(
+                parse_protobuf_file_scan_config_with_codec(
+                    scan.base_conf.as_ref().unwrap(),
+                    registry,
+                    extension_codec,
+                )?,
+                CompressionTypeVariant::from(scan.file_compression_type()).into(),
+            ))),
+            PhysicalPlanType::ArrowScan(scan) => Ok(Arc::new(ArrowExec::new(
+                parse_protobuf_file_scan_config_with_codec(
+                    scan.base_conf.as_ref().unwrap(),
+                    registry,
+                    extension_codec,
+                )?,
+            ))),
         }
     }
 
@@ -849,6 +1177,15 @@ impl AsExecutionPlan for PhysicalPlanNode {
             });
         }
 
+        if let Some(exec) = plan.downcast_ref::<SharedExec>() {
+            // A subplan can only be shared by consumers in the same process, so
+            // each consumer gets its own copy of the shared input
+            return protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            );
+        }
+
         if let Some(exec) = plan.downcast_ref::<ProjectionExec>() {
             let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
                 exec.input().to_owned(),
@@ -857,7 +1194,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
             let expr = exec
                 .expr()
                 .iter()
-                .map(|expr| expr.0.clone().try_into())
+                .map(|expr| serialize_physical_expr(expr.0.clone(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
             let expr_name = exec.expr().iter().map(|expr| expr.1.clone()).collect();
             return Ok(protobuf::PhysicalPlanNode {
@@ -897,7 +1234,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::Filter(Box::new(
                     protobuf::FilterExecNode {
                         input: Some(Box::new(input)),
-                        expr: Some(exec.predicate().clone().try_into()?),
+                        expr: Some(serialize_physical_expr(
+                            exec.predicate().clone(),
+                            extension_codec,
+                        )?),
                     },
                 ))),
             });
@@ -966,7 +1306,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .filter()
                 .as_ref()
                 .map(|f| {
-                    let expression = f.expression().to_owned().try_into()?;
+                    let expression = serialize_physical_expr(
+                        f.expression().to_owned(),
+                        extension_codec,
+                    )?;
                     let column_indices = f
                         .column_indices()
                         .iter()
@@ -1045,19 +1388,23 @@ impl AsExecutionPlan for PhysicalPlanNode {
             let filter = exec
                 .filter_expr()
                 .iter()
-                .map(|expr| expr.to_owned().try_into())
+                .map(|expr| serialize_maybe_filter(expr.to_owned(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
 
             let order_by = exec
                 .order_by_expr()
                 .iter()
-                .map(|expr| expr.to_owned().try_into())
+                .map(|expr| {
+                    serialize_maybe_physical_sort_exprs(expr.to_owned(), extension_codec)
+                })
                 .collect::<Result<Vec<_>>>()?;
 
             let agg = exec
                 .aggr_expr()
                 .iter()
-                .map(|expr| expr.to_owned().try_into())
+                .map(|expr| {
+                    serialize_physical_aggr_expr(expr.to_owned(), extension_codec)
+                })
                 .collect::<Result<Vec<_>>>()?;
             let agg_names = exec
                 .aggr_expr()
@@ -1089,14 +1436,14 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .group_expr()
                 .null_expr()
                 .iter()
-                .map(|expr| expr.0.to_owned().try_into())
+                .map(|expr| serialize_physical_expr(expr.0.to_owned(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
 
             let group_expr = exec
                 .group_expr()
                 .expr()
                 .iter()
-                .map(|expr| expr.0.to_owned().try_into())
+                .map(|expr| serialize_physical_expr(expr.0.to_owned(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
 
             return Ok(protobuf::PhysicalPlanNode {
@@ -1149,7 +1496,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
             return Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::CsvScan(
                     protobuf::CsvScanExecNode {
-                        base_conf: Some(exec.base_config().try_into()?),
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
                         has_header: exec.has_header(),
                         delimiter: byte_to_string(exec.delimiter(), "delimiter")?,
                         quote: byte_to_string(exec.quote(), "quote")?,
@@ -1160,6 +1510,14 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         } else {
                             None
                         },
+                        optional_file_compression_type: Some(
+                            protobuf::csv_scan_exec_node::OptionalFileCompressionType::FileCompressionType(
+                                protobuf::CompressionTypeVariant::from(
+                                    exec.file_compression_type.get_variant(),
+                                )
+                                .into(),
+                            ),
+                        ),
                     },
                 )),
             });
@@ -1169,12 +1527,15 @@ impl AsExecutionPlan for PhysicalPlanNode {
         if let Some(exec) = plan.downcast_ref::<ParquetExec>() {
             let predicate = exec
                 .predicate()
-                .map(|pred| pred.clone().try_into())
+                .map(|pred| serialize_physical_expr(pred.clone(), extension_codec))
                 .transpose()?;
             return Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::ParquetScan(
                     protobuf::ParquetScanExecNode {
-                        base_conf: Some(exec.base_config().try_into()?),
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
                         predicate,
                     },
                 )),
@@ -1185,7 +1546,39 @@ impl AsExecutionPlan for PhysicalPlanNode {
             return Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::AvroScan(
                     protobuf::AvroScanExecNode {
-                        base_conf: Some(exec.base_config().try_into()?),
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
+                    },
+                )),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<NdJsonExec>() {
+            let file_compression_type: protobuf::CompressionTypeVariant =
+                exec.file_compression_type().get_variant().into();
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::JsonScan(
+                    protobuf::JsonScanExecNode {
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
+                        file_compression_type: file_compression_type.into(),
+                    },
+                )),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<ArrowExec>() {
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::ArrowScan(
+                    protobuf::ArrowScanExecNode {
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
                     },
                 )),
             });
@@ -1216,7 +1609,9 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     PartitionMethod::Hash(protobuf::PhysicalHashRepartition {
                         hash_expr: exprs
                             .iter()
-                            .map(|expr| expr.clone().try_into())
+                            .map(|expr| {
+                                serialize_physical_expr(expr.clone(), extension_codec)
+                            })
                             .collect::<Result<Vec<_>>>()?,
                         partition_count: *partition_count as u64,
                     })
@@ -1249,7 +1644,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .iter()
                 .map(|expr| {
                     let sort_expr = Box::new(protobuf::PhysicalSortExprNode {
-                        expr: Some(Box::new(expr.expr.to_owned().try_into()?)),
+                        expr: Some(Box::new(serialize_physical_expr(
+                            expr.expr.to_owned(),
+                            extension_codec,
+                        )?)),
                         asc: !expr.options.descending,
                         nulls_first: expr.options.nulls_first,
                     });
@@ -1300,7 +1698,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .iter()
                 .map(|expr| {
                     let sort_expr = Box::new(protobuf::PhysicalSortExprNode {
-                        expr: Some(Box::new(expr.expr.to_owned().try_into()?)),
+                        expr: Some(Box::new(serialize_physical_expr(
+                            expr.expr.to_owned(),
+                            extension_codec,
+                        )?)),
                         asc: !expr.options.descending,
                         nulls_first: expr.options.nulls_first,
                     });
@@ -1337,7 +1738,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .filter()
                 .as_ref()
                 .map(|f| {
-                    let expression = f.expression().to_owned().try_into()?;
+                    let expression = serialize_physical_expr(
+                        f.expression().to_owned(),
+                        extension_codec,
+                    )?;
                     let column_indices = f
                         .column_indices()
                         .iter()
@@ -1370,22 +1774,183 @@ impl AsExecutionPlan for PhysicalPlanNode {
             });
         }
 
+        if let Some(exec) = plan.downcast_ref::<SortMergeJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left.to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right.to_owned(),
+                extension_codec,
+            )?;
+            let join_type: protobuf::JoinType = exec.join_type().into();
+            let sort_options = exec
+                .sort_options
+                .iter()
+                .map(|options| protobuf::SortOptions {
+                    descending: options.descending,
+                    nulls_first: options.nulls_first,
+                })
+                .collect();
+
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SortMergeJoin(Box::new(
+                    protobuf::SortMergeJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on: serialize_join_on(exec.on()),
+                        join_type: join_type.into(),
+                        sort_options,
+                        null_equals_null: exec.null_equals_null,
+                    },
+                ))),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<SymmetricHashJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
+            let filter = exec
+                .filter()
+                .map(|f| serialize_join_filter(f, extension_codec))
+                .transpose()?;
+            let partition_mode = match exec.partition_mode() {
+                StreamJoinPartitionMode::SinglePartition => {
+                    protobuf::StreamPartitionMode::SinglePartition
+                }
+                StreamJoinPartitionMode::Partitioned => {
+                    protobuf::StreamPartitionMode::PartitionedExec
+                }
+            };
+
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SymmetricHashJoin(Box::new(
+                    protobuf::SymmetricHashJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on: serialize_join_on(exec.on()),
+                        join_type: join_type.into(),
+                        partition_mode: partition_mode.into(),
+                        null_equals_null: exec.null_equals_null(),
+                        filter,
+                    },
+                ))),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<UnnestExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Unnest(Box::new(
+                    protobuf::UnnestExecNode {
+                        input: Some(Box::new(input)),
+                        schema: Some(exec.schema().as_ref().try_into()?),
+                        column: Some(protobuf::PhysicalColumn {
+                            name: exec.column().name().to_string(),
+                            index: exec.column().index() as u32,
+                        }),
+                        options: Some(protobuf::UnnestOptions {
+                            preserve_nulls: exec.options().preserve_nulls,
+                        }),
+                    },
+                ))),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<ValuesExec>() {
+            let schema = exec.schema();
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Values(
+                    protobuf::ValuesExecNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        data: write_ipc_batches(&schema, &exec.data())?,
+                    },
+                )),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<MemoryExec>() {
+            let schema = exec.original_schema();
+            let partitions = exec
+                .partitions()
+                .iter()
+                .map(|batches| write_ipc_batches(&schema, batches))
+                .collect::<Result<Vec<_>>>()?;
+            let sort_information = exec
+                .sort_information()
+                .iter()
+                .map(|ordering| {
+                    let physical_sort_expr_nodes = ordering
+                        .iter()
+                        .map(|expr| {
+                            serialize_physical_sort_expr(expr.to_owned(), extension_codec)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(PhysicalSortExprNodeCollection {
+                        physical_sort_expr_nodes,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Memory(
+                    protobuf::MemoryExecNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        partitions,
+                        projection: exec.projection().as_ref().map(|projection| {
+                            protobuf::MemoryProjection {
+                                columns: projection.iter().map(|c| *c as u32).collect(),
+                            }
+                        }),
+                        sort_information,
+                    },
+                )),
+            });
+        }
+
+        if let Some(interleave) = plan.downcast_ref::<InterleaveExec>() {
+            let inputs = interleave
+                .inputs()
+                .iter()
+                .map(|input| {
+                    protobuf::PhysicalPlanNode::try_from_physical_plan(
+                        input.to_owned(),
+                        extension_codec,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Interleave(
+                    protobuf::InterleaveExecNode { inputs },
+                )),
+            });
+        }
+
         if let Some(exec) = plan.downcast_ref::<WindowAggExec>() {
             let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
                 exec.input().to_owned(),
                 extension_codec,
             )?;
 
-            let window_expr =
-                exec.window_expr()
-                    .iter()
-                    .map(|e| e.clone().try_into())
-                    .collect::<Result<Vec<protobuf::PhysicalWindowExprNode>>>()?;
+            let window_expr = exec
+                .window_expr()
+                .iter()
+                .map(|e| serialize_physical_window_expr(e.clone(), extension_codec))
+                .collect::<Result<Vec<protobuf::PhysicalWindowExprNode>>>()?;
 
             let partition_keys = exec
                 .partition_keys
                 .iter()
-                .map(|e| e.clone().try_into())
+                .map(|e| serialize_physical_expr(e.clone(), extension_codec))
                 .collect::<Result<Vec<protobuf::PhysicalExprNode>>>()?;
 
             return Ok(protobuf::PhysicalPlanNode {
@@ -1406,16 +1971,16 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 extension_codec,
             )?;
 
-            let window_expr =
-                exec.window_expr()
-                    .iter()
-                    .map(|e| e.clone().try_into())
-                    .collect::<Result<Vec<protobuf::PhysicalWindowExprNode>>>()?;
+            let window_expr = exec
+                .window_expr()
+                .iter()
+                .map(|e| serialize_physical_window_expr(e.clone(), extension_codec))
+                .collect::<Result<Vec<protobuf::PhysicalWindowExprNode>>>()?;
 
             let partition_keys = exec
                 .partition_keys
                 .iter()
-                .map(|e| e.clone().try_into())
+                .map(|e| serialize_physical_expr(e.clone(), extension_codec))
                 .collect::<Result<Vec<protobuf::PhysicalExprNode>>>()?;
 
             let partition_search_mode = match &exec.partition_search_mode {
@@ -1462,7 +2027,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         .map(|requirement| {
                             let expr: PhysicalSortExpr = requirement.to_owned().into();
                             let sort_expr = protobuf::PhysicalSortExprNode {
-                                expr: Some(Box::new(expr.expr.to_owned().try_into()?)),
+                                expr: Some(Box::new(serialize_physical_expr(
+                                    expr.expr.to_owned(),
+                                    extension_codec,
+                                )?)),
                                 asc: !expr.options.descending,
                                 nulls_first: expr.options.nulls_first,
                             };
@@ -1489,6 +2057,33 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 });
             }
 
+            if let Some(sink) = exec.sink().as_any().downcast_ref::<CsvSink>() {
+                return Ok(protobuf::PhysicalPlanNode {
+                    physical_plan_type: Some(PhysicalPlanType::CsvSink(Box::new(
+                        protobuf::CsvSinkExecNode {
+                            input: Some(Box::new(input)),
+                            sink: Some(sink.try_into()?),
+                            sink_schema: Some(exec.schema().as_ref().try_into()?),
+                            sort_order,
+                        },
+                    ))),
+                });
+            }
+
+            #[cfg(feature = "parquet")]
+            if let Some(sink) = exec.sink().as_any().downcast_ref::<ParquetSink>() {
+                return Ok(protobuf::PhysicalPlanNode {
+                    physical_plan_type: Some(PhysicalPlanType::ParquetSink(Box::new(
+                        protobuf::ParquetSinkExecNode {
+                            input: Some(Box::new(input)),
+                            sink: Some(sink.try_into()?),
+                            sink_schema: Some(exec.schema().as_ref().try_into()?),
+                            sort_order,
+                        },
+                    ))),
+                });
+            }
+
             // If unknown DataSink then let extension handle it
         }
 
@@ -1512,6 +2107,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     )),
                 })
             }
+            // the partition streams of a StreamingTableExec are arbitrary
+            // code, which only an extension codec can encode
+            Err(e) if plan_clone.as_any().is::<StreamingTableExec>() => not_impl_err!(
+                "StreamingTableExec can only be serialized by a PhysicalExtensionCodec \
+                 that encodes its partition streams, the extension codec failed with [{e}]"
+            ),
             Err(e) => internal_err!(
                 "Unsupported plan and extension codec failed with [{e}]. Plan: {plan_clone:?}"
             ),
@@ -1553,6 +2154,56 @@ pub trait PhysicalExtensionCodec: Debug + Send + Sync {
     ) -> Result<Arc<dyn ExecutionPlan>>;
 
     fn try_encode(&self, node: Arc<dyn ExecutionPlan>, buf: &mut Vec<u8>) -> Result<()>;
+
+    /// Decode a scalar UDF named `name` from the definition written by
+    /// [`Self::try_encode_udf`]
+    ///
+    /// This is only called for functions with a non-empty definition, other
+    /// functions are looked up by name in the function registry.
+    fn try_decode_udf(&self, name: &str, _buf: &[u8]) -> Result<Arc<ScalarUDF>> {
+        not_impl_err!("PhysicalExtensionCodec is not provided for scalar function {name}")
+    }
+
+    /// Encode the definition of a scalar UDF, such as its configuration,
+    /// into `buf`
+    ///
+    /// Writing nothing, which is the default, encodes the function by name
+    /// only.
+    fn try_encode_udf(&self, _node: &ScalarUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decode an aggregate UDF named `name` from the definition written by
+    /// [`Self::try_encode_udaf`]
+    ///
+    /// Like [`Self::try_decode_udf`], this is only called for functions with
+    /// a non-empty definition.
+    fn try_decode_udaf(&self, name: &str, _buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        not_impl_err!(
+            "PhysicalExtensionCodec is not provided for aggregate function {name}"
+        )
+    }
+
+    /// Encode the definition of an aggregate UDF into `buf`, or nothing to
+    /// encode it by name only
+    fn try_encode_udaf(&self, _node: &AggregateUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decode a window UDF named `name` from the definition written by
+    /// [`Self::try_encode_udwf`]
+    ///
+    /// Like [`Self::try_decode_udf`], this is only called for functions with
+    /// a non-empty definition.
+    fn try_decode_udwf(&self, name: &str, _buf: &[u8]) -> Result<Arc<WindowUDF>> {
+        not_impl_err!("PhysicalExtensionCodec is not provided for window function {name}")
+    }
+
+    /// Encode the definition of a window UDF into `buf`, or nothing to
+    /// encode it by name only
+    fn try_encode_udwf(&self, _node: &WindowUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -1589,3 +2240,107 @@ fn into_physical_plan(
         Err(proto_error("Missing required field in protobuf"))
     }
 }
+
+fn parse_join_on(on: &[protobuf::JoinOn]) -> Result<Vec<(Column, Column)>> {
+    on.iter()
+        .map(|col| {
+            let left = into_required!(col.left)?;
+            let right = into_required!(col.right)?;
+            Ok((left, right))
+        })
+        .collect()
+}
+
+fn serialize_join_on(on: &[(Column, Column)]) -> Vec<protobuf::JoinOn> {
+    on.iter()
+        .map(|(left, right)| protobuf::JoinOn {
+            left: Some(protobuf::PhysicalColumn {
+                name: left.name().to_string(),
+                index: left.index() as u32,
+            }),
+            right: Some(protobuf::PhysicalColumn {
+                name: right.name().to_string(),
+                index: right.index() as u32,
+            }),
+        })
+        .collect()
+}
+
+fn parse_join_filter(
+    filter: &protobuf::JoinFilter,
+    registry: &dyn FunctionRegistry,
+    extension_codec: &dyn PhysicalExtensionCodec,
+) -> Result<JoinFilter> {
+    let schema = filter
+        .schema
+        .as_ref()
+        .ok_or_else(|| proto_error("Missing JoinFilter schema"))?
+        .try_into()?;
+    let expression = parse_physical_expr_with_codec(
+        filter
+            .expression
+            .as_ref()
+            .ok_or_else(|| proto_error("Unexpected empty filter expression"))?,
+        registry,
+        extension_codec,
+        &schema,
+    )?;
+    let column_indices = filter
+        .column_indices
+        .iter()
+        .map(|i| {
+            let side = protobuf::JoinSide::try_from(i.side).map_err(|_| {
+                proto_error(format!(
+                    "Received a JoinFilter with unknown JoinSide {}",
+                    i.side
+                ))
+            })?;
+            Ok(ColumnIndex {
+                index: i.index as usize,
+                side: side.into(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(JoinFilter::new(expression, column_indices, schema))
+}
+
+fn serialize_join_filter(
+    filter: &JoinFilter,
+    extension_codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::JoinFilter> {
+    let column_indices = filter
+        .column_indices()
+        .iter()
+        .map(|i| {
+            let side: protobuf::JoinSide = i.side.to_owned().into();
+            protobuf::ColumnIndex {
+                index: i.index as u32,
+                side: side.into(),
+            }
+        })
+        .collect();
+    Ok(protobuf::JoinFilter {
+        expression: Some(serialize_physical_expr(
+            filter.expression().to_owned(),
+            extension_codec,
+        )?),
+        column_indices,
+        schema: Some(filter.schema().try_into()?),
+    })
+}
+
+/// Encodes `batches` as an Arrow IPC stream
+fn write_ipc_batches(schema: &SchemaRef, batches: &[RecordBatch]) -> Result<Vec<u8>> {
+    let mut writer = StreamWriter::try_new(vec![], schema)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    Ok(writer.into_inner()?)
+}
+
+/// Decodes the batches of an Arrow IPC stream written by [`write_ipc_batches`]
+fn read_ipc_batches(data: &[u8]) -> Result<Vec<RecordBatch>> {
+    let reader = StreamReader::try_new(data, None)?;
+    Ok(reader.collect::<Result<Vec<_>, _>>()?)
+}
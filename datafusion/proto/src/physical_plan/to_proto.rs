@@ -22,13 +22,17 @@ use std::{
     sync::Arc,
 };
 
+use crate::physical_plan::{DefaultPhysicalExtensionCodec, PhysicalExtensionCodec};
 use crate::protobuf::{self, physical_window_expr_node, scalar_value::Value};
 use crate::protobuf::{
     physical_aggregate_expr_node, PhysicalSortExprNode, PhysicalSortExprNodeCollection,
     ScalarValue,
 };
+use datafusion::arrow::datatypes::Schema;
+#[cfg(feature = "parquet")]
+use datafusion::datasource::file_format::parquet::ParquetSink;
 use datafusion::datasource::{
-    file_format::json::JsonSink, physical_plan::FileScanConfig,
+    file_format::csv::CsvSink, file_format::json::JsonSink, physical_plan::FileScanConfig,
 };
 use datafusion::datasource::{
     file_format::write::FileWriterMode,
@@ -36,21 +40,32 @@ use datafusion::datasource::{
     physical_plan::FileSinkConfig,
 };
 use datafusion::logical_expr::BuiltinScalarFunction;
+#[cfg(feature = "parquet")]
+use datafusion::parquet::{
+    arrow::arrow_to_parquet_schema,
+    basic::Compression,
+    file::properties::{WriterProperties, WriterVersion},
+    schema::types::ColumnPath,
+};
 use datafusion::physical_expr::expressions::{GetFieldAccessExpr, GetIndexedFieldExpr};
 use datafusion::physical_expr::window::{NthValueKind, SlidingAggregateWindowExpr};
-use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
+use datafusion::physical_expr::{
+    HigherOrderFunctionExpr, PhysicalSortExpr, ScalarFunctionExpr,
+};
 use datafusion::physical_plan::expressions::{
     ApproxDistinct, ApproxMedian, ApproxPercentileCont, ApproxPercentileContWithWeight,
     ArrayAgg, Avg, BinaryExpr, BitAnd, BitOr, BitXor, BoolAnd, BoolOr, CaseExpr,
-    CastExpr, Column, Correlation, Count, Covariance, CovariancePop, CumeDist,
-    DistinctArrayAgg, DistinctBitXor, DistinctCount, DistinctSum, FirstValue, Grouping,
-    InListExpr, IsNotNullExpr, IsNullExpr, LastValue, LikeExpr, Literal, Max, Median,
-    Min, NegativeExpr, NotExpr, NthValue, Ntile, OrderSensitiveArrayAgg, Rank, RankType,
-    Regr, RegrType, RowNumber, Stddev, StddevPop, Sum, TryCastExpr, Variance,
-    VariancePop, WindowShift,
+    CastExpr, CollateExpr, Column, Correlation, Count, Covariance, CovariancePop,
+    CumeDist, DistinctArrayAgg, DistinctBitXor, DistinctCount, DistinctSum, FirstValue,
+    Grouping, InListExpr, IsNotNullExpr, IsNullExpr, LastValue, LikeExpr, Literal, Max,
+    Median, Min, NegativeExpr, NoOp, NotExpr, NthValue, Ntile, OrderSensitiveArrayAgg,
+    Rank, RankType, Regr, RegrType, RowNumber, Stddev, StddevPop, Sum, TryCastExpr,
+    UnKnownColumn, Variance, VariancePop, WindowShift,
 };
 use datafusion::physical_plan::udaf::AggregateFunctionExpr;
-use datafusion::physical_plan::windows::{BuiltInWindowExpr, PlainAggregateWindowExpr};
+use datafusion::physical_plan::windows::{
+    BuiltInWindowExpr, PlainAggregateWindowExpr, WindowUDFExpr,
+};
 use datafusion::physical_plan::{
     AggregateExpr, ColumnStatistics, PhysicalExpr, Statistics, WindowExpr,
 };
@@ -70,202 +85,248 @@ impl TryFrom<Arc<dyn AggregateExpr>> for protobuf::PhysicalExprNode {
     type Error = DataFusionError;
 
     fn try_from(a: Arc<dyn AggregateExpr>) -> Result<Self, Self::Error> {
-        let expressions: Vec<protobuf::PhysicalExprNode> = a
-            .expressions()
-            .iter()
-            .map(|e| e.clone().try_into())
-            .collect::<Result<Vec<_>>>()?;
-
-        let ordering_req: Vec<protobuf::PhysicalSortExprNode> = a
-            .order_bys()
-            .unwrap_or(&[])
-            .iter()
-            .map(|e| e.clone().try_into())
-            .collect::<Result<Vec<_>>>()?;
+        serialize_physical_aggr_expr(a, &DefaultPhysicalExtensionCodec {})
+    }
+}
 
-        if let Some(a) = a.as_any().downcast_ref::<AggregateFunctionExpr>() {
-            return Ok(protobuf::PhysicalExprNode {
+/// Serialize an [`AggregateExpr`], encoding the definitions of scalar UDFs with `codec`
+pub fn serialize_physical_aggr_expr(
+    a: Arc<dyn AggregateExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalExprNode> {
+    let expressions: Vec<protobuf::PhysicalExprNode> = a
+        .expressions()
+        .iter()
+        .map(|e| serialize_physical_expr(e.clone(), codec))
+        .collect::<Result<Vec<_>>>()?;
+
+    let ordering_req: Vec<protobuf::PhysicalSortExprNode> = a
+        .order_bys()
+        .unwrap_or(&[])
+        .iter()
+        .map(|e| serialize_physical_sort_expr(e.clone(), codec))
+        .collect::<Result<Vec<_>>>()?;
+
+    if let Some(a) = a.as_any().downcast_ref::<AggregateFunctionExpr>() {
+        let mut buf = Vec::new();
+        codec.try_encode_udaf(a.fun(), &mut buf)?;
+        return Ok(protobuf::PhysicalExprNode {
                     expr_type: Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
                         protobuf::PhysicalAggregateExprNode {
                             aggregate_function: Some(physical_aggregate_expr_node::AggregateFunction::UserDefinedAggrFunction(a.fun().name.clone())),
                             expr: expressions,
                             ordering_req,
                             distinct: false,
+                            fun_definition: (!buf.is_empty()).then_some(buf),
                         },
                     )),
                 });
-        }
-
-        let AggrFn {
-            inner: aggr_function,
-            distinct,
-        } = aggr_expr_to_aggr_fn(a.as_ref())?;
+    }
 
-        Ok(protobuf::PhysicalExprNode {
-            expr_type: Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
-                protobuf::PhysicalAggregateExprNode {
-                    aggregate_function: Some(
-                        physical_aggregate_expr_node::AggregateFunction::AggrFunction(
-                            aggr_function as i32,
-                        ),
+    let AggrFn {
+        inner: aggr_function,
+        distinct,
+    } = aggr_expr_to_aggr_fn(a.as_ref())?;
+
+    Ok(protobuf::PhysicalExprNode {
+        expr_type: Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
+            protobuf::PhysicalAggregateExprNode {
+                aggregate_function: Some(
+                    physical_aggregate_expr_node::AggregateFunction::AggrFunction(
+                        aggr_function as i32,
                     ),
-                    expr: expressions,
-                    ordering_req,
-                    distinct,
-                },
-            )),
-        })
-    }
+                ),
+                expr: expressions,
+                ordering_req,
+                distinct,
+                fun_definition: None,
+            },
+        )),
+    })
 }
 
 impl TryFrom<Arc<dyn WindowExpr>> for protobuf::PhysicalWindowExprNode {
     type Error = DataFusionError;
 
-    fn try_from(
-        window_expr: Arc<dyn WindowExpr>,
-    ) -> std::result::Result<Self, Self::Error> {
-        let expr = window_expr.as_any();
-
-        let mut args = window_expr.expressions().to_vec();
-        let window_frame = window_expr.get_window_frame();
-
-        let window_function = if let Some(built_in_window_expr) =
-            expr.downcast_ref::<BuiltInWindowExpr>()
-        {
-            let expr = built_in_window_expr.get_built_in_func_expr();
-            let built_in_fn_expr = expr.as_any();
-
-            let builtin_fn = if built_in_fn_expr.downcast_ref::<RowNumber>().is_some() {
-                protobuf::BuiltInWindowFunction::RowNumber
-            } else if let Some(rank_expr) = built_in_fn_expr.downcast_ref::<Rank>() {
-                match rank_expr.get_type() {
-                    RankType::Basic => protobuf::BuiltInWindowFunction::Rank,
-                    RankType::Dense => protobuf::BuiltInWindowFunction::DenseRank,
-                    RankType::Percent => protobuf::BuiltInWindowFunction::PercentRank,
-                }
-            } else if built_in_fn_expr.downcast_ref::<CumeDist>().is_some() {
-                protobuf::BuiltInWindowFunction::CumeDist
-            } else if let Some(ntile_expr) = built_in_fn_expr.downcast_ref::<Ntile>() {
-                args.insert(
-                    0,
-                    Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(Some(
-                        ntile_expr.get_n() as i64,
-                    )))),
-                );
-                protobuf::BuiltInWindowFunction::Ntile
-            } else if let Some(window_shift_expr) =
-                built_in_fn_expr.downcast_ref::<WindowShift>()
-            {
-                args.insert(
-                    1,
-                    Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(Some(
-                        window_shift_expr.get_shift_offset(),
-                    )))),
-                );
-                if let Some(default_value) = window_shift_expr.get_default_value() {
-                    args.insert(2, Arc::new(Literal::new(default_value)));
-                }
-                if window_shift_expr.get_shift_offset() >= 0 {
-                    protobuf::BuiltInWindowFunction::Lag
-                } else {
-                    protobuf::BuiltInWindowFunction::Lead
-                }
-            } else if let Some(nth_value_expr) =
-                built_in_fn_expr.downcast_ref::<NthValue>()
-            {
-                match nth_value_expr.get_kind() {
-                    NthValueKind::First => protobuf::BuiltInWindowFunction::FirstValue,
-                    NthValueKind::Last => protobuf::BuiltInWindowFunction::LastValue,
-                    NthValueKind::Nth(n) => {
-                        args.insert(
-                            1,
-                            Arc::new(Literal::new(
-                                datafusion_common::ScalarValue::Int64(Some(n as i64)),
-                            )),
-                        );
-                        protobuf::BuiltInWindowFunction::NthValue
-                    }
-                }
-            } else {
-                return not_impl_err!("BuiltIn function not supported: {expr:?}");
-            };
-
-            physical_window_expr_node::WindowFunction::BuiltInFunction(builtin_fn as i32)
-        } else if let Some(plain_aggr_window_expr) =
-            expr.downcast_ref::<PlainAggregateWindowExpr>()
-        {
-            let AggrFn { inner, distinct } = aggr_expr_to_aggr_fn(
-                plain_aggr_window_expr.get_aggregate_expr().as_ref(),
-            )?;
-
-            if distinct {
-                // TODO
-                return not_impl_err!(
-                    "Distinct aggregate functions not supported in window expressions"
-                );
-            }
+    fn try_from(window_expr: Arc<dyn WindowExpr>) -> Result<Self, Self::Error> {
+        serialize_physical_window_expr(window_expr, &DefaultPhysicalExtensionCodec {})
+    }
+}
 
-            if !window_frame.start_bound.is_unbounded() {
-                return Err(DataFusionError::Internal(format!("Invalid PlainAggregateWindowExpr = {window_expr:?} with WindowFrame = {window_frame:?}")));
+/// Serialize a [`WindowExpr`], encoding the definitions of user-defined functions
+/// with `codec`
+pub fn serialize_physical_window_expr(
+    window_expr: Arc<dyn WindowExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalWindowExprNode> {
+    let expr = window_expr.as_any();
+
+    let mut args = window_expr.expressions().to_vec();
+    let window_frame = window_expr.get_window_frame();
+    let mut buf = Vec::new();
+
+    let (window_function, distinct) = if let Some(udwf_expr) =
+        expr.downcast_ref::<BuiltInWindowExpr>().and_then(|e| {
+            e.get_built_in_func_expr()
+                .as_any()
+                .downcast_ref::<WindowUDFExpr>()
+        }) {
+        codec.try_encode_udwf(udwf_expr.fun(), &mut buf)?;
+        (
+            physical_window_expr_node::WindowFunction::UserDefinedWindowFunction(
+                udwf_expr.fun().name.clone(),
+            ),
+            false,
+        )
+    } else if let Some(built_in_window_expr) = expr.downcast_ref::<BuiltInWindowExpr>() {
+        let expr = built_in_window_expr.get_built_in_func_expr();
+        let built_in_fn_expr = expr.as_any();
+
+        let builtin_fn = if built_in_fn_expr.downcast_ref::<RowNumber>().is_some() {
+            protobuf::BuiltInWindowFunction::RowNumber
+        } else if let Some(rank_expr) = built_in_fn_expr.downcast_ref::<Rank>() {
+            match rank_expr.get_type() {
+                RankType::Basic => protobuf::BuiltInWindowFunction::Rank,
+                RankType::Dense => protobuf::BuiltInWindowFunction::DenseRank,
+                RankType::Percent => protobuf::BuiltInWindowFunction::PercentRank,
             }
-
-            physical_window_expr_node::WindowFunction::AggrFunction(inner as i32)
-        } else if let Some(sliding_aggr_window_expr) =
-            expr.downcast_ref::<SlidingAggregateWindowExpr>()
+        } else if built_in_fn_expr.downcast_ref::<CumeDist>().is_some() {
+            protobuf::BuiltInWindowFunction::CumeDist
+        } else if let Some(ntile_expr) = built_in_fn_expr.downcast_ref::<Ntile>() {
+            args.insert(
+                0,
+                Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(Some(
+                    ntile_expr.get_n() as i64,
+                )))),
+            );
+            protobuf::BuiltInWindowFunction::Ntile
+        } else if let Some(window_shift_expr) =
+            built_in_fn_expr.downcast_ref::<WindowShift>()
         {
-            let AggrFn { inner, distinct } = aggr_expr_to_aggr_fn(
-                sliding_aggr_window_expr.get_aggregate_expr().as_ref(),
-            )?;
-
-            if distinct {
-                // TODO
-                return not_impl_err!(
-                    "Distinct aggregate functions not supported in window expressions"
-                );
+            args.insert(
+                1,
+                Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(Some(
+                    window_shift_expr.get_shift_offset(),
+                )))),
+            );
+            if let Some(default_value) = window_shift_expr.get_default_value() {
+                args.insert(2, Arc::new(Literal::new(default_value)));
             }
-
-            if window_frame.start_bound.is_unbounded() {
-                return Err(DataFusionError::Internal(format!("Invalid SlidingAggregateWindowExpr = {window_expr:?} with WindowFrame = {window_frame:?}")));
+            if window_shift_expr.get_shift_offset() >= 0 {
+                protobuf::BuiltInWindowFunction::Lag
+            } else {
+                protobuf::BuiltInWindowFunction::Lead
+            }
+        } else if let Some(nth_value_expr) = built_in_fn_expr.downcast_ref::<NthValue>() {
+            match nth_value_expr.get_kind() {
+                NthValueKind::First => protobuf::BuiltInWindowFunction::FirstValue,
+                NthValueKind::Last => protobuf::BuiltInWindowFunction::LastValue,
+                NthValueKind::Nth(n) => {
+                    args.insert(
+                        1,
+                        Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(
+                            Some(n as i64),
+                        ))),
+                    );
+                    protobuf::BuiltInWindowFunction::NthValue
+                }
             }
-
-            physical_window_expr_node::WindowFunction::AggrFunction(inner as i32)
         } else {
-            return not_impl_err!("WindowExpr not supported: {window_expr:?}");
+            return not_impl_err!("BuiltIn function not supported: {expr:?}");
         };
 
-        let args = args
-            .into_iter()
-            .map(|e| e.try_into())
-            .collect::<Result<Vec<protobuf::PhysicalExprNode>>>()?;
+        (
+            physical_window_expr_node::WindowFunction::BuiltInFunction(builtin_fn as i32),
+            false,
+        )
+    } else if let Some(plain_aggr_window_expr) =
+        expr.downcast_ref::<PlainAggregateWindowExpr>()
+    {
+        if !window_frame.start_bound.is_unbounded() {
+            return Err(DataFusionError::Internal(format!("Invalid PlainAggregateWindowExpr = {window_expr:?} with WindowFrame = {window_frame:?}")));
+        }
 
-        let partition_by = window_expr
-            .partition_by()
-            .iter()
-            .map(|p| p.clone().try_into())
-            .collect::<Result<Vec<protobuf::PhysicalExprNode>>>()?;
+        aggr_expr_to_window_fn(
+            plain_aggr_window_expr.get_aggregate_expr().as_ref(),
+            codec,
+            &mut buf,
+        )?
+    } else if let Some(sliding_aggr_window_expr) =
+        expr.downcast_ref::<SlidingAggregateWindowExpr>()
+    {
+        if window_frame.start_bound.is_unbounded() {
+            return Err(DataFusionError::Internal(format!("Invalid SlidingAggregateWindowExpr = {window_expr:?} with WindowFrame = {window_frame:?}")));
+        }
 
-        let order_by = window_expr
-            .order_by()
-            .iter()
-            .map(|o| o.clone().try_into())
-            .collect::<Result<Vec<protobuf::PhysicalSortExprNode>>>()?;
+        aggr_expr_to_window_fn(
+            sliding_aggr_window_expr.get_aggregate_expr().as_ref(),
+            codec,
+            &mut buf,
+        )?
+    } else {
+        return not_impl_err!("WindowExpr not supported: {window_expr:?}");
+    };
 
-        let window_frame: protobuf::WindowFrame = window_frame
-            .as_ref()
-            .try_into()
-            .map_err(|e| DataFusionError::Internal(format!("{e}")))?;
-
-        let name = window_expr.name().to_string();
-
-        Ok(protobuf::PhysicalWindowExprNode {
-            args,
-            partition_by,
-            order_by,
-            window_frame: Some(window_frame),
-            window_function: Some(window_function),
-            name,
-        })
+    let args = args
+        .into_iter()
+        .map(|e| serialize_physical_expr(e, codec))
+        .collect::<Result<Vec<protobuf::PhysicalExprNode>>>()?;
+
+    let partition_by = window_expr
+        .partition_by()
+        .iter()
+        .map(|p| serialize_physical_expr(p.clone(), codec))
+        .collect::<Result<Vec<protobuf::PhysicalExprNode>>>()?;
+
+    let order_by = window_expr
+        .order_by()
+        .iter()
+        .map(|o| serialize_physical_sort_expr(o.clone(), codec))
+        .collect::<Result<Vec<protobuf::PhysicalSortExprNode>>>()?;
+
+    let window_frame: protobuf::WindowFrame = window_frame
+        .as_ref()
+        .try_into()
+        .map_err(|e| DataFusionError::Internal(format!("{e}")))?;
+
+    let name = window_expr.name().to_string();
+
+    Ok(protobuf::PhysicalWindowExprNode {
+        args,
+        partition_by,
+        order_by,
+        window_frame: Some(window_frame),
+        window_function: Some(window_function),
+        name,
+        distinct,
+        fun_definition: (!buf.is_empty()).then_some(buf),
+    })
+}
+
+/// Returns the window function of an aggregate used as a window expression,
+/// and whether the aggregate is distinct. The definition of a user-defined
+/// aggregate is encoded into `buf` with `codec`
+fn aggr_expr_to_window_fn(
+    expr: &dyn AggregateExpr,
+    codec: &dyn PhysicalExtensionCodec,
+    buf: &mut Vec<u8>,
+) -> Result<(physical_window_expr_node::WindowFunction, bool)> {
+    if let Some(udaf_expr) = expr.as_any().downcast_ref::<AggregateFunctionExpr>() {
+        codec.try_encode_udaf(udaf_expr.fun(), buf)?;
+        return Ok((
+            physical_window_expr_node::WindowFunction::UserDefinedAggrFunction(
+                udaf_expr.fun().name.clone(),
+            ),
+            false,
+        ));
     }
+
+    let AggrFn { inner, distinct } = aggr_expr_to_aggr_fn(expr)?;
+
+    Ok((
+        physical_window_expr_node::WindowFunction::AggrFunction(inner as i32),
+        distinct,
+    ))
 }
 
 struct AggrFn {
@@ -369,180 +430,245 @@ impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
     type Error = DataFusionError;
 
     fn try_from(value: Arc<dyn PhysicalExpr>) -> Result<Self, Self::Error> {
-        let expr = value.as_any();
+        serialize_physical_expr(value, &DefaultPhysicalExtensionCodec {})
+    }
+}
 
-        if let Some(expr) = expr.downcast_ref::<Column>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Column(
-                    protobuf::PhysicalColumn {
-                        name: expr.name().to_string(),
-                        index: expr.index() as u32,
-                    },
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<BinaryExpr>() {
-            let binary_expr = Box::new(protobuf::PhysicalBinaryExprNode {
-                l: Some(Box::new(expr.left().to_owned().try_into()?)),
-                r: Some(Box::new(expr.right().to_owned().try_into()?)),
-                op: format!("{:?}", expr.op()),
-            });
+/// Serialize a [`PhysicalExpr`], encoding the definitions of scalar UDFs with `codec`
+pub fn serialize_physical_expr(
+    value: Arc<dyn PhysicalExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalExprNode> {
+    let expr = value.as_any();
 
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::BinaryExpr(
-                    binary_expr,
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<CaseExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::Case(
-                        Box::new(
-                            protobuf::PhysicalCaseNode {
-                                expr: expr
-                                    .expr()
-                                    .map(|exp| exp.clone().try_into().map(Box::new))
-                                    .transpose()?,
-                                when_then_expr: expr
-                                    .when_then_expr()
-                                    .iter()
-                                    .map(|(when_expr, then_expr)| {
-                                        try_parse_when_then_expr(when_expr, then_expr)
-                                    })
-                                    .collect::<Result<
-                                        Vec<protobuf::PhysicalWhenThen>,
-                                        Self::Error,
-                                    >>()?,
-                                else_expr: expr
-                                    .else_expr()
-                                    .map(|a| a.clone().try_into().map(Box::new))
-                                    .transpose()?,
-                            },
-                        ),
+    if let Some(expr) = expr.downcast_ref::<Column>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Column(
+                protobuf::PhysicalColumn {
+                    name: expr.name().to_string(),
+                    index: expr.index() as u32,
+                },
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<UnKnownColumn>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::UnknownColumn(
+                protobuf::PhysicalUnknownColumn {
+                    name: expr.name().to_string(),
+                },
+            )),
+        })
+    } else if expr.downcast_ref::<NoOp>().is_some() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::NoOp(
+                protobuf::EmptyMessage {},
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<BinaryExpr>() {
+        let binary_expr = Box::new(protobuf::PhysicalBinaryExprNode {
+            l: Some(Box::new(serialize_physical_expr(
+                expr.left().to_owned(),
+                codec,
+            )?)),
+            r: Some(Box::new(serialize_physical_expr(
+                expr.right().to_owned(),
+                codec,
+            )?)),
+            op: format!("{:?}", expr.op()),
+        });
+
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::BinaryExpr(
+                binary_expr,
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<CaseExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(
+                protobuf::physical_expr_node::ExprType::Case(
+                    Box::new(
+                        protobuf::PhysicalCaseNode {
+                            expr: expr
+                                .expr()
+                                .map(|exp| {
+                                    serialize_physical_expr(exp.clone(), codec)
+                                        .map(Box::new)
+                                })
+                                .transpose()?,
+                            when_then_expr: expr
+                                .when_then_expr()
+                                .iter()
+                                .map(|(when_expr, then_expr)| {
+                                    try_parse_when_then_expr(when_expr, then_expr, codec)
+                                })
+                                .collect::<Result<
+                                    Vec<protobuf::PhysicalWhenThen>,
+                                    DataFusionError,
+                                >>()?,
+                            else_expr: expr
+                                .else_expr()
+                                .map(|a| {
+                                    serialize_physical_expr(a.clone(), codec)
+                                        .map(Box::new)
+                                })
+                                .transpose()?,
+                        },
                     ),
                 ),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<NotExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::NotExpr(
-                    Box::new(protobuf::PhysicalNot {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<IsNullExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::IsNullExpr(
-                    Box::new(protobuf::PhysicalIsNull {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<IsNotNullExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::IsNotNullExpr(
-                    Box::new(protobuf::PhysicalIsNotNull {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<InListExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::InList(
-                        Box::new(
-                            protobuf::PhysicalInListNode {
-                                expr: Some(Box::new(expr.expr().to_owned().try_into()?)),
-                                list: expr
-                                    .list()
-                                    .iter()
-                                    .map(|a| a.clone().try_into())
-                                    .collect::<Result<
+            ),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<NotExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::NotExpr(Box::new(
+                protobuf::PhysicalNot {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<IsNullExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::IsNullExpr(
+                Box::new(protobuf::PhysicalIsNull {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                }),
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<IsNotNullExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::IsNotNullExpr(
+                Box::new(protobuf::PhysicalIsNotNull {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                }),
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<InListExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(
+                protobuf::physical_expr_node::ExprType::InList(
+                    Box::new(
+                        protobuf::PhysicalInListNode {
+                            expr: Some(Box::new(serialize_physical_expr(
+                                expr.expr().to_owned(),
+                                codec,
+                            )?)),
+                            list: expr
+                                .list()
+                                .iter()
+                                .map(|a| serialize_physical_expr(a.clone(), codec))
+                                .collect::<Result<
                                     Vec<protobuf::PhysicalExprNode>,
-                                    Self::Error,
+                                    DataFusionError,
                                 >>()?,
-                                negated: expr.negated(),
-                            },
-                        ),
+                            negated: expr.negated(),
+                        },
                     ),
                 ),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<NegativeExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Negative(
-                    Box::new(protobuf::PhysicalNegativeNode {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(lit) = expr.downcast_ref::<Literal>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Literal(
-                    lit.value().try_into()?,
-                )),
-            })
-        } else if let Some(cast) = expr.downcast_ref::<CastExpr>() {
+            ),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<NegativeExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Negative(Box::new(
+                protobuf::PhysicalNegativeNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                },
+            ))),
+        })
+    } else if let Some(lit) = expr.downcast_ref::<Literal>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Literal(
+                lit.value().try_into()?,
+            )),
+        })
+    } else if let Some(cast) = expr.downcast_ref::<CastExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Cast(Box::new(
+                protobuf::PhysicalCastNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        cast.expr().clone(),
+                        codec,
+                    )?)),
+                    arrow_type: Some(cast.cast_type().try_into()?),
+                },
+            ))),
+        })
+    } else if let Some(cast) = expr.downcast_ref::<TryCastExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::TryCast(Box::new(
+                protobuf::PhysicalTryCastNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        cast.expr().clone(),
+                        codec,
+                    )?)),
+                    arrow_type: Some(cast.cast_type().try_into()?),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<ScalarFunctionExpr>() {
+        let args: Vec<protobuf::PhysicalExprNode> = expr
+            .args()
+            .iter()
+            .map(|e| serialize_physical_expr(e.to_owned(), codec))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Ok(fun) = BuiltinScalarFunction::from_str(expr.name()) {
+            let fun: protobuf::ScalarFunction = (&fun).try_into()?;
+
             Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Cast(Box::new(
-                    protobuf::PhysicalCastNode {
-                        expr: Some(Box::new(cast.expr().clone().try_into()?)),
-                        arrow_type: Some(cast.cast_type().try_into()?),
+                expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarFunction(
+                    protobuf::PhysicalScalarFunctionNode {
+                        name: expr.name().to_string(),
+                        fun: fun.into(),
+                        args,
+                        return_type: Some(expr.return_type().try_into()?),
                     },
-                ))),
-            })
-        } else if let Some(cast) = expr.downcast_ref::<TryCastExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::TryCast(
-                    Box::new(protobuf::PhysicalTryCastNode {
-                        expr: Some(Box::new(cast.expr().clone().try_into()?)),
-                        arrow_type: Some(cast.cast_type().try_into()?),
-                    }),
                 )),
             })
-        } else if let Some(expr) = expr.downcast_ref::<ScalarFunctionExpr>() {
-            let args: Vec<protobuf::PhysicalExprNode> = expr
-                .args()
-                .iter()
-                .map(|e| e.to_owned().try_into())
-                .collect::<Result<Vec<_>, _>>()?;
-            if let Ok(fun) = BuiltinScalarFunction::from_str(expr.name()) {
-                let fun: protobuf::ScalarFunction = (&fun).try_into()?;
-
-                Ok(protobuf::PhysicalExprNode {
-                    expr_type: Some(
-                        protobuf::physical_expr_node::ExprType::ScalarFunction(
-                            protobuf::PhysicalScalarFunctionNode {
-                                name: expr.name().to_string(),
-                                fun: fun.into(),
-                                args,
-                                return_type: Some(expr.return_type().try_into()?),
-                            },
-                        ),
-                    ),
-                })
-            } else {
-                Ok(protobuf::PhysicalExprNode {
-                    expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarUdf(
-                        protobuf::PhysicalScalarUdfNode {
-                            name: expr.name().to_string(),
-                            args,
-                            return_type: Some(expr.return_type().try_into()?),
-                        },
-                    )),
-                })
+        } else {
+            let mut buf = Vec::new();
+            if let Some(udf) = expr.udf() {
+                codec.try_encode_udf(udf, &mut buf)?;
             }
-        } else if let Some(expr) = expr.downcast_ref::<LikeExpr>() {
             Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::LikeExpr(
-                    Box::new(protobuf::PhysicalLikeExprNode {
-                        negated: expr.negated(),
-                        case_insensitive: expr.case_insensitive(),
-                        expr: Some(Box::new(expr.expr().to_owned().try_into()?)),
-                        pattern: Some(Box::new(expr.pattern().to_owned().try_into()?)),
-                    }),
+                expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarUdf(
+                    protobuf::PhysicalScalarUdfNode {
+                        name: expr.name().to_string(),
+                        args,
+                        fun_definition: (!buf.is_empty()).then_some(buf),
+                        return_type: Some(expr.return_type().try_into()?),
+                    },
                 )),
             })
-        } else if let Some(expr) = expr.downcast_ref::<GetIndexedFieldExpr>() {
-            let field = match expr.field() {
+        }
+    } else if let Some(expr) = expr.downcast_ref::<LikeExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::LikeExpr(Box::new(
+                protobuf::PhysicalLikeExprNode {
+                    negated: expr.negated(),
+                    case_insensitive: expr.case_insensitive(),
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.expr().to_owned(),
+                        codec,
+                    )?)),
+                    pattern: Some(Box::new(serialize_physical_expr(
+                        expr.pattern().to_owned(),
+                        codec,
+                    )?)),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<GetIndexedFieldExpr>() {
+        let field = match expr.field() {
                 GetFieldAccessExpr::NamedStructField{name} => Some(
                     protobuf::physical_get_indexed_field_expr_node::Field::NamedStructFieldExpr(protobuf::NamedStructFieldExpr {
                         name: Some(ScalarValue::try_from(name)?)
@@ -550,40 +676,83 @@ impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
                 ),
                 GetFieldAccessExpr::ListIndex{key} => Some(
                     protobuf::physical_get_indexed_field_expr_node::Field::ListIndexExpr(Box::new(protobuf::ListIndexExpr {
-                        key: Some(Box::new(key.to_owned().try_into()?))
+                        key: Some(Box::new(serialize_physical_expr(key.to_owned(), codec)?))
                     }))
                 ),
                 GetFieldAccessExpr::ListRange{start, stop} => Some(
                     protobuf::physical_get_indexed_field_expr_node::Field::ListRangeExpr(Box::new(protobuf::ListRangeExpr {
-                        start: Some(Box::new(start.to_owned().try_into()?)),
-                        stop: Some(Box::new(stop.to_owned().try_into()?)),
+                        start: Some(Box::new(serialize_physical_expr(start.to_owned(), codec)?)),
+                        stop: Some(Box::new(serialize_physical_expr(stop.to_owned(), codec)?)),
                     }))
                 ),
             };
 
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::GetIndexedFieldExpr(
-                        Box::new(protobuf::PhysicalGetIndexedFieldExprNode {
-                            arg: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                            field,
-                        }),
-                    ),
-                ),
-            })
-        } else {
-            internal_err!("physical_plan::to_proto() unsupported expression {value:?}")
-        }
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::GetIndexedFieldExpr(
+                Box::new(protobuf::PhysicalGetIndexedFieldExprNode {
+                    arg: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                    field,
+                }),
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<CollateExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::CollateExpr(
+                Box::new(protobuf::PhysicalCollateExprNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.expr().to_owned(),
+                        codec,
+                    )?)),
+                    collation: expr.collation().to_string(),
+                }),
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<HigherOrderFunctionExpr>() {
+        let fun: protobuf::ScalarFunction = expr.fun().try_into()?;
+        let args = expr
+            .args()
+            .iter()
+            .map(|e| serialize_physical_expr(e.to_owned(), codec))
+            .collect::<Result<Vec<_>, _>>()?;
+        let lambda = protobuf::PhysicalLambdaNode {
+            params: Some(expr.lambda().params().as_ref().try_into()?),
+            body: Some(Box::new(serialize_physical_expr(
+                expr.lambda().body().to_owned(),
+                codec,
+            )?)),
+            captures: expr
+                .lambda()
+                .captures()
+                .iter()
+                .map(|e| serialize_physical_expr(e.to_owned(), codec))
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::HigherOrderFunction(
+                Box::new(protobuf::PhysicalHigherOrderFunctionNode {
+                    fun: fun.into(),
+                    args,
+                    lambda: Some(Box::new(lambda)),
+                    return_type: Some(expr.return_type().try_into()?),
+                }),
+            )),
+        })
+    } else {
+        internal_err!("physical_plan::to_proto() unsupported expression {value:?}")
     }
 }
 
 fn try_parse_when_then_expr(
     when_expr: &Arc<dyn PhysicalExpr>,
     then_expr: &Arc<dyn PhysicalExpr>,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<protobuf::PhysicalWhenThen> {
     Ok(protobuf::PhysicalWhenThen {
-        when_expr: Some(when_expr.clone().try_into()?),
-        then_expr: Some(then_expr.clone().try_into()?),
+        when_expr: Some(serialize_physical_expr(when_expr.clone(), codec)?),
+        then_expr: Some(serialize_physical_expr(then_expr.clone(), codec)?),
     })
 }
 
@@ -701,57 +870,64 @@ impl From<&ColumnStatistics> for protobuf::ColumnStats {
 
 impl TryFrom<&FileScanConfig> for protobuf::FileScanExecConf {
     type Error = DataFusionError;
-    fn try_from(
-        conf: &FileScanConfig,
-    ) -> Result<protobuf::FileScanExecConf, Self::Error> {
-        let file_groups = conf
-            .file_groups
-            .iter()
-            .map(|p| p.as_slice().try_into())
-            .collect::<Result<Vec<_>, _>>()?;
 
-        let mut output_orderings = vec![];
-        for order in &conf.output_ordering {
-            let expr_node_vec = order
-                .iter()
-                .map(|sort_expr| {
-                    let expr = sort_expr.expr.clone().try_into()?;
-                    Ok(PhysicalSortExprNode {
-                        expr: Some(Box::new(expr)),
-                        asc: !sort_expr.options.descending,
-                        nulls_first: sort_expr.options.nulls_first,
-                    })
-                })
-                .collect::<Result<Vec<PhysicalSortExprNode>>>()?;
-            output_orderings.push(expr_node_vec)
-        }
+    fn try_from(conf: &FileScanConfig) -> Result<Self, Self::Error> {
+        serialize_file_scan_config(conf, &DefaultPhysicalExtensionCodec {})
+    }
+}
 
-        Ok(protobuf::FileScanExecConf {
-            file_groups,
-            statistics: Some((&conf.statistics).into()),
-            limit: conf.limit.map(|l| protobuf::ScanLimit { limit: l as u32 }),
-            projection: conf
-                .projection
-                .as_ref()
-                .unwrap_or(&vec![])
-                .iter()
-                .map(|n| *n as u32)
-                .collect(),
-            schema: Some(conf.file_schema.as_ref().try_into()?),
-            table_partition_cols: conf
-                .table_partition_cols
-                .iter()
-                .map(|x| x.name().clone())
-                .collect::<Vec<_>>(),
-            object_store_url: conf.object_store_url.to_string(),
-            output_ordering: output_orderings
-                .into_iter()
-                .map(|e| PhysicalSortExprNodeCollection {
-                    physical_sort_expr_nodes: e,
+/// Serialize a [`FileScanConfig`], encoding the definitions of scalar UDFs with `codec`
+pub fn serialize_file_scan_config(
+    conf: &FileScanConfig,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::FileScanExecConf> {
+    let file_groups = conf
+        .file_groups
+        .iter()
+        .map(|p| p.as_slice().try_into())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut output_orderings = vec![];
+    for order in &conf.output_ordering {
+        let expr_node_vec = order
+            .iter()
+            .map(|sort_expr| {
+                let expr = serialize_physical_expr(sort_expr.expr.clone(), codec)?;
+                Ok(PhysicalSortExprNode {
+                    expr: Some(Box::new(expr)),
+                    asc: !sort_expr.options.descending,
+                    nulls_first: sort_expr.options.nulls_first,
                 })
-                .collect::<Vec<_>>(),
-        })
+            })
+            .collect::<Result<Vec<PhysicalSortExprNode>>>()?;
+        output_orderings.push(expr_node_vec)
     }
+
+    Ok(protobuf::FileScanExecConf {
+        file_groups,
+        statistics: Some((&conf.statistics).into()),
+        limit: conf.limit.map(|l| protobuf::ScanLimit { limit: l as u32 }),
+        projection: conf
+            .projection
+            .as_ref()
+            .unwrap_or(&vec![])
+            .iter()
+            .map(|n| *n as u32)
+            .collect(),
+        schema: Some(conf.file_schema.as_ref().try_into()?),
+        table_partition_cols: conf
+            .table_partition_cols
+            .iter()
+            .map(|x| x.name().clone())
+            .collect::<Vec<_>>(),
+        object_store_url: conf.object_store_url.to_string(),
+        output_ordering: output_orderings
+            .into_iter()
+            .map(|e| PhysicalSortExprNodeCollection {
+                physical_sort_expr_nodes: e,
+            })
+            .collect::<Vec<_>>(),
+    })
 }
 
 impl From<JoinSide> for protobuf::JoinSide {
@@ -767,12 +943,20 @@ impl TryFrom<Option<Arc<dyn PhysicalExpr>>> for protobuf::MaybeFilter {
     type Error = DataFusionError;
 
     fn try_from(expr: Option<Arc<dyn PhysicalExpr>>) -> Result<Self, Self::Error> {
-        match expr {
-            None => Ok(protobuf::MaybeFilter { expr: None }),
-            Some(expr) => Ok(protobuf::MaybeFilter {
-                expr: Some(expr.try_into()?),
-            }),
-        }
+        serialize_maybe_filter(expr, &DefaultPhysicalExtensionCodec {})
+    }
+}
+
+/// Serialize an optional filter, encoding the definitions of scalar UDFs with `codec`
+pub fn serialize_maybe_filter(
+    expr: Option<Arc<dyn PhysicalExpr>>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::MaybeFilter> {
+    match expr {
+        None => Ok(protobuf::MaybeFilter { expr: None }),
+        Some(expr) => Ok(protobuf::MaybeFilter {
+            expr: Some(serialize_physical_expr(expr, codec)?),
+        }),
     }
 }
 
@@ -780,30 +964,46 @@ impl TryFrom<Option<Vec<PhysicalSortExpr>>> for protobuf::MaybePhysicalSortExprs
     type Error = DataFusionError;
 
     fn try_from(sort_exprs: Option<Vec<PhysicalSortExpr>>) -> Result<Self, Self::Error> {
-        match sort_exprs {
-            None => Ok(protobuf::MaybePhysicalSortExprs { sort_expr: vec![] }),
-            Some(sort_exprs) => Ok(protobuf::MaybePhysicalSortExprs {
-                sort_expr: sort_exprs
-                    .into_iter()
-                    .map(|sort_expr| sort_expr.try_into())
-                    .collect::<Result<Vec<_>>>()?,
-            }),
-        }
+        serialize_maybe_physical_sort_exprs(sort_exprs, &DefaultPhysicalExtensionCodec {})
+    }
+}
+
+/// Serialize an optional ordering, encoding the definitions of scalar UDFs with `codec`
+pub fn serialize_maybe_physical_sort_exprs(
+    sort_exprs: Option<Vec<PhysicalSortExpr>>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::MaybePhysicalSortExprs> {
+    match sort_exprs {
+        None => Ok(protobuf::MaybePhysicalSortExprs { sort_expr: vec![] }),
+        Some(sort_exprs) => Ok(protobuf::MaybePhysicalSortExprs {
+            sort_expr: sort_exprs
+                .into_iter()
+                .map(|sort_expr| serialize_physical_sort_expr(sort_expr, codec))
+                .collect::<Result<Vec<_>>>()?,
+        }),
     }
 }
 
 impl TryFrom<PhysicalSortExpr> for protobuf::PhysicalSortExprNode {
     type Error = DataFusionError;
 
-    fn try_from(sort_expr: PhysicalSortExpr) -> std::result::Result<Self, Self::Error> {
-        Ok(PhysicalSortExprNode {
-            expr: Some(Box::new(sort_expr.expr.try_into()?)),
-            asc: !sort_expr.options.descending,
-            nulls_first: sort_expr.options.nulls_first,
-        })
+    fn try_from(sort_expr: PhysicalSortExpr) -> Result<Self, Self::Error> {
+        serialize_physical_sort_expr(sort_expr, &DefaultPhysicalExtensionCodec {})
     }
 }
 
+/// Serialize a [`PhysicalSortExpr`], encoding the definitions of scalar UDFs with `codec`
+pub fn serialize_physical_sort_expr(
+    sort_expr: PhysicalSortExpr,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalSortExprNode> {
+    Ok(PhysicalSortExprNode {
+        expr: Some(Box::new(serialize_physical_expr(sort_expr.expr, codec)?)),
+        asc: !sort_expr.options.descending,
+        nulls_first: sort_expr.options.nulls_first,
+    })
+}
+
 impl TryFrom<&JsonSink> for protobuf::JsonSink {
     type Error = DataFusionError;
 
@@ -814,6 +1014,27 @@ impl TryFrom<&JsonSink> for protobuf::JsonSink {
     }
 }
 
+impl TryFrom<&CsvSink> for protobuf::CsvSink {
+    type Error = DataFusionError;
+
+    fn try_from(value: &CsvSink) -> Result<Self, Self::Error> {
+        Ok(Self {
+            config: Some(value.config().try_into()?),
+        })
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl TryFrom<&ParquetSink> for protobuf::ParquetSink {
+    type Error = DataFusionError;
+
+    fn try_from(value: &ParquetSink) -> Result<Self, Self::Error> {
+        Ok(Self {
+            config: Some(value.config().try_into()?),
+        })
+    }
+}
+
 impl TryFrom<&FileSinkConfig> for protobuf::FileSinkConfig {
     type Error = DataFusionError;
 
@@ -850,7 +1071,10 @@ impl TryFrom<&FileSinkConfig> for protobuf::FileSinkConfig {
             single_file_output: conf.single_file_output,
             unbounded_input: conf.unbounded_input,
             overwrite: conf.overwrite,
-            file_type_writer_options: Some(file_type_writer_options.try_into()?),
+            file_type_writer_options: Some(serialize_file_type_writer_options(
+                file_type_writer_options,
+                &conf.output_schema,
+            )?),
         })
     }
 }
@@ -877,36 +1101,160 @@ impl From<&CompressionTypeVariant> for protobuf::CompressionTypeVariant {
     }
 }
 
-impl TryFrom<&FileTypeWriterOptions> for protobuf::FileTypeWriterOptions {
-    type Error = DataFusionError;
+/// Serializes the [`FileTypeWriterOptions`] of a sink that writes `schema`
+#[cfg_attr(not(feature = "parquet"), allow(unused_variables))]
+fn serialize_file_type_writer_options(
+    opts: &FileTypeWriterOptions,
+    schema: &Schema,
+) -> Result<protobuf::FileTypeWriterOptions> {
+    let file_type = match opts {
+        #[cfg(feature = "parquet")]
+        FileTypeWriterOptions::Parquet(ParquetWriterOptions { writer_options }) => {
+            protobuf::file_type_writer_options::FileType::ParquetOptions(
+                protobuf::ParquetWriterOptions {
+                    writer_properties: Some(serialize_writer_properties(
+                        writer_options,
+                        schema,
+                    )?),
+                },
+            )
+        }
+        FileTypeWriterOptions::CSV(CsvWriterOptions {
+            writer_options,
+            compression,
+        }) => {
+            let compression: protobuf::CompressionTypeVariant = compression.into();
+            let format = |format: Option<&str>| format.unwrap_or_default().to_string();
+            protobuf::file_type_writer_options::FileType::CsvOptions(
+                protobuf::CsvWriterOptions {
+                    compression: compression.into(),
+                    has_header: writer_options.header(),
+                    delimiter: vec![writer_options.delimiter()],
+                    quote: vec![writer_options.quote()],
+                    escape: vec![writer_options.escape()],
+                    double_quote: writer_options.double_quote(),
+                    date_format: format(writer_options.date_format()),
+                    datetime_format: format(writer_options.datetime_format()),
+                    timestamp_format: format(writer_options.timestamp_format()),
+                    timestamp_tz_format: format(writer_options.timestamp_tz_format()),
+                    time_format: format(writer_options.time_format()),
+                    null_value: writer_options.null().to_string(),
+                },
+            )
+        }
+        FileTypeWriterOptions::JSON(JsonWriterOptions { compression }) => {
+            let compression: protobuf::CompressionTypeVariant = compression.into();
+            protobuf::file_type_writer_options::FileType::JsonOptions(
+                protobuf::JsonWriterOptions {
+                    compression: compression.into(),
+                },
+            )
+        }
+        FileTypeWriterOptions::Avro(AvroWriterOptions {}) => {
+            return not_impl_err!("Avro file sink protobuf serialization")
+        }
+        FileTypeWriterOptions::Arrow(ArrowWriterOptions {}) => {
+            return not_impl_err!("Arrow file sink protobuf serialization")
+        }
+    };
+    Ok(protobuf::FileTypeWriterOptions {
+        file_type: Some(file_type),
+    })
+}
 
-    fn try_from(opts: &FileTypeWriterOptions) -> Result<Self, Self::Error> {
-        let file_type = match opts {
-            #[cfg(feature = "parquet")]
-            FileTypeWriterOptions::Parquet(ParquetWriterOptions {
-                writer_options: _,
-            }) => return not_impl_err!("Parquet file sink protobuf serialization"),
-            FileTypeWriterOptions::CSV(CsvWriterOptions {
-                writer_options: _,
-                compression: _,
-            }) => return not_impl_err!("CSV file sink protobuf serialization"),
-            FileTypeWriterOptions::JSON(JsonWriterOptions { compression }) => {
-                let compression: protobuf::CompressionTypeVariant = compression.into();
-                protobuf::file_type_writer_options::FileType::JsonOptions(
-                    protobuf::JsonWriterOptions {
-                        compression: compression.into(),
-                    },
+/// Serializes parquet [`WriterProperties`], including the settings of the
+/// columns of `schema` that differ from the defaults for all columns
+#[cfg(feature = "parquet")]
+fn serialize_writer_properties(
+    props: &WriterProperties,
+    schema: &Schema,
+) -> Result<protobuf::WriterProperties> {
+    let defaults = serialize_column_writer_properties(props, &ColumnPath::from(""));
+    let column_properties = arrow_to_parquet_schema(schema)?
+        .columns()
+        .iter()
+        .filter_map(|column| {
+            let properties = serialize_column_writer_properties(props, column.path());
+            (properties != defaults).then(|| protobuf::ColumnWriterProperties {
+                path: column.path().parts().to_vec(),
+                ..properties
+            })
+        })
+        .collect();
+
+    Ok(protobuf::WriterProperties {
+        data_page_size_limit: props.data_page_size_limit() as u64,
+        dictionary_page_size_limit: props.dictionary_page_size_limit() as u64,
+        data_page_row_count_limit: props.data_page_row_count_limit() as u64,
+        write_batch_size: props.write_batch_size() as u64,
+        max_row_group_size: props.max_row_group_size() as u64,
+        writer_version: match props.writer_version() {
+            WriterVersion::PARQUET_1_0 => "1.0",
+            WriterVersion::PARQUET_2_0 => "2.0",
+        }
+        .to_string(),
+        created_by: props.created_by().to_string(),
+        optional_column_index_truncate_length: props
+            .column_index_truncate_length()
+            .map(|length| {
+                protobuf::writer_properties::OptionalColumnIndexTruncateLength::ColumnIndexTruncateLength(
+                    length as u64,
                 )
+            }),
+        optional_encoding: defaults.optional_encoding.map(|encoding| match encoding {
+            protobuf::column_writer_properties::OptionalEncoding::Encoding(encoding) => {
+                protobuf::writer_properties::OptionalEncoding::Encoding(encoding)
             }
-            FileTypeWriterOptions::Avro(AvroWriterOptions {}) => {
-                return not_impl_err!("Avro file sink protobuf serialization")
-            }
-            FileTypeWriterOptions::Arrow(ArrowWriterOptions {}) => {
-                return not_impl_err!("Arrow file sink protobuf serialization")
-            }
-        };
-        Ok(Self {
-            file_type: Some(file_type),
-        })
+        }),
+        compression: defaults.compression,
+        dictionary_enabled: defaults.dictionary_enabled,
+        statistics_enabled: defaults.statistics_enabled,
+        max_statistics_size: defaults.max_statistics_size,
+        bloom_filter_enabled: defaults.bloom_filter_enabled,
+        bloom_filter_fpp: defaults.bloom_filter_fpp,
+        bloom_filter_ndv: defaults.bloom_filter_ndv,
+        column_properties,
+    })
+}
+
+/// Serializes the parquet settings of `column`, without its path. The
+/// defaults for all columns are returned for an empty path.
+#[cfg(feature = "parquet")]
+fn serialize_column_writer_properties(
+    props: &WriterProperties,
+    column: &ColumnPath,
+) -> protobuf::ColumnWriterProperties {
+    let bloom_filter = props.bloom_filter_properties(column);
+    protobuf::ColumnWriterProperties {
+        path: vec![],
+        optional_encoding: props.encoding(column).map(|encoding| {
+            protobuf::column_writer_properties::OptionalEncoding::Encoding(
+                format!("{encoding:?}").to_lowercase(),
+            )
+        }),
+        compression: compression_to_string(props.compression(column)),
+        dictionary_enabled: props.dictionary_enabled(column),
+        statistics_enabled: format!("{:?}", props.statistics_enabled(column))
+            .to_lowercase(),
+        max_statistics_size: props.max_statistics_size(column) as u64,
+        bloom_filter_enabled: bloom_filter.is_some(),
+        bloom_filter_fpp: bloom_filter.map(|b| b.fpp).unwrap_or_default(),
+        bloom_filter_ndv: bloom_filter.map(|b| b.ndv).unwrap_or_default(),
+    }
+}
+
+/// Formats a parquet compression the way `datafusion.execution.parquet.compression`
+/// expects it, e.g. `zstd(3)`
+#[cfg(feature = "parquet")]
+fn compression_to_string(compression: Compression) -> String {
+    match compression {
+        Compression::UNCOMPRESSED => "uncompressed".to_string(),
+        Compression::SNAPPY => "snappy".to_string(),
+        Compression::GZIP(level) => format!("gzip({})", level.compression_level()),
+        Compression::LZO => "lzo".to_string(),
+        Compression::BROTLI(level) => format!("brotli({})", level.compression_level()),
+        Compression::LZ4 => "lz4".to_string(),
+        Compression::ZSTD(level) => format!("zstd({})", level.compression_level()),
+        Compression::LZ4_RAW => "lz4_raw".to_string(),
     }
 }
@@ -19,14 +19,16 @@ use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 
-use arrow::array::ArrayRef;
+use arrow::array::{ArrayRef, AsArray, Int32Builder, MapBuilder, StringBuilder};
 use arrow::datatypes::{
-    DataType, Field, Fields, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit,
-    Schema, SchemaRef, TimeUnit, UnionFields, UnionMode,
+    i256, DataType, Field, Fields, Int64Type, IntervalDayTimeType,
+    IntervalMonthDayNanoType, IntervalUnit, Schema, SchemaRef, TimeUnit, UnionFields,
+    UnionMode,
 };
 
 use prost::Message;
 
+use datafusion::assert_batches_eq;
 use datafusion::datasource::provider::TableProviderFactory;
 use datafusion::datasource::TableProvider;
 use datafusion::execution::context::SessionState;
@@ -36,24 +38,30 @@ use datafusion::prelude::{create_udf, CsvReadOptions, SessionConfig, SessionCont
 use datafusion::test_util::{TestTableFactory, TestTableProvider};
 use datafusion_common::Result;
 use datafusion_common::{internal_err, not_impl_err, plan_err};
-use datafusion_common::{DFField, DFSchema, DFSchemaRef, DataFusionError, ScalarValue};
+use datafusion_common::{
+    Column, DFField, DFSchema, DFSchemaRef, DataFusionError, ScalarValue,
+};
 use datafusion_expr::expr::{
-    self, Between, BinaryExpr, Case, Cast, GroupingSet, InList, Like, ScalarFunction,
-    ScalarUDF, Sort,
+    self, Between, BinaryExpr, Case, Cast, GroupingSet, InList, Lambda, Like,
+    ScalarFunction, ScalarUDF, Sort,
 };
 use datafusion_expr::logical_plan::{Extension, UserDefinedLogicalNodeCore};
 use datafusion_expr::{
-    col, create_udaf, lit, Accumulator, AggregateFunction,
+    array_filter, col, create_udaf, create_udwf, lit, Accumulator,
+    AccumulatorFactoryFunction, AggregateFunction, AggregateUDF,
     BuiltinScalarFunction::{Sqrt, Substr},
-    Expr, LogicalPlan, Operator, PartitionEvaluator, Signature, TryCast, Volatility,
-    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowFunction, WindowUDF,
+    Expr, LogicalPlan, Operator, PartitionEvaluator, PartitionEvaluatorFactory,
+    Signature, TryCast, Volatility, WindowFrame, WindowFrameBound, WindowFrameUnits,
+    WindowFunction, WindowUDF,
 };
 use datafusion_proto::bytes::{
     logical_plan_from_bytes, logical_plan_from_bytes_with_extension_codec,
     logical_plan_to_bytes, logical_plan_to_bytes_with_extension_codec,
 };
-use datafusion_proto::logical_plan::LogicalExtensionCodec;
 use datafusion_proto::logical_plan::{from_proto, to_proto};
+use datafusion_proto::logical_plan::{
+    DefaultLogicalExtensionCodec, LogicalExtensionCodec,
+};
 use datafusion_proto::protobuf;
 
 #[cfg(feature = "json")]
@@ -338,6 +346,277 @@ async fn roundtrip_logical_plan_with_extension() -> Result<()> {
     Ok(())
 }
 
+/// Creates a scalar function adding a configured `offset` to its argument
+fn add_offset_udf(offset: i64) -> datafusion_expr::ScalarUDF {
+    let fun = make_scalar_function(move |args: &[ArrayRef]| {
+        let array = args[0].as_primitive::<Int64Type>();
+        Ok(Arc::new(array.unary::<_, Int64Type>(|v| v + offset)) as ArrayRef)
+    });
+    create_udf(
+        "add_offset",
+        vec![DataType::Int64],
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        fun,
+    )
+}
+
+/// Encodes the offset of `add_offset` functions alongside the plan
+#[derive(Debug)]
+struct AddOffsetCodec {
+    offset: i64,
+}
+
+impl LogicalExtensionCodec for AddOffsetCodec {
+    fn try_decode(
+        &self,
+        _buf: &[u8],
+        _inputs: &[LogicalPlan],
+        _ctx: &SessionContext,
+    ) -> Result<Extension> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_encode(&self, _node: &Extension, _buf: &mut Vec<u8>) -> Result<()> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_decode_table_provider(
+        &self,
+        _buf: &[u8],
+        _schema: SchemaRef,
+        _ctx: &SessionContext,
+    ) -> Result<Arc<dyn TableProvider>> {
+        not_impl_err!("No table provider codec provided")
+    }
+
+    fn try_encode_table_provider(
+        &self,
+        _node: Arc<dyn TableProvider>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        not_impl_err!("No table provider codec provided")
+    }
+
+    fn try_decode_udf(
+        &self,
+        name: &str,
+        buf: &[u8],
+    ) -> Result<Arc<datafusion_expr::ScalarUDF>> {
+        if name != "add_offset" {
+            return not_impl_err!("Unexpected scalar function {name}");
+        }
+        let offset = i64::from_le_bytes(buf.try_into().map_err(|_| {
+            DataFusionError::Internal("Error decoding add_offset".to_string())
+        })?);
+        Ok(Arc::new(add_offset_udf(offset)))
+    }
+
+    fn try_encode_udf(
+        &self,
+        node: &datafusion_expr::ScalarUDF,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        if node.name == "add_offset" {
+            buf.extend_from_slice(&self.offset.to_le_bytes());
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn roundtrip_logical_plan_with_udf_definition() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.register_csv("t1", "tests/testdata/test.csv", CsvReadOptions::default())
+        .await?;
+    ctx.register_udf(add_offset_udf(10));
+    let plan = ctx
+        .sql("SELECT add_offset(a) FROM t1")
+        .await?
+        .into_optimized_plan()?;
+
+    let codec = AddOffsetCodec { offset: 10 };
+    let bytes = logical_plan_to_bytes_with_extension_codec(&plan, &codec)?;
+
+    // add_offset is not registered, so its definition comes from the codec
+    let ctx = SessionContext::new();
+    let logical_round_trip =
+        logical_plan_from_bytes_with_extension_codec(&bytes, &ctx, &codec)?;
+    assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+
+    let batches = ctx
+        .execute_logical_plan(logical_round_trip)
+        .await?
+        .collect()
+        .await?;
+    let expected = [
+        "+------------------+",
+        "| add_offset(t1.a) |",
+        "+------------------+",
+        "| 11               |",
+        "| 13               |",
+        "+------------------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+    Ok(())
+}
+
+#[tokio::test]
+async fn roundtrip_logical_plan_with_unregistered_udf() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.register_csv("t1", "tests/testdata/test.csv", CsvReadOptions::default())
+        .await?;
+    ctx.register_udf(add_offset_udf(10));
+    let plan = ctx
+        .sql("SELECT add_offset(a) FROM t1")
+        .await?
+        .into_optimized_plan()?;
+    let bytes = logical_plan_to_bytes(&plan)?;
+
+    let err = logical_plan_from_bytes(&bytes, &SessionContext::new()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Scalar function add_offset was encoded by name only"),
+        "{err}"
+    );
+    Ok(())
+}
+
+/// Creates an aggregate function named `example_udaf`
+fn example_udaf() -> AggregateUDF {
+    let accumulator: AccumulatorFactoryFunction =
+        Arc::new(|_| not_impl_err!("example_udaf accumulator"));
+    create_udaf(
+        "example_udaf",
+        vec![DataType::Int64],
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        accumulator,
+        Arc::new(vec![DataType::Int64]),
+    )
+}
+
+/// Creates a window function named `example_udwf`
+fn example_udwf() -> WindowUDF {
+    let partition_evaluator_factory: PartitionEvaluatorFactory =
+        Arc::new(|| not_impl_err!("example_udwf evaluator"));
+    create_udwf(
+        "example_udwf",
+        DataType::Int64,
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        partition_evaluator_factory,
+    )
+}
+
+/// Encodes the definitions of `example_udaf` and `example_udwf` alongside the plan
+#[derive(Debug)]
+struct ExampleFunctionCodec {}
+
+impl LogicalExtensionCodec for ExampleFunctionCodec {
+    fn try_decode(
+        &self,
+        _buf: &[u8],
+        _inputs: &[LogicalPlan],
+        _ctx: &SessionContext,
+    ) -> Result<Extension> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_encode(&self, _node: &Extension, _buf: &mut Vec<u8>) -> Result<()> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_decode_table_provider(
+        &self,
+        _buf: &[u8],
+        _schema: SchemaRef,
+        _ctx: &SessionContext,
+    ) -> Result<Arc<dyn TableProvider>> {
+        not_impl_err!("No table provider codec provided")
+    }
+
+    fn try_encode_table_provider(
+        &self,
+        _node: Arc<dyn TableProvider>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        not_impl_err!("No table provider codec provided")
+    }
+
+    fn try_decode_udaf(&self, name: &str, buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        match (name, buf) {
+            ("example_udaf", b"udaf") => Ok(Arc::new(example_udaf())),
+            _ => not_impl_err!("Unexpected aggregate function {name}"),
+        }
+    }
+
+    fn try_encode_udaf(&self, node: &AggregateUDF, buf: &mut Vec<u8>) -> Result<()> {
+        if node.name == "example_udaf" {
+            buf.extend_from_slice(b"udaf");
+        }
+        Ok(())
+    }
+
+    fn try_decode_udwf(&self, name: &str, buf: &[u8]) -> Result<Arc<WindowUDF>> {
+        match (name, buf) {
+            ("example_udwf", b"udwf") => Ok(Arc::new(example_udwf())),
+            _ => not_impl_err!("Unexpected window function {name}"),
+        }
+    }
+
+    fn try_encode_udwf(&self, node: &WindowUDF, buf: &mut Vec<u8>) -> Result<()> {
+        if node.name == "example_udwf" {
+            buf.extend_from_slice(b"udwf");
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn roundtrip_aggregate_and_window_udfs_with_definition() {
+    let exprs = [
+        Expr::AggregateUDF(expr::AggregateUDF::new(
+            Arc::new(example_udaf()),
+            vec![col("a")],
+            None,
+            None,
+        )),
+        Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunction::AggregateUDF(Arc::new(example_udaf())),
+            vec![col("a")],
+            vec![col("b")],
+            vec![],
+            WindowFrame::new(false),
+        )),
+        Expr::WindowFunction(expr::WindowFunction::new(
+            WindowFunction::WindowUDF(Arc::new(example_udwf())),
+            vec![col("a")],
+            vec![col("b")],
+            vec![],
+            WindowFrame::new(false),
+        )),
+    ];
+
+    // Neither function is registered, so their definitions come from the codec
+    let ctx = SessionContext::new();
+    let codec = ExampleFunctionCodec {};
+    for expr in exprs {
+        let proto = to_proto::serialize_expr(&expr, &codec).unwrap();
+        let round_trip = from_proto::parse_expr_with_codec(&proto, &ctx, &codec).unwrap();
+        assert_eq!(format!("{expr:?}"), format!("{round_trip:?}"));
+
+        // Without the codec the functions must be registered
+        let proto =
+            to_proto::serialize_expr(&expr, &DefaultLogicalExtensionCodec {}).unwrap();
+        let err = from_proto::parse_expr(&proto, &ctx).unwrap_err();
+        assert!(
+            err.to_string().contains("was encoded by name only"),
+            "{err}"
+        );
+    }
+}
+
 #[tokio::test]
 async fn roundtrip_logical_plan_with_view_scan() -> Result<()> {
     let ctx = SessionContext::new();
@@ -456,7 +735,7 @@ impl LogicalExtensionCodec for TopKExtensionCodec {
                 let node = TopKPlanNode::new(
                     proto.k as usize,
                     input.clone(),
-                    from_proto::parse_expr(expr, ctx)?,
+                    from_proto::parse_expr_with_codec(expr, ctx, self)?,
                 );
 
                 Ok(Extension {
@@ -583,6 +862,14 @@ fn round_trip_scalar_values() {
         ScalarValue::UInt32(Some(0)),
         ScalarValue::UInt64(Some(u64::MAX)),
         ScalarValue::UInt64(Some(0)),
+        ScalarValue::Decimal128(Some(123_456), 20, 3),
+        ScalarValue::Decimal128(None, 20, 3),
+        ScalarValue::Decimal256(
+            Some(i256::from_i128(i128::MAX) * i256::from_i128(10)),
+            60,
+            12,
+        ),
+        ScalarValue::Decimal256(None, 60, 12),
         ScalarValue::Utf8(Some(String::from("Test string   "))),
         ScalarValue::LargeUtf8(Some(String::from("Test Large utf8"))),
         ScalarValue::Date32(Some(0)),
@@ -664,6 +951,22 @@ fn round_trip_scalar_values() {
             ],
             &DataType::List(new_arc_field("item", DataType::Float32, true)),
         )),
+        ScalarValue::Map({
+            let mut builder =
+                MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+            builder.keys().append_value("a");
+            builder.values().append_value(1);
+            builder.keys().append_value("b");
+            builder.values().append_null();
+            builder.append(true).unwrap();
+            Arc::new(builder.finish())
+        }),
+        ScalarValue::Map({
+            let mut builder =
+                MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+            builder.append(false).unwrap();
+            Arc::new(builder.finish())
+        }),
         ScalarValue::Dictionary(
             Box::new(DataType::Int32),
             Box::new(ScalarValue::Utf8(Some("foo".into()))),
@@ -796,6 +1099,7 @@ fn round_trip_datatype() {
         DataType::Utf8,
         DataType::LargeUtf8,
         DataType::Decimal128(7, 12),
+        DataType::Decimal256(60, 12),
         // Recursive list tests
         DataType::List(new_arc_field("Level1", DataType::Binary, true)),
         DataType::List(new_arc_field(
@@ -1153,6 +1457,34 @@ fn roundtrip_wildcard() {
     roundtrip_expr_test(test_expr, ctx);
 }
 
+#[test]
+fn roundtrip_lambda() {
+    let lambda = Expr::Lambda(Lambda::new(
+        vec![("x".to_string(), DataType::Int64)],
+        col("x").gt(lit(1i64)),
+    ));
+    let test_expr = array_filter(col("a"), lambda);
+
+    let ctx = SessionContext::new();
+    roundtrip_expr_test(test_expr, ctx);
+
+    // x -> x > t.b, which captures the column t.b
+    let lambda = Expr::Lambda(
+        Lambda::new(
+            vec![
+                ("x".to_string(), DataType::Int64),
+                ("t.b".to_string(), DataType::Int64),
+            ],
+            col("x").gt(Expr::Column(Column::from_name("t.b"))),
+        )
+        .with_captures(vec![col("t.b")]),
+    );
+    let test_expr = array_filter(col("t.a"), lambda);
+
+    let ctx = SessionContext::new();
+    roundtrip_expr_test(test_expr, ctx);
+}
+
 #[test]
 fn roundtrip_sqrt() {
     let test_expr = Expr::ScalarFunction(ScalarFunction::new(Sqrt, vec![col("col")]));
@@ -18,55 +18,90 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
-use datafusion::arrow::array::ArrayRef;
+use datafusion::arrow::array::{ArrayRef, AsArray, Int64Array, StringArray};
 use datafusion::arrow::compute::kernels::sort::SortOptions;
-use datafusion::arrow::datatypes::{DataType, Field, Fields, IntervalUnit, Schema};
+use datafusion::arrow::csv::WriterBuilder;
+use datafusion::arrow::datatypes::{
+    DataType, Field, Fields, Int64Type, IntervalUnit, Schema, SchemaRef,
+};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::file_format::csv::CsvSink;
+use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
 use datafusion::datasource::file_format::json::JsonSink;
+use datafusion::datasource::file_format::parquet::ParquetSink;
 use datafusion::datasource::file_format::write::FileWriterMode;
 use datafusion::datasource::listing::{ListingTableUrl, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::datasource::physical_plan::{
-    FileScanConfig, FileSinkConfig, ParquetExec,
+    ArrowExec, CsvExec, FileScanConfig, FileSinkConfig, NdJsonExec, ParquetExec,
 };
 use datafusion::execution::context::ExecutionProps;
+use datafusion::execution::{FunctionRegistry, TaskContext};
 use datafusion::logical_expr::{
-    create_udf, BuiltinScalarFunction, JoinType, Operator, Volatility,
+    create_udf, create_udwf, BuiltinScalarFunction, JoinType, Operator,
+    PartitionEvaluatorFactory, Volatility, WindowFunction,
+};
+use datafusion::parquet::basic::{Compression, Encoding, ZstdLevel};
+use datafusion::parquet::file::properties::{
+    EnabledStatistics, WriterProperties, WriterVersion,
 };
+use datafusion::parquet::schema::types::ColumnPath;
 use datafusion::physical_expr::window::SlidingAggregateWindowExpr;
-use datafusion::physical_expr::{PhysicalSortRequirement, ScalarFunctionExpr};
+use datafusion::physical_expr::{
+    udf, HigherOrderFunctionExpr, LambdaExpr, PhysicalSortRequirement, ScalarFunctionExpr,
+};
 use datafusion::physical_plan::aggregates::{
     AggregateExec, AggregateMode, PhysicalGroupBy,
 };
 use datafusion::physical_plan::analyze::AnalyzeExec;
 use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::expressions::{
-    binary, cast, col, in_list, like, lit, Avg, BinaryExpr, Column, DistinctCount,
-    GetFieldAccessExpr, GetIndexedFieldExpr, NotExpr, NthValue, PhysicalSortExpr, Sum,
+    binary, cast, col, in_list, like, lit, Avg, BinaryExpr, CollateExpr, Column,
+    DistinctCount, GetFieldAccessExpr, GetIndexedFieldExpr, NoOp, NotExpr, NthValue,
+    PhysicalSortExpr, Sum, UnKnownColumn,
 };
 use datafusion::physical_plan::filter::FilterExec;
 use datafusion::physical_plan::functions::make_scalar_function;
 use datafusion::physical_plan::insert::FileSinkExec;
-use datafusion::physical_plan::joins::{HashJoinExec, NestedLoopJoinExec, PartitionMode};
+use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
+use datafusion::physical_plan::joins::{
+    HashJoinExec, NestedLoopJoinExec, PartitionMode, SortMergeJoinExec,
+    StreamJoinPartitionMode, SymmetricHashJoinExec,
+};
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use datafusion::physical_plan::memory::MemoryExec;
 use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
+use datafusion::physical_plan::streaming::{PartitionStream, StreamingTableExec};
+use datafusion::physical_plan::union::InterleaveExec;
+use datafusion::physical_plan::unnest::UnnestExec;
+use datafusion::physical_plan::values::ValuesExec;
 use datafusion::physical_plan::windows::{
-    BuiltInWindowExpr, PlainAggregateWindowExpr, WindowAggExec,
+    create_window_expr, BuiltInWindowExpr, PlainAggregateWindowExpr, WindowAggExec,
 };
 use datafusion::physical_plan::{
-    functions, udaf, AggregateExpr, ExecutionPlan, PhysicalExpr, Statistics,
+    displayable, functions, udaf, AggregateExpr, EmptyRecordBatchStream, ExecutionPlan,
+    Partitioning, PhysicalExpr, SendableRecordBatchStream, Statistics,
 };
 use datafusion::prelude::SessionContext;
 use datafusion::scalar::ScalarValue;
+use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
+use datafusion_common::file_options::parquet_writer::ParquetWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
-use datafusion_common::{FileTypeWriterOptions, Result};
+use datafusion_common::{
+    not_impl_err, Collation, DataFusionError, FileTypeWriterOptions, JoinSide, Result,
+    UnnestOptions,
+};
 use datafusion_expr::{
     Accumulator, AccumulatorFactoryFunction, AggregateUDF, ReturnTypeFunction, Signature,
     StateTypeFunction, WindowFrame, WindowFrameBound,
 };
-use datafusion_proto::physical_plan::{AsExecutionPlan, DefaultPhysicalExtensionCodec};
+use datafusion_proto::physical_plan::{
+    AsExecutionPlan, DefaultPhysicalExtensionCodec, PhysicalExtensionCodec,
+};
 use datafusion_proto::protobuf;
 
 fn roundtrip_test(exec_plan: Arc<dyn ExecutionPlan>) -> Result<()> {
@@ -570,6 +605,106 @@ fn roundtrip_scalar_udf() -> Result<()> {
     roundtrip_test_with_context(Arc::new(project), ctx)
 }
 
+/// Creates a scalar function adding a configured `offset` to its argument
+fn add_offset_udf(offset: i64) -> datafusion_expr::ScalarUDF {
+    let fun = make_scalar_function(move |args: &[ArrayRef]| {
+        let array = args[0].as_primitive::<Int64Type>();
+        Ok(Arc::new(array.unary::<_, Int64Type>(|v| v + offset)) as ArrayRef)
+    });
+    create_udf(
+        "add_offset",
+        vec![DataType::Int64],
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        fun,
+    )
+}
+
+/// Encodes the offset of `add_offset` functions alongside the plan
+#[derive(Debug)]
+struct AddOffsetCodec {
+    offset: i64,
+}
+
+impl PhysicalExtensionCodec for AddOffsetCodec {
+    fn try_decode(
+        &self,
+        _buf: &[u8],
+        _inputs: &[Arc<dyn ExecutionPlan>],
+        _registry: &dyn FunctionRegistry,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_encode(
+        &self,
+        _node: Arc<dyn ExecutionPlan>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_decode_udf(
+        &self,
+        name: &str,
+        buf: &[u8],
+    ) -> Result<Arc<datafusion_expr::ScalarUDF>> {
+        if name != "add_offset" {
+            return not_impl_err!("Unexpected scalar function {name}");
+        }
+        let offset = i64::from_le_bytes(buf.try_into().map_err(|_| {
+            DataFusionError::Internal("Error decoding add_offset".to_string())
+        })?);
+        Ok(Arc::new(add_offset_udf(offset)))
+    }
+
+    fn try_encode_udf(
+        &self,
+        node: &datafusion_expr::ScalarUDF,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        if node.name == "add_offset" {
+            buf.extend_from_slice(&self.offset.to_le_bytes());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn roundtrip_scalar_udf_with_definition() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let input = Arc::new(EmptyExec::new(false, schema.clone()));
+
+    let expr =
+        udf::create_physical_expr(&add_offset_udf(10), &[col("a", &schema)?], &schema)?;
+    let project: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+        vec![(expr, "a".to_string())],
+        input,
+    )?);
+
+    let codec = AddOffsetCodec { offset: 10 };
+    let proto =
+        protobuf::PhysicalPlanNode::try_from_physical_plan(project.clone(), &codec)?;
+
+    // add_offset is not registered, so its definition comes from the codec
+    let ctx = SessionContext::new();
+    let runtime = ctx.runtime_env();
+    let result_exec_plan = proto.try_into_physical_plan(&ctx, runtime.deref(), &codec)?;
+    assert_eq!(format!("{project:?}"), format!("{result_exec_plan:?}"));
+
+    let projection = result_exec_plan
+        .as_any()
+        .downcast_ref::<ProjectionExec>()
+        .unwrap();
+    let batch = RecordBatch::try_new(
+        schema,
+        vec![Arc::new(Int64Array::from(vec![1, 3])) as ArrayRef],
+    )?;
+    let result = projection.expr()[0].0.evaluate(&batch)?.into_array(2)?;
+    assert_eq!(result.as_primitive::<Int64Type>().values(), &[11, 13]);
+    Ok(())
+}
+
 #[test]
 fn roundtrip_distinct_count() -> Result<()> {
     let field_a = Field::new("a", DataType::Int64, false);
@@ -596,6 +731,30 @@ fn roundtrip_distinct_count() -> Result<()> {
     )?))
 }
 
+#[test]
+fn roundtrip_distinct_window_aggregate() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, false);
+    let field_b = Field::new("b", DataType::Int64, false);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+
+    let distinct_window_expr = Arc::new(PlainAggregateWindowExpr::new(
+        Arc::new(DistinctCount::new(
+            DataType::Int64,
+            col("b", &schema)?,
+            "COUNT(DISTINCT b)".to_string(),
+        )),
+        &[col("a", &schema)?],
+        &[],
+        Arc::new(WindowFrame::new(false)),
+    ));
+
+    roundtrip_test(Arc::new(WindowAggExec::try_new(
+        vec![distinct_window_expr],
+        Arc::new(EmptyExec::new(false, schema.clone())),
+        vec![col("a", &schema)?],
+    )?))
+}
+
 #[test]
 fn roundtrip_like() -> Result<()> {
     let schema = Schema::new(vec![
@@ -617,6 +776,66 @@ fn roundtrip_like() -> Result<()> {
     roundtrip_test(plan)
 }
 
+#[test]
+fn roundtrip_collate() -> Result<()> {
+    let schema = Schema::new(vec![Field::new("a", DataType::Utf8, false)]);
+    let input = Arc::new(EmptyExec::new(false, Arc::new(schema.clone())));
+    let collate_expr = Arc::new(CollateExpr::new(col("a", &schema)?, Collation::NoCase));
+    let plan = Arc::new(ProjectionExec::try_new(
+        vec![(collate_expr, "a".to_string())],
+        input,
+    )?);
+    roundtrip_test(plan)
+}
+
+#[test]
+fn roundtrip_higher_order_function() -> Result<()> {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::new_list(DataType::Int64, true), true),
+        Field::new("b", DataType::Int64, true),
+    ]);
+    let input = Arc::new(EmptyExec::new(false, Arc::new(schema.clone())));
+    let params = Arc::new(Schema::new(vec![Field::new("x", DataType::Int64, true)]));
+    let body = binary(
+        col("x", &params)?,
+        Operator::Gt,
+        lit(ScalarValue::Int64(Some(1))),
+        &params,
+    )?;
+    let array_filter = Arc::new(HigherOrderFunctionExpr::try_new(
+        BuiltinScalarFunction::ArrayFilter,
+        vec![col("a", &schema)?],
+        LambdaExpr::new(params, body),
+        DataType::new_list(DataType::Int64, true),
+    )?);
+
+    // x -> x > b, which captures the column b
+    let params = Arc::new(Schema::new(vec![
+        Field::new("x", DataType::Int64, true),
+        Field::new("b", DataType::Int64, true),
+    ]));
+    let body = binary(
+        col("x", &params)?,
+        Operator::Gt,
+        col("b", &params)?,
+        &params,
+    )?;
+    let array_filter_captured = Arc::new(HigherOrderFunctionExpr::try_new(
+        BuiltinScalarFunction::ArrayFilter,
+        vec![col("a", &schema)?],
+        LambdaExpr::new(params, body).with_captures(vec![col("b", &schema)?]),
+        DataType::new_list(DataType::Int64, true),
+    )?);
+    let plan = Arc::new(ProjectionExec::try_new(
+        vec![
+            (array_filter, "filtered".to_string()),
+            (array_filter_captured, "filtered_captured".to_string()),
+        ],
+        input,
+    )?);
+    roundtrip_test(plan)
+}
+
 #[test]
 fn roundtrip_get_indexed_field_named_struct_field() -> Result<()> {
     let fields = vec![
@@ -756,3 +975,713 @@ fn roundtrip_json_sink() -> Result<()> {
         Some(sort_order),
     )))
 }
+
+#[test]
+fn roundtrip_csv_sink() -> Result<()> {
+    let field_a = Field::new("plan_type", DataType::Utf8, false);
+    let field_b = Field::new("plan", DataType::Utf8, false);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+    let input = Arc::new(EmptyExec::new(true, schema.clone()));
+
+    let file_sink_config = FileSinkConfig {
+        object_store_url: ObjectStoreUrl::local_filesystem(),
+        file_groups: vec![PartitionedFile::new("/tmp".to_string(), 1)],
+        table_paths: vec![ListingTableUrl::parse("file:///")?],
+        output_schema: schema.clone(),
+        table_partition_cols: vec![("plan_type".to_string(), DataType::Utf8)],
+        writer_mode: FileWriterMode::Put,
+        single_file_output: true,
+        unbounded_input: false,
+        overwrite: true,
+        file_type_writer_options: FileTypeWriterOptions::CSV(CsvWriterOptions::new(
+            WriterBuilder::new().with_header(false),
+            CompressionTypeVariant::GZIP,
+        )),
+    };
+    let data_sink = Arc::new(CsvSink::new(file_sink_config));
+
+    roundtrip_test(Arc::new(FileSinkExec::new(
+        input,
+        data_sink,
+        schema.clone(),
+        None,
+    )))
+}
+
+#[test]
+fn roundtrip_csv_sink_writer_options() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, false)]));
+    let file_sink_config = FileSinkConfig {
+        object_store_url: ObjectStoreUrl::local_filesystem(),
+        file_groups: vec![PartitionedFile::new("/tmp".to_string(), 1)],
+        table_paths: vec![ListingTableUrl::parse("file:///")?],
+        output_schema: schema,
+        table_partition_cols: vec![],
+        writer_mode: FileWriterMode::Put,
+        single_file_output: true,
+        unbounded_input: false,
+        overwrite: true,
+        file_type_writer_options: FileTypeWriterOptions::CSV(CsvWriterOptions::new(
+            WriterBuilder::new()
+                .with_delimiter(b'|')
+                .with_quote(b'\'')
+                .with_date_format("%d/%m/%Y".to_string())
+                .with_null("NULL".to_string()),
+            CompressionTypeVariant::UNCOMPRESSED,
+        )),
+    };
+
+    let proto = protobuf::FileSinkConfig::try_from(&file_sink_config)?;
+    let config = FileSinkConfig::try_from(&proto)?;
+    let options = &config
+        .file_type_writer_options
+        .try_into_csv()?
+        .writer_options;
+    assert!(options.header());
+    assert_eq!(options.delimiter(), b'|');
+    assert_eq!(options.quote(), b'\'');
+    assert_eq!(options.escape(), WriterBuilder::new().escape());
+    assert_eq!(options.date_format(), Some("%d/%m/%Y"));
+    assert_eq!(options.datetime_format(), None);
+    assert_eq!(options.null(), "NULL");
+    Ok(())
+}
+
+#[test]
+fn roundtrip_parquet_sink() -> Result<()> {
+    let field_a = Field::new("plan_type", DataType::Utf8, false);
+    let field_b = Field::new("plan", DataType::Utf8, false);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+    let input = Arc::new(EmptyExec::new(true, schema.clone()));
+
+    let writer_properties = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(ZstdLevel::try_new(3)?))
+        .set_writer_version(WriterVersion::PARQUET_2_0)
+        .set_max_row_group_size(1024)
+        .set_bloom_filter_enabled(true)
+        .build();
+    let file_sink_config = FileSinkConfig {
+        object_store_url: ObjectStoreUrl::local_filesystem(),
+        file_groups: vec![PartitionedFile::new("/tmp".to_string(), 1)],
+        table_paths: vec![ListingTableUrl::parse("file:///")?],
+        output_schema: schema.clone(),
+        table_partition_cols: vec![("plan_type".to_string(), DataType::Utf8)],
+        writer_mode: FileWriterMode::PutMultipart,
+        single_file_output: false,
+        unbounded_input: false,
+        overwrite: false,
+        file_type_writer_options: FileTypeWriterOptions::Parquet(
+            ParquetWriterOptions::new(writer_properties.clone()),
+        ),
+    };
+    // the sink's Debug output does not include the writer options
+    let proto = protobuf::FileSinkConfig::try_from(&file_sink_config)?;
+    let data_sink = Arc::new(ParquetSink::new(file_sink_config));
+    let sort_order = vec![PhysicalSortRequirement::new(
+        Arc::new(Column::new("plan_type", 0)),
+        Some(SortOptions {
+            descending: true,
+            nulls_first: false,
+        }),
+    )];
+
+    roundtrip_test(Arc::new(FileSinkExec::new(
+        input,
+        data_sink,
+        schema.clone(),
+        Some(sort_order),
+    )))?;
+
+    let config = FileSinkConfig::try_from(&proto)?;
+    let props = config
+        .file_type_writer_options
+        .try_into_parquet()?
+        .writer_options();
+    let column = ColumnPath::from("");
+    assert_eq!(
+        props.compression(&column),
+        writer_properties.compression(&column)
+    );
+    assert_eq!(props.writer_version(), WriterVersion::PARQUET_2_0);
+    assert_eq!(props.max_row_group_size(), 1024);
+    assert_eq!(
+        props
+            .bloom_filter_properties(&column)
+            .map(|b| (b.fpp, b.ndv)),
+        writer_properties
+            .bloom_filter_properties(&column)
+            .map(|b| (b.fpp, b.ndv))
+    );
+    assert_eq!(props.created_by(), writer_properties.created_by());
+    Ok(())
+}
+
+#[test]
+fn roundtrip_parquet_sink_column_writer_properties() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let a = ColumnPath::from("a");
+    let b = ColumnPath::from("b");
+    let writer_properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .set_column_encoding(a.clone(), Encoding::DELTA_BINARY_PACKED)
+        .set_column_compression(a.clone(), Compression::ZSTD(ZstdLevel::try_new(5)?))
+        .set_column_dictionary_enabled(a.clone(), false)
+        .set_column_statistics_enabled(a.clone(), EnabledStatistics::Chunk)
+        .set_column_bloom_filter_enabled(b.clone(), true)
+        .set_column_bloom_filter_ndv(b.clone(), 100)
+        .build();
+    let file_sink_config = FileSinkConfig {
+        object_store_url: ObjectStoreUrl::local_filesystem(),
+        file_groups: vec![PartitionedFile::new("/tmp".to_string(), 1)],
+        table_paths: vec![ListingTableUrl::parse("file:///")?],
+        output_schema: schema,
+        table_partition_cols: vec![],
+        writer_mode: FileWriterMode::PutMultipart,
+        single_file_output: false,
+        unbounded_input: false,
+        overwrite: false,
+        file_type_writer_options: FileTypeWriterOptions::Parquet(
+            ParquetWriterOptions::new(writer_properties.clone()),
+        ),
+    };
+
+    let proto = protobuf::FileSinkConfig::try_from(&file_sink_config)?;
+    let config = FileSinkConfig::try_from(&proto)?;
+    let props = config
+        .file_type_writer_options
+        .try_into_parquet()?
+        .writer_options();
+    for column in [ColumnPath::from(""), a, b] {
+        assert_eq!(props.encoding(&column), writer_properties.encoding(&column));
+        assert_eq!(
+            props.compression(&column),
+            writer_properties.compression(&column)
+        );
+        assert_eq!(
+            props.dictionary_enabled(&column),
+            writer_properties.dictionary_enabled(&column)
+        );
+        assert_eq!(
+            props.statistics_enabled(&column),
+            writer_properties.statistics_enabled(&column)
+        );
+        assert_eq!(
+            props
+                .bloom_filter_properties(&column)
+                .map(|b| (b.fpp, b.ndv)),
+            writer_properties
+                .bloom_filter_properties(&column)
+                .map(|b| (b.fpp, b.ndv))
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn roundtrip_sort_merge_join() -> Result<()> {
+    let field_a = Field::new("col", DataType::Int64, false);
+    let schema_left = Arc::new(Schema::new(vec![field_a.clone()]));
+    let schema_right = Arc::new(Schema::new(vec![field_a]));
+    let on = vec![(Column::new("col", 0), Column::new("col", 0))];
+
+    for join_type in &[
+        JoinType::Inner,
+        JoinType::Left,
+        JoinType::Right,
+        JoinType::Full,
+        JoinType::LeftAnti,
+        JoinType::LeftSemi,
+    ] {
+        roundtrip_test(Arc::new(SortMergeJoinExec::try_new(
+            Arc::new(EmptyExec::new(false, schema_left.clone())),
+            Arc::new(EmptyExec::new(false, schema_right.clone())),
+            on.clone(),
+            *join_type,
+            vec![SortOptions {
+                descending: true,
+                nulls_first: false,
+            }],
+            true,
+        )?))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn roundtrip_symmetric_hash_join() -> Result<()> {
+    let field_a = Field::new("col", DataType::Int64, false);
+    let schema_left = Arc::new(Schema::new(vec![field_a.clone()]));
+    let schema_right = Arc::new(Schema::new(vec![field_a]));
+    let on = vec![(Column::new("col", 0), Column::new("col", 0))];
+
+    let filter_schema = Schema::new(vec![
+        Field::new("l", DataType::Int64, false),
+        Field::new("r", DataType::Int64, false),
+    ]);
+    let filter = JoinFilter::new(
+        binary(
+            col("l", &filter_schema)?,
+            Operator::Gt,
+            col("r", &filter_schema)?,
+            &filter_schema,
+        )?,
+        vec![
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Right,
+            },
+        ],
+        filter_schema,
+    );
+
+    for join_type in &[JoinType::Inner, JoinType::Left, JoinType::Full] {
+        for partition_mode in &[
+            StreamJoinPartitionMode::Partitioned,
+            StreamJoinPartitionMode::SinglePartition,
+        ] {
+            roundtrip_test(Arc::new(SymmetricHashJoinExec::try_new(
+                Arc::new(EmptyExec::new(false, schema_left.clone())),
+                Arc::new(EmptyExec::new(false, schema_right.clone())),
+                on.clone(),
+                Some(filter.clone()),
+                join_type,
+                false,
+                *partition_mode,
+            )?))?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn roundtrip_unnest() -> Result<()> {
+    let list_field =
+        Field::new_list("a", Field::new("item", DataType::Int64, true), true);
+    let input_schema = Arc::new(Schema::new(vec![
+        list_field,
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let output_schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+
+    roundtrip_test(Arc::new(UnnestExec::new(
+        Arc::new(EmptyExec::new(false, input_schema)),
+        Column::new("a", 0),
+        output_schema,
+        UnnestOptions::new().with_preserve_nulls(false),
+    )))
+}
+
+#[test]
+fn roundtrip_values() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(StringArray::from(vec!["x", "y", "z"])),
+        ],
+    )?;
+
+    roundtrip_test(Arc::new(ValuesExec::try_new_from_batches(
+        schema,
+        vec![batch],
+    )?))
+}
+
+#[test]
+fn roundtrip_memory() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+            Arc::new(StringArray::from(vec!["x", "y", "z"])),
+        ],
+    )?;
+    let sort_information = vec![vec![PhysicalSortExpr {
+        expr: Arc::new(Column::new("b", 0)),
+        options: SortOptions::default(),
+    }]];
+
+    roundtrip_test(Arc::new(
+        MemoryExec::try_new(&[vec![batch.clone()], vec![batch]], schema, Some(vec![1]))?
+            .with_sort_information(sort_information),
+    ))
+}
+
+#[test]
+fn roundtrip_interleave() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let partitioning = Partitioning::Hash(vec![col("a", &schema)?], 3);
+    let inputs: Vec<Arc<dyn ExecutionPlan>> = (0..2)
+        .map(|_| {
+            let input = Arc::new(EmptyExec::new(false, schema.clone()));
+            Ok(Arc::new(RepartitionExec::try_new(input, partitioning.clone())?) as _)
+        })
+        .collect::<Result<_>>()?;
+
+    roundtrip_test(Arc::new(InterleaveExec::try_new(inputs)?))
+}
+
+#[test]
+fn roundtrip_file_scans() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("col", DataType::Utf8, false)]));
+    let scan_config = FileScanConfig {
+        object_store_url: ObjectStoreUrl::local_filesystem(),
+        file_schema: schema.clone(),
+        file_groups: vec![vec![PartitionedFile::new(
+            "/path/to/file".to_string(),
+            1024,
+        )]],
+        statistics: Statistics::new_unknown(&schema),
+        projection: None,
+        limit: None,
+        table_partition_cols: vec![],
+        output_ordering: vec![],
+        infinite_source: false,
+    };
+
+    roundtrip_test(Arc::new(NdJsonExec::new(
+        scan_config.clone(),
+        FileCompressionType::GZIP,
+    )))?;
+    roundtrip_test(Arc::new(ArrowExec::new(scan_config.clone())))?;
+    roundtrip_test(Arc::new(CsvExec::new(
+        scan_config,
+        true,
+        b',',
+        b'"',
+        None,
+        FileCompressionType::ZSTD,
+    )))
+}
+
+/// Creates a window function named `example_udwf`
+fn example_udwf() -> datafusion_expr::WindowUDF {
+    let partition_evaluator_factory: PartitionEvaluatorFactory =
+        Arc::new(|| not_impl_err!("example_udwf evaluator"));
+    create_udwf(
+        "example_udwf",
+        DataType::Int64,
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        partition_evaluator_factory,
+    )
+}
+
+/// Creates an aggregate function named `example_udaf`
+fn example_udaf() -> AggregateUDF {
+    let rt_func: ReturnTypeFunction = Arc::new(move |_| Ok(Arc::new(DataType::Int64)));
+    let accumulator: AccumulatorFactoryFunction =
+        Arc::new(|_| not_impl_err!("example_udaf accumulator"));
+    let st_func: StateTypeFunction =
+        Arc::new(move |_| Ok(Arc::new(vec![DataType::Int64])));
+    AggregateUDF::new(
+        "example_udaf",
+        &Signature::exact(vec![DataType::Int64], Volatility::Immutable),
+        &rt_func,
+        &accumulator,
+        &st_func,
+    )
+}
+
+fn window_udfs_plan(
+    udwf: Arc<datafusion_expr::WindowUDF>,
+    udaf: Arc<AggregateUDF>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Int64, false),
+    ]));
+
+    let window_exprs = vec![
+        create_window_expr(
+            &WindowFunction::WindowUDF(udwf),
+            "example_udwf(a)".to_string(),
+            &[col("a", &schema)?],
+            &[col("b", &schema)?],
+            &[],
+            Arc::new(WindowFrame::new(false)),
+            &schema,
+        )?,
+        create_window_expr(
+            &WindowFunction::AggregateUDF(udaf),
+            "example_udaf(a)".to_string(),
+            &[col("a", &schema)?],
+            &[col("b", &schema)?],
+            &[],
+            Arc::new(WindowFrame::new(false)),
+            &schema,
+        )?,
+    ];
+
+    Ok(Arc::new(WindowAggExec::try_new(
+        window_exprs,
+        Arc::new(EmptyExec::new(false, schema.clone())),
+        vec![col("b", &schema)?],
+    )?))
+}
+
+#[test]
+fn roundtrip_window_udfs() -> Result<()> {
+    let udwf = Arc::new(example_udwf());
+    let udaf = Arc::new(example_udaf());
+
+    let ctx = SessionContext::new();
+    ctx.register_udwf(udwf.as_ref().clone());
+    ctx.register_udaf(udaf.as_ref().clone());
+
+    roundtrip_test_with_context(window_udfs_plan(udwf, udaf)?, ctx)
+}
+
+/// Encodes the definitions of `example_udaf` and `example_udwf` alongside the plan
+#[derive(Debug)]
+struct ExampleFunctionCodec {}
+
+impl PhysicalExtensionCodec for ExampleFunctionCodec {
+    fn try_decode(
+        &self,
+        _buf: &[u8],
+        _inputs: &[Arc<dyn ExecutionPlan>],
+        _registry: &dyn FunctionRegistry,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_encode(
+        &self,
+        _node: Arc<dyn ExecutionPlan>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        not_impl_err!("No extension codec provided")
+    }
+
+    fn try_decode_udaf(&self, name: &str, buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        match (name, buf) {
+            ("example_udaf", b"udaf") => Ok(Arc::new(example_udaf())),
+            _ => not_impl_err!("Unexpected aggregate function {name}"),
+        }
+    }
+
+    fn try_encode_udaf(&self, node: &AggregateUDF, buf: &mut Vec<u8>) -> Result<()> {
+        if node.name == "example_udaf" {
+            buf.extend_from_slice(b"udaf");
+        }
+        Ok(())
+    }
+
+    fn try_decode_udwf(
+        &self,
+        name: &str,
+        buf: &[u8],
+    ) -> Result<Arc<datafusion_expr::WindowUDF>> {
+        match (name, buf) {
+            ("example_udwf", b"udwf") => Ok(Arc::new(example_udwf())),
+            _ => not_impl_err!("Unexpected window function {name}"),
+        }
+    }
+
+    fn try_encode_udwf(
+        &self,
+        node: &datafusion_expr::WindowUDF,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        if node.name == "example_udwf" {
+            buf.extend_from_slice(b"udwf");
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn roundtrip_aggregate_and_window_udfs_with_definition() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Int64, false),
+    ]));
+    let udaf = Arc::new(example_udaf());
+    let aggregate: Arc<dyn ExecutionPlan> = Arc::new(AggregateExec::try_new(
+        AggregateMode::Final,
+        PhysicalGroupBy::new_single(vec![(col("a", &schema)?, "a".to_string())]),
+        vec![udaf::create_aggregate_expr(
+            &udaf,
+            &[col("b", &schema)?],
+            &schema,
+            "example_udaf(b)",
+        )?],
+        vec![None],
+        vec![None],
+        Arc::new(EmptyExec::new(false, schema.clone())),
+        schema,
+    )?);
+    let window = window_udfs_plan(Arc::new(example_udwf()), udaf)?;
+
+    // Neither function is registered, so their definitions come from the codec
+    let ctx = SessionContext::new();
+    let runtime = ctx.runtime_env();
+    let codec = ExampleFunctionCodec {};
+    for plan in [aggregate, window] {
+        let proto =
+            protobuf::PhysicalPlanNode::try_from_physical_plan(plan.clone(), &codec)?;
+        let result_exec_plan =
+            proto.try_into_physical_plan(&ctx, runtime.deref(), &codec)?;
+        assert_eq!(format!("{plan:?}"), format!("{result_exec_plan:?}"));
+    }
+
+    // Without the codec the functions must be registered
+    let proto = protobuf::PhysicalPlanNode::try_from_physical_plan(
+        window_udfs_plan(Arc::new(example_udwf()), Arc::new(example_udaf()))?,
+        &DefaultPhysicalExtensionCodec {},
+    )?;
+    let err = proto
+        .try_into_physical_plan(&ctx, runtime.deref(), &DefaultPhysicalExtensionCodec {})
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Window function example_udwf was encoded by name only"),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn roundtrip_unknown_column_and_no_op() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let exprs: Vec<(Arc<dyn PhysicalExpr>, String)> = vec![
+        (
+            Arc::new(UnKnownColumn::new("missing")),
+            "missing".to_string(),
+        ),
+        (Arc::new(NoOp::new()), "no_op".to_string()),
+    ];
+
+    roundtrip_test(Arc::new(ProjectionExec::try_new(
+        exprs,
+        Arc::new(EmptyExec::new(false, schema)),
+    )?))
+}
+
+/// A partition stream without rows
+struct EmptyPartition(SchemaRef);
+
+impl PartitionStream for EmptyPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.0
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        Box::pin(EmptyRecordBatchStream::new(self.0.clone()))
+    }
+}
+
+/// Encodes the projection and boundedness of a `StreamingTableExec`, whose
+/// partition streams are recreated when decoding
+#[derive(Debug)]
+struct StreamingTableCodec {
+    schema: SchemaRef,
+}
+
+impl PhysicalExtensionCodec for StreamingTableCodec {
+    fn try_decode(
+        &self,
+        buf: &[u8],
+        _inputs: &[Arc<dyn ExecutionPlan>],
+        _registry: &dyn FunctionRegistry,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let Some((infinite, projection)) = buf.split_first() else {
+            return not_impl_err!("Empty StreamingTableExec");
+        };
+        let projection = projection.iter().map(|i| *i as usize).collect();
+        Ok(Arc::new(StreamingTableExec::try_new(
+            self.schema.clone(),
+            vec![Arc::new(EmptyPartition(self.schema.clone()))],
+            Some(&projection),
+            None,
+            *infinite == 1,
+        )?))
+    }
+
+    fn try_encode(&self, node: Arc<dyn ExecutionPlan>, buf: &mut Vec<u8>) -> Result<()> {
+        let Some(exec) = node.as_any().downcast_ref::<StreamingTableExec>() else {
+            return not_impl_err!("Unexpected plan {node:?}");
+        };
+        buf.push(exec.is_infinite() as u8);
+        buf.extend(
+            exec.projection()
+                .unwrap_or_default()
+                .iter()
+                .map(|i| *i as u8),
+        );
+        Ok(())
+    }
+}
+
+fn streaming_table(schema: &SchemaRef) -> Result<Arc<dyn ExecutionPlan>> {
+    Ok(Arc::new(StreamingTableExec::try_new(
+        schema.clone(),
+        vec![Arc::new(EmptyPartition(schema.clone()))],
+        Some(&vec![1]),
+        None,
+        true,
+    )?))
+}
+
+#[test]
+fn roundtrip_streaming_table_with_codec() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let exec_plan = streaming_table(&schema)?;
+
+    let codec = StreamingTableCodec { schema };
+    let proto =
+        protobuf::PhysicalPlanNode::try_from_physical_plan(exec_plan.clone(), &codec)?;
+    let ctx = SessionContext::new();
+    let runtime = ctx.runtime_env();
+    let result_exec_plan = proto.try_into_physical_plan(&ctx, runtime.deref(), &codec)?;
+    assert_eq!(
+        displayable(exec_plan.as_ref()).indent(true).to_string(),
+        displayable(result_exec_plan.as_ref())
+            .indent(true)
+            .to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn roundtrip_streaming_table_without_codec() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let codec = DefaultPhysicalExtensionCodec {};
+    let err = protobuf::PhysicalPlanNode::try_from_physical_plan(
+        streaming_table(&schema)?,
+        &codec,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().starts_with(
+            "This feature is not implemented: StreamingTableExec can only be serialized"
+        ),
+        "{err}"
+    );
+    Ok(())
+}
@@ -0,0 +1,144 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use datafusion_common::{
+    not_impl_err, plan_err, Collation, DFSchema, DataFusionError, Result, ScalarValue,
+};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::{lit, BinaryExpr, BuiltinScalarFunction, Expr, Operator};
+use sqlparser::ast::{Expr as SQLExpr, ObjectName};
+
+impl<'a, S: ContextProvider> SqlToRel<'a, S> {
+    /// Plans `expr COLLATE name` as `expr` marked with the collation, e.g.
+    /// `name COLLATE nocase` becomes `collate(name, 'nocase')`.
+    ///
+    /// The values of `expr` are unchanged. Comparing, sorting, grouping and
+    /// joining on it honors the collation, see [`collate_operands`] for how
+    /// it is applied to the other operands of a comparison.
+    pub(super) fn sql_collate_to_expr(
+        &self,
+        expr: SQLExpr,
+        collation: ObjectName,
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Expr> {
+        let collation = match collation.0.as_slice() {
+            [name] => name.value.parse::<Collation>()?,
+            _ => return not_impl_err!("Unsupported collation {collation}"),
+        };
+        let expr = self.sql_expr_to_logical_expr(expr, schema, planner_context)?;
+        // the outermost COLLATE wins, e.g. `a COLLATE nocase COLLATE binary`
+        let expr = match expr {
+            Expr::ScalarFunction(ScalarFunction {
+                fun: BuiltinScalarFunction::Collate,
+                mut args,
+            }) => args.swap_remove(0),
+            expr => expr,
+        };
+        Ok(collate(expr, collation))
+    }
+}
+
+/// Returns `expr` marked with `collation`
+fn collate(expr: Expr, collation: Collation) -> Expr {
+    Expr::ScalarFunction(ScalarFunction::new(
+        BuiltinScalarFunction::Collate,
+        vec![expr, lit(collation.to_string())],
+    ))
+}
+
+/// Returns the explicit collation of `expr`, if any
+fn collation_of(expr: &Expr) -> Option<Collation> {
+    match expr {
+        Expr::ScalarFunction(ScalarFunction {
+            fun: BuiltinScalarFunction::Collate,
+            args,
+        }) => match &args[1] {
+            Expr::Literal(ScalarValue::Utf8(Some(name))) => name.parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns true if `op` compares its operands under their collation
+fn is_collated_operator(op: &Operator) -> bool {
+    matches!(
+        op,
+        Operator::Eq
+            | Operator::NotEq
+            | Operator::Lt
+            | Operator::LtEq
+            | Operator::Gt
+            | Operator::GtEq
+            | Operator::IsDistinctFrom
+            | Operator::IsNotDistinctFrom
+    )
+}
+
+/// Applies the explicit collation of any of the compared `operands` to all
+/// of them, so that `a COLLATE nocase = 'ABC'` compares both sides under
+/// `nocase`.
+///
+/// Errors if the operands have different explicit collations.
+pub(super) fn collate_operands(operands: Vec<Expr>) -> Result<Vec<Expr>> {
+    let mut collation = None;
+    for operand in &operands {
+        match (collation, collation_of(operand)) {
+            (Some(c), Some(other)) if c != other => {
+                return plan_err!(
+                    "Collation mismatch between explicit collations {c} and {other}"
+                );
+            }
+            (None, Some(c)) => collation = Some(c),
+            _ => {}
+        }
+    }
+
+    match collation {
+        Some(collation) => Ok(operands
+            .into_iter()
+            .map(|operand| match collation_of(&operand) {
+                Some(_) => operand,
+                None => collate(operand, collation),
+            })
+            .collect()),
+        None => Ok(operands),
+    }
+}
+
+/// Builds `left op right`, comparing both operands under their explicit
+/// collation if `op` is a comparison
+pub(super) fn collated_binary_expr(
+    left: Expr,
+    op: Operator,
+    right: Expr,
+) -> Result<Expr> {
+    let (left, right) = if is_collated_operator(&op) {
+        let mut operands = collate_operands(vec![left, right])?;
+        let right = operands.pop().unwrap();
+        (operands.pop().unwrap(), right)
+    } else {
+        (left, right)
+    };
+    Ok(Expr::BinaryExpr(BinaryExpr::new(
+        Box::new(left),
+        op,
+        Box::new(right),
+    )))
+}
@@ -32,6 +32,7 @@ use sqlparser::ast::{
 use std::str::FromStr;
 
 use super::arrow_cast::ARROW_CAST_NAME;
+use super::lambda::sql_lambda;
 
 impl<'a, S: ContextProvider> SqlToRel<'a, S> {
     pub(super) fn sql_function_to_expr(
@@ -71,7 +72,8 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
 
         // next, scalar built-in
         if let Ok(fun) = BuiltinScalarFunction::from_str(&name) {
-            let args = self.function_args_to_expr(args, schema, planner_context)?;
+            let args =
+                self.builtin_function_args_to_expr(fun, args, schema, planner_context)?;
             return Ok(Expr::ScalarFunction(ScalarFunction::new(fun, args)));
         };
 
@@ -221,6 +223,51 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
     }
 
+    /// Plans the arguments of a built-in function, where the lambda argument
+    /// of a higher-order function such as `array_transform` is planned
+    /// against its parameters
+    fn builtin_function_args_to_expr(
+        &self,
+        fun: BuiltinScalarFunction,
+        args: Vec<FunctionArg>,
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Vec<Expr>> {
+        let higher_order = matches!(
+            fun,
+            BuiltinScalarFunction::ArrayTransform
+                | BuiltinScalarFunction::ArrayFilter
+                | BuiltinScalarFunction::ArrayReduce
+                | BuiltinScalarFunction::ArrayAnyMatch
+                | BuiltinScalarFunction::ArrayAllMatch
+                | BuiltinScalarFunction::ArraySort
+        );
+        if !higher_order {
+            return self.function_args_to_expr(args, schema, planner_context);
+        }
+
+        let mut exprs = Vec::with_capacity(args.len());
+        for arg in args {
+            let lambda = match &arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)) => sql_lambda(arg),
+                _ => None,
+            };
+            let expr = match lambda {
+                Some((params, body)) => self.sql_lambda_to_expr(
+                    fun,
+                    params,
+                    body,
+                    &mut exprs,
+                    schema,
+                    planner_context,
+                )?,
+                None => self.sql_fn_arg_to_logical_expr(arg, schema, planner_context)?,
+            };
+            exprs.push(expr);
+        }
+        Ok(exprs)
+    }
+
     pub(super) fn function_args_to_expr(
         &self,
         args: Vec<FunctionArg>,
@@ -16,16 +16,74 @@
 // under the License.
 
 use crate::planner::{ContextProvider, SqlToRel};
-use datafusion_common::{not_impl_err, DataFusionError, Result};
-use datafusion_expr::Operator;
+use datafusion_common::{not_impl_err, plan_err, DataFusionError, Result, ScalarValue};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::{lit, BuiltinScalarFunction, Expr, Operator};
 use sqlparser::ast::JsonOperator;
 
+/// How a SQL JSON operator is planned
+pub(crate) enum JsonAccess {
+    /// A binary operator such as `@>`
+    Operator(Operator),
+    /// A JSON function call such as `json_get` for `->`. When `path` is
+    /// true the right operand is a Postgres style path like `'{a,0,b}'`
+    Function {
+        fun: BuiltinScalarFunction,
+        path: bool,
+    },
+}
+
 impl<'a, S: ContextProvider> SqlToRel<'a, S> {
-    pub(crate) fn parse_sql_json_access(&self, op: JsonOperator) -> Result<Operator> {
-        match op {
-            JsonOperator::AtArrow => Ok(Operator::AtArrow),
-            JsonOperator::ArrowAt => Ok(Operator::ArrowAt),
-            _ => not_impl_err!("Unsupported SQL json operator {op:?}"),
+    pub(crate) fn parse_sql_json_access(&self, op: JsonOperator) -> Result<JsonAccess> {
+        let (fun, path) = match op {
+            JsonOperator::AtArrow => return Ok(JsonAccess::Operator(Operator::AtArrow)),
+            JsonOperator::ArrowAt => return Ok(JsonAccess::Operator(Operator::ArrowAt)),
+            JsonOperator::Arrow => (BuiltinScalarFunction::JsonGet, false),
+            JsonOperator::LongArrow => (BuiltinScalarFunction::JsonGetStr, false),
+            JsonOperator::HashArrow => (BuiltinScalarFunction::JsonGet, true),
+            JsonOperator::HashLongArrow => (BuiltinScalarFunction::JsonGetStr, true),
+            _ => return not_impl_err!("Unsupported SQL json operator {op:?}"),
+        };
+        Ok(JsonAccess::Function { fun, path })
+    }
+
+    /// Builds the function call for a JSON access operator, e.g.
+    /// `payload #>> '{a,0}'` becomes `json_get_str(payload, 'a', '0')`
+    pub(crate) fn json_access_to_expr(
+        &self,
+        fun: BuiltinScalarFunction,
+        path: bool,
+        left: Expr,
+        right: Expr,
+    ) -> Result<Expr> {
+        let mut args = vec![left];
+        if path {
+            match right {
+                Expr::Literal(ScalarValue::Utf8(Some(s))) => {
+                    let Some(inner) =
+                        s.trim().strip_prefix('{').and_then(|s| s.strip_suffix('}'))
+                    else {
+                        return plan_err!(
+                            "JSON path must be a literal of the form '{{a,b}}', got '{s}'"
+                        );
+                    };
+                    args.extend(
+                        inner
+                            .split(',')
+                            .map(|key| key.trim())
+                            .filter(|key| !key.is_empty())
+                            .map(|key| lit(key.trim_matches('"'))),
+                    );
+                }
+                other => {
+                    return plan_err!(
+                        "JSON path must be a string literal of the form '{{a,b}}', got {other}"
+                    )
+                }
+            }
+        } else {
+            args.push(right);
         }
+        Ok(Expr::ScalarFunction(ScalarFunction::new(fun, args)))
     }
 }
@@ -0,0 +1,192 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use arrow_schema::DataType;
+use datafusion_common::tree_node::{Transformed, TreeNode};
+use datafusion_common::{plan_err, Column, DFField, DFSchema, DataFusionError, Result};
+use datafusion_expr::expr::Lambda;
+use datafusion_expr::utils::{expr_to_columns, find_out_reference_exprs};
+use datafusion_expr::{BuiltinScalarFunction, Expr, ExprSchemable};
+use sqlparser::ast::{Expr as SQLExpr, Ident, JsonOperator};
+use std::collections::{HashMap, HashSet};
+
+/// Returns the parameters and body of a lambda such as `x -> x + 1` or
+/// `(acc, x) -> acc + x`, which `sqlparser` parses as a JSON access
+pub(super) fn sql_lambda(expr: &SQLExpr) -> Option<(Vec<Ident>, SQLExpr)> {
+    let SQLExpr::JsonAccess {
+        left,
+        operator: JsonOperator::Arrow,
+        right,
+    } = expr
+    else {
+        return None;
+    };
+    let params = match left.as_ref() {
+        SQLExpr::Identifier(ident) => vec![ident.clone()],
+        SQLExpr::Nested(param) => match param.as_ref() {
+            SQLExpr::Identifier(ident) => vec![ident.clone()],
+            _ => return None,
+        },
+        SQLExpr::Tuple(params) => params
+            .iter()
+            .map(|param| match param {
+                SQLExpr::Identifier(ident) => Some(ident.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    Some((params, right.as_ref().clone()))
+}
+
+impl<'a, S: ContextProvider> SqlToRel<'a, S> {
+    /// Plans the lambda argument of the higher-order function `fun`, given
+    /// the arguments before it. The parameters are typed from the elements
+    /// of the list argument, and for `array_reduce` the accumulator from the
+    /// initial value, which is cast if the body returns a different type.
+    pub(super) fn sql_lambda_to_expr(
+        &self,
+        fun: BuiltinScalarFunction,
+        params: Vec<Ident>,
+        body: SQLExpr,
+        args: &mut [Expr],
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Expr> {
+        let element_type = match args.first().map(|arg| arg.get_type(schema)) {
+            Some(Ok(
+                DataType::List(field)
+                | DataType::LargeList(field)
+                | DataType::FixedSizeList(field, _),
+            )) => field.data_type().clone(),
+            Some(Ok(DataType::Null)) => DataType::Null,
+            Some(Ok(other)) => {
+                return plan_err!(
+                    "The {fun} function can only accept a list as the first argument, got {other}"
+                )
+            }
+            Some(Err(e)) => return Err(e),
+            None => return plan_err!("The {fun} function requires a list argument"),
+        };
+        let param_types = match fun {
+            BuiltinScalarFunction::ArrayReduce => match args.get(1) {
+                Some(initial) => vec![initial.get_type(schema)?, element_type],
+                None => {
+                    return plan_err!(
+                        "The {fun} function requires an initial value before the lambda"
+                    )
+                }
+            },
+            BuiltinScalarFunction::ArraySort => vec![element_type.clone(), element_type],
+            BuiltinScalarFunction::ArrayTransform
+            | BuiltinScalarFunction::ArrayFilter
+            | BuiltinScalarFunction::ArrayAnyMatch
+            | BuiltinScalarFunction::ArrayAllMatch => vec![element_type],
+            _ => return plan_err!("The {fun} function does not accept a lambda"),
+        };
+        if params.len() != param_types.len() {
+            return plan_err!(
+                "The lambda of the {fun} function takes {} parameters, got {}",
+                param_types.len(),
+                params.len()
+            );
+        }
+        let params = params
+            .into_iter()
+            .map(|param| self.normalizer.normalize(param))
+            .zip(param_types)
+            .collect();
+
+        let mut lambda =
+            self.plan_lambda(params, body.clone(), schema, planner_context)?;
+        if fun == BuiltinScalarFunction::ArrayReduce {
+            // widen the accumulator to the type of the body, e.g. to
+            // float for `(acc, x) -> acc + x` with an integer initial value
+            let acc_type = lambda.params[0].1.clone();
+            let body_type = lambda.body.get_type(&lambda.params_schema()?)?;
+            if body_type != acc_type && body_type != DataType::Null {
+                let mut params = lambda.declared_params().to_vec();
+                params[0].1 = body_type.clone();
+                lambda = self.plan_lambda(params, body, schema, planner_context)?;
+                args[1] = args[1].clone().cast_to(&body_type, schema)?;
+            }
+        }
+        Ok(Expr::Lambda(lambda))
+    }
+
+    /// Plans the body of a lambda against its parameters and the columns of
+    /// `schema`, which the parameters shadow. The columns of `schema` that
+    /// the body references are captured as additional parameters, named
+    /// after the column as written, e.g. `t.threshold`.
+    fn plan_lambda(
+        &self,
+        params: Vec<(String, DataType)>,
+        body: SQLExpr,
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Lambda> {
+        let mut fields = params
+            .iter()
+            .map(|(name, data_type)| {
+                DFField::new_unqualified(name, data_type.clone(), true)
+            })
+            .collect::<Vec<_>>();
+        let params_schema = DFSchema::new_with_metadata(fields.clone(), HashMap::new())?;
+        fields.extend(
+            schema
+                .fields()
+                .iter()
+                .filter(|field| !params.iter().any(|(name, _)| name == field.name()))
+                .cloned(),
+        );
+        let body_schema = DFSchema::new_with_metadata(fields, HashMap::new())?;
+        let body = self.sql_expr_to_logical_expr(body, &body_schema, planner_context)?;
+
+        if let Some(expr) = find_out_reference_exprs(&body).first() {
+            return plan_err!(
+                "A lambda may not reference the columns of an outer query, got {expr}"
+            );
+        }
+
+        let mut columns = HashSet::new();
+        expr_to_columns(&body, &mut columns)?;
+        let mut captured = columns
+            .into_iter()
+            .filter(|column| !params_schema.has_column(column))
+            .collect::<Vec<_>>();
+        captured.sort_by_key(|column| column.flat_name());
+
+        let mut params = params;
+        let mut captures = Vec::with_capacity(captured.len());
+        for column in &captured {
+            let field = schema.field_from_column(column)?;
+            params.push((column.flat_name(), field.data_type().clone()));
+            captures.push(Expr::Column(field.qualified_column()));
+        }
+        // the body refers to the captured columns as unqualified parameters
+        let body = body.transform_up(&|expr| {
+            Ok(match expr {
+                Expr::Column(column) if captured.contains(&column) => {
+                    Transformed::Yes(Expr::Column(Column::from_name(column.flat_name())))
+                }
+                _ => Transformed::No(expr),
+            })
+        })?;
+        Ok(Lambda::new(params, body).with_captures(captures))
+    }
+}
@@ -17,18 +17,23 @@
 
 pub(crate) mod arrow_cast;
 mod binary_op;
+mod collate;
 mod function;
 mod grouping_set;
 mod identifier;
 mod json_access;
+mod lambda;
 mod order_by;
 mod subquery;
 mod substring;
 mod unary_op;
 mod value;
 
+use crate::expr::collate::{collate_operands, collated_binary_expr};
+use crate::expr::json_access::JsonAccess;
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
-use arrow_schema::DataType;
+use arrow_array::timezone::Tz;
+use arrow_schema::{DataType, TimeUnit};
 use datafusion_common::{
     internal_err, not_impl_err, plan_err, Column, DFSchema, DataFusionError, Result,
     ScalarValue,
@@ -36,11 +41,12 @@ use datafusion_common::{
 use datafusion_expr::expr::InList;
 use datafusion_expr::expr::ScalarFunction;
 use datafusion_expr::{
-    col, expr, lit, AggregateFunction, Between, BinaryExpr, BuiltinScalarFunction, Cast,
-    Expr, ExprSchemable, GetFieldAccess, GetIndexedField, Like, Operator, TryCast,
+    col, expr, lit, AggregateFunction, Between, BuiltinScalarFunction, Cast, Expr,
+    ExprSchemable, GetFieldAccess, GetIndexedField, Like, Operator, TryCast,
 };
 use sqlparser::ast::{ArrayAgg, Expr as SQLExpr, JsonOperator, TrimWhereField, Value};
 use sqlparser::parser::ParserError::ParserError;
+use std::str::FromStr;
 
 impl<'a, S: ContextProvider> SqlToRel<'a, S> {
     pub(crate) fn sql_expr_to_logical_expr(
@@ -52,6 +58,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         enum StackEntry {
             SQLExpr(Box<SQLExpr>),
             Operator(Operator),
+            JsonFunction(BuiltinScalarFunction, bool),
         }
 
         // Virtual stack machine to convert SQLExpr to Expr
@@ -78,8 +85,13 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                             operator,
                             right,
                         } => {
-                            let op = self.parse_sql_json_access(operator)?;
-                            stack.push(StackEntry::Operator(op));
+                            let entry = match self.parse_sql_json_access(operator)? {
+                                JsonAccess::Operator(op) => StackEntry::Operator(op),
+                                JsonAccess::Function { fun, path } => {
+                                    StackEntry::JsonFunction(fun, path)
+                                }
+                            };
+                            stack.push(entry);
                             stack.push(StackEntry::SQLExpr(right));
                             stack.push(StackEntry::SQLExpr(left));
                         }
@@ -96,11 +108,13 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 StackEntry::Operator(op) => {
                     let right = eval_stack.pop().unwrap();
                     let left = eval_stack.pop().unwrap();
-                    let expr = Expr::BinaryExpr(BinaryExpr::new(
-                        Box::new(left),
-                        op,
-                        Box::new(right),
-                    ));
+                    let expr = collated_binary_expr(left, op, right)?;
+                    eval_stack.push(expr);
+                }
+                StackEntry::JsonFunction(fun, path) => {
+                    let right = eval_stack.pop().unwrap();
+                    let left = eval_stack.pop().unwrap();
+                    let expr = self.json_access_to_expr(fun, path, left, right)?;
                     eval_stack.push(expr);
                 }
             }
@@ -233,6 +247,27 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 self.convert_data_type(&data_type)?,
             ))),
 
+            SQLExpr::Collate { expr, collation } => {
+                self.sql_collate_to_expr(*expr, collation, schema, planner_context)
+            }
+
+            SQLExpr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => {
+                Tz::from_str(&time_zone)?;
+                let expr =
+                    self.sql_expr_to_logical_expr(*timestamp, schema, planner_context)?;
+                let unit = match expr.get_type(schema)? {
+                    DataType::Timestamp(unit, _) => unit,
+                    _ => TimeUnit::Nanosecond,
+                };
+                Ok(Expr::Cast(Cast::new(
+                    Box::new(expr),
+                    DataType::Timestamp(unit, Some(time_zone.into())),
+                )))
+            }
+
             SQLExpr::TryCast {
                 expr, data_type, ..
             } => Ok(Expr::TryCast(TryCast::new(
@@ -257,37 +292,17 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 self.sql_expr_to_logical_expr(*expr, schema, planner_context)?,
             ))),
 
-            SQLExpr::IsDistinctFrom(left, right) => {
-                Ok(Expr::BinaryExpr(BinaryExpr::new(
-                    Box::new(self.sql_expr_to_logical_expr(
-                        *left,
-                        schema,
-                        planner_context,
-                    )?),
-                    Operator::IsDistinctFrom,
-                    Box::new(self.sql_expr_to_logical_expr(
-                        *right,
-                        schema,
-                        planner_context,
-                    )?),
-                )))
-            }
+            SQLExpr::IsDistinctFrom(left, right) => collated_binary_expr(
+                self.sql_expr_to_logical_expr(*left, schema, planner_context)?,
+                Operator::IsDistinctFrom,
+                self.sql_expr_to_logical_expr(*right, schema, planner_context)?,
+            ),
 
-            SQLExpr::IsNotDistinctFrom(left, right) => {
-                Ok(Expr::BinaryExpr(BinaryExpr::new(
-                    Box::new(self.sql_expr_to_logical_expr(
-                        *left,
-                        schema,
-                        planner_context,
-                    )?),
-                    Operator::IsNotDistinctFrom,
-                    Box::new(self.sql_expr_to_logical_expr(
-                        *right,
-                        schema,
-                        planner_context,
-                    )?),
-                )))
-            }
+            SQLExpr::IsNotDistinctFrom(left, right) => collated_binary_expr(
+                self.sql_expr_to_logical_expr(*left, schema, planner_context)?,
+                Operator::IsNotDistinctFrom,
+                self.sql_expr_to_logical_expr(*right, schema, planner_context)?,
+            ),
 
             SQLExpr::IsTrue(expr) => Ok(Expr::IsTrue(Box::new(
                 self.sql_expr_to_logical_expr(*expr, schema, planner_context)?,
@@ -322,20 +337,22 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 negated,
                 low,
                 high,
-            } => Ok(Expr::Between(Between::new(
-                Box::new(self.sql_expr_to_logical_expr(
-                    *expr,
-                    schema,
-                    planner_context,
-                )?),
-                negated,
-                Box::new(self.sql_expr_to_logical_expr(*low, schema, planner_context)?),
-                Box::new(self.sql_expr_to_logical_expr(
-                    *high,
-                    schema,
-                    planner_context,
-                )?),
-            ))),
+            } => {
+                let mut operands = collate_operands(vec![
+                    self.sql_expr_to_logical_expr(*expr, schema, planner_context)?,
+                    self.sql_expr_to_logical_expr(*low, schema, planner_context)?,
+                    self.sql_expr_to_logical_expr(*high, schema, planner_context)?,
+                ])?;
+                let high = operands.pop().unwrap();
+                let low = operands.pop().unwrap();
+                let expr = operands.pop().unwrap();
+                Ok(Expr::Between(Between::new(
+                    Box::new(expr),
+                    negated,
+                    Box::new(low),
+                    Box::new(high),
+                )))
+            }
 
             SQLExpr::InList {
                 expr,
@@ -559,14 +576,19 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         schema: &DFSchema,
         planner_context: &mut PlannerContext,
     ) -> Result<Expr> {
-        let list_expr = list
-            .into_iter()
-            .map(|e| self.sql_expr_to_logical_expr(e, schema, planner_context))
+        let expr = self.sql_expr_to_logical_expr(expr, schema, planner_context)?;
+        let operands = std::iter::once(Ok(expr))
+            .chain(
+                list.into_iter()
+                    .map(|e| self.sql_expr_to_logical_expr(e, schema, planner_context)),
+            )
             .collect::<Result<Vec<_>>>()?;
+        let mut operands = collate_operands(operands)?.into_iter();
+        let expr = operands.next().unwrap();
 
         Ok(Expr::InList(InList::new(
-            Box::new(self.sql_expr_to_logical_expr(expr, schema, planner_context)?),
-            list_expr,
+            Box::new(expr),
+            operands.collect(),
             negated,
         )))
     }
@@ -587,9 +609,13 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         if pattern_type != DataType::Utf8 && pattern_type != DataType::Null {
             return plan_err!("Invalid pattern in LIKE expression");
         }
+        let expr = self.sql_expr_to_logical_expr(expr, schema, planner_context)?;
+        let mut operands = collate_operands(vec![expr, pattern])?;
+        let pattern = operands.pop().unwrap();
+        let expr = operands.pop().unwrap();
         Ok(Expr::Like(Like::new(
             negated,
-            Box::new(self.sql_expr_to_logical_expr(expr, schema, planner_context)?),
+            Box::new(expr),
             Box::new(pattern),
             escape_char,
             case_insensitive,
@@ -735,6 +761,15 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             expr
         };
 
+        // map subscripts look up keys of any type
+        if let Ok(DataType::Map(_, _)) = expr.get_type(schema) {
+            let key = self.sql_expr_to_logical_expr(indices, schema, planner_context)?;
+            return Ok(Expr::ScalarFunction(ScalarFunction::new(
+                BuiltinScalarFunction::ElementAt,
+                vec![expr, key],
+            )));
+        }
+
         Ok(Expr::GetIndexedField(GetIndexedField::new(
             Box::new(expr),
             self.plan_indices(indices, schema, planner_context)?,
@@ -18,7 +18,7 @@
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use arrow::array::new_null_array;
 use arrow::compute::kernels::cast_utils::parse_interval_month_day_nano;
-use arrow::datatypes::DECIMAL128_MAX_PRECISION;
+use arrow::datatypes::{i256, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION};
 use arrow_schema::DataType;
 use datafusion_common::{
     not_impl_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue,
@@ -81,7 +81,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
 
         if self.options.parse_float_as_decimal {
-            parse_decimal_128(unsigned_number, negative)
+            parse_decimal(unsigned_number, negative)
         } else {
             signed_number.parse::<f64>().map(lit).map_err(|_| {
                 DataFusionError::from(ParserError(format!(
@@ -394,10 +394,11 @@ const fn try_decode_hex_char(c: u8) -> Option<u8> {
     }
 }
 
-/// Parse Decimal128 from a string
+/// Parse Decimal128 or Decimal256 from a string, depending on the number of
+/// digits it has
 ///
 /// TODO: support parsing from scientific notation
-fn parse_decimal_128(unsigned_number: &str, negative: bool) -> Result<Expr> {
+fn parse_decimal(unsigned_number: &str, negative: bool) -> Result<Expr> {
     // remove leading zeroes
     let trimmed = unsigned_number.trim_start_matches('0');
     // parse precision and scale, remove decimal point if exists
@@ -415,24 +416,39 @@ fn parse_decimal_128(unsigned_number: &str, negative: bool) -> Result<Expr> {
         (trimmed.len(), 0, Cow::Borrowed(trimmed))
     };
 
-    let number = replaced_str.parse::<i128>().map_err(|e| {
-        DataFusionError::from(ParserError(format!(
-            "Cannot parse {replaced_str} as i128 when building decimal: {e}"
+    if precision <= DECIMAL128_MAX_PRECISION as usize {
+        let number = replaced_str.parse::<i128>().map_err(|e| {
+            DataFusionError::from(ParserError(format!(
+                "Cannot parse {replaced_str} as i128 when building decimal: {e}"
+            )))
+        })?;
+
+        Ok(Expr::Literal(ScalarValue::Decimal128(
+            Some(if negative { -number } else { number }),
+            precision as u8,
+            scale as i8,
         )))
-    })?;
-
-    // check precision overflow
-    if precision as u8 > DECIMAL128_MAX_PRECISION {
-        return Err(DataFusionError::from(ParserError(format!(
-            "Cannot parse {replaced_str} as i128 when building decimal: precision overflow"
-        ))));
+    } else if precision <= DECIMAL256_MAX_PRECISION as usize {
+        let number = i256::from_string(&replaced_str).ok_or_else(|| {
+            DataFusionError::from(ParserError(format!(
+                "Cannot parse {replaced_str} as i256 when building decimal"
+            )))
+        })?;
+
+        Ok(Expr::Literal(ScalarValue::Decimal256(
+            Some(if negative {
+                number.wrapping_neg()
+            } else {
+                number
+            }),
+            precision as u8,
+            scale as i8,
+        )))
+    } else {
+        Err(DataFusionError::from(ParserError(format!(
+            "Cannot parse {replaced_str} as i256 when building decimal: precision overflow"
+        ))))
     }
-
-    Ok(Expr::Literal(ScalarValue::Decimal128(
-        Some(if negative { -number } else { number }),
-        precision as u8,
-        scale as i8,
-    )))
 }
 
 #[cfg(test)]
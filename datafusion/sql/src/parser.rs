@@ -18,7 +18,10 @@
 //! [`DFParser`]: DataFusion SQL Parser based on [`sqlparser`]
 
 use datafusion_common::parsers::CompressionTypeVariant;
-use sqlparser::ast::{OrderByExpr, Query, Value};
+use sqlparser::ast::{
+    visit_expressions_mut, Expr as SQLExpr, FunctionArg, FunctionArgExpr, JsonOperator,
+    OrderByExpr, Query, Value, VisitMut,
+};
 use sqlparser::tokenizer::Word;
 use sqlparser::{
     ast::{
@@ -31,6 +34,7 @@ use sqlparser::{
 };
 use std::collections::VecDeque;
 use std::fmt;
+use std::ops::ControlFlow;
 use std::{collections::HashMap, str::FromStr};
 
 // Use `Parser::expected` instead, if possible
@@ -220,6 +224,25 @@ pub struct DescribeTableStmt {
     pub table_name: ObjectName,
 }
 
+/// DataFusion extension DDL for `REFRESH MATERIALIZED VIEW`
+///
+/// Syntax:
+///
+/// ```text
+/// REFRESH MATERIALIZED VIEW <VIEW_NAME>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshMaterializedViewStatement {
+    /// View name
+    pub name: ObjectName,
+}
+
+impl fmt::Display for RefreshMaterializedViewStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW {}", self.name)
+    }
+}
+
 /// DataFusion SQL Statement.
 ///
 /// This can either be a [`Statement`] from [`sqlparser`] from a
@@ -239,6 +262,8 @@ pub enum Statement {
     CopyTo(CopyToStatement),
     /// EXPLAIN for extensions
     Explain(ExplainStatement),
+    /// Extension: `REFRESH MATERIALIZED VIEW`
+    RefreshMaterializedView(RefreshMaterializedViewStatement),
 }
 
 impl fmt::Display for Statement {
@@ -249,6 +274,7 @@ impl fmt::Display for Statement {
             Statement::DescribeTableStmt(_) => write!(f, "DESCRIBE TABLE ..."),
             Statement::CopyTo(stmt) => write!(f, "{stmt}"),
             Statement::Explain(stmt) => write!(f, "{stmt}"),
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}"),
         }
     }
 }
@@ -261,6 +287,11 @@ impl fmt::Display for Statement {
 /// `sqlparser`. However, certain statements such as `COPY` and
 /// `CREATE EXTERNAL TABLE` have special syntax in DataFusion. See
 /// [`Statement`] for a list of this special syntax
+///
+/// The time travel clauses `VERSION AS OF <expr>` and
+/// `FOR SYSTEM_TIME AS OF <expr>` may follow the name of a table in the
+/// `FROM` clause or a join in any dialect. They are parsed as the table
+/// arguments `(version => <expr>)` and `(system_time => <expr>)`.
 pub struct DFParser<'a> {
     parser: Parser<'a>,
 }
@@ -280,7 +311,7 @@ impl<'a> DFParser<'a> {
         dialect: &'a dyn Dialect,
     ) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = rewrite_map_literals(rewrite_time_travel(tokenizer.tokenize()?));
 
         Ok(DFParser {
             parser: Parser::new(dialect).with_tokens(tokens),
@@ -354,23 +385,31 @@ impl<'a> DFParser<'a> {
                         self.parser.next_token(); // EXPLAIN
                         self.parse_explain()
                     }
+                    _ if w.value.eq_ignore_ascii_case("REFRESH") => {
+                        self.parser.next_token(); // REFRESH
+                        self.parse_refresh()
+                    }
                     _ => {
                         // use sqlparser-rs parser
-                        Ok(Statement::Statement(Box::from(
-                            self.parser.parse_statement()?,
-                        )))
+                        self.parse_sql_statement()
                     }
                 }
             }
             _ => {
                 // use the native parser
-                Ok(Statement::Statement(Box::from(
-                    self.parser.parse_statement()?,
-                )))
+                self.parse_sql_statement()
             }
         }
     }
 
+    /// Parse a statement with the `sqlparser` parser, then the lambda
+    /// arguments of higher-order functions in it
+    fn parse_sql_statement(&mut self) -> Result<Statement, ParserError> {
+        let mut statement = self.parser.parse_statement()?;
+        parse_lambdas(&mut statement);
+        Ok(Statement::Statement(Box::from(statement)))
+    }
+
     /// Parse a SQL `DESCRIBE` statement
     pub fn parse_describe(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parser.parse_object_name()?;
@@ -379,11 +418,22 @@ impl<'a> DFParser<'a> {
         }))
     }
 
+    /// Parse a SQL `REFRESH MATERIALIZED VIEW` statement
+    pub fn parse_refresh(&mut self) -> Result<Statement, ParserError> {
+        self.parser.expect_keyword(Keyword::MATERIALIZED)?;
+        self.parser.expect_keyword(Keyword::VIEW)?;
+        let name = self.parser.parse_object_name()?;
+        Ok(Statement::RefreshMaterializedView(
+            RefreshMaterializedViewStatement { name },
+        ))
+    }
+
     /// Parse a SQL `COPY TO` statement
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
         // parse as a query
         let source = if self.parser.consume_token(&Token::LParen) {
-            let query = self.parser.parse_query()?;
+            let mut query = self.parser.parse_query()?;
+            parse_lambdas(&mut query);
             self.parser.expect_token(&Token::RParen)?;
             CopyToSource::Query(query)
         } else {
@@ -473,7 +523,9 @@ impl<'a> DFParser<'a> {
             self.parser.expect_keyword(Keyword::EXTERNAL)?;
             self.parse_create_external_table(true)
         } else {
-            Ok(Statement::Statement(Box::from(self.parser.parse_create()?)))
+            let mut statement = self.parser.parse_create()?;
+            parse_lambdas(&mut statement);
+            Ok(Statement::Statement(Box::from(statement)))
         }
     }
 
@@ -836,6 +888,461 @@ impl<'a> DFParser<'a> {
     }
 }
 
+/// Keywords ending the expression of a time travel clause
+const TIME_TRAVEL_END_KEYWORDS: &[Keyword] = &[
+    Keyword::AS,
+    Keyword::WHERE,
+    Keyword::JOIN,
+    Keyword::INNER,
+    Keyword::LEFT,
+    Keyword::RIGHT,
+    Keyword::FULL,
+    Keyword::OUTER,
+    Keyword::CROSS,
+    Keyword::NATURAL,
+    Keyword::ON,
+    Keyword::USING,
+    Keyword::GROUP,
+    Keyword::ORDER,
+    Keyword::LIMIT,
+    Keyword::OFFSET,
+    Keyword::UNION,
+    Keyword::EXCEPT,
+    Keyword::INTERSECT,
+    Keyword::HAVING,
+    Keyword::WINDOW,
+    Keyword::QUALIFY,
+];
+
+/// Keywords ending the `FROM` clause of a query
+const FROM_END_KEYWORDS: &[Keyword] = &[
+    Keyword::SELECT,
+    Keyword::WHERE,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::WINDOW,
+    Keyword::QUALIFY,
+    Keyword::ORDER,
+    Keyword::LIMIT,
+    Keyword::OFFSET,
+    Keyword::FETCH,
+    Keyword::UNION,
+    Keyword::EXCEPT,
+    Keyword::INTERSECT,
+];
+
+/// Returns the index of the first token at or after `index` that is not
+/// whitespace
+fn skip_whitespace(tokens: &[Token], mut index: usize) -> usize {
+    while let Some(Token::Whitespace(_)) = tokens.get(index) {
+        index += 1;
+    }
+    index
+}
+
+/// If the words `clause` start at `tokens[start]`, ignoring whitespace,
+/// returns the index of the token after them
+fn match_words(tokens: &[Token], start: usize, clause: &[&str]) -> Option<usize> {
+    let mut index = start;
+    for expected in clause {
+        index = skip_whitespace(tokens, index);
+        match tokens.get(index) {
+            Some(Token::Word(word))
+                if word.quote_style.is_none()
+                    && word.value.eq_ignore_ascii_case(expected) =>
+            {
+                index += 1
+            }
+            _ => return None,
+        }
+    }
+    Some(index)
+}
+
+/// If an object name such as `s.t` starts at `tokens[start]`, returns the
+/// index of the token after it
+fn match_object_name(tokens: &[Token], start: usize) -> Option<usize> {
+    let Some(Token::Word(_)) = tokens.get(start) else {
+        return None;
+    };
+    let mut end = start + 1;
+    loop {
+        let period = skip_whitespace(tokens, end);
+        if !matches!(tokens.get(period), Some(Token::Period)) {
+            return Some(end);
+        }
+        let part = skip_whitespace(tokens, period + 1);
+        match tokens.get(part) {
+            Some(Token::Word(_)) => end = part + 1,
+            _ => return Some(end),
+        }
+    }
+}
+
+/// If a time travel clause `VERSION AS OF <expr>` or
+/// `FOR SYSTEM_TIME AS OF <expr>` starts at `tokens[start]`, returns the
+/// table arguments `(version => <expr>)` or `(system_time => <expr>)` it is
+/// parsed as, and the index of the token after the clause
+fn match_time_travel(tokens: &[Token], start: usize) -> Option<(Vec<Token>, usize)> {
+    let (arg_name, expr_start) = match_words(tokens, start, &["VERSION", "AS", "OF"])
+        .map(|end| ("version", end))
+        .or_else(|| {
+            match_words(tokens, start, &["FOR", "SYSTEM_TIME", "AS", "OF"])
+                .map(|end| ("system_time", end))
+        })?;
+
+    // the expression ends at the first keyword or delimiter that is not
+    // nested in parentheses
+    let mut expr_end = expr_start;
+    let mut depth = 0;
+    while let Some(token) = tokens.get(expr_end) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen if depth == 0 => break,
+            Token::RParen => depth -= 1,
+            Token::Comma | Token::SemiColon | Token::EOF if depth == 0 => break,
+            Token::Word(word)
+                if depth == 0
+                    && word.quote_style.is_none()
+                    && TIME_TRAVEL_END_KEYWORDS.contains(&word.keyword) =>
+            {
+                break
+            }
+            _ => {}
+        }
+        expr_end += 1;
+    }
+    let expr = &tokens[expr_start..expr_end];
+    if expr
+        .iter()
+        .all(|token| matches!(token, Token::Whitespace(_)))
+    {
+        // leave the missing expression to be reported by the parser
+        return None;
+    }
+
+    let mut args = vec![
+        Token::LParen,
+        Token::make_word(arg_name, None),
+        Token::RArrow,
+    ];
+    args.extend_from_slice(expr);
+    args.push(Token::RParen);
+    Some((args, expr_end))
+}
+
+/// Where relations are expected at one level of parentheses, while scanning
+/// the tokens of a statement for time travel clauses
+#[derive(Debug, Default, Clone, Copy)]
+struct RelationScope {
+    /// In a `FROM` clause, where a comma starts another relation
+    in_from: bool,
+    /// The next word starts the name of a relation
+    expect_relation: bool,
+}
+
+/// Rewrites the time travel clauses `VERSION AS OF <expr>` and
+/// `FOR SYSTEM_TIME AS OF <expr>` that follow the name of a relation in a
+/// `FROM` clause or a join to the table arguments `(version => <expr>)` and
+/// `(system_time => <expr>)`, as `sqlparser` only parses the latter for some
+/// dialects. The same words anywhere else, such as after a column named
+/// `version`, are left to the parser.
+fn rewrite_time_travel(tokens: Vec<Token>) -> Vec<Token> {
+    let mut rewritten = Vec::with_capacity(tokens.len());
+    // the scope of each level of parentheses
+    let mut scopes = vec![RelationScope::default()];
+    let mut index = 0;
+    while index < tokens.len() {
+        let keyword = match &tokens[index] {
+            Token::Word(word) if word.quote_style.is_none() => word.keyword,
+            _ => Keyword::NoKeyword,
+        };
+        let scope = scopes
+            .last_mut()
+            .expect("the outermost scope is never popped");
+        match &tokens[index] {
+            Token::Whitespace(_) => {}
+            Token::LParen => {
+                // a subquery or the arguments of a table function
+                scope.expect_relation = false;
+                scopes.push(RelationScope::default());
+            }
+            Token::RParen => {
+                if scopes.len() > 1 {
+                    scopes.pop();
+                }
+            }
+            Token::Comma => scope.expect_relation = scope.in_from,
+            Token::SemiColon => *scope = RelationScope::default(),
+            Token::Word(_) if keyword == Keyword::FROM => {
+                scope.in_from = true;
+                scope.expect_relation = true;
+            }
+            Token::Word(_) if keyword == Keyword::JOIN => scope.expect_relation = true,
+            Token::Word(_) if keyword == Keyword::LATERAL => {}
+            Token::Word(_) if FROM_END_KEYWORDS.contains(&keyword) => {
+                *scope = RelationScope::default()
+            }
+            Token::Word(_) if scope.expect_relation => {
+                scope.expect_relation = false;
+                let clause = match_object_name(&tokens, index).and_then(|name_end| {
+                    match_time_travel(&tokens, name_end)
+                        .map(|(args, end)| (name_end, args, end))
+                });
+                if let Some((name_end, args, end)) = clause {
+                    rewritten.extend_from_slice(&tokens[index..name_end]);
+                    rewritten.extend(args);
+                    index = end;
+                    continue;
+                }
+            }
+            _ => scope.expect_relation = false,
+        }
+        rewritten.push(tokens[index].clone());
+        index += 1;
+    }
+    rewritten
+}
+
+/// Splits `tokens` at each `delimiter` that is not nested in parentheses,
+/// brackets or braces
+fn split_top_level<'t>(tokens: &'t [Token], delimiter: &Token) -> Vec<&'t [Token]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::LParen | Token::LBracket | Token::LBrace => depth += 1,
+            Token::RParen | Token::RBracket | Token::RBrace => depth -= 1,
+            token if depth == 0 && token == delimiter => {
+                parts.push(&tokens[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts
+}
+
+/// Returns true if an expression may start after `previous`, the last
+/// token that is not whitespace. It can not after a token ending an
+/// operand, such as a name or a literal, or the `.` of a compound name.
+fn starts_expression(previous: Option<&Token>) -> bool {
+    !matches!(
+        previous,
+        Some(
+            Token::Period
+                | Token::RParen
+                | Token::RBracket
+                | Token::RBrace
+                | Token::Number(_, _)
+                | Token::SingleQuotedString(_)
+                | Token::DoubleQuotedString(_)
+                | Token::Word(Word {
+                    keyword: Keyword::NoKeyword,
+                    ..
+                })
+                | Token::Word(Word {
+                    quote_style: Some(_),
+                    ..
+                })
+        )
+    )
+}
+
+/// Rewrites the map literal `MAP {k1: v1, k2: v2}` to
+/// `map(make_array(k1, k2), make_array(v1, v2))`, as `sqlparser` does not
+/// parse map literals. Only a `MAP` starting an expression is rewritten,
+/// and only if its braces hold `key: value` pairs.
+fn rewrite_map_literals(tokens: Vec<Token>) -> Vec<Token> {
+    let mut rewritten: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut index = 0;
+    while index < tokens.len() {
+        let previous = rewritten
+            .iter()
+            .rev()
+            .find(|token| !matches!(token, Token::Whitespace(_)));
+        let brace = starts_expression(previous)
+            .then(|| match_words(&tokens, index, &["MAP"]))
+            .flatten()
+            .map(|brace| skip_whitespace(&tokens, brace))
+            .filter(|&brace| matches!(tokens.get(brace), Some(Token::LBrace)));
+        // the matching closing brace
+        let end = brace.and_then(|brace| {
+            let mut depth = 0;
+            (brace..tokens.len()).find(|&end| {
+                match tokens[end] {
+                    Token::LBrace => depth += 1,
+                    Token::RBrace => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+        });
+        let (Some(brace), Some(end)) = (brace, end) else {
+            rewritten.push(tokens[index].clone());
+            index += 1;
+            continue;
+        };
+
+        let entries = &tokens[brace + 1..end];
+        let entries = if entries
+            .iter()
+            .all(|token| matches!(token, Token::Whitespace(_)))
+        {
+            Some(vec![])
+        } else {
+            split_top_level(entries, &Token::Comma)
+                .into_iter()
+                .map(|entry| match split_top_level(entry, &Token::Colon)[..] {
+                    [key, value] => Some((key, value)),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+        };
+        let Some(entries) = entries else {
+            // leave the malformed literal to be reported by the parser
+            rewritten.push(tokens[index].clone());
+            index += 1;
+            continue;
+        };
+
+        let make_array = |parts: Vec<&[Token]>| {
+            let mut tokens = vec![Token::make_word("make_array", None), Token::LParen];
+            for (i, part) in parts.into_iter().enumerate() {
+                if i > 0 {
+                    tokens.push(Token::Comma);
+                }
+                // map literals may be nested in keys and values
+                tokens.extend(rewrite_map_literals(part.to_vec()));
+            }
+            tokens.push(Token::RParen);
+            tokens
+        };
+        rewritten.push(Token::make_word("map", None));
+        rewritten.push(Token::LParen);
+        rewritten.extend(make_array(entries.iter().map(|(key, _)| *key).collect()));
+        rewritten.push(Token::Comma);
+        rewritten.extend(make_array(
+            entries.iter().map(|(_, value)| *value).collect(),
+        ));
+        rewritten.push(Token::RParen);
+        index = end + 1;
+    }
+    rewritten
+}
+
+/// Functions whose arguments may be lambdas such as `x -> x + 1`
+const LAMBDA_FUNCTIONS: &[&str] = &[
+    "array_transform",
+    "list_transform",
+    "array_filter",
+    "list_filter",
+    "array_reduce",
+    "list_reduce",
+    "aggregate",
+    "array_any_match",
+    "any_match",
+    "array_all_match",
+    "all_match",
+    "array_sort",
+    "list_sort",
+];
+
+/// Returns true if `expr` is the parameters of a lambda, `x`, `(x)` or
+/// `(x, y)`
+fn is_lambda_params(expr: &SQLExpr) -> bool {
+    match expr {
+        SQLExpr::Identifier(_) => true,
+        SQLExpr::Nested(param) => matches!(param.as_ref(), SQLExpr::Identifier(_)),
+        SQLExpr::Tuple(params) => params
+            .iter()
+            .all(|param| matches!(param, SQLExpr::Identifier(_))),
+        _ => false,
+    }
+}
+
+/// If the leftmost operand of `expr` is `params -> right`, replaces it with
+/// `right` and returns `params`
+fn take_lambda_params(expr: &mut SQLExpr) -> Option<SQLExpr> {
+    if let SQLExpr::JsonAccess {
+        left,
+        operator: JsonOperator::Arrow,
+        right,
+    } = expr
+    {
+        if is_lambda_params(left) {
+            let params = left.as_ref().clone();
+            let right = right.as_ref().clone();
+            *expr = right;
+            return Some(params);
+        }
+    }
+    match expr {
+        SQLExpr::JsonAccess { left, .. }
+        | SQLExpr::BinaryOp { left, .. }
+        | SQLExpr::IsDistinctFrom(left, _)
+        | SQLExpr::IsNotDistinctFrom(left, _) => take_lambda_params(left),
+        SQLExpr::IsNull(operand)
+        | SQLExpr::IsNotNull(operand)
+        | SQLExpr::IsTrue(operand)
+        | SQLExpr::IsNotTrue(operand)
+        | SQLExpr::IsFalse(operand)
+        | SQLExpr::IsNotFalse(operand)
+        | SQLExpr::IsUnknown(operand)
+        | SQLExpr::IsNotUnknown(operand)
+        | SQLExpr::Between { expr: operand, .. }
+        | SQLExpr::InList { expr: operand, .. }
+        | SQLExpr::Like { expr: operand, .. }
+        | SQLExpr::ILike { expr: operand, .. }
+        | SQLExpr::Cast { expr: operand, .. } => take_lambda_params(operand),
+        _ => None,
+    }
+}
+
+/// Parses each lambda argument of a higher-order function in `node` as
+/// `params -> (body)`, e.g. `array_transform(a, x -> x + 1)` as
+/// `array_transform(a, x -> (x + 1))`.
+///
+/// `sqlparser` parses `->` as a JSON operator that binds tighter than the
+/// operators in the body, so the argument `x -> x + 1` is parsed as
+/// `(x -> x) + 1`: the lambda is its leftmost operand, and the body is the
+/// argument with that operand replaced by its right side. The lambda is
+/// then planned from the `JsonAccess` expression.
+fn parse_lambdas<V: VisitMut>(node: &mut V) {
+    let _ = visit_expressions_mut(node, |expr| {
+        let SQLExpr::Function(function) = expr else {
+            return ControlFlow::<()>::Continue(());
+        };
+        let name = function.name.to_string();
+        if !LAMBDA_FUNCTIONS
+            .iter()
+            .any(|lambda_function| name.eq_ignore_ascii_case(lambda_function))
+        {
+            return ControlFlow::Continue(());
+        }
+        for arg in function.args.iter_mut() {
+            let FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)) = arg else {
+                continue;
+            };
+            let mut body = arg.clone();
+            if let Some(params) = take_lambda_params(&mut body) {
+                let body = match body {
+                    SQLExpr::Nested(_) => body,
+                    body => SQLExpr::Nested(Box::new(body)),
+                };
+                *arg = SQLExpr::JsonAccess {
+                    left: Box::new(params),
+                    operator: JsonOperator::Arrow,
+                    right: Box::new(body),
+                };
+            }
+        }
+        ControlFlow::Continue(())
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1456,6 +1963,103 @@ mod tests {
 
     // For error cases, see: `copy.slt`
 
+    #[test]
+    fn refresh_materialized_view() -> Result<(), ParserError> {
+        let sql = "REFRESH MATERIALIZED VIEW my_schema.my_view";
+        let expected =
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStatement {
+                name: ObjectName(vec![Ident::new("my_schema"), Ident::new("my_view")]),
+            });
+        assert_eq!(verified_stmt(sql), expected);
+
+        // keywords are case insensitive
+        one_statement_parses_to(
+            "refresh materialized view my_view",
+            "REFRESH MATERIALIZED VIEW my_view",
+        );
+
+        let sql = "REFRESH VIEW my_view";
+        assert!(DFParser::parse_sql(sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn time_travel() {
+        one_statement_parses_to(
+            "SELECT * FROM t VERSION AS OF 3 AS v WHERE v.a > 1",
+            "SELECT * FROM t(version => 3) AS v WHERE v.a > 1",
+        );
+        one_statement_parses_to(
+            "select * from s.t for system_time as of timestamp '2023-01-01 00:00:00' join u on t.a = u.a",
+            "SELECT * FROM s.t(system_time => TIMESTAMP '2023-01-01 00:00:00') JOIN u ON t.a = u.a",
+        );
+        one_statement_parses_to(
+            "SELECT * FROM t VERSION AS OF (1 + 2), u",
+            "SELECT * FROM t(version => (1 + 2)), u",
+        );
+        one_statement_parses_to(
+            "SELECT * FROM t, s . u VERSION AS OF 1 LEFT JOIN v VERSION AS OF 2 ON u.a = v.a",
+            "SELECT * FROM t, s.u(version => 1) LEFT JOIN v(version => 2) ON u.a = v.a",
+        );
+        one_statement_parses_to(
+            "SELECT * FROM (SELECT version FROM t VERSION AS OF 1) AS s WHERE s.version > 1",
+            "SELECT * FROM (SELECT version FROM t(version => 1)) AS s WHERE s.version > 1",
+        );
+
+        // only clauses following the name of a relation are rewritten
+        verified_stmt("SELECT version AS v FROM t");
+        verified_stmt("SELECT version AS of FROM t");
+        verified_stmt("SELECT version, version AS of FROM t WHERE version > 1");
+        // the expression is required
+        assert!(DFParser::parse_sql("SELECT * FROM t VERSION AS OF").is_err());
+    }
+
+    #[test]
+    fn map_literal() {
+        one_statement_parses_to(
+            "SELECT MAP {'a': 1, 'b': x + 1} AS m",
+            "SELECT map(make_array('a', 'b'), make_array(1, x + 1)) AS m",
+        );
+        one_statement_parses_to(
+            "SELECT MAP {1: MAP {'x': [1, 2]}}, MAP {}",
+            "SELECT map(make_array(1), make_array(map(make_array('x'), make_array([1, 2])))), map(make_array(), make_array())",
+        );
+
+        // entries must be key value pairs
+        assert!(DFParser::parse_sql("SELECT MAP {'a'}").is_err());
+    }
+
+    #[test]
+    fn lambda() {
+        one_statement_parses_to(
+            "SELECT array_transform(a, x -> x + 1), list_filter(a, x -> x % 2 = 0)",
+            "SELECT array_transform(a, x -> (x + 1)), list_filter(a, x -> (x % 2 = 0))",
+        );
+        one_statement_parses_to(
+            "SELECT aggregate(a, 0, (acc, x) -> acc + x * 2) FROM t",
+            "SELECT aggregate(a, 0, (acc, x) -> (acc + x * 2)) FROM t",
+        );
+        one_statement_parses_to(
+            "SELECT array_transform(a, x -> array_filter(x, y -> y > 1))",
+            "SELECT array_transform(a, x -> (array_filter(x, y -> (y > 1))))",
+        );
+        one_statement_parses_to(
+            "SELECT array_filter(a, x -> x IS NOT NULL AND x BETWEEN 1 AND 2)",
+            "SELECT array_filter(a, x -> (x IS NOT NULL AND x BETWEEN 1 AND 2))",
+        );
+        one_statement_parses_to(
+            "SELECT array_filter(a, x -> x -> 'k' = 'v'), array_filter(a, (x) -> x)",
+            "SELECT array_filter(a, x -> (x -> 'k' = 'v')), array_filter(a, (x) -> (x))",
+        );
+        one_statement_parses_to(
+            "EXPLAIN SELECT list_transform(a, x -> x * 2) FROM t",
+            "EXPLAIN SELECT list_transform(a, x -> (x * 2)) FROM t",
+        );
+
+        // the JSON operator is not rewritten
+        verified_stmt("SELECT json_get(payload -> 'a', 'b')");
+    }
+
     fn object_name(name: &str) -> CopyToSource {
         CopyToSource::Relation(ObjectName(vec![Ident::new(name)]))
     }
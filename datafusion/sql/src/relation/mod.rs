@@ -16,9 +16,15 @@
 // under the License.
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
-use datafusion_common::{not_impl_err, DataFusionError, Result};
-use datafusion_expr::{LogicalPlan, LogicalPlanBuilder};
-use sqlparser::ast::TableFactor;
+use arrow_schema::{DataType, TimeUnit};
+use datafusion_common::{
+    not_impl_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue, UnnestOptions,
+};
+use datafusion_expr::expr::{BinaryExpr, Cast, ScalarFunction};
+use datafusion_expr::{
+    AsOf, BuiltinScalarFunction, Expr, LogicalPlan, LogicalPlanBuilder, Operator,
+};
+use sqlparser::ast::{FunctionArg, FunctionArgExpr, ObjectName, TableFactor};
 
 mod join;
 
@@ -30,7 +36,21 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         planner_context: &mut PlannerContext,
     ) -> Result<LogicalPlan> {
         let (plan, alias) = match relation {
-            TableFactor::Table { name, alias, .. } => {
+            TableFactor::Table {
+                name,
+                alias,
+                args: Some(args),
+                ..
+            } if is_json_array_elements(&name) => (
+                self.json_array_elements_to_plan(args, planner_context)?,
+                alias,
+            ),
+            TableFactor::Table {
+                name, alias, args, ..
+            } => {
+                let as_of = args
+                    .map(|args| self.table_args_to_as_of(args, planner_context))
+                    .transpose()?;
                 // normalize name and alias
                 let table_ref = self.object_name_to_table_reference(name)?;
                 let table_name = table_ref.to_string();
@@ -40,8 +60,15 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                         cte,
                         self.context_provider.get_table_source(table_ref.clone()),
                     ) {
+                        (Some(_), _) if as_of.is_some() => {
+                            plan_err!("Time travel is not supported for CTE {table_name}")
+                        }
                         (Some(cte_plan), _) => Ok(cte_plan.clone()),
                         (_, Ok(provider)) => {
+                            let provider = match &as_of {
+                                Some(as_of) => provider.as_of(as_of)?,
+                                None => provider,
+                            };
                             LogicalPlanBuilder::scan(table_ref, provider, None)?.build()
                         }
                         (None, Err(e)) => Err(e),
@@ -75,4 +102,133 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             Ok(plan)
         }
     }
+
+    /// Plans the table function `json_array_elements(json, path...)` as a
+    /// single row projection of the function, unnested into one row per
+    /// element in a column named `value`
+    fn json_array_elements_to_plan(
+        &self,
+        args: Vec<FunctionArg>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let schema = DFSchema::empty();
+        let args = args
+            .into_iter()
+            .map(|arg| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)) => {
+                    self.sql_to_expr(arg, &schema, planner_context)
+                }
+                arg => not_impl_err!("Unsupported argument {arg} to json_array_elements"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let elements = Expr::ScalarFunction(ScalarFunction::new(
+            BuiltinScalarFunction::JsonArrayElements,
+            args,
+        ))
+        .alias("value");
+        LogicalPlanBuilder::empty(true)
+            .project(vec![elements])?
+            .unnest_column_with_options(
+                "value",
+                UnnestOptions::new().with_preserve_nulls(false),
+            )?
+            .build()
+    }
+
+    /// Returns the version of a table selected by the table arguments
+    /// `(version => <expr>)` or `(system_time => <expr>)`, which
+    /// [`DFParser`](crate::parser::DFParser) parses `VERSION AS OF <expr>`
+    /// and `FOR SYSTEM_TIME AS OF <expr>` as
+    fn table_args_to_as_of(
+        &self,
+        args: Vec<FunctionArg>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<AsOf> {
+        let [FunctionArg::Named {
+            name,
+            arg: FunctionArgExpr::Expr(arg),
+            ..
+        }] = args.as_slice()
+        else {
+            return not_impl_err!("Unsupported table arguments {args:?}");
+        };
+        let expr = self.sql_to_expr(arg.clone(), &DFSchema::empty(), planner_context)?;
+        match name.value.to_lowercase().as_str() {
+            "version" => match (&expr, integer_constant(&expr)) {
+                (Expr::Literal(ScalarValue::UInt64(Some(version))), _) => {
+                    Ok(AsOf::Version(*version))
+                }
+                (_, Some(version)) if version >= 0 => Ok(AsOf::Version(version as u64)),
+                _ => plan_err!(
+                    "VERSION AS OF requires a non-negative integer constant, got {expr}"
+                ),
+            },
+            "system_time" => {
+                let nanos = match &expr {
+                    Expr::Literal(ScalarValue::Utf8(Some(s))) => parse_timestamp(s)?,
+                    Expr::Cast(Cast {
+                        expr: inner,
+                        data_type: DataType::Timestamp(_, _),
+                    }) => match inner.as_ref() {
+                        Expr::Literal(ScalarValue::Utf8(Some(s))) => parse_timestamp(s)?,
+                        _ => None,
+                    },
+                    Expr::Literal(ScalarValue::TimestampSecond(Some(v), _)) => {
+                        v.checked_mul(1_000_000_000)
+                    }
+                    Expr::Literal(ScalarValue::TimestampMillisecond(Some(v), _)) => {
+                        v.checked_mul(1_000_000)
+                    }
+                    Expr::Literal(ScalarValue::TimestampMicrosecond(Some(v), _)) => {
+                        v.checked_mul(1_000)
+                    }
+                    Expr::Literal(ScalarValue::TimestampNanosecond(Some(v), _)) => {
+                        Some(*v)
+                    }
+                    _ => None,
+                };
+                match nanos {
+                    Some(nanos) => Ok(AsOf::Timestamp(nanos)),
+                    None => plan_err!(
+                        "FOR SYSTEM_TIME AS OF requires a timestamp literal, got {expr}"
+                    ),
+                }
+            }
+            other => not_impl_err!("Unsupported table argument {other}"),
+        }
+    }
+}
+
+/// Evaluates `expr` if it is an integer constant such as `3` or `1 + 2`
+fn integer_constant(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Literal(ScalarValue::Int64(value)) => *value,
+        Expr::Negative(expr) => integer_constant(expr)?.checked_neg(),
+        Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
+            let (left, right) = (integer_constant(left)?, integer_constant(right)?);
+            match op {
+                Operator::Plus => left.checked_add(right),
+                Operator::Minus => left.checked_sub(right),
+                Operator::Multiply => left.checked_mul(right),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if `name` refers to the `json_array_elements` table function
+fn is_json_array_elements(name: &ObjectName) -> bool {
+    matches!(name.0.as_slice(), [ident] if ident.value.eq_ignore_ascii_case("json_array_elements"))
+}
+
+/// Parses `s` as a UTC timestamp in nanoseconds since the epoch
+fn parse_timestamp(s: &str) -> Result<Option<i64>> {
+    match ScalarValue::try_from_string(
+        s.to_string(),
+        &DataType::Timestamp(TimeUnit::Nanosecond, None),
+    )? {
+        ScalarValue::TimestampNanosecond(nanos, _) => Ok(nanos),
+        _ => Ok(None),
+    }
 }
@@ -24,12 +24,12 @@ use crate::utils::{
     resolve_columns, resolve_positions_to_exprs,
 };
 
-use datafusion_common::Column;
 use datafusion_common::{
     get_target_functional_dependencies, not_impl_err, plan_err, DFSchemaRef,
     DataFusionError, Result,
 };
-use datafusion_expr::expr::Alias;
+use datafusion_common::{Column, UnnestOptions};
+use datafusion_expr::expr::{Alias, ScalarFunction};
 use datafusion_expr::expr_rewriter::{
     normalize_col, normalize_col_with_schemas_and_ambiguity_check,
 };
@@ -39,7 +39,8 @@ use datafusion_expr::utils::{
     find_aggregate_exprs, find_window_exprs,
 };
 use datafusion_expr::{
-    Expr, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder, Partitioning,
+    BuiltinScalarFunction, Expr, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder,
+    Partitioning,
 };
 use sqlparser::ast::{
     Distinct, Expr as SQLExpr, GroupByExpr, ReplaceSelectItem, WildcardAdditionalOptions,
@@ -225,8 +226,21 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             plan
         };
 
-        // final projection
+        // final projection, unnesting a top level set returning function
+        let unnest_index = set_returning_function_index(&select_exprs_post_aggr)?;
         let plan = project(plan, select_exprs_post_aggr)?;
+        let plan = match unnest_index {
+            Some(index) => {
+                let column = plan.schema().field(index).qualified_column();
+                LogicalPlanBuilder::from(plan)
+                    .unnest_column_with_options(
+                        column,
+                        UnnestOptions::new().with_preserve_nulls(false),
+                    )?
+                    .build()?
+            }
+            None => plan,
+        };
 
         // process distinct clause
         let distinct = select
@@ -599,6 +613,36 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
     }
 }
 
+/// Returns the position of the SELECT expression calling a set returning
+/// function, `json_array_elements` or `regexp_matches`, whose list result is
+/// unnested into one row per element.
+fn set_returning_function_index(select_exprs: &[Expr]) -> Result<Option<usize>> {
+    let is_set_returning_function = |expr: &Expr| {
+        matches!(
+            expr,
+            Expr::ScalarFunction(ScalarFunction {
+                fun: BuiltinScalarFunction::JsonArrayElements
+                    | BuiltinScalarFunction::RegexpMatches,
+                ..
+            })
+        )
+    };
+    let indices = select_exprs
+        .iter()
+        .enumerate()
+        .filter(|(_, expr)| match expr {
+            Expr::Alias(Alias { expr, .. }) => is_set_returning_function(expr),
+            expr => is_set_returning_function(expr),
+        })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    match indices.as_slice() {
+        [] => Ok(None),
+        [index] => Ok(Some(*index)),
+        _ => not_impl_err!("Multiple set returning functions in a SELECT list"),
+    }
+}
+
 // If there are any multiple-defined windows, we raise an error.
 fn check_conflicting_windows(window_defs: &[NamedWindowDefinition]) -> Result<()> {
     for (i, window_def_i) in window_defs.iter().enumerate() {
@@ -16,17 +16,20 @@
 // under the License.
 
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::parser::{
     CopyToSource, CopyToStatement, CreateExternalTable, DFParser, DescribeTableStmt,
-    ExplainStatement, LexOrdering, Statement as DFStatement,
+    ExplainStatement, LexOrdering, RefreshMaterializedViewStatement,
+    Statement as DFStatement,
 };
 use crate::planner::{
     object_name_to_qualifier, ContextProvider, PlannerContext, SqlToRel,
 };
 use crate::utils::normalize_ident;
 
+use arrow_array::timezone::Tz;
 use arrow_schema::DataType;
 use datafusion_common::file_options::StatementOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
@@ -42,10 +45,11 @@ use datafusion_expr::logical_plan::DdlStatement;
 use datafusion_expr::utils::expr_to_columns;
 use datafusion_expr::{
     cast, col, Analyze, CreateCatalog, CreateCatalogSchema,
-    CreateExternalTable as PlanCreateExternalTable, CreateMemoryTable, CreateView,
-    DescribeTable, DmlStatement, DropCatalogSchema, DropTable, DropView, EmptyRelation,
-    Explain, ExprSchemable, Filter, LogicalPlan, LogicalPlanBuilder, PlanType, Prepare,
-    SetVariable, Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
+    CreateExternalTable as PlanCreateExternalTable, CreateMaterializedView,
+    CreateMemoryTable, CreateView, DescribeTable, DmlStatement, DropCatalogSchema,
+    DropTable, DropView, EmptyRelation, Explain, ExprSchemable, Filter, LogicalPlan,
+    LogicalPlanBuilder, PlanType, Prepare, RefreshMaterializedView, SetVariable,
+    Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
     TransactionConclusion, TransactionEnd, TransactionIsolationLevel, TransactionStart,
     WriteOp,
 };
@@ -143,6 +147,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 analyze,
                 statement,
             }) => self.explain_to_plan(verbose, analyze, *statement),
+            DFStatement::RefreshMaterializedView(s) => {
+                self.refresh_materialized_view_to_plan(s)
+            }
         }
     }
 
@@ -278,6 +285,42 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 }
             }
 
+            Statement::CreateView {
+                or_replace,
+                materialized: true,
+                name,
+                columns,
+                query,
+                with_options,
+                ..
+            } => {
+                let mut location = None;
+                for option in with_options {
+                    match (option.name.value.to_lowercase().as_str(), &option.value) {
+                        ("location", Value::SingleQuotedString(s)) => {
+                            location = Some(s.clone())
+                        }
+                        _ => {
+                            return plan_err!(
+                                "Unsupported materialized view option: {option}"
+                            )
+                        }
+                    }
+                }
+
+                let mut plan = self.query_to_plan(*query, &mut PlannerContext::new())?;
+                plan = self.apply_expr_alias(plan, columns)?;
+
+                Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                    CreateMaterializedView {
+                        name: self.object_name_to_table_reference(name)?,
+                        input: Arc::new(plan),
+                        or_replace,
+                        location,
+                        definition: sql,
+                    },
+                )))
+            }
             Statement::CreateView {
                 or_replace,
                 name,
@@ -637,6 +680,19 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }))
     }
 
+    fn refresh_materialized_view_to_plan(
+        &self,
+        statement: RefreshMaterializedViewStatement,
+    ) -> Result<LogicalPlan> {
+        let RefreshMaterializedViewStatement { name } = statement;
+        Ok(LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(
+            RefreshMaterializedView {
+                name: self.object_name_to_table_reference(name)?,
+                schema: DFSchemaRef::new(DFSchema::empty()),
+            },
+        )))
+    }
+
     fn copy_to_plan(&self, statement: CopyToStatement) -> Result<LogicalPlan> {
         // determine if source is table or query and handle accordingly
         let copy_source = statement.source;
@@ -910,6 +966,11 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             }
         };
 
+        // reject unknown timezones here rather than on first use
+        if variable_lower == "datafusion.execution.time_zone" {
+            Tz::from_str(&value_string)?;
+        }
+
         let statement = PlanStatement::SetVariable(SetVariable {
             variable: variable_lower,
             value: value_string,
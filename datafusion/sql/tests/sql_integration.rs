@@ -56,6 +56,14 @@ fn parse_decimals() {
             "18446744073709551616",
             "Decimal128(Some(18446744073709551616),20,0)",
         ),
+        (
+            "1234567890123456789012345678901234567890",
+            "Decimal256(Some(1234567890123456789012345678901234567890),40,0)",
+        ),
+        (
+            "-1234567890.12345678901234567890123456789",
+            "Decimal256(Some(-123456789012345678901234567890123456789),39,29)",
+        ),
     ];
     for (a, b) in test_data {
         let sql = format!("SELECT {a}");
@@ -1212,6 +1220,58 @@ fn select_arrow_at_operator() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn select_json_operators() {
+    let sql = "SELECT first_name->'a'->>0, first_name #>> '{b, 1}' from person";
+    let expected = "Projection: json_get_str(json_get(person.first_name, Utf8(\"a\")), Int64(0)), json_get_str(person.first_name, Utf8(\"b\"), Utf8(\"1\"))\
+                        \n  TableScan: person";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn select_json_array_elements() {
+    let sql = "SELECT id, json_array_elements(first_name) AS e from person";
+    let expected = "Unnest: e\
+                        \n  Projection: person.id, json_array_elements(person.first_name) AS e\
+                        \n    TableScan: person";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn select_from_json_array_elements() {
+    let sql = "SELECT e.value FROM json_array_elements('[1, 2]') AS e";
+    let expected = "Projection: e.value\
+                        \n  SubqueryAlias: e\
+                        \n    Unnest: value\
+                        \n      Projection: json_array_elements(Utf8(\"[1, 2]\")) AS value\
+                        \n        EmptyRelation";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn select_lambda() {
+    let sql = "SELECT array_transform(make_array(age), x -> x + 1), \
+               aggregate(make_array(age), 0, (acc, x) -> acc + x) from person";
+    let expected = "Projection: array_transform(make_array(person.age), x -> x + Int64(1)), array_reduce(make_array(person.age), Int64(0), (acc, x) -> acc + x)\
+                        \n  TableScan: person";
+    quick_test(sql, expected);
+
+    // columns of the input are captured
+    let sql = "SELECT array_filter(make_array(age), x -> x > id), \
+               array_transform(make_array(age), x -> x + person.id) from person";
+    let expected = "Projection: array_filter(make_array(person.age), x -> x > id), array_transform(make_array(person.age), x -> x + person.id)\
+                        \n  TableScan: person";
+    quick_test(sql, expected);
+
+    let sql = "SELECT id FROM person WHERE EXISTS (SELECT 1 FROM orders \
+               WHERE array_any_match(make_array(order_id), x -> x = person.id))";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Error during planning: A lambda may not reference the columns of an outer query, got outer_ref(person.id)",
+        err.strip_backtrace()
+    );
+}
+
 #[test]
 fn select_wildcard_with_groupby() {
     quick_test(
@@ -2587,6 +2647,54 @@ fn select_typed_time_string() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn select_at_time_zone() {
+    let sql = "SELECT birth_date AT TIME ZONE 'America/New_York' FROM person";
+    let expected = "Projection: CAST(person.birth_date AS Timestamp(Nanosecond, Some(\"America/New_York\")))\
+            \n  TableScan: person";
+    quick_test(sql, expected);
+
+    let sql = "SELECT birth_date AT TIME ZONE 'Mars/Olympus_Mons' FROM person";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Arrow error: Parser error: Invalid timezone \"Mars/Olympus_Mons\": 'Mars/Olympus_Mons' is not a valid timezone",
+        err.strip_backtrace()
+    );
+}
+
+#[test]
+fn select_collate() {
+    let sql = "SELECT first_name FROM person \
+               WHERE first_name COLLATE nocase = 'JOHN' \
+               ORDER BY first_name COLLATE \"und-ci\"";
+    let expected = "Sort: collate(person.first_name, Utf8(\"und-ci\")) ASC NULLS LAST\
+            \n  Projection: person.first_name\
+            \n    Filter: collate(person.first_name, Utf8(\"nocase\")) = collate(Utf8(\"JOHN\"), Utf8(\"nocase\"))\
+            \n      TableScan: person";
+    quick_test(sql, expected);
+
+    // the collation applies to all operands of the comparison
+    let sql = "SELECT first_name COLLATE NOCASE IN ('A', 'B') FROM person";
+    let expected = "Projection: collate(person.first_name, Utf8(\"nocase\")) IN ([collate(Utf8(\"A\"), Utf8(\"nocase\")), collate(Utf8(\"B\"), Utf8(\"nocase\"))])\
+            \n  TableScan: person";
+    quick_test(sql, expected);
+
+    let sql =
+        "SELECT first_name COLLATE nocase = last_name COLLATE \"und-ci\" FROM person";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Error during planning: Collation mismatch between explicit collations nocase and und-ci",
+        err.strip_backtrace()
+    );
+
+    let sql = "SELECT first_name COLLATE \"fr-ci\" FROM person";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Error during planning: Unsupported collation 'fr-ci', expected one of: binary, nocase, und-ci",
+        err.strip_backtrace()
+    );
+}
+
 #[test]
 fn select_multibyte_column() {
     let sql = r#"SELECT "😀" FROM person"#;
@@ -3273,6 +3381,38 @@ fn group_by_ambiguous_name() {
     assert_eq!(err.strip_backtrace(), expected);
 }
 
+#[test]
+fn time_travel_not_supported() {
+    let sql = "SELECT id FROM person VERSION AS OF 1";
+    let expected = "Error during planning: Time travel (VERSION AS OF 1) is not supported by this table";
+    let err = logical_plan(sql).unwrap_err();
+    assert_eq!(err.strip_backtrace(), expected);
+
+    let sql = "SELECT id FROM person VERSION AS OF (1 + 2) AS p";
+    let expected = "Error during planning: Time travel (VERSION AS OF 3) is not supported by this table";
+    let err = logical_plan(sql).unwrap_err();
+    assert_eq!(err.strip_backtrace(), expected);
+
+    let sql = "SELECT id FROM person FOR SYSTEM_TIME AS OF '2023-01-01T00:00:00Z'";
+    let expected = "Error during planning: Time travel (FOR SYSTEM_TIME AS OF '2023-01-01T00:00:00') is not supported by this table";
+    let err = logical_plan(sql).unwrap_err();
+    assert_eq!(err.strip_backtrace(), expected);
+}
+
+#[test]
+fn time_travel_invalid_version() {
+    let sql = "SELECT id FROM person VERSION AS OF 'yesterday'";
+    let expected = "Error during planning: VERSION AS OF requires a non-negative integer constant, got Utf8(\"yesterday\")";
+    let err = logical_plan(sql).unwrap_err();
+    assert_eq!(err.strip_backtrace(), expected);
+
+    let sql = "SELECT id FROM person FOR SYSTEM_TIME AS OF 1";
+    let expected =
+        "Error during planning: FOR SYSTEM_TIME AS OF requires a timestamp literal, got Int64(1)";
+    let err = logical_plan(sql).unwrap_err();
+    assert_eq!(err.strip_backtrace(), expected);
+}
+
 #[test]
 fn test_zero_offset_with_limit() {
     let sql = "select id from person where person.id > 100 LIMIT 5 OFFSET 0;";
@@ -16,16 +16,19 @@
 // under the License.
 
 use async_recursion::async_recursion;
-use datafusion::arrow::datatypes::{DataType, Field, TimeUnit};
-use datafusion::common::{not_impl_err, DFField, DFSchema, DFSchemaRef};
+use datafusion::arrow::datatypes::{
+    i256, DataType, Field, Fields, IntervalDayTimeType, IntervalUnit, Schema, TimeUnit,
+};
+use datafusion::common::{not_impl_err, Constraints, DFField, DFSchema, DFSchemaRef};
 
 use datafusion::logical_expr::{
     aggregate_function, window_function::find_df_window_func, BinaryExpr,
     BuiltinScalarFunction, Case, Expr, LogicalPlan, Operator,
 };
 use datafusion::logical_expr::{
-    expr, Cast, Extension, GroupingSet, Like, LogicalPlanBuilder, WindowFrameBound,
-    WindowFrameUnits,
+    expr, Cast, CreateMemoryTable, CreateView, DdlStatement, DmlStatement, DropTable,
+    DropView, EmptyRelation, Extension, GroupingSet, Like, LogicalPlanBuilder,
+    Projection, Subquery, Values, WindowFrameBound, WindowFrameUnits, WriteOp,
 };
 use datafusion::prelude::JoinType;
 use datafusion::sql::TableReference;
@@ -38,12 +41,17 @@ use datafusion::{
 use substrait::proto::expression::{Literal, ScalarFunction};
 use substrait::proto::{
     aggregate_function::AggregationInvocation,
+    ddl_rel,
     expression::{
-        field_reference::ReferenceType::DirectReference, literal::LiteralType,
+        field_reference::ReferenceType::DirectReference,
+        field_reference::RootType,
+        literal::LiteralType,
         reference_segment::ReferenceType::StructField,
+        subquery::{set_predicate::PredicateOp, SubqueryType},
         window_function::bound as SubstraitBound,
-        window_function::bound::Kind as BoundKind, window_function::Bound,
-        MaskExpression, RexType,
+        window_function::bound::Kind as BoundKind,
+        window_function::Bound,
+        FieldReference, MaskExpression, RexType,
     },
     extensions::simple_extension_declaration::MappingType,
     function_argument::ArgType,
@@ -52,21 +60,22 @@ use substrait::proto::{
     rel::RelType,
     set_rel,
     sort_field::{SortDirection, SortKind::*},
-    AggregateFunction, Expression, Plan, Rel, Type,
+    write_rel, AggregateFunction, Expression, NamedStruct, Plan, Rel, Type,
 };
 use substrait::proto::{FunctionArgument, SortField};
 
 use datafusion::common::plan_err;
-use datafusion::logical_expr::expr::{InList, Sort};
+use datafusion::logical_expr::expr::{Exists, InList, InSubquery, Sort};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use crate::logical_plan::{outer_schemas, OUTER_SCHEMAS};
 use crate::variation_const::{
     DATE_32_TYPE_REF, DATE_64_TYPE_REF, DECIMAL_128_TYPE_REF, DECIMAL_256_TYPE_REF,
-    DEFAULT_CONTAINER_TYPE_REF, DEFAULT_TYPE_REF, LARGE_CONTAINER_TYPE_REF,
-    TIMESTAMP_MICRO_TYPE_REF, TIMESTAMP_MILLI_TYPE_REF, TIMESTAMP_NANO_TYPE_REF,
-    TIMESTAMP_SECOND_TYPE_REF, UNSIGNED_INTEGER_TYPE_REF,
+    DEFAULT_CONTAINER_TYPE_REF, DEFAULT_TYPE_REF, INTERVAL_MONTH_DAY_NANO_TYPE_REF,
+    LARGE_CONTAINER_TYPE_REF, TIMESTAMP_MICRO_TYPE_REF, TIMESTAMP_MILLI_TYPE_REF,
+    TIMESTAMP_NANO_TYPE_REF, TIMESTAMP_SECOND_TYPE_REF, UNSIGNED_INTEGER_TYPE_REF,
 };
 
 enum ScalarFunctionType {
@@ -232,7 +241,8 @@ pub async fn from_substrait_rel(
                 let mut exprs: Vec<Expr> = vec![];
                 for e in &p.expressions {
                     let x =
-                        from_substrait_rex(e, input.clone().schema(), extensions).await?;
+                        from_substrait_rex(ctx, e, input.clone().schema(), extensions)
+                            .await?;
                     // if the expression is WindowFunction, wrap in a Window relation
                     //   before returning and do not add to list of this Projection's expression list
                     // otherwise, add expression to the Projection's expression list
@@ -258,7 +268,8 @@ pub async fn from_substrait_rel(
                 );
                 if let Some(condition) = filter.condition.as_ref() {
                     let expr =
-                        from_substrait_rex(condition, input.schema(), extensions).await?;
+                        from_substrait_rex(ctx, condition, input.schema(), extensions)
+                            .await?;
                     input.filter(expr.as_ref().clone())?.build()
                 } else {
                     not_impl_err!("Filter without an condition is not valid")
@@ -290,7 +301,8 @@ pub async fn from_substrait_rel(
                     from_substrait_rel(ctx, input, extensions).await?,
                 );
                 let sorts =
-                    from_substrait_sorts(&sort.sorts, input.schema(), extensions).await?;
+                    from_substrait_sorts(ctx, &sort.sorts, input.schema(), extensions)
+                        .await?;
                 input.sort(sorts)?.build()
             } else {
                 not_impl_err!("Sort without an input is not valid")
@@ -308,7 +320,8 @@ pub async fn from_substrait_rel(
                     1 => {
                         for e in &agg.groupings[0].grouping_expressions {
                             let x =
-                                from_substrait_rex(e, input.schema(), extensions).await?;
+                                from_substrait_rex(ctx, e, input.schema(), extensions)
+                                    .await?;
                             group_expr.push(x.as_ref().clone());
                         }
                     }
@@ -317,8 +330,13 @@ pub async fn from_substrait_rel(
                         for grouping in &agg.groupings {
                             let mut grouping_set = vec![];
                             for e in &grouping.grouping_expressions {
-                                let x = from_substrait_rex(e, input.schema(), extensions)
-                                    .await?;
+                                let x = from_substrait_rex(
+                                    ctx,
+                                    e,
+                                    input.schema(),
+                                    extensions,
+                                )
+                                .await?;
                                 grouping_set.push(x.as_ref().clone());
                             }
                             grouping_sets.push(grouping_set);
@@ -336,7 +354,7 @@ pub async fn from_substrait_rel(
                 for m in &agg.measures {
                     let filter = match &m.filter {
                         Some(fil) => Some(Box::new(
-                            from_substrait_rex(fil, input.schema(), extensions)
+                            from_substrait_rex(ctx, fil, input.schema(), extensions)
                                 .await?
                                 .as_ref()
                                 .clone(),
@@ -359,6 +377,7 @@ pub async fn from_substrait_rel(
                                 _ => false,
                             };
                             from_substrait_agg_func(
+                                ctx,
                                 f,
                                 input.schema(),
                                 extensions,
@@ -403,8 +422,8 @@ pub async fn from_substrait_rel(
             // Otherwise, build join with only the filter, without join keys
             match &join.expression.as_ref() {
                 Some(expr) => {
-                    let on =
-                        from_substrait_rex(expr, &in_join_schema, extensions).await?;
+                    let on = from_substrait_rex(ctx, expr, &in_join_schema, extensions)
+                        .await?;
                     // The join expression can contain both equal and non-equal ops.
                     // As of datafusion 31.0.0, the equal and non equal join conditions are in separate fields.
                     // So we extract each part as follows:
@@ -428,23 +447,7 @@ pub async fn from_substrait_rel(
         }
         Some(RelType::Read(read)) => match &read.as_ref().read_type {
             Some(ReadType::NamedTable(nt)) => {
-                let table_reference = match nt.names.len() {
-                    0 => {
-                        return plan_err!("No table name found in NamedTable");
-                    }
-                    1 => TableReference::Bare {
-                        table: (&nt.names[0]).into(),
-                    },
-                    2 => TableReference::Partial {
-                        schema: (&nt.names[0]).into(),
-                        table: (&nt.names[1]).into(),
-                    },
-                    _ => TableReference::Full {
-                        catalog: (&nt.names[0]).into(),
-                        schema: (&nt.names[1]).into(),
-                        table: (&nt.names[2]).into(),
-                    },
-                };
+                let table_reference = from_substrait_table_reference(&nt.names)?;
                 let t = ctx.table(table_reference).await?;
                 let t = t.into_optimized_plan()?;
                 match &read.projection {
@@ -478,8 +481,53 @@ pub async fn from_substrait_rel(
                     _ => Ok(t),
                 }
             }
-            _ => not_impl_err!("Only NamedTable reads are supported"),
+            Some(ReadType::VirtualTable(vt)) => {
+                let Some(base_schema) = &read.base_schema else {
+                    return plan_err!("Virtual table without a base schema is not valid");
+                };
+                let schema = Arc::new(DFSchema::try_from(from_substrait_named_struct(
+                    base_schema,
+                )?)?);
+                // Rows without any fields make up an empty relation
+                if vt.values.iter().all(|row| row.fields.is_empty()) {
+                    return Ok(LogicalPlan::EmptyRelation(EmptyRelation {
+                        produce_one_row: !vt.values.is_empty(),
+                        schema,
+                    }));
+                }
+                let values = vt
+                    .values
+                    .iter()
+                    .map(|row| {
+                        let mut name_idx = 0;
+                        row.fields
+                            .iter()
+                            .map(|lit| {
+                                // Top level names are already part of the schema
+                                name_idx += 1;
+                                let value = from_substrait_literal_with_names(
+                                    lit,
+                                    &base_schema.names,
+                                    &mut name_idx,
+                                )?;
+                                Ok(Expr::Literal(value))
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LogicalPlan::Values(Values { schema, values }))
+            }
+            _ => not_impl_err!("Only NamedTable and VirtualTable reads are supported"),
         },
+        Some(RelType::Cross(cross)) => {
+            let left = LogicalPlanBuilder::from(
+                from_substrait_rel(ctx, cross.left.as_ref().unwrap(), extensions).await?,
+            );
+            let right =
+                from_substrait_rel(ctx, cross.right.as_ref().unwrap(), extensions)
+                    .await?;
+            left.cross_join(right)?.build()
+        }
         Some(RelType::Set(set)) => match set_rel::SetOp::try_from(set.op) {
             Ok(set_op) => match set_op {
                 set_rel::SetOp::UnionAll => {
@@ -496,6 +544,48 @@ pub async fn from_substrait_rel(
                         not_impl_err!("Union relation requires at least one input")
                     }
                 }
+                set_rel::SetOp::UnionDistinct => {
+                    if !set.inputs.is_empty() {
+                        let mut union_builder = Ok(LogicalPlanBuilder::from(
+                            from_substrait_rel(ctx, &set.inputs[0], extensions).await?,
+                        ));
+                        for input in &set.inputs[1..] {
+                            union_builder = union_builder?.union_distinct(
+                                from_substrait_rel(ctx, input, extensions).await?,
+                            );
+                        }
+                        union_builder?.build()
+                    } else {
+                        not_impl_err!("Union relation requires at least one input")
+                    }
+                }
+                set_rel::SetOp::IntersectionPrimary
+                | set_rel::SetOp::IntersectionMultiset
+                | set_rel::SetOp::MinusPrimary
+                | set_rel::SetOp::MinusMultiset => {
+                    if set.inputs.len() < 2 {
+                        return plan_err!("{set_op:?} requires at least two inputs");
+                    }
+                    let is_all = matches!(
+                        set_op,
+                        set_rel::SetOp::IntersectionMultiset
+                            | set_rel::SetOp::MinusMultiset
+                    );
+                    // The primary input is combined with each of the secondary inputs
+                    let mut plan =
+                        from_substrait_rel(ctx, &set.inputs[0], extensions).await?;
+                    for input in &set.inputs[1..] {
+                        let right = from_substrait_rel(ctx, input, extensions).await?;
+                        plan = match set_op {
+                            set_rel::SetOp::IntersectionPrimary
+                            | set_rel::SetOp::IntersectionMultiset => {
+                                LogicalPlanBuilder::intersect(plan, right, is_all)?
+                            }
+                            _ => LogicalPlanBuilder::except(plan, right, is_all)?,
+                        };
+                    }
+                    Ok(plan)
+                }
                 _ => not_impl_err!("Unsupported set operator: {set_op:?}"),
             },
             Err(e) => not_impl_err!("Invalid set operation type {}: {e}", set.op),
@@ -549,10 +639,218 @@ pub async fn from_substrait_rel(
             let plan = plan.from_template(&plan.expressions(), &inputs);
             Ok(LogicalPlan::Extension(Extension { node: plan }))
         }
+        Some(RelType::Write(write)) => {
+            let Some(write_rel::WriteType::NamedTable(named_table)) = &write.write_type
+            else {
+                return not_impl_err!("Only writes to named tables are supported");
+            };
+            let table_name =
+                from_substrait_table_reference(&named_table.names)?.to_owned_reference();
+            let Some(input) = write.input.as_ref() else {
+                return plan_err!("WriteRel without an input is not valid");
+            };
+            let input = from_substrait_rel(ctx, input, extensions).await?;
+            let Ok(op) = write_rel::WriteOp::try_from(write.op) else {
+                return plan_err!("Invalid write operation {}", write.op);
+            };
+            if op == write_rel::WriteOp::Ctas {
+                return Ok(LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(
+                    CreateMemoryTable {
+                        name: table_name,
+                        constraints: Constraints::empty(),
+                        input: Arc::new(input),
+                        if_not_exists: false,
+                        or_replace: false,
+                    },
+                )));
+            }
+            let Some(table_schema) = write.table_schema.as_ref() else {
+                return plan_err!("WriteRel without a table schema is not valid");
+            };
+            let table_schema = from_substrait_named_struct(table_schema)?;
+            let (op, table_schema) = match op {
+                write_rel::WriteOp::Insert => {
+                    (WriteOp::InsertInto, DFSchema::try_from(table_schema)?)
+                }
+                write_rel::WriteOp::Delete => {
+                    (WriteOp::Delete, DFSchema::try_from(table_schema)?)
+                }
+                // The assignments of an update refer to the qualified table columns
+                write_rel::WriteOp::Update => (
+                    WriteOp::Update,
+                    DFSchema::try_from_qualified_schema(
+                        table_name.clone(),
+                        &table_schema,
+                    )?,
+                ),
+                _ => return not_impl_err!("Unsupported write operation {op:?}"),
+            };
+            let input = if op == WriteOp::Update {
+                alias_update_assignments(input, &table_schema)?
+            } else {
+                input
+            };
+            Ok(LogicalPlan::Dml(DmlStatement {
+                table_name,
+                table_schema: Arc::new(table_schema),
+                op,
+                input: Arc::new(input),
+            }))
+        }
+        Some(RelType::Ddl(ddl)) => {
+            let Some(ddl_rel::WriteType::NamedObject(named_object)) = &ddl.write_type
+            else {
+                return not_impl_err!("Only DDL on named objects is supported");
+            };
+            let name =
+                from_substrait_table_reference(&named_object.names)?.to_owned_reference();
+            let Ok(object) = ddl_rel::DdlObject::try_from(ddl.object) else {
+                return plan_err!("Invalid DDL object {}", ddl.object);
+            };
+            let Ok(op) = ddl_rel::DdlOp::try_from(ddl.op) else {
+                return plan_err!("Invalid DDL operation {}", ddl.op);
+            };
+            let statement = match (object, op) {
+                (
+                    ddl_rel::DdlObject::Table,
+                    ddl_rel::DdlOp::Create | ddl_rel::DdlOp::CreateOrReplace,
+                ) => {
+                    let Some(table_schema) = ddl.table_schema.as_ref() else {
+                        return plan_err!("Creating a table requires a table schema");
+                    };
+                    let schema = Arc::new(DFSchema::try_from(
+                        from_substrait_named_struct(table_schema)?,
+                    )?);
+                    DdlStatement::CreateMemoryTable(CreateMemoryTable {
+                        name,
+                        constraints: Constraints::empty(),
+                        input: Arc::new(LogicalPlan::EmptyRelation(EmptyRelation {
+                            produce_one_row: false,
+                            schema,
+                        })),
+                        if_not_exists: false,
+                        or_replace: op == ddl_rel::DdlOp::CreateOrReplace,
+                    })
+                }
+                (
+                    ddl_rel::DdlObject::View,
+                    ddl_rel::DdlOp::Create | ddl_rel::DdlOp::CreateOrReplace,
+                ) => {
+                    let Some(view_definition) = ddl.view_definition.as_ref() else {
+                        return plan_err!("Creating a view requires a view definition");
+                    };
+                    let input =
+                        from_substrait_rel(ctx, view_definition, extensions).await?;
+                    DdlStatement::CreateView(CreateView {
+                        name,
+                        input: Arc::new(input),
+                        or_replace: op == ddl_rel::DdlOp::CreateOrReplace,
+                        definition: None,
+                    })
+                }
+                (
+                    ddl_rel::DdlObject::Table,
+                    ddl_rel::DdlOp::Drop | ddl_rel::DdlOp::DropIfExist,
+                ) => DdlStatement::DropTable(DropTable {
+                    name,
+                    if_exists: op == ddl_rel::DdlOp::DropIfExist,
+                    schema: Arc::new(DFSchema::empty()),
+                }),
+                (
+                    ddl_rel::DdlObject::View,
+                    ddl_rel::DdlOp::Drop | ddl_rel::DdlOp::DropIfExist,
+                ) => DdlStatement::DropView(DropView {
+                    name,
+                    if_exists: op == ddl_rel::DdlOp::DropIfExist,
+                    schema: Arc::new(DFSchema::empty()),
+                }),
+                _ => {
+                    return not_impl_err!(
+                        "Unsupported DDL operation {op:?} on {object:?}"
+                    )
+                }
+            };
+            Ok(LogicalPlan::Ddl(statement))
+        }
         _ => not_impl_err!("Unsupported RelType: {:?}", rel.rel_type),
     }
 }
 
+/// Name the expressions of the projection below an update after the table
+/// columns they assign, as Substrait does not carry the names of expressions
+fn alias_update_assignments(
+    input: LogicalPlan,
+    table_schema: &DFSchema,
+) -> Result<LogicalPlan> {
+    let LogicalPlan::Projection(projection) = input else {
+        return Ok(input);
+    };
+    if projection.expr.len() != table_schema.fields().len() {
+        return plan_err!(
+            "Update assigns {} expressions to a table with {} columns",
+            projection.expr.len(),
+            table_schema.fields().len()
+        );
+    }
+    let exprs = projection
+        .expr
+        .into_iter()
+        .zip(table_schema.fields())
+        .map(|(expr, field)| expr.unalias().alias(field.name()))
+        .collect();
+    Ok(LogicalPlan::Projection(Projection::try_new(
+        exprs,
+        projection.input,
+    )?))
+}
+
+fn from_substrait_table_reference(names: &[String]) -> Result<TableReference<'_>> {
+    match names.len() {
+        0 => plan_err!("No table name found in NamedTable"),
+        1 => Ok(TableReference::Bare {
+            table: (&names[0]).into(),
+        }),
+        2 => Ok(TableReference::Partial {
+            schema: (&names[0]).into(),
+            table: (&names[1]).into(),
+        }),
+        _ => Ok(TableReference::Full {
+            catalog: (&names[0]).into(),
+            schema: (&names[1]).into(),
+            table: (&names[2]).into(),
+        }),
+    }
+}
+
+/// Convert Substrait `NamedStruct` to an Arrow schema
+///
+/// The names of fields nested in structs follow the name of the struct
+/// field itself, in depth-first order.
+pub(crate) fn from_substrait_named_struct(base_schema: &NamedStruct) -> Result<Schema> {
+    let Some(r#struct) = &base_schema.r#struct else {
+        return plan_err!("Named struct without a struct type is not valid");
+    };
+    let mut name_idx = 0;
+    let fields = r#struct
+        .types
+        .iter()
+        .enumerate()
+        .map(|(i, dt)| {
+            let name = next_struct_field_name(i, &base_schema.names, &mut name_idx)?;
+            let data_type =
+                from_substrait_type_with_names(dt, &base_schema.names, &mut name_idx)?;
+            Ok(Field::new(name, data_type, is_substrait_type_nullable(dt)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if name_idx != base_schema.names.len() {
+        return plan_err!(
+            "Named struct has {} names but its type only has {name_idx} fields",
+            base_schema.names.len()
+        );
+    }
+    Ok(Schema::new(fields))
+}
+
 fn from_substrait_jointype(join_type: i32) -> Result<JoinType> {
     if let Ok(substrait_join_type) = join_rel::JoinType::try_from(join_type) {
         match substrait_join_type {
@@ -571,14 +869,16 @@ fn from_substrait_jointype(join_type: i32) -> Result<JoinType> {
 
 /// Convert Substrait Sorts to DataFusion Exprs
 pub async fn from_substrait_sorts(
+    ctx: &SessionContext,
     substrait_sorts: &Vec<SortField>,
     input_schema: &DFSchema,
     extensions: &HashMap<u32, &String>,
 ) -> Result<Vec<Expr>> {
     let mut sorts: Vec<Expr> = vec![];
     for s in substrait_sorts {
-        let expr = from_substrait_rex(s.expr.as_ref().unwrap(), input_schema, extensions)
-            .await?;
+        let expr =
+            from_substrait_rex(ctx, s.expr.as_ref().unwrap(), input_schema, extensions)
+                .await?;
         let asc_nullfirst = match &s.sort_kind {
             Some(k) => match k {
                 Direction(d) => {
@@ -619,13 +919,14 @@ pub async fn from_substrait_sorts(
 
 /// Convert Substrait Expressions to DataFusion Exprs
 pub async fn from_substrait_rex_vec(
+    ctx: &SessionContext,
     exprs: &Vec<Expression>,
     input_schema: &DFSchema,
     extensions: &HashMap<u32, &String>,
 ) -> Result<Vec<Expr>> {
     let mut expressions: Vec<Expr> = vec![];
     for expr in exprs {
-        let expression = from_substrait_rex(expr, input_schema, extensions).await?;
+        let expression = from_substrait_rex(ctx, expr, input_schema, extensions).await?;
         expressions.push(expression.as_ref().clone());
     }
     Ok(expressions)
@@ -633,6 +934,7 @@ pub async fn from_substrait_rex_vec(
 
 /// Convert Substrait FunctionArguments to DataFusion Exprs
 pub async fn from_substriat_func_args(
+    ctx: &SessionContext,
     arguments: &Vec<FunctionArgument>,
     input_schema: &DFSchema,
     extensions: &HashMap<u32, &String>,
@@ -641,7 +943,7 @@ pub async fn from_substriat_func_args(
     for arg in arguments {
         let arg_expr = match &arg.arg_type {
             Some(ArgType::Value(e)) => {
-                from_substrait_rex(e, input_schema, extensions).await
+                from_substrait_rex(ctx, e, input_schema, extensions).await
             }
             _ => {
                 not_impl_err!("Aggregated function argument non-Value type not supported")
@@ -654,6 +956,7 @@ pub async fn from_substriat_func_args(
 
 /// Convert Substrait AggregateFunction to DataFusion Expr
 pub async fn from_substrait_agg_func(
+    ctx: &SessionContext,
     f: &AggregateFunction,
     input_schema: &DFSchema,
     extensions: &HashMap<u32, &String>,
@@ -665,7 +968,7 @@ pub async fn from_substrait_agg_func(
     for arg in &f.arguments {
         let arg_expr = match &arg.arg_type {
             Some(ArgType::Value(e)) => {
-                from_substrait_rex(e, input_schema, extensions).await
+                from_substrait_rex(ctx, e, input_schema, extensions).await
             }
             _ => {
                 not_impl_err!("Aggregated function argument non-Value type not supported")
@@ -696,6 +999,7 @@ pub async fn from_substrait_agg_func(
 /// Convert Substrait Rex to DataFusion Expr
 #[async_recursion]
 pub async fn from_substrait_rex(
+    ctx: &SessionContext,
     e: &Expression,
     input_schema: &DFSchema,
     extensions: &HashMap<u32, &String>,
@@ -706,16 +1010,26 @@ pub async fn from_substrait_rex(
             let substrait_list = s.options.as_ref();
             Ok(Arc::new(Expr::InList(InList {
                 expr: Box::new(
-                    from_substrait_rex(substrait_expr, input_schema, extensions)
+                    from_substrait_rex(ctx, substrait_expr, input_schema, extensions)
                         .await?
                         .as_ref()
                         .clone(),
                 ),
-                list: from_substrait_rex_vec(substrait_list, input_schema, extensions)
-                    .await?,
+                list: from_substrait_rex_vec(
+                    ctx,
+                    substrait_list,
+                    input_schema,
+                    extensions,
+                )
+                .await?,
                 negated: false,
             })))
         }
+        Some(RexType::Selection(field_ref))
+            if matches!(field_ref.root_type, Some(RootType::OuterReference(_))) =>
+        {
+            from_substrait_outer_ref(field_ref)
+        }
         Some(RexType::Selection(field_ref)) => match &field_ref.reference_type {
             Some(DirectReference(direct)) => match &direct.reference_type.as_ref() {
                 Some(StructField(x)) => match &x.child.as_ref() {
@@ -748,6 +1062,7 @@ pub async fn from_substrait_rex(
                     if if_expr.then.is_none() {
                         expr = Some(Box::new(
                             from_substrait_rex(
+                                ctx,
                                 if_expr.r#if.as_ref().unwrap(),
                                 input_schema,
                                 extensions,
@@ -762,6 +1077,7 @@ pub async fn from_substrait_rex(
                 when_then_expr.push((
                     Box::new(
                         from_substrait_rex(
+                            ctx,
                             if_expr.r#if.as_ref().unwrap(),
                             input_schema,
                             extensions,
@@ -772,6 +1088,7 @@ pub async fn from_substrait_rex(
                     ),
                     Box::new(
                         from_substrait_rex(
+                            ctx,
                             if_expr.then.as_ref().unwrap(),
                             input_schema,
                             extensions,
@@ -785,7 +1102,7 @@ pub async fn from_substrait_rex(
             // Parse `else`
             let else_expr = match &if_then.r#else {
                 Some(e) => Some(Box::new(
-                    from_substrait_rex(e, input_schema, extensions)
+                    from_substrait_rex(ctx, e, input_schema, extensions)
                         .await?
                         .as_ref()
                         .clone(),
@@ -812,7 +1129,7 @@ pub async fn from_substrait_rex(
                     for arg in &f.arguments {
                         let arg_expr = match &arg.arg_type {
                             Some(ArgType::Value(e)) => {
-                                from_substrait_rex(e, input_schema, extensions).await
+                                from_substrait_rex(ctx, e, input_schema, extensions).await
                             }
                             _ => not_impl_err!(
                                 "Aggregated function argument non-Value type not supported"
@@ -838,14 +1155,14 @@ pub async fn from_substrait_rex(
                         (Some(ArgType::Value(l)), Some(ArgType::Value(r))) => {
                             Ok(Arc::new(Expr::BinaryExpr(BinaryExpr {
                                 left: Box::new(
-                                    from_substrait_rex(l, input_schema, extensions)
+                                    from_substrait_rex(ctx, l, input_schema, extensions)
                                         .await?
                                         .as_ref()
                                         .clone(),
                                 ),
                                 op,
                                 right: Box::new(
-                                    from_substrait_rex(r, input_schema, extensions)
+                                    from_substrait_rex(ctx, r, input_schema, extensions)
                                         .await?
                                         .as_ref()
                                         .clone(),
@@ -865,20 +1182,21 @@ pub async fn from_substrait_rex(
                     })?;
                     match &arg.arg_type {
                         Some(ArgType::Value(e)) => {
-                            let expr = from_substrait_rex(e, input_schema, extensions)
-                                .await?
-                                .as_ref()
-                                .clone();
+                            let expr =
+                                from_substrait_rex(ctx, e, input_schema, extensions)
+                                    .await?
+                                    .as_ref()
+                                    .clone();
                             Ok(Arc::new(Expr::Not(Box::new(expr))))
                         }
                         _ => not_impl_err!("Invalid arguments for Not expression"),
                     }
                 }
                 ScalarFunctionType::Like => {
-                    make_datafusion_like(false, f, input_schema, extensions).await
+                    make_datafusion_like(ctx, false, f, input_schema, extensions).await
                 }
                 ScalarFunctionType::ILike => {
-                    make_datafusion_like(true, f, input_schema, extensions).await
+                    make_datafusion_like(ctx, true, f, input_schema, extensions).await
                 }
             }
         }
@@ -890,6 +1208,7 @@ pub async fn from_substrait_rex(
             Some(output_type) => Ok(Arc::new(Expr::Cast(Cast::new(
                 Box::new(
                     from_substrait_rex(
+                        ctx,
                         cast.as_ref().input.as_ref().unwrap().as_ref(),
                         input_schema,
                         extensions,
@@ -913,7 +1232,8 @@ pub async fn from_substrait_rex(
                 ),
             };
             let order_by =
-                from_substrait_sorts(&window.sorts, input_schema, extensions).await?;
+                from_substrait_sorts(ctx, &window.sorts, input_schema, extensions)
+                    .await?;
             // Substrait does not encode WindowFrameUnits so we're using a simple logic to determine the units
             // If there is no `ORDER BY`, then by default, the frame counts each row from the lower up to upper boundary
             // If there is `ORDER BY`, then by default, each frame is a range starting from unbounded preceding to current row
@@ -926,12 +1246,14 @@ pub async fn from_substrait_rex(
             Ok(Arc::new(Expr::WindowFunction(expr::WindowFunction {
                 fun: fun?.unwrap(),
                 args: from_substriat_func_args(
+                    ctx,
                     &window.arguments,
                     input_schema,
                     extensions,
                 )
                 .await?,
                 partition_by: from_substrait_rex_vec(
+                    ctx,
                     &window.partitions,
                     input_schema,
                     extensions,
@@ -945,11 +1267,130 @@ pub async fn from_substrait_rex(
                 },
             })))
         }
+        Some(RexType::Subquery(subquery)) => match &subquery.subquery_type {
+            Some(SubqueryType::Scalar(scalar)) => {
+                let Some(input) = scalar.input.as_ref() else {
+                    return plan_err!("Scalar subquery without an input is not valid");
+                };
+                let subquery =
+                    from_substrait_subquery(ctx, input, input_schema, extensions).await?;
+                Ok(Arc::new(Expr::ScalarSubquery(subquery)))
+            }
+            Some(SubqueryType::InPredicate(in_predicate)) => {
+                if in_predicate.needles.len() != 1 {
+                    return not_impl_err!(
+                        "IN subquery with {} needles is not supported",
+                        in_predicate.needles.len()
+                    );
+                }
+                let Some(haystack) = in_predicate.haystack.as_ref() else {
+                    return plan_err!("IN subquery without a haystack is not valid");
+                };
+                let expr = from_substrait_rex(
+                    ctx,
+                    &in_predicate.needles[0],
+                    input_schema,
+                    extensions,
+                )
+                .await?;
+                let subquery =
+                    from_substrait_subquery(ctx, haystack, input_schema, extensions)
+                        .await?;
+                Ok(Arc::new(Expr::InSubquery(InSubquery::new(
+                    Box::new(expr.as_ref().clone()),
+                    subquery,
+                    false,
+                ))))
+            }
+            Some(SubqueryType::SetPredicate(set_predicate)) => {
+                match PredicateOp::try_from(set_predicate.predicate_op) {
+                    Ok(PredicateOp::Exists) => {
+                        let Some(tuples) = set_predicate.tuples.as_ref() else {
+                            return plan_err!(
+                                "EXISTS subquery without an input is not valid"
+                            );
+                        };
+                        let subquery = from_substrait_subquery(
+                            ctx,
+                            tuples,
+                            input_schema,
+                            extensions,
+                        )
+                        .await?;
+                        Ok(Arc::new(Expr::Exists(Exists::new(subquery, false))))
+                    }
+                    _ => not_impl_err!(
+                        "Unsupported set predicate: {}",
+                        set_predicate.predicate_op
+                    ),
+                }
+            }
+            _ => not_impl_err!("Unsupported subquery type: {:?}", subquery.subquery_type),
+        },
         _ => not_impl_err!("unsupported rex_type"),
     }
 }
 
-fn from_substrait_type(dt: &substrait::proto::Type) -> Result<DataType> {
+/// Convert the Substrait Rel of a subquery to a DataFusion Subquery
+///
+/// `outer_schema` is the input schema of the expression containing the
+/// subquery, which outer references of a correlated subquery refer to.
+async fn from_substrait_subquery(
+    ctx: &SessionContext,
+    rel: &Rel,
+    outer_schema: &DFSchema,
+    extensions: &HashMap<u32, &String>,
+) -> Result<Subquery> {
+    let mut schemas = outer_schemas();
+    schemas.push(Arc::new(outer_schema.clone()));
+    let plan = OUTER_SCHEMAS
+        .scope(schemas, from_substrait_rel(ctx, rel, extensions))
+        .await?;
+    Ok(Subquery {
+        outer_ref_columns: plan.all_out_ref_exprs(),
+        subquery: Arc::new(plan),
+    })
+}
+
+/// Convert a Substrait outer reference to a column of an enclosing query
+fn from_substrait_outer_ref(field_ref: &FieldReference) -> Result<Arc<Expr>> {
+    let steps_out = match &field_ref.root_type {
+        Some(RootType::OuterReference(outer)) => outer.steps_out as usize,
+        _ => 0,
+    };
+    let schemas = outer_schemas();
+    if steps_out == 0 || steps_out > schemas.len() {
+        return plan_err!(
+            "Outer reference {steps_out} steps out of {} enclosing queries",
+            schemas.len()
+        );
+    }
+    let schema = &schemas[schemas.len() - steps_out];
+    let index = match &field_ref.reference_type {
+        Some(DirectReference(direct)) => match &direct.reference_type {
+            Some(StructField(x)) if x.child.is_none() => x.field as usize,
+            _ => {
+                return not_impl_err!(
+                    "Outer references other than to a top-level field are not supported"
+                )
+            }
+        },
+        _ => return not_impl_err!("unsupported field ref type"),
+    };
+    if index >= schema.fields().len() {
+        return plan_err!(
+            "Outer reference to field {index} of a schema with {} fields",
+            schema.fields().len()
+        );
+    }
+    let field = schema.field(index);
+    Ok(Arc::new(Expr::OuterReferenceColumn(
+        field.data_type().clone(),
+        field.qualified_column(),
+    )))
+}
+
+pub(crate) fn from_substrait_type(dt: &substrait::proto::Type) -> Result<DataType> {
     match &dt.kind {
         Some(s_kind) => match s_kind {
             r#type::Kind::Bool(_) => Ok(DataType::Boolean),
@@ -1051,12 +1492,117 @@ fn from_substrait_type(dt: &substrait::proto::Type) -> Result<DataType> {
                     "Unsupported Substrait type variation {v} of type {s_kind:?}"
                 ),
             },
+            r#type::Kind::Struct(_) => from_substrait_type_with_names(dt, &[], &mut 0),
+            r#type::Kind::Map(map) => {
+                let (Some(key), Some(value)) = (&map.key, &map.value) else {
+                    return Err(DataFusionError::Substrait(
+                        "Map type must have key and value types".to_string(),
+                    ));
+                };
+                let entries = Fields::from(vec![
+                    Field::new("keys", from_substrait_type(key)?, false),
+                    Field::new(
+                        "values",
+                        from_substrait_type(value)?,
+                        is_substrait_type_nullable(value)?,
+                    ),
+                ]);
+                Ok(DataType::Map(
+                    Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+                    false,
+                ))
+            }
+            r#type::Kind::IntervalYear(_) => {
+                Ok(DataType::Interval(IntervalUnit::YearMonth))
+            }
+            r#type::Kind::IntervalDay(_) => Ok(DataType::Interval(IntervalUnit::DayTime)),
+            r#type::Kind::UserDefined(u)
+                if u.type_reference == INTERVAL_MONTH_DAY_NANO_TYPE_REF =>
+            {
+                Ok(DataType::Interval(IntervalUnit::MonthDayNano))
+            }
             _ => not_impl_err!("Unsupported Substrait type: {s_kind:?}"),
         },
         _ => not_impl_err!("`None` Substrait kind is not supported"),
     }
 }
 
+/// Convert Substrait type to DataFusion type, taking the names of struct
+/// fields from `dfs_names` in depth-first order
+///
+/// Struct fields are named `c0`, `c1`, ... if no names are given.
+fn from_substrait_type_with_names(
+    dt: &Type,
+    dfs_names: &[String],
+    name_idx: &mut usize,
+) -> Result<DataType> {
+    match &dt.kind {
+        Some(r#type::Kind::Struct(s)) => {
+            let fields = s
+                .types
+                .iter()
+                .enumerate()
+                .map(|(i, field_type)| {
+                    let name = next_struct_field_name(i, dfs_names, name_idx)?;
+                    Ok(Field::new(
+                        name,
+                        from_substrait_type_with_names(field_type, dfs_names, name_idx)?,
+                        is_substrait_type_nullable(field_type)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(DataType::Struct(fields.into()))
+        }
+        _ => from_substrait_type(dt),
+    }
+}
+
+fn next_struct_field_name(
+    i: usize,
+    dfs_names: &[String],
+    name_idx: &mut usize,
+) -> Result<String> {
+    if dfs_names.is_empty() {
+        return Ok(format!("c{i}"));
+    }
+    let name = dfs_names.get(*name_idx).cloned().ok_or_else(|| {
+        DataFusionError::Substrait(format!(
+            "Missing name for field {name_idx} of named struct"
+        ))
+    })?;
+    *name_idx += 1;
+    Ok(name)
+}
+
+fn is_substrait_type_nullable(dt: &Type) -> Result<bool> {
+    let nullability = match &dt.kind {
+        Some(kind) => match kind {
+            r#type::Kind::Bool(t) => t.nullability,
+            r#type::Kind::I8(t) => t.nullability,
+            r#type::Kind::I16(t) => t.nullability,
+            r#type::Kind::I32(t) => t.nullability,
+            r#type::Kind::I64(t) => t.nullability,
+            r#type::Kind::Fp32(t) => t.nullability,
+            r#type::Kind::Fp64(t) => t.nullability,
+            r#type::Kind::String(t) => t.nullability,
+            r#type::Kind::Binary(t) => t.nullability,
+            r#type::Kind::FixedBinary(t) => t.nullability,
+            r#type::Kind::Timestamp(t) => t.nullability,
+            r#type::Kind::Date(t) => t.nullability,
+            r#type::Kind::IntervalYear(t) => t.nullability,
+            r#type::Kind::IntervalDay(t) => t.nullability,
+            r#type::Kind::Decimal(t) => t.nullability,
+            r#type::Kind::Struct(t) => t.nullability,
+            r#type::Kind::List(t) => t.nullability,
+            r#type::Kind::Map(t) => t.nullability,
+            r#type::Kind::UserDefined(t) => t.nullability,
+            _ => return not_impl_err!("Unsupported Substrait type: {kind:?}"),
+        },
+        None => return not_impl_err!("`None` Substrait kind is not supported"),
+    };
+    Ok(nullability != r#type::Nullability::Required as i32)
+}
+
 fn from_substrait_bound(
     bound: &Option<Bound>,
     is_lower: bool,
@@ -1096,6 +1642,17 @@ fn from_substrait_bound(
 }
 
 pub(crate) fn from_substrait_literal(lit: &Literal) -> Result<ScalarValue> {
+    from_substrait_literal_with_names(lit, &[], &mut 0)
+}
+
+/// Convert Substrait literal to a DataFusion scalar, taking the names of
+/// struct fields from `dfs_names` in depth-first order
+#[allow(deprecated)]
+fn from_substrait_literal_with_names(
+    lit: &Literal,
+    dfs_names: &[String],
+    name_idx: &mut usize,
+) -> Result<ScalarValue> {
     let scalar_value = match &lit.literal_type {
         Some(LiteralType::Boolean(b)) => ScalarValue::Boolean(Some(*b)),
         Some(LiteralType::I8(n)) => match lit.type_variation_reference {
@@ -1170,13 +1727,6 @@ pub(crate) fn from_substrait_literal(lit: &Literal) -> Result<ScalarValue> {
             ScalarValue::FixedSizeBinary(b.len() as _, Some(b.clone()))
         }
         Some(LiteralType::Decimal(d)) => {
-            let value: [u8; 16] =
-                d.value
-                    .clone()
-                    .try_into()
-                    .or(Err(DataFusionError::Substrait(
-                        "Failed to parse decimal value".to_string(),
-                    )))?;
             let p = d.precision.try_into().map_err(|e| {
                 DataFusionError::Substrait(format!(
                     "Failed to parse decimal precision: {e}"
@@ -1185,20 +1735,110 @@ pub(crate) fn from_substrait_literal(lit: &Literal) -> Result<ScalarValue> {
             let s = d.scale.try_into().map_err(|e| {
                 DataFusionError::Substrait(format!("Failed to parse decimal scale: {e}"))
             })?;
-            ScalarValue::Decimal128(
-                Some(std::primitive::i128::from_le_bytes(value)),
-                p,
-                s,
-            )
+            match lit.type_variation_reference {
+                DECIMAL_128_TYPE_REF => {
+                    let value: [u8; 16] = d.value.clone().try_into().or(Err(
+                        DataFusionError::Substrait(
+                            "Failed to parse decimal value".to_string(),
+                        ),
+                    ))?;
+                    ScalarValue::Decimal128(
+                        Some(std::primitive::i128::from_le_bytes(value)),
+                        p,
+                        s,
+                    )
+                }
+                DECIMAL_256_TYPE_REF => {
+                    let value: [u8; 32] = d.value.clone().try_into().or(Err(
+                        DataFusionError::Substrait(
+                            "Failed to parse decimal value".to_string(),
+                        ),
+                    ))?;
+                    ScalarValue::Decimal256(Some(i256::from_le_bytes(value)), p, s)
+                }
+                others => {
+                    return Err(DataFusionError::Substrait(format!(
+                        "Unknown type variation reference {others}",
+                    )));
+                }
+            }
+        }
+        Some(LiteralType::IntervalYearToMonth(i)) => {
+            ScalarValue::IntervalYearMonth(Some(i.years * 12 + i.months))
+        }
+        Some(LiteralType::IntervalDayToSecond(i)) => {
+            ScalarValue::IntervalDayTime(Some(IntervalDayTimeType::make_value(
+                i.days,
+                i.seconds * 1000 + i.microseconds / 1000,
+            )))
+        }
+        Some(LiteralType::UserDefined(u))
+            if u.type_reference == INTERVAL_MONTH_DAY_NANO_TYPE_REF =>
+        {
+            let Some(value) = u.value.as_ref() else {
+                return Err(DataFusionError::Substrait(
+                    "Interval literal without a value".to_string(),
+                ));
+            };
+            let value: [u8; 16] =
+                value
+                    .value
+                    .clone()
+                    .try_into()
+                    .or(Err(DataFusionError::Substrait(
+                        "Failed to parse interval value".to_string(),
+                    )))?;
+            ScalarValue::IntervalMonthDayNano(Some(i128::from_le_bytes(value)))
+        }
+        Some(LiteralType::List(l)) => {
+            // Struct field names are only given for the top level of nested values
+            let elements = l
+                .values
+                .iter()
+                .map(from_substrait_literal)
+                .collect::<Result<Vec<_>>>()?;
+            let Some(first) = elements.first() else {
+                return Err(DataFusionError::Substrait(
+                    "Empty list must be encoded as EmptyList literal type".to_string(),
+                ));
+            };
+            ScalarValue::List(ScalarValue::new_list(&elements, &first.data_type()))
+        }
+        Some(LiteralType::EmptyList(l)) => {
+            let Some(element_type) = l.r#type.as_ref() else {
+                return Err(DataFusionError::Substrait(
+                    "Empty list type must have an element type".to_string(),
+                ));
+            };
+            let element_type = from_substrait_type(element_type)?;
+            ScalarValue::List(ScalarValue::new_list(&[], &element_type))
+        }
+        Some(LiteralType::Struct(st)) => {
+            let mut fields = Vec::with_capacity(st.fields.len());
+            let mut values = Vec::with_capacity(st.fields.len());
+            for (i, field) in st.fields.iter().enumerate() {
+                let name = next_struct_field_name(i, dfs_names, name_idx)?;
+                let value =
+                    from_substrait_literal_with_names(field, dfs_names, name_idx)?;
+                fields.push(Field::new(name, value.data_type(), true));
+                values.push(value);
+            }
+            ScalarValue::Struct(Some(values), fields.into())
+        }
+        Some(LiteralType::Null(ntype)) => {
+            from_substrait_null(ntype, dfs_names, name_idx)?
         }
-        Some(LiteralType::Null(ntype)) => from_substrait_null(ntype)?,
         _ => return not_impl_err!("Unsupported literal_type: {:?}", lit.literal_type),
     };
 
     Ok(scalar_value)
 }
 
-fn from_substrait_null(null_type: &Type) -> Result<ScalarValue> {
+fn from_substrait_null(
+    null_type: &Type,
+    dfs_names: &[String],
+    name_idx: &mut usize,
+) -> Result<ScalarValue> {
     if let Some(kind) = &null_type.kind {
         match kind {
             r#type::Kind::Bool(_) => Ok(ScalarValue::Boolean(None)),
@@ -1269,11 +1909,33 @@ fn from_substrait_null(null_type: &Type) -> Result<ScalarValue> {
                     "Unsupported Substrait type variation {v} of type {kind:?}"
                 ),
             },
-            r#type::Kind::Decimal(d) => Ok(ScalarValue::Decimal128(
-                None,
-                d.precision as u8,
-                d.scale as i8,
-            )),
+            r#type::Kind::Decimal(d) => match d.type_variation_reference {
+                DECIMAL_128_TYPE_REF => Ok(ScalarValue::Decimal128(
+                    None,
+                    d.precision as u8,
+                    d.scale as i8,
+                )),
+                DECIMAL_256_TYPE_REF => Ok(ScalarValue::Decimal256(
+                    None,
+                    d.precision as u8,
+                    d.scale as i8,
+                )),
+                v => not_impl_err!(
+                    "Unsupported Substrait type variation {v} of type {kind:?}"
+                ),
+            },
+            r#type::Kind::Struct(_) => {
+                match from_substrait_type_with_names(null_type, dfs_names, name_idx)? {
+                    DataType::Struct(fields) => Ok(ScalarValue::Struct(None, fields)),
+                    _ => unreachable!(),
+                }
+            }
+            r#type::Kind::List(_)
+            | r#type::Kind::IntervalYear(_)
+            | r#type::Kind::IntervalDay(_)
+            | r#type::Kind::UserDefined(_) => {
+                ScalarValue::try_from(&from_substrait_type(null_type)?)
+            }
             _ => not_impl_err!("Unsupported Substrait type: {kind:?}"),
         }
     } else {
@@ -1282,6 +1944,7 @@ fn from_substrait_null(null_type: &Type) -> Result<ScalarValue> {
 }
 
 async fn make_datafusion_like(
+    ctx: &SessionContext,
     case_insensitive: bool,
     f: &ScalarFunction,
     input_schema: &DFSchema,
@@ -1295,14 +1958,14 @@ async fn make_datafusion_like(
     let Some(ArgType::Value(expr_substrait)) = &f.arguments[0].arg_type else {
         return not_impl_err!("Invalid arguments type for `{fn_name}` expr");
     };
-    let expr = from_substrait_rex(expr_substrait, input_schema, extensions)
+    let expr = from_substrait_rex(ctx, expr_substrait, input_schema, extensions)
         .await?
         .as_ref()
         .clone();
     let Some(ArgType::Value(pattern_substrait)) = &f.arguments[1].arg_type else {
         return not_impl_err!("Invalid arguments type for `{fn_name}` expr");
     };
-    let pattern = from_substrait_rex(pattern_substrait, input_schema, extensions)
+    let pattern = from_substrait_rex(ctx, pattern_substrait, input_schema, extensions)
         .await?
         .as_ref()
         .clone();
@@ -1310,7 +1973,7 @@ async fn make_datafusion_like(
         return not_impl_err!("Invalid arguments type for `{fn_name}` expr");
     };
     let escape_char_expr =
-        from_substrait_rex(escape_char_substrait, input_schema, extensions)
+        from_substrait_rex(ctx, escape_char_substrait, input_schema, extensions)
             .await?
             .as_ref()
             .clone();
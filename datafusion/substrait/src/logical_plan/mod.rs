@@ -17,3 +17,20 @@
 
 pub mod consumer;
 pub mod producer;
+
+use datafusion::common::DFSchemaRef;
+
+tokio::task_local! {
+    /// The input schemas of the relations enclosing the subquery that is
+    /// being converted, innermost last. Substrait refers to their columns
+    /// with an `OuterReference`, counting `steps_out` subquery boundaries
+    /// from the innermost one.
+    static OUTER_SCHEMAS: Vec<DFSchemaRef>;
+}
+
+/// Returns the schemas of the enclosing queries, innermost last
+fn outer_schemas() -> Vec<DFSchemaRef> {
+    OUTER_SCHEMAS
+        .try_with(|schemas| schemas.clone())
+        .unwrap_or_default()
+}
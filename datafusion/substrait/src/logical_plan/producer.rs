@@ -17,26 +17,31 @@
 
 use std::collections::HashMap;
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use datafusion::logical_expr::{Like, WindowFrameUnits};
 use datafusion::{
-    arrow::datatypes::{DataType, TimeUnit},
+    arrow::array::Array,
+    arrow::datatypes::{DataType, IntervalDayTimeType, IntervalUnit, Schema, TimeUnit},
     error::{DataFusionError, Result},
     logical_expr::{WindowFrame, WindowFrameBound},
     prelude::{JoinType, SessionContext},
     scalar::ScalarValue,
 };
 
-use datafusion::common::DFSchemaRef;
-use datafusion::common::{exec_err, internal_err, not_impl_err};
+use datafusion::common::cast::as_list_array;
+use datafusion::common::{exec_err, internal_err, not_impl_err, plan_err};
+use datafusion::common::{Column, DFSchemaRef, OwnedTableReference};
 #[allow(unused_imports)]
 use datafusion::logical_expr::aggregate_function;
 use datafusion::logical_expr::expr::{
-    Alias, BinaryExpr, Case, Cast, GroupingSet, InList,
+    Alias, BinaryExpr, Case, Cast, Exists, GroupingSet, InList, InSubquery,
     ScalarFunction as DFScalarFunction, Sort, WindowFunction,
 };
-use datafusion::logical_expr::{expr, Between, JoinConstraint, LogicalPlan, Operator};
+use datafusion::logical_expr::{
+    expr, Between, CreateMemoryTable, CreateView, DdlStatement, DropTable, DropView,
+    EmptyRelation, JoinConstraint, LogicalPlan, Operator, Subquery, WriteOp,
+};
 use datafusion::prelude::Expr;
 use prost_types::Any as ProtoAny;
 use substrait::proto::expression::window_function::BoundsType;
@@ -44,17 +49,23 @@ use substrait::{
     proto::{
         aggregate_function::AggregationInvocation,
         aggregate_rel::{Grouping, Measure},
+        ddl_rel,
         expression::{
-            field_reference::ReferenceType,
+            field_reference::{OuterReference, ReferenceType, RootType},
             if_then::IfClause,
-            literal::{Decimal, LiteralType},
+            literal::{
+                Decimal, IntervalDayToSecond, IntervalYearToMonth, LiteralType, Struct,
+                UserDefined,
+            },
             mask_expression::{StructItem, StructSelect},
             reference_segment,
+            subquery::{self, SubqueryType},
             window_function::bound as SubstraitBound,
             window_function::bound::Kind as BoundKind,
             window_function::Bound,
             FieldReference, IfThen, Literal, MaskExpression, ReferenceSegment, RexType,
-            ScalarFunction, SingularOrList, WindowFunction as SubstraitWindowFunction,
+            ScalarFunction, SingularOrList, Subquery as SubstraitSubquery,
+            WindowFunction as SubstraitWindowFunction,
         },
         extensions::{
             self,
@@ -62,23 +73,25 @@ use substrait::{
         },
         function_argument::ArgType,
         join_rel, plan_rel, r#type,
-        read_rel::{NamedTable, ReadType},
+        read_rel::{NamedTable, ReadType, VirtualTable},
         rel::RelType,
         set_rel,
         sort_field::{SortDirection, SortKind},
-        AggregateFunction, AggregateRel, AggregationPhase, Expression, ExtensionLeafRel,
-        ExtensionMultiRel, ExtensionSingleRel, FetchRel, FilterRel, FunctionArgument,
-        JoinRel, NamedStruct, Plan, PlanRel, ProjectRel, ReadRel, Rel, RelRoot, SetRel,
-        SortField, SortRel,
+        write_rel, AggregateFunction, AggregateRel, AggregationPhase, CrossRel, DdlRel,
+        Expression, ExtensionLeafRel, ExtensionMultiRel, ExtensionSingleRel, FetchRel,
+        FilterRel, FunctionArgument, JoinRel, NamedObjectWrite, NamedStruct, Plan,
+        PlanRel, ProjectRel, ReadRel, Rel, RelRoot, SetRel, SortField, SortRel, WriteRel,
     },
     version,
 };
 
+use crate::logical_plan::{outer_schemas, OUTER_SCHEMAS};
 use crate::variation_const::{
     DATE_32_TYPE_REF, DATE_64_TYPE_REF, DECIMAL_128_TYPE_REF, DECIMAL_256_TYPE_REF,
-    DEFAULT_CONTAINER_TYPE_REF, DEFAULT_TYPE_REF, LARGE_CONTAINER_TYPE_REF,
-    TIMESTAMP_MICRO_TYPE_REF, TIMESTAMP_MILLI_TYPE_REF, TIMESTAMP_NANO_TYPE_REF,
-    TIMESTAMP_SECOND_TYPE_REF, UNSIGNED_INTEGER_TYPE_REF,
+    DEFAULT_CONTAINER_TYPE_REF, DEFAULT_TYPE_REF, INTERVAL_MONTH_DAY_NANO_TYPE_REF,
+    INTERVAL_MONTH_DAY_NANO_TYPE_URL, LARGE_CONTAINER_TYPE_REF, TIMESTAMP_MICRO_TYPE_REF,
+    TIMESTAMP_MILLI_TYPE_REF, TIMESTAMP_NANO_TYPE_REF, TIMESTAMP_SECOND_TYPE_REF,
+    UNSIGNED_INTEGER_TYPE_REF,
 };
 
 /// Convert DataFusion LogicalPlan to Substrait Plan
@@ -163,7 +176,9 @@ pub fn to_substrait_rel(
             let expressions = p
                 .expr
                 .iter()
-                .map(|e| to_substrait_rex(e, p.input.schema(), 0, extension_info))
+                .map(|e| {
+                    to_substrait_rex_with_ctx(ctx, e, p.input.schema(), 0, extension_info)
+                })
                 .collect::<Result<Vec<_>>>()?;
             Ok(Box::new(Rel {
                 rel_type: Some(RelType::Project(Box::new(ProjectRel {
@@ -176,7 +191,8 @@ pub fn to_substrait_rel(
         }
         LogicalPlan::Filter(filter) => {
             let input = to_substrait_rel(filter.input.as_ref(), ctx, extension_info)?;
-            let filter_expr = to_substrait_rex(
+            let filter_expr = to_substrait_rex_with_ctx(
+                ctx,
                 &filter.predicate,
                 filter.input.schema(),
                 0,
@@ -210,7 +226,9 @@ pub fn to_substrait_rel(
             let sort_fields = sort
                 .expr
                 .iter()
-                .map(|e| substrait_sort_field(e, sort.input.schema(), extension_info))
+                .map(|e| {
+                    substrait_sort_field(ctx, e, sort.input.schema(), extension_info)
+                })
                 .collect::<Result<Vec<_>>>()?;
             Ok(Box::new(Rel {
                 rel_type: Some(RelType::Sort(Box::new(SortRel {
@@ -223,7 +241,8 @@ pub fn to_substrait_rel(
         }
         LogicalPlan::Aggregate(agg) => {
             let input = to_substrait_rel(agg.input.as_ref(), ctx, extension_info)?;
-            let groupings = to_substrait_groupings(
+            let groupings = to_substrait_groupings_with_ctx(
+                ctx,
                 &agg.group_expr,
                 agg.input.schema(),
                 extension_info,
@@ -231,7 +250,14 @@ pub fn to_substrait_rel(
             let measures = agg
                 .aggr_expr
                 .iter()
-                .map(|e| to_substrait_agg_measure(e, agg.input.schema(), extension_info))
+                .map(|e| {
+                    to_substrait_agg_measure_with_ctx(
+                        ctx,
+                        e,
+                        agg.input.schema(),
+                        extension_info,
+                    )
+                })
                 .collect::<Result<Vec<_>>>()?;
 
             Ok(Box::new(Rel {
@@ -278,7 +304,8 @@ pub fn to_substrait_rel(
             // parse filter if exists
             let in_join_schema = join.left.schema().join(join.right.schema())?;
             let join_filter = match &join.filter {
-                Some(filter) => Some(to_substrait_rex(
+                Some(filter) => Some(to_substrait_rex_with_ctx(
+                    ctx,
                     filter,
                     &Arc::new(in_join_schema),
                     0,
@@ -295,6 +322,7 @@ pub fn to_substrait_rel(
                 Operator::Eq
             };
             let join_on = to_substrait_join_expr(
+                ctx,
                 &join.on,
                 eq_op,
                 join.left.schema(),
@@ -337,6 +365,50 @@ pub fn to_substrait_rel(
             // since there is no corresponding relation type in Substrait
             to_substrait_rel(alias.input.as_ref(), ctx, extension_info)
         }
+        LogicalPlan::EmptyRelation(EmptyRelation {
+            produce_one_row,
+            schema,
+        }) => {
+            // An empty relation is a virtual table without rows, or with a
+            // single row of no columns when `produce_one_row` is set
+            let values = if *produce_one_row {
+                vec![Struct { fields: vec![] }]
+            } else {
+                vec![]
+            };
+            Ok(to_substrait_virtual_table(schema, values)?)
+        }
+        LogicalPlan::Values(values) => {
+            let rows = values
+                .values
+                .iter()
+                .map(|row| {
+                    let fields = row
+                        .iter()
+                        .map(|expr| match expr {
+                            Expr::Literal(value) => to_substrait_literal(value),
+                            _ => not_impl_err!(
+                                "Only literal values are supported in VALUES: {expr:?}"
+                            ),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(Struct { fields })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(to_substrait_virtual_table(&values.schema, rows)?)
+        }
+        LogicalPlan::CrossJoin(cross_join) => {
+            let left = to_substrait_rel(cross_join.left.as_ref(), ctx, extension_info)?;
+            let right = to_substrait_rel(cross_join.right.as_ref(), ctx, extension_info)?;
+            Ok(Box::new(Rel {
+                rel_type: Some(RelType::Cross(Box::new(CrossRel {
+                    common: None,
+                    left: Some(left),
+                    right: Some(right),
+                    advanced_extension: None,
+                }))),
+            }))
+        }
         LogicalPlan::Union(union) => {
             let input_rels = union
                 .inputs
@@ -379,7 +451,8 @@ pub fn to_substrait_rel(
             // Parse WindowFunction expression
             let mut window_exprs = vec![];
             for expr in &window.window_expr {
-                window_exprs.push(to_substrait_rex(
+                window_exprs.push(to_substrait_rex_with_ctx(
+                    ctx,
                     expr,
                     window.input.schema(),
                     0,
@@ -392,6 +465,97 @@ pub fn to_substrait_rel(
                 rel_type: Some(RelType::Project(project_rel)),
             }))
         }
+        LogicalPlan::Dml(dml) => {
+            let op = match dml.op {
+                WriteOp::InsertInto => write_rel::WriteOp::Insert,
+                WriteOp::Delete => write_rel::WriteOp::Delete,
+                WriteOp::Update => write_rel::WriteOp::Update,
+                WriteOp::Ctas => write_rel::WriteOp::Ctas,
+                WriteOp::InsertOverwrite => {
+                    return not_impl_err!("Unsupported write operation: {}", dml.op)
+                }
+            };
+            let input = to_substrait_rel(dml.input.as_ref(), ctx, extension_info)?;
+            to_substrait_write_rel(&dml.table_name, &dml.table_schema, op, input)
+        }
+        LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(CreateMemoryTable {
+            name,
+            constraints,
+            input,
+            if_not_exists,
+            or_replace,
+        })) => {
+            if *if_not_exists {
+                return not_impl_err!("CREATE TABLE IF NOT EXISTS is not supported");
+            }
+            if !constraints.is_empty() {
+                return not_impl_err!("Table constraints are not supported");
+            }
+            match input.as_ref() {
+                // A table created without a query only defines its schema
+                LogicalPlan::EmptyRelation(EmptyRelation {
+                    produce_one_row: false,
+                    schema,
+                }) => to_substrait_ddl_rel(
+                    name,
+                    schema,
+                    ddl_rel::DdlObject::Table,
+                    to_substrait_ddl_create_op(*or_replace),
+                    None,
+                ),
+                _ => {
+                    if *or_replace {
+                        return not_impl_err!(
+                            "CREATE OR REPLACE TABLE AS SELECT is not supported"
+                        );
+                    }
+                    let input_rel = to_substrait_rel(input, ctx, extension_info)?;
+                    to_substrait_write_rel(
+                        name,
+                        input.schema(),
+                        write_rel::WriteOp::Ctas,
+                        input_rel,
+                    )
+                }
+            }
+        }
+        LogicalPlan::Ddl(DdlStatement::CreateView(CreateView {
+            name,
+            input,
+            or_replace,
+            ..
+        })) => {
+            let view_definition = to_substrait_rel(input, ctx, extension_info)?;
+            to_substrait_ddl_rel(
+                name,
+                input.schema(),
+                ddl_rel::DdlObject::View,
+                to_substrait_ddl_create_op(*or_replace),
+                Some(view_definition),
+            )
+        }
+        LogicalPlan::Ddl(DdlStatement::DropTable(DropTable {
+            name,
+            if_exists,
+            schema,
+        })) => to_substrait_ddl_rel(
+            name,
+            schema,
+            ddl_rel::DdlObject::Table,
+            to_substrait_ddl_drop_op(*if_exists),
+            None,
+        ),
+        LogicalPlan::Ddl(DdlStatement::DropView(DropView {
+            name,
+            if_exists,
+            schema,
+        })) => to_substrait_ddl_rel(
+            name,
+            schema,
+            ddl_rel::DdlObject::View,
+            to_substrait_ddl_drop_op(*if_exists),
+            None,
+        ),
         LogicalPlan::Extension(extension_plan) => {
             let extension_bytes = ctx
                 .state()
@@ -431,7 +595,116 @@ pub fn to_substrait_rel(
     }
 }
 
+/// Convert DataFusion schema to a Substrait `NamedStruct`
+///
+/// Substrait expects the names of all fields in depth-first order, including
+/// the names of fields nested in structs.
+pub(crate) fn to_substrait_named_struct(schema: &Schema) -> Result<NamedStruct> {
+    fn names_dfs(name: &str, data_type: &DataType, names: &mut Vec<String>) {
+        names.push(name.to_string());
+        if let DataType::Struct(fields) = data_type {
+            for field in fields {
+                names_dfs(field.name(), field.data_type(), names);
+            }
+        }
+    }
+
+    let mut names = vec![];
+    for field in schema.fields() {
+        names_dfs(field.name(), field.data_type(), &mut names);
+    }
+    let types = schema
+        .fields()
+        .iter()
+        .map(|f| to_substrait_type(f.data_type(), f.is_nullable()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(NamedStruct {
+        names,
+        r#struct: Some(r#type::Struct {
+            types,
+            type_variation_reference: DEFAULT_TYPE_REF,
+            nullability: r#type::Nullability::Required as i32,
+        }),
+    })
+}
+
+fn to_substrait_virtual_table(
+    schema: &DFSchemaRef,
+    values: Vec<Struct>,
+) -> Result<Box<Rel>> {
+    Ok(Box::new(Rel {
+        rel_type: Some(RelType::Read(Box::new(ReadRel {
+            common: None,
+            base_schema: Some(to_substrait_named_struct(&schema.as_ref().into())?),
+            filter: None,
+            best_effort_filter: None,
+            projection: None,
+            advanced_extension: None,
+            read_type: Some(ReadType::VirtualTable(VirtualTable { values })),
+        }))),
+    }))
+}
+
+fn to_substrait_write_rel(
+    table_name: &OwnedTableReference,
+    table_schema: &DFSchemaRef,
+    op: write_rel::WriteOp,
+    input: Box<Rel>,
+) -> Result<Box<Rel>> {
+    Ok(Box::new(Rel {
+        rel_type: Some(RelType::Write(Box::new(WriteRel {
+            write_type: Some(write_rel::WriteType::NamedTable(NamedObjectWrite {
+                names: table_name.to_vec(),
+                advanced_extension: None,
+            })),
+            table_schema: Some(to_substrait_named_struct(&table_schema.as_ref().into())?),
+            op: op as i32,
+            input: Some(input),
+            output: write_rel::OutputMode::Unspecified as i32,
+        }))),
+    }))
+}
+
+fn to_substrait_ddl_rel(
+    name: &OwnedTableReference,
+    schema: &DFSchemaRef,
+    object: ddl_rel::DdlObject,
+    op: ddl_rel::DdlOp,
+    view_definition: Option<Box<Rel>>,
+) -> Result<Box<Rel>> {
+    Ok(Box::new(Rel {
+        rel_type: Some(RelType::Ddl(Box::new(DdlRel {
+            write_type: Some(ddl_rel::WriteType::NamedObject(NamedObjectWrite {
+                names: name.to_vec(),
+                advanced_extension: None,
+            })),
+            table_schema: Some(to_substrait_named_struct(&schema.as_ref().into())?),
+            table_defaults: None,
+            object: object as i32,
+            op: op as i32,
+            view_definition,
+        }))),
+    }))
+}
+
+fn to_substrait_ddl_create_op(or_replace: bool) -> ddl_rel::DdlOp {
+    if or_replace {
+        ddl_rel::DdlOp::CreateOrReplace
+    } else {
+        ddl_rel::DdlOp::Create
+    }
+}
+
+fn to_substrait_ddl_drop_op(if_exists: bool) -> ddl_rel::DdlOp {
+    if if_exists {
+        ddl_rel::DdlOp::DropIfExist
+    } else {
+        ddl_rel::DdlOp::Drop
+    }
+}
+
 fn to_substrait_join_expr(
+    ctx: &SessionContext,
     join_conditions: &Vec<(Expr, Expr)>,
     eq_op: Operator,
     left_schema: &DFSchemaRef,
@@ -445,9 +718,10 @@ fn to_substrait_join_expr(
     let mut exprs: Vec<Expression> = vec![];
     for (left, right) in join_conditions {
         // Parse left
-        let l = to_substrait_rex(left, left_schema, 0, extension_info)?;
+        let l = to_substrait_rex_with_ctx(ctx, left, left_schema, 0, extension_info)?;
         // Parse right
-        let r = to_substrait_rex(
+        let r = to_substrait_rex_with_ctx(
+            ctx,
             right,
             right_schema,
             left_schema.fields().len(), // offset to return the correct index
@@ -507,6 +781,15 @@ pub fn operator_to_name(op: Operator) -> &'static str {
     }
 }
 
+/// The [`SessionContext`] used to convert subqueries by the functions that
+/// are not given one, created once and shared between calls
+fn default_context() -> &'static SessionContext {
+    static DEFAULT_CONTEXT: OnceLock<SessionContext> = OnceLock::new();
+    DEFAULT_CONTEXT.get_or_init(SessionContext::new)
+}
+
+/// Converts grouping expressions to a Substrait `Grouping`, converting
+/// subqueries with a default [`SessionContext`]
 pub fn parse_flat_grouping_exprs(
     exprs: &[Expr],
     schema: &DFSchemaRef,
@@ -514,16 +797,32 @@ pub fn parse_flat_grouping_exprs(
         Vec<extensions::SimpleExtensionDeclaration>,
         HashMap<String, u32>,
     ),
+) -> Result<Grouping> {
+    parse_flat_grouping_exprs_with_ctx(default_context(), exprs, schema, extension_info)
+}
+
+/// Converts grouping expressions to a Substrait `Grouping`, converting
+/// subqueries with `ctx`
+pub fn parse_flat_grouping_exprs_with_ctx(
+    ctx: &SessionContext,
+    exprs: &[Expr],
+    schema: &DFSchemaRef,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
 ) -> Result<Grouping> {
     let grouping_expressions = exprs
         .iter()
-        .map(|e| to_substrait_rex(e, schema, 0, extension_info))
+        .map(|e| to_substrait_rex_with_ctx(ctx, e, schema, 0, extension_info))
         .collect::<Result<Vec<_>>>()?;
     Ok(Grouping {
         grouping_expressions,
     })
 }
 
+/// Converts grouping expressions, including grouping sets, to Substrait
+/// `Grouping`s, converting subqueries with a default [`SessionContext`]
 pub fn to_substrait_groupings(
     exprs: &Vec<Expr>,
     schema: &DFSchemaRef,
@@ -531,6 +830,20 @@ pub fn to_substrait_groupings(
         Vec<extensions::SimpleExtensionDeclaration>,
         HashMap<String, u32>,
     ),
+) -> Result<Vec<Grouping>> {
+    to_substrait_groupings_with_ctx(default_context(), exprs, schema, extension_info)
+}
+
+/// Converts grouping expressions, including grouping sets, to Substrait
+/// `Grouping`s, converting subqueries with `ctx`
+pub fn to_substrait_groupings_with_ctx(
+    ctx: &SessionContext,
+    exprs: &Vec<Expr>,
+    schema: &DFSchemaRef,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
 ) -> Result<Vec<Grouping>> {
     match exprs.len() {
         1 => match &exprs[0] {
@@ -540,7 +853,14 @@ pub fn to_substrait_groupings(
                 )),
                 GroupingSet::GroupingSets(sets) => Ok(sets
                     .iter()
-                    .map(|set| parse_flat_grouping_exprs(set, schema, extension_info))
+                    .map(|set| {
+                        parse_flat_grouping_exprs_with_ctx(
+                            ctx,
+                            set,
+                            schema,
+                            extension_info,
+                        )
+                    })
                     .collect::<Result<Vec<_>>>()?),
                 GroupingSet::Rollup(set) => {
                     let mut sets: Vec<Vec<Expr>> = vec![vec![]];
@@ -550,17 +870,26 @@ pub fn to_substrait_groupings(
                     Ok(sets
                         .iter()
                         .rev()
-                        .map(|set| parse_flat_grouping_exprs(set, schema, extension_info))
+                        .map(|set| {
+                            parse_flat_grouping_exprs_with_ctx(
+                                ctx,
+                                set,
+                                schema,
+                                extension_info,
+                            )
+                        })
                         .collect::<Result<Vec<_>>>()?)
                 }
             },
-            _ => Ok(vec![parse_flat_grouping_exprs(
+            _ => Ok(vec![parse_flat_grouping_exprs_with_ctx(
+                ctx,
                 exprs,
                 schema,
                 extension_info,
             )?]),
         },
-        _ => Ok(vec![parse_flat_grouping_exprs(
+        _ => Ok(vec![parse_flat_grouping_exprs_with_ctx(
+            ctx,
             exprs,
             schema,
             extension_info,
@@ -568,7 +897,8 @@ pub fn to_substrait_groupings(
     }
 }
 
-#[allow(deprecated)]
+/// Converts an aggregate expression to a Substrait `Measure`, converting
+/// subqueries with a default [`SessionContext`]
 pub fn to_substrait_agg_measure(
     expr: &Expr,
     schema: &DFSchemaRef,
@@ -576,17 +906,32 @@ pub fn to_substrait_agg_measure(
         Vec<extensions::SimpleExtensionDeclaration>,
         HashMap<String, u32>,
     ),
+) -> Result<Measure> {
+    to_substrait_agg_measure_with_ctx(default_context(), expr, schema, extension_info)
+}
+
+/// Converts an aggregate expression to a Substrait `Measure`, converting
+/// subqueries with `ctx`
+#[allow(deprecated)]
+pub fn to_substrait_agg_measure_with_ctx(
+    ctx: &SessionContext,
+    expr: &Expr,
+    schema: &DFSchemaRef,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
 ) -> Result<Measure> {
     match expr {
         Expr::AggregateFunction(expr::AggregateFunction { fun, args, distinct, filter, order_by }) => {
             let sorts = if let Some(order_by) = order_by {
-                order_by.iter().map(|expr| to_substrait_sort_field(expr, schema, extension_info)).collect::<Result<Vec<_>>>()?
+                order_by.iter().map(|expr| to_substrait_sort_field(ctx, expr, schema, extension_info)).collect::<Result<Vec<_>>>()?
             } else {
                 vec![]
             };
             let mut arguments: Vec<FunctionArgument> = vec![];
             for arg in args {
-                arguments.push(FunctionArgument { arg_type: Some(ArgType::Value(to_substrait_rex(arg, schema, 0, extension_info)?)) });
+                arguments.push(FunctionArgument { arg_type: Some(ArgType::Value(to_substrait_rex_with_ctx(ctx, arg, schema, 0, extension_info)?)) });
             }
             let function_name = fun.to_string().to_lowercase();
             let function_anchor = _register_function(function_name, extension_info);
@@ -605,13 +950,13 @@ pub fn to_substrait_agg_measure(
                     options: vec![],
                 }),
                 filter: match filter {
-                    Some(f) => Some(to_substrait_rex(f, schema, 0, extension_info)?),
+                    Some(f) => Some(to_substrait_rex_with_ctx(ctx, f, schema, 0, extension_info)?),
                     None => None
                 }
             })
         }
         Expr::Alias(Alias{expr,..})=> {
-            to_substrait_agg_measure(expr, schema, extension_info)
+            to_substrait_agg_measure_with_ctx(ctx, expr, schema, extension_info)
         }
         _ => internal_err!(
             "Expression must be compatible with aggregation. Unsupported expression: {:?}. ExpressionType: {:?}",
@@ -623,6 +968,7 @@ pub fn to_substrait_agg_measure(
 
 /// Converts sort expression to corresponding substrait `SortField`
 fn to_substrait_sort_field(
+    ctx: &SessionContext,
     expr: &Expr,
     schema: &DFSchemaRef,
     extension_info: &mut (
@@ -639,7 +985,8 @@ fn to_substrait_sort_field(
                 (false, false) => SortDirection::DescNullsLast,
             };
             Ok(SortField {
-                expr: Some(to_substrait_rex(
+                expr: Some(to_substrait_rex_with_ctx(
+                    ctx,
                     sort.expr.deref(),
                     schema,
                     0,
@@ -652,7 +999,7 @@ fn to_substrait_sort_field(
     }
 }
 
-fn _register_function(
+pub(crate) fn _register_function(
     function_name: String,
     extension_info: &mut (
         Vec<extensions::SimpleExtensionDeclaration>,
@@ -746,7 +1093,9 @@ pub fn make_binary_op_scalar_func(
 ///                     , where `3` is the number of `left` columns (`col_ref_offset`) and `0` is the index
 ///                     of the join key column from `right`
 /// * `extension_info` - Substrait extension info. Contains registered function information
-#[allow(deprecated)]
+///
+/// Subqueries are converted with a default [`SessionContext`], use
+/// [`to_substrait_rex_with_ctx`] to convert them with the context of the plan.
 pub fn to_substrait_rex(
     expr: &Expr,
     schema: &DFSchemaRef,
@@ -755,6 +1104,29 @@ pub fn to_substrait_rex(
         Vec<extensions::SimpleExtensionDeclaration>,
         HashMap<String, u32>,
     ),
+) -> Result<Expression> {
+    to_substrait_rex_with_ctx(
+        default_context(),
+        expr,
+        schema,
+        col_ref_offset,
+        extension_info,
+    )
+}
+
+/// Convert DataFusion Expr to Substrait Rex, converting subqueries with `ctx`
+///
+/// See [`to_substrait_rex`] for the other arguments.
+#[allow(deprecated)]
+pub fn to_substrait_rex_with_ctx(
+    ctx: &SessionContext,
+    expr: &Expr,
+    schema: &DFSchemaRef,
+    col_ref_offset: usize,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
 ) -> Result<Expression> {
     match expr {
         Expr::InList(InList {
@@ -764,10 +1136,23 @@ pub fn to_substrait_rex(
         }) => {
             let substrait_list = list
                 .iter()
-                .map(|x| to_substrait_rex(x, schema, col_ref_offset, extension_info))
+                .map(|x| {
+                    to_substrait_rex_with_ctx(
+                        ctx,
+                        x,
+                        schema,
+                        col_ref_offset,
+                        extension_info,
+                    )
+                })
                 .collect::<Result<Vec<Expression>>>()?;
-            let substrait_expr =
-                to_substrait_rex(expr, schema, col_ref_offset, extension_info)?;
+            let substrait_expr = to_substrait_rex_with_ctx(
+                ctx,
+                expr,
+                schema,
+                col_ref_offset,
+                extension_info,
+            )?;
 
             let substrait_or_list = Expression {
                 rex_type: Some(RexType::SingularOrList(Box::new(SingularOrList {
@@ -777,20 +1162,7 @@ pub fn to_substrait_rex(
             };
 
             if *negated {
-                let function_anchor =
-                    _register_function("not".to_string(), extension_info);
-
-                Ok(Expression {
-                    rex_type: Some(RexType::ScalarFunction(ScalarFunction {
-                        function_reference: function_anchor,
-                        arguments: vec![FunctionArgument {
-                            arg_type: Some(ArgType::Value(substrait_or_list)),
-                        }],
-                        output_type: None,
-                        args: vec![],
-                        options: vec![],
-                    })),
-                })
+                Ok(make_substrait_not(substrait_or_list, extension_info))
             } else {
                 Ok(substrait_or_list)
             }
@@ -799,7 +1171,8 @@ pub fn to_substrait_rex(
             let mut arguments: Vec<FunctionArgument> = vec![];
             for arg in args {
                 arguments.push(FunctionArgument {
-                    arg_type: Some(ArgType::Value(to_substrait_rex(
+                    arg_type: Some(ArgType::Value(to_substrait_rex_with_ctx(
+                        ctx,
                         arg,
                         schema,
                         col_ref_offset,
@@ -827,12 +1200,27 @@ pub fn to_substrait_rex(
         }) => {
             if *negated {
                 // `expr NOT BETWEEN low AND high` can be translated into (expr < low OR high < expr)
-                let substrait_expr =
-                    to_substrait_rex(expr, schema, col_ref_offset, extension_info)?;
-                let substrait_low =
-                    to_substrait_rex(low, schema, col_ref_offset, extension_info)?;
-                let substrait_high =
-                    to_substrait_rex(high, schema, col_ref_offset, extension_info)?;
+                let substrait_expr = to_substrait_rex_with_ctx(
+                    ctx,
+                    expr,
+                    schema,
+                    col_ref_offset,
+                    extension_info,
+                )?;
+                let substrait_low = to_substrait_rex_with_ctx(
+                    ctx,
+                    low,
+                    schema,
+                    col_ref_offset,
+                    extension_info,
+                )?;
+                let substrait_high = to_substrait_rex_with_ctx(
+                    ctx,
+                    high,
+                    schema,
+                    col_ref_offset,
+                    extension_info,
+                )?;
 
                 let l_expr = make_binary_op_scalar_func(
                     &substrait_expr,
@@ -855,12 +1243,27 @@ pub fn to_substrait_rex(
                 ))
             } else {
                 // `expr BETWEEN low AND high` can be translated into (low <= expr AND expr <= high)
-                let substrait_expr =
-                    to_substrait_rex(expr, schema, col_ref_offset, extension_info)?;
-                let substrait_low =
-                    to_substrait_rex(low, schema, col_ref_offset, extension_info)?;
-                let substrait_high =
-                    to_substrait_rex(high, schema, col_ref_offset, extension_info)?;
+                let substrait_expr = to_substrait_rex_with_ctx(
+                    ctx,
+                    expr,
+                    schema,
+                    col_ref_offset,
+                    extension_info,
+                )?;
+                let substrait_low = to_substrait_rex_with_ctx(
+                    ctx,
+                    low,
+                    schema,
+                    col_ref_offset,
+                    extension_info,
+                )?;
+                let substrait_high = to_substrait_rex_with_ctx(
+                    ctx,
+                    high,
+                    schema,
+                    col_ref_offset,
+                    extension_info,
+                )?;
 
                 let l_expr = make_binary_op_scalar_func(
                     &substrait_low,
@@ -887,9 +1290,22 @@ pub fn to_substrait_rex(
             let index = schema.index_of_column(col)?;
             substrait_field_ref(index + col_ref_offset)
         }
+        Expr::OuterReferenceColumn(_, col) => to_substrait_outer_ref(col),
         Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
-            let l = to_substrait_rex(left, schema, col_ref_offset, extension_info)?;
-            let r = to_substrait_rex(right, schema, col_ref_offset, extension_info)?;
+            let l = to_substrait_rex_with_ctx(
+                ctx,
+                left,
+                schema,
+                col_ref_offset,
+                extension_info,
+            )?;
+            let r = to_substrait_rex_with_ctx(
+                ctx,
+                right,
+                schema,
+                col_ref_offset,
+                extension_info,
+            )?;
 
             Ok(make_binary_op_scalar_func(&l, &r, *op, extension_info))
         }
@@ -903,7 +1319,8 @@ pub fn to_substrait_rex(
             if let Some(e) = expr {
                 // Base expression exists
                 ifs.push(IfClause {
-                    r#if: Some(to_substrait_rex(
+                    r#if: Some(to_substrait_rex_with_ctx(
+                        ctx,
                         e,
                         schema,
                         col_ref_offset,
@@ -915,13 +1332,15 @@ pub fn to_substrait_rex(
             // Parse `when`s
             for (r#if, then) in when_then_expr {
                 ifs.push(IfClause {
-                    r#if: Some(to_substrait_rex(
+                    r#if: Some(to_substrait_rex_with_ctx(
+                        ctx,
                         r#if,
                         schema,
                         col_ref_offset,
                         extension_info,
                     )?),
-                    then: Some(to_substrait_rex(
+                    then: Some(to_substrait_rex_with_ctx(
+                        ctx,
                         then,
                         schema,
                         col_ref_offset,
@@ -932,7 +1351,8 @@ pub fn to_substrait_rex(
 
             // Parse outer `else`
             let r#else: Option<Box<Expression>> = match else_expr {
-                Some(e) => Some(Box::new(to_substrait_rex(
+                Some(e) => Some(Box::new(to_substrait_rex_with_ctx(
+                    ctx,
                     e,
                     schema,
                     col_ref_offset,
@@ -949,8 +1369,9 @@ pub fn to_substrait_rex(
             Ok(Expression {
                 rex_type: Some(RexType::Cast(Box::new(
                     substrait::proto::expression::Cast {
-                        r#type: Some(to_substrait_type(data_type)?),
-                        input: Some(Box::new(to_substrait_rex(
+                        r#type: Some(to_substrait_type(data_type, false)?),
+                        input: Some(Box::new(to_substrait_rex_with_ctx(
+                            ctx,
                             expr,
                             schema,
                             col_ref_offset,
@@ -961,9 +1382,9 @@ pub fn to_substrait_rex(
                 ))),
             })
         }
-        Expr::Literal(value) => to_substrait_literal(value),
+        Expr::Literal(value) => to_substrait_literal_expr(value),
         Expr::Alias(Alias { expr, .. }) => {
-            to_substrait_rex(expr, schema, col_ref_offset, extension_info)
+            to_substrait_rex_with_ctx(ctx, expr, schema, col_ref_offset, extension_info)
         }
         Expr::WindowFunction(WindowFunction {
             fun,
@@ -979,7 +1400,8 @@ pub fn to_substrait_rex(
             let mut arguments: Vec<FunctionArgument> = vec![];
             for arg in args {
                 arguments.push(FunctionArgument {
-                    arg_type: Some(ArgType::Value(to_substrait_rex(
+                    arg_type: Some(ArgType::Value(to_substrait_rex_with_ctx(
+                        ctx,
                         arg,
                         schema,
                         col_ref_offset,
@@ -990,12 +1412,20 @@ pub fn to_substrait_rex(
             // partition by expressions
             let partition_by = partition_by
                 .iter()
-                .map(|e| to_substrait_rex(e, schema, col_ref_offset, extension_info))
+                .map(|e| {
+                    to_substrait_rex_with_ctx(
+                        ctx,
+                        e,
+                        schema,
+                        col_ref_offset,
+                        extension_info,
+                    )
+                })
                 .collect::<Result<Vec<_>>>()?;
             // order by expressions
             let order_by = order_by
                 .iter()
-                .map(|e| substrait_sort_field(e, schema, extension_info))
+                .map(|e| substrait_sort_field(ctx, e, schema, extension_info))
                 .collect::<Result<Vec<_>>>()?;
             // window frame
             let bounds = to_substrait_bounds(window_frame)?;
@@ -1016,6 +1446,7 @@ pub fn to_substrait_rex(
             escape_char,
             case_insensitive,
         }) => make_substrait_like_expr(
+            ctx,
             *case_insensitive,
             *negated,
             expr,
@@ -1025,79 +1456,141 @@ pub fn to_substrait_rex(
             col_ref_offset,
             extension_info,
         ),
+        Expr::ScalarSubquery(subquery) => {
+            let input = to_substrait_subquery_rel(ctx, subquery, schema, extension_info)?;
+            Ok(make_substrait_subquery(SubqueryType::Scalar(Box::new(
+                subquery::Scalar { input: Some(input) },
+            ))))
+        }
+        Expr::InSubquery(InSubquery {
+            expr,
+            subquery,
+            negated,
+        }) => {
+            let needle = to_substrait_rex_with_ctx(
+                ctx,
+                expr,
+                schema,
+                col_ref_offset,
+                extension_info,
+            )?;
+            let haystack =
+                to_substrait_subquery_rel(ctx, subquery, schema, extension_info)?;
+            let in_predicate = make_substrait_subquery(SubqueryType::InPredicate(
+                Box::new(subquery::InPredicate {
+                    needles: vec![needle],
+                    haystack: Some(haystack),
+                }),
+            ));
+            if *negated {
+                Ok(make_substrait_not(in_predicate, extension_info))
+            } else {
+                Ok(in_predicate)
+            }
+        }
+        Expr::Exists(Exists { subquery, negated }) => {
+            let tuples =
+                to_substrait_subquery_rel(ctx, subquery, schema, extension_info)?;
+            let exists = make_substrait_subquery(SubqueryType::SetPredicate(Box::new(
+                subquery::SetPredicate {
+                    predicate_op: subquery::set_predicate::PredicateOp::Exists as i32,
+                    tuples: Some(tuples),
+                },
+            )));
+            if *negated {
+                Ok(make_substrait_not(exists, extension_info))
+            } else {
+                Ok(exists)
+            }
+        }
         _ => not_impl_err!("Unsupported expression: {expr:?}"),
     }
 }
 
-fn to_substrait_type(dt: &DataType) -> Result<substrait::proto::Type> {
-    let default_nullability = r#type::Nullability::Required as i32;
+fn make_substrait_subquery(subquery_type: SubqueryType) -> Expression {
+    Expression {
+        rex_type: Some(RexType::Subquery(Box::new(SubstraitSubquery {
+            subquery_type: Some(subquery_type),
+        }))),
+    }
+}
+
+pub(crate) fn to_substrait_type(
+    dt: &DataType,
+    nullable: bool,
+) -> Result<substrait::proto::Type> {
+    let nullability = if nullable {
+        r#type::Nullability::Nullable as i32
+    } else {
+        r#type::Nullability::Required as i32
+    };
     match dt {
         DataType::Null => internal_err!("Null cast is not valid"),
         DataType::Boolean => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Bool(r#type::Boolean {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Int8 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I8(r#type::I8 {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::UInt8 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I8(r#type::I8 {
                 type_variation_reference: UNSIGNED_INTEGER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Int16 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I16(r#type::I16 {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::UInt16 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I16(r#type::I16 {
                 type_variation_reference: UNSIGNED_INTEGER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Int32 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I32(r#type::I32 {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::UInt32 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I32(r#type::I32 {
                 type_variation_reference: UNSIGNED_INTEGER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Int64 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I64(r#type::I64 {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::UInt64 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I64(r#type::I64 {
                 type_variation_reference: UNSIGNED_INTEGER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         // Float16 is not supported in Substrait
         DataType::Float32 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Fp32(r#type::Fp32 {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Float64 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Fp64(r#type::Fp64 {
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         // Timezone is ignored.
@@ -1111,90 +1604,90 @@ fn to_substrait_type(dt: &DataType) -> Result<substrait::proto::Type> {
             Ok(substrait::proto::Type {
                 kind: Some(r#type::Kind::Timestamp(r#type::Timestamp {
                     type_variation_reference,
-                    nullability: default_nullability,
+                    nullability,
                 })),
             })
         }
         DataType::Date32 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Date(r#type::Date {
                 type_variation_reference: DATE_32_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Date64 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Date(r#type::Date {
                 type_variation_reference: DATE_64_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Binary => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Binary(r#type::Binary {
                 type_variation_reference: DEFAULT_CONTAINER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::FixedSizeBinary(length) => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::FixedBinary(r#type::FixedBinary {
                 length: *length,
                 type_variation_reference: DEFAULT_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::LargeBinary => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Binary(r#type::Binary {
                 type_variation_reference: LARGE_CONTAINER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::Utf8 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::String(r#type::String {
                 type_variation_reference: DEFAULT_CONTAINER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::LargeUtf8 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::String(r#type::String {
                 type_variation_reference: LARGE_CONTAINER_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
             })),
         }),
         DataType::List(inner) => {
-            let inner_type = to_substrait_type(inner.data_type())?;
+            let inner_type = to_substrait_type(inner.data_type(), inner.is_nullable())?;
             Ok(substrait::proto::Type {
                 kind: Some(r#type::Kind::List(Box::new(r#type::List {
                     r#type: Some(Box::new(inner_type)),
                     type_variation_reference: DEFAULT_CONTAINER_TYPE_REF,
-                    nullability: default_nullability,
+                    nullability,
                 }))),
             })
         }
         DataType::LargeList(inner) => {
-            let inner_type = to_substrait_type(inner.data_type())?;
+            let inner_type = to_substrait_type(inner.data_type(), inner.is_nullable())?;
             Ok(substrait::proto::Type {
                 kind: Some(r#type::Kind::List(Box::new(r#type::List {
                     r#type: Some(Box::new(inner_type)),
                     type_variation_reference: LARGE_CONTAINER_TYPE_REF,
-                    nullability: default_nullability,
+                    nullability,
                 }))),
             })
         }
         DataType::Struct(fields) => {
             let field_types = fields
                 .iter()
-                .map(|field| to_substrait_type(field.data_type()))
+                .map(|field| to_substrait_type(field.data_type(), field.is_nullable()))
                 .collect::<Result<Vec<_>>>()?;
             Ok(substrait::proto::Type {
                 kind: Some(r#type::Kind::Struct(r#type::Struct {
                     types: field_types,
                     type_variation_reference: DEFAULT_TYPE_REF,
-                    nullability: default_nullability,
+                    nullability,
                 })),
             })
         }
         DataType::Decimal128(p, s) => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Decimal(r#type::Decimal {
                 type_variation_reference: DECIMAL_128_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
                 scale: *s as i32,
                 precision: *p as i32,
             })),
@@ -1202,11 +1695,51 @@ fn to_substrait_type(dt: &DataType) -> Result<substrait::proto::Type> {
         DataType::Decimal256(p, s) => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::Decimal(r#type::Decimal {
                 type_variation_reference: DECIMAL_256_TYPE_REF,
-                nullability: default_nullability,
+                nullability,
                 scale: *s as i32,
                 precision: *p as i32,
             })),
         }),
+        DataType::Interval(IntervalUnit::YearMonth) => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::IntervalYear(r#type::IntervalYear {
+                type_variation_reference: DEFAULT_TYPE_REF,
+                nullability,
+            })),
+        }),
+        DataType::Interval(IntervalUnit::DayTime) => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::IntervalDay(r#type::IntervalDay {
+                type_variation_reference: DEFAULT_TYPE_REF,
+                nullability,
+            })),
+        }),
+        // There is no Substrait interval with months, days and nanoseconds
+        DataType::Interval(IntervalUnit::MonthDayNano) => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::UserDefined(r#type::UserDefined {
+                type_reference: INTERVAL_MONTH_DAY_NANO_TYPE_REF,
+                type_variation_reference: DEFAULT_TYPE_REF,
+                nullability,
+                type_parameters: vec![],
+            })),
+        }),
+        DataType::Map(entries, _) => {
+            let DataType::Struct(fields) = entries.data_type() else {
+                return plan_err!("Map entries must be a struct, found {entries:?}");
+            };
+            if fields.len() != 2 {
+                return plan_err!("Map entries must have a key and a value field");
+            }
+            let key_type = to_substrait_type(fields[0].data_type(), false)?;
+            let value_type =
+                to_substrait_type(fields[1].data_type(), fields[1].is_nullable())?;
+            Ok(substrait::proto::Type {
+                kind: Some(r#type::Kind::Map(Box::new(r#type::Map {
+                    key: Some(Box::new(key_type)),
+                    value: Some(Box::new(value_type)),
+                    type_variation_reference: DEFAULT_CONTAINER_TYPE_REF,
+                    nullability,
+                }))),
+            })
+        }
         _ => not_impl_err!("Unsupported cast type: {dt:?}"),
     }
 }
@@ -1241,6 +1774,7 @@ fn make_substrait_window_function(
 #[allow(deprecated)]
 #[allow(clippy::too_many_arguments)]
 fn make_substrait_like_expr(
+    ctx: &SessionContext,
     ignore_case: bool,
     negated: bool,
     expr: &Expr,
@@ -1258,10 +1792,13 @@ fn make_substrait_like_expr(
     } else {
         _register_function("like".to_string(), extension_info)
     };
-    let expr = to_substrait_rex(expr, schema, col_ref_offset, extension_info)?;
-    let pattern = to_substrait_rex(pattern, schema, col_ref_offset, extension_info)?;
-    let escape_char =
-        to_substrait_literal(&ScalarValue::Utf8(escape_char.map(|c| c.to_string())))?;
+    let expr =
+        to_substrait_rex_with_ctx(ctx, expr, schema, col_ref_offset, extension_info)?;
+    let pattern =
+        to_substrait_rex_with_ctx(ctx, pattern, schema, col_ref_offset, extension_info)?;
+    let escape_char = to_substrait_literal_expr(&ScalarValue::Utf8(
+        escape_char.map(|c| c.to_string()),
+    ))?;
     let arguments = vec![
         FunctionArgument {
             arg_type: Some(ArgType::Value(expr)),
@@ -1285,24 +1822,84 @@ fn make_substrait_like_expr(
     };
 
     if negated {
-        let function_anchor = _register_function("not".to_string(), extension_info);
-
-        Ok(Expression {
-            rex_type: Some(RexType::ScalarFunction(ScalarFunction {
-                function_reference: function_anchor,
-                arguments: vec![FunctionArgument {
-                    arg_type: Some(ArgType::Value(substrait_like)),
-                }],
-                output_type: None,
-                args: vec![],
-                options: vec![],
-            })),
-        })
+        Ok(make_substrait_not(substrait_like, extension_info))
     } else {
         Ok(substrait_like)
     }
 }
 
+/// Return Substrait `not` scalar function wrapping the given expression
+#[allow(deprecated)]
+pub(crate) fn make_substrait_not(
+    expr: Expression,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Expression {
+    let function_anchor = _register_function("not".to_string(), extension_info);
+    Expression {
+        rex_type: Some(RexType::ScalarFunction(ScalarFunction {
+            function_reference: function_anchor,
+            arguments: vec![FunctionArgument {
+                arg_type: Some(ArgType::Value(expr)),
+            }],
+            output_type: None,
+            args: vec![],
+            options: vec![],
+        })),
+    }
+}
+
+/// Convert the plan of a DataFusion subquery to a Substrait Rel
+///
+/// `outer_schema` is the input schema of the expression containing the
+/// subquery, which outer references of a correlated subquery refer to.
+fn to_substrait_subquery_rel(
+    ctx: &SessionContext,
+    subquery: &Subquery,
+    outer_schema: &DFSchemaRef,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Box<Rel>> {
+    let mut schemas = outer_schemas();
+    schemas.push(outer_schema.clone());
+    OUTER_SCHEMAS.sync_scope(schemas, || {
+        to_substrait_rel(subquery.subquery.as_ref(), ctx, extension_info)
+    })
+}
+
+/// Convert a column of an enclosing query to a Substrait outer reference
+fn to_substrait_outer_ref(col: &Column) -> Result<Expression> {
+    let schemas = outer_schemas();
+    for (steps_out, schema) in schemas.iter().rev().enumerate() {
+        if let Ok(index) = schema.index_of_column(col) {
+            return Ok(Expression {
+                rex_type: Some(RexType::Selection(Box::new(FieldReference {
+                    reference_type: Some(ReferenceType::DirectReference(
+                        ReferenceSegment {
+                            reference_type: Some(
+                                reference_segment::ReferenceType::StructField(Box::new(
+                                    reference_segment::StructField {
+                                        field: index as i32,
+                                        child: None,
+                                    },
+                                )),
+                            ),
+                        },
+                    )),
+                    root_type: Some(RootType::OuterReference(OuterReference {
+                        steps_out: steps_out as u32 + 1,
+                    })),
+                }))),
+            });
+        }
+    }
+    plan_err!("Outer reference {col} not found in the enclosing queries")
+}
+
 fn to_substrait_bound(bound: &WindowFrameBound) -> Bound {
     match bound {
         WindowFrameBound::CurrentRow => Bound {
@@ -1417,7 +2014,14 @@ fn to_substrait_bounds(window_frame: &WindowFrame) -> Result<(Bound, Bound)> {
     ))
 }
 
-fn to_substrait_literal(value: &ScalarValue) -> Result<Expression> {
+pub(crate) fn to_substrait_literal_expr(value: &ScalarValue) -> Result<Expression> {
+    Ok(Expression {
+        rex_type: Some(RexType::Literal(to_substrait_literal(value)?)),
+    })
+}
+
+#[allow(deprecated)]
+pub(crate) fn to_substrait_literal(value: &ScalarValue) -> Result<Literal> {
     let (literal_type, type_variation_reference) = match value {
         ScalarValue::Boolean(Some(b)) => (LiteralType::Boolean(*b), DEFAULT_TYPE_REF),
         ScalarValue::Int8(Some(n)) => (LiteralType::I8(*n as i32), DEFAULT_TYPE_REF),
@@ -1475,15 +2079,84 @@ fn to_substrait_literal(value: &ScalarValue) -> Result<Expression> {
             }),
             DECIMAL_128_TYPE_REF,
         ),
+        // Values of up to 38 digits fit in the 16 bytes of a Substrait
+        // decimal, the type variation keeps the full width of larger values
+        ScalarValue::Decimal256(Some(v), p, s) => (
+            LiteralType::Decimal(Decimal {
+                value: v.to_le_bytes().to_vec(),
+                precision: *p as i32,
+                scale: *s as i32,
+            }),
+            DECIMAL_256_TYPE_REF,
+        ),
+        ScalarValue::IntervalYearMonth(Some(i)) => (
+            LiteralType::IntervalYearToMonth(IntervalYearToMonth {
+                years: *i / 12,
+                months: *i % 12,
+            }),
+            DEFAULT_TYPE_REF,
+        ),
+        ScalarValue::IntervalDayTime(Some(i)) => {
+            let (days, millis) = IntervalDayTimeType::to_parts(*i);
+            (
+                LiteralType::IntervalDayToSecond(IntervalDayToSecond {
+                    days,
+                    seconds: millis / 1000,
+                    microseconds: (millis % 1000) * 1000,
+                }),
+                DEFAULT_TYPE_REF,
+            )
+        }
+        ScalarValue::IntervalMonthDayNano(Some(i)) => (
+            LiteralType::UserDefined(UserDefined {
+                type_reference: INTERVAL_MONTH_DAY_NANO_TYPE_REF,
+                type_parameters: vec![],
+                value: Some(ProtoAny {
+                    type_url: INTERVAL_MONTH_DAY_NANO_TYPE_URL.to_string(),
+                    value: i.to_le_bytes().to_vec(),
+                }),
+            }),
+            DEFAULT_TYPE_REF,
+        ),
+        ScalarValue::List(arr) if !arr.is_null(0) => {
+            let list = as_list_array(arr)?;
+            let values = list.value(0);
+            let literals = (0..values.len())
+                .map(|i| to_substrait_literal(&ScalarValue::try_from_array(&values, i)?))
+                .collect::<Result<Vec<_>>>()?;
+            if literals.is_empty() {
+                let Some(r#type::Kind::List(list_type)) =
+                    to_substrait_type(arr.data_type(), true)?.kind
+                else {
+                    return internal_err!("Expected list type for {value:?}");
+                };
+                (
+                    LiteralType::EmptyList(*list_type),
+                    DEFAULT_CONTAINER_TYPE_REF,
+                )
+            } else {
+                (
+                    LiteralType::List(substrait::proto::expression::literal::List {
+                        values: literals,
+                    }),
+                    DEFAULT_CONTAINER_TYPE_REF,
+                )
+            }
+        }
+        ScalarValue::Struct(Some(values), _) => {
+            let fields = values
+                .iter()
+                .map(to_substrait_literal)
+                .collect::<Result<Vec<_>>>()?;
+            (LiteralType::Struct(Struct { fields }), DEFAULT_TYPE_REF)
+        }
         _ => (try_to_substrait_null(value)?, DEFAULT_TYPE_REF),
     };
 
-    Ok(Expression {
-        rex_type: Some(RexType::Literal(Literal {
-            nullable: true,
-            type_variation_reference,
-            literal_type: Some(literal_type),
-        })),
+    Ok(Literal {
+        nullable: true,
+        type_variation_reference,
+        literal_type: Some(literal_type),
     })
 }
 
@@ -1642,12 +2315,32 @@ fn try_to_substrait_null(v: &ScalarValue) -> Result<LiteralType> {
                 })),
             }))
         }
+        ScalarValue::Decimal256(None, p, s) => {
+            Ok(LiteralType::Null(substrait::proto::Type {
+                kind: Some(r#type::Kind::Decimal(r#type::Decimal {
+                    scale: *s as i32,
+                    precision: *p as i32,
+                    type_variation_reference: DECIMAL_256_TYPE_REF,
+                    nullability: default_nullability,
+                })),
+            }))
+        }
+        ScalarValue::IntervalYearMonth(None)
+        | ScalarValue::IntervalDayTime(None)
+        | ScalarValue::IntervalMonthDayNano(None)
+        | ScalarValue::Struct(None, _) => {
+            Ok(LiteralType::Null(to_substrait_type(&v.data_type(), true)?))
+        }
+        ScalarValue::List(arr) if arr.is_null(0) => {
+            Ok(LiteralType::Null(to_substrait_type(arr.data_type(), true)?))
+        }
         // TODO: Extend support for remaining data types
         _ => not_impl_err!("Unsupported literal: {v:?}"),
     }
 }
 
 fn substrait_sort_field(
+    ctx: &SessionContext,
     expr: &Expr,
     schema: &DFSchemaRef,
     extension_info: &mut (
@@ -1661,7 +2354,7 @@ fn substrait_sort_field(
             asc,
             nulls_first,
         }) => {
-            let e = to_substrait_rex(expr, schema, 0, extension_info)?;
+            let e = to_substrait_rex_with_ctx(ctx, expr, schema, 0, extension_info)?;
             let d = match (asc, nulls_first) {
                 (true, true) => SortDirection::AscNullsFirst,
                 (true, false) => SortDirection::AscNullsLast,
@@ -1677,7 +2370,7 @@ fn substrait_sort_field(
     }
 }
 
-fn substrait_field_ref(index: usize) -> Result<Expression> {
+pub(crate) fn substrait_field_ref(index: usize) -> Result<Expression> {
     Ok(Expression {
         rex_type: Some(RexType::Selection(Box::new(FieldReference {
             reference_type: Some(ReferenceType::DirectReference(ReferenceSegment {
@@ -1696,6 +2389,7 @@ fn substrait_field_ref(index: usize) -> Result<Expression> {
 #[cfg(test)]
 mod test {
     use crate::logical_plan::consumer::from_substrait_literal;
+    use datafusion::arrow::datatypes::{i256, Field, Fields, IntervalMonthDayNanoType};
 
     use super::*;
 
@@ -1733,20 +2427,59 @@ mod test {
         round_trip_literal(ScalarValue::UInt64(Some(u64::MIN)))?;
         round_trip_literal(ScalarValue::UInt64(Some(u64::MAX)))?;
 
+        round_trip_literal(ScalarValue::Decimal128(Some(-123456), 10, 2))?;
+        round_trip_literal(ScalarValue::Decimal128(None, 10, 2))?;
+        round_trip_literal(ScalarValue::Decimal256(Some(i256::from(-123456)), 50, 2))?;
+        round_trip_literal(ScalarValue::Decimal256(Some(i256::MAX), 76, 0))?;
+        round_trip_literal(ScalarValue::Decimal256(None, 50, 2))?;
+
+        round_trip_literal(ScalarValue::IntervalYearMonth(None))?;
+        round_trip_literal(ScalarValue::IntervalYearMonth(Some(-13)))?;
+        round_trip_literal(ScalarValue::IntervalDayTime(None))?;
+        round_trip_literal(ScalarValue::IntervalDayTime(Some(
+            IntervalDayTimeType::make_value(-2, 1234),
+        )))?;
+        round_trip_literal(ScalarValue::IntervalMonthDayNano(None))?;
+        round_trip_literal(ScalarValue::IntervalMonthDayNano(Some(
+            IntervalMonthDayNanoType::make_value(1, -2, 3_000_000_004),
+        )))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_nested_literals() -> Result<()> {
+        let int_list = |values: &[ScalarValue]| {
+            ScalarValue::List(ScalarValue::new_list(values, &DataType::Int32))
+        };
+        round_trip_literal(int_list(&[
+            ScalarValue::Int32(Some(1)),
+            ScalarValue::Int32(None),
+        ]))?;
+        round_trip_literal(int_list(&[]))?;
+        round_trip_literal(ScalarValue::try_from(&DataType::List(Arc::new(
+            Field::new("item", DataType::Int32, true),
+        )))?)?;
+
+        let fields = Fields::from(vec![
+            Field::new("c0", DataType::Utf8, true),
+            Field::new("c1", DataType::Int64, true),
+        ]);
+        round_trip_literal(ScalarValue::Struct(
+            Some(vec![
+                ScalarValue::Utf8(Some("a".to_string())),
+                ScalarValue::Int64(None),
+            ]),
+            fields.clone(),
+        ))?;
+        round_trip_literal(ScalarValue::Struct(None, fields))?;
         Ok(())
     }
 
     fn round_trip_literal(scalar: ScalarValue) -> Result<()> {
         println!("Checking round trip of {scalar:?}");
 
-        let substrait = to_substrait_literal(&scalar)?;
-        let Expression {
-            rex_type: Some(RexType::Literal(substrait_literal)),
-        } = substrait
-        else {
-            panic!("Expected Literal expression, got {substrait:?}");
-        };
-
+        let substrait_literal = to_substrait_literal(&scalar)?;
         let roundtrip_scalar = from_substrait_literal(&substrait_literal)?;
         assert_eq!(scalar, roundtrip_scalar);
         Ok(())
@@ -16,123 +16,885 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
-use datafusion::arrow::datatypes::Schema;
-use datafusion::common::not_impl_err;
-use datafusion::datasource::listing::PartitionedFile;
+use datafusion::arrow::compute::SortOptions;
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
+use datafusion::common::parsers::CompressionTypeVariant;
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{not_impl_err, plan_err, JoinSide};
+use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
+use datafusion::datasource::listing::{FileRange, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
-use datafusion::datasource::physical_plan::{FileScanConfig, ParquetExec};
+use datafusion::datasource::physical_plan::{
+    ArrowExec, CsvExec, FileScanConfig, NdJsonExec, ParquetExec,
+};
 use datafusion::error::{DataFusionError, Result};
-use datafusion::physical_plan::{ExecutionPlan, Statistics};
-use datafusion::prelude::SessionContext;
+use datafusion::logical_expr::{aggregate_function, BuiltinScalarFunction};
+use datafusion::physical_expr::utils::collect_columns;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::aggregates::{
+    create_aggregate_expr, AggregateExec, AggregateMode, PhysicalGroupBy,
+};
+use datafusion::physical_plan::coalesce_batches::CoalesceBatchesExec;
+use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use datafusion::physical_plan::expressions::{
+    binary, cast, in_list, is_not_null, is_null, like, negative, not, try_cast, Column,
+    InListExpr, LikeExpr, Literal,
+};
+use datafusion::physical_plan::filter::FilterExec;
+use datafusion::physical_plan::functions::create_physical_expr;
+use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
+use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode, SortMergeJoinExec};
+use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::repartition::RepartitionExec;
+use datafusion::physical_plan::sorts::sort::SortExec;
+use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use datafusion::physical_plan::{ExecutionPlan, Partitioning, PhysicalExpr, Statistics};
+use datafusion::prelude::{JoinType, SessionContext};
 
 use async_recursion::async_recursion;
 use chrono::DateTime;
 use object_store::ObjectMeta;
-use substrait::proto::read_rel::local_files::file_or_files::PathType;
+use substrait::proto::aggregate_function::AggregationInvocation;
+use substrait::proto::exchange_rel::ExchangeKind;
+use substrait::proto::expression::field_reference::ReferenceType::DirectReference;
+use substrait::proto::expression::reference_segment::ReferenceType::StructField;
+use substrait::proto::expression::{cast::FailureBehavior, FieldReference, RexType};
+use substrait::proto::extensions::simple_extension_declaration::MappingType;
+use substrait::proto::function_argument::ArgType;
+use substrait::proto::read_rel::local_files::file_or_files::{FileFormat, PathType};
+use substrait::proto::sort_field::{SortDirection, SortKind};
 use substrait::proto::{
-    expression::MaskExpression, read_rel::ReadType, rel::RelType, Rel,
+    expression::MaskExpression, hash_join_rel, merge_join_rel, plan_rel,
+    read_rel::ReadRel, read_rel::ReadType, rel::RelType, Expression, FunctionArgument,
+    Plan, Rel, RelRoot, SortField,
 };
 
+use super::properties::{
+    self, AggregateProperties, CoalesceBatchesProperties, CsvFormat, FileScanProperties,
+    HashJoinProperties, JsonFormat, LimitProperties, PhysicalProperties,
+    ProjectionProperties, RepartitionProperties, SortMergeJoinProperties, SortProperties,
+};
+use crate::logical_plan::consumer::{
+    from_substrait_literal, from_substrait_named_struct, from_substrait_type, name_to_op,
+};
+
+/// Convert Substrait Plan to DataFusion ExecutionPlan
+pub async fn from_substrait_plan(
+    ctx: &SessionContext,
+    plan: &Plan,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    // Register function extension
+    let function_extension = plan
+        .extensions
+        .iter()
+        .map(|e| match &e.mapping_type {
+            Some(MappingType::ExtensionFunction(ext_f)) => {
+                Ok((ext_f.function_anchor, &ext_f.name))
+            }
+            Some(ext) => not_impl_err!("Extension type not supported: {ext:?}"),
+            None => not_impl_err!("Cannot parse empty extension"),
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    match plan.relations.as_slice() {
+        [plan_rel] => match &plan_rel.rel_type {
+            Some(plan_rel::RelType::Rel(rel)) => {
+                from_substrait_rel(ctx, rel, &function_extension).await
+            }
+            Some(plan_rel::RelType::Root(RelRoot {
+                input: Some(input), ..
+            })) => from_substrait_rel(ctx, input, &function_extension).await,
+            _ => plan_err!("Cannot parse plan relation: {:?}", plan_rel.rel_type),
+        },
+        relations => not_impl_err!(
+            "Substrait plan with more than 1 relation trees not supported. Number of relation trees: {:?}",
+            relations.len()
+        ),
+    }
+}
+
 /// Convert Substrait Rel to DataFusion ExecutionPlan
+///
+/// The DataFusion specific properties the producer attaches to relations, as
+/// described in [`properties`](super::properties), are required to rebuild
+/// the plan.
 #[async_recursion]
 pub async fn from_substrait_rel(
-    _ctx: &SessionContext,
+    ctx: &SessionContext,
     rel: &Rel,
-    _extensions: &HashMap<u32, &String>,
+    extensions: &HashMap<u32, &String>,
 ) -> Result<Arc<dyn ExecutionPlan>> {
     match &rel.rel_type {
-        Some(RelType::Read(read)) => {
-            if read.filter.is_some() || read.best_effort_filter.is_some() {
-                return not_impl_err!("Read with filter is not supported");
+        Some(RelType::Read(read)) => from_substrait_read(ctx, read, extensions),
+        Some(RelType::Project(project)) => {
+            let input = from_substrait_input(ctx, &project.input, extensions).await?;
+            let properties = ProjectionProperties::from_advanced_extension(
+                project.advanced_extension.as_ref(),
+            )?;
+            if properties.names.len() != project.expressions.len() {
+                return Err(DataFusionError::Substrait(format!(
+                    "Expected {} projection names but found {}",
+                    project.expressions.len(),
+                    properties.names.len()
+                )));
+            }
+            let schema = input.schema();
+            let exprs = project
+                .expressions
+                .iter()
+                .zip(properties.names)
+                .map(|(e, name)| {
+                    Ok((from_substrait_rex(ctx, e, &schema, extensions)?, name))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Arc::new(ProjectionExec::try_new(exprs, input)?))
+        }
+        Some(RelType::Filter(filter)) => {
+            let input = from_substrait_input(ctx, &filter.input, extensions).await?;
+            let Some(condition) = filter.condition.as_ref() else {
+                return not_impl_err!("Filter without a condition is not valid");
+            };
+            let predicate =
+                from_substrait_rex(ctx, condition, &input.schema(), extensions)?;
+            Ok(Arc::new(FilterExec::try_new(predicate, input)?))
+        }
+        Some(RelType::Aggregate(agg)) => {
+            let input = from_substrait_input(ctx, &agg.input, extensions).await?;
+            let properties = AggregateProperties::from_advanced_extension(
+                agg.advanced_extension.as_ref(),
+            )?;
+            let mode = match properties.mode() {
+                properties::AggregateMode::Partial => AggregateMode::Partial,
+                properties::AggregateMode::Final => AggregateMode::Final,
+                properties::AggregateMode::FinalPartitioned => {
+                    AggregateMode::FinalPartitioned
+                }
+                properties::AggregateMode::Single => AggregateMode::Single,
+                properties::AggregateMode::SinglePartitioned => {
+                    AggregateMode::SinglePartitioned
+                }
+            };
+            let input_schema: SchemaRef = match &properties.input_schema {
+                Some(input_schema) => {
+                    Arc::new(from_substrait_named_struct(input_schema)?)
+                }
+                None => input.schema(),
+            };
+            let schema = input.schema();
+
+            let group_exprs = match agg.groupings.as_slice() {
+                [] => vec![],
+                [grouping] => grouping
+                    .grouping_expressions
+                    .iter()
+                    .map(|e| from_substrait_rex(ctx, e, &schema, extensions))
+                    .collect::<Result<Vec<_>>>()?,
+                _ => return not_impl_err!("Grouping sets are not supported"),
+            };
+            if properties.group_names.len() != group_exprs.len()
+                || properties.aggregate_names.len() != agg.measures.len()
+            {
+                return Err(DataFusionError::Substrait(
+                    "Aggregate names do not match the aggregate expressions".to_string(),
+                ));
             }
-            if read.base_schema.is_some() {
-                return not_impl_err!("Read with schema is not supported");
+            let group_by = PhysicalGroupBy::new_single(
+                group_exprs
+                    .into_iter()
+                    .zip(properties.group_names)
+                    .collect(),
+            );
+
+            let mut aggr_exprs = vec![];
+            let mut filter_exprs = vec![];
+            let mut order_by_exprs = vec![];
+            for (measure, name) in agg.measures.iter().zip(properties.aggregate_names) {
+                let Some(f) = measure.measure.as_ref() else {
+                    return not_impl_err!("Aggregate without an aggregate function");
+                };
+                let fun = match extensions.get(&f.function_reference) {
+                    Some(function_name) => {
+                        aggregate_function::AggregateFunction::from_str(function_name)
+                    }
+                    None => not_impl_err!(
+                        "Aggregate function not found: function anchor = {:?}",
+                        f.function_reference
+                    ),
+                }?;
+                let distinct = f.invocation == AggregationInvocation::Distinct as i32;
+                let args = from_substrait_func_args(
+                    ctx,
+                    &f.arguments,
+                    &input_schema,
+                    extensions,
+                )?;
+                let ordering =
+                    from_substrait_sorts(ctx, &f.sorts, &input_schema, extensions)?;
+                aggr_exprs.push(create_aggregate_expr(
+                    &fun,
+                    distinct,
+                    &args,
+                    &ordering,
+                    &input_schema,
+                    name,
+                )?);
+                filter_exprs.push(
+                    measure
+                        .filter
+                        .as_ref()
+                        .map(|e| from_substrait_rex(ctx, e, &schema, extensions))
+                        .transpose()?,
+                );
+                order_by_exprs.push((!ordering.is_empty()).then_some(ordering));
             }
-            if read.advanced_extension.is_some() {
-                return not_impl_err!("Read with AdvancedExtension is not supported");
+
+            Ok(Arc::new(AggregateExec::try_new(
+                mode,
+                group_by,
+                aggr_exprs,
+                filter_exprs,
+                order_by_exprs,
+                input,
+                input_schema,
+            )?))
+        }
+        Some(RelType::HashJoin(join)) => {
+            let left = from_substrait_input(ctx, &join.left, extensions).await?;
+            let right = from_substrait_input(ctx, &join.right, extensions).await?;
+            let properties = HashJoinProperties::from_advanced_extension(
+                join.advanced_extension.as_ref(),
+            )?;
+            let on = from_substrait_join_on(
+                &join.left_keys,
+                &join.right_keys,
+                &left.schema(),
+                &right.schema(),
+            )?;
+            let filter = join
+                .post_join_filter
+                .as_ref()
+                .map(|e| {
+                    from_substrait_join_filter(
+                        ctx,
+                        e,
+                        &left.schema(),
+                        &right.schema(),
+                        extensions,
+                    )
+                })
+                .transpose()?;
+            let join_type = from_substrait_hash_join_type(join.r#type())?;
+            let partition_mode = match properties.partition_mode() {
+                properties::PartitionMode::Partitioned => PartitionMode::Partitioned,
+                properties::PartitionMode::CollectLeft => PartitionMode::CollectLeft,
+                properties::PartitionMode::Auto => PartitionMode::Auto,
+            };
+            Ok(Arc::new(HashJoinExec::try_new(
+                left,
+                right,
+                on,
+                filter,
+                &join_type,
+                partition_mode,
+                properties.null_equals_null,
+            )?))
+        }
+        Some(RelType::MergeJoin(join)) => {
+            if join.post_join_filter.is_some() {
+                return not_impl_err!(
+                    "MergeJoin with a post join filter is not supported"
+                );
             }
-            match &read.as_ref().read_type {
-                Some(ReadType::LocalFiles(files)) => {
-                    let mut file_groups = vec![];
-
-                    for file in &files.items {
-                        let path = if let Some(path_type) = &file.path_type {
-                            match path_type {
-                                PathType::UriPath(path) => Ok(path.clone()),
-                                PathType::UriPathGlob(path) => Ok(path.clone()),
-                                PathType::UriFile(path) => Ok(path.clone()),
-                                PathType::UriFolder(path) => Ok(path.clone()),
-                            }
-                        } else {
-                            Err(DataFusionError::Substrait(
-                                "Missing PathType".to_string(),
-                            ))
-                        }?;
-
-                        // TODO substrait plans do not have `last_modified` or `size` but `ObjectMeta`
-                        // requires them both - perhaps we can change the object-store crate
-                        // to make these optional? We cannot guarantee that we have access to the
-                        // files to get this information, depending on how this library is being
-                        // used
-                        let last_modified = DateTime::parse_from_str(
-                            "1970 Jan 1 00:00:00.000 +0000",
-                            "%Y %b %d %H:%M:%S%.3f %z",
-                        )
-                        .unwrap();
-                        let size = 0;
-
-                        let partitioned_file = PartitionedFile {
-                            object_meta: ObjectMeta {
-                                last_modified: last_modified.into(),
-                                location: path.into(),
-                                size,
-                                e_tag: None,
-                            },
-                            partition_values: vec![],
-                            range: None,
-                            extensions: None,
-                        };
-
-                        let part_index = file.partition_index as usize;
-                        while part_index >= file_groups.len() {
-                            file_groups.push(vec![]);
-                        }
-                        file_groups[part_index].push(partitioned_file)
+            let left = from_substrait_input(ctx, &join.left, extensions).await?;
+            let right = from_substrait_input(ctx, &join.right, extensions).await?;
+            let properties = SortMergeJoinProperties::from_advanced_extension(
+                join.advanced_extension.as_ref(),
+            )?;
+            let on = from_substrait_join_on(
+                &join.left_keys,
+                &join.right_keys,
+                &left.schema(),
+                &right.schema(),
+            )?;
+            let join_type = from_substrait_merge_join_type(join.r#type())?;
+            let sort_options = properties
+                .sort_options
+                .iter()
+                .map(|options| SortOptions {
+                    descending: options.descending,
+                    nulls_first: options.nulls_first,
+                })
+                .collect();
+            Ok(Arc::new(SortMergeJoinExec::try_new(
+                left,
+                right,
+                on,
+                join_type,
+                sort_options,
+                properties.null_equals_null,
+            )?))
+        }
+        Some(RelType::Sort(sort)) => {
+            let input = from_substrait_input(ctx, &sort.input, extensions).await?;
+            let properties = SortProperties::from_advanced_extension(
+                sort.advanced_extension.as_ref(),
+            )?;
+            let expr =
+                from_substrait_sorts(ctx, &sort.sorts, &input.schema(), extensions)?;
+            let fetch = properties.fetch.map(|fetch| fetch as usize);
+            if properties.merge {
+                Ok(Arc::new(
+                    SortPreservingMergeExec::new(expr, input).with_fetch(fetch),
+                ))
+            } else {
+                Ok(Arc::new(
+                    SortExec::new(expr, input)
+                        .with_fetch(fetch)
+                        .with_preserve_partitioning(properties.preserve_partitioning),
+                ))
+            }
+        }
+        Some(RelType::Fetch(fetch)) => {
+            let input = from_substrait_input(ctx, &fetch.input, extensions).await?;
+            let properties = LimitProperties::from_advanced_extension(
+                fetch.advanced_extension.as_ref(),
+            )?;
+            if properties.local {
+                return Ok(Arc::new(LocalLimitExec::new(input, fetch.count as usize)));
+            }
+            let offset = fetch.offset as usize;
+            // Since protobuf can't directly distinguish `None` vs `0` `None` is encoded as `MAX`
+            let count = if fetch.count as usize == usize::MAX {
+                None
+            } else {
+                Some(fetch.count as usize)
+            };
+            Ok(Arc::new(GlobalLimitExec::new(input, offset, count)))
+        }
+        Some(RelType::Exchange(exchange)) => {
+            let input = from_substrait_input(ctx, &exchange.input, extensions).await?;
+            let partition_count = exchange.partition_count as usize;
+            let partitioning = match &exchange.exchange_kind {
+                Some(ExchangeKind::SingleTarget(_)) if partition_count == 1 => {
+                    return Ok(Arc::new(CoalescePartitionsExec::new(input)));
+                }
+                Some(ExchangeKind::ScatterByFields(scatter)) => {
+                    let schema = input.schema();
+                    let exprs = scatter
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let col = from_substrait_field_ref(field, &schema)?;
+                            Ok(Arc::new(col) as Arc<dyn PhysicalExpr>)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Partitioning::Hash(exprs, partition_count)
+                }
+                Some(ExchangeKind::RoundRobin(_)) => {
+                    Partitioning::RoundRobinBatch(partition_count)
+                }
+                exchange_kind => {
+                    return not_impl_err!("Unsupported exchange kind: {exchange_kind:?}")
+                }
+            };
+            let properties = RepartitionProperties::from_advanced_extension(
+                exchange.advanced_extension.as_ref(),
+            )?;
+            Ok(Arc::new(
+                RepartitionExec::try_new(input, partitioning)?
+                    .with_preserve_order(properties.preserve_order),
+            ))
+        }
+        Some(RelType::ExtensionSingle(extension)) => {
+            let input = from_substrait_input(ctx, &extension.input, extensions).await?;
+            let Some(detail) = extension.detail.as_ref() else {
+                return not_impl_err!(
+                    "ExtensionSingleRel without detail is not supported"
+                );
+            };
+            let properties = CoalesceBatchesProperties::from_any(detail)?;
+            Ok(Arc::new(CoalesceBatchesExec::new(
+                input,
+                properties.target_batch_size as usize,
+            )))
+        }
+        _ => not_impl_err!("Unsupported RelType: {:?}", rel.rel_type),
+    }
+}
+
+async fn from_substrait_input(
+    ctx: &SessionContext,
+    input: &Option<Box<Rel>>,
+    extensions: &HashMap<u32, &String>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    match input {
+        Some(input) => from_substrait_rel(ctx, input, extensions).await,
+        None => not_impl_err!("Relation without an input is not valid"),
+    }
+}
+
+fn from_substrait_read(
+    ctx: &SessionContext,
+    read: &ReadRel,
+    extensions: &HashMap<u32, &String>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if read.best_effort_filter.is_some() {
+        return not_impl_err!("Read with best effort filter is not supported");
+    }
+    let Some(ReadType::LocalFiles(files)) = &read.read_type else {
+        return not_impl_err!("Only LocalFile reads are supported when parsing physical");
+    };
+    let Some(base_schema) = &read.base_schema else {
+        return not_impl_err!("Read without a schema is not supported");
+    };
+    let file_schema = Arc::new(from_substrait_named_struct(base_schema)?);
+    let properties =
+        FileScanProperties::from_advanced_extension(read.advanced_extension.as_ref())?;
+    if properties.files.len() != files.items.len() {
+        return Err(DataFusionError::Substrait(format!(
+            "Expected properties of {} files but found {}",
+            files.items.len(),
+            properties.files.len()
+        )));
+    }
+    let table_partition_cols = match &properties.table_partition_cols {
+        Some(cols) => from_substrait_named_struct(cols)?
+            .fields()
+            .iter()
+            .map(|field| field.as_ref().clone())
+            .collect(),
+        None => vec![],
+    };
+
+    // Substrait plans do not have `last_modified`, which is not needed to
+    // scan a file
+    let last_modified = DateTime::parse_from_str(
+        "1970 Jan 1 00:00:00.000 +0000",
+        "%Y %b %d %H:%M:%S%.3f %z",
+    )
+    .unwrap();
+
+    let mut file_groups = vec![];
+    for (file, file_properties) in files.items.iter().zip(&properties.files) {
+        let path = match &file.path_type {
+            Some(PathType::UriPath(path))
+            | Some(PathType::UriPathGlob(path))
+            | Some(PathType::UriFile(path))
+            | Some(PathType::UriFolder(path)) => path.clone(),
+            None => {
+                return Err(DataFusionError::Substrait("Missing PathType".to_string()))
+            }
+        };
+        let size = file_properties.size as usize;
+        let range = if file.start == 0 && file.length as usize == size {
+            None
+        } else {
+            Some(FileRange {
+                start: file.start as i64,
+                end: (file.start + file.length) as i64,
+            })
+        };
+        let partitioned_file = PartitionedFile {
+            object_meta: ObjectMeta {
+                last_modified: last_modified.into(),
+                location: path.into(),
+                size,
+                e_tag: None,
+            },
+            partition_values: file_properties
+                .partition_values
+                .iter()
+                .map(from_substrait_literal)
+                .collect::<Result<Vec<_>>>()?,
+            range,
+            extensions: None,
+        };
+
+        let part_index = file.partition_index as usize;
+        while part_index >= file_groups.len() {
+            file_groups.push(vec![]);
+        }
+        file_groups[part_index].push(partitioned_file)
+    }
+
+    // The output orderings refer to the file schema followed by the table
+    // partition columns
+    let mut table_fields = file_schema.fields().to_vec();
+    table_fields.extend(table_partition_cols.iter().cloned().map(Arc::new));
+    let table_schema = Schema::new(table_fields);
+    let output_ordering = properties
+        .output_ordering
+        .iter()
+        .map(|ordering| {
+            from_substrait_sorts(ctx, &ordering.sorts, &table_schema, extensions)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut base_config = FileScanConfig {
+        object_store_url: ObjectStoreUrl::parse(&properties.object_store_url)?,
+        file_schema: file_schema.clone(),
+        file_groups,
+        statistics: Statistics::new_unknown(&file_schema),
+        projection: None,
+        limit: properties.limit.map(|limit| limit as usize),
+        table_partition_cols,
+        output_ordering,
+        infinite_source: properties.infinite_source,
+    };
+
+    if let Some(MaskExpression { select, .. }) = &read.projection {
+        if let Some(projection) = &select.as_ref() {
+            let column_indices: Vec<usize> = projection
+                .struct_items
+                .iter()
+                .map(|item| item.field as usize)
+                .collect();
+            base_config.projection = Some(column_indices);
+        }
+    }
+
+    let file_format = files
+        .items
+        .first()
+        .and_then(|file| file.file_format.as_ref());
+    match file_format {
+        Some(FileFormat::Parquet(_)) => {
+            let predicate = read
+                .filter
+                .as_ref()
+                .map(|e| from_substrait_rex(ctx, e, &file_schema, extensions))
+                .transpose()?;
+            Ok(Arc::new(ParquetExec::new(base_config, predicate, None)))
+        }
+        _ if read.filter.is_some() => {
+            not_impl_err!("Read with filter is only supported for Parquet files")
+        }
+        Some(FileFormat::Arrow(_)) => Ok(Arc::new(ArrowExec::new(base_config))),
+        Some(FileFormat::Extension(any)) if any.type_url == CsvFormat::type_url() => {
+            let format = CsvFormat::from_any(any)?;
+            let to_u8 = |c: u32| {
+                u8::try_from(c).map_err(|_| {
+                    DataFusionError::Substrait(format!("Invalid CSV character: {c}"))
+                })
+            };
+            Ok(Arc::new(CsvExec::new(
+                base_config,
+                format.has_header,
+                to_u8(format.delimiter)?,
+                to_u8(format.quote)?,
+                format.escape.map(to_u8).transpose()?,
+                from_compression(&format.compression)?,
+            )))
+        }
+        Some(FileFormat::Extension(any)) if any.type_url == JsonFormat::type_url() => {
+            let format = JsonFormat::from_any(any)?;
+            Ok(Arc::new(NdJsonExec::new(
+                base_config,
+                from_compression(&format.compression)?,
+            )))
+        }
+        file_format => not_impl_err!("Unsupported file format: {file_format:?}"),
+    }
+}
+
+fn from_compression(compression: &str) -> Result<FileCompressionType> {
+    Ok(CompressionTypeVariant::from_str(compression)?.into())
+}
+
+fn from_substrait_field_ref(
+    field_ref: &FieldReference,
+    schema: &Schema,
+) -> Result<Column> {
+    match &field_ref.reference_type {
+        Some(DirectReference(direct)) => match &direct.reference_type {
+            Some(StructField(x)) if x.child.is_none() => {
+                let index = x.field as usize;
+                if index >= schema.fields().len() {
+                    return plan_err!(
+                        "Field reference {index} is out of bounds for schema {schema:?}"
+                    );
+                }
+                Ok(Column::new(schema.field(index).name(), index))
+            }
+            _ => not_impl_err!(
+                "Direct reference with types other than StructField is not supported"
+            ),
+        },
+        _ => not_impl_err!("unsupported field ref type"),
+    }
+}
+
+fn from_substrait_join_on(
+    left_keys: &[FieldReference],
+    right_keys: &[FieldReference],
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> Result<Vec<(Column, Column)>> {
+    if left_keys.len() != right_keys.len() {
+        return plan_err!("Join keys of both sides must have the same length");
+    }
+    left_keys
+        .iter()
+        .zip(right_keys)
+        .map(|(l, r)| {
+            Ok((
+                from_substrait_field_ref(l, left_schema)?,
+                from_substrait_field_ref(r, right_schema)?,
+            ))
+        })
+        .collect()
+}
+
+/// Convert the filter of a join from Substrait, whose columns refer to the
+/// columns of the left input followed by the columns of the right input
+///
+/// Like the physical planner, the intermediate schema of the filter only
+/// contains the columns the filter uses, in the order of the inputs.
+fn from_substrait_join_filter(
+    ctx: &SessionContext,
+    expr: &Expression,
+    left_schema: &Schema,
+    right_schema: &Schema,
+    extensions: &HashMap<u32, &String>,
+) -> Result<JoinFilter> {
+    let left_field_count = left_schema.fields().len();
+    let join_schema = Schema::new(
+        left_schema
+            .fields()
+            .iter()
+            .chain(right_schema.fields())
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+    let expr = from_substrait_rex(ctx, expr, &join_schema, extensions)?;
+
+    let mut used_indices = collect_columns(&expr)
+        .iter()
+        .map(|col| col.index())
+        .collect::<Vec<_>>();
+    used_indices.sort_unstable();
+    let column_indices = used_indices
+        .iter()
+        .map(|&index| {
+            if index < left_field_count {
+                ColumnIndex {
+                    index,
+                    side: JoinSide::Left,
+                }
+            } else {
+                ColumnIndex {
+                    index: index - left_field_count,
+                    side: JoinSide::Right,
+                }
+            }
+        })
+        .collect();
+    let filter_schema = Schema::new(
+        used_indices
+            .iter()
+            .map(|&index| join_schema.fields()[index].clone())
+            .collect::<Vec<_>>(),
+    );
+    let expr = expr.transform_up(&|expr| {
+        let Some(col) = expr.as_any().downcast_ref::<Column>() else {
+            return Ok(Transformed::No(expr));
+        };
+        // `used_indices` contains the index of every column of the filter
+        let index = used_indices.binary_search(&col.index()).unwrap();
+        Ok(Transformed::Yes(Arc::new(Column::new(col.name(), index))))
+    })?;
+    Ok(JoinFilter::new(expr, column_indices, filter_schema))
+}
+
+fn from_substrait_hash_join_type(join_type: hash_join_rel::JoinType) -> Result<JoinType> {
+    match join_type {
+        hash_join_rel::JoinType::Inner => Ok(JoinType::Inner),
+        hash_join_rel::JoinType::Left => Ok(JoinType::Left),
+        hash_join_rel::JoinType::Right => Ok(JoinType::Right),
+        hash_join_rel::JoinType::Outer => Ok(JoinType::Full),
+        hash_join_rel::JoinType::LeftSemi => Ok(JoinType::LeftSemi),
+        hash_join_rel::JoinType::RightSemi => Ok(JoinType::RightSemi),
+        hash_join_rel::JoinType::LeftAnti => Ok(JoinType::LeftAnti),
+        hash_join_rel::JoinType::RightAnti => Ok(JoinType::RightAnti),
+        _ => plan_err!("unsupported join type {join_type:?}"),
+    }
+}
+
+fn from_substrait_merge_join_type(
+    join_type: merge_join_rel::JoinType,
+) -> Result<JoinType> {
+    match join_type {
+        merge_join_rel::JoinType::Inner => Ok(JoinType::Inner),
+        merge_join_rel::JoinType::Left => Ok(JoinType::Left),
+        merge_join_rel::JoinType::Right => Ok(JoinType::Right),
+        merge_join_rel::JoinType::Outer => Ok(JoinType::Full),
+        merge_join_rel::JoinType::LeftSemi => Ok(JoinType::LeftSemi),
+        merge_join_rel::JoinType::RightSemi => Ok(JoinType::RightSemi),
+        merge_join_rel::JoinType::LeftAnti => Ok(JoinType::LeftAnti),
+        merge_join_rel::JoinType::RightAnti => Ok(JoinType::RightAnti),
+        _ => plan_err!("unsupported join type {join_type:?}"),
+    }
+}
+
+fn from_substrait_sorts(
+    ctx: &SessionContext,
+    sorts: &[SortField],
+    schema: &Schema,
+    extensions: &HashMap<u32, &String>,
+) -> Result<Vec<PhysicalSortExpr>> {
+    sorts
+        .iter()
+        .map(|sort| {
+            let Some(expr) = sort.expr.as_ref() else {
+                return not_impl_err!("Sort without an expression is not valid");
+            };
+            let (descending, nulls_first) = match &sort.sort_kind {
+                Some(SortKind::Direction(direction)) => {
+                    match SortDirection::try_from(*direction) {
+                        Ok(SortDirection::AscNullsFirst) => (false, true),
+                        Ok(SortDirection::AscNullsLast) => (false, false),
+                        Ok(SortDirection::DescNullsFirst) => (true, true),
+                        Ok(SortDirection::DescNullsLast) => (true, false),
+                        _ => return not_impl_err!("Unsupported sort direction"),
                     }
+                }
+                _ => return not_impl_err!("Sort without a direction is not supported"),
+            };
+            Ok(PhysicalSortExpr {
+                expr: from_substrait_rex(ctx, expr, schema, extensions)?,
+                options: SortOptions {
+                    descending,
+                    nulls_first,
+                },
+            })
+        })
+        .collect()
+}
 
-                    let mut base_config = FileScanConfig {
-                        object_store_url: ObjectStoreUrl::local_filesystem(),
-                        file_schema: Arc::new(Schema::empty()),
-                        file_groups,
-                        statistics: Statistics::new_unknown(&Schema::empty()),
-                        projection: None,
-                        limit: None,
-                        table_partition_cols: vec![],
-                        output_ordering: vec![],
-                        infinite_source: false,
-                    };
-
-                    if let Some(MaskExpression { select, .. }) = &read.projection {
-                        if let Some(projection) = &select.as_ref() {
-                            let column_indices: Vec<usize> = projection
-                                .struct_items
-                                .iter()
-                                .map(|item| item.field as usize)
-                                .collect();
-                            base_config.projection = Some(column_indices);
+fn from_substrait_func_args(
+    ctx: &SessionContext,
+    arguments: &[FunctionArgument],
+    schema: &Schema,
+    extensions: &HashMap<u32, &String>,
+) -> Result<Vec<Arc<dyn PhysicalExpr>>> {
+    arguments
+        .iter()
+        .map(|arg| match &arg.arg_type {
+            Some(ArgType::Value(e)) => from_substrait_rex(ctx, e, schema, extensions),
+            _ => not_impl_err!("Function argument non-Value type not supported"),
+        })
+        .collect()
+}
+
+/// Convert Substrait Rex to DataFusion PhysicalExpr
+///
+/// Field references are resolved against the given input schema.
+pub fn from_substrait_rex(
+    ctx: &SessionContext,
+    e: &Expression,
+    schema: &Schema,
+    extensions: &HashMap<u32, &String>,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    match &e.rex_type {
+        Some(RexType::Selection(field_ref)) => {
+            Ok(Arc::new(from_substrait_field_ref(field_ref, schema)?))
+        }
+        Some(RexType::Literal(lit)) => {
+            Ok(Arc::new(Literal::new(from_substrait_literal(lit)?)))
+        }
+        Some(RexType::Cast(c)) => {
+            let (Some(input), Some(output_type)) = (c.input.as_ref(), c.r#type.as_ref())
+            else {
+                return not_impl_err!("Cast without an input or a type is not valid");
+            };
+            let input = from_substrait_rex(ctx, input, schema, extensions)?;
+            let data_type = from_substrait_type(output_type)?;
+            if c.failure_behavior == FailureBehavior::ReturnNull as i32 {
+                try_cast(input, schema, data_type)
+            } else {
+                cast(input, schema, data_type)
+            }
+        }
+        Some(RexType::SingularOrList(s)) => {
+            let Some(value) = s.value.as_ref() else {
+                return not_impl_err!("SingularOrList without a value is not valid");
+            };
+            let value = from_substrait_rex(ctx, value, schema, extensions)?;
+            let list = s
+                .options
+                .iter()
+                .map(|e| from_substrait_rex(ctx, e, schema, extensions))
+                .collect::<Result<Vec<_>>>()?;
+            in_list(value, list, &false, schema)
+        }
+        Some(RexType::ScalarFunction(f)) => {
+            let Some(fn_name) = extensions.get(&f.function_reference) else {
+                return not_impl_err!(
+                    "Function not found: function reference = {:?}",
+                    f.function_reference
+                );
+            };
+            let mut args =
+                from_substrait_func_args(ctx, &f.arguments, schema, extensions)?;
+            match (fn_name.as_str(), args.len()) {
+                ("not", 1) => {
+                    let arg = args.remove(0);
+                    // Substrait has no negated forms of LIKE and IN
+                    if let Some(l) = arg.as_any().downcast_ref::<LikeExpr>() {
+                        if !l.negated() {
+                            return like(
+                                true,
+                                l.case_insensitive(),
+                                l.expr().clone(),
+                                l.pattern().clone(),
+                                schema,
+                            );
                         }
                     }
-
-                    Ok(Arc::new(ParquetExec::new(base_config, None, None))
-                        as Arc<dyn ExecutionPlan>)
+                    if let Some(i) = arg.as_any().downcast_ref::<InListExpr>() {
+                        if !i.negated() {
+                            return in_list(
+                                i.expr().clone(),
+                                i.list().to_vec(),
+                                &true,
+                                schema,
+                            );
+                        }
+                    }
+                    not(arg)
                 }
-                _ => not_impl_err!(
-                    "Only LocalFile reads are supported when parsing physical"
-                ),
+                ("is_null", 1) => is_null(args.remove(0)),
+                ("is_not_null", 1) => is_not_null(args.remove(0)),
+                ("negate", 1) => negative(args.remove(0), schema),
+                // The escape character is the third argument, which is always null
+                (name @ ("like" | "ilike"), 3) => {
+                    let pattern = args.remove(1);
+                    let expr = args.remove(0);
+                    like(false, name == "ilike", expr, pattern, schema)
+                }
+                (name, 2) if name_to_op(name).is_ok() => {
+                    let op = name_to_op(name)?;
+                    let r = args.remove(1);
+                    let l = args.remove(0);
+                    binary(l, op, r, schema)
+                }
+                (name, _) => match BuiltinScalarFunction::from_str(name) {
+                    Ok(fun) => create_physical_expr(
+                        &fun,
+                        &args,
+                        schema,
+                        ctx.state().execution_props(),
+                    ),
+                    Err(_) => not_impl_err!(
+                        "Unsupported function {name} with {} arguments",
+                        args.len()
+                    ),
+                },
             }
         }
-        _ => not_impl_err!("Unsupported RelType: {:?}", rel.rel_type),
+        _ => not_impl_err!("unsupported rex_type: {:?}", e.rex_type),
     }
 }
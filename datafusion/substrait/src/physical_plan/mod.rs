@@ -17,3 +17,4 @@
 
 pub mod consumer;
 pub mod producer;
+pub mod properties;
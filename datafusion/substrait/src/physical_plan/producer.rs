@@ -15,67 +15,1006 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use datafusion::datasource::physical_plan::ParquetExec;
-use datafusion::error::{DataFusionError, Result};
-use datafusion::physical_plan::{displayable, ExecutionPlan};
 use std::collections::HashMap;
-use substrait::proto::expression::MaskExpression;
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::Schema;
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{not_impl_err, JoinSide};
+use datafusion::datasource::physical_plan::{
+    ArrowExec, CsvExec, FileScanConfig, NdJsonExec, ParquetExec,
+};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::aggregate_function::AggregateFunction as DFAggregateFunction;
+use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
+use datafusion::physical_plan::aggregates::{AggregateExec, AggregateMode};
+use datafusion::physical_plan::coalesce_batches::CoalesceBatchesExec;
+use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use datafusion::physical_plan::expressions::{
+    ApproxDistinct, ArrayAgg, Avg, BinaryExpr, BitAnd, BitOr, BitXor, BoolAnd, BoolOr,
+    CastExpr, Column, Correlation, Count, Covariance, CovariancePop, DistinctArrayAgg,
+    DistinctCount, DistinctSum, FirstValue, InListExpr, IsNotNullExpr, IsNullExpr,
+    LastValue, LikeExpr, Literal, Max, Median, Min, NegativeExpr, NotExpr,
+    OrderSensitiveArrayAgg, Stddev, StddevPop, Sum, TryCastExpr, Variance, VariancePop,
+};
+use datafusion::physical_plan::filter::FilterExec;
+use datafusion::physical_plan::joins::utils::JoinFilter;
+use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode, SortMergeJoinExec};
+use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::repartition::RepartitionExec;
+use datafusion::physical_plan::sorts::sort::SortExec;
+use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use datafusion::physical_plan::{
+    displayable, AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr,
+};
+use datafusion::prelude::JoinType;
+use datafusion::scalar::ScalarValue;
+use substrait::proto::aggregate_function::AggregationInvocation;
+use substrait::proto::aggregate_rel::{Grouping, Measure};
+use substrait::proto::exchange_rel::{
+    ExchangeKind, RoundRobin, ScatterFields, SingleBucketExpression,
+};
+use substrait::proto::expression::field_reference::ReferenceType;
+use substrait::proto::expression::{
+    cast, reference_segment, FieldReference, MaskExpression, ReferenceSegment, RexType,
+    ScalarFunction, SingularOrList,
+};
 use substrait::proto::extensions;
-use substrait::proto::read_rel::local_files::file_or_files::ParquetReadOptions;
-use substrait::proto::read_rel::local_files::file_or_files::{FileFormat, PathType};
+use substrait::proto::function_argument::ArgType;
+use substrait::proto::read_rel::local_files::file_or_files::{
+    ArrowReadOptions, FileFormat, ParquetReadOptions, PathType,
+};
 use substrait::proto::read_rel::local_files::FileOrFiles;
 use substrait::proto::read_rel::LocalFiles;
 use substrait::proto::read_rel::ReadType;
 use substrait::proto::rel::RelType;
-use substrait::proto::ReadRel;
-use substrait::proto::Rel;
+use substrait::proto::sort_field::{SortDirection, SortKind};
+use substrait::proto::{
+    expression::mask_expression::{StructItem, StructSelect},
+    hash_join_rel, merge_join_rel, plan_rel, version, AggregateFunction, AggregateRel,
+    AggregationPhase, ExchangeRel, Expression, ExtensionSingleRel, FetchRel, FilterRel,
+    FunctionArgument, HashJoinRel, MergeJoinRel, Plan, PlanRel, ProjectRel, ReadRel, Rel,
+    RelRoot, SortField, SortRel,
+};
+
+use super::properties::{
+    self, AggregateProperties, CoalesceBatchesProperties, CsvFormat, FileProperties,
+    FileScanProperties, HashJoinProperties, JsonFormat, LimitProperties, Ordering,
+    PhysicalProperties, ProjectionProperties, RepartitionProperties,
+    SortMergeJoinProperties, SortProperties,
+};
+use crate::logical_plan::producer::{
+    _register_function, make_binary_op_scalar_func, make_substrait_not,
+    substrait_field_ref, to_substrait_literal, to_substrait_literal_expr,
+    to_substrait_named_struct, to_substrait_type,
+};
+
+/// Convert DataFusion ExecutionPlan to Substrait Plan
+pub fn to_substrait_plan(plan: &dyn ExecutionPlan) -> Result<Box<Plan>> {
+    let mut extension_info: (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ) = (vec![], HashMap::new());
+    let plan_rels = vec![PlanRel {
+        rel_type: Some(plan_rel::RelType::Root(RelRoot {
+            input: Some(*to_substrait_rel(plan, &mut extension_info)?),
+            names: plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|f| f.name().to_string())
+                .collect(),
+        })),
+    }];
+
+    let (function_extensions, _) = extension_info;
+
+    Ok(Box::new(Plan {
+        version: Some(version::version_with_producer("datafusion")),
+        extension_uris: vec![],
+        extensions: function_extensions,
+        relations: plan_rels,
+        advanced_extensions: None,
+        expected_type_urls: vec![],
+    }))
+}
 
 /// Convert DataFusion ExecutionPlan to Substrait Rel
+///
+/// Properties of the plan that Substrait can not express, such as the mode of
+/// an aggregation, are attached to the relations as described in
+/// [`properties`](super::properties).
 pub fn to_substrait_rel(
     plan: &dyn ExecutionPlan,
-    _extension_info: &mut (
+    extension_info: &mut (
         Vec<extensions::SimpleExtensionDeclaration>,
         HashMap<String, u32>,
     ),
 ) -> Result<Box<Rel>> {
-    if let Some(scan) = plan.as_any().downcast_ref::<ParquetExec>() {
-        let base_config = scan.base_config();
-        let mut substrait_files = vec![];
-        for (partition_index, files) in base_config.file_groups.iter().enumerate() {
-            for file in files {
-                substrait_files.push(FileOrFiles {
-                    partition_index: partition_index.try_into().unwrap(),
-                    start: 0,
-                    length: file.object_meta.size as u64,
-                    path_type: Some(PathType::UriPath(
-                        file.object_meta.location.as_ref().to_string(),
-                    )),
-                    file_format: Some(FileFormat::Parquet(ParquetReadOptions {})),
-                });
-            }
+    let plan_any = plan.as_any();
+
+    if let Some(scan) = plan_any.downcast_ref::<ParquetExec>() {
+        return to_substrait_file_scan(
+            scan.base_config(),
+            FileFormat::Parquet(ParquetReadOptions {}),
+            scan.predicate(),
+            extension_info,
+        );
+    }
+
+    if let Some(scan) = plan_any.downcast_ref::<CsvExec>() {
+        let format = CsvFormat {
+            has_header: scan.has_header(),
+            delimiter: scan.delimiter() as u32,
+            quote: scan.quote() as u32,
+            escape: scan.escape().map(|e| e as u32),
+            compression: scan.file_compression_type.get_variant().to_string(),
+        };
+        return to_substrait_file_scan(
+            scan.base_config(),
+            FileFormat::Extension(format.to_any()),
+            None,
+            extension_info,
+        );
+    }
+
+    if let Some(scan) = plan_any.downcast_ref::<NdJsonExec>() {
+        let format = JsonFormat {
+            compression: scan.file_compression_type().get_variant().to_string(),
+        };
+        return to_substrait_file_scan(
+            scan.base_config(),
+            FileFormat::Extension(format.to_any()),
+            None,
+            extension_info,
+        );
+    }
+
+    if let Some(scan) = plan_any.downcast_ref::<ArrowExec>() {
+        return to_substrait_file_scan(
+            scan.base_config(),
+            FileFormat::Arrow(ArrowReadOptions {}),
+            None,
+            extension_info,
+        );
+    }
+
+    if let Some(projection) = plan_any.downcast_ref::<ProjectionExec>() {
+        let input = to_substrait_rel(projection.input().as_ref(), extension_info)?;
+        let expressions = projection
+            .expr()
+            .iter()
+            .map(|(e, _)| to_substrait_rex(e, extension_info))
+            .collect::<Result<Vec<_>>>()?;
+        let properties = ProjectionProperties {
+            names: projection
+                .expr()
+                .iter()
+                .map(|(_, name)| name.clone())
+                .collect(),
+        };
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::Project(Box::new(ProjectRel {
+                common: None,
+                input: Some(input),
+                expressions,
+                advanced_extension: Some(properties.to_advanced_extension()),
+            }))),
+        }));
+    }
+
+    if let Some(filter) = plan_any.downcast_ref::<FilterExec>() {
+        let input = to_substrait_rel(filter.input().as_ref(), extension_info)?;
+        let condition = to_substrait_rex(filter.predicate(), extension_info)?;
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::Filter(Box::new(FilterRel {
+                common: None,
+                input: Some(input),
+                condition: Some(Box::new(condition)),
+                advanced_extension: None,
+            }))),
+        }));
+    }
+
+    if let Some(agg) = plan_any.downcast_ref::<AggregateExec>() {
+        let group_by = agg.group_expr();
+        if !group_by.is_single() {
+            return not_impl_err!("Grouping sets are not supported: {group_by:?}");
         }
+        let input = to_substrait_rel(agg.input().as_ref(), extension_info)?;
+        let grouping_expressions = group_by
+            .expr()
+            .iter()
+            .map(|(e, _)| to_substrait_rex(e, extension_info))
+            .collect::<Result<Vec<_>>>()?;
+        let groupings = if grouping_expressions.is_empty() {
+            vec![]
+        } else {
+            vec![Grouping {
+                grouping_expressions,
+            }]
+        };
+        let (mode, phase) = match agg.mode() {
+            AggregateMode::Partial => (
+                properties::AggregateMode::Partial,
+                AggregationPhase::InitialToIntermediate,
+            ),
+            AggregateMode::Final => (
+                properties::AggregateMode::Final,
+                AggregationPhase::IntermediateToResult,
+            ),
+            AggregateMode::FinalPartitioned => (
+                properties::AggregateMode::FinalPartitioned,
+                AggregationPhase::IntermediateToResult,
+            ),
+            AggregateMode::Single => (
+                properties::AggregateMode::Single,
+                AggregationPhase::InitialToResult,
+            ),
+            AggregateMode::SinglePartitioned => (
+                properties::AggregateMode::SinglePartitioned,
+                AggregationPhase::InitialToResult,
+            ),
+        };
+        let measures = agg
+            .aggr_expr()
+            .iter()
+            .zip(agg.filter_expr())
+            .zip(agg.order_by_expr())
+            .map(|((aggr_expr, filter), order_by)| {
+                to_substrait_agg_measure(
+                    aggr_expr.as_ref(),
+                    filter.as_ref(),
+                    order_by.as_deref().unwrap_or_default(),
+                    phase,
+                    extension_info,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let properties = AggregateProperties {
+            mode: mode as i32,
+            group_names: group_by
+                .expr()
+                .iter()
+                .map(|(_, name)| name.clone())
+                .collect(),
+            aggregate_names: agg
+                .aggr_expr()
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect(),
+            input_schema: Some(to_substrait_named_struct(&agg.input_schema())?),
+        };
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::Aggregate(Box::new(AggregateRel {
+                common: None,
+                input: Some(input),
+                groupings,
+                measures,
+                advanced_extension: Some(properties.to_advanced_extension()),
+            }))),
+        }));
+    }
 
-        Ok(Box::new(Rel {
-            rel_type: Some(RelType::Read(Box::new(ReadRel {
+    if let Some(join) = plan_any.downcast_ref::<HashJoinExec>() {
+        let left = to_substrait_rel(join.left().as_ref(), extension_info)?;
+        let right = to_substrait_rel(join.right().as_ref(), extension_info)?;
+        let (left_keys, right_keys): (Vec<_>, Vec<_>) = join
+            .on()
+            .iter()
+            .map(|(l, r)| (to_substrait_field_ref(l), to_substrait_field_ref(r)))
+            .unzip();
+        let post_join_filter = join
+            .filter()
+            .map(|filter| {
+                to_substrait_join_filter(
+                    filter,
+                    join.left().schema().fields().len(),
+                    extension_info,
+                )
+            })
+            .transpose()?;
+        let partition_mode = match join.partition_mode() {
+            PartitionMode::Partitioned => properties::PartitionMode::Partitioned,
+            PartitionMode::CollectLeft => properties::PartitionMode::CollectLeft,
+            PartitionMode::Auto => properties::PartitionMode::Auto,
+        };
+        let properties = HashJoinProperties {
+            partition_mode: partition_mode as i32,
+            null_equals_null: join.null_equals_null(),
+        };
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::HashJoin(Box::new(HashJoinRel {
                 common: None,
-                base_schema: None,
-                filter: None,
-                best_effort_filter: None,
-                projection: Some(MaskExpression {
-                    select: None,
-                    maintain_singular_struct: false,
-                }),
+                left: Some(left),
+                right: Some(right),
+                left_keys,
+                right_keys,
+                post_join_filter: post_join_filter.map(Box::new),
+                r#type: to_substrait_hash_join_type(*join.join_type()) as i32,
+                advanced_extension: Some(properties.to_advanced_extension()),
+            }))),
+        }));
+    }
+
+    if let Some(join) = plan_any.downcast_ref::<SortMergeJoinExec>() {
+        let left = to_substrait_rel(join.left(), extension_info)?;
+        let right = to_substrait_rel(join.right(), extension_info)?;
+        let (left_keys, right_keys): (Vec<_>, Vec<_>) = join
+            .on()
+            .iter()
+            .map(|(l, r)| (to_substrait_field_ref(l), to_substrait_field_ref(r)))
+            .unzip();
+        let properties = SortMergeJoinProperties {
+            sort_options: join
+                .sort_options
+                .iter()
+                .map(|options| properties::SortOptions {
+                    descending: options.descending,
+                    nulls_first: options.nulls_first,
+                })
+                .collect(),
+            null_equals_null: join.null_equals_null,
+        };
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::MergeJoin(Box::new(MergeJoinRel {
+                common: None,
+                left: Some(left),
+                right: Some(right),
+                left_keys,
+                right_keys,
+                post_join_filter: None,
+                r#type: to_substrait_merge_join_type(join.join_type()) as i32,
+                advanced_extension: Some(properties.to_advanced_extension()),
+            }))),
+        }));
+    }
+
+    if let Some(sort) = plan_any.downcast_ref::<SortExec>() {
+        let properties = SortProperties {
+            merge: false,
+            preserve_partitioning: sort.preserve_partitioning(),
+            fetch: sort.fetch().map(|fetch| fetch as u64),
+        };
+        return to_substrait_sort_rel(
+            sort.input(),
+            sort.expr(),
+            properties,
+            extension_info,
+        );
+    }
+
+    if let Some(merge) = plan_any.downcast_ref::<SortPreservingMergeExec>() {
+        let properties = SortProperties {
+            merge: true,
+            preserve_partitioning: false,
+            fetch: merge.fetch().map(|fetch| fetch as u64),
+        };
+        return to_substrait_sort_rel(
+            merge.input(),
+            merge.expr(),
+            properties,
+            extension_info,
+        );
+    }
+
+    if let Some(limit) = plan_any.downcast_ref::<GlobalLimitExec>() {
+        // Since protobuf can't directly distinguish `None` vs `0` encode `None` as `MAX`
+        let fetch = limit.fetch().unwrap_or(usize::MAX);
+        return to_substrait_fetch_rel(
+            limit.input(),
+            limit.skip(),
+            fetch,
+            false,
+            extension_info,
+        );
+    }
+
+    if let Some(limit) = plan_any.downcast_ref::<LocalLimitExec>() {
+        return to_substrait_fetch_rel(
+            limit.input(),
+            0,
+            limit.fetch(),
+            true,
+            extension_info,
+        );
+    }
+
+    if let Some(repartition) = plan_any.downcast_ref::<RepartitionExec>() {
+        let input = to_substrait_rel(repartition.input().as_ref(), extension_info)?;
+        let (partition_count, exchange_kind) = match repartition.partitioning() {
+            Partitioning::Hash(exprs, partition_count) => {
+                let fields = exprs
+                    .iter()
+                    .map(|e| match e.as_any().downcast_ref::<Column>() {
+                        Some(col) => Ok(to_substrait_field_ref(col)),
+                        None => not_impl_err!(
+                            "Hash partitioning on expression {e} is not supported"
+                        ),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                (
+                    *partition_count,
+                    ExchangeKind::ScatterByFields(Box::new(ScatterFields { fields })),
+                )
+            }
+            Partitioning::RoundRobinBatch(partition_count) => (
+                *partition_count,
+                ExchangeKind::RoundRobin(RoundRobin { exact: false }),
+            ),
+            partitioning => {
+                return not_impl_err!("Unsupported partitioning: {partitioning:?}")
+            }
+        };
+        let properties = RepartitionProperties {
+            preserve_order: repartition.preserve_order(),
+        };
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::Exchange(Box::new(ExchangeRel {
+                common: None,
+                input: Some(input),
+                partition_count: partition_count as i32,
+                targets: vec![],
+                exchange_kind: Some(exchange_kind),
+                advanced_extension: Some(properties.to_advanced_extension()),
+            }))),
+        }));
+    }
+
+    if let Some(coalesce) = plan_any.downcast_ref::<CoalescePartitionsExec>() {
+        // All rows are sent to the one and only output partition
+        let input = to_substrait_rel(coalesce.input().as_ref(), extension_info)?;
+        let target = to_substrait_literal_expr(&ScalarValue::Int32(Some(0)))?;
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::Exchange(Box::new(ExchangeRel {
+                common: None,
+                input: Some(input),
+                partition_count: 1,
+                targets: vec![],
+                exchange_kind: Some(ExchangeKind::SingleTarget(Box::new(
+                    SingleBucketExpression {
+                        expression: Some(Box::new(target)),
+                    },
+                ))),
                 advanced_extension: None,
-                read_type: Some(ReadType::LocalFiles(LocalFiles {
-                    items: substrait_files,
-                    advanced_extension: None,
-                })),
             }))),
-        }))
+        }));
+    }
+
+    if let Some(coalesce) = plan_any.downcast_ref::<CoalesceBatchesExec>() {
+        let input = to_substrait_rel(coalesce.input().as_ref(), extension_info)?;
+        let properties = CoalesceBatchesProperties {
+            target_batch_size: coalesce.target_batch_size() as u64,
+        };
+        return Ok(Box::new(Rel {
+            rel_type: Some(RelType::ExtensionSingle(Box::new(ExtensionSingleRel {
+                common: None,
+                input: Some(input),
+                detail: Some(properties.to_any()),
+            }))),
+        }));
+    }
+
+    Err(DataFusionError::Substrait(format!(
+        "Unsupported plan in Substrait physical plan producer: {}",
+        displayable(plan).one_line()
+    )))
+}
+
+fn to_substrait_file_scan(
+    base_config: &FileScanConfig,
+    file_format: FileFormat,
+    predicate: Option<&Arc<dyn PhysicalExpr>>,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Box<Rel>> {
+    let mut substrait_files = vec![];
+    let mut files = vec![];
+    for (partition_index, group) in base_config.file_groups.iter().enumerate() {
+        for file in group {
+            let (start, length) = match &file.range {
+                Some(range) => (range.start as u64, (range.end - range.start) as u64),
+                None => (0, file.object_meta.size as u64),
+            };
+            substrait_files.push(FileOrFiles {
+                partition_index: partition_index.try_into().unwrap(),
+                start,
+                length,
+                path_type: Some(PathType::UriPath(
+                    file.object_meta.location.as_ref().to_string(),
+                )),
+                file_format: Some(file_format.clone()),
+            });
+            files.push(FileProperties {
+                size: file.object_meta.size as u64,
+                partition_values: file
+                    .partition_values
+                    .iter()
+                    .map(to_substrait_literal)
+                    .collect::<Result<Vec<_>>>()?,
+            });
+        }
+    }
+
+    let projection = base_config
+        .projection
+        .as_ref()
+        .map(|projection| StructSelect {
+            struct_items: projection
+                .iter()
+                .map(|index| StructItem {
+                    field: *index as i32,
+                    child: None,
+                })
+                .collect(),
+        });
+    let filter = predicate
+        .map(|predicate| to_substrait_rex(predicate, extension_info))
+        .transpose()?;
+    let output_ordering = base_config
+        .output_ordering
+        .iter()
+        .map(|ordering| {
+            Ok(Ordering {
+                sorts: ordering
+                    .iter()
+                    .map(|sort_expr| to_substrait_sort_field(sort_expr, extension_info))
+                    .collect::<Result<Vec<_>>>()?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let properties = FileScanProperties {
+        object_store_url: base_config.object_store_url.as_str().to_string(),
+        files,
+        table_partition_cols: Some(to_substrait_named_struct(&Schema::new(
+            base_config.table_partition_cols.clone(),
+        ))?),
+        limit: base_config.limit.map(|limit| limit as u64),
+        output_ordering,
+        infinite_source: base_config.infinite_source,
+    };
+
+    Ok(Box::new(Rel {
+        rel_type: Some(RelType::Read(Box::new(ReadRel {
+            common: None,
+            base_schema: Some(to_substrait_named_struct(&base_config.file_schema)?),
+            filter: filter.map(Box::new),
+            best_effort_filter: None,
+            projection: Some(MaskExpression {
+                select: projection,
+                maintain_singular_struct: false,
+            }),
+            advanced_extension: Some(properties.to_advanced_extension()),
+            read_type: Some(ReadType::LocalFiles(LocalFiles {
+                items: substrait_files,
+                advanced_extension: None,
+            })),
+        }))),
+    }))
+}
+
+fn to_substrait_sort_rel(
+    input: &Arc<dyn ExecutionPlan>,
+    expr: &[PhysicalSortExpr],
+    properties: SortProperties,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Box<Rel>> {
+    let input = to_substrait_rel(input.as_ref(), extension_info)?;
+    let sorts = expr
+        .iter()
+        .map(|sort_expr| to_substrait_sort_field(sort_expr, extension_info))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(Rel {
+        rel_type: Some(RelType::Sort(Box::new(SortRel {
+            common: None,
+            input: Some(input),
+            sorts,
+            advanced_extension: Some(properties.to_advanced_extension()),
+        }))),
+    }))
+}
+
+fn to_substrait_fetch_rel(
+    input: &Arc<dyn ExecutionPlan>,
+    skip: usize,
+    fetch: usize,
+    local: bool,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Box<Rel>> {
+    let input = to_substrait_rel(input.as_ref(), extension_info)?;
+    let properties = LimitProperties { local };
+    Ok(Box::new(Rel {
+        rel_type: Some(RelType::Fetch(Box::new(FetchRel {
+            common: None,
+            input: Some(input),
+            offset: skip as i64,
+            count: fetch as i64,
+            advanced_extension: Some(properties.to_advanced_extension()),
+        }))),
+    }))
+}
+
+/// Convert the filter of a join to a Substrait expression
+///
+/// The columns of the filter refer to an intermediate schema of only the
+/// columns the filter uses, while Substrait refers to the columns of the left
+/// input followed by the columns of the right input.
+fn to_substrait_join_filter(
+    filter: &JoinFilter,
+    left_field_count: usize,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Expression> {
+    let column_indices = filter.column_indices();
+    let expr = filter.expression().clone().transform_up(&|expr| {
+        let Some(col) = expr.as_any().downcast_ref::<Column>() else {
+            return Ok(Transformed::No(expr));
+        };
+        let column_index = &column_indices[col.index()];
+        let index = match column_index.side {
+            JoinSide::Left => column_index.index,
+            JoinSide::Right => left_field_count + column_index.index,
+        };
+        Ok(Transformed::Yes(Arc::new(Column::new(col.name(), index))))
+    })?;
+    to_substrait_rex(&expr, extension_info)
+}
+
+fn to_substrait_hash_join_type(join_type: JoinType) -> hash_join_rel::JoinType {
+    match join_type {
+        JoinType::Inner => hash_join_rel::JoinType::Inner,
+        JoinType::Left => hash_join_rel::JoinType::Left,
+        JoinType::Right => hash_join_rel::JoinType::Right,
+        JoinType::Full => hash_join_rel::JoinType::Outer,
+        JoinType::LeftSemi => hash_join_rel::JoinType::LeftSemi,
+        JoinType::RightSemi => hash_join_rel::JoinType::RightSemi,
+        JoinType::LeftAnti => hash_join_rel::JoinType::LeftAnti,
+        JoinType::RightAnti => hash_join_rel::JoinType::RightAnti,
+    }
+}
+
+fn to_substrait_merge_join_type(join_type: JoinType) -> merge_join_rel::JoinType {
+    match join_type {
+        JoinType::Inner => merge_join_rel::JoinType::Inner,
+        JoinType::Left => merge_join_rel::JoinType::Left,
+        JoinType::Right => merge_join_rel::JoinType::Right,
+        JoinType::Full => merge_join_rel::JoinType::Outer,
+        JoinType::LeftSemi => merge_join_rel::JoinType::LeftSemi,
+        JoinType::RightSemi => merge_join_rel::JoinType::RightSemi,
+        JoinType::LeftAnti => merge_join_rel::JoinType::LeftAnti,
+        JoinType::RightAnti => merge_join_rel::JoinType::RightAnti,
+    }
+}
+
+#[allow(deprecated)]
+fn to_substrait_agg_measure(
+    aggr_expr: &dyn AggregateExpr,
+    filter: Option<&Arc<dyn PhysicalExpr>>,
+    order_by: &[PhysicalSortExpr],
+    phase: AggregationPhase,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Measure> {
+    let (fun, distinct) = to_aggregate_function(aggr_expr)?;
+    let function_anchor =
+        _register_function(fun.to_string().to_lowercase(), extension_info);
+    let arguments = aggr_expr
+        .expressions()
+        .iter()
+        .map(|arg| {
+            Ok(FunctionArgument {
+                arg_type: Some(ArgType::Value(to_substrait_rex(arg, extension_info)?)),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let sorts = order_by
+        .iter()
+        .map(|sort_expr| to_substrait_sort_field(sort_expr, extension_info))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Measure {
+        measure: Some(AggregateFunction {
+            function_reference: function_anchor,
+            arguments,
+            sorts,
+            output_type: None,
+            invocation: match distinct {
+                true => AggregationInvocation::Distinct as i32,
+                false => AggregationInvocation::All as i32,
+            },
+            phase: phase as i32,
+            args: vec![],
+            options: vec![],
+        }),
+        filter: filter
+            .map(|filter| to_substrait_rex(filter, extension_info))
+            .transpose()?,
+    })
+}
+
+/// Find the aggregate function of an aggregate expression, and whether it
+/// aggregates distinct values
+fn to_aggregate_function(
+    expr: &dyn AggregateExpr,
+) -> Result<(DFAggregateFunction, bool)> {
+    let aggr_expr = expr.as_any();
+    let fun = if aggr_expr.is::<Count>() {
+        DFAggregateFunction::Count
+    } else if aggr_expr.is::<DistinctCount>() {
+        return Ok((DFAggregateFunction::Count, true));
+    } else if aggr_expr.is::<Sum>() {
+        DFAggregateFunction::Sum
+    } else if aggr_expr.is::<DistinctSum>() {
+        return Ok((DFAggregateFunction::Sum, true));
+    } else if aggr_expr.is::<Min>() {
+        DFAggregateFunction::Min
+    } else if aggr_expr.is::<Max>() {
+        DFAggregateFunction::Max
+    } else if aggr_expr.is::<Avg>() {
+        DFAggregateFunction::Avg
+    } else if aggr_expr.is::<ArrayAgg>() || aggr_expr.is::<OrderSensitiveArrayAgg>() {
+        DFAggregateFunction::ArrayAgg
+    } else if aggr_expr.is::<DistinctArrayAgg>() {
+        return Ok((DFAggregateFunction::ArrayAgg, true));
+    } else if aggr_expr.is::<FirstValue>() {
+        DFAggregateFunction::FirstValue
+    } else if aggr_expr.is::<LastValue>() {
+        DFAggregateFunction::LastValue
+    } else if aggr_expr.is::<ApproxDistinct>() {
+        DFAggregateFunction::ApproxDistinct
+    } else if aggr_expr.is::<Median>() {
+        DFAggregateFunction::Median
+    } else if aggr_expr.is::<BitAnd>() {
+        DFAggregateFunction::BitAnd
+    } else if aggr_expr.is::<BitOr>() {
+        DFAggregateFunction::BitOr
+    } else if aggr_expr.is::<BitXor>() {
+        DFAggregateFunction::BitXor
+    } else if aggr_expr.is::<BoolAnd>() {
+        DFAggregateFunction::BoolAnd
+    } else if aggr_expr.is::<BoolOr>() {
+        DFAggregateFunction::BoolOr
+    } else if aggr_expr.is::<Variance>() {
+        DFAggregateFunction::Variance
+    } else if aggr_expr.is::<VariancePop>() {
+        DFAggregateFunction::VariancePop
+    } else if aggr_expr.is::<Stddev>() {
+        DFAggregateFunction::Stddev
+    } else if aggr_expr.is::<StddevPop>() {
+        DFAggregateFunction::StddevPop
+    } else if aggr_expr.is::<Covariance>() {
+        DFAggregateFunction::Covariance
+    } else if aggr_expr.is::<CovariancePop>() {
+        DFAggregateFunction::CovariancePop
+    } else if aggr_expr.is::<Correlation>() {
+        DFAggregateFunction::Correlation
     } else {
-        Err(DataFusionError::Substrait(format!(
-            "Unsupported plan in Substrait physical plan producer: {}",
-            displayable(plan).one_line()
-        )))
+        return not_impl_err!("Unsupported aggregate expression: {expr:?}");
+    };
+    Ok((fun, false))
+}
+
+fn to_substrait_sort_field(
+    sort_expr: &PhysicalSortExpr,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<SortField> {
+    let direction = match (sort_expr.options.descending, sort_expr.options.nulls_first) {
+        (false, true) => SortDirection::AscNullsFirst,
+        (false, false) => SortDirection::AscNullsLast,
+        (true, true) => SortDirection::DescNullsFirst,
+        (true, false) => SortDirection::DescNullsLast,
+    };
+    Ok(SortField {
+        expr: Some(to_substrait_rex(&sort_expr.expr, extension_info)?),
+        sort_kind: Some(SortKind::Direction(direction as i32)),
+    })
+}
+
+fn to_substrait_field_ref(col: &Column) -> FieldReference {
+    FieldReference {
+        reference_type: Some(ReferenceType::DirectReference(ReferenceSegment {
+            reference_type: Some(reference_segment::ReferenceType::StructField(
+                Box::new(reference_segment::StructField {
+                    field: col.index() as i32,
+                    child: None,
+                }),
+            )),
+        })),
+        root_type: None,
+    }
+}
+
+/// Convert DataFusion PhysicalExpr to Substrait Rex
+///
+/// Columns are referenced by their index in the input of the relation.
+#[allow(deprecated)]
+pub fn to_substrait_rex(
+    expr: &Arc<dyn PhysicalExpr>,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Expression> {
+    let expr_any = expr.as_any();
+
+    if let Some(col) = expr_any.downcast_ref::<Column>() {
+        return substrait_field_ref(col.index());
+    }
+
+    if let Some(lit) = expr_any.downcast_ref::<Literal>() {
+        return to_substrait_literal_expr(lit.value());
+    }
+
+    if let Some(binary) = expr_any.downcast_ref::<BinaryExpr>() {
+        let l = to_substrait_rex(binary.left(), extension_info)?;
+        let r = to_substrait_rex(binary.right(), extension_info)?;
+        return Ok(make_binary_op_scalar_func(
+            &l,
+            &r,
+            *binary.op(),
+            extension_info,
+        ));
+    }
+
+    if let Some(cast) = expr_any.downcast_ref::<CastExpr>() {
+        return to_substrait_cast(
+            cast.expr(),
+            cast.cast_type(),
+            cast::FailureBehavior::Unspecified,
+            extension_info,
+        );
+    }
+
+    if let Some(cast) = expr_any.downcast_ref::<TryCastExpr>() {
+        return to_substrait_cast(
+            cast.expr(),
+            cast.cast_type(),
+            cast::FailureBehavior::ReturnNull,
+            extension_info,
+        );
+    }
+
+    if let Some(not) = expr_any.downcast_ref::<NotExpr>() {
+        let arg = to_substrait_rex(not.arg(), extension_info)?;
+        return Ok(make_substrait_not(arg, extension_info));
+    }
+
+    if let Some(is_null) = expr_any.downcast_ref::<IsNullExpr>() {
+        let arg = to_substrait_rex(is_null.arg(), extension_info)?;
+        return Ok(make_substrait_scalar_func(
+            "is_null",
+            vec![arg],
+            extension_info,
+        ));
+    }
+
+    if let Some(is_not_null) = expr_any.downcast_ref::<IsNotNullExpr>() {
+        let arg = to_substrait_rex(is_not_null.arg(), extension_info)?;
+        return Ok(make_substrait_scalar_func(
+            "is_not_null",
+            vec![arg],
+            extension_info,
+        ));
+    }
+
+    if let Some(negative) = expr_any.downcast_ref::<NegativeExpr>() {
+        let arg = to_substrait_rex(negative.arg(), extension_info)?;
+        return Ok(make_substrait_scalar_func(
+            "negate",
+            vec![arg],
+            extension_info,
+        ));
+    }
+
+    if let Some(in_list) = expr_any.downcast_ref::<InListExpr>() {
+        let value = to_substrait_rex(in_list.expr(), extension_info)?;
+        let options = in_list
+            .list()
+            .iter()
+            .map(|e| to_substrait_rex(e, extension_info))
+            .collect::<Result<Vec<_>>>()?;
+        let substrait_or_list = Expression {
+            rex_type: Some(RexType::SingularOrList(Box::new(SingularOrList {
+                value: Some(Box::new(value)),
+                options,
+            }))),
+        };
+        return if in_list.negated() {
+            Ok(make_substrait_not(substrait_or_list, extension_info))
+        } else {
+            Ok(substrait_or_list)
+        };
+    }
+
+    if let Some(like) = expr_any.downcast_ref::<LikeExpr>() {
+        let name = if like.case_insensitive() {
+            "ilike"
+        } else {
+            "like"
+        };
+        let args = vec![
+            to_substrait_rex(like.expr(), extension_info)?,
+            to_substrait_rex(like.pattern(), extension_info)?,
+            to_substrait_literal_expr(&ScalarValue::Utf8(None))?,
+        ];
+        let substrait_like = make_substrait_scalar_func(name, args, extension_info);
+        return if like.negated() {
+            Ok(make_substrait_not(substrait_like, extension_info))
+        } else {
+            Ok(substrait_like)
+        };
+    }
+
+    if let Some(fun) = expr_any.downcast_ref::<ScalarFunctionExpr>() {
+        let args = fun
+            .args()
+            .iter()
+            .map(|arg| to_substrait_rex(arg, extension_info))
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(make_substrait_scalar_func(fun.name(), args, extension_info));
+    }
+
+    not_impl_err!("Unsupported physical expression: {expr}")
+}
+
+fn to_substrait_cast(
+    expr: &Arc<dyn PhysicalExpr>,
+    cast_type: &datafusion::arrow::datatypes::DataType,
+    failure_behavior: cast::FailureBehavior,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<Expression> {
+    Ok(Expression {
+        rex_type: Some(RexType::Cast(Box::new(
+            substrait::proto::expression::Cast {
+                r#type: Some(to_substrait_type(cast_type, false)?),
+                input: Some(Box::new(to_substrait_rex(expr, extension_info)?)),
+                failure_behavior: failure_behavior as i32,
+            },
+        ))),
+    })
+}
+
+/// Return Substrait scalar function with the given arguments
+#[allow(deprecated)]
+fn make_substrait_scalar_func(
+    name: &str,
+    args: Vec<Expression>,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Expression {
+    let function_anchor = _register_function(name.to_string(), extension_info);
+    Expression {
+        rex_type: Some(RexType::ScalarFunction(ScalarFunction {
+            function_reference: function_anchor,
+            arguments: args
+                .into_iter()
+                .map(|arg| FunctionArgument {
+                    arg_type: Some(ArgType::Value(arg)),
+                })
+                .collect(),
+            output_type: None,
+            args: vec![],
+            options: vec![],
+        })),
     }
 }
@@ -0,0 +1,310 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! DataFusion specific properties of physical plans
+//!
+//! Substrait relations do not capture everything needed to execute a physical
+//! plan without planning it again, such as the mode of an aggregation or the
+//! partition values of scanned files. The producer attaches these properties
+//! to relations as protobuf messages defined in this module, packed into a
+//! [`prost_types::Any`]. Most are the `enhancement` of the relation's
+//! [`AdvancedExtension`], as they change how a relation is executed and must
+//! not be ignored by consumers.
+
+use datafusion::error::{DataFusionError, Result};
+use prost::Message;
+use prost_types::Any as ProtoAny;
+use substrait::proto::expression::Literal;
+use substrait::proto::extensions::AdvancedExtension;
+use substrait::proto::{NamedStruct, SortField};
+
+/// Prefix of the type URL of all properties
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/datafusion.substrait.";
+
+/// A protobuf message holding properties of a physical plan
+pub trait PhysicalProperties: Message + Default + Sized {
+    /// Name of the message, which is part of its type URL
+    const NAME: &'static str;
+
+    /// Type URL of the message when packed into a [`ProtoAny`]
+    fn type_url() -> String {
+        format!("{TYPE_URL_PREFIX}{}", Self::NAME)
+    }
+
+    /// Pack the properties into a [`ProtoAny`]
+    fn to_any(&self) -> ProtoAny {
+        ProtoAny {
+            type_url: Self::type_url(),
+            value: self.encode_to_vec(),
+        }
+    }
+
+    /// Unpack the properties from a [`ProtoAny`]
+    fn from_any(any: &ProtoAny) -> Result<Self> {
+        if any.type_url != Self::type_url() {
+            return Err(DataFusionError::Substrait(format!(
+                "Expected {} but found {}",
+                Self::type_url(),
+                any.type_url
+            )));
+        }
+        Self::decode(any.value.as_slice()).map_err(|e| {
+            DataFusionError::Substrait(format!("Failed to decode {}: {e}", Self::NAME))
+        })
+    }
+
+    /// Attach the properties to a relation as an enhancement
+    fn to_advanced_extension(&self) -> AdvancedExtension {
+        AdvancedExtension {
+            optimization: None,
+            enhancement: Some(self.to_any()),
+        }
+    }
+
+    /// Read the properties from the enhancement of a relation
+    fn from_advanced_extension(extension: Option<&AdvancedExtension>) -> Result<Self> {
+        match extension.and_then(|e| e.enhancement.as_ref()) {
+            Some(any) => Self::from_any(any),
+            None => Err(DataFusionError::Substrait(format!(
+                "Missing {} enhancement",
+                Self::NAME
+            ))),
+        }
+    }
+}
+
+/// Properties of a `ProjectionExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct ProjectionProperties {
+    /// Output names of the projected expressions
+    #[prost(string, repeated, tag = "1")]
+    pub names: Vec<String>,
+}
+
+impl PhysicalProperties for ProjectionProperties {
+    const NAME: &'static str = "ProjectionProperties";
+}
+
+/// Mode of an `AggregateExec`
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum AggregateMode {
+    Partial = 0,
+    Final = 1,
+    FinalPartitioned = 2,
+    Single = 3,
+    SinglePartitioned = 4,
+}
+
+/// Properties of an `AggregateExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct AggregateProperties {
+    #[prost(enumeration = "AggregateMode", tag = "1")]
+    pub mode: i32,
+    /// Output names of the group expressions
+    #[prost(string, repeated, tag = "2")]
+    pub group_names: Vec<String>,
+    /// Output names of the aggregate expressions
+    #[prost(string, repeated, tag = "3")]
+    pub aggregate_names: Vec<String>,
+    /// Schema the arguments of the aggregate expressions refer to, which
+    /// is not the schema of the input for final aggregations
+    #[prost(message, optional, tag = "4")]
+    pub input_schema: Option<NamedStruct>,
+}
+
+impl PhysicalProperties for AggregateProperties {
+    const NAME: &'static str = "AggregateProperties";
+}
+
+/// Partition mode of a `HashJoinExec`
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum PartitionMode {
+    Partitioned = 0,
+    CollectLeft = 1,
+    Auto = 2,
+}
+
+/// Properties of a `HashJoinExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct HashJoinProperties {
+    #[prost(enumeration = "PartitionMode", tag = "1")]
+    pub partition_mode: i32,
+    #[prost(bool, tag = "2")]
+    pub null_equals_null: bool,
+}
+
+impl PhysicalProperties for HashJoinProperties {
+    const NAME: &'static str = "HashJoinProperties";
+}
+
+/// Sort options of a join key of a `SortMergeJoinExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct SortOptions {
+    #[prost(bool, tag = "1")]
+    pub descending: bool,
+    #[prost(bool, tag = "2")]
+    pub nulls_first: bool,
+}
+
+/// Properties of a `SortMergeJoinExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct SortMergeJoinProperties {
+    /// Sort options of each join key
+    #[prost(message, repeated, tag = "1")]
+    pub sort_options: Vec<SortOptions>,
+    #[prost(bool, tag = "2")]
+    pub null_equals_null: bool,
+}
+
+impl PhysicalProperties for SortMergeJoinProperties {
+    const NAME: &'static str = "SortMergeJoinProperties";
+}
+
+/// Properties of a `SortExec` or `SortPreservingMergeExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct SortProperties {
+    /// Whether the sorted partitions of the input are merged into one,
+    /// i.e. the plan is a `SortPreservingMergeExec`
+    #[prost(bool, tag = "1")]
+    pub merge: bool,
+    #[prost(bool, tag = "2")]
+    pub preserve_partitioning: bool,
+    #[prost(uint64, optional, tag = "3")]
+    pub fetch: Option<u64>,
+}
+
+impl PhysicalProperties for SortProperties {
+    const NAME: &'static str = "SortProperties";
+}
+
+/// Properties of a `GlobalLimitExec` or `LocalLimitExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct LimitProperties {
+    /// Whether the limit applies to each partition, i.e. the plan is a
+    /// `LocalLimitExec`
+    #[prost(bool, tag = "1")]
+    pub local: bool,
+}
+
+impl PhysicalProperties for LimitProperties {
+    const NAME: &'static str = "LimitProperties";
+}
+
+/// Properties of a `RepartitionExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct RepartitionProperties {
+    #[prost(bool, tag = "1")]
+    pub preserve_order: bool,
+}
+
+impl PhysicalProperties for RepartitionProperties {
+    const NAME: &'static str = "RepartitionProperties";
+}
+
+/// Properties of a `CoalesceBatchesExec`, which is the detail of an
+/// `ExtensionSingleRel`
+#[derive(Clone, PartialEq, Message)]
+pub struct CoalesceBatchesProperties {
+    #[prost(uint64, tag = "1")]
+    pub target_batch_size: u64,
+}
+
+impl PhysicalProperties for CoalesceBatchesProperties {
+    const NAME: &'static str = "CoalesceBatchesProperties";
+}
+
+/// Properties of a file scanned by a file scan
+#[derive(Clone, PartialEq, Message)]
+pub struct FileProperties {
+    /// Size of the whole file in bytes
+    #[prost(uint64, tag = "1")]
+    pub size: u64,
+    /// Values of the table partition columns for this file
+    #[prost(message, repeated, tag = "2")]
+    pub partition_values: Vec<Literal>,
+}
+
+/// An ordering of the output of a file scan
+#[derive(Clone, PartialEq, Message)]
+pub struct Ordering {
+    #[prost(message, repeated, tag = "1")]
+    pub sorts: Vec<SortField>,
+}
+
+/// Properties of a file scan, such as a `ParquetExec`
+///
+/// The schema of the files is the base schema of the read, and the files
+/// with their partition index are its local files. The projection and
+/// output orderings refer to the file schema followed by the table
+/// partition columns.
+#[derive(Clone, PartialEq, Message)]
+pub struct FileScanProperties {
+    #[prost(string, tag = "1")]
+    pub object_store_url: String,
+    /// Properties of each of the local files, in the same order
+    #[prost(message, repeated, tag = "2")]
+    pub files: Vec<FileProperties>,
+    #[prost(message, optional, tag = "3")]
+    pub table_partition_cols: Option<NamedStruct>,
+    #[prost(uint64, optional, tag = "4")]
+    pub limit: Option<u64>,
+    #[prost(message, repeated, tag = "5")]
+    pub output_ordering: Vec<Ordering>,
+    #[prost(bool, tag = "6")]
+    pub infinite_source: bool,
+}
+
+impl PhysicalProperties for FileScanProperties {
+    const NAME: &'static str = "FileScanProperties";
+}
+
+/// Format of CSV files, which is the extension file format of a `CsvExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct CsvFormat {
+    #[prost(bool, tag = "1")]
+    pub has_header: bool,
+    #[prost(uint32, tag = "2")]
+    pub delimiter: u32,
+    #[prost(uint32, tag = "3")]
+    pub quote: u32,
+    #[prost(uint32, optional, tag = "4")]
+    pub escape: Option<u32>,
+    #[prost(string, tag = "5")]
+    pub compression: String,
+}
+
+impl PhysicalProperties for CsvFormat {
+    const NAME: &'static str = "CsvFormat";
+}
+
+/// Format of newline delimited JSON files, which is the extension file
+/// format of a `NdJsonExec`
+#[derive(Clone, PartialEq, Message)]
+pub struct JsonFormat {
+    #[prost(string, tag = "1")]
+    pub compression: String,
+}
+
+impl PhysicalProperties for JsonFormat {
+    const NAME: &'static str = "JsonFormat";
+}
@@ -37,3 +37,14 @@ pub const DEFAULT_CONTAINER_TYPE_REF: u32 = 0;
 pub const LARGE_CONTAINER_TYPE_REF: u32 = 1;
 pub const DECIMAL_128_TYPE_REF: u32 = 0;
 pub const DECIMAL_256_TYPE_REF: u32 = 1;
+
+/// For [`DataType::Interval`] with [`IntervalUnit::MonthDayNano`], which has no
+/// counterpart in the core specification, a user defined type is used.
+///
+/// [`DataType::Interval`]: datafusion::arrow::datatypes::DataType::Interval
+/// [`IntervalUnit::MonthDayNano`]: datafusion::arrow::datatypes::IntervalUnit::MonthDayNano
+pub const INTERVAL_MONTH_DAY_NANO_TYPE_REF: u32 = 1;
+/// The type URL of the protobuf `Any` value holding an interval month day nano
+/// literal, which is the little endian encoding of the interval's `i128` value.
+pub const INTERVAL_MONTH_DAY_NANO_TYPE_URL: &str =
+    "type.googleapis.com/arrow.interval_month_day_nano";
@@ -34,7 +34,7 @@ use datafusion::prelude::*;
 
 use substrait::proto::extensions::simple_extension_declaration::MappingType;
 use substrait::proto::rel::RelType;
-use substrait::proto::{plan_rel, Plan, Rel};
+use substrait::proto::{plan_rel, set_rel, Plan, PlanRel, Rel, SetRel};
 
 struct MockSerializerRegistry;
 
@@ -507,6 +507,143 @@ async fn simple_intersect_table_reuse() -> Result<()> {
         .await
 }
 
+#[tokio::test]
+async fn roundtrip_intersect_set_rel() -> Result<()> {
+    roundtrip_set_rel(set_rel::SetOp::IntersectionPrimary, "INTERSECT").await?;
+    roundtrip_set_rel(set_rel::SetOp::IntersectionMultiset, "INTERSECT ALL").await
+}
+
+#[tokio::test]
+async fn roundtrip_except_set_rel() -> Result<()> {
+    roundtrip_set_rel(set_rel::SetOp::MinusPrimary, "EXCEPT").await?;
+    roundtrip_set_rel(set_rel::SetOp::MinusMultiset, "EXCEPT ALL").await
+}
+
+#[tokio::test]
+async fn roundtrip_values() -> Result<()> {
+    roundtrip("VALUES (1, 'a', 1.5), (NULL, NULL, 2.5)").await
+}
+
+#[tokio::test]
+async fn roundtrip_empty_relation() -> Result<()> {
+    roundtrip("SELECT * FROM data WHERE false").await
+}
+
+#[tokio::test]
+async fn roundtrip_cross_join() -> Result<()> {
+    roundtrip("SELECT data.a, data2.a FROM data CROSS JOIN data2").await
+}
+
+#[tokio::test]
+async fn roundtrip_scalar_subquery() -> Result<()> {
+    roundtrip_unoptimized("SELECT a FROM data WHERE a > (SELECT MAX(a) FROM data2)").await
+}
+
+#[tokio::test]
+async fn roundtrip_in_subquery() -> Result<()> {
+    roundtrip_unoptimized("SELECT a FROM data WHERE a IN (SELECT a FROM data2)").await?;
+    roundtrip_unoptimized("SELECT a FROM data WHERE a NOT IN (SELECT a FROM data2)").await
+}
+
+#[tokio::test]
+async fn roundtrip_exists_subquery() -> Result<()> {
+    roundtrip_unoptimized("SELECT a FROM data WHERE EXISTS (SELECT a FROM data2)")
+        .await?;
+    roundtrip_unoptimized("SELECT a FROM data WHERE NOT EXISTS (SELECT a FROM data2)")
+        .await
+}
+
+#[tokio::test]
+async fn roundtrip_correlated_subquery() -> Result<()> {
+    roundtrip_unoptimized(
+        "SELECT a FROM data WHERE EXISTS (SELECT a FROM data2 WHERE data2.a = data.a)",
+    )
+    .await?;
+    roundtrip_unoptimized(
+        "SELECT a FROM data WHERE b > (SELECT MAX(b) FROM data2 WHERE data2.a = data.a)",
+    )
+    .await
+}
+
+#[tokio::test]
+async fn roundtrip_interval_literal() -> Result<()> {
+    roundtrip("SELECT c FROM data WHERE c + INTERVAL '1 month 2 days' > c").await?;
+    roundtrip(
+        "SELECT c FROM data WHERE c + arrow_cast('1 year 2 months', 'Interval(YearMonth)') > c",
+    )
+    .await?;
+    roundtrip(
+        "SELECT c FROM data WHERE c + arrow_cast('3 days 4 seconds', 'Interval(DayTime)') > c",
+    )
+    .await
+}
+
+#[tokio::test]
+async fn roundtrip_list_literal() -> Result<()> {
+    roundtrip("SELECT a FROM data WHERE array_has(make_array(1, 2, 3), a)").await
+}
+
+#[tokio::test]
+async fn roundtrip_struct_literal() -> Result<()> {
+    roundtrip("SELECT a FROM data WHERE struct(a, f) = struct(1, 'x')").await
+}
+
+#[tokio::test]
+async fn roundtrip_insert() -> Result<()> {
+    roundtrip_statement("INSERT INTO data SELECT * FROM data").await
+}
+
+#[tokio::test]
+async fn roundtrip_delete() -> Result<()> {
+    roundtrip_statement("DELETE FROM data").await?;
+
+    // The planner doesn't qualify the columns of the predicate, while the
+    // consumer resolves them against the qualified table scan
+    let ctx = create_context().await?;
+    let plan = ctx
+        .state()
+        .create_logical_plan("DELETE FROM data WHERE a > 1")
+        .await?;
+    let proto = to_substrait_plan(&plan, &ctx)?;
+    let plan2 = from_substrait_plan(&ctx, &proto).await?;
+    assert_eq!(
+        format!("{plan2:?}"),
+        "Dml: op=[Delete] table=[data]\
+        \n  Filter: data.a > Int64(1)\
+        \n    TableScan: data"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn roundtrip_update() -> Result<()> {
+    roundtrip_statement("UPDATE data SET a = 1").await?;
+    roundtrip_statement("UPDATE data SET a = a + 1, f = 'x' WHERE e > 2").await
+}
+
+#[tokio::test]
+async fn roundtrip_create_table() -> Result<()> {
+    roundtrip_statement("CREATE TABLE t (x INT, y VARCHAR NOT NULL)").await?;
+    roundtrip_statement("CREATE OR REPLACE TABLE t (x INT)").await
+}
+
+#[tokio::test]
+async fn roundtrip_create_table_as_select() -> Result<()> {
+    roundtrip_statement("CREATE TABLE t AS SELECT a, b FROM data").await
+}
+
+#[tokio::test]
+async fn roundtrip_create_view() -> Result<()> {
+    roundtrip_statement("CREATE VIEW v AS SELECT a, b FROM data").await
+}
+
+#[tokio::test]
+async fn roundtrip_drop() -> Result<()> {
+    roundtrip_statement("DROP TABLE data").await?;
+    roundtrip_statement("DROP TABLE IF EXISTS data").await?;
+    roundtrip_statement("DROP VIEW IF EXISTS v").await
+}
+
 #[tokio::test]
 async fn simple_window_function() -> Result<()> {
     roundtrip("SELECT RANK() OVER (PARTITION BY a ORDER BY b), d, SUM(b) OVER (PARTITION BY a) FROM data;").await
@@ -547,10 +684,10 @@ async fn roundtrip_inner_join_table_reuse_non_zero_index() -> Result<()> {
 }
 
 /// Construct a plan that contains several literals of types that are currently supported.
+/// Interval, list and struct literals are covered by their own roundtrip tests.
 /// This case ignores:
 /// - Date64, for this literal is not supported
 /// - FixedSizeBinary, for converting UTF-8 literal to FixedSizeBinary is not supported
-/// - List, this nested type is not supported in arrow_cast
 /// - Decimal128 and Decimal256, them will fallback to UTF8 cast expr rather than plain literal.
 #[tokio::test]
 async fn all_type_literal() -> Result<()> {
@@ -779,6 +916,82 @@ async fn roundtrip(sql: &str) -> Result<()> {
     Ok(())
 }
 
+/// Roundtrip a plan before optimization, comparing the optimized plans
+///
+/// Used for plans like subqueries that the optimizer would otherwise rewrite
+/// into joins before they reach the producer.
+async fn roundtrip_unoptimized(sql: &str) -> Result<()> {
+    let ctx = create_context().await?;
+    let plan = ctx.sql(sql).await?.into_unoptimized_plan();
+    let proto = to_substrait_plan(&plan, &ctx)?;
+    let plan2 = from_substrait_plan(&ctx, &proto).await?;
+
+    let plan = ctx.state().optimize(&plan)?;
+    let plan2 = ctx.state().optimize(&plan2)?;
+
+    println!("{plan:#?}");
+    println!("{plan2:#?}");
+
+    let plan1str = format!("{plan:?}");
+    let plan2str = format!("{plan2:?}");
+    assert_eq!(plan1str, plan2str);
+    Ok(())
+}
+
+/// Roundtrip a DML or DDL statement without executing it
+async fn roundtrip_statement(sql: &str) -> Result<()> {
+    let ctx = create_context().await?;
+    let plan = ctx.state().create_logical_plan(sql).await?;
+    let proto = to_substrait_plan(&plan, &ctx)?;
+    let plan2 = from_substrait_plan(&ctx, &proto).await?;
+
+    println!("{plan:#?}");
+    println!("{plan2:#?}");
+
+    let plan1str = format!("{plan:?}");
+    let plan2str = format!("{plan2:?}");
+    assert_eq!(plan1str, plan2str);
+    Ok(())
+}
+
+/// Consume a Substrait `SetRel` with operator `op` over `SELECT a FROM data`
+/// and `SELECT a FROM data2`, comparing it with the same query in SQL
+async fn roundtrip_set_rel(op: set_rel::SetOp, sql_op: &str) -> Result<()> {
+    let ctx = create_context().await?;
+    let mut inputs = vec![];
+    for sql in ["SELECT a FROM data", "SELECT a FROM data2"] {
+        let plan = ctx.sql(sql).await?.into_unoptimized_plan();
+        let proto = to_substrait_plan(&plan, &ctx)?;
+        let Some(plan_rel::RelType::Root(root)) = &proto.relations[0].rel_type else {
+            return plan_err!("Expected a RelRoot");
+        };
+        inputs.push(root.input.clone().unwrap());
+    }
+    let proto = Plan {
+        relations: vec![PlanRel {
+            rel_type: Some(plan_rel::RelType::Rel(Rel {
+                rel_type: Some(RelType::Set(SetRel {
+                    common: None,
+                    inputs,
+                    op: op as i32,
+                    advanced_extension: None,
+                })),
+            })),
+        }],
+        ..Default::default()
+    };
+    let plan = from_substrait_plan(&ctx, &proto).await?;
+    let expected = ctx
+        .sql(&format!("SELECT a FROM data {sql_op} SELECT a FROM data2"))
+        .await?
+        .into_unoptimized_plan();
+
+    let plan1str = format!("{:?}", ctx.state().optimize(&expected)?);
+    let plan2str = format!("{:?}", ctx.state().optimize(&plan)?);
+    assert_eq!(plan1str, plan2str);
+    Ok(())
+}
+
 async fn roundtrip_verify_post_join_filter(sql: &str) -> Result<()> {
     let ctx = create_context().await?;
     let df = ctx.sql(sql).await?;
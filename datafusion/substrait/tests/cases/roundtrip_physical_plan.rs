@@ -18,13 +18,18 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use datafusion::arrow::datatypes::Schema;
-use datafusion::datasource::listing::PartitionedFile;
+use datafusion::arrow::compute::SortOptions;
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::datasource::listing::{FileRange, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::datasource::physical_plan::{FileScanConfig, ParquetExec};
 use datafusion::error::Result;
+use datafusion::logical_expr::Operator;
+use datafusion::physical_expr::PhysicalSortExpr;
+use datafusion::physical_plan::expressions::{binary, col, lit};
 use datafusion::physical_plan::{displayable, ExecutionPlan, Statistics};
-use datafusion::prelude::SessionContext;
+use datafusion::prelude::{CsvReadOptions, SessionConfig, SessionContext};
+use datafusion::scalar::ScalarValue;
 use datafusion_substrait::physical_plan::{consumer, producer};
 
 use substrait::proto::extensions;
@@ -77,3 +82,124 @@ async fn parquet_exec() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn parquet_exec_with_partitions() -> Result<()> {
+    let file_schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, false),
+    ]));
+    let mut file =
+        PartitionedFile::new("data/year=2023/part-0.parquet".to_string(), 1024);
+    file.partition_values = vec![ScalarValue::Utf8(Some("2023".to_string()))];
+    file.range = Some(FileRange {
+        start: 512,
+        end: 1024,
+    });
+    let scan_config = FileScanConfig {
+        object_store_url: ObjectStoreUrl::parse("s3://bucket")?,
+        file_schema: file_schema.clone(),
+        file_groups: vec![vec![file]],
+        statistics: Statistics::new_unknown(&file_schema),
+        projection: Some(vec![2, 0]),
+        limit: Some(10),
+        table_partition_cols: vec![Field::new("year", DataType::Utf8, false)],
+        output_ordering: vec![vec![PhysicalSortExpr {
+            expr: col("a", &file_schema)?,
+            options: SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        }]],
+        infinite_source: false,
+    };
+    let predicate = binary(
+        col("a", &file_schema)?,
+        Operator::Gt,
+        lit(1i64),
+        &file_schema,
+    )?;
+    let plan: Arc<dyn ExecutionPlan> =
+        Arc::new(ParquetExec::new(scan_config, Some(predicate), None));
+
+    roundtrip_plan(&SessionContext::new(), plan).await
+}
+
+#[tokio::test]
+async fn csv_scan_filter_sort_limit() -> Result<()> {
+    roundtrip("SELECT a, e FROM data WHERE a > 1 AND f LIKE 'a%' ORDER BY e DESC LIMIT 1")
+        .await
+}
+
+#[tokio::test]
+async fn expressions() -> Result<()> {
+    roundtrip(
+        "SELECT abs(a), CAST(e AS BIGINT), -a, a IS NULL, f NOT LIKE 'a%' \
+         FROM data WHERE a NOT IN (1, 2) OR e IS NOT NULL",
+    )
+    .await
+}
+
+#[tokio::test]
+async fn aggregate() -> Result<()> {
+    roundtrip("SELECT f, SUM(a), MAX(e), COUNT(*) FROM data GROUP BY f").await
+}
+
+#[tokio::test]
+async fn aggregate_distinct() -> Result<()> {
+    roundtrip("SELECT COUNT(DISTINCT a), SUM(e) FROM data").await
+}
+
+#[tokio::test]
+async fn hash_join_with_filter() -> Result<()> {
+    roundtrip(
+        "SELECT data.a, data2.f FROM data \
+         JOIN data2 ON data.a = data2.a AND data.e > data2.e",
+    )
+    .await
+}
+
+#[tokio::test]
+async fn sort_merge_join() -> Result<()> {
+    let config = SessionConfig::new()
+        .with_target_partitions(2)
+        .set_bool("datafusion.optimizer.prefer_hash_join", false);
+    let ctx = create_context(config).await?;
+    let plan = ctx
+        .sql("SELECT data.a, data2.f FROM data LEFT JOIN data2 ON data.a = data2.a")
+        .await?
+        .create_physical_plan()
+        .await?;
+    roundtrip_plan(&ctx, plan).await
+}
+
+async fn roundtrip(sql: &str) -> Result<()> {
+    let ctx = create_context(SessionConfig::new().with_target_partitions(2)).await?;
+    let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+    roundtrip_plan(&ctx, plan).await
+}
+
+/// Roundtrip an execution plan through a Substrait plan, comparing the
+/// displayed plans
+async fn roundtrip_plan(
+    ctx: &SessionContext,
+    plan: Arc<dyn ExecutionPlan>,
+) -> Result<()> {
+    let proto = producer::to_substrait_plan(plan.as_ref())?;
+    let plan2 = consumer::from_substrait_plan(ctx, &proto).await?;
+
+    let expected = format!("{}", displayable(plan.as_ref()).indent(true));
+    let actual = format!("{}", displayable(plan2.as_ref()).indent(true));
+    assert_eq!(expected, actual);
+    assert_eq!(plan.schema(), plan2.schema());
+    Ok(())
+}
+
+async fn create_context(config: SessionConfig) -> Result<SessionContext> {
+    let ctx = SessionContext::new_with_config(config);
+    ctx.register_csv("data", "tests/testdata/data.csv", CsvReadOptions::new())
+        .await?;
+    ctx.register_csv("data2", "tests/testdata/data.csv", CsvReadOptions::new())
+        .await?;
+    Ok(ctx)
+}